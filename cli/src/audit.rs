@@ -0,0 +1,491 @@
+use {
+    crate::prelude::*,
+    std::{io::Write, sync::Mutex},
+};
+
+/// Arguments of the command that is currently running, without the binary
+/// name. Set once in [crate::main] and again for each command of a batch.
+static COMMAND_ARGS: Mutex<Vec<String>> = Mutex::new(vec![]);
+
+/// Flags whose values are replaced before they are written to the audit log.
+/// `--input-json` is included as it may hold the plaintext of encrypted ports.
+const REDACTED_FLAG_MARKERS: [&str; 6] = [
+    "secret",
+    "passphrase",
+    "password",
+    "mnemonic",
+    "token",
+    "input-json",
+];
+
+const REDACTED: &str = "<redacted>";
+
+/// One line of the audit log.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct AuditEntry {
+    pub(crate) timestamp: chrono::DateTime<chrono::Utc>,
+    pub(crate) network: String,
+    /// The subcommand, for example `tool register`.
+    pub(crate) command: String,
+    /// The remaining arguments with secrets redacted.
+    pub(crate) args: Vec<String>,
+    pub(crate) digest: sui::TransactionDigest,
+}
+
+/// Remember the arguments of the command that is about to run.
+pub(crate) fn set_command_args(args: impl IntoIterator<Item = String>) {
+    let mut command_args = COMMAND_ARGS.lock().expect("Command args lock poisoned");
+
+    *command_args = args.into_iter().collect();
+}
+
+/// Whether the value of the flag with the given long name must not be written
+/// to the audit log.
+fn is_secret_flag(long: &str) -> bool {
+    REDACTED_FLAG_MARKERS
+        .iter()
+        .any(|marker| long.to_lowercase().contains(marker))
+}
+
+/// Whether the value of `arg` must not be written to the audit log.
+fn is_secret_arg(arg: &clap::Arg) -> bool {
+    let id = arg.get_id().as_str().replace('_', "-");
+
+    is_secret_flag(arg.get_long().unwrap_or(&id))
+}
+
+/// Find the flag of `command` with the given long name or alias.
+fn find_long<'a>(command: &'a clap::Command, long: &str) -> Option<&'a clap::Arg> {
+    command.get_arguments().find(|arg| {
+        arg.get_long() == Some(long)
+            || arg
+                .get_all_aliases()
+                .is_some_and(|aliases| aliases.contains(&long))
+    })
+}
+
+/// Find the flag of `command` with the given short name or alias.
+fn find_short(command: &clap::Command, short: char) -> Option<&clap::Arg> {
+    command.get_arguments().find(|arg| {
+        arg.get_short() == Some(short)
+            || arg
+                .get_all_short_aliases()
+                .is_some_and(|aliases| aliases.contains(&short))
+    })
+}
+
+/// Split the arguments into the subcommand and the redacted remaining
+/// arguments.
+///
+/// Flags are resolved against the [crate::Cli] definition so that short
+/// flags, aliases and global flags given before the subcommand are treated
+/// the same way clap parses them.
+fn split_and_redact(args: &[String]) -> (String, Vec<String>) {
+    let mut cli = crate::Cli::command();
+
+    // Propagate global flags to all subcommands.
+    cli.build();
+
+    let mut command = &cli;
+    let mut path = vec![];
+    let mut redacted = Vec::with_capacity(args.len());
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        // Everything after `--` is positional.
+        if arg == "--" {
+            redacted.push(arg.clone());
+            redacted.extend(args.cloned());
+
+            break;
+        }
+
+        // Whether the next argument is the value of this flag and if so,
+        // whether it is secret.
+        let mut value_is_next = None;
+
+        if let Some(long) = arg.strip_prefix("--") {
+            let (name, value) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value)),
+                None => (long, None),
+            };
+
+            let (secret, takes_value) = match find_long(command, name) {
+                Some(flag) => (is_secret_arg(flag), flag.get_action().takes_values()),
+                // Be conservative with flags that are not defined.
+                None => (is_secret_flag(name), true),
+            };
+
+            match value {
+                Some(_) if secret => redacted.push(format!("--{name}={REDACTED}")),
+                None if takes_value => {
+                    redacted.push(arg.clone());
+                    value_is_next = Some(secret);
+                }
+                _ => redacted.push(arg.clone()),
+            }
+        } else if let Some(shorts) = arg.strip_prefix('-').filter(|shorts| !shorts.is_empty()) {
+            // A group of short flags, the first one that takes a value takes
+            // the rest of the group or the next argument.
+            let mut group = String::from("-");
+
+            for (index, short) in shorts.char_indices() {
+                group.push(short);
+
+                let Some(flag) = find_short(command, short) else {
+                    continue;
+                };
+
+                if !flag.get_action().takes_values() {
+                    continue;
+                }
+
+                let secret = is_secret_arg(flag);
+
+                match &shorts[index + short.len_utf8()..] {
+                    "" => value_is_next = Some(secret),
+                    rest if secret && rest.starts_with('=') => {
+                        group.push_str(&format!("={REDACTED}"))
+                    }
+                    _ if secret => group.push_str(REDACTED),
+                    rest => group.push_str(rest),
+                }
+
+                break;
+            }
+
+            redacted.push(group);
+        } else {
+            match command.find_subcommand(arg) {
+                Some(subcommand) => {
+                    path.push(subcommand.get_name().to_string());
+                    command = subcommand;
+                }
+                None => redacted.push(arg.clone()),
+            }
+
+            continue;
+        }
+
+        if let (Some(secret), Some(value)) = (value_is_next, args.next()) {
+            match secret {
+                true => redacted.push(REDACTED.to_string()),
+                false => redacted.push(value.clone()),
+            }
+        }
+    }
+
+    (path.join(" "), redacted)
+}
+
+impl AuditEntry {
+    /// Create an entry for the transaction with `digest` submitted by the
+    /// current command on `network`.
+    pub(crate) fn new(network: SuiNet, digest: sui::TransactionDigest) -> Self {
+        let args = COMMAND_ARGS
+            .lock()
+            .expect("Command args lock poisoned")
+            .clone();
+        let (command, args) = split_and_redact(&args);
+
+        Self {
+            timestamp: chrono::Utc::now(),
+            network: network.to_string(),
+            command,
+            args,
+            digest,
+        }
+    }
+}
+
+/// Append `entry` to the audit log at `path` as a JSON line.
+pub(crate) fn append_entry(path: &Path, entry: &AuditEntry) -> AnyResult<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+
+    writeln!(file, "{}", serde_json::to_string(entry)?)?;
+
+    Ok(())
+}
+
+/// Record the transaction with `digest` in the audit log if one is configured.
+/// The transaction was already executed so failing to record it only prints a
+/// warning.
+pub(crate) async fn record_transaction(digest: sui::TransactionDigest) {
+    let Ok(conf) = CliConf::load().await else {
+        return;
+    };
+
+    let Some(path) = &conf.audit_log else {
+        return;
+    };
+
+    if let Err(e) = append_entry(path, &AuditEntry::new(conf.sui.net, digest)) {
+        eprintln!(
+            "{warning} Could not write to the audit log at '{path}': {e}",
+            warning = "⚠".bold().yellow(),
+            path = path.display()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        nexus_sdk::{
+            idents::{primitives, workflow, ModuleAndNameIdent},
+            test_utils::{
+                sui_mocks,
+                sui_rpc::{MockSuiRpc, RpcMethod},
+            },
+        },
+    };
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_split_and_redact() {
+        let (command, redacted) = split_and_redact(&args(&[
+            "--json",
+            "dag",
+            "execute",
+            "--dag-id",
+            "0x1",
+            "--input-json",
+            "{\"vertex\": {\"port\": \"secret\"}}",
+            "--api-token=abc",
+            "--inspect",
+        ]));
+
+        // Global flags before the subcommand are kept in the arguments.
+        assert_eq!(command, "dag execute");
+        assert_eq!(
+            redacted,
+            args(&[
+                "--json",
+                "--dag-id",
+                "0x1",
+                "--input-json",
+                REDACTED,
+                "--api-token=<redacted>",
+                "--inspect",
+            ])
+        );
+
+        // Short flags are resolved like their long counterparts.
+        let (command, redacted) = split_and_redact(&args(&[
+            "dag", "execute", "-d", "0x1", "-i", "{}", "-n", "-i{}", "-ni={}",
+        ]));
+
+        assert_eq!(command, "dag execute");
+        assert_eq!(
+            redacted,
+            args(&[
+                "-d",
+                "0x1",
+                "-i",
+                REDACTED,
+                "-n",
+                "-i<redacted>",
+                "-ni=<redacted>"
+            ])
+        );
+
+        // `-i` is the invocation cost for `tool register`.
+        let (command, redacted) = split_and_redact(&args(&[
+            "--profile",
+            "tool",
+            "tool",
+            "register",
+            "-i",
+            "10",
+        ]));
+
+        assert_eq!(command, "tool register");
+        assert_eq!(redacted, args(&["--profile", "tool", "-i", "10"]));
+
+        let (command, redacted) = split_and_redact(&args(&["crypto", "auth"]));
+
+        assert_eq!(command, "crypto auth");
+        assert!(redacted.is_empty());
+    }
+
+    #[tokio::test]
+    #[serial_test::serial(master_key_env, conf_env)]
+    async fn test_tool_register_appends_audit_line() {
+        // Isolate HOME so that ~/.nexus/conf.toml resolves into a temp dir.
+        let home = tempfile::tempdir().unwrap();
+        let audit_log = home.path().join("audit").join("log.jsonl");
+        let original_home = std::env::var_os("HOME");
+
+        std::env::set_var("HOME", home.path());
+
+        // Mock the tool.
+        let mut tool = mockito::Server::new_async().await;
+        let tool_url = tool.url();
+
+        tool.mock("GET", "/health")
+            .with_status(200)
+            .create_async()
+            .await;
+        tool.mock("GET", "/meta")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "fqn": "xyz.dummy.tool@1",
+                    "url": tool_url,
+                    "description": "",
+                    "input_schema": {},
+                    "output_schema": { "oneOf": [] },
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        // Mock the Sui RPC that executes the registration.
+        let objects = sui_mocks::mock_nexus_objects();
+        let digest = sui::TransactionDigest::random();
+        let owner_cap = |what_for: ModuleAndNameIdent| {
+            json!({
+                "type": "created",
+                "sender": sui::Address::ZERO,
+                "owner": { "AddressOwner": sui::Address::ZERO },
+                "objectType": format!(
+                    "{}::{}::{}<{}::{}::{}>",
+                    objects.primitives_pkg_id,
+                    primitives::OwnerCap::CLONEABLE_OWNER_CAP.module,
+                    primitives::OwnerCap::CLONEABLE_OWNER_CAP.name,
+                    objects.workflow_pkg_id,
+                    what_for.module,
+                    what_for.name,
+                ),
+                "objectId": sui::ObjectID::random(),
+                "version": "1",
+                "digest": sui::ObjectDigest::random(),
+            })
+        };
+        let response = json!({
+            "digest": digest,
+            "objectChanges": [
+                owner_cap(workflow::ToolRegistry::OVER_TOOL),
+                owner_cap(workflow::Gas::OVER_GAS),
+            ],
+            "checkpoint": "1",
+        });
+
+        let rpc = MockSuiRpc::builder()
+            .with_response(
+                RpcMethod::GetCoins,
+                json!({
+                    "data": [
+                        sui_mocks::mock_sui_coin(1_000_000_000),
+                        sui_mocks::mock_sui_coin(1_000_000_000),
+                    ],
+                    "nextCursor": null,
+                    "hasNextPage": false,
+                }),
+            )
+            .with_response(RpcMethod::GetReferenceGasPrice, json!("1000"))
+            .with_response(RpcMethod::ExecuteTransactionBlock, response.clone())
+            .with_response(RpcMethod::GetTransactionBlock, response)
+            .build()
+            .await;
+
+        // Create a wallet for the mocked network.
+        let keystore_path = home.path().join("sui.keystore");
+        let wallet_path = home.path().join("client.yaml");
+        let mut keystore = sui::FileBasedKeystore::new(&keystore_path).unwrap();
+        let address = keystore
+            .import_from_mnemonic(
+                "cost harsh bright regular skin trumpet pave about edit forget isolate monkey",
+                sui::SignatureScheme::ED25519,
+                None,
+                None,
+            )
+            .unwrap();
+        let mut client_config = sui::ClientConfig::new(keystore.into());
+
+        client_config.add_env(sui::Env {
+            alias: "localnet".to_string(),
+            rpc: rpc.url(),
+            ws: None,
+            basic_auth: None,
+        });
+        client_config.active_env = Some("localnet".to_string());
+        client_config.active_address = Some(address);
+        client_config.save(&wallet_path).unwrap();
+
+        let conf = CliConf {
+            sui: SuiConf {
+                net: SuiNet::Localnet,
+                wallet_path,
+                rpc_url: Some(reqwest::Url::parse(&rpc.url()).unwrap()),
+            },
+            nexus: Some(objects),
+            audit_log: Some(audit_log.clone()),
+            ..Default::default()
+        };
+
+        conf.save().await.unwrap();
+
+        // Run `tool register` with a global flag before the subcommand.
+        let command_args = args(&[
+            "--finality-timeout",
+            "5",
+            "tool",
+            "register",
+            "--off-chain",
+            &tool_url,
+            "-i",
+            "10",
+        ]);
+        let cli = crate::Cli::try_parse_from(
+            std::iter::once("nexus".to_string()).chain(command_args.clone()),
+        )
+        .unwrap();
+
+        set_command_args(command_args);
+
+        crate::run(cli.command).await.unwrap();
+
+        let content = std::fs::read_to_string(&audit_log).unwrap();
+        let entries = content
+            .lines()
+            .map(|line| serde_json::from_str::<AuditEntry>(line).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].digest, digest);
+        assert_eq!(entries[0].command, "tool register");
+        assert_eq!(entries[0].network, "localnet");
+        assert_eq!(
+            entries[0].args,
+            args(&[
+                "--finality-timeout",
+                "5",
+                "--off-chain",
+                &tool_url,
+                "-i",
+                "10"
+            ])
+        );
+        rpc.assert_calls(RpcMethod::ExecuteTransactionBlock, 1);
+
+        // Restore HOME for other tests.
+        match original_home {
+            Some(original_home) => std::env::set_var("HOME", original_home),
+            None => std::env::remove_var("HOME"),
+        }
+    }
+}
@@ -0,0 +1,405 @@
+use crate::{
+    command_title,
+    display::json_output,
+    item,
+    notify_error,
+    notify_success,
+    prelude::*,
+    Cli,
+    Command,
+};
+
+/// Keys of a batch command table that are not CLI flags.
+const COMMAND_KEY: &str = "command";
+const ARGS_KEY: &str = "args";
+
+/// A batch file is an array of tables where each table is one command.
+///
+/// ```toml
+/// [[commands]]
+/// command = "dag execute"
+/// dag-id = "0x1"
+/// input-json = '{"vertex": {"port": 1}}'
+/// inspect = true
+///
+/// [[commands]]
+/// command = "completion"
+/// args = ["bash"]
+/// ```
+///
+/// `command` holds the subcommand, `args` holds positional arguments and all
+/// other keys are long CLI flags. `true` booleans are passed as bare flags and
+/// arrays are passed as repeated flags.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BatchFile {
+    commands: Vec<toml::Table>,
+}
+
+/// Read the commands from the TOML file at `file`, validate all of them and
+/// then execute them in sequence.
+pub(crate) async fn run_batch(
+    file: PathBuf,
+    dry_run: bool,
+    continue_on_error: bool,
+) -> AnyResult<(), NexusCliError> {
+    command_title!("Running batch of commands from '{}'", file.display());
+
+    let content = tokio::fs::read_to_string(&file)
+        .await
+        .map_err(NexusCliError::Io)?;
+
+    let batch = toml::from_str::<BatchFile>(&content)
+        .map_err(|e| NexusCliError::Any(anyhow!("Failed to parse batch file: {e}")))?;
+
+    // Validate all commands before executing any of them.
+    let mut commands = Vec::with_capacity(batch.commands.len());
+
+    for (index, entry) in batch.commands.into_iter().enumerate() {
+        let args = entry_to_args(entry)
+            .map_err(|e| NexusCliError::Any(anyhow!("Command #{}: {e}", index + 1)))?;
+
+        let command = match parse_command(&args) {
+            Ok(command) => command,
+            Err(e) => {
+                notify_error!("Command #{} is invalid: {}", index + 1, display_args(&args));
+
+                return Err(e);
+            }
+        };
+
+        commands.push((args, command));
+    }
+
+    let total = commands.len();
+
+    if dry_run {
+        notify_success!("Batch contains {total} valid commands");
+
+        for (args, _) in &commands {
+            item!("{}", display_args(args).truecolor(100, 100, 100));
+        }
+
+        json_output(&json!({
+            "commands": commands.iter().map(|(args, _)| args).collect::<Vec<_>>(),
+        }))?;
+
+        return Ok(());
+    }
+
+    let mut failed = vec![];
+
+    for (index, (args, command)) in commands.into_iter().enumerate() {
+        let number = index + 1;
+
+        item!(
+            "Running command #{number} of {total}: {}",
+            display_args(&args).truecolor(100, 100, 100)
+        );
+
+        crate::audit::set_command_args(args);
+
+        // Boxed as the batch command is itself dispatched by `run`.
+        if let Err(e) = Box::pin(crate::run(command)).await {
+            eprintln!("\n{ballot} {e}", ballot = "X".red().bold());
+
+            if !continue_on_error {
+                return Err(NexusCliError::Any(anyhow!(
+                    "Batch aborted at command #{number} of {total}"
+                )));
+            }
+
+            failed.push(number);
+        }
+    }
+
+    if !failed.is_empty() {
+        return Err(NexusCliError::Any(anyhow!(
+            "{} of {total} commands failed: {}",
+            failed.len(),
+            failed
+                .iter()
+                .map(|number| format!("#{number}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+
+    notify_success!(
+        "All {total} commands {succeeded}",
+        succeeded = "succeeded".truecolor(100, 100, 100)
+    );
+
+    Ok(())
+}
+
+/// Turn a batch command table into CLI arguments.
+fn entry_to_args(mut entry: toml::Table) -> AnyResult<Vec<String>> {
+    let command = match entry.remove(COMMAND_KEY) {
+        Some(toml::Value::String(command)) => command,
+        Some(_) => bail!("'{COMMAND_KEY}' must be a string"),
+        None => bail!("Missing the '{COMMAND_KEY}' key"),
+    };
+
+    let mut args = command
+        .split_whitespace()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+
+    match args.first().map(String::as_str) {
+        None => bail!("'{COMMAND_KEY}' cannot be empty"),
+        Some("batch") => bail!("Batches cannot be nested"),
+        Some(_) => (),
+    }
+
+    let positional = entry.remove(ARGS_KEY);
+
+    for (flag, value) in entry {
+        // Global flags apply to the whole batch.
+        if flag == "json" || flag == "profile" || flag == "output" {
+            bail!("Global flag '--{flag}' must be passed to the batch command itself");
+        }
+
+        match value {
+            toml::Value::Boolean(true) => args.push(format!("--{flag}")),
+            toml::Value::Boolean(false) => (),
+            toml::Value::Array(values) => {
+                for value in values {
+                    args.push(format!("--{flag}"));
+                    args.push(value_to_arg(&flag, value)?);
+                }
+            }
+            value => {
+                args.push(format!("--{flag}"));
+                args.push(value_to_arg(&flag, value)?);
+            }
+        }
+    }
+
+    match positional {
+        Some(toml::Value::Array(values)) => {
+            for value in values {
+                args.push(value_to_arg(ARGS_KEY, value)?);
+            }
+        }
+        Some(_) => bail!("'{ARGS_KEY}' must be an array"),
+        None => (),
+    }
+
+    Ok(args)
+}
+
+/// Turn a scalar TOML value into a CLI argument.
+fn value_to_arg(key: &str, value: toml::Value) -> AnyResult<String> {
+    match value {
+        toml::Value::String(value) => Ok(value),
+        toml::Value::Integer(value) => Ok(value.to_string()),
+        toml::Value::Float(value) => Ok(value.to_string()),
+        toml::Value::Boolean(value) => Ok(value.to_string()),
+        toml::Value::Datetime(value) => Ok(value.to_string()),
+        toml::Value::Array(_) | toml::Value::Table(_) => {
+            bail!("Value of '{key}' must be a string, number or boolean")
+        }
+    }
+}
+
+/// Parse CLI arguments the same way as the command line is parsed.
+fn parse_command(args: &[String]) -> AnyResult<Command, NexusCliError> {
+    let cli = Cli::try_parse_from(std::iter::once("nexus").chain(args.iter().map(String::as_str)))
+        .map_err(NexusCliError::Syntax)?;
+
+    Ok(cli.command)
+}
+
+/// Format CLI arguments as they would be typed in a shell.
+fn display_args(args: &[String]) -> String {
+    let args = args
+        .iter()
+        .map(|arg| {
+            if arg.is_empty() || arg.contains(|c: char| c.is_whitespace() || c == '"' || c == '\'')
+            {
+                format!("'{}'", arg.replace('\'', "'\\''"))
+            } else {
+                arg.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    format!("nexus {}", args.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_entry(toml: &str) -> AnyResult<Vec<String>> {
+        entry_to_args(toml::from_str::<toml::Table>(toml).unwrap())
+    }
+
+    async fn write_batch(content: &str) -> PathBuf {
+        let path = tempfile::tempdir().unwrap().into_path().join("batch.toml");
+
+        tokio::fs::write(&path, content).await.unwrap();
+
+        path
+    }
+
+    #[test]
+    fn test_entry_to_args() {
+        let args = parse_entry(
+            r#"
+            command = "dag execute"
+            dag-id = "0x1"
+            input-json = '{"a": {"b": 1}}'
+            inspect = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            args,
+            vec![
+                "dag",
+                "execute",
+                "--dag-id",
+                "0x1",
+                "--input-json",
+                r#"{"a": {"b": 1}}"#,
+                "--inspect"
+            ]
+        );
+        assert!(matches!(parse_command(&args), Ok(Command::Dag(_))));
+
+        // Positional arguments, numbers, false booleans and repeated flags.
+        let args = parse_entry(
+            r#"
+            command = "tool register"
+            args = ["positional"]
+            invocation-cost = 10
+            no-save = false
+            batch = true
+            off-chain = ["https://a.example", "https://b.example"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            args,
+            vec![
+                "tool",
+                "register",
+                "--batch",
+                "--invocation-cost",
+                "10",
+                "--off-chain",
+                "https://a.example",
+                "--off-chain",
+                "https://b.example",
+                "positional"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_entry_to_args_invalid() {
+        assert!(parse_entry(r#"dag-id = "0x1""#).is_err());
+        assert!(parse_entry("command = 1").is_err());
+        assert!(parse_entry(r#"command = "  ""#).is_err());
+        assert!(parse_entry(r#"command = "batch""#).is_err());
+        assert!(parse_entry("command = \"tool list\"\njson = true").is_err());
+        assert!(parse_entry("command = \"tool list\"\noutput = \"out.json\"").is_err());
+        assert!(parse_entry("command = \"tool list\"\nargs = \"x\"").is_err());
+        assert!(parse_entry("command = \"tool list\"\nfoo = { bar = 1 }").is_err());
+        assert!(parse_entry("command = \"tool list\"\nfoo = [[1]]").is_err());
+
+        // Syntactically invalid commands are rejected by clap.
+        let args = parse_entry("command = \"tool list\"\nnot-a-flag = 1").unwrap();
+
+        assert!(matches!(
+            parse_command(&args),
+            Err(NexusCliError::Syntax(_))
+        ));
+    }
+
+    #[test]
+    fn test_display_args() {
+        let args = vec![
+            "dag".to_string(),
+            "execute".to_string(),
+            "--input-json".to_string(),
+            r#"{"a": 1}"#.to_string(),
+        ];
+
+        assert_eq!(
+            display_args(&args),
+            r#"nexus dag execute --input-json '{"a": 1}'"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_batch() {
+        let missing = tempfile::tempdir()
+            .unwrap()
+            .into_path()
+            .join("missing.json");
+        let path = write_batch(&format!(
+            r#"
+            [[commands]]
+            command = "dag validate"
+            path = "{missing}"
+
+            [[commands]]
+            command = "completion"
+            args = ["bash"]
+            "#,
+            missing = missing.display()
+        ))
+        .await;
+
+        // Nothing is executed in dry run mode.
+        assert!(run_batch(path.clone(), true, false).await.is_ok());
+
+        // The batch is aborted on the first error...
+        let result = run_batch(path.clone(), false, false).await;
+
+        assert!(
+            matches!(result, Err(NexusCliError::Any(e)) if e.to_string().contains("aborted at command #1 of 2"))
+        );
+
+        // ... unless errors are ignored.
+        let result = run_batch(path, false, true).await;
+
+        assert!(
+            matches!(result, Err(NexusCliError::Any(e)) if e.to_string() == "1 of 2 commands failed: #1")
+        );
+
+        // Only valid commands are executed.
+        let path = write_batch(
+            r#"
+            [[commands]]
+            command = "completion"
+            args = ["bash"]
+
+            [[commands]]
+            command = "tool unknown"
+            "#,
+        )
+        .await;
+
+        assert!(matches!(
+            run_batch(path, false, false).await,
+            Err(NexusCliError::Syntax(_))
+        ));
+
+        let path = write_batch(
+            r#"
+            [[commands]]
+            command = "completion"
+            args = ["bash"]
+            "#,
+        )
+        .await;
+
+        assert!(run_batch(path, false, false).await.is_ok());
+    }
+}
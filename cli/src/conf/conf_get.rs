@@ -1,8 +1,9 @@
-use crate::{command_title, prelude::*};
+use crate::{command_title, item, prelude::*};
 
-/// Print the current Nexus CLI configuration.
+/// Print the current Nexus CLI configuration, including the values overridden
+/// by environment variables.
 pub(crate) async fn get_nexus_conf(conf_path: PathBuf) -> AnyResult<CliConf, NexusCliError> {
-    let conf = CliConf::load_from_path(&conf_path).await.map_err(|e| {
+    let mut conf = CliConf::load_from_path(&conf_path).await.map_err(|e| {
         NexusCliError::Any(anyhow!(
             "Failed to load Nexus CLI configuration from {}: {}",
             conf_path.display(),
@@ -10,11 +11,36 @@ pub(crate) async fn get_nexus_conf(conf_path: PathBuf) -> AnyResult<CliConf, Nex
         ))
     })?;
 
+    conf.apply_env().await.map_err(NexusCliError::Any)?;
+
     command_title!("Current Nexus CLI Configuration");
 
     Ok(conf)
 }
 
+/// Print where the values of the configuration come from.
+pub(crate) fn print_value_sources(conf: &CliConf) {
+    command_title!("Value Sources");
+
+    for (key, source) in conf.value_sources() {
+        item!("{key}: {source}");
+    }
+}
+
+/// Print only the values of the given profile.
+pub(crate) async fn get_nexus_conf_profile(
+    profile: &str,
+    conf_path: PathBuf,
+) -> AnyResult<ProfileConf, NexusCliError> {
+    let mut conf = get_nexus_conf(conf_path).await?;
+
+    conf.profiles.remove(profile).ok_or_else(|| {
+        NexusCliError::Any(anyhow!(
+            "Profile '{profile}' does not exist in the configuration"
+        ))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -23,7 +49,7 @@ mod tests {
     };
 
     #[tokio::test]
-    #[serial_test::serial(master_key_env)]
+    #[serial_test::serial(master_key_env, conf_env)]
     async fn test_get_nexus_conf() {
         std::env::set_var("NEXUS_CLI_STORE_PASSPHRASE", "test_passphrase");
 
@@ -79,7 +105,7 @@ mod tests {
             .expect("Failed to initiate session");
 
         // Store the sender session
-        sessions.insert(*sender_session.id(), sender_session);
+        sessions.insert(*sender_session.id(), StoredSession::new(sender_session));
 
         let crypto_conf = CryptoConf {
             identity_key: Some(IdentityKey::generate()),
@@ -91,6 +117,7 @@ mod tests {
             nexus: Some(nexus_objects.clone()),
             tools: tools.clone(),
             crypto: Some(Secret::new(crypto_conf)),
+            ..Default::default()
         };
 
         // Write the configuration to the file.
@@ -113,10 +140,10 @@ mod tests {
         );
 
         // Verify we can recover the sessions from the configuration
-        for (session_id, session) in result.crypto.as_ref().unwrap().sessions.iter() {
+        for (session_id, stored) in result.crypto.as_ref().unwrap().sessions.iter() {
             // Verify session IDs are properly stored and retrieved
             assert_eq!(
-                session.id(),
+                stored.session.id(),
                 session_id,
                 "Session ID should match the map key"
             );
@@ -128,6 +155,7 @@ mod tests {
             nexus: Some(nexus_objects.clone()),
             tools: tools.clone(),
             crypto: None,
+            ..Default::default()
         };
 
         let path_no_crypto = tempdir.join("conf_no_crypto.toml");
@@ -151,4 +179,37 @@ mod tests {
         std::env::remove_var("XDG_CONFIG_HOME");
         std::env::remove_var("XDG_DATA_HOME");
     }
+
+    #[tokio::test]
+    async fn test_get_nexus_conf_profile() {
+        let tempdir = tempfile::tempdir().unwrap().into_path();
+        let path = tempdir.join("conf.toml");
+
+        let dev = ProfileConf {
+            sui: SuiProfileConf {
+                net: Some(SuiNet::Devnet),
+                rpc_url: Some(reqwest::Url::parse("https://devnet.sui.io").unwrap()),
+                ..Default::default()
+            },
+            nexus: None,
+        };
+
+        let conf = CliConf {
+            profiles: HashMap::from([("dev".to_string(), dev.clone())]),
+            ..Default::default()
+        };
+
+        conf.save_to_path(&path).await.unwrap();
+
+        let result = get_nexus_conf_profile("dev", path.clone())
+            .await
+            .expect("Failed to get profile");
+
+        assert_eq!(result, dev);
+
+        // Missing profiles are reported.
+        let result = get_nexus_conf_profile("prod", path).await;
+
+        assert!(matches!(result, Err(NexusCliError::Any(e)) if e.to_string().contains("'prod'")));
+    }
 }
@@ -0,0 +1,122 @@
+use crate::{command_title, confirm, loading, prelude::*};
+
+/// Reset the Nexus CLI configuration at `conf_path` to its default values.
+///
+/// Unless `confirm` is set, the current configuration is printed and the user
+/// is asked for confirmation first.
+pub(crate) async fn reset_nexus_conf(
+    confirm: bool,
+    conf_path: PathBuf,
+) -> AnyResult<CliConf, NexusCliError> {
+    command_title!("Resetting Nexus CLI Configuration");
+
+    if !confirm && !JSON_MODE.load(Ordering::Relaxed) {
+        // Show the user what is about to be discarded.
+        if let Ok(current) = CliConf::load_from_path(&conf_path).await {
+            if let Ok(current) = toml::to_string_pretty(&current) {
+                println!("{current}");
+            }
+        }
+
+        confirm!(
+            "This will reset the configuration at '{}' to defaults, including saved tool owner caps and crypto state. Do you want to proceed?",
+            conf_path.display()
+        );
+    }
+
+    let conf_handle = loading!("Resetting configuration...");
+
+    // The default configuration has no Nexus objects so that the CLI falls
+    // back to fetching them again instead of using stale ones.
+    let conf = CliConf::default();
+
+    match conf.save_to_path(&conf_path).await {
+        Ok(()) => {
+            conf_handle.success();
+
+            Ok(conf)
+        }
+        Err(e) => {
+            conf_handle.error();
+
+            Err(NexusCliError::Any(e))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, nexus_sdk::test_utils::sui_mocks};
+
+    #[tokio::test]
+    #[serial_test::serial(master_key_env)]
+    async fn test_reset_nexus_conf() {
+        std::env::set_var("NEXUS_CLI_STORE_PASSPHRASE", "test_passphrase");
+
+        let secret_home = tempfile::tempdir().unwrap();
+        std::env::set_var("XDG_CONFIG_HOME", secret_home.path());
+        std::env::set_var("XDG_DATA_HOME", secret_home.path());
+
+        let tempdir = tempfile::tempdir().unwrap().into_path();
+        let path = tempdir.join("conf.toml");
+
+        let mut tools = HashMap::new();
+        tools.insert(
+            fqn!("xyz.dummy.tool@1"),
+            ToolOwnerCaps {
+                over_tool: sui::ObjectID::random(),
+                over_gas: sui::ObjectID::random(),
+            },
+        );
+
+        let conf = CliConf {
+            sui: SuiConf {
+                net: SuiNet::Mainnet,
+                wallet_path: tempdir.join("wallet"),
+                rpc_url: Some(reqwest::Url::parse("https://mainnet.sui.io").unwrap()),
+            },
+            nexus: Some(NexusObjects {
+                workflow_pkg_id: sui::ObjectID::random(),
+                primitives_pkg_id: sui::ObjectID::random(),
+                interface_pkg_id: sui::ObjectID::random(),
+                network_id: sui::ObjectID::random(),
+                tool_registry: sui_mocks::mock_sui_object_ref(),
+                default_tap: sui_mocks::mock_sui_object_ref(),
+                gas_service: sui_mocks::mock_sui_object_ref(),
+                pre_key_vault: sui_mocks::mock_sui_object_ref(),
+            }),
+            tools,
+            crypto: None,
+            ..Default::default()
+        };
+
+        conf.save_to_path(&path).await.unwrap();
+
+        let result = reset_nexus_conf(true, path.clone())
+            .await
+            .expect("Failed to reset config");
+
+        assert_eq!(result, CliConf::default());
+
+        // Check that the file was overwritten with defaults.
+        let conf = CliConf::load_from_path(&path).await.unwrap();
+
+        assert_eq!(conf, CliConf::default());
+        assert!(conf.nexus.is_none());
+        assert!(conf.tools.is_empty());
+
+        // Resetting a non-existent config creates it.
+        let missing_path = tempdir.join("missing").join("conf.toml");
+
+        assert!(reset_nexus_conf(true, missing_path.clone()).await.is_ok());
+        assert_eq!(
+            CliConf::load_from_path(&missing_path).await.unwrap(),
+            CliConf::default()
+        );
+
+        // Clean-up env vars
+        std::env::remove_var("NEXUS_CLI_STORE_PASSPHRASE");
+        std::env::remove_var("XDG_CONFIG_HOME");
+        std::env::remove_var("XDG_DATA_HOME");
+    }
+}
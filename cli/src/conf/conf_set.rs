@@ -1,11 +1,25 @@
-use crate::{command_title, display::json_output, loading, prelude::*, sui::resolve_wallet_path};
+use crate::{
+    command_title,
+    conf::find_missing_objects,
+    display::json_output,
+    loading,
+    prelude::*,
+    sui::resolve_wallet_path,
+};
 
-/// Set the Nexus CLI configuration from the provided arguments.
+/// Set the Nexus CLI configuration from the provided arguments. If `verify` is
+/// set and Nexus objects are provided, warn about those that do not exist on
+/// the configured network.
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn set_nexus_conf(
     sui_net: Option<SuiNet>,
     sui_wallet_path: Option<PathBuf>,
     sui_rpc_url: Option<reqwest::Url>,
     nexus_objects_path: Option<PathBuf>,
+    default_profile: Option<String>,
+    audit_log: Option<PathBuf>,
+    profile: Option<String>,
+    verify: bool,
     conf_path: PathBuf,
 ) -> AnyResult<(), NexusCliError> {
     let mut conf = CliConf::load_from_path(&conf_path)
@@ -16,42 +30,110 @@ pub(crate) async fn set_nexus_conf(
     let conf_handle = loading!("Updating configuration...");
 
     // If a nexus.objects file is provided, load the file and update configuration.
-    if let Some(objects_path) = nexus_objects_path {
-        let content = std::fs::read_to_string(&objects_path).map_err(|e| {
-            NexusCliError::Any(anyhow!(
-                "Failed to read objects file {}: {}",
-                objects_path.display(),
-                e
-            ))
-        })?;
-
-        let objects: NexusObjects = toml::from_str(&content).map_err(|e| {
-            NexusCliError::Any(anyhow!(
-                "Failed to parse objects file {}: {}",
-                objects_path.display(),
-                e
-            ))
-        })?;
-
-        conf.nexus = Some(objects);
+    let objects = match nexus_objects_path {
+        Some(objects_path) => Some(load_nexus_objects(objects_path)?),
+        None => None,
+    };
+    let verify = verify && objects.is_some();
+
+    if let Some(default_profile) = default_profile {
+        conf.profile = Some(default_profile);
+    }
+
+    if let Some(audit_log) = audit_log {
+        conf.audit_log = Some(audit_log);
     }
 
-    conf.sui.net = sui_net.unwrap_or(conf.sui.net);
-    conf.sui.wallet_path = resolve_wallet_path(sui_wallet_path, &conf.sui)?;
-    conf.sui.rpc_url = sui_rpc_url.or(conf.sui.rpc_url);
+    match profile {
+        // Write the values to the profile sub-table.
+        Some(profile) => {
+            let profile = conf.profiles.entry(profile).or_default();
+
+            profile.sui.net = sui_net.or(profile.sui.net);
+            profile.sui.wallet_path = sui_wallet_path.or(profile.sui.wallet_path.take());
+            profile.sui.rpc_url = sui_rpc_url.or(profile.sui.rpc_url.take());
+            profile.nexus = objects.or(profile.nexus.take());
+        }
+        None => {
+            conf.nexus = objects.or(conf.nexus);
+            conf.sui.net = sui_net.unwrap_or(conf.sui.net);
+            conf.sui.wallet_path = resolve_wallet_path(sui_wallet_path, &conf.sui)?;
+            conf.sui.rpc_url = sui_rpc_url.or(conf.sui.rpc_url);
+        }
+    }
 
     json_output(&serde_json::to_value(&conf).unwrap())?;
 
     match conf.save_to_path(&conf_path).await {
-        Ok(()) => {
-            conf_handle.success();
-            Ok(())
-        }
+        Ok(()) => conf_handle.success(),
         Err(e) => {
             conf_handle.error();
-            Err(NexusCliError::Any(e))
+
+            return Err(NexusCliError::Any(e));
         }
     }
+
+    if verify {
+        warn_missing_objects(&conf_path).await;
+    }
+
+    Ok(())
+}
+
+/// Print a warning for every Nexus object that does not exist on the network
+/// of the saved configuration. The configuration is valid regardless so
+/// failures are not errors.
+async fn warn_missing_objects(conf_path: &PathBuf) {
+    let missing: AnyResult<Vec<types::MissingObject>> = async {
+        let mut conf = CliConf::load_from_path(conf_path).await?;
+
+        conf.apply_profile()?;
+
+        let Some(objects) = &conf.nexus else {
+            return Ok(vec![]);
+        };
+
+        find_missing_objects(&conf.sui, objects)
+            .await
+            .map_err(|e| anyhow!("{e}"))
+    }
+    .await;
+
+    if JSON_MODE.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let warning = "⚠".bold().yellow();
+
+    match missing {
+        Ok(missing) => {
+            for missing in missing {
+                eprintln!("{warning} Nexus object {missing}");
+            }
+        }
+        Err(e) => eprintln!("{warning} Could not verify the Nexus objects: {e}"),
+    }
+}
+
+/// Load Nexus objects from the TOML file at the given path.
+fn load_nexus_objects(objects_path: PathBuf) -> AnyResult<NexusObjects, NexusCliError> {
+    let content = std::fs::read_to_string(&objects_path).map_err(|e| {
+        NexusCliError::Any(anyhow!(
+            "Failed to read objects file {}: {}",
+            objects_path.display(),
+            e
+        ))
+    })?;
+
+    let objects: NexusObjects = toml::from_str(&content).map_err(|e| {
+        NexusCliError::Any(anyhow!(
+            "Failed to parse objects file {}: {}",
+            objects_path.display(),
+            e
+        ))
+    })?;
+
+    Ok(objects)
 }
 
 #[cfg(test)]
@@ -99,6 +181,10 @@ mod tests {
             Some(tempdir.join("wallet")),
             Some(reqwest::Url::parse("https://mainnet.sui.io").unwrap()),
             Some(tempdir.join("objects.toml")),
+            None,
+            None,
+            None,
+            false,
             path.clone(),
         )
         .await;
@@ -118,7 +204,18 @@ mod tests {
         assert_eq!(objects, nexus_objects_instance);
 
         // Overriding one value will save that one value and leave other values intact.
-        let result = set_nexus_conf(Some(SuiNet::Testnet), None, None, None, path.clone()).await;
+        let result = set_nexus_conf(
+            Some(SuiNet::Testnet),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            path.clone(),
+        )
+        .await;
 
         assert_matches!(result, Ok(()));
 
@@ -133,6 +230,35 @@ mod tests {
         );
         assert_eq!(objects, nexus_objects_instance);
 
+        // Values are written to the profile sub-table if a profile is given.
+        let result = set_nexus_conf(
+            Some(SuiNet::Devnet),
+            None,
+            Some(reqwest::Url::parse("https://devnet.sui.io").unwrap()),
+            None,
+            Some("dev".to_string()),
+            None,
+            Some("dev".to_string()),
+            false,
+            path.clone(),
+        )
+        .await;
+
+        assert_matches!(result, Ok(()));
+
+        let conf = CliConf::load_from_path(&path).await.unwrap();
+        let profile = conf.profiles.get("dev").unwrap();
+
+        assert_eq!(conf.profile, Some("dev".to_string()));
+        assert_eq!(conf.sui.net, SuiNet::Testnet);
+        assert_eq!(profile.sui.net, Some(SuiNet::Devnet));
+        assert_eq!(profile.sui.wallet_path, None);
+        assert_eq!(
+            profile.sui.rpc_url,
+            Some(reqwest::Url::parse("https://devnet.sui.io").unwrap())
+        );
+        assert_eq!(profile.nexus, None);
+
         // Clean up env vars.
         std::env::remove_var("NEXUS_CLI_STORE_PASSPHRASE");
         std::env::remove_var("XDG_CONFIG_HOME");
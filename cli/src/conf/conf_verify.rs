@@ -0,0 +1,181 @@
+use crate::{
+    command_title,
+    display::json_output,
+    item,
+    loading,
+    notify_success,
+    prelude::*,
+    sui::build_sui_client,
+};
+
+/// Check that the configured Nexus objects exist on the active network.
+pub(crate) async fn verify_nexus_conf(conf_path: PathBuf) -> AnyResult<(), NexusCliError> {
+    let mut conf = CliConf::load_from_path(&conf_path).await.map_err(|e| {
+        NexusCliError::Any(anyhow!(
+            "Failed to load Nexus CLI configuration from {}: {}",
+            conf_path.display(),
+            e
+        ))
+    })?;
+
+    conf.apply_profile().map_err(NexusCliError::Any)?;
+    conf.apply_env().await.map_err(NexusCliError::Any)?;
+
+    command_title!("Verifying Nexus objects on '{}'", conf.sui.net);
+
+    let Some(objects) = conf.nexus.clone() else {
+        return Err(NexusCliError::Any(anyhow!(
+            "{message}\n\n{command}",
+            message = "References to Nexus objects are missing in the CLI configuration. Use the following command to update it:",
+            command = "$ nexus conf set --nexus.objects <PATH_TO_OBJECTS_TOML>".bold(),
+        )));
+    };
+
+    let missing = find_missing_objects(&conf.sui, &objects).await?;
+
+    json_output(&json!({
+        "missing": missing
+            .iter()
+            .map(|missing| json!({
+                "name": missing.name,
+                "object_id": missing.object_id,
+                "reason": missing.to_string(),
+            }))
+            .collect::<Vec<_>>(),
+    }))?;
+
+    if missing.is_empty() {
+        notify_success!("All Nexus objects exist on the network");
+
+        return Ok(());
+    }
+
+    for missing in &missing {
+        item!("{missing}");
+    }
+
+    Err(NexusCliError::Any(anyhow!(
+        "{} of the configured Nexus objects are missing or invalid",
+        missing.len()
+    )))
+}
+
+/// Fetch the Nexus objects from the network the configuration points to and
+/// return those that are missing or of the wrong type.
+pub(crate) async fn find_missing_objects(
+    sui_conf: &SuiConf,
+    objects: &NexusObjects,
+) -> AnyResult<Vec<types::MissingObject>, NexusCliError> {
+    let sui = build_sui_client(sui_conf).await?;
+
+    let objects_handle = loading!("Fetching Nexus objects...");
+
+    match objects.verify(&sui).await {
+        Ok(()) => {
+            objects_handle.success();
+
+            Ok(vec![])
+        }
+        Err(missing) => {
+            objects_handle.error();
+
+            Ok(missing)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        nexus_sdk::test_utils::{
+            sui_mocks,
+            sui_rpc::{MockSuiRpc, RpcMethod},
+        },
+        serde_json::Value,
+    };
+
+    fn object(object_id: sui::ObjectID, type_: String) -> Value {
+        json!({
+            "data": {
+                "objectId": object_id,
+                "version": "2",
+                "digest": sui::ObjectDigest::random(),
+                "type": type_,
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn test_verify_nexus_conf() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let path = tempdir.path().join("conf.toml");
+        let objects = sui_mocks::mock_nexus_objects();
+        let pkg = objects.workflow_pkg_id;
+
+        let present = vec![
+            object(objects.workflow_pkg_id, "package".to_string()),
+            object(objects.primitives_pkg_id, "package".to_string()),
+            object(objects.interface_pkg_id, "package".to_string()),
+            object(objects.network_id, format!("{pkg}::network::Network")),
+            object(
+                objects.tool_registry.object_id,
+                format!("{pkg}::tool_registry::ToolRegistry"),
+            ),
+            object(
+                objects.default_tap.object_id,
+                format!("{pkg}::default_tap::DefaultTAP"),
+            ),
+            object(
+                objects.gas_service.object_id,
+                format!("{pkg}::gas::GasService"),
+            ),
+            object(
+                objects.pre_key_vault.object_id,
+                format!("{pkg}::pre_key_vault::PreKeyVault"),
+            ),
+        ];
+
+        // The gas service is missing on the second call.
+        let mut with_missing = present.clone();
+        with_missing[6] = json!({
+            "error": { "code": "notExists", "object_id": objects.gas_service.object_id },
+        });
+
+        let rpc = MockSuiRpc::builder()
+            .with_responses(
+                RpcMethod::MultiGetObjects,
+                vec![json!(present), json!(with_missing)],
+            )
+            .build()
+            .await;
+
+        let conf = CliConf {
+            sui: SuiConf {
+                rpc_url: Some(reqwest::Url::parse(&rpc.url()).unwrap()),
+                ..Default::default()
+            },
+            nexus: Some(objects.clone()),
+            ..Default::default()
+        };
+
+        conf.save_to_path(&path).await.unwrap();
+
+        assert!(verify_nexus_conf(path.clone()).await.is_ok());
+
+        let result = verify_nexus_conf(path.clone()).await;
+
+        assert!(matches!(result, Err(NexusCliError::Any(e)) if e.to_string().starts_with("1 of")));
+
+        let missing = find_missing_objects(&conf.sui, &objects).await.unwrap();
+
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].name, "gas_service");
+        rpc.assert_calls(RpcMethod::MultiGetObjects, 3);
+
+        // Nothing to verify without objects.
+        CliConf::default().save_to_path(&path).await.unwrap();
+
+        assert!(verify_nexus_conf(path).await.is_err());
+    }
+}
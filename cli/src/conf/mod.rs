@@ -1,10 +1,14 @@
 mod conf_get;
+mod conf_reset;
 mod conf_set;
+mod conf_verify;
 
 use {
     crate::{display::json_output, prelude::*},
     conf_get::*,
+    conf_reset::*,
     conf_set::*,
+    conf_verify::*,
 };
 
 #[derive(Subcommand, Clone, Debug)]
@@ -51,6 +55,54 @@ pub(crate) enum ConfCommand {
             value_parser = ValueParser::from(expand_tilde)
         )]
         nexus_objects_path: Option<PathBuf>,
+        #[arg(
+            long = "default-profile",
+            help = "Set the profile to use when no --profile is passed",
+            value_name = "NAME"
+        )]
+        default_profile: Option<String>,
+        #[arg(
+            long = "audit-log",
+            help = "Append a JSON line for every submitted transaction to this file",
+            value_name = "PATH",
+            value_parser = ValueParser::from(expand_tilde)
+        )]
+        audit_log: Option<PathBuf>,
+        #[arg(
+            long = "no-verify",
+            help = "Do not check that the Nexus objects exist on the network"
+        )]
+        no_verify: bool,
+
+        /// Hidden argument used for testing to set the path of the configuration
+        /// file.
+        #[arg(
+            long = "conf-path",
+            hide = true,
+            default_value = CLI_CONF_PATH,
+            value_parser = ValueParser::from(expand_tilde)
+        )]
+        conf_path: PathBuf,
+    },
+
+    #[command(about = "Check that the configured Nexus objects exist on the network")]
+    Verify {
+        /// Hidden argument used for testing to set the path of the configuration
+        /// file.
+        #[arg(
+            long = "conf-path",
+            hide = true,
+            default_value = CLI_CONF_PATH,
+            value_parser = ValueParser::from(expand_tilde)
+        )]
+        conf_path: PathBuf,
+    },
+
+    #[command(about = "Reset the Nexus CLI configuration to its default values")]
+    Reset {
+        /// Whether to skip the confirmation prompt.
+        #[arg(long = "yes", short = 'y', help = "Skip the confirmation prompt")]
+        confirm: bool,
 
         /// Hidden argument used for testing to set the path of the configuration
         /// file.
@@ -68,26 +120,32 @@ pub(crate) enum ConfCommand {
 /// [crate::main].
 pub(crate) async fn handle(command: ConfCommand) -> AnyResult<(), NexusCliError> {
     match command {
-        ConfCommand::Get { conf_path } => {
-            let conf = get_nexus_conf(conf_path).await?;
+        // == `$ nexus conf get` ==
+        ConfCommand::Get { conf_path } => match ACTIVE_PROFILE.get() {
+            Some(profile) => {
+                let profile = get_nexus_conf_profile(profile, conf_path).await?;
 
-            json_output(&conf)?;
+                print_conf(&profile)
+            }
+            None => {
+                let conf = get_nexus_conf(conf_path).await?;
 
-            if !JSON_MODE.load(std::sync::atomic::Ordering::Relaxed) {
-                let conf = toml::to_string_pretty(&conf).map_err(|e| {
-                    NexusCliError::Any(anyhow!("Failed to serialize configuration to JSON: {}", e))
-                })?;
+                print_conf(&conf)?;
+                print_value_sources(&conf);
 
-                println!("{conf}");
+                Ok(())
             }
+        },
 
-            Ok(())
-        }
+        // == `$ nexus conf set` ==
         ConfCommand::Set {
             sui_net,
             sui_wallet_path,
             sui_rpc_url,
             nexus_objects_path,
+            default_profile,
+            audit_log,
+            no_verify,
             conf_path,
         } => {
             set_nexus_conf(
@@ -95,9 +153,38 @@ pub(crate) async fn handle(command: ConfCommand) -> AnyResult<(), NexusCliError>
                 sui_wallet_path,
                 sui_rpc_url,
                 nexus_objects_path,
+                default_profile,
+                audit_log,
+                ACTIVE_PROFILE.get().cloned(),
+                !no_verify,
                 conf_path,
             )
             .await
         }
+
+        // == `$ nexus conf verify` ==
+        ConfCommand::Verify { conf_path } => verify_nexus_conf(conf_path).await,
+
+        // == `$ nexus conf reset` ==
+        ConfCommand::Reset { confirm, conf_path } => {
+            let conf = reset_nexus_conf(confirm, conf_path).await?;
+
+            print_conf(&conf)
+        }
+    }
+}
+
+/// Print the configuration as TOML, or as JSON in [`JSON_MODE`].
+fn print_conf<T: Serialize>(conf: &T) -> AnyResult<(), NexusCliError> {
+    json_output(conf)?;
+
+    if !JSON_MODE.load(std::sync::atomic::Ordering::Relaxed) {
+        let conf = toml::to_string_pretty(conf).map_err(|e| {
+            NexusCliError::Any(anyhow!("Failed to serialize configuration to JSON: {}", e))
+        })?;
+
+        println!("{conf}");
     }
+
+    Ok(())
 }
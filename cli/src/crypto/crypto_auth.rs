@@ -1,5 +1,11 @@
 use {
-    crate::{command_title, display::json_output, loading, prelude::*, sui::*},
+    crate::{
+        command_title,
+        display::{json_output, TxProgress},
+        loading,
+        prelude::*,
+        sui::*,
+    },
     nexus_sdk::{
         crypto::{
             session::Session,
@@ -34,15 +40,14 @@ pub(crate) async fn crypto_auth(gas: GasArgs) -> AnyResult<(), NexusCliError> {
     let reference_gas_price = fetch_reference_gas_price(&sui).await?;
 
     // 4. Craft claim transaction
-    let tx_handle = loading!("Crafting transaction...");
+    let progress = TxProgress::new();
     let mut tx_builder = sui::ProgrammableTransactionBuilder::new();
     // Ignore the return value, it's probably empty
     if let Err(e) = claim_pre_key_for_self(&mut tx_builder, objects) {
-        tx_handle.error();
+        progress.error();
         return Err(NexusCliError::Any(e));
     }
     let ptb = tx_builder.finish();
-    tx_handle.success();
 
     let tx_data = sui::TransactionData::new_programmable(
         address,
@@ -52,7 +57,7 @@ pub(crate) async fn crypto_auth(gas: GasArgs) -> AnyResult<(), NexusCliError> {
         reference_gas_price,
     );
 
-    let tx_resp = sign_and_execute_transaction(&sui, &wallet, tx_data).await?;
+    let tx_resp = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
 
     // 5. Locate the newly‑created Prekey object in effects
     let effects = tx_resp
@@ -120,7 +125,9 @@ pub(crate) async fn crypto_auth(gas: GasArgs) -> AnyResult<(), NexusCliError> {
 
     // Store session and save config
     let session_id = *session.id();
-    crypto_secret.sessions.insert(session_id, session);
+    crypto_secret
+        .sessions
+        .insert(session_id, StoredSession::new(session));
 
     let save_handle = loading!("Saving session to configuration...");
 
@@ -140,7 +147,7 @@ pub(crate) async fn crypto_auth(gas: GasArgs) -> AnyResult<(), NexusCliError> {
     let objects = &get_nexus_objects(&mut conf).await?;
 
     // 8. Craft associate transaction
-    let tx_handle = loading!("Crafting transaction...");
+    let progress = TxProgress::new();
     let mut tx_builder = sui::ProgrammableTransactionBuilder::new();
     if let Err(e) = associate_pre_key_with_sender(
         &mut tx_builder,
@@ -148,11 +155,10 @@ pub(crate) async fn crypto_auth(gas: GasArgs) -> AnyResult<(), NexusCliError> {
         &prekey_object_ref,
         initial_message.clone(),
     ) {
-        tx_handle.error();
+        progress.error();
         return Err(NexusCliError::Any(e));
     }
     let ptb = tx_builder.finish();
-    tx_handle.success();
 
     let tx_data = sui::TransactionData::new_programmable(
         address,
@@ -162,7 +168,7 @@ pub(crate) async fn crypto_auth(gas: GasArgs) -> AnyResult<(), NexusCliError> {
         reference_gas_price,
     );
 
-    let associate_tx_resp = sign_and_execute_transaction(&sui, &wallet, tx_data).await?;
+    let associate_tx_resp = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
 
     // Output both transaction digests
     json_output(&json!({
@@ -217,7 +223,7 @@ mod tests {
 
         // Build a CryptoConf with the freshly created session and wrap it in a Secret.
         let mut sessions = HashMap::new();
-        sessions.insert(session_id, session);
+        sessions.insert(session_id, StoredSession::new(session));
         let crypto_conf = CryptoConf {
             identity_key: Some(sender_identity),
             sessions,
@@ -230,6 +236,7 @@ mod tests {
             nexus: None,
             tools: HashMap::new(),
             crypto: Some(secret_crypto),
+            ..Default::default()
         };
 
         cli_conf.save_to_path(&conf_path).await.expect("save conf");
@@ -245,7 +252,7 @@ mod tests {
             .expect("session stored");
 
         // Basic sanity: session IDs match.
-        assert_eq!(saved_session.id(), &session_id);
+        assert_eq!(saved_session.session.id(), &session_id);
 
         // Clean-up env so other tests are unaffected.
         env::remove_var("XDG_CONFIG_HOME");
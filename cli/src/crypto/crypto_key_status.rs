@@ -1,6 +1,7 @@
 use {
     crate::{
         command_title,
+        item,
         loading,
         notify_success,
         prelude::*,
@@ -9,33 +10,77 @@ use {
     keyring::Entry,
 };
 
-/// Show where the key was loaded from.
-pub fn crypto_key_status() -> AnyResult<(), NexusCliError> {
+/// Show where the key was loaded from and the age of the stored sessions.
+pub(crate) async fn crypto_key_status(max_age_days: u32) -> AnyResult<(), NexusCliError> {
     command_title!("Checking master key status");
 
     let check_handle = loading!("Checking key sources...");
 
     let status = if std::env::var("NEXUS_CLI_STORE_PASSPHRASE").is_ok() {
-        "source: ENV var"
+        "source: ENV var".to_string()
     } else if let Ok(_) = Entry::new(SERVICE, "passphrase")
         .map_err(|e| NexusCliError::Any(e.into()))?
         .get_password()
     {
-        "source: key-ring pass-phrase"
+        "source: key-ring pass-phrase".to_string()
     } else if let Ok(hex) = Entry::new(SERVICE, USER)
         .map_err(|e| NexusCliError::Any(e.into()))?
         .get_password()
     {
-        check_handle.success();
-        notify_success!("source: key-ring raw key ({:.8}…)", &hex[..8]);
-        return Ok(());
+        format!("source: key-ring raw key ({:.8}…)", &hex[..8])
     } else {
-        check_handle.success();
-        notify_success!("no persistent master key found");
-        return Ok(());
+        "no persistent master key found".to_string()
     };
 
     check_handle.success();
     notify_success!("{}", status);
+
+    // Sessions can only be read if the master key is available.
+    let conf = CliConf::load().await.unwrap_or_default();
+
+    let Some(crypto_secret) = &conf.crypto else {
+        return Ok(());
+    };
+
+    let max_age = chrono::Duration::days(max_age_days.into());
+    let now = chrono::Utc::now();
+
+    notify_success!("{} stored session(s)", crypto_secret.sessions.len());
+
+    for (session_id, stored) in &crypto_secret.sessions {
+        item!(
+            "{}… created {} ago, last used {} ago{}",
+            hex::encode(&session_id[..4]),
+            format_age(now - stored.created_at),
+            format_age(now - stored.last_used),
+            if stored.is_expired(max_age, now) {
+                " (expired)".red().to_string()
+            } else {
+                String::new()
+            }
+        );
+    }
+
     Ok(())
 }
+
+/// Format an age in its largest whole unit.
+fn format_age(age: chrono::Duration) -> String {
+    match (age.num_days(), age.num_hours(), age.num_minutes()) {
+        (days, ..) if days > 0 => format!("{days}d"),
+        (_, hours, _) if hours > 0 => format!("{hours}h"),
+        (.., minutes) => format!("{}m", minutes.max(0)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(chrono::Duration::seconds(30)), "0m");
+        assert_eq!(format_age(chrono::Duration::minutes(90)), "1h");
+        assert_eq!(format_age(chrono::Duration::days(45)), "45d");
+    }
+}
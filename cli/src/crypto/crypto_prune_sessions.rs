@@ -0,0 +1,51 @@
+use crate::{command_title, display::json_output, loading, notify_success, prelude::*};
+
+/// Remove the sessions that were not used for more than `max_age_days`.
+pub(crate) async fn crypto_prune_sessions(
+    max_age_days: u32,
+    conf_path: PathBuf,
+) -> AnyResult<(), NexusCliError> {
+    command_title!("Pruning sessions unused for more than {max_age_days} days");
+
+    let mut conf = CliConf::load_from_path(&conf_path)
+        .await
+        .unwrap_or_default();
+
+    let Some(crypto_secret) = conf.crypto.as_mut() else {
+        notify_success!("No sessions stored");
+
+        json_output(&json!({ "pruned": [] }))?;
+
+        return Ok(());
+    };
+
+    let pruned = crypto_secret.prune_sessions(chrono::Duration::days(max_age_days.into()));
+    let remaining = crypto_secret.sessions.len();
+
+    if !pruned.is_empty() {
+        let conf_handle = loading!("Saving configuration...");
+
+        match conf.save_to_path(&conf_path).await {
+            Ok(()) => {
+                conf_handle.success();
+            }
+            Err(e) => {
+                conf_handle.error();
+                return Err(NexusCliError::Any(e));
+            }
+        }
+    }
+
+    notify_success!(
+        "Pruned {} expired session(s), {} remaining",
+        pruned.len(),
+        remaining
+    );
+
+    json_output(&json!({
+        "pruned": pruned.iter().map(hex::encode).collect::<Vec<_>>(),
+        "remaining": remaining,
+    }))?;
+
+    Ok(())
+}
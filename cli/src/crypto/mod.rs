@@ -4,6 +4,7 @@ mod crypto_auth;
 mod crypto_generate_id_key;
 mod crypto_init_key;
 mod crypto_key_status;
+mod crypto_prune_sessions;
 mod crypto_set_passphrase;
 
 use {
@@ -11,6 +12,7 @@ use {
     crypto_generate_id_key::crypto_generate_identity_key,
     crypto_init_key::crypto_init_key,
     crypto_key_status::crypto_key_status,
+    crypto_prune_sessions::crypto_prune_sessions,
     crypto_set_passphrase::crypto_set_passphrase,
 };
 
@@ -56,8 +58,28 @@ pub(crate) enum CryptoCommand {
         #[arg(long)]
         force: bool,
     },
-    #[command(about = "Show where the key was loaded from.")]
-    KeyStatus,
+    #[command(about = "Show where the key was loaded from and the age of the stored sessions.")]
+    KeyStatus {
+        /// Number of days after their last use that sessions are reported as
+        /// expired.
+        #[arg(long = "max-age-days", default_value_t = DEFAULT_SESSION_MAX_AGE_DAYS)]
+        max_age_days: u32,
+    },
+    #[command(about = "Remove the sessions that were not used for longer than the max-age.")]
+    PruneSessions {
+        /// Number of days after their last use that sessions expire.
+        #[arg(long = "max-age-days", default_value_t = DEFAULT_SESSION_MAX_AGE_DAYS)]
+        max_age_days: u32,
+        /// Hidden argument used for testing to set the path of the configuration
+        /// file.
+        #[arg(
+            long = "conf-path",
+            hide = true,
+            default_value = CLI_CONF_PATH,
+            value_parser = ValueParser::from(expand_tilde)
+        )]
+        conf_path: PathBuf,
+    },
 }
 
 /// Handle the provided crypto command.
@@ -69,6 +91,10 @@ pub async fn handle(cmd: CryptoCommand) -> AnyResult<(), NexusCliError> {
         }
         CryptoCommand::InitKey { force } => crypto_init_key(force).await,
         CryptoCommand::SetPassphrase { stdin, force } => crypto_set_passphrase(stdin, force).await,
-        CryptoCommand::KeyStatus => crypto_key_status(),
+        CryptoCommand::KeyStatus { max_age_days } => crypto_key_status(max_age_days).await,
+        CryptoCommand::PruneSessions {
+            max_age_days,
+            conf_path,
+        } => crypto_prune_sessions(max_age_days, conf_path).await,
     }
 }
@@ -0,0 +1,159 @@
+use {
+    crate::{
+        command_title,
+        confirm,
+        display::{json_output, TxProgress},
+        loading,
+        notify_success,
+        prelude::*,
+        sui::*,
+    },
+    nexus_sdk::{
+        object_crawler::{fetch_one, Structure},
+        transactions::dag,
+    },
+};
+
+/// States of a DAG execution that can no longer be cancelled.
+const FINAL_EXECUTION_STATES: [&str; 2] = ["Completed", "Cancelled"];
+
+/// The parts of the on-chain `DAGExecution` object we care about.
+#[derive(Clone, Debug, Deserialize)]
+struct DagExecution {
+    state: DagExecutionState,
+}
+
+/// Move enums are represented by the name of their variant.
+#[derive(Clone, Debug, Deserialize)]
+struct DagExecutionState {
+    variant: String,
+}
+
+impl DagExecutionState {
+    fn is_cancellable(&self) -> bool {
+        !FINAL_EXECUTION_STATES.contains(&self.variant.as_str())
+    }
+}
+
+/// Cancel a running Nexus DAG execution based on the provided object ID.
+pub(crate) async fn cancel_dag_execution(
+    dag_execution_id: sui::ObjectID,
+    owner_cap: sui::ObjectID,
+    sui_gas_coin: Option<sui::ObjectID>,
+    sui_gas_budget: u64,
+    skip_confirmation: bool,
+) -> AnyResult<(), NexusCliError> {
+    command_title!("Cancelling Nexus DAG Execution '{dag_execution_id}'");
+
+    // Load CLI configuration.
+    let mut conf = CliConf::load().await.unwrap_or_default();
+
+    // Nexus objects must be present in the configuration.
+    let objects = &get_nexus_objects(&mut conf).await?;
+
+    // Create wallet context, Sui client and find the active address.
+    let mut wallet = create_wallet_context(&conf.sui.wallet_path, conf.sui.net).await?;
+    let sui = build_sui_client(&conf.sui).await?;
+    let address = wallet.active_address().map_err(NexusCliError::Any)?;
+
+    // Fetch the DAGExecution object and check its state.
+    let execution_handle = loading!("Fetching DAGExecution object '{dag_execution_id}'...");
+
+    let execution = match fetch_one::<Structure<DagExecution>>(&sui, dag_execution_id).await {
+        Ok(execution) => execution,
+        Err(e) => {
+            execution_handle.error();
+
+            return Err(NexusCliError::Any(e));
+        }
+    };
+
+    execution_handle.success();
+
+    let state = &execution.data.inner().state;
+
+    if !state.is_cancellable() {
+        if !JSON_MODE.load(Ordering::Relaxed) {
+            println!(
+                "{warning} {message}",
+                warning = "⚠".bold().yellow(),
+                message = format!(
+                    "DAG execution is already '{}' and cannot be cancelled.",
+                    state.variant
+                )
+                .bold()
+            );
+        }
+
+        json_output(&json!({ "cancelled": false, "state": state.variant }))?;
+
+        return Ok(());
+    }
+
+    if !skip_confirmation {
+        confirm!(
+            "Cancelling the DAG execution will stop all of its pending walks. Do you want to proceed?"
+        );
+    }
+
+    // Fetch gas coin object.
+    let gas_coin = fetch_gas_coin(&sui, address, sui_gas_coin).await?;
+
+    // Fetch reference gas price.
+    let reference_gas_price = fetch_reference_gas_price(&sui).await?;
+
+    // Fetch the owner cap object.
+    let owner_cap = fetch_object_by_id(&sui, owner_cap).await?;
+
+    // Craft a TX to cancel the execution.
+    let progress = TxProgress::new();
+
+    let mut tx = sui::ProgrammableTransactionBuilder::new();
+
+    if let Err(e) = dag::cancel_execution(&mut tx, objects, &execution.object_ref(), &owner_cap) {
+        progress.error();
+
+        return Err(NexusCliError::Any(e));
+    }
+
+    let tx_data = sui::TransactionData::new_programmable(
+        address,
+        vec![gas_coin.object_ref()],
+        tx.finish(),
+        sui_gas_budget,
+        reference_gas_price,
+    );
+
+    // Sign and submit the TX.
+    let response = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
+
+    notify_success!(
+        "DAG execution {id} cancelled",
+        id = dag_execution_id.to_string().truecolor(100, 100, 100)
+    );
+
+    json_output(&json!({ "cancelled": true, "digest": response.digest }))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dag_execution_state_is_cancellable() {
+        let state = |variant: &str| {
+            serde_json::from_value::<DagExecutionState>(json!({
+                "type": "0x1::dag::DAGExecutionState",
+                "variant": variant,
+                "fields": {}
+            }))
+            .unwrap()
+        };
+
+        assert!(state("Running").is_cancellable());
+        assert!(!state("Completed").is_cancellable());
+        assert!(!state("Cancelled").is_cancellable());
+    }
+}
@@ -1,8 +1,10 @@
+#[cfg(feature = "watch")]
+use crate::dag::dag_watch_execution::{can_watch_dag_execution, watch_dag_execution};
 use {
     crate::{
         command_title,
-        dag::dag_inspect_execution::inspect_dag_execution,
-        display::json_output,
+        dag::dag_inspect_execution::{inspect_dag_execution, InspectOutputFormat},
+        display::{json_output, TxProgress},
         loading,
         notify_success,
         prelude::*,
@@ -25,9 +27,15 @@ pub(crate) async fn execute_dag(
     entry_group: String,
     mut input_json: serde_json::Value,
     inspect: bool,
+    output_format: InspectOutputFormat,
     sui_gas_coin: Option<sui::ObjectID>,
     sui_gas_budget: u64,
 ) -> AnyResult<(), NexusCliError> {
+    // Stdout is reserved for the event lines of the inspection.
+    if output_format == InspectOutputFormat::Ndjson {
+        JSON_MODE.store(true, Ordering::Relaxed);
+    }
+
     command_title!("Executing Nexus DAG '{dag_id}'");
 
     // Load CLI configuration.
@@ -61,18 +69,16 @@ pub(crate) async fn execute_dag(
     let dag = fetch_object_by_id(&sui, dag_id).await?;
 
     // Craft a TX to publish the DAG.
-    let tx_handle = loading!("Crafting transaction...");
+    let progress = TxProgress::new();
 
     let mut tx = sui::ProgrammableTransactionBuilder::new();
 
     if let Err(e) = dag::execute(&mut tx, objects, &dag, &entry_group, input_json, &encrypt) {
-        tx_handle.error();
+        progress.error();
 
         return Err(NexusCliError::Any(e));
     }
 
-    tx_handle.success();
-
     let tx_data = sui::TransactionData::new_programmable(
         address,
         vec![gas_coin.object_ref()],
@@ -82,7 +88,7 @@ pub(crate) async fn execute_dag(
     );
 
     // Sign and send the TX.
-    let response = sign_and_execute_transaction(&sui, &wallet, tx_data).await?;
+    let response = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
 
     // We need to parse the DAGExecution object ID from the response.
     let dag = response
@@ -115,7 +121,15 @@ pub(crate) async fn execute_dag(
     );
 
     if inspect {
-        inspect_dag_execution(object_id, response.digest).await?;
+        // Render a live table of the execution if the terminal supports it.
+        #[cfg(feature = "watch")]
+        if can_watch_dag_execution() {
+            conf.save().await.map_err(NexusCliError::Any)?;
+
+            return watch_dag_execution(object_id, response.digest).await;
+        }
+
+        inspect_dag_execution(object_id, response.digest, output_format).await?;
     } else {
         json_output(&json!({ "digest": response.digest, "execution_id": object_id }))?;
     }
@@ -166,10 +180,11 @@ fn get_active_session(
                 )));
             }
 
-            let session_id = *crypto_secret.sessions.values().next().unwrap().id();
+            let session_id = *crypto_secret.sessions.values().next().unwrap().session.id();
             crypto_secret
                 .sessions
                 .get_mut(&session_id)
+                .map(StoredSession::touch)
                 .ok_or_else(|| NexusCliError::Any(anyhow!("Session not found in config")))
         }
         None => Err(NexusCliError::Any(anyhow!(
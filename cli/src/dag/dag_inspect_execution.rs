@@ -9,19 +9,84 @@ use {
         sui::*,
     },
     nexus_sdk::{
-        events::{NexusEvent, NexusEventKind},
+        events::{
+            EndStateReachedEvent,
+            ExecutionFinishedEvent,
+            GasSettlementUpdateEvent,
+            NexusEvent,
+            NexusEventKind,
+            RequestWalkExecutionEvent,
+            WalkAdvancedEvent,
+            WalkFailedEvent,
+        },
         idents::primitives,
-        types::{NexusData, TypeName},
+        types::{NexusData, RuntimeVertex, TypeName, VertexStatus},
     },
-    std::collections::HashMap,
+    std::{collections::HashMap, io::Write, time::Duration},
 };
 
+/// Where to store the last processed event of each inspected execution.
+const INSPECT_CHECKPOINTS_DIR: &str = "~/.nexus/inspect";
+
+/// How to output the events of an inspected execution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub(crate) enum InspectOutputFormat {
+    /// Human readable trace, or a JSON trace once the execution finishes if
+    /// `--json` is passed.
+    #[default]
+    Human,
+    /// One JSON object per execution event written to stdout as it happens.
+    Ndjson,
+}
+
+/// One line of the [`InspectOutputFormat::Ndjson`] output.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum NdjsonEvent<'a> {
+    VertexStarted(&'a RequestWalkExecutionEvent),
+    ToolInvoked(&'a GasSettlementUpdateEvent),
+    OutputProduced {
+        #[serde(flatten)]
+        event: &'a WalkAdvancedEvent,
+        data: &'a [serde_json::Value],
+    },
+    EndStateReached {
+        #[serde(flatten)]
+        event: &'a EndStateReachedEvent,
+        data: &'a [serde_json::Value],
+    },
+    Errored(&'a WalkFailedEvent),
+    ExecutionFinished(&'a ExecutionFinishedEvent),
+}
+
+impl NdjsonEvent<'_> {
+    /// Write the event as a single line and flush it right away so that
+    /// consumers see it as it happens.
+    fn write_line(&self, out: &mut dyn Write) -> AnyResult<()> {
+        serde_json::to_writer(&mut *out, self)?;
+
+        out.write_all(b"\n")?;
+        out.flush()?;
+
+        Ok(())
+    }
+}
+
 /// Inspect a Nexus DAG execution process based on the provided object ID and
 /// execution digest.
+///
+/// If a previous inspection of the same execution was interrupted, the
+/// inspection resumes after the last processed event.
 pub(crate) async fn inspect_dag_execution(
     dag_execution_id: sui::ObjectID,
     execution_digest: sui::TransactionDigest,
+    output_format: InspectOutputFormat,
 ) -> AnyResult<(), NexusCliError> {
+    // Stdout is reserved for the event lines.
+    if output_format == InspectOutputFormat::Ndjson {
+        JSON_MODE.store(true, Ordering::Relaxed);
+    }
+
     command_title!("Inspecting Nexus DAG Execution '{dag_execution_id}'");
 
     // Load CLI configuration.
@@ -42,53 +107,109 @@ pub(crate) async fn inspect_dag_execution(
     // Check if we have authentication for potential decryption and get the session
     let session = get_active_session(&mut conf)?;
 
-    let limit = None;
-    let descending_order = false;
+    let checkpoint = Checkpoint {
+        path: expand_tilde(INSPECT_CHECKPOINTS_DIR)
+            .map_err(NexusCliError::Any)?
+            .join(format!("{dag_execution_id}.json")),
+    };
 
-    // Starting cursor is the provided event digest and `event_seq` always 0.
-    let mut cursor = Some(sui::EventID {
-        tx_digest: execution_digest,
-        event_seq: 0,
-    });
+    // Starting cursor is the provided event digest and `event_seq` always 0
+    // unless a previous inspection was interrupted.
+    let cursor = match checkpoint.load().await {
+        Some(cursor) => {
+            notify_success!(
+                "Resuming inspection after event '{digest}'",
+                digest = cursor.tx_digest.to_string().truecolor(100, 100, 100)
+            );
 
-    let mut json_trace = Vec::new();
+            cursor
+        }
+        None => sui::EventID {
+            tx_digest: execution_digest,
+            event_seq: 0,
+        },
+    };
 
-    // Loop until we find an `ExecutionFinished` event.
-    'query: loop {
-        let query = sui::EventFilter::MoveEventModule {
+    let source = SuiEventSource {
+        sui: &sui,
+        query: sui::EventFilter::MoveEventModule {
             package: primitives_pkg_id,
             module: primitives::Event::EVENT_WRAPPER.module.into(),
-        };
+        },
+    };
 
-        let events = match sui
-            .event_api()
-            .query_events(query, cursor, limit, descending_order)
-            .await
-        {
-            Ok(page) => {
-                cursor = page.next_cursor;
+    let mut stdout = std::io::stdout();
+    let ndjson = match output_format {
+        InspectOutputFormat::Human => None,
+        InspectOutputFormat::Ndjson => Some(&mut stdout as &mut dyn Write),
+    };
 
-                page.data
-            }
-            Err(_) => {
-                // If RPC call fails, wait and retry.
-                tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    let result = inspect_events(
+        &source,
+        dag_execution_id,
+        cursor,
+        session,
+        &checkpoint,
+        &Backoff::DEFAULT,
+        ndjson,
+    )
+    .await;
 
-                continue;
-            }
-        };
+    // Always save the updated config
+    conf.save().await.map_err(NexusCliError::Any)?;
 
-        // Parse `SuiEvent` into `NexusEvent`.
-        let events = events.into_iter().filter_map(|e| match e.try_into() {
-            Ok(event) => Some::<NexusEvent>(event),
-            Err(e) => {
-                eprintln!("Failed to parse event: {:?}", e);
-                None
-            }
-        });
+    let json_trace = result.map_err(|e| {
+        NexusCliError::Any(anyhow!(
+            "{e}\nResume the inspection with `nexus dag inspect-execution --dag-execution-id {dag_execution_id} --execution-digest {execution_digest}`"
+        ))
+    })?;
+
+    // Events were already streamed.
+    if output_format == InspectOutputFormat::Human {
+        json_output(&json_trace)?;
+    }
+
+    Ok(())
+}
+
+/// Process events of the given execution starting at `cursor` until an
+/// `ExecutionFinished` event is found. The cursor is checkpointed after each
+/// page of events.
+///
+/// If `ndjson` is provided, each event of the execution is also written to it
+/// as a [`NdjsonEvent`] line.
+async fn inspect_events<S: EventSource>(
+    source: &S,
+    dag_execution_id: sui::ObjectID,
+    mut cursor: sui::EventID,
+    session: &mut Session,
+    checkpoint: &Checkpoint,
+    backoff: &Backoff,
+    mut ndjson: Option<&mut dyn Write>,
+) -> AnyResult<Vec<serde_json::Value>> {
+    let mut json_trace = Vec::new();
+    let mut vertex_statuses = VertexStatuses::default();
+
+    // Loop until we find an `ExecutionFinished` event.
+    'query: loop {
+        let page = query_events_with_retry(source, cursor, backoff).await?;
+
+        for event in page.events {
+            vertex_statuses.record(dag_execution_id, &event.data);
 
-        for event in events {
             match event.data {
+                NexusEventKind::RequestWalkExecution(e) if e.execution == dag_execution_id => {
+                    if let Some(out) = ndjson.as_deref_mut() {
+                        NdjsonEvent::VertexStarted(&e).write_line(out)?;
+                    }
+                }
+
+                NexusEventKind::GasSettlementUpdate(e) if e.execution == dag_execution_id => {
+                    if let Some(out) = ndjson.as_deref_mut() {
+                        NdjsonEvent::ToolInvoked(&e).write_line(out)?;
+                    }
+                }
+
                 NexusEventKind::WalkAdvanced(e) if e.execution == dag_execution_id => {
                     notify_success!(
                         "Vertex '{vertex}' evaluated with output variant '{variant}'.",
@@ -105,6 +226,14 @@ pub(crate) async fn inspect_dag_execution(
                                 format!("{:?}", e.variant_ports_to_data).truecolor(100, 100, 100),
                         );
 
+                        if let Some(out) = ndjson.as_deref_mut() {
+                            NdjsonEvent::OutputProduced {
+                                event: &e,
+                                data: &[],
+                            }
+                            .write_line(out)?;
+                        }
+
                         continue;
                     };
 
@@ -112,10 +241,7 @@ pub(crate) async fn inspect_dag_execution(
 
                     for (port, data) in variant_ports_to_data.values {
                         let (display_data, json_data_value) =
-                            match process_port_data(&port, &data, session) {
-                                Ok(result) => result,
-                                Err(e) => return Err(NexusCliError::Any(anyhow!("{}", e))),
-                            };
+                            process_port_data(&port, &data, session).map_err(|e| anyhow!("{e}"))?;
 
                         item!(
                             "Port '{port}' produced data: {data}",
@@ -126,9 +252,18 @@ pub(crate) async fn inspect_dag_execution(
                         json_data.push(json_data_value);
                     }
 
+                    if let Some(out) = ndjson.as_deref_mut() {
+                        NdjsonEvent::OutputProduced {
+                            event: &e,
+                            data: &json_data,
+                        }
+                        .write_line(out)?;
+                    }
+
                     json_trace.push(json!({
                         "end_state": false,
                         "vertex": e.vertex,
+                        "status": VertexStatus::Succeeded,
                         "variant": e.variant.name,
                         "data": json_data,
                     }));
@@ -151,6 +286,14 @@ pub(crate) async fn inspect_dag_execution(
                                 format!("{:?}", e.variant_ports_to_data).truecolor(100, 100, 100),
                         );
 
+                        if let Some(out) = ndjson.as_deref_mut() {
+                            NdjsonEvent::EndStateReached {
+                                event: &e,
+                                data: &[],
+                            }
+                            .write_line(out)?;
+                        }
+
                         continue;
                     };
 
@@ -158,10 +301,7 @@ pub(crate) async fn inspect_dag_execution(
 
                     for (port, data) in variant_ports_to_data.values {
                         let (display_data, json_data_value) =
-                            match process_port_data(&port, &data, session) {
-                                Ok(result) => result,
-                                Err(e) => return Err(NexusCliError::Any(anyhow!("{}", e))),
-                            };
+                            process_port_data(&port, &data, session).map_err(|e| anyhow!("{e}"))?;
 
                         item!(
                             "Port '{port}' produced data: {data}",
@@ -172,22 +312,55 @@ pub(crate) async fn inspect_dag_execution(
                         json_data.push(json_data_value);
                     }
 
+                    if let Some(out) = ndjson.as_deref_mut() {
+                        NdjsonEvent::EndStateReached {
+                            event: &e,
+                            data: &json_data,
+                        }
+                        .write_line(out)?;
+                    }
+
                     json_trace.push(json!({
                         "end_state": true,
                         "vertex": e.vertex,
+                        "status": VertexStatus::Succeeded,
                         "variant": e.variant.name,
                         "data": json_data,
                     }));
                 }
 
+                NexusEventKind::WalkFailed(e) if e.execution == dag_execution_id => {
+                    notify_error!(
+                        "Vertex '{vertex}' {status}",
+                        vertex = format!("{}", e.vertex).truecolor(100, 100, 100),
+                        status = VertexStatus::Failed {
+                            reason: e.reason.clone()
+                        },
+                    );
+
+                    if let Some(out) = ndjson.as_deref_mut() {
+                        NdjsonEvent::Errored(&e).write_line(out)?;
+                    }
+                }
+
                 NexusEventKind::ExecutionFinished(e) if e.execution == dag_execution_id => {
-                    if e.has_any_walk_failed {
-                        notify_error!("DAG execution finished unsuccessfully");
+                    if let Some(out) = ndjson.as_deref_mut() {
+                        NdjsonEvent::ExecutionFinished(&e).write_line(out)?;
+                    }
 
-                        break 'query;
+                    for (vertex, status) in vertex_statuses.statuses(dag_execution_id) {
+                        item!(
+                            "Vertex '{vertex}': {status}",
+                            vertex = format!("{vertex}").truecolor(100, 100, 100),
+                            status = colorize_status(&status),
+                        );
                     }
 
-                    notify_success!("DAG execution finished successfully");
+                    if e.has_any_walk_failed {
+                        notify_error!("DAG execution finished unsuccessfully");
+                    } else {
+                        notify_success!("DAG execution finished successfully");
+                    }
 
                     break 'query;
                 }
@@ -195,14 +368,194 @@ pub(crate) async fn inspect_dag_execution(
                 _ => {}
             }
         }
+
+        // Keep the cursor if there are no new events.
+        if let Some(next_cursor) = page.next_cursor {
+            cursor = next_cursor;
+
+            // Failing to checkpoint only means that a resume starts earlier.
+            let _ = checkpoint.save(&cursor).await;
+        }
     }
 
-    // Always save the updated config
-    conf.save().await.map_err(NexusCliError::Any)?;
+    // The execution is finished so there is nothing to resume.
+    checkpoint.remove().await;
 
-    json_output(&json_trace)?;
+    Ok(json_trace)
+}
 
-    Ok(())
+/// Events of an inspected execution along with the vertices they concern in the
+/// order they were first seen, so that [RuntimeVertex::status] can be derived
+/// for each vertex.
+#[derive(Default)]
+struct VertexStatuses {
+    vertices: Vec<RuntimeVertex>,
+    events: Vec<NexusEventKind>,
+}
+
+impl VertexStatuses {
+    /// Remember the event if it belongs to the execution.
+    fn record(&mut self, execution: sui::ObjectID, event: &NexusEventKind) {
+        let (event_execution, vertex) = match event {
+            NexusEventKind::RequestWalkExecution(e) => (e.execution, Some(&e.next_vertex)),
+            NexusEventKind::GasSettlementUpdate(e) => (e.execution, Some(&e.vertex)),
+            NexusEventKind::WalkAdvanced(e) => (e.execution, Some(&e.vertex)),
+            NexusEventKind::EndStateReached(e) => (e.execution, Some(&e.vertex)),
+            NexusEventKind::WalkFailed(e) => (e.execution, Some(&e.vertex)),
+            NexusEventKind::ExecutionFinished(e) => (e.execution, None),
+            _ => return,
+        };
+
+        if event_execution != execution {
+            return;
+        }
+
+        if let Some(vertex) = vertex.filter(|vertex| !self.vertices.contains(vertex)) {
+            self.vertices.push(vertex.clone());
+        }
+
+        self.events.push(event.clone());
+    }
+
+    fn statuses(&self, execution: sui::ObjectID) -> Vec<(RuntimeVertex, VertexStatus)> {
+        self.vertices
+            .iter()
+            .map(|vertex| (vertex.clone(), vertex.status(execution, &self.events)))
+            .collect()
+    }
+}
+
+fn colorize_status(status: &VertexStatus) -> colored::ColoredString {
+    let text = status.to_string();
+
+    match status {
+        VertexStatus::Succeeded => text.green(),
+        VertexStatus::Failed { .. } => text.red(),
+        VertexStatus::Pending | VertexStatus::Running => text.yellow(),
+        VertexStatus::Skipped | VertexStatus::Unknown { .. } => text.truecolor(100, 100, 100),
+    }
+}
+
+/// One page of Nexus events and the cursor to fetch the next page with.
+struct EventPage {
+    events: Vec<NexusEvent>,
+    next_cursor: Option<sui::EventID>,
+}
+
+/// Source of Nexus events, abstracted so that the inspection can be tested
+/// without a Sui node.
+trait EventSource {
+    async fn query_events(&self, cursor: sui::EventID) -> AnyResult<EventPage>;
+}
+
+/// Queries events matching `query` from the Sui RPC.
+struct SuiEventSource<'a> {
+    sui: &'a sui::Client,
+    query: sui::EventFilter,
+}
+
+impl EventSource for SuiEventSource<'_> {
+    async fn query_events(&self, cursor: sui::EventID) -> AnyResult<EventPage> {
+        let limit = None;
+        let descending_order = false;
+
+        let page = self
+            .sui
+            .event_api()
+            .query_events(self.query.clone(), Some(cursor), limit, descending_order)
+            .await?;
+
+        // Parse `SuiEvent` into `NexusEvent`.
+        let events = page
+            .data
+            .into_iter()
+            .filter_map(|e| match e.try_into() {
+                Ok(event) => Some::<NexusEvent>(event),
+                Err(e) => {
+                    eprintln!("Failed to parse event: {:?}", e);
+                    None
+                }
+            })
+            .collect();
+
+        Ok(EventPage {
+            events,
+            next_cursor: page.next_cursor,
+        })
+    }
+}
+
+/// Exponential backoff for transient RPC errors.
+#[derive(Clone, Copy, Debug)]
+struct Backoff {
+    initial_delay: Duration,
+    max_delay: Duration,
+    max_retries: u32,
+}
+
+impl Backoff {
+    const DEFAULT: Self = Self {
+        initial_delay: Duration::from_secs(1),
+        max_delay: Duration::from_secs(30),
+        max_retries: 8,
+    };
+
+    /// Delay before the given retry, starting at 0.
+    fn delay(&self, retry: u32) -> Duration {
+        self.initial_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay)
+    }
+}
+
+/// Query a page of events, retrying with backoff if the query fails.
+async fn query_events_with_retry<S: EventSource>(
+    source: &S,
+    cursor: sui::EventID,
+    backoff: &Backoff,
+) -> AnyResult<EventPage> {
+    let mut retry = 0;
+
+    loop {
+        match source.query_events(cursor).await {
+            Ok(page) => return Ok(page),
+            Err(e) if retry >= backoff.max_retries => {
+                bail!("Failed to fetch events after {retry} retries: {e}")
+            }
+            Err(_) => {
+                tokio::time::sleep(backoff.delay(retry)).await;
+
+                retry += 1;
+            }
+        }
+    }
+}
+
+/// Last processed event of an execution persisted to a file.
+struct Checkpoint {
+    path: PathBuf,
+}
+
+impl Checkpoint {
+    async fn load(&self) -> Option<sui::EventID> {
+        let content = tokio::fs::read_to_string(&self.path).await.ok()?;
+
+        serde_json::from_str(&content).ok()
+    }
+
+    async fn save(&self, cursor: &sui::EventID) -> AnyResult<()> {
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        tokio::fs::write(&self.path, serde_json::to_string(cursor)?).await?;
+
+        Ok(())
+    }
+
+    async fn remove(&self) {
+        let _ = tokio::fs::remove_file(&self.path).await;
+    }
 }
 
 /// Process port data, handling decryption if needed
@@ -254,10 +607,11 @@ fn get_active_session(
                 )));
             }
 
-            let session_id = *crypto_secret.sessions.values().next().unwrap().id();
+            let session_id = *crypto_secret.sessions.values().next().unwrap().session.id();
             crypto_secret
                 .sessions
                 .get_mut(&session_id)
+                .map(StoredSession::touch)
                 .ok_or_else(|| NexusCliError::Any(anyhow!("Session not found in config")))
         }
         None => Err(NexusCliError::Any(anyhow!(
@@ -347,6 +701,346 @@ mod tests {
         (sender_sess, receiver_sess)
     }
 
+    /// Event source that replays a scripted sequence of responses and records
+    /// the cursors it was queried with.
+    struct MockEventSource {
+        responses: std::sync::Mutex<std::collections::VecDeque<AnyResult<EventPage>>>,
+        cursors: std::sync::Mutex<Vec<sui::EventID>>,
+    }
+
+    impl MockEventSource {
+        fn new(responses: Vec<AnyResult<EventPage>>) -> Self {
+            Self {
+                responses: std::sync::Mutex::new(responses.into()),
+                cursors: std::sync::Mutex::new(vec![]),
+            }
+        }
+    }
+
+    impl EventSource for MockEventSource {
+        async fn query_events(&self, cursor: sui::EventID) -> AnyResult<EventPage> {
+            self.cursors.lock().unwrap().push(cursor);
+
+            self.responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or_else(|| Err(anyhow!("connection refused")))
+        }
+    }
+
+    fn event_id(event_seq: u64) -> sui::EventID {
+        sui::EventID {
+            tx_digest: sui::TransactionDigest::random(),
+            event_seq,
+        }
+    }
+
+    fn walk_advanced(execution: sui::ObjectID, vertex: &str) -> NexusEvent {
+        NexusEvent {
+            id: event_id(0),
+            generics: vec![],
+            data: NexusEventKind::WalkAdvanced(nexus_sdk::events::WalkAdvancedEvent {
+                dag: sui::ObjectID::random(),
+                execution,
+                walk_index: 0,
+                vertex: nexus_sdk::types::RuntimeVertex::plain(vertex),
+                variant: TypeName {
+                    name: "ok".to_string(),
+                },
+                variant_ports_to_data: json!({ "contents": [] }),
+            }),
+        }
+    }
+
+    fn execution_finished(execution: sui::ObjectID) -> NexusEvent {
+        NexusEvent {
+            id: event_id(0),
+            generics: vec![],
+            data: NexusEventKind::ExecutionFinished(nexus_sdk::events::ExecutionFinishedEvent {
+                dag: sui::ObjectID::random(),
+                execution,
+                has_any_walk_failed: false,
+                has_any_walk_succeeded: true,
+            }),
+        }
+    }
+
+    const TEST_BACKOFF: Backoff = Backoff {
+        initial_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(5),
+        max_retries: 3,
+    };
+
+    #[test]
+    fn test_vertex_statuses() {
+        let execution = sui::ObjectID::random();
+        let mut statuses = VertexStatuses::default();
+
+        let failed = NexusEventKind::WalkFailed(WalkFailedEvent {
+            dag: sui::ObjectID::random(),
+            execution,
+            walk_index: 1,
+            vertex: RuntimeVertex::plain("b"),
+            reason: "tool unreachable".to_string(),
+        });
+
+        for event in [
+            walk_advanced(execution, "a"),
+            walk_advanced(sui::ObjectID::random(), "other"),
+        ] {
+            statuses.record(execution, &event.data);
+        }
+
+        statuses.record(execution, &failed);
+        statuses.record(execution, &execution_finished(execution).data);
+
+        assert_eq!(
+            statuses.statuses(execution),
+            vec![
+                (RuntimeVertex::plain("a"), VertexStatus::Succeeded),
+                (
+                    RuntimeVertex::plain("b"),
+                    VertexStatus::Failed {
+                        reason: "tool unreachable".to_string()
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_backoff_delay() {
+        assert_eq!(Backoff::DEFAULT.delay(0), Duration::from_secs(1));
+        assert_eq!(Backoff::DEFAULT.delay(3), Duration::from_secs(8));
+        assert_eq!(Backoff::DEFAULT.delay(10), Duration::from_secs(30));
+        assert_eq!(Backoff::DEFAULT.delay(u32::MAX), Duration::from_secs(30));
+    }
+
+    #[tokio::test]
+    async fn test_inspect_events_retries_dropped_connection() {
+        let (_, mut session) = create_test_sessions();
+        let execution = sui::ObjectID::random();
+        let tempdir = tempfile::tempdir().unwrap();
+        let checkpoint = Checkpoint {
+            path: tempdir.path().join("checkpoint.json"),
+        };
+        let (start, first, second) = (event_id(0), event_id(1), event_id(2));
+
+        let source = MockEventSource::new(vec![
+            Ok(EventPage {
+                events: vec![walk_advanced(execution, "a")],
+                next_cursor: Some(first),
+            }),
+            // Connection drops mid-poll and the page is fetched again.
+            Err(anyhow!("connection reset by peer")),
+            Err(anyhow!("connection reset by peer")),
+            Ok(EventPage {
+                events: vec![walk_advanced(sui::ObjectID::random(), "other")],
+                next_cursor: None,
+            }),
+            Ok(EventPage {
+                events: vec![walk_advanced(execution, "b"), execution_finished(execution)],
+                next_cursor: Some(second),
+            }),
+        ]);
+
+        let trace = inspect_events(
+            &source,
+            execution,
+            start,
+            &mut session,
+            &checkpoint,
+            &TEST_BACKOFF,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(trace.len(), 2);
+        assert_eq!(
+            trace[0]["vertex"],
+            json!(nexus_sdk::types::RuntimeVertex::plain("a"))
+        );
+        assert_eq!(
+            trace[1]["vertex"],
+            json!(nexus_sdk::types::RuntimeVertex::plain("b"))
+        );
+        assert_eq!(trace[1]["status"], json!(VertexStatus::Succeeded));
+
+        // Retries continue from the last cursor instead of restarting.
+        assert_eq!(
+            *source.cursors.lock().unwrap(),
+            vec![start, first, first, first, first]
+        );
+
+        // Nothing is left to resume once the execution is finished.
+        assert!(checkpoint.load().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_inspect_events_resumes_from_checkpoint() {
+        let (_, mut session) = create_test_sessions();
+        let execution = sui::ObjectID::random();
+        let tempdir = tempfile::tempdir().unwrap();
+        let checkpoint = Checkpoint {
+            path: tempdir.path().join("checkpoint.json"),
+        };
+        let (start, first) = (event_id(0), event_id(1));
+
+        // All retries fail after the first page.
+        let source = MockEventSource::new(vec![Ok(EventPage {
+            events: vec![walk_advanced(execution, "a")],
+            next_cursor: Some(first),
+        })]);
+
+        let result = inspect_events(
+            &source,
+            execution,
+            start,
+            &mut session,
+            &checkpoint,
+            &TEST_BACKOFF,
+            None,
+        )
+        .await;
+
+        assert!(
+            matches!(result, Err(e) if e.to_string().starts_with("Failed to fetch events after 3 retries"))
+        );
+        assert_eq!(source.cursors.lock().unwrap().len(), 5);
+
+        // The last processed event is checkpointed...
+        let resume_cursor = checkpoint.load().await.unwrap();
+
+        assert_eq!(resume_cursor, first);
+
+        // ... and the inspection continues from it.
+        let source = MockEventSource::new(vec![Ok(EventPage {
+            events: vec![execution_finished(execution)],
+            next_cursor: None,
+        })]);
+
+        let trace = inspect_events(
+            &source,
+            execution,
+            resume_cursor,
+            &mut session,
+            &checkpoint,
+            &TEST_BACKOFF,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert!(trace.is_empty());
+        assert_eq!(*source.cursors.lock().unwrap(), vec![first]);
+        assert!(checkpoint.load().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_inspect_events_streams_ndjson() {
+        let (_, mut session) = create_test_sessions();
+        let execution = sui::ObjectID::random();
+        let tempdir = tempfile::tempdir().unwrap();
+        let checkpoint = Checkpoint {
+            path: tempdir.path().join("checkpoint.json"),
+        };
+        let dag = sui::ObjectID::random();
+        let vertex = nexus_sdk::types::RuntimeVertex::plain("a");
+
+        let event = |data| NexusEvent {
+            id: event_id(0),
+            generics: vec![],
+            data,
+        };
+
+        let source = MockEventSource::new(vec![Ok(EventPage {
+            events: vec![
+                event(NexusEventKind::RequestWalkExecution(
+                    nexus_sdk::events::RequestWalkExecutionEvent {
+                        dag,
+                        execution,
+                        walk_index: 0,
+                        next_vertex: vertex.clone(),
+                        evaluations: sui::ObjectID::random(),
+                        worksheet_from_type: TypeName {
+                            name: "worksheet".to_string(),
+                        },
+                    },
+                )),
+                event(NexusEventKind::GasSettlementUpdate(
+                    nexus_sdk::events::GasSettlementUpdateEvent {
+                        execution,
+                        tool_fqn: fqn!("xyz.dummy.tool@1"),
+                        vertex: vertex.clone(),
+                        was_settled: true,
+                    },
+                )),
+                // Events of other executions are not streamed.
+                walk_advanced(sui::ObjectID::random(), "other"),
+                walk_advanced(execution, "a"),
+                event(NexusEventKind::WalkFailed(
+                    nexus_sdk::events::WalkFailedEvent {
+                        dag,
+                        execution,
+                        walk_index: 1,
+                        vertex: vertex.clone(),
+                        reason: "tool unreachable".to_string(),
+                    },
+                )),
+                execution_finished(execution),
+            ],
+            next_cursor: None,
+        })]);
+
+        let mut stdout = Vec::new();
+
+        inspect_events(
+            &source,
+            execution,
+            event_id(0),
+            &mut session,
+            &checkpoint,
+            &TEST_BACKOFF,
+            Some(&mut stdout),
+        )
+        .await
+        .unwrap();
+
+        let stdout = String::from_utf8(stdout).unwrap();
+
+        assert!(stdout.ends_with('\n'));
+
+        // Each line is a standalone JSON object.
+        let lines = stdout
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(lines.iter().all(|line| line.is_object()));
+        assert_eq!(
+            lines
+                .iter()
+                .map(|line| line["type"].as_str().unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                "vertex_started",
+                "tool_invoked",
+                "output_produced",
+                "errored",
+                "execution_finished"
+            ]
+        );
+        assert_eq!(lines[0]["next_vertex"], json!(vertex));
+        assert_eq!(lines[1]["tool_fqn"], "xyz.dummy.tool@1");
+        assert_eq!(lines[2]["vertex"], json!(vertex));
+        assert_eq!(lines[2]["variant"]["name"], "ok");
+        assert_eq!(lines[2]["data"], json!([]));
+        assert_eq!(lines[3]["reason"], "tool unreachable");
+        assert_eq!(lines[4]["execution"], json!(execution));
+    }
+
     #[test]
     fn test_process_port_data_plain_data() {
         let (mut _sender, mut receiver) = create_test_sessions();
@@ -2,8 +2,7 @@ use {
     crate::{
         command_title,
         dag::dag_validate::validate_dag,
-        display::json_output,
-        loading,
+        display::{json_output, TxProgress},
         notify_success,
         prelude::*,
         sui::*,
@@ -40,7 +39,7 @@ pub(crate) async fn publish_dag(
     let reference_gas_price = fetch_reference_gas_price(&sui).await?;
 
     // Craft a TX to publish the DAG.
-    let tx_handle = loading!("Crafting transaction...");
+    let progress = TxProgress::new();
 
     let mut tx = sui::ProgrammableTransactionBuilder::new();
 
@@ -51,7 +50,7 @@ pub(crate) async fn publish_dag(
     dag_arg = match dag::create(&mut tx, objects, dag_arg, dag) {
         Ok(dag_arg) => dag_arg,
         Err(e) => {
-            tx_handle.error();
+            progress.error();
 
             return Err(NexusCliError::Any(e));
         }
@@ -60,8 +59,6 @@ pub(crate) async fn publish_dag(
     // Public share the DAG, locking it.
     dag::publish(&mut tx, objects, dag_arg);
 
-    tx_handle.success();
-
     let tx_data = sui::TransactionData::new_programmable(
         address,
         vec![gas_coin.object_ref()],
@@ -71,7 +68,7 @@ pub(crate) async fn publish_dag(
     );
 
     // Sign the transaction and send it to the network.
-    let response = sign_and_execute_transaction(&sui, &wallet, tx_data).await?;
+    let response = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
 
     // We need to parse the DAG object ID from the response.
     let dag = response
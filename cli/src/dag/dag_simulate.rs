@@ -0,0 +1,823 @@
+use {
+    crate::{
+        command_title,
+        dag::dag_validate::validate_dag,
+        display::json_output,
+        item,
+        loading,
+        notify_success,
+        prelude::*,
+        sui::*,
+        tool::{fetch_tool_urls, tool_base_url},
+    },
+    nexus_sdk::types::{Dag, Data, EdgeKind, VertexKind, DEFAULT_ENTRY_GROUP},
+    std::collections::{BTreeSet, HashSet},
+    tokio::task::JoinSet,
+};
+
+/// Output of a vertex evaluated during the simulation.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct SimulatedVertex {
+    vertex: String,
+    variant: String,
+    data: serde_json::Map<String, serde_json::Value>,
+    /// Whether the output variant has no outgoing edges, i.e. the walk ends
+    /// here.
+    end_state: bool,
+}
+
+/// Simulate an execution of the DAG at `path` off-chain by invoking its tools
+/// directly and propagating their outputs along the edges.
+///
+/// Tool URLs are looked up in the tool registry unless they are provided via
+/// `tool_urls`.
+pub(crate) async fn simulate_dag(
+    path: PathBuf,
+    entry_group: String,
+    input_json: serde_json::Value,
+    tool_urls: Vec<(ToolFqn, reqwest::Url)>,
+    max_concurrency: usize,
+) -> AnyResult<(), NexusCliError> {
+    let dag = validate_dag(path).await?;
+
+    command_title!("Simulating Nexus DAG with entry group '{entry_group}'");
+
+    let mut urls = tool_urls.into_iter().collect::<HashMap<_, _>>();
+
+    // Only fetch the tool registry if some tool URLs are not provided.
+    let needs_registry = dag.vertices.iter().any(|vertex| match &vertex.kind {
+        VertexKind::OffChain { tool_fqn } => !urls.contains_key(tool_fqn),
+        VertexKind::OnChain { .. } => false,
+    });
+
+    if needs_registry {
+        // Load CLI configuration.
+        let mut conf = CliConf::load().await.unwrap_or_default();
+
+        // Nexus objects must be present in the configuration.
+        let NexusObjects { tool_registry, .. } = &get_nexus_objects(&mut conf).await?;
+
+        // Build the Sui client.
+        let sui = build_sui_client(&conf.sui).await?;
+
+        let tools_handle = loading!("Fetching tool URLs from the tool registry...");
+
+        match fetch_tool_urls(&sui, tool_registry.object_id).await {
+            Ok(registered) => {
+                tools_handle.success();
+
+                // Provided URLs take precedence.
+                for (fqn, url) in registered {
+                    urls.entry(fqn).or_insert(url);
+                }
+            }
+            Err(e) => {
+                tools_handle.error();
+
+                return Err(NexusCliError::Any(e));
+            }
+        }
+    }
+
+    let simulation_handle = loading!("Simulating DAG execution...");
+
+    let trace = match simulate(
+        &dag,
+        &entry_group,
+        &input_json,
+        &urls,
+        &reqwest::Client::new(),
+        max_concurrency,
+    )
+    .await
+    {
+        Ok(trace) => trace,
+        Err(e) => {
+            simulation_handle.error();
+
+            return Err(NexusCliError::Any(e));
+        }
+    };
+
+    simulation_handle.success();
+
+    for evaluated in &trace {
+        let end_state = match evaluated.end_state {
+            true => format!("{} ", "END STATE".truecolor(100, 100, 100)),
+            false => String::new(),
+        };
+
+        notify_success!(
+            "{end_state}Vertex '{vertex}' evaluated with output variant '{variant}'.",
+            vertex = evaluated.vertex.truecolor(100, 100, 100),
+            variant = evaluated.variant.truecolor(100, 100, 100),
+        );
+
+        for (port, data) in &evaluated.data {
+            item!(
+                "Port '{port}' produced data: {data}",
+                port = port.truecolor(100, 100, 100),
+                data = data.to_string().truecolor(100, 100, 100),
+            );
+        }
+    }
+
+    json_output(&trace)?;
+
+    Ok(())
+}
+
+/// Walk the DAG starting at the vertices of the given entry group. A vertex is
+/// invoked once all of its input ports have data. Vertices that become ready
+/// at the same time are invoked concurrently, at most `max_concurrency` at a
+/// time. Fails on the first vertex that cannot be invoked or that returns an
+/// error.
+async fn simulate(
+    dag: &Dag,
+    entry_group: &str,
+    input_json: &serde_json::Value,
+    urls: &HashMap<ToolFqn, reqwest::Url>,
+    client: &reqwest::Client,
+    max_concurrency: usize,
+) -> AnyResult<Vec<SimulatedVertex>> {
+    // Loops and for-each edges are evaluated by the workflow only.
+    if let Some(edge) = dag.edges.iter().find(|e| e.kind != EdgeKind::Normal) {
+        bail!(
+            "Simulating DAGs with '{kind:?}' edges is not supported",
+            kind = edge.kind
+        );
+    }
+
+    let entry_vertices = find_entry_vertices(dag, entry_group)?;
+
+    // Input ports of each vertex that need data before it can be invoked.
+    let mut required = HashMap::<&str, BTreeSet<&str>>::new();
+    // Data that each vertex received so far.
+    let mut inputs = HashMap::<&str, serde_json::Map<String, serde_json::Value>>::new();
+
+    for edge in &dag.edges {
+        required
+            .entry(edge.to.vertex.as_str())
+            .or_default()
+            .insert(edge.to.input_port.as_str());
+    }
+
+    for default in dag.default_values.iter().flatten() {
+        let Data::Inline { data, .. } = &default.value;
+
+        required
+            .entry(default.vertex.as_str())
+            .or_default()
+            .insert(default.input_port.as_str());
+        inputs
+            .entry(default.vertex.as_str())
+            .or_default()
+            .insert(default.input_port.clone(), data.clone());
+    }
+
+    // Entry ports must be provided by the input data.
+    let Some(input_json) = input_json.as_object() else {
+        bail!("Input JSON must be an object with entry vertex names as keys");
+    };
+
+    if let Some(vertex) = input_json
+        .keys()
+        .find(|vertex| !entry_vertices.contains(vertex.as_str()))
+    {
+        bail!("Vertex '{vertex}' is not an entry vertex of entry group '{entry_group}'");
+    }
+
+    for vertex in dag
+        .vertices
+        .iter()
+        .filter(|v| entry_vertices.contains(v.name.as_str()))
+    {
+        let entry_ports = vertex.entry_ports.iter().flatten().collect::<Vec<_>>();
+        let provided = input_json
+            .get(&vertex.name)
+            .and_then(|data| data.as_object());
+
+        if let Some(port) = provided
+            .into_iter()
+            .flatten()
+            .map(|(port, _)| port)
+            .find(|port| {
+                !entry_ports
+                    .iter()
+                    .any(|entry_port| &entry_port.name == *port)
+            })
+        {
+            bail!("Vertex '{}' has no entry port '{port}'", vertex.name);
+        }
+
+        for entry_port in entry_ports {
+            let Some(data) = provided.and_then(|provided| provided.get(&entry_port.name)) else {
+                bail!(
+                    "Missing input data for entry port '{}.{}'",
+                    vertex.name,
+                    entry_port.name
+                );
+            };
+
+            required
+                .entry(vertex.name.as_str())
+                .or_default()
+                .insert(entry_port.name.as_str());
+            inputs
+                .entry(vertex.name.as_str())
+                .or_default()
+                .insert(entry_port.name.clone(), data.clone());
+        }
+    }
+
+    // All vertices must be reachable from the entry vertices.
+    let mut reachable = entry_vertices.clone();
+    let mut to_visit = entry_vertices.iter().copied().collect::<Vec<_>>();
+
+    while let Some(vertex) = to_visit.pop() {
+        for edge in dag.edges.iter().filter(|e| e.from.vertex == vertex) {
+            if reachable.insert(edge.to.vertex.as_str()) {
+                to_visit.push(edge.to.vertex.as_str());
+            }
+        }
+    }
+
+    let mut unreachable = dag
+        .vertices
+        .iter()
+        .map(|v| v.name.as_str())
+        .filter(|vertex| !reachable.contains(vertex))
+        .collect::<Vec<_>>();
+
+    if !unreachable.is_empty() {
+        unreachable.sort_unstable();
+
+        bail!(
+            "Vertices {} are unreachable from entry group '{entry_group}'",
+            unreachable
+                .iter()
+                .map(|vertex| format!("'{vertex}'"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+
+    let is_ready =
+        |vertex: &str, inputs: &HashMap<&str, serde_json::Map<String, serde_json::Value>>| {
+            required.get(vertex).is_none_or(|ports| {
+                ports.iter().all(|port| {
+                    inputs
+                        .get(vertex)
+                        .is_some_and(|data| data.contains_key(*port))
+                })
+            })
+        };
+
+    // Walk the DAG level by level. All vertices of a level have their input
+    // data and are invoked concurrently.
+    let mut trace = Vec::new();
+    let mut evaluated = HashSet::<&str>::new();
+    let mut level = dag
+        .vertices
+        .iter()
+        .map(|v| v.name.as_str())
+        .filter(|vertex| entry_vertices.contains(vertex) && is_ready(*vertex, &inputs))
+        .collect::<Vec<_>>();
+
+    while !level.is_empty() {
+        let mut invocations = Vec::with_capacity(level.len());
+
+        for &name in &level {
+            evaluated.insert(name);
+
+            let vertex = dag
+                .vertices
+                .iter()
+                .find(|v| v.name == name)
+                .expect("Vertex must exist in a validated DAG");
+
+            let VertexKind::OffChain { tool_fqn } = &vertex.kind else {
+                bail!("Vertex '{name}' is an on-chain tool which cannot be simulated");
+            };
+
+            let Some(url) = urls.get(tool_fqn) else {
+                bail!("Vertex '{name}' failed: tool '{tool_fqn}' is not registered");
+            };
+
+            invocations.push((url.clone(), inputs.remove(name).unwrap_or_default()));
+        }
+
+        let outputs = invoke_level(client, invocations, max_concurrency).await;
+        let mut next_level = Vec::new();
+
+        // Outputs are in the order of the level regardless of which invocation
+        // finished first.
+        for (name, output) in level.into_iter().zip(outputs) {
+            let (variant, data) = output.map_err(|e| anyhow!("Vertex '{name}' failed: {e}"))?;
+
+            // Propagate the output data along the edges of the evaluated variant.
+            let mut end_state = true;
+
+            for edge in dag
+                .edges
+                .iter()
+                .filter(|e| e.from.vertex == name && e.from.output_variant == variant)
+            {
+                end_state = false;
+
+                let Some(port_data) = data.get(&edge.from.output_port) else {
+                    bail!(
+                        "Vertex '{name}' failed: output variant '{variant}' has no port '{}'",
+                        edge.from.output_port
+                    );
+                };
+
+                inputs
+                    .entry(edge.to.vertex.as_str())
+                    .or_default()
+                    .insert(edge.to.input_port.clone(), port_data.clone());
+            }
+
+            trace.push(SimulatedVertex {
+                vertex: name.to_string(),
+                variant,
+                data,
+                end_state,
+            });
+
+            for edge in dag.edges.iter().filter(|e| e.from.vertex == name) {
+                let next = edge.to.vertex.as_str();
+
+                if !evaluated.contains(next)
+                    && !next_level.contains(&next)
+                    && is_ready(next, &inputs)
+                {
+                    next_level.push(next);
+                }
+            }
+        }
+
+        // Keep the order of the vertices in the DAG so that the trace is
+        // deterministic.
+        next_level.sort_by_key(|next| dag.vertices.iter().position(|v| v.name == *next));
+
+        level = next_level;
+    }
+
+    // Vertices that received some but not all of their input data can never
+    // be invoked.
+    if let Some(vertex) = dag.vertices.iter().find(|v| {
+        let name = v.name.as_str();
+
+        !evaluated.contains(name)
+            && (entry_vertices.contains(name)
+                || dag
+                    .edges
+                    .iter()
+                    .any(|e| e.to.vertex == name && evaluated.contains(e.from.vertex.as_str())))
+    }) {
+        let missing = required
+            .get(vertex.name.as_str())
+            .into_iter()
+            .flatten()
+            .filter(|port| {
+                !inputs
+                    .get(vertex.name.as_str())
+                    .is_some_and(|data| data.contains_key(**port))
+            })
+            .map(|port| format!("'{port}'"))
+            .collect::<Vec<_>>();
+
+        bail!(
+            "Vertex '{}' is missing data for input ports {}",
+            vertex.name,
+            missing.join(", ")
+        );
+    }
+
+    Ok(trace)
+}
+
+type ToolOutput = (String, serde_json::Map<String, serde_json::Value>);
+
+/// Invoke the tools of one level concurrently, at most `max_concurrency` at a
+/// time. Outputs are returned in the order of the `invocations`.
+async fn invoke_level(
+    client: &reqwest::Client,
+    invocations: Vec<(reqwest::Url, serde_json::Map<String, serde_json::Value>)>,
+    max_concurrency: usize,
+) -> Vec<AnyResult<ToolOutput>> {
+    let mut outputs = invocations
+        .iter()
+        .map(|_| Err(anyhow!("tool invocation did not finish")))
+        .collect::<Vec<_>>();
+    let mut set = JoinSet::new();
+
+    for (index, (url, input)) in invocations.into_iter().enumerate() {
+        // Wait for a slot to free up.
+        if set.len() >= max_concurrency {
+            if let Some(Ok((index, output))) = set.join_next().await {
+                outputs[index] = output;
+            }
+        }
+
+        let client = client.clone();
+
+        set.spawn(async move { (index, invoke_tool(&client, &url, input).await) });
+    }
+
+    while let Some(result) = set.join_next().await {
+        if let Ok((index, output)) = result {
+            outputs[index] = output;
+        }
+    }
+
+    outputs
+}
+
+/// Find the names of the vertices that are part of the given entry group.
+fn find_entry_vertices<'a>(dag: &'a Dag, entry_group: &str) -> AnyResult<HashSet<&'a str>> {
+    match &dag.entry_groups {
+        Some(groups) => match groups.iter().find(|group| group.name == entry_group) {
+            Some(group) => Ok(group.vertices.iter().map(String::as_str).collect()),
+            None => bail!("Entry group '{entry_group}' not found in DAG"),
+        },
+        // All vertices with entry ports are in the default entry group.
+        None if entry_group == DEFAULT_ENTRY_GROUP => Ok(dag
+            .vertices
+            .iter()
+            .filter(|vertex| vertex.entry_ports.is_some())
+            .map(|vertex| vertex.name.as_str())
+            .collect()),
+        None => bail!("Entry group '{entry_group}' not found in DAG"),
+    }
+}
+
+/// Invoke the tool at `url` with the given input port data and return the
+/// output variant and its port data.
+async fn invoke_tool(
+    client: &reqwest::Client,
+    url: &reqwest::Url,
+    input: serde_json::Map<String, serde_json::Value>,
+) -> AnyResult<ToolOutput> {
+    let invoke_url = tool_base_url(url)
+        .join("invoke")
+        .expect("Appending invoke must be valid");
+
+    let response = client.post(invoke_url).json(&input).send().await?;
+    let status = response.status();
+
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+
+        bail!("tool responded with status '{status}': {body}");
+    }
+
+    // Tool outputs are enums serialized as `{ "variant": { "port": data } }`.
+    let output = response.json::<serde_json::Value>().await?;
+
+    match output {
+        serde_json::Value::Object(output) if output.len() == 1 => {
+            let (variant, data) = output.into_iter().next().expect("Length checked");
+
+            match data {
+                serde_json::Value::Object(data) => Ok((variant, data)),
+                _ => bail!("output variant '{variant}' does not contain port data"),
+            }
+        }
+        _ => bail!("tool output is not an output variant: {output}"),
+    }
+}
+
+/// Parse a `fqn=url` pair.
+pub(crate) fn parse_tool_url(s: &str) -> AnyResult<(ToolFqn, reqwest::Url)> {
+    let Some((fqn, url)) = s.split_once('=') else {
+        bail!("Expected a tool FQN and URL in the 'fqn=url' format");
+    };
+
+    Ok((fqn.trim().parse()?, url.trim().parse()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, mockito::Server};
+
+    fn two_vertex_dag() -> Dag {
+        serde_json::from_value(json!({
+            "vertices": [
+                {
+                    "kind": { "variant": "off_chain", "tool_fqn": "xyz.dummy.greet@1" },
+                    "name": "greet",
+                    "entry_ports": [{ "name": "name" }]
+                },
+                {
+                    "kind": { "variant": "off_chain", "tool_fqn": "xyz.dummy.shout@1" },
+                    "name": "shout"
+                }
+            ],
+            "edges": [
+                {
+                    "from": { "vertex": "greet", "output_variant": "ok", "output_port": "message" },
+                    "to": { "vertex": "shout", "input_port": "text" }
+                }
+            ],
+            "default_values": [
+                {
+                    "vertex": "shout",
+                    "input_port": "times",
+                    "value": { "storage": "inline", "data": 2 }
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn urls(greet: &Server, shout: &Server) -> HashMap<ToolFqn, reqwest::Url> {
+        HashMap::from([
+            (fqn!("xyz.dummy.greet@1"), greet.url().parse().unwrap()),
+            (fqn!("xyz.dummy.shout@1"), shout.url().parse().unwrap()),
+        ])
+    }
+
+    #[test]
+    fn test_parse_tool_url() {
+        let (fqn, url) = parse_tool_url("xyz.dummy.tool@1=http://localhost:8080/tool").unwrap();
+
+        assert_eq!(fqn, fqn!("xyz.dummy.tool@1"));
+        assert_eq!(url.as_str(), "http://localhost:8080/tool");
+
+        assert!(parse_tool_url("xyz.dummy.tool@1").is_err());
+        assert!(parse_tool_url("not a fqn=http://localhost").is_err());
+        assert!(parse_tool_url("xyz.dummy.tool@1=not a url").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_simulate_two_vertex_dag() {
+        let mut greet = Server::new_async().await;
+        let mut shout = Server::new_async().await;
+
+        let greet_mock = greet
+            .mock("POST", "/invoke")
+            .match_body(mockito::Matcher::Json(json!({ "name": "Alice" })))
+            .with_status(200)
+            .with_body(json!({ "ok": { "message": "Hello Alice" } }).to_string())
+            .create_async()
+            .await;
+
+        // Receives the output of the first vertex and the default value.
+        let shout_mock = shout
+            .mock("POST", "/invoke")
+            .match_body(mockito::Matcher::Json(
+                json!({ "text": "Hello Alice", "times": 2 }),
+            ))
+            .with_status(200)
+            .with_body(json!({ "ok": { "text": "HELLO ALICE HELLO ALICE" } }).to_string())
+            .create_async()
+            .await;
+
+        let trace = simulate(
+            &two_vertex_dag(),
+            DEFAULT_ENTRY_GROUP,
+            &json!({ "greet": { "name": "Alice" } }),
+            &urls(&greet, &shout),
+            &reqwest::Client::new(),
+            4,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&trace).unwrap(),
+            json!([
+                {
+                    "vertex": "greet",
+                    "variant": "ok",
+                    "data": { "message": "Hello Alice" },
+                    "end_state": false
+                },
+                {
+                    "vertex": "shout",
+                    "variant": "ok",
+                    "data": { "text": "HELLO ALICE HELLO ALICE" },
+                    "end_state": true
+                }
+            ])
+        );
+
+        greet_mock.assert_async().await;
+        shout_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_simulate_reports_failing_vertex() {
+        let mut greet = Server::new_async().await;
+        let mut shout = Server::new_async().await;
+
+        greet
+            .mock("POST", "/invoke")
+            .with_status(200)
+            .with_body(json!({ "ok": { "message": "Hello Alice" } }).to_string())
+            .create_async()
+            .await;
+
+        shout
+            .mock("POST", "/invoke")
+            .with_status(422)
+            .with_body(json!({ "error": "input_deserialization_error" }).to_string())
+            .create_async()
+            .await;
+
+        let err = simulate(
+            &two_vertex_dag(),
+            DEFAULT_ENTRY_GROUP,
+            &json!({ "greet": { "name": "Alice" } }),
+            &urls(&greet, &shout),
+            &reqwest::Client::new(),
+            4,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(err
+            .to_string()
+            .starts_with("Vertex 'shout' failed: tool responded with status '422"));
+    }
+
+    #[tokio::test]
+    async fn test_simulate_missing_input() {
+        let greet = Server::new_async().await;
+        let shout = Server::new_async().await;
+        let urls = urls(&greet, &shout);
+        let client = reqwest::Client::new();
+        let dag = two_vertex_dag();
+
+        let err = simulate(&dag, DEFAULT_ENTRY_GROUP, &json!({}), &urls, &client, 4)
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Missing input data for entry port 'greet.name'"
+        );
+
+        let err = simulate(
+            &dag,
+            DEFAULT_ENTRY_GROUP,
+            &json!({ "shout": { "text": "hi" } }),
+            &urls,
+            &client,
+            4,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Vertex 'shout' is not an entry vertex of entry group '_default_group'"
+        );
+
+        let err = simulate(&dag, "missing", &json!({}), &urls, &client, 4)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "Entry group 'missing' not found in DAG");
+    }
+
+    #[tokio::test]
+    async fn test_simulate_unreachable_vertex() {
+        let greet = Server::new_async().await;
+        let shout = Server::new_async().await;
+
+        let mut dag = two_vertex_dag();
+        dag.edges.clear();
+
+        let err = simulate(
+            &dag,
+            DEFAULT_ENTRY_GROUP,
+            &json!({ "greet": { "name": "Alice" } }),
+            &urls(&greet, &shout),
+            &reqwest::Client::new(),
+            4,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Vertices 'shout' are unreachable from entry group '_default_group'"
+        );
+    }
+
+    /// Serve tools at `/{name}` that record when they start and finish and
+    /// take 100ms to reply with their name.
+    async fn serve_recording_tools(
+        events: std::sync::Arc<std::sync::Mutex<Vec<(String, &'static str)>>>,
+    ) -> std::net::SocketAddr {
+        use warp::Filter;
+
+        let routes = warp::post()
+            .and(warp::path::param::<String>())
+            .and(warp::path("invoke"))
+            .and(warp::body::json::<serde_json::Value>())
+            .then(move |name: String, _input| {
+                let events = events.clone();
+
+                async move {
+                    events.lock().unwrap().push((name.clone(), "start"));
+                    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                    events.lock().unwrap().push((name.clone(), "end"));
+
+                    warp::reply::json(&json!({ "ok": { "value": name } }))
+                }
+            });
+
+        let (addr, server) = warp::serve(routes).bind_ephemeral(([127, 0, 0, 1], 0));
+
+        tokio::spawn(server);
+
+        addr
+    }
+
+    fn diamond_dag() -> Dag {
+        let vertex = |name: &str| {
+            json!({
+                "kind": { "variant": "off_chain", "tool_fqn": format!("xyz.dummy.{name}@1") },
+                "name": name,
+            })
+        };
+        let edge = |from: &str, to: &str, port: &str| {
+            json!({
+                "from": { "vertex": from, "output_variant": "ok", "output_port": "value" },
+                "to": { "vertex": to, "input_port": port }
+            })
+        };
+
+        let mut a = vertex("a");
+        a["entry_ports"] = json!([{ "name": "input" }]);
+
+        serde_json::from_value(json!({
+            "vertices": [a, vertex("b"), vertex("c"), vertex("d")],
+            "edges": [
+                edge("a", "b", "input"),
+                edge("a", "c", "input"),
+                edge("b", "d", "b"),
+                edge("c", "d", "c"),
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_simulate_diamond_dag_concurrently() {
+        for max_concurrency in [4, 1] {
+            let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+            let addr = serve_recording_tools(events.clone()).await;
+
+            let urls = ["a", "b", "c", "d"]
+                .into_iter()
+                .map(|name| {
+                    (
+                        format!("xyz.dummy.{name}@1").parse().unwrap(),
+                        format!("http://{addr}/{name}").parse().unwrap(),
+                    )
+                })
+                .collect::<HashMap<ToolFqn, reqwest::Url>>();
+
+            let trace = simulate(
+                &diamond_dag(),
+                DEFAULT_ENTRY_GROUP,
+                &json!({ "a": { "input": 1 } }),
+                &urls,
+                &reqwest::Client::new(),
+                max_concurrency,
+            )
+            .await
+            .unwrap();
+
+            // Trace is in DAG order regardless of which vertex finished first.
+            assert_eq!(
+                trace.iter().map(|v| v.vertex.as_str()).collect::<Vec<_>>(),
+                vec!["a", "b", "c", "d"]
+            );
+            assert!(trace[3].end_state);
+
+            let events = events.lock().unwrap().clone();
+            let position = |name: &str, event: &str| {
+                events
+                    .iter()
+                    .position(|(n, e)| n == name && *e == event)
+                    .unwrap()
+            };
+
+            // D waits for both B and C.
+            assert!(position("d", "start") > position("b", "end"));
+            assert!(position("d", "start") > position("c", "end"));
+
+            let overlap = position("b", "start") < position("c", "end")
+                && position("c", "start") < position("b", "end");
+
+            // B and C only run at the same time if the concurrency allows it.
+            assert_eq!(overlap, max_concurrency > 1);
+        }
+    }
+}
@@ -1,8 +1,29 @@
 use {
-    crate::{command_title, loading, prelude::*},
-    nexus_sdk::{dag::validator::validate, types::Dag},
+    crate::{
+        command_title,
+        item,
+        loading,
+        notify_error,
+        prelude::*,
+        sui::*,
+        tool::{fetch_tool_urls, tool_base_url},
+    },
+    nexus_sdk::{
+        dag::validator::validate,
+        types::{Dag, Data, ToolMeta, VertexKind},
+    },
+    std::collections::BTreeMap,
 };
 
+/// Default values of a vertex that do not satisfy the input schema of its
+/// tool.
+#[derive(Clone, Debug, PartialEq)]
+struct SchemaMismatch {
+    vertex: String,
+    tool_fqn: ToolFqn,
+    errors: Vec<String>,
+}
+
 /// Validate if a JSON file at the provided location is a valid Nexus DAG. If so,
 /// return the parsed DAG.
 pub(crate) async fn validate_dag(path: PathBuf) -> AnyResult<Dag, NexusCliError> {
@@ -50,3 +71,306 @@ pub(crate) async fn validate_dag(path: PathBuf) -> AnyResult<Dag, NexusCliError>
         }
     }
 }
+
+/// Fetch the input schema of each off-chain tool referenced in the DAG and
+/// check that the default values of its vertices satisfy it.
+pub(crate) async fn check_dag_schemas(dag: &Dag) -> AnyResult<(), NexusCliError> {
+    command_title!("Checking Nexus DAG default values against tool input schemas");
+
+    // Load CLI configuration.
+    let mut conf = CliConf::load().await.unwrap_or_default();
+
+    // Nexus objects must be present in the configuration.
+    let NexusObjects { tool_registry, .. } = &get_nexus_objects(&mut conf).await?;
+
+    // Build the Sui client.
+    let sui = build_sui_client(&conf.sui).await?;
+
+    let tools_handle = loading!("Fetching tool URLs from the tool registry...");
+
+    let urls = match fetch_tool_urls(&sui, tool_registry.object_id).await {
+        Ok(urls) => urls,
+        Err(e) => {
+            tools_handle.error();
+
+            return Err(NexusCliError::Any(e));
+        }
+    };
+
+    tools_handle.success();
+
+    let schemas_handle = loading!("Fetching tool input schemas...");
+
+    let schemas = match fetch_input_schemas(dag, &urls, &reqwest::Client::new()).await {
+        Ok(schemas) => schemas,
+        Err(e) => {
+            schemas_handle.error();
+
+            return Err(NexusCliError::Any(e));
+        }
+    };
+
+    schemas_handle.success();
+
+    let check_handle = loading!("Checking default values...");
+
+    let mismatches = match schema_mismatches(dag, &schemas) {
+        Ok(mismatches) => mismatches,
+        Err(e) => {
+            check_handle.error();
+
+            return Err(NexusCliError::Any(e));
+        }
+    };
+
+    if mismatches.is_empty() {
+        check_handle.success();
+
+        return Ok(());
+    }
+
+    check_handle.error();
+
+    for mismatch in &mismatches {
+        notify_error!(
+            "Default values of vertex '{vertex}' do not match the input schema of tool '{tool_fqn}'",
+            vertex = mismatch.vertex.truecolor(100, 100, 100),
+            tool_fqn = mismatch.tool_fqn.to_string().truecolor(100, 100, 100),
+        );
+
+        for error in &mismatch.errors {
+            item!("{}", error.truecolor(100, 100, 100));
+        }
+    }
+
+    Err(NexusCliError::Any(anyhow!(
+        "{count} vertices have default values that do not match their tool input schema",
+        count = mismatches.len()
+    )))
+}
+
+/// Fetch the input schema of every off-chain tool referenced in the DAG from
+/// its `/meta` endpoint.
+async fn fetch_input_schemas(
+    dag: &Dag,
+    urls: &HashMap<ToolFqn, reqwest::Url>,
+    client: &reqwest::Client,
+) -> AnyResult<HashMap<ToolFqn, serde_json::Value>> {
+    let mut schemas = HashMap::new();
+
+    for vertex in &dag.vertices {
+        let VertexKind::OffChain { tool_fqn } = &vertex.kind else {
+            continue;
+        };
+
+        if schemas.contains_key(tool_fqn) {
+            continue;
+        }
+
+        let Some(url) = urls.get(tool_fqn) else {
+            bail!(
+                "Tool '{tool_fqn}' of vertex '{}' is not registered",
+                vertex.name
+            );
+        };
+
+        let meta_url = tool_base_url(url)
+            .join("meta")
+            .expect("Appending meta must be valid");
+
+        let meta = client
+            .get(meta_url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| anyhow!("Failed to fetch the meta of tool '{tool_fqn}': {e}"))?
+            .json::<ToolMeta>()
+            .await
+            .map_err(|e| anyhow!("Failed to parse the meta of tool '{tool_fqn}': {e}"))?;
+
+        schemas.insert(tool_fqn.clone(), meta.input_schema);
+    }
+
+    Ok(schemas)
+}
+
+/// Validate the default values of each off-chain vertex against the input
+/// schema of its tool. Encrypted values are skipped as they cannot be read.
+///
+/// Input ports that are not given a default value are filled in at runtime so
+/// the `required` keyword of the schema is ignored.
+fn schema_mismatches(
+    dag: &Dag,
+    schemas: &HashMap<ToolFqn, serde_json::Value>,
+) -> AnyResult<Vec<SchemaMismatch>> {
+    // Group the default values by vertex.
+    let mut defaults = BTreeMap::<&str, serde_json::Map<String, serde_json::Value>>::new();
+
+    for default in dag.default_values.iter().flatten() {
+        let Data::Inline { data, encrypted } = &default.value;
+
+        if *encrypted {
+            continue;
+        }
+
+        defaults
+            .entry(default.vertex.as_str())
+            .or_default()
+            .insert(default.input_port.clone(), data.clone());
+    }
+
+    let mut mismatches = vec![];
+
+    for (vertex_name, values) in defaults {
+        let Some(vertex) = dag
+            .vertices
+            .iter()
+            .find(|vertex| vertex.name == vertex_name)
+        else {
+            continue;
+        };
+
+        let VertexKind::OffChain { tool_fqn } = &vertex.kind else {
+            continue;
+        };
+
+        let Some(schema) = schemas.get(tool_fqn) else {
+            continue;
+        };
+
+        let mut schema = schema.clone();
+
+        if let Some(schema) = schema.as_object_mut() {
+            schema.remove("required");
+        }
+
+        let validator = jsonschema::validator_for(&schema).map_err(|e| {
+            anyhow!("The input schema of tool '{tool_fqn}' is not a valid JSON schema: {e}")
+        })?;
+
+        let instance = serde_json::Value::Object(values);
+        let errors = validator
+            .iter_errors(&instance)
+            .map(|e| match e.instance_path.to_string() {
+                path if path.is_empty() => e.to_string(),
+                path => format!("{path}: {e}"),
+            })
+            .collect::<Vec<_>>();
+
+        if !errors.is_empty() {
+            mismatches.push(SchemaMismatch {
+                vertex: vertex_name.to_string(),
+                tool_fqn: tool_fqn.clone(),
+                errors,
+            });
+        }
+    }
+
+    Ok(mismatches)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, mockito::Server};
+
+    fn dag(times: serde_json::Value) -> Dag {
+        serde_json::from_value(json!({
+            "vertices": [
+                {
+                    "kind": { "variant": "off_chain", "tool_fqn": "xyz.dummy.shout@1" },
+                    "name": "shout",
+                    "entry_ports": [{ "name": "text" }]
+                }
+            ],
+            "edges": [],
+            "default_values": [
+                {
+                    "vertex": "shout",
+                    "input_port": "times",
+                    "value": { "storage": "inline", "data": times }
+                },
+                {
+                    "vertex": "shout",
+                    "input_port": "secret",
+                    "value": { "storage": "inline", "data": "ciphertext", "encrypted": true }
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn schemas() -> HashMap<ToolFqn, serde_json::Value> {
+        HashMap::from([(
+            fqn!("xyz.dummy.shout@1"),
+            json!({
+                "type": "object",
+                "properties": {
+                    "text": { "type": "string" },
+                    "times": { "type": "integer", "minimum": 1 },
+                    "secret": { "type": "integer" }
+                },
+                "required": ["text", "times"]
+            }),
+        )])
+    }
+
+    #[test]
+    fn test_schema_mismatches() {
+        // Missing required ports and encrypted values are not reported.
+        assert_eq!(
+            schema_mismatches(&dag(json!(2)), &schemas()).unwrap(),
+            vec![]
+        );
+
+        let mismatches = schema_mismatches(&dag(json!("twice")), &schemas()).unwrap();
+
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].vertex, "shout");
+        assert_eq!(mismatches[0].tool_fqn, fqn!("xyz.dummy.shout@1"));
+        assert_eq!(mismatches[0].errors.len(), 1);
+        assert!(mismatches[0].errors[0].starts_with("/times: "));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_input_schemas() {
+        let mut server = Server::new_async().await;
+
+        let meta = server
+            .mock("GET", "/shout/meta")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "fqn": "xyz.dummy.shout@1",
+                    "url": format!("{}/shout", server.url()),
+                    "description": "Shouts",
+                    "input_schema": schemas()[&fqn!("xyz.dummy.shout@1")],
+                    "output_schema": { "oneOf": [] }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let urls = HashMap::from([(
+            fqn!("xyz.dummy.shout@1"),
+            format!("{}/shout", server.url()).parse().unwrap(),
+        )]);
+
+        let schemas = fetch_input_schemas(&dag(json!(2)), &urls, &reqwest::Client::new())
+            .await
+            .unwrap();
+
+        assert_eq!(schemas, self::schemas());
+
+        meta.assert_async().await;
+
+        // Unregistered tools are reported.
+        let err = fetch_input_schemas(&dag(json!(2)), &HashMap::new(), &reqwest::Client::new())
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("is not registered"));
+    }
+}
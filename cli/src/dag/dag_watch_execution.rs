@@ -0,0 +1,473 @@
+use {
+    crate::{command_title, notify_error, notify_success, prelude::*, sui::*},
+    crossterm::{
+        cursor::MoveToPreviousLine,
+        queue,
+        terminal::{Clear, ClearType},
+    },
+    nexus_sdk::{
+        events::{NexusEvent, NexusEventKind},
+        idents::primitives,
+        types::RuntimeVertex,
+    },
+    std::{
+        io::{IsTerminal, Write},
+        time::{Duration, Instant},
+    },
+};
+
+/// How often the table is refreshed with new events.
+const REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The live table can only be drawn into an interactive terminal and is not
+/// used when the output is JSON.
+pub(crate) fn can_watch_dag_execution() -> bool {
+    !JSON_MODE.load(Ordering::Relaxed) && std::io::stdout().is_terminal()
+}
+
+/// Watch a Nexus DAG execution based on the provided object ID and execution
+/// digest. A table with one row per vertex is redrawn every
+/// [`REFRESH_INTERVAL`] until the execution finishes.
+pub(crate) async fn watch_dag_execution(
+    dag_execution_id: sui::ObjectID,
+    execution_digest: sui::TransactionDigest,
+) -> AnyResult<(), NexusCliError> {
+    command_title!("Watching Nexus DAG Execution '{dag_execution_id}'");
+
+    // Load CLI configuration.
+    let mut conf = CliConf::load().await.unwrap_or_default();
+
+    // Nexus objects must be present in the configuration.
+    let primitives_pkg_id = get_nexus_objects(&mut conf).await?.primitives_pkg_id;
+
+    // Build Sui client.
+    let sui = build_sui_client(&conf.sui).await?;
+
+    let query = sui::EventFilter::MoveEventModule {
+        package: primitives_pkg_id,
+        module: primitives::Event::EVENT_WRAPPER.module.into(),
+    };
+
+    // Starting cursor is the provided event digest and `event_seq` always 0.
+    let mut cursor = Some(sui::EventID {
+        tx_digest: execution_digest,
+        event_seq: 0,
+    });
+
+    let mut table = ExecutionTable::default();
+    let mut stdout = std::io::stdout();
+    let mut drawn_lines = 0;
+    let mut interval = tokio::time::interval(REFRESH_INTERVAL);
+
+    let has_any_walk_failed = loop {
+        interval.tick().await;
+
+        // Fetch all events emitted since the last refresh.
+        loop {
+            let page = match sui
+                .event_api()
+                .query_events(query.clone(), cursor, None, false)
+                .await
+            {
+                Ok(page) => page,
+                // If RPC call fails, retry on the next refresh.
+                Err(_) => break,
+            };
+
+            // Keep the cursor if there are no new events.
+            if page.next_cursor.is_some() {
+                cursor = page.next_cursor;
+            }
+
+            for event in page.data {
+                if let Ok(event) = NexusEvent::try_from(event) {
+                    table.apply(dag_execution_id, event.data, Instant::now());
+                }
+            }
+
+            if !page.has_next_page {
+                break;
+            }
+        }
+
+        drawn_lines = redraw(&mut stdout, &table.render(Instant::now()), drawn_lines)
+            .map_err(NexusCliError::Io)?;
+
+        // The table stays frozen in its last state.
+        if let Some(has_any_walk_failed) = table.has_any_walk_failed {
+            break has_any_walk_failed;
+        }
+    };
+
+    if has_any_walk_failed {
+        notify_error!("DAG execution finished unsuccessfully");
+
+        return Err(NexusCliError::Any(anyhow!(
+            "DAG execution '{dag_execution_id}' has failed walks"
+        )));
+    }
+
+    notify_success!("DAG execution finished successfully");
+
+    Ok(())
+}
+
+/// Replace the previously drawn lines with the new ones. Returns the number of
+/// lines drawn.
+fn redraw(
+    stdout: &mut std::io::Stdout,
+    lines: &[String],
+    drawn_lines: u16,
+) -> std::io::Result<u16> {
+    if drawn_lines > 0 {
+        queue!(stdout, MoveToPreviousLine(drawn_lines))?;
+    }
+
+    queue!(stdout, Clear(ClearType::FromCursorDown))?;
+
+    for line in lines {
+        writeln!(stdout, "{line}")?;
+    }
+
+    stdout.flush()?;
+
+    Ok(lines.len().try_into().unwrap_or(u16::MAX))
+}
+
+/// Vertex name along with the iteration for vertices in a for-each loop.
+fn vertex_label(vertex: &RuntimeVertex) -> String {
+    match vertex {
+        RuntimeVertex::Plain { vertex } => vertex.name.clone(),
+        RuntimeVertex::WithIterator {
+            vertex,
+            iteration,
+            out_of,
+        } => format!("{}[{}/{}]", vertex.name, iteration + 1, out_of),
+    }
+}
+
+/// State of a vertex as derived from the events emitted so far.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum VertexState {
+    /// The walk has requested the vertex to be evaluated.
+    Pending,
+    /// Gas for the tool invocation was settled so the tool is being evaluated.
+    Running,
+    /// The vertex was evaluated.
+    Done,
+    /// The walk failed at this vertex.
+    Error,
+}
+
+impl VertexState {
+    fn is_final(&self) -> bool {
+        matches!(self, Self::Done | Self::Error)
+    }
+
+    fn colorize(&self, text: &str) -> colored::ColoredString {
+        match self {
+            Self::Pending => text.truecolor(100, 100, 100),
+            Self::Running => text.yellow(),
+            Self::Done => text.green(),
+            Self::Error => text.red(),
+        }
+    }
+}
+
+impl std::fmt::Display for VertexState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = match self {
+            Self::Pending => "Pending",
+            Self::Running => "Running",
+            Self::Done => "Done",
+            Self::Error => "Error",
+        };
+
+        f.pad(state)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct VertexRow {
+    vertex: RuntimeVertex,
+    tool_fqn: Option<ToolFqn>,
+    state: VertexState,
+    started_at: Instant,
+    finished_at: Option<Instant>,
+}
+
+impl VertexRow {
+    fn elapsed(&self, now: Instant) -> Duration {
+        self.finished_at
+            .unwrap_or(now)
+            .saturating_duration_since(self.started_at)
+    }
+}
+
+/// Rows of the live table in the order the vertices were first seen.
+#[derive(Debug, Default)]
+struct ExecutionTable {
+    rows: Vec<VertexRow>,
+    /// Set once the execution has finished.
+    has_any_walk_failed: Option<bool>,
+}
+
+impl ExecutionTable {
+    /// Update the table with an event. Events of other executions are ignored.
+    fn apply(&mut self, execution: sui::ObjectID, event: NexusEventKind, now: Instant) {
+        match event {
+            NexusEventKind::RequestWalkExecution(e) if e.execution == execution => {
+                self.row(&e.next_vertex, now);
+            }
+            NexusEventKind::GasSettlementUpdate(e) if e.execution == execution => {
+                let row = self.row(&e.vertex, now);
+
+                row.tool_fqn = Some(e.tool_fqn);
+
+                if !row.state.is_final() {
+                    row.state = VertexState::Running;
+                }
+            }
+            NexusEventKind::WalkAdvanced(e) if e.execution == execution => {
+                self.finish(&e.vertex, VertexState::Done, now);
+            }
+            NexusEventKind::EndStateReached(e) if e.execution == execution => {
+                self.finish(&e.vertex, VertexState::Done, now);
+            }
+            NexusEventKind::WalkFailed(e) if e.execution == execution => {
+                self.finish(&e.vertex, VertexState::Error, now);
+            }
+            NexusEventKind::ExecutionFinished(e) if e.execution == execution => {
+                self.has_any_walk_failed = Some(e.has_any_walk_failed);
+            }
+            _ => (),
+        }
+    }
+
+    /// Find the row of the given vertex or add a pending one.
+    fn row(&mut self, vertex: &RuntimeVertex, now: Instant) -> &mut VertexRow {
+        let index = match self.rows.iter().position(|row| &row.vertex == vertex) {
+            Some(index) => index,
+            None => {
+                self.rows.push(VertexRow {
+                    vertex: vertex.clone(),
+                    tool_fqn: None,
+                    state: VertexState::Pending,
+                    started_at: now,
+                    finished_at: None,
+                });
+
+                self.rows.len() - 1
+            }
+        };
+
+        &mut self.rows[index]
+    }
+
+    fn finish(&mut self, vertex: &RuntimeVertex, state: VertexState, now: Instant) {
+        let row = self.row(vertex, now);
+
+        row.state = state;
+        row.finished_at = Some(now);
+    }
+
+    /// Render the table into lines of text.
+    fn render(&self, now: Instant) -> Vec<String> {
+        let cells = self
+            .rows
+            .iter()
+            .map(|row| {
+                (
+                    vertex_label(&row.vertex),
+                    row.tool_fqn
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_else(|| "-".to_string()),
+                    row.state,
+                    format!("{:.1}s", row.elapsed(now).as_secs_f64()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let vertex_width = cells
+            .iter()
+            .map(|c| c.0.len())
+            .fold("VERTEX".len(), usize::max);
+        let tool_width = cells
+            .iter()
+            .map(|c| c.1.len())
+            .fold("TOOL".len(), usize::max);
+        let state_width = "Pending".len();
+
+        let mut lines = vec![format!(
+            "{:vertex_width$}  {:tool_width$}  {:state_width$}  ELAPSED",
+            "VERTEX", "TOOL", "STATE",
+        )
+        .bold()
+        .to_string()];
+
+        for (vertex, tool_fqn, state, elapsed) in cells {
+            lines.push(format!(
+                "{vertex:vertex_width$}  {tool_fqn:tool_width$}  {state}  {elapsed}",
+                state = state.colorize(&format!("{state:state_width$}")),
+            ));
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        nexus_sdk::{
+            events::{
+                ExecutionFinishedEvent,
+                GasSettlementUpdateEvent,
+                RequestWalkExecutionEvent,
+                WalkAdvancedEvent,
+                WalkFailedEvent,
+            },
+            types::TypeName,
+        },
+    };
+
+    fn request(execution: sui::ObjectID, vertex: &str) -> NexusEventKind {
+        NexusEventKind::RequestWalkExecution(RequestWalkExecutionEvent {
+            dag: sui::ObjectID::random(),
+            execution,
+            walk_index: 0,
+            next_vertex: RuntimeVertex::plain(vertex),
+            evaluations: sui::ObjectID::random(),
+            worksheet_from_type: TypeName {
+                name: "worksheet".to_string(),
+            },
+        })
+    }
+
+    fn settle(execution: sui::ObjectID, vertex: &str) -> NexusEventKind {
+        NexusEventKind::GasSettlementUpdate(GasSettlementUpdateEvent {
+            execution,
+            tool_fqn: fqn!("xyz.dummy.tool@1"),
+            vertex: RuntimeVertex::plain(vertex),
+            was_settled: true,
+        })
+    }
+
+    fn advance(execution: sui::ObjectID, vertex: &str) -> NexusEventKind {
+        NexusEventKind::WalkAdvanced(WalkAdvancedEvent {
+            dag: sui::ObjectID::random(),
+            execution,
+            walk_index: 0,
+            vertex: RuntimeVertex::plain(vertex),
+            variant: TypeName {
+                name: "ok".to_string(),
+            },
+            variant_ports_to_data: json!({}),
+        })
+    }
+
+    fn fail(execution: sui::ObjectID, vertex: &str) -> NexusEventKind {
+        NexusEventKind::WalkFailed(WalkFailedEvent {
+            dag: sui::ObjectID::random(),
+            execution,
+            walk_index: 0,
+            vertex: RuntimeVertex::plain(vertex),
+            reason: "boom".to_string(),
+        })
+    }
+
+    fn finished(execution: sui::ObjectID, has_any_walk_failed: bool) -> NexusEventKind {
+        NexusEventKind::ExecutionFinished(ExecutionFinishedEvent {
+            dag: sui::ObjectID::random(),
+            execution,
+            has_any_walk_failed,
+            has_any_walk_succeeded: !has_any_walk_failed,
+        })
+    }
+
+    #[test]
+    fn test_execution_table_apply() {
+        let execution = sui::ObjectID::random();
+        let start = Instant::now();
+        let mut table = ExecutionTable::default();
+
+        table.apply(execution, request(execution, "a"), start);
+        table.apply(execution, request(execution, "b"), start);
+
+        assert_eq!(table.rows.len(), 2);
+        assert!(table.rows.iter().all(|r| r.state == VertexState::Pending));
+
+        table.apply(execution, settle(execution, "a"), start);
+
+        assert_eq!(table.rows[0].state, VertexState::Running);
+        assert_eq!(table.rows[0].tool_fqn, Some(fqn!("xyz.dummy.tool@1")));
+
+        // Events of other executions are ignored.
+        table.apply(execution, advance(sui::ObjectID::random(), "a"), start);
+
+        assert_eq!(table.rows[0].state, VertexState::Running);
+
+        let later = start + Duration::from_secs(2);
+
+        table.apply(execution, advance(execution, "a"), later);
+        table.apply(execution, fail(execution, "b"), later);
+
+        assert_eq!(table.rows[0].state, VertexState::Done);
+        assert_eq!(table.rows[1].state, VertexState::Error);
+        assert_eq!(table.has_any_walk_failed, None);
+
+        // Elapsed time is frozen once a vertex is done.
+        assert_eq!(
+            table.rows[0].elapsed(later + Duration::from_secs(10)),
+            Duration::from_secs(2)
+        );
+
+        table.apply(execution, finished(execution, true), later);
+
+        assert_eq!(table.has_any_walk_failed, Some(true));
+    }
+
+    #[test]
+    fn test_execution_table_render() {
+        let execution = sui::ObjectID::random();
+        let start = Instant::now();
+        let mut table = ExecutionTable::default();
+
+        table.apply(execution, settle(execution, "vertex_a"), start);
+        table.apply(execution, request(execution, "b"), start);
+        table.apply(
+            execution,
+            advance(execution, "vertex_a"),
+            start + Duration::from_millis(1500),
+        );
+
+        // Strip colors to compare the layout.
+        let ansi = regex::Regex::new(r"\x1b\[[0-9;]*m").unwrap();
+        let lines = table
+            .render(start + Duration::from_secs(3))
+            .iter()
+            .map(|line| ansi.replace_all(line, "").to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lines,
+            vec![
+                "VERTEX    TOOL              STATE    ELAPSED",
+                "vertex_a  xyz.dummy.tool@1  Done     1.5s",
+                "b         -                 Pending  3.0s",
+            ]
+        );
+
+        let iteration = RuntimeVertex::WithIterator {
+            vertex: TypeName {
+                name: "each".to_string(),
+            },
+            iteration: 0,
+            out_of: 3,
+        };
+
+        assert_eq!(vertex_label(&iteration), "each[1/3]");
+    }
+}
@@ -1,13 +1,19 @@
+mod dag_cancel_execution;
 mod dag_execute;
 mod dag_inspect_execution;
 mod dag_publish;
+mod dag_simulate;
 mod dag_validate;
+#[cfg(feature = "watch")]
+mod dag_watch_execution;
 
 use {
     crate::prelude::*,
+    dag_cancel_execution::*,
     dag_execute::*,
     dag_inspect_execution::*,
     dag_publish::*,
+    dag_simulate::*,
     dag_validate::*,
     nexus_sdk::types::DEFAULT_ENTRY_GROUP,
 };
@@ -24,6 +30,12 @@ pub(crate) enum DagCommand {
             value_parser = ValueParser::from(expand_tilde)
         )]
         path: PathBuf,
+        /// Whether to check default values against tool input schemas.
+        #[arg(
+            long = "check-schemas",
+            help = "Whether to also check default values against the input schemas of the tools. Tool URLs are fetched from the tool registry."
+        )]
+        check_schemas: bool,
     },
 
     #[command(
@@ -51,7 +63,8 @@ pub(crate) enum DagCommand {
             long = "dag-id",
             short = 'd',
             help = "The object ID of the Nexus DAG",
-            value_name = "OBJECT_ID"
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
         )]
         dag_id: sui::ObjectID,
         /// The entry group to invoke.
@@ -79,10 +92,70 @@ pub(crate) enum DagCommand {
             help = "Whether to inspect the DAG execution process. If not provided, command returns after submitting the transaction."
         )]
         inspect: bool,
+        /// How to output the inspected DAG execution.
+        #[arg(
+            long = "output-format",
+            help = "How to output the inspected DAG execution. `ndjson` writes one JSON object per execution event to stdout as it happens.",
+            value_name = "FORMAT",
+            default_value = "human",
+            requires = "inspect"
+        )]
+        output_format: InspectOutputFormat,
         #[command(flatten)]
         gas: GasArgs,
     },
 
+    #[command(
+        about = "Simulate an execution of a Nexus DAG JSON file off-chain by invoking its tools directly."
+    )]
+    Simulate {
+        /// The path to the Nexus DAG JSON file to simulate.
+        #[arg(
+            long = "path",
+            short = 'p',
+            help = "The path to the Nexus DAG JSON file to simulate",
+            value_parser = ValueParser::from(expand_tilde)
+        )]
+        path: PathBuf,
+        /// The entry group to invoke.
+        #[arg(
+            long = "entry-group",
+            short = 'e',
+            help = "The entry group to invoke",
+            value_name = "NAME",
+            default_value = DEFAULT_ENTRY_GROUP,
+        )]
+        entry_group: String,
+        /// The initial input data for the DAG.
+        #[arg(
+            long = "input-json",
+            short = 'i',
+            help = "The initial input data for the DAG as a JSON object. Keys are names of entry vertices and values are the input data.",
+            value_parser = ValueParser::from(parse_json_string),
+            value_name = "DATA"
+        )]
+        input_json: serde_json::Value,
+        /// URLs to invoke tools at instead of the registered ones.
+        #[arg(
+            long = "tool-url",
+            short = 'u',
+            help = "Invoke the tool with the given FQN at the given URL instead of the URL registered in the tool registry. Can be passed multiple times.",
+            value_parser = ValueParser::from(parse_tool_url),
+            value_name = "FQN=URL"
+        )]
+        tool_urls: Vec<(ToolFqn, reqwest::Url)>,
+        /// How many vertices to invoke at the same time.
+        #[arg(
+            long = "max-concurrency",
+            short = 'c',
+            help = "How many independent vertices to invoke at the same time",
+            default_value = "4",
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+            value_name = "COUNT"
+        )]
+        max_concurrency: usize,
+    },
+
     #[command(
         about = "Inspect a Nexus DAG execution process based on the provided object ID and execution digest."
     )]
@@ -92,7 +165,8 @@ pub(crate) enum DagCommand {
             long = "dag-execution-id",
             short = 'e',
             help = "The object ID of the Nexus DAGExecution object.",
-            value_name = "OBJECT_ID"
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
         )]
         dag_execution_id: sui::ObjectID,
         /// The entry group to invoke.
@@ -103,6 +177,41 @@ pub(crate) enum DagCommand {
             value_name = "DIGEST"
         )]
         execution_digest: sui::TransactionDigest,
+        /// How to output the inspected DAG execution.
+        #[arg(
+            long = "output-format",
+            help = "How to output the inspected DAG execution. `ndjson` writes one JSON object per execution event to stdout as it happens.",
+            value_name = "FORMAT",
+            default_value = "human"
+        )]
+        output_format: InspectOutputFormat,
+    },
+
+    #[command(about = "Cancel a running Nexus DAG execution based on the provided object ID.")]
+    CancelExecution {
+        /// The object ID of the Nexus DAGExecution object.
+        #[arg(
+            long = "dag-execution-id",
+            short = 'e',
+            help = "The object ID of the Nexus DAGExecution object.",
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
+        )]
+        dag_execution_id: sui::ObjectID,
+        /// The OwnerCap object ID that allows cancelling the execution.
+        #[arg(
+            long = "owner-cap",
+            short = 'o',
+            help = "The OwnerCap object ID of the execution that must be owned by the sender.",
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
+        )]
+        owner_cap: sui::ObjectID,
+        /// Whether to skip the confirmation prompt.
+        #[arg(long = "yes", short = 'y', help = "Skip the confirmation prompt")]
+        skip_confirmation: bool,
+        #[command(flatten)]
+        gas: GasArgs,
     },
 }
 
@@ -111,7 +220,17 @@ pub(crate) enum DagCommand {
 pub(crate) async fn handle(command: DagCommand) -> AnyResult<(), NexusCliError> {
     match command {
         // == `$ nexus dag validate` ==
-        DagCommand::Validate { path } => validate_dag(path).await.map(|_| ()),
+        DagCommand::Validate {
+            path,
+            check_schemas,
+        } => {
+            let dag = validate_dag(path).await?;
+
+            match check_schemas {
+                true => check_dag_schemas(&dag).await,
+                false => Ok(()),
+            }
+        }
 
         // == `$ nexus dag publish` ==
         DagCommand::Publish { path, gas } => {
@@ -124,6 +243,7 @@ pub(crate) async fn handle(command: DagCommand) -> AnyResult<(), NexusCliError>
             entry_group,
             input_json,
             inspect,
+            output_format,
             gas,
         } => {
             // Optional: Check auth at CLI level instead of inside execute_dag
@@ -134,16 +254,44 @@ pub(crate) async fn handle(command: DagCommand) -> AnyResult<(), NexusCliError>
                 entry_group,
                 input_json,
                 inspect,
+                output_format,
                 gas.sui_gas_coin,
                 gas.sui_gas_budget,
             )
             .await
         }
 
+        // == `$ nexus dag simulate` ==
+        DagCommand::Simulate {
+            path,
+            entry_group,
+            input_json,
+            tool_urls,
+            max_concurrency,
+        } => simulate_dag(path, entry_group, input_json, tool_urls, max_concurrency).await,
+
         // == `$ nexus dag inspect-execution` ==
         DagCommand::InspectExecution {
             dag_execution_id,
             execution_digest,
-        } => inspect_dag_execution(dag_execution_id, execution_digest).await,
+            output_format,
+        } => inspect_dag_execution(dag_execution_id, execution_digest, output_format).await,
+
+        // == `$ nexus dag cancel-execution` ==
+        DagCommand::CancelExecution {
+            dag_execution_id,
+            owner_cap,
+            skip_confirmation,
+            gas,
+        } => {
+            cancel_dag_execution(
+                dag_execution_id,
+                owner_cap,
+                gas.sui_gas_coin,
+                gas.sui_gas_budget,
+                skip_confirmation,
+            )
+            .await
+        }
     }
 }
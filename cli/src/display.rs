@@ -1,4 +1,9 @@
-use {crate::prelude::*, colored::ColoredString, indicatif::ProgressBar};
+use {
+    crate::prelude::*,
+    colored::ColoredString,
+    indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle},
+    std::{io::IsTerminal, time::Duration},
+};
 
 /// Print a grey colored line to separate sections
 pub(crate) fn separator() -> ColoredString {
@@ -148,8 +153,132 @@ impl LoadingHandle {
     }
 }
 
-/// If [`JSON_MODE`] is enabled, output the given data as JSON.
+/// Whether transaction progress can be drawn. Progress is only shown to a
+/// human watching an interactive terminal and never in [`JSON_MODE`].
+pub(crate) fn progress_enabled() -> bool {
+    !JSON_MODE.load(Ordering::Relaxed)
+        && std::io::stdout().is_terminal()
+        && std::io::stderr().is_terminal()
+}
+
+/// Stages a transaction goes through from crafting until it is finalized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum TxStage {
+    Building,
+    DryRunning,
+    Signing,
+    Submitting,
+    Finalizing,
+}
+
+impl std::fmt::Display for TxStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TxStage::Building => write!(f, "Crafting transaction"),
+            TxStage::DryRunning => write!(f, "Dry running transaction"),
+            TxStage::Signing => write!(f, "Signing transaction"),
+            TxStage::Submitting => write!(f, "Submitting transaction"),
+            TxStage::Finalizing => write!(f, "Waiting for the transaction to be checkpointed"),
+        }
+    }
+}
+
+/// Spinner showing the current [`TxStage`] of a transaction on stderr so that
+/// it never interleaves with the command output on stdout.
+pub(crate) struct TxProgress {
+    pb: ProgressBar,
+    stage: Option<TxStage>,
+}
+
+impl TxProgress {
+    /// Start showing progress at the [`TxStage::Building`] stage.
+    pub(crate) fn new() -> Self {
+        let mut progress = Self::with_enabled(progress_enabled());
+
+        progress.stage(TxStage::Building);
+
+        progress
+    }
+
+    pub(crate) fn with_enabled(enabled: bool) -> Self {
+        if !enabled {
+            return Self {
+                pb: ProgressBar::hidden(),
+                stage: None,
+            };
+        }
+
+        let pb = ProgressBar::with_draw_target(None, ProgressDrawTarget::stderr());
+
+        pb.set_style(
+            ProgressStyle::default_spinner()
+                .template("[{spinner}] {msg} {elapsed:.dim}")
+                .unwrap(),
+        );
+        pb.enable_steady_tick(Duration::from_millis(100));
+
+        Self { pb, stage: None }
+    }
+
+    /// Move on to the next stage.
+    pub(crate) fn stage(&mut self, stage: TxStage) {
+        self.stage = Some(stage);
+        self.pb.set_message(format!("{stage}..."));
+    }
+
+    pub(crate) fn is_hidden(&self) -> bool {
+        self.pb.is_hidden()
+    }
+
+    pub(crate) fn success(self) {
+        self.finish("✓".green().bold());
+    }
+
+    pub(crate) fn error(self) {
+        self.finish("X".red().bold());
+    }
+
+    fn finish(self, mark: ColoredString) {
+        if self.is_hidden() {
+            return;
+        }
+
+        self.pb.finish_and_clear();
+
+        if let Some(stage) = self.stage {
+            eprintln!("[{mark}] {stage}");
+        }
+    }
+}
+
+/// Render an amount of MIST as SUI, e.g. `0.5 SUI`. Trailing zeros of the
+/// fraction are dropped but no precision is lost.
+pub(crate) fn format_sui(mist: u64) -> String {
+    let whole = mist / sui::MIST_PER_SUI;
+    let fraction = mist % sui::MIST_PER_SUI;
+
+    if fraction == 0 {
+        return format!("{whole} SUI");
+    }
+
+    let fraction = format!("{fraction:09}");
+
+    format!("{whole}.{} SUI", fraction.trim_end_matches('0'))
+}
+
+/// Render an amount of MIST together with its value in SUI, e.g.
+/// `500000000 MIST (0.5 SUI)`.
+pub(crate) fn format_mist(mist: u64) -> String {
+    format!("{mist} MIST ({})", format_sui(mist))
+}
+
+/// Write the given data to the [`OUTPUT_PATH`] file if it's set. Otherwise,
+/// if [`JSON_MODE`] is enabled, output the given data as JSON.
 pub(crate) fn json_output<T: Serialize>(data: &T) -> AnyResult<(), NexusCliError> {
+    if let Some(path) = OUTPUT_PATH.get() {
+        return write_output(path, data);
+    }
+
     if !JSON_MODE.load(Ordering::Relaxed) {
         return Ok(());
     }
@@ -163,3 +292,129 @@ pub(crate) fn json_output<T: Serialize>(data: &T) -> AnyResult<(), NexusCliError
         Err(e) => Err(NexusCliError::Any(e.into())),
     }
 }
+
+/// Write the data to the file as TOML if its extension is `.toml` and as JSON
+/// otherwise, replacing any previous contents.
+pub(crate) fn write_output<T: Serialize>(path: &Path, data: &T) -> AnyResult<(), NexusCliError> {
+    let output = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("toml") => to_toml(data).map_err(NexusCliError::Any)?,
+        _ => serde_json::to_string_pretty(data).map_err(|e| NexusCliError::Any(e.into()))? + "\n",
+    };
+
+    std::fs::write(path, output).map_err(|e| {
+        NexusCliError::Any(anyhow!(
+            "Failed to write the output to {}: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// A TOML document must be a table so any other data is written as
+/// `result = ...` instead.
+fn to_toml<T: Serialize>(data: &T) -> AnyResult<String> {
+    #[derive(Serialize)]
+    struct TomlResult<'a, T> {
+        result: &'a T,
+    }
+
+    match serde_json::to_value(data)? {
+        serde_json::Value::Object(_) => Ok(toml::to_string_pretty(data)?),
+        serde_json::Value::Null => bail!("There is no output to write as TOML"),
+        _ => Ok(toml::to_string_pretty(&TomlResult { result: data })?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_sui() {
+        assert_eq!(format_sui(0), "0 SUI");
+        assert_eq!(format_sui(1_000_000_000), "1 SUI");
+        assert_eq!(format_sui(500_000_000), "0.5 SUI");
+        assert_eq!(format_sui(1_000_000_001), "1.000000001 SUI");
+        assert_eq!(format_sui(12_345_670_000), "12.34567 SUI");
+        assert_eq!(format_mist(250_000_000), "250000000 MIST (0.25 SUI)");
+    }
+
+    #[test]
+    fn test_tx_progress_hidden() {
+        let mut progress = TxProgress::with_enabled(false);
+
+        assert!(progress.is_hidden());
+
+        progress.stage(TxStage::Building);
+        progress.stage(TxStage::DryRunning);
+        progress.stage(TxStage::Signing);
+        progress.stage(TxStage::Submitting);
+        progress.stage(TxStage::Finalizing);
+        progress.success();
+
+        let json_mode = JSON_MODE.swap(true, Ordering::Relaxed);
+
+        assert!(!progress_enabled());
+        assert!(TxProgress::new().is_hidden());
+
+        JSON_MODE.store(json_mode, Ordering::Relaxed);
+    }
+
+    #[test]
+    fn test_write_output() {
+        #[derive(Serialize)]
+        struct Output {
+            digest: String,
+            checkpoint: Option<u64>,
+        }
+
+        let tempdir = tempfile::tempdir().unwrap();
+        let output = Output {
+            digest: "abc".to_string(),
+            checkpoint: None,
+        };
+
+        // `--output out.json` writes nothing but the JSON.
+        let json_path = tempdir.path().join("out.json");
+
+        write_output(&json_path, &output).unwrap();
+
+        let written = std::fs::read_to_string(&json_path).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&written).expect("Output must be valid JSON"),
+            json!({ "digest": "abc", "checkpoint": null })
+        );
+
+        // `.toml` files get TOML.
+        let toml_path = tempdir.path().join("out.toml");
+
+        write_output(&toml_path, &output).unwrap();
+
+        let written = std::fs::read_to_string(&toml_path).unwrap();
+
+        assert_eq!(
+            toml::from_str::<serde_json::Value>(&written).unwrap(),
+            json!({ "digest": "abc" })
+        );
+
+        // Top-level arrays are wrapped in a table.
+        write_output(&toml_path, &vec![output]).unwrap();
+
+        let written = std::fs::read_to_string(&toml_path).unwrap();
+
+        assert_eq!(
+            toml::from_str::<serde_json::Value>(&written).unwrap(),
+            json!({ "result": [{ "digest": "abc" }] })
+        );
+
+        write_output(&toml_path, &42).unwrap();
+
+        let written = std::fs::read_to_string(&toml_path).unwrap();
+
+        assert_eq!(written.trim(), "result = 42");
+
+        // Null can't be written as TOML at all.
+        assert!(write_output(&toml_path, &serde_json::Value::Null).is_err());
+    }
+}
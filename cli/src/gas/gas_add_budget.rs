@@ -1,5 +1,10 @@
 use {
-    crate::{command_title, display::json_output, loading, prelude::*, sui::*},
+    crate::{
+        command_title,
+        display::{json_output, TxProgress},
+        prelude::*,
+        sui::*,
+    },
     nexus_sdk::transactions::gas,
 };
 
@@ -38,18 +43,16 @@ pub(crate) async fn add_gas_budget(
     let reference_gas_price = fetch_reference_gas_price(&sui).await?;
 
     // Craft the transaction.
-    let tx_handle = loading!("Crafting transaction...");
+    let progress = TxProgress::new();
 
     let mut tx = sui::ProgrammableTransactionBuilder::new();
 
     if let Err(e) = gas::add_budget(&mut tx, objects, address.into(), &budget_coin) {
-        tx_handle.error();
+        progress.error();
 
         return Err(NexusCliError::Any(e));
     }
 
-    tx_handle.success();
-
     let tx_data = sui::TransactionData::new_programmable(
         address,
         vec![gas_coin.object_ref()],
@@ -59,7 +62,7 @@ pub(crate) async fn add_gas_budget(
     );
 
     // Sign and send the TX.
-    let response = sign_and_execute_transaction(&sui, &wallet, tx_data).await?;
+    let response = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
 
     json_output(&json!({ "digest": response.digest }))?;
 
@@ -0,0 +1,398 @@
+use {
+    crate::{
+        command_title,
+        display::{format_sui, json_output},
+        item,
+        loading,
+        notify_success,
+        prelude::*,
+        sui::*,
+    },
+    nexus_sdk::transactions::gas,
+};
+
+/// Operations whose gas cost can be estimated. Mirrors [super::GasCommand].
+#[derive(Subcommand)]
+pub(crate) enum GasOperationType {
+    #[command(about = "Estimate adding a SUI coin as gas budget")]
+    AddBudget {
+        #[arg(
+            long = "coin",
+            short = 'c',
+            help = "Owned SUI coin object ID to use as budget",
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
+        )]
+        coin: sui::ObjectID,
+    },
+
+    #[command(about = "Estimate enabling the expiry gas ticket extension")]
+    ExpiryEnable {
+        #[arg(
+            long = "tool-fqn",
+            short = 't',
+            help = "The FQN of the tool.",
+            value_name = "FQN"
+        )]
+        tool_fqn: ToolFqn,
+        #[arg(
+            long = "owner-cap",
+            short = 'o',
+            help = "The OwnerCap<OverGas> object ID that must be owned by the sender.",
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
+        )]
+        owner_cap: Option<sui::ObjectID>,
+        #[arg(
+            long = "cost-per-minute",
+            short = 'c',
+            help = "The cost per minute in MIST, or in SUI with a 'sui' suffix.",
+            value_name = "AMOUNT",
+            value_parser = ValueParser::from(parse_mist)
+        )]
+        cost_per_minute: u64,
+    },
+
+    #[command(about = "Estimate disabling the expiry gas ticket extension")]
+    ExpiryDisable {
+        #[arg(
+            long = "tool-fqn",
+            short = 't',
+            help = "The FQN of the tool.",
+            value_name = "FQN"
+        )]
+        tool_fqn: ToolFqn,
+        #[arg(
+            long = "owner-cap",
+            short = 'o',
+            help = "The OwnerCap<OverGas> object ID that must be owned by the sender.",
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
+        )]
+        owner_cap: Option<sui::ObjectID>,
+    },
+
+    #[command(about = "Estimate buying an expiry gas ticket for the specified tool")]
+    ExpiryBuyTicket {
+        #[arg(
+            long = "tool-fqn",
+            short = 't',
+            help = "The FQN of the tool.",
+            value_name = "FQN"
+        )]
+        tool_fqn: ToolFqn,
+        #[arg(
+            long = "minutes",
+            short = 'm',
+            help = "The duration of the ticket in minutes.",
+            value_name = "MINUTES"
+        )]
+        minutes: u64,
+        #[arg(
+            long = "coin",
+            short = 'c',
+            help = "Owned SUI coin object ID to use to pay for the ticket",
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
+        )]
+        coin: sui::ObjectID,
+    },
+
+    #[command(about = "Estimate enabling the limited invocations gas ticket extension")]
+    LimitedInvocationsEnable {
+        #[arg(
+            long = "tool-fqn",
+            short = 't',
+            help = "The FQN of the tool.",
+            value_name = "FQN"
+        )]
+        tool_fqn: ToolFqn,
+        #[arg(
+            long = "owner-cap",
+            short = 'o',
+            help = "The OwnerCap<OverGas> object ID that must be owned by the sender.",
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
+        )]
+        owner_cap: Option<sui::ObjectID>,
+        #[arg(
+            long = "cost-per-invocation",
+            short = 'c',
+            help = "The cost per invocation in MIST, or in SUI with a 'sui' suffix.",
+            value_name = "AMOUNT",
+            value_parser = ValueParser::from(parse_mist)
+        )]
+        cost_per_invocation: u64,
+        #[arg(
+            long = "min-invocations",
+            help = "The minimum number of invocations required for a ticket.",
+            value_name = "COUNT"
+        )]
+        min_invocations: u64,
+        #[arg(
+            long = "max-invocations",
+            help = "The maximum number of invocations allowed for a ticket.",
+            value_name = "COUNT"
+        )]
+        max_invocations: u64,
+    },
+
+    #[command(about = "Estimate disabling the limited invocations gas ticket extension")]
+    LimitedInvocationsDisable {
+        #[arg(
+            long = "tool-fqn",
+            short = 't',
+            help = "The FQN of the tool.",
+            value_name = "FQN"
+        )]
+        tool_fqn: ToolFqn,
+        #[arg(
+            long = "owner-cap",
+            short = 'o',
+            help = "The OwnerCap<OverGas> object ID that must be owned by the sender.",
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
+        )]
+        owner_cap: Option<sui::ObjectID>,
+    },
+
+    #[command(about = "Estimate buying a limited invocations gas ticket for the specified tool")]
+    LimitedInvocationsBuyTicket {
+        #[arg(
+            long = "tool-fqn",
+            short = 't',
+            help = "The FQN of the tool.",
+            value_name = "FQN"
+        )]
+        tool_fqn: ToolFqn,
+        #[arg(
+            long = "invocations",
+            short = 'i',
+            help = "The number of invocations the ticket should cover.",
+            value_name = "COUNT"
+        )]
+        invocations: u64,
+        #[arg(
+            long = "coin",
+            short = 'c',
+            help = "Owned SUI coin object ID to use to pay for the ticket",
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
+        )]
+        coin: sui::ObjectID,
+    },
+}
+
+/// Build the PTB for the given operation and dry run it to estimate its gas
+/// cost without submitting it.
+pub(crate) async fn estimate_gas(
+    operation: GasOperationType,
+    sui_gas_coin: Option<sui::ObjectID>,
+    sui_gas_budget: u64,
+) -> AnyResult<(), NexusCliError> {
+    command_title!("Estimating gas cost of the operation");
+
+    // Load CLI configuration.
+    let mut conf = CliConf::load().await.unwrap_or_default();
+
+    // Nexus objects must be present in the configuration.
+    let objects = &get_nexus_objects(&mut conf).await?;
+
+    // Create wallet context, Sui client and find the active address.
+    let mut wallet = create_wallet_context(&conf.sui.wallet_path, conf.sui.net).await?;
+    let sui = build_sui_client(&conf.sui).await?;
+    let address = wallet.active_address().map_err(NexusCliError::Any)?;
+
+    // Fetch gas coin object.
+    let gas_coin = fetch_gas_coin(&sui, address, sui_gas_coin).await?;
+
+    // Fetch reference gas price.
+    let reference_gas_price = fetch_reference_gas_price(&sui).await?;
+
+    // Craft the transaction.
+    let mut tx = sui::ProgrammableTransactionBuilder::new();
+
+    craft_operation(&mut tx, &conf, objects, &sui, address, &gas_coin, operation).await?;
+
+    let tx_data = sui::TransactionData::new_programmable(
+        address,
+        vec![gas_coin.object_ref()],
+        tx.finish(),
+        sui_gas_budget,
+        reference_gas_price,
+    );
+
+    // Dry run the TX.
+    let estimate_handle = loading!("Dry running transaction...");
+
+    let estimate = match gas::estimate_gas(&sui, tx_data).await {
+        Ok(estimate) => estimate,
+        Err(e) => {
+            estimate_handle.error();
+
+            return Err(NexusCliError::Any(e));
+        }
+    };
+
+    estimate_handle.success();
+
+    let net_cost = estimate.net_cost();
+
+    notify_success!(
+        "Estimated gas cost: {mist} MIST ({in_sui})",
+        mist = net_cost.to_string().truecolor(100, 100, 100),
+        in_sui = format!(
+            "{sign}{sui}",
+            // The storage rebate can exceed the costs.
+            sign = if net_cost < 0 { "-" } else { "" },
+            sui = format_sui(net_cost.unsigned_abs())
+        )
+        .truecolor(100, 100, 100)
+    );
+
+    item!("Computation cost: {} MIST", estimate.computation_cost);
+    item!("Storage cost: {} MIST", estimate.storage_cost);
+    item!("Storage rebate: {} MIST", estimate.storage_rebate);
+
+    json_output(&json!({
+        "computation_cost": estimate.computation_cost,
+        "storage_cost": estimate.storage_cost,
+        "storage_rebate": estimate.storage_rebate,
+        "net_cost": net_cost,
+    }))?;
+
+    Ok(())
+}
+
+/// Add the PTB commands for the given operation to `tx`.
+async fn craft_operation(
+    tx: &mut sui::ProgrammableTransactionBuilder,
+    conf: &CliConf,
+    objects: &NexusObjects,
+    sui: &sui::Client,
+    address: sui::Address,
+    gas_coin: &sui::Coin,
+    operation: GasOperationType,
+) -> AnyResult<(), NexusCliError> {
+    // Fetch the objects the operation needs and craft the TX.
+    let result = match operation {
+        GasOperationType::AddBudget { coin } => {
+            let budget_coin = fetch_object_by_id(sui, coin).await?;
+
+            if budget_coin.object_id == gas_coin.coin_object_id {
+                return Err(NexusCliError::Any(anyhow!(
+                    "Gas and budget coins must be different."
+                )));
+            }
+
+            gas::add_budget(tx, objects, address.into(), &budget_coin)
+        }
+        GasOperationType::ExpiryEnable {
+            tool_fqn,
+            owner_cap,
+            cost_per_minute,
+        } => {
+            let owner_cap = fetch_owner_cap(conf, sui, &tool_fqn, owner_cap).await?;
+
+            gas::enable_expiry(tx, objects, &tool_fqn, &owner_cap, cost_per_minute)
+        }
+        GasOperationType::ExpiryDisable {
+            tool_fqn,
+            owner_cap,
+        } => {
+            let owner_cap = fetch_owner_cap(conf, sui, &tool_fqn, owner_cap).await?;
+
+            gas::disable_expiry(tx, objects, &tool_fqn, &owner_cap)
+        }
+        GasOperationType::ExpiryBuyTicket {
+            tool_fqn,
+            minutes,
+            coin,
+        } => {
+            let pay_with = fetch_payment_coin(sui, gas_coin, coin).await?;
+
+            gas::buy_expiry_gas_ticket(tx, objects, &tool_fqn, &pay_with, minutes)
+        }
+        GasOperationType::LimitedInvocationsEnable {
+            tool_fqn,
+            owner_cap,
+            cost_per_invocation,
+            min_invocations,
+            max_invocations,
+        } => {
+            let owner_cap = fetch_owner_cap(conf, sui, &tool_fqn, owner_cap).await?;
+
+            gas::enable_limited_invocations(
+                tx,
+                objects,
+                &tool_fqn,
+                &owner_cap,
+                cost_per_invocation,
+                min_invocations,
+                max_invocations,
+            )
+        }
+        GasOperationType::LimitedInvocationsDisable {
+            tool_fqn,
+            owner_cap,
+        } => {
+            let owner_cap = fetch_owner_cap(conf, sui, &tool_fqn, owner_cap).await?;
+
+            gas::disable_limited_invocations(tx, objects, &tool_fqn, &owner_cap)
+        }
+        GasOperationType::LimitedInvocationsBuyTicket {
+            tool_fqn,
+            invocations,
+            coin,
+        } => {
+            let pay_with = fetch_payment_coin(sui, gas_coin, coin).await?;
+
+            gas::buy_limited_invocations_gas_ticket(tx, objects, &tool_fqn, &pay_with, invocations)
+        }
+    };
+
+    let tx_handle = loading!("Crafting transaction...");
+
+    if let Err(e) = result {
+        tx_handle.error();
+
+        return Err(NexusCliError::Any(e));
+    }
+
+    tx_handle.success();
+
+    Ok(())
+}
+
+/// Use the provided or saved `owner_cap` object ID and fetch the object.
+async fn fetch_owner_cap(
+    conf: &CliConf,
+    sui: &sui::Client,
+    tool_fqn: &ToolFqn,
+    owner_cap: Option<sui::ObjectID>,
+) -> AnyResult<sui::ObjectRef, NexusCliError> {
+    let Some(owner_cap) = owner_cap.or(conf.tools.get(tool_fqn).map(|t| t.over_gas)) else {
+        return Err(NexusCliError::Any(anyhow!(
+            "No OwnerCap object ID found for tool '{tool_fqn}'."
+        )));
+    };
+
+    fetch_object_by_id(sui, owner_cap).await
+}
+
+/// Fetch the coin used to pay for a ticket, making sure it's not the gas coin.
+async fn fetch_payment_coin(
+    sui: &sui::Client,
+    gas_coin: &sui::Coin,
+    coin: sui::ObjectID,
+) -> AnyResult<sui::ObjectRef, NexusCliError> {
+    let pay_with = fetch_object_by_id(sui, coin).await?;
+
+    if pay_with.object_id == gas_coin.coin_object_id {
+        return Err(NexusCliError::Any(anyhow!(
+            "Gas and payment coins must be different."
+        )));
+    }
+
+    Ok(pay_with)
+}
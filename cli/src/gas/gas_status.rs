@@ -0,0 +1,383 @@
+use {
+    super::tickets::list::{fetch_gas_tickets, GasTicket, TicketKind},
+    crate::{
+        command_title,
+        display::{format_mist, json_output},
+        item,
+        loading,
+        notify_success,
+        prelude::*,
+        sui::*,
+    },
+    nexus_sdk::object_crawler::{fetch_one, get_field_path},
+    std::collections::BTreeMap,
+};
+
+/// Field of the on-chain `GasService` that holds the gas budgets as a
+/// `Table<Scope, Balance<SUI>>`.
+const GAS_BUDGETS_FIELD: &str = "gas_budgets";
+
+/// Gas tickets of the active address for a single tool.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct ToolGasStatus {
+    tool_fqn: ToolFqn,
+    /// Tickets that can still be used to pay for invocations.
+    active_tickets: usize,
+    expired_tickets: usize,
+    /// Invocations left on all active limited invocations tickets.
+    invocations_left: u64,
+    /// The latest expiry of all active expiry tickets.
+    expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Summary of the gas budget and tickets of the active address.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct GasStatus {
+    /// Gas budget of the active address in MIST.
+    total_budget: u64,
+    active_tickets: usize,
+    expired_tickets: usize,
+    tools: Vec<ToolGasStatus>,
+}
+
+impl GasStatus {
+    /// Aggregate the tickets per tool, optionally only for `tool_fqn`. Tools
+    /// are sorted by their FQN.
+    fn from_tickets(
+        total_budget: u64,
+        tickets: Vec<GasTicket>,
+        tool_fqn: Option<&ToolFqn>,
+    ) -> Self {
+        let mut tools = BTreeMap::<String, ToolGasStatus>::new();
+
+        for ticket in tickets {
+            if tool_fqn.is_some_and(|fqn| *fqn != ticket.tool_fqn) {
+                continue;
+            }
+
+            let status =
+                tools
+                    .entry(ticket.tool_fqn.to_string())
+                    .or_insert_with(|| ToolGasStatus {
+                        tool_fqn: ticket.tool_fqn.clone(),
+                        active_tickets: 0,
+                        expired_tickets: 0,
+                        invocations_left: 0,
+                        expires_at: None,
+                    });
+
+            if ticket.expired {
+                status.expired_tickets += 1;
+
+                continue;
+            }
+
+            status.active_tickets += 1;
+
+            match ticket.kind {
+                TicketKind::Expiry => {
+                    status.expires_at = status.expires_at.max(ticket.expires_at);
+                }
+                TicketKind::LimitedInvocations => {
+                    status.invocations_left = status
+                        .invocations_left
+                        .saturating_add(ticket.invocations_left.unwrap_or_default());
+                }
+            }
+        }
+
+        let tools = tools.into_values().collect::<Vec<_>>();
+
+        Self {
+            total_budget,
+            active_tickets: tools.iter().map(|tool| tool.active_tickets).sum(),
+            expired_tickets: tools.iter().map(|tool| tool.expired_tickets).sum(),
+            tools,
+        }
+    }
+}
+
+/// Fetch the gas budget of `address` from the gas service, that is the balance
+/// of its invoker address scope. Returns 0 if the address has no budget.
+pub(crate) async fn fetch_gas_budget(
+    sui: &sui::Client,
+    gas_service: sui::ObjectID,
+    address: sui::Address,
+) -> AnyResult<u64> {
+    let service = fetch_one::<serde_json::Value>(sui, gas_service).await?;
+    let budgets = get_field_path(&service.data, &format!("{GAS_BUDGETS_FIELD}.id.id"))?;
+    let budgets = serde_json::from_value::<sui::ObjectID>(budgets)?;
+
+    let mut cursor = None;
+
+    loop {
+        let page = sui
+            .read_api()
+            .get_dynamic_fields(budgets, cursor, None)
+            .await?;
+
+        if let Some(field) = page
+            .data
+            .iter()
+            .find(|field| is_invoker_scope(&field.name.value, address))
+        {
+            let budget = fetch_one::<serde_json::Value>(sui, field.object_id).await?;
+
+            return balance_value(&get_field_path(&budget.data, "value")?)
+                .ok_or_else(|| anyhow!("Could not parse the gas budget of '{address}'"));
+        }
+
+        if !page.has_next_page {
+            return Ok(0);
+        }
+
+        cursor = page.next_cursor;
+    }
+}
+
+/// Whether the dynamic field name is the `Scope::InvokerAddress` of `address`.
+fn is_invoker_scope(name: &serde_json::Value, address: sui::Address) -> bool {
+    name["variant"] == "InvokerAddress"
+        && name["fields"]["pos0"]
+            .as_str()
+            .is_some_and(|pos0| sui::normalized_eq(pos0, &address.to_string()))
+}
+
+/// A `Balance<SUI>` is rendered either as its value or as a struct holding it.
+fn balance_value(balance: &serde_json::Value) -> Option<u64> {
+    match balance {
+        serde_json::Value::String(value) => value.parse().ok(),
+        serde_json::Value::Number(value) => value.as_u64(),
+        _ => balance_value(&get_field_path(balance, "value").ok()?),
+    }
+}
+
+/// Summarize the gas budget and the gas tickets owned by the active address
+/// per tool, optionally only for the given `tool_fqn`.
+pub(crate) async fn gas_status(tool_fqn: Option<ToolFqn>) -> AnyResult<(), NexusCliError> {
+    command_title!("Summarizing gas budget and tickets of the active address");
+
+    // Load CLI configuration.
+    let mut conf = CliConf::load().await.unwrap_or_default();
+
+    // Nexus objects must be present in the configuration.
+    let NexusObjects {
+        workflow_pkg_id,
+        gas_service,
+        ..
+    } = &get_nexus_objects(&mut conf).await?;
+
+    // Create wallet context, Sui client and find the active address.
+    let mut wallet = create_wallet_context(&conf.sui.wallet_path, conf.sui.net).await?;
+    let sui = build_sui_client(&conf.sui).await?;
+    let address = wallet.active_address().map_err(NexusCliError::Any)?;
+
+    let budget_handle = loading!("Fetching gas budget...");
+
+    let total_budget = match fetch_gas_budget(&sui, gas_service.object_id, address).await {
+        Ok(total_budget) => total_budget,
+        Err(e) => {
+            budget_handle.error();
+
+            return Err(NexusCliError::Any(e));
+        }
+    };
+
+    budget_handle.success();
+
+    let tickets_handle = loading!("Fetching gas tickets...");
+
+    let tickets = match fetch_gas_tickets(&sui, address, *workflow_pkg_id, None).await {
+        Ok(tickets) => tickets,
+        Err(e) => {
+            tickets_handle.error();
+
+            return Err(NexusCliError::Any(e));
+        }
+    };
+
+    tickets_handle.success();
+
+    let status = GasStatus::from_tickets(total_budget, tickets, tool_fqn.as_ref());
+
+    item!(
+        "Gas budget: {budget}",
+        budget = format_mist(status.total_budget).truecolor(100, 100, 100),
+    );
+
+    if status.tools.is_empty() {
+        notify_success!("Active address owns no gas tickets");
+    }
+
+    for tool in &status.tools {
+        let expires_at = tool
+            .expires_at
+            .map(|date| date.to_string())
+            .unwrap_or_else(|| "-".to_string());
+
+        item!(
+            "Tool '{fqn}' - {active} active and {expired} expired tickets, {invocations} invocations left, expires '{expires_at}'",
+            fqn = tool.tool_fqn.to_string().truecolor(100, 100, 100),
+            active = tool.active_tickets.to_string().truecolor(100, 100, 100),
+            expired = tool.expired_tickets.to_string().truecolor(100, 100, 100),
+            invocations = tool.invocations_left.to_string().truecolor(100, 100, 100),
+            expires_at = expires_at.truecolor(100, 100, 100),
+        );
+    }
+
+    if !status.tools.is_empty() {
+        notify_success!(
+            "{active} active and {expired} expired tickets in total",
+            active = status.active_tickets.to_string().truecolor(100, 100, 100),
+            expired = status.expired_tickets.to_string().truecolor(100, 100, 100),
+        );
+    }
+
+    json_output(&status)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticket(
+        tool_fqn: ToolFqn,
+        kind: TicketKind,
+        invocations_left: Option<u64>,
+        expires_at: Option<chrono::DateTime<chrono::Utc>>,
+        expired: bool,
+    ) -> GasTicket {
+        GasTicket {
+            ticket_id: sui::ObjectID::random(),
+            kind,
+            tool_fqn,
+            minutes_left: expires_at.map(|_| 0),
+            invocations_left,
+            expires_at,
+            expired,
+        }
+    }
+
+    #[test]
+    fn test_gas_status_from_tickets() {
+        let now = chrono::Utc::now();
+        let later = now + chrono::Duration::hours(2);
+        let dummy = fqn!("xyz.dummy.tool@1");
+        let other = fqn!("xyz.other.tool@1");
+
+        let tickets = vec![
+            ticket(
+                other.clone(),
+                TicketKind::LimitedInvocations,
+                Some(0),
+                None,
+                true,
+            ),
+            ticket(
+                dummy.clone(),
+                TicketKind::LimitedInvocations,
+                Some(3),
+                None,
+                false,
+            ),
+            ticket(
+                dummy.clone(),
+                TicketKind::LimitedInvocations,
+                Some(4),
+                None,
+                false,
+            ),
+            ticket(dummy.clone(), TicketKind::Expiry, None, Some(now), false),
+            ticket(dummy.clone(), TicketKind::Expiry, None, Some(later), false),
+            ticket(
+                dummy.clone(),
+                TicketKind::Expiry,
+                None,
+                Some(now - chrono::Duration::hours(1)),
+                true,
+            ),
+        ];
+
+        let status = GasStatus::from_tickets(10, tickets.clone(), None);
+
+        assert_eq!(status.total_budget, 10);
+        assert_eq!(status.active_tickets, 4);
+        assert_eq!(status.expired_tickets, 2);
+        assert_eq!(
+            status.tools,
+            vec![
+                ToolGasStatus {
+                    tool_fqn: dummy.clone(),
+                    active_tickets: 4,
+                    expired_tickets: 1,
+                    invocations_left: 7,
+                    expires_at: Some(later),
+                },
+                ToolGasStatus {
+                    tool_fqn: other.clone(),
+                    active_tickets: 0,
+                    expired_tickets: 1,
+                    invocations_left: 0,
+                    expires_at: None,
+                },
+            ]
+        );
+
+        let status = GasStatus::from_tickets(10, tickets, Some(&other));
+
+        assert_eq!(status.active_tickets, 0);
+        assert_eq!(status.expired_tickets, 1);
+        assert_eq!(status.tools.len(), 1);
+        assert_eq!(status.tools[0].tool_fqn, other);
+
+        let status = GasStatus::from_tickets(0, vec![], None);
+
+        assert_eq!(
+            serde_json::to_value(&status).unwrap(),
+            json!({ "total_budget": 0, "active_tickets": 0, "expired_tickets": 0, "tools": [] })
+        );
+    }
+
+    #[test]
+    fn test_invoker_scope_budget() {
+        let address = sui::Address::random_for_testing_only();
+        let scope = |variant: &str, address: &sui::Address| {
+            json!({
+                "type": "0x1::gas::Scope",
+                "variant": variant,
+                "fields": { "pos0": address.to_string() },
+            })
+        };
+
+        assert!(is_invoker_scope(
+            &scope("InvokerAddress", &address),
+            address
+        ));
+        assert!(!is_invoker_scope(&scope("Execution", &address), address));
+
+        // Addresses rendered without padding still match.
+        let short = sui::parse_address("0xab").unwrap();
+        let unpadded = json!({
+            "variant": "InvokerAddress",
+            "fields": { "pos0": "0xAB" },
+        });
+
+        assert!(is_invoker_scope(&unpadded, short));
+        assert!(!is_invoker_scope(
+            &scope("InvokerAddress", &sui::Address::random_for_testing_only()),
+            address
+        ));
+
+        assert_eq!(balance_value(&json!("1000")), Some(1000));
+        assert_eq!(balance_value(&json!(1000)), Some(1000));
+        assert_eq!(
+            balance_value(
+                &json!({ "type": "0x2::balance::Balance<0x2::sui::SUI>", "fields": { "value": "1000" } })
+            ),
+            Some(1000)
+        );
+        assert_eq!(balance_value(&json!({ "other": "1000" })), None);
+    }
+}
@@ -1,10 +1,14 @@
 mod gas_add_budget;
+mod gas_estimate;
+mod gas_status;
 mod tickets;
 
 use {
     crate::prelude::*,
     gas_add_budget::*,
-    tickets::{expiry::*, limited_invocations::*},
+    gas_estimate::*,
+    gas_status::*,
+    tickets::{buy_many::*, expiry::*, limited_invocations::*, list::*},
 };
 
 #[derive(Subcommand)]
@@ -15,13 +19,36 @@ pub(crate) enum GasCommand {
             long = "coin",
             short = 'c',
             help = "Owned SUI coin object ID to use as budget",
-            value_name = "OBJECT_ID"
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
         )]
         coin: sui::ObjectID,
         #[command(flatten)]
         gas: GasArgs,
     },
 
+    #[command(about = "Buy the gas tickets listed in a TOML manifest in one transaction")]
+    BuyTickets {
+        #[arg(
+            long = "manifest",
+            short = 'm',
+            help = "Path to a TOML file with a [[tickets]] table per ticket",
+            value_name = "PATH",
+            value_parser = ValueParser::from(expand_tilde)
+        )]
+        manifest: PathBuf,
+        #[command(flatten)]
+        gas: GasArgs,
+    },
+
+    #[command(about = "Estimate the gas cost of an operation without submitting it")]
+    Estimate {
+        #[command(subcommand)]
+        operation: GasOperationType,
+        #[command(flatten)]
+        gas: GasArgs,
+    },
+
     #[command(subcommand, about = "Manage the expiry gas ticket extension")]
     Expiry(ExpiryCommand),
 
@@ -30,6 +57,28 @@ pub(crate) enum GasCommand {
         about = "Manage the limited invocations gas ticket extension"
     )]
     LimitedInvocations(LimitedInvocationsCommand),
+
+    #[command(about = "List gas tickets owned by the active address")]
+    ListTickets {
+        #[arg(
+            long = "kind",
+            short = 'k',
+            help = "Only list gas tickets of this kind.",
+            value_name = "KIND"
+        )]
+        kind: Option<TicketKind>,
+    },
+
+    #[command(about = "Summarize the gas tickets owned by the active address per tool")]
+    Status {
+        #[arg(
+            long = "tool-fqn",
+            short = 't',
+            help = "Only summarize gas tickets for this tool.",
+            value_name = "FQN"
+        )]
+        tool_fqn: Option<ToolFqn>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -47,14 +96,16 @@ pub(crate) enum ExpiryCommand {
             long = "owner-cap",
             short = 'o',
             help = "The OwnerCap<OverGas> object ID that must be owned by the sender.",
-            value_name = "OBJECT_ID"
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
         )]
         owner_cap: Option<sui::ObjectID>,
         #[arg(
             long = "cost-per-minute",
             short = 'c',
-            help = "The cost per minute in MIST.",
-            value_name = "MIST"
+            help = "The cost per minute in MIST, or in SUI with a 'sui' suffix.",
+            value_name = "AMOUNT",
+            value_parser = ValueParser::from(parse_mist)
         )]
         cost_per_minute: u64,
         #[command(flatten)]
@@ -74,7 +125,8 @@ pub(crate) enum ExpiryCommand {
             long = "owner-cap",
             short = 'o',
             help = "The OwnerCap<OverGas> object ID that must be owned by the sender.",
-            value_name = "OBJECT_ID"
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
         )]
         owner_cap: Option<sui::ObjectID>,
         #[command(flatten)]
@@ -101,7 +153,8 @@ pub(crate) enum ExpiryCommand {
             long = "coin",
             short = 'c',
             help = "Owned SUI coin object ID to use to pay for the ticket",
-            value_name = "OBJECT_ID"
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
         )]
         coin: sui::ObjectID,
         #[command(flatten)]
@@ -124,14 +177,16 @@ pub(crate) enum LimitedInvocationsCommand {
             long = "owner-cap",
             short = 'o',
             help = "The OwnerCap<OverGas> object ID that must be owned by the sender.",
-            value_name = "OBJECT_ID"
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
         )]
         owner_cap: Option<sui::ObjectID>,
         #[arg(
             long = "cost-per-invocation",
             short = 'c',
-            help = "The cost per invocation in MIST.",
-            value_name = "MIST"
+            help = "The cost per invocation in MIST, or in SUI with a 'sui' suffix.",
+            value_name = "AMOUNT",
+            value_parser = ValueParser::from(parse_mist)
         )]
         cost_per_invocation: u64,
         #[arg(
@@ -163,7 +218,8 @@ pub(crate) enum LimitedInvocationsCommand {
             long = "owner-cap",
             short = 'o',
             help = "The OwnerCap<OverGas> object ID that must be owned by the sender.",
-            value_name = "OBJECT_ID"
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
         )]
         owner_cap: Option<sui::ObjectID>,
         #[command(flatten)]
@@ -190,7 +246,8 @@ pub(crate) enum LimitedInvocationsCommand {
             long = "coin",
             short = 'c',
             help = "Owned SUI coin object ID to use to pay for the ticket",
-            value_name = "OBJECT_ID"
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
         )]
         coin: sui::ObjectID,
         #[command(flatten)]
@@ -207,6 +264,16 @@ pub(crate) async fn handle(command: GasCommand) -> AnyResult<(), NexusCliError>
             add_gas_budget(coin, gas.sui_gas_coin, gas.sui_gas_budget).await
         }
 
+        // == `$ nexus gas buy-tickets` ==
+        GasCommand::BuyTickets { manifest, gas } => {
+            buy_gas_tickets(manifest, gas.sui_gas_coin, gas.sui_gas_budget).await
+        }
+
+        // == `$ nexus gas estimate` ==
+        GasCommand::Estimate { operation, gas } => {
+            estimate_gas(operation, gas.sui_gas_coin, gas.sui_gas_budget).await
+        }
+
         // == `$ nexus gas expiry` ==
         GasCommand::Expiry(command) => match command {
             // == `$ nexus gas expiry enable` ==
@@ -309,5 +376,11 @@ pub(crate) async fn handle(command: GasCommand) -> AnyResult<(), NexusCliError>
                 .await
             }
         },
+
+        // == `$ nexus gas list-tickets` ==
+        GasCommand::ListTickets { kind } => list_gas_tickets(kind).await,
+
+        // == `$ nexus gas status` ==
+        GasCommand::Status { tool_fqn } => gas_status(tool_fqn).await,
     }
 }
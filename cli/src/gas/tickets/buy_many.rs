@@ -0,0 +1,259 @@
+use {
+    crate::{
+        command_title,
+        display::{json_output, TxProgress},
+        item,
+        notify_success,
+        prelude::*,
+        sui::*,
+    },
+    nexus_sdk::transactions::gas::{self, GasTicketPurchase, GasTicketPurchaseKind},
+};
+
+/// A manifest is an array of tables where each table is one ticket.
+///
+/// ```toml
+/// [[tickets]]
+/// tool_fqn = "xyz.dummy.tool@1"
+/// minutes = 60
+/// coin = "0x1"
+/// amount = 6000
+///
+/// [[tickets]]
+/// tool_fqn = "xyz.other.tool@1"
+/// invocations = 10
+/// coin = "0x1"
+/// amount = 500
+/// ```
+///
+/// Either `minutes` or `invocations` must be set. `amount` is the MIST split
+/// off `coin` for the ticket and is required if multiple tickets are paid with
+/// the same coin.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TicketManifest {
+    tickets: Vec<TicketManifestEntry>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct TicketManifestEntry {
+    tool_fqn: ToolFqn,
+    #[serde(default)]
+    minutes: Option<u64>,
+    #[serde(default)]
+    invocations: Option<u64>,
+    coin: sui::ObjectID,
+    #[serde(default)]
+    amount: Option<u64>,
+}
+
+impl TicketManifestEntry {
+    fn kind(&self) -> AnyResult<GasTicketPurchaseKind> {
+        match (self.minutes, self.invocations) {
+            (Some(minutes), None) => Ok(GasTicketPurchaseKind::Expiry { minutes }),
+            (None, Some(invocations)) => {
+                Ok(GasTicketPurchaseKind::LimitedInvocations { invocations })
+            }
+            _ => bail!("Exactly one of 'minutes' and 'invocations' must be set"),
+        }
+    }
+}
+
+/// Parse the manifest and check that every entry describes one ticket.
+fn parse_manifest(content: &str) -> AnyResult<Vec<(TicketManifestEntry, GasTicketPurchaseKind)>> {
+    let manifest = toml::from_str::<TicketManifest>(content)
+        .map_err(|e| anyhow!("Failed to parse ticket manifest: {e}"))?;
+
+    if manifest.tickets.is_empty() {
+        bail!("Ticket manifest contains no tickets");
+    }
+
+    manifest
+        .tickets
+        .into_iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let kind = entry
+                .kind()
+                .map_err(|e| anyhow!("Ticket #{}: {e}", index + 1))?;
+
+            Ok((entry, kind))
+        })
+        .collect()
+}
+
+/// Buy all gas tickets listed in the TOML manifest at `manifest` in a single
+/// transaction.
+pub(crate) async fn buy_gas_tickets(
+    manifest: PathBuf,
+    sui_gas_coin: Option<sui::ObjectID>,
+    sui_gas_budget: u64,
+) -> AnyResult<(), NexusCliError> {
+    command_title!("Buying gas tickets from '{}'", manifest.display());
+
+    let content = tokio::fs::read_to_string(&manifest)
+        .await
+        .map_err(NexusCliError::Io)?;
+
+    let entries = parse_manifest(&content).map_err(NexusCliError::Any)?;
+
+    // Load CLI configuration.
+    let mut conf = CliConf::load().await.unwrap_or_default();
+
+    // Nexus objects must be present in the configuration.
+    let objects = &get_nexus_objects(&mut conf).await?;
+
+    // Create wallet context, Sui client and find the active address.
+    let mut wallet = create_wallet_context(&conf.sui.wallet_path, conf.sui.net).await?;
+    let sui = build_sui_client(&conf.sui).await?;
+    let address = wallet.active_address().map_err(NexusCliError::Any)?;
+
+    // Fetch gas coin object.
+    let gas_coin = fetch_gas_coin(&sui, address, sui_gas_coin).await?;
+
+    // Fetch every coin to pay for the tickets with once.
+    let mut coins = HashMap::new();
+
+    for (entry, _) in &entries {
+        if coins.contains_key(&entry.coin) {
+            continue;
+        }
+
+        if entry.coin == gas_coin.coin_object_id {
+            return Err(NexusCliError::Any(anyhow!(
+                "Gas and payment coins must be different."
+            )));
+        }
+
+        coins.insert(entry.coin, fetch_object_by_id(&sui, entry.coin).await?);
+    }
+
+    // Fetch reference gas price.
+    let reference_gas_price = fetch_reference_gas_price(&sui).await?;
+
+    // Craft the transaction.
+    let progress = TxProgress::new();
+
+    let purchases = entries
+        .iter()
+        .map(|(entry, kind)| GasTicketPurchase {
+            tool_fqn: entry.tool_fqn.clone(),
+            kind: *kind,
+            pay_with: coins[&entry.coin].clone(),
+            amount: entry.amount,
+        })
+        .collect::<Vec<_>>();
+
+    let mut tx = sui::ProgrammableTransactionBuilder::new();
+
+    if let Err(e) = gas::buy_gas_tickets(&mut tx, objects, &purchases) {
+        progress.error();
+
+        return Err(NexusCliError::Any(e));
+    };
+
+    let tx_data = sui::TransactionData::new_programmable(
+        address,
+        vec![gas_coin.object_ref()],
+        tx.finish(),
+        sui_gas_budget,
+        reference_gas_price,
+    );
+
+    // Sign and send the TX. All tickets are bought or none are.
+    let response = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
+
+    notify_success!("Bought {} gas tickets", entries.len());
+
+    for (entry, kind) in &entries {
+        let ticket = match kind {
+            GasTicketPurchaseKind::Expiry { minutes } => format!("{minutes} minutes"),
+            GasTicketPurchaseKind::LimitedInvocations { invocations } => {
+                format!("{invocations} invocations")
+            }
+        };
+
+        item!(
+            "Ticket for tool '{fqn}' - {ticket}, paid with '{coin}'",
+            fqn = entry.tool_fqn.to_string().truecolor(100, 100, 100),
+            ticket = ticket.truecolor(100, 100, 100),
+            coin = entry.coin.to_string().truecolor(100, 100, 100),
+        );
+    }
+
+    json_output(&json!({
+        "digest": response.digest,
+        "tickets": entries.iter().map(|(entry, _)| entry).collect::<Vec<_>>(),
+    }))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let entries = parse_manifest(
+            r#"
+            [[tickets]]
+            tool_fqn = "xyz.dummy.tool@1"
+            minutes = 60
+            coin = "0x1"
+            amount = 6000
+
+            [[tickets]]
+            tool_fqn = "xyz.other.tool@1"
+            invocations = 10
+            coin = "0x1"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].0.tool_fqn, fqn!("xyz.dummy.tool@1"));
+        assert_eq!(entries[0].0.amount, Some(6000));
+        assert_eq!(entries[0].1, GasTicketPurchaseKind::Expiry { minutes: 60 });
+        assert_eq!(entries[1].0.coin, sui::ObjectID::from_single_byte(1));
+        assert_eq!(entries[1].0.amount, None);
+        assert_eq!(
+            entries[1].1,
+            GasTicketPurchaseKind::LimitedInvocations { invocations: 10 }
+        );
+    }
+
+    #[test]
+    fn test_parse_manifest_invalid() {
+        let err = parse_manifest(
+            r#"
+            [[tickets]]
+            tool_fqn = "xyz.dummy.tool@1"
+            minutes = 60
+            coin = "0x1"
+
+            [[tickets]]
+            tool_fqn = "xyz.other.tool@1"
+            minutes = 60
+            invocations = 10
+            coin = "0x1"
+            "#,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Ticket #2: Exactly one of 'minutes' and 'invocations' must be set"
+        );
+
+        assert!(parse_manifest("tickets = []")
+            .unwrap_err()
+            .to_string()
+            .contains("no tickets"));
+        assert!(parse_manifest("[[tickets]]\nunknown = 1")
+            .unwrap_err()
+            .to_string()
+            .contains("Failed to parse ticket manifest"));
+    }
+}
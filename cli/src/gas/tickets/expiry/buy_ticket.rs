@@ -1,5 +1,10 @@
 use {
-    crate::{command_title, display::json_output, loading, prelude::*, sui::*},
+    crate::{
+        command_title,
+        display::{json_output, TxProgress},
+        prelude::*,
+        sui::*,
+    },
     nexus_sdk::transactions::gas,
 };
 
@@ -41,19 +46,17 @@ pub(crate) async fn buy_expiry_gas_ticket(
     let reference_gas_price = fetch_reference_gas_price(&sui).await?;
 
     // Craft the transaction.
-    let tx_handle = loading!("Crafting transaction...");
+    let progress = TxProgress::new();
 
     let mut tx = sui::ProgrammableTransactionBuilder::new();
 
     if let Err(e) = gas::buy_expiry_gas_ticket(&mut tx, objects, &tool_fqn, &pay_with_coin, minutes)
     {
-        tx_handle.error();
+        progress.error();
 
         return Err(NexusCliError::Any(e));
     };
 
-    tx_handle.success();
-
     let tx_data = sui::TransactionData::new_programmable(
         address,
         vec![gas_coin.object_ref()],
@@ -63,7 +66,7 @@ pub(crate) async fn buy_expiry_gas_ticket(
     );
 
     // Sign and send the TX.
-    let response = sign_and_execute_transaction(&sui, &wallet, tx_data).await?;
+    let response = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
 
     json_output(&json!({ "digest": response.digest }))?;
 
@@ -1,5 +1,11 @@
 use {
-    crate::{command_title, display::json_output, loading, prelude::*, sui::*},
+    crate::{
+        command_title,
+        display::{format_mist, json_output, TxProgress},
+        loading,
+        prelude::*,
+        sui::*,
+    },
     nexus_sdk::transactions::gas,
 };
 
@@ -11,7 +17,10 @@ pub(crate) async fn enable_expiry_extension(
     sui_gas_coin: Option<sui::ObjectID>,
     sui_gas_budget: u64,
 ) -> AnyResult<(), NexusCliError> {
-    command_title!("Enabling the expiry gas extension for tool '{tool_fqn}' with cost '{cost_per_minute}' MIST per minute");
+    command_title!(
+        "Enabling the expiry gas extension for tool '{tool_fqn}' with cost '{cost}' per minute",
+        cost = format_mist(cost_per_minute)
+    );
 
     // Load CLI configuration.
     let mut conf = CliConf::load().await.unwrap_or_default();
@@ -40,18 +49,16 @@ pub(crate) async fn enable_expiry_extension(
     let reference_gas_price = fetch_reference_gas_price(&sui).await?;
 
     // Craft the transaction.
-    let tx_handle = loading!("Crafting transaction...");
+    let progress = TxProgress::new();
 
     let mut tx = sui::ProgrammableTransactionBuilder::new();
 
     if let Err(e) = gas::enable_expiry(&mut tx, objects, &tool_fqn, &owner_cap, cost_per_minute) {
-        tx_handle.error();
+        progress.error();
 
         return Err(NexusCliError::Any(e));
     };
 
-    tx_handle.success();
-
     let tx_data = sui::TransactionData::new_programmable(
         address,
         vec![gas_coin.object_ref()],
@@ -61,7 +68,7 @@ pub(crate) async fn enable_expiry_extension(
     );
 
     // Sign and send the TX.
-    let response = sign_and_execute_transaction(&sui, &wallet, tx_data).await?;
+    let response = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
 
     json_output(&json!({ "digest": response.digest }))?;
 
@@ -1,5 +1,11 @@
 use {
-    crate::{command_title, display::json_output, loading, prelude::*, sui::*},
+    crate::{
+        command_title,
+        display::{format_mist, json_output, TxProgress},
+        loading,
+        prelude::*,
+        sui::*,
+    },
     nexus_sdk::transactions::gas,
 };
 
@@ -14,7 +20,7 @@ pub(crate) async fn enable_limited_invocations_extension(
     sui_gas_coin: Option<sui::ObjectID>,
     sui_gas_budget: u64,
 ) -> AnyResult<(), NexusCliError> {
-    command_title!("Enabling the limited invocations gas extension for tool '{tool_fqn}' with cost '{cost_per_invocation}' MIST per invocation (min: {min_invocations}, max: {max_invocations})");
+    command_title!("Enabling the limited invocations gas extension for tool '{tool_fqn}' with cost '{cost}' per invocation (min: {min_invocations}, max: {max_invocations})", cost = format_mist(cost_per_invocation));
 
     // Load CLI configuration.
     let mut conf = CliConf::load().await.unwrap_or_default();
@@ -43,7 +49,7 @@ pub(crate) async fn enable_limited_invocations_extension(
     let reference_gas_price = fetch_reference_gas_price(&sui).await?;
 
     // Craft the transaction.
-    let tx_handle = loading!("Crafting transaction...");
+    let progress = TxProgress::new();
 
     let mut tx = sui::ProgrammableTransactionBuilder::new();
 
@@ -56,13 +62,11 @@ pub(crate) async fn enable_limited_invocations_extension(
         min_invocations,
         max_invocations,
     ) {
-        tx_handle.error();
+        progress.error();
 
         return Err(NexusCliError::Any(e));
     };
 
-    tx_handle.success();
-
     let tx_data = sui::TransactionData::new_programmable(
         address,
         vec![gas_coin.object_ref()],
@@ -72,7 +76,7 @@ pub(crate) async fn enable_limited_invocations_extension(
     );
 
     // Sign and send the TX.
-    let response = sign_and_execute_transaction(&sui, &wallet, tx_data).await?;
+    let response = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
 
     json_output(&json!({ "digest": response.digest }))?;
 
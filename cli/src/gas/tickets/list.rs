@@ -0,0 +1,326 @@
+use {
+    crate::{
+        command_title,
+        display::json_output,
+        item,
+        loading,
+        notify_success,
+        prelude::*,
+        sui::*,
+    },
+    nexus_sdk::{
+        idents::workflow,
+        object_crawler::{fetch_many, Response, Structure},
+        types::{deserialize_string_to_datetime, deserialize_sui_u64},
+    },
+    serde::de::DeserializeOwned,
+};
+
+/// Kinds of gas tickets that can be bought for a tool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum TicketKind {
+    Expiry,
+    LimitedInvocations,
+}
+
+/// The parts of the on-chain `ExpiryGasTicket` object we care about.
+#[derive(Clone, Debug, Deserialize)]
+struct ExpiryGasTicket {
+    tool_fqn: ToolFqn,
+    #[serde(deserialize_with = "deserialize_string_to_datetime")]
+    expires_at_ms: chrono::DateTime<chrono::Utc>,
+}
+
+/// The parts of the on-chain `LimitedInvocationsGasTicket` object we care
+/// about.
+#[derive(Clone, Debug, Deserialize)]
+struct LimitedInvocationsGasTicket {
+    tool_fqn: ToolFqn,
+    #[serde(deserialize_with = "deserialize_sui_u64")]
+    invocations_left: u64,
+}
+
+/// A gas ticket of either kind as it is displayed to the user.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct GasTicket {
+    pub(crate) ticket_id: sui::ObjectID,
+    pub(crate) kind: TicketKind,
+    pub(crate) tool_fqn: ToolFqn,
+    pub(crate) minutes_left: Option<u64>,
+    pub(crate) invocations_left: Option<u64>,
+    pub(crate) expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub(crate) expired: bool,
+}
+
+impl GasTicket {
+    fn from_expiry(
+        ticket_id: sui::ObjectID,
+        ticket: ExpiryGasTicket,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        let minutes_left = (ticket.expires_at_ms - now).num_minutes().max(0) as u64;
+
+        Self {
+            ticket_id,
+            kind: TicketKind::Expiry,
+            tool_fqn: ticket.tool_fqn,
+            minutes_left: Some(minutes_left),
+            invocations_left: None,
+            expires_at: Some(ticket.expires_at_ms),
+            expired: ticket.expires_at_ms <= now,
+        }
+    }
+
+    fn from_limited_invocations(
+        ticket_id: sui::ObjectID,
+        ticket: LimitedInvocationsGasTicket,
+    ) -> Self {
+        Self {
+            ticket_id,
+            kind: TicketKind::LimitedInvocations,
+            tool_fqn: ticket.tool_fqn,
+            minutes_left: None,
+            invocations_left: Some(ticket.invocations_left),
+            expires_at: None,
+            expired: ticket.invocations_left == 0,
+        }
+    }
+
+    /// Human readable remaining value of the ticket.
+    fn remaining(&self) -> String {
+        match (self.minutes_left, self.invocations_left) {
+            (Some(minutes), _) => format!("{minutes} minutes left"),
+            (_, Some(invocations)) => format!("{invocations} invocations left"),
+            _ => "unknown".to_string(),
+        }
+    }
+}
+
+/// List gas tickets owned by the active address, optionally only of the
+/// given `kind`.
+pub(crate) async fn list_gas_tickets(kind: Option<TicketKind>) -> AnyResult<(), NexusCliError> {
+    command_title!("Listing gas tickets of the active address");
+
+    // Load CLI configuration.
+    let mut conf = CliConf::load().await.unwrap_or_default();
+
+    // Nexus objects must be present in the configuration.
+    let NexusObjects {
+        workflow_pkg_id, ..
+    } = &get_nexus_objects(&mut conf).await?;
+
+    // Create wallet context, Sui client and find the active address.
+    let mut wallet = create_wallet_context(&conf.sui.wallet_path, conf.sui.net).await?;
+    let sui = build_sui_client(&conf.sui).await?;
+    let address = wallet.active_address().map_err(NexusCliError::Any)?;
+
+    let tickets_handle = loading!("Fetching gas tickets...");
+
+    let tickets = match fetch_gas_tickets(&sui, address, *workflow_pkg_id, kind).await {
+        Ok(tickets) => tickets,
+        Err(e) => {
+            tickets_handle.error();
+
+            return Err(NexusCliError::Any(e));
+        }
+    };
+
+    tickets_handle.success();
+
+    if tickets.is_empty() {
+        notify_success!("Active address owns no gas tickets");
+    }
+
+    for ticket in &tickets {
+        // Expired tickets are labeled.
+        let label = match ticket.expired {
+            true => format!("{} ", "[EXPIRED]".red().bold()),
+            false => String::new(),
+        };
+
+        let expires_at = ticket
+            .expires_at
+            .map(|date| date.to_string())
+            .unwrap_or_else(|| "never".to_string());
+
+        item!(
+            "{label}Ticket '{id}' for tool '{fqn}' - {remaining}, expires '{expires_at}'",
+            id = ticket.ticket_id.to_string().truecolor(100, 100, 100),
+            fqn = ticket.tool_fqn.to_string().truecolor(100, 100, 100),
+            remaining = ticket.remaining().truecolor(100, 100, 100),
+            expires_at = expires_at.truecolor(100, 100, 100),
+        );
+    }
+
+    json_output(&tickets)?;
+
+    Ok(())
+}
+
+/// Fetch the gas tickets owned by `address`, optionally only of the given
+/// `kind`.
+pub(crate) async fn fetch_gas_tickets(
+    sui: &sui::Client,
+    address: sui::Address,
+    workflow_pkg_id: sui::ObjectID,
+    kind: Option<TicketKind>,
+) -> AnyResult<Vec<GasTicket>> {
+    let now = chrono::Utc::now();
+    let mut tickets = Vec::new();
+
+    if kind.is_none_or(|kind| kind == TicketKind::Expiry) {
+        let owned = fetch_owned::<ExpiryGasTicket>(
+            sui,
+            address,
+            workflow_pkg_id,
+            workflow::GasExtension::EXPIRY_GAS_TICKET,
+        )
+        .await?;
+
+        tickets.extend(
+            owned
+                .into_iter()
+                .map(|t| GasTicket::from_expiry(t.id, t.data.into_inner(), now)),
+        );
+    }
+
+    if kind.is_none_or(|kind| kind == TicketKind::LimitedInvocations) {
+        let owned = fetch_owned::<LimitedInvocationsGasTicket>(
+            sui,
+            address,
+            workflow_pkg_id,
+            workflow::GasExtension::LIMITED_INVOCATIONS_GAS_TICKET,
+        )
+        .await?;
+
+        tickets.extend(
+            owned
+                .into_iter()
+                .map(|t| GasTicket::from_limited_invocations(t.id, t.data.into_inner())),
+        );
+    }
+
+    Ok(tickets)
+}
+
+/// Fetch all objects of the given type owned by `address`.
+async fn fetch_owned<T: DeserializeOwned>(
+    sui: &sui::Client,
+    address: sui::Address,
+    workflow_pkg_id: sui::ObjectID,
+    ident: nexus_sdk::idents::ModuleAndNameIdent,
+) -> AnyResult<Vec<Response<Structure<T>>>> {
+    let sui::MoveTypeTag::Struct(struct_tag) = workflow::into_type_tag(workflow_pkg_id, ident)
+    else {
+        unreachable!("Type tag is always a struct");
+    };
+
+    let query =
+        sui::ObjectResponseQuery::new(Some(sui::ObjectDataFilter::StructType(*struct_tag)), None);
+
+    let limit = None;
+    let mut cursor = None;
+    let mut object_ids = Vec::new();
+
+    // Keep fetching objects until there are no more pages.
+    loop {
+        let response = sui
+            .read_api()
+            .get_owned_objects(address, Some(query.clone()), cursor, limit)
+            .await?;
+
+        cursor = response.next_cursor;
+        object_ids.extend(response.data.iter().filter_map(|r| r.object_id().ok()));
+
+        if !response.has_next_page {
+            break;
+        }
+    }
+
+    if object_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    fetch_many(sui, object_ids).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gas_ticket_from_expiry() {
+        let now = chrono::Utc::now();
+        let ticket_id = sui::ObjectID::random();
+
+        let ticket = GasTicket::from_expiry(
+            ticket_id,
+            ExpiryGasTicket {
+                tool_fqn: fqn!("xyz.dummy.tool@1"),
+                expires_at_ms: now + chrono::Duration::seconds(90 * 60 + 30),
+            },
+            now,
+        );
+
+        assert_eq!(ticket.kind, TicketKind::Expiry);
+        assert_eq!(ticket.minutes_left, Some(90));
+        assert_eq!(ticket.invocations_left, None);
+        assert!(!ticket.expired);
+        assert_eq!(ticket.remaining(), "90 minutes left");
+
+        let ticket = GasTicket::from_expiry(
+            ticket_id,
+            ExpiryGasTicket {
+                tool_fqn: fqn!("xyz.dummy.tool@1"),
+                expires_at_ms: now - chrono::Duration::minutes(5),
+            },
+            now,
+        );
+
+        assert_eq!(ticket.minutes_left, Some(0));
+        assert!(ticket.expired);
+    }
+
+    #[test]
+    fn test_gas_ticket_from_limited_invocations() {
+        let ticket_id = sui::ObjectID::random();
+
+        let ticket = GasTicket::from_limited_invocations(
+            ticket_id,
+            serde_json::from_value(json!({
+                "tool_fqn": "xyz.dummy.tool@1",
+                "invocations_left": "3"
+            }))
+            .unwrap(),
+        );
+
+        assert_eq!(ticket.kind, TicketKind::LimitedInvocations);
+        assert_eq!(ticket.invocations_left, Some(3));
+        assert!(!ticket.expired);
+        assert_eq!(ticket.remaining(), "3 invocations left");
+
+        assert_eq!(
+            serde_json::to_value(&ticket).unwrap(),
+            json!({
+                "ticket_id": ticket_id,
+                "kind": "limited_invocations",
+                "tool_fqn": "xyz.dummy.tool@1",
+                "minutes_left": null,
+                "invocations_left": 3,
+                "expires_at": null,
+                "expired": false
+            })
+        );
+
+        let ticket = GasTicket::from_limited_invocations(
+            ticket_id,
+            LimitedInvocationsGasTicket {
+                tool_fqn: fqn!("xyz.dummy.tool@1"),
+                invocations_left: 0,
+            },
+        );
+
+        assert!(ticket.expired);
+    }
+}
@@ -1,2 +1,4 @@
+pub(crate) mod buy_many;
 pub(crate) mod expiry;
 pub(crate) mod limited_invocations;
+pub(crate) mod list;
@@ -1,3 +1,5 @@
+mod audit;
+mod batch;
 mod completion;
 mod conf;
 mod crypto;
@@ -23,6 +25,48 @@ struct Cli {
         help = "Change the output format to JSON"
     )]
     json: bool,
+    /// Which configuration profile to use.
+    #[arg(
+        global = true,
+        long = "profile",
+        help = "Use the values of a configuration profile",
+        value_name = "NAME"
+    )]
+    profile: Option<String>,
+    /// Where to write the structured output.
+    #[arg(
+        global = true,
+        long = "output",
+        help = "Write the structured output to a file, as TOML if it ends with .toml and as JSON otherwise",
+        value_name = "FILE",
+        value_parser = ValueParser::from(expand_tilde)
+    )]
+    output: Option<PathBuf>,
+    /// Whether to wait for submitted transactions to be checkpointed.
+    #[arg(
+        global = true,
+        long = "wait-for-finality",
+        help = "Wait for submitted transactions to be checkpointed before returning (default)",
+        overrides_with = "no_wait"
+    )]
+    wait_for_finality: bool,
+    /// Whether to return as soon as transactions are executed.
+    #[arg(
+        global = true,
+        long = "no-wait",
+        help = "Return as soon as submitted transactions are executed without waiting for them to be checkpointed",
+        overrides_with = "wait_for_finality"
+    )]
+    no_wait: bool,
+    /// How long to wait for submitted transactions to be checkpointed.
+    #[arg(
+        global = true,
+        long = "finality-timeout",
+        help = "How many seconds to wait for submitted transactions to be checkpointed",
+        value_name = "SECONDS",
+        default_value_t = DEFAULT_FINALITY_TIMEOUT_SECS
+    )]
+    finality_timeout: u64,
     #[command(subcommand)]
     command: Command,
 }
@@ -43,6 +87,29 @@ enum Command {
     Crypto(crypto::CryptoCommand),
     #[command(about = "Provide shell completions")]
     Completion(completion::CompletionCommand),
+    #[command(about = "Run a sequence of Nexus CLI commands from a TOML file")]
+    Batch {
+        /// The path to the TOML file containing the commands.
+        #[arg(
+            long = "file",
+            short = 'f',
+            help = "The path to the TOML file containing the commands",
+            value_parser = ValueParser::from(expand_tilde)
+        )]
+        file: PathBuf,
+        /// Whether to only print the commands.
+        #[arg(
+            long = "dry-run",
+            help = "Print the commands that would be executed without running them"
+        )]
+        dry_run: bool,
+        /// Whether to keep going after a command fails.
+        #[arg(
+            long = "continue-on-error",
+            help = "Run the remaining commands even if one of them fails"
+        )]
+        continue_on_error: bool,
+    },
 }
 
 #[tokio::main]
@@ -74,8 +141,52 @@ async fn main() {
 
     JSON_MODE.store(cli.json, Ordering::Relaxed);
 
-    // Send each sub-command to the respective handler.
-    let result = match cli.command {
+    audit::set_command_args(std::env::args().skip(1));
+
+    if let Some(profile) = cli.profile {
+        ACTIVE_PROFILE
+            .set(profile)
+            .expect("Profile must only be set once");
+    }
+
+    if let Some(output) = cli.output {
+        OUTPUT_PATH
+            .set(output)
+            .expect("Output path must only be set once");
+    }
+
+    // The last of `--wait-for-finality` and `--no-wait` wins.
+    let wait_for_finality = cli.wait_for_finality || !cli.no_wait;
+
+    FINALITY_TIMEOUT
+        .set(wait_for_finality.then(|| std::time::Duration::from_secs(cli.finality_timeout)))
+        .expect("Finality timeout must only be set once");
+
+    // Make sure the selected profile exists. Conf commands manage profiles
+    // themselves.
+    if !matches!(cli.command, Command::Conf(_)) {
+        if let Err(e) = CliConf::check_profile().await {
+            eprintln!(
+                "\n{ballot} {e}",
+                ballot = "X".red().bold(),
+                e = NexusCliError::Any(e)
+            );
+
+            std::process::exit(1);
+        }
+    }
+
+    // Handle any errors that occurred during command execution.
+    if let Err(e) = run(cli.command).await {
+        eprintln!("\n{ballot} {e}", ballot = "X".red().bold());
+
+        std::process::exit(1);
+    }
+}
+
+/// Send each sub-command to the respective handler.
+async fn run(command: Command) -> AnyResult<(), NexusCliError> {
+    match command {
         Command::Tool(tool) => tool::handle(tool).await,
         Command::Conf(conf) => conf::handle(conf).await,
         Command::Dag(dag) => dag::handle(dag).await,
@@ -83,12 +194,10 @@ async fn main() {
         Command::Gas(gas) => gas::handle(gas).await,
         Command::Crypto(crypto) => crypto::handle(crypto).await,
         Command::Completion(completion) => completion::handle(completion),
-    };
-
-    // Handle any errors that occurred during command execution.
-    if let Err(e) = result {
-        eprintln!("\n{ballot} {e}", ballot = "X".red().bold());
-
-        std::process::exit(1);
+        Command::Batch {
+            file,
+            dry_run,
+            continue_on_error,
+        } => batch::run_batch(file, dry_run, continue_on_error).await,
     }
 }
@@ -1,6 +1,15 @@
 mod network_create;
+mod network_leader_cap_list;
+mod network_leader_cap_transfer;
+mod network_list;
 
-use {crate::prelude::*, network_create::*};
+use {
+    crate::prelude::*,
+    network_create::*,
+    network_leader_cap_list::*,
+    network_leader_cap_transfer::*,
+    network_list::*,
+};
 
 #[derive(Subcommand)]
 pub(crate) enum NetworkCommand {
@@ -14,7 +23,8 @@ pub(crate) enum NetworkCommand {
             short = 'a',
             help = "Space separated list of addresses to assign leader caps to",
             num_args = 0..,
-            value_name = "ADDRESSES"
+            value_name = "ADDRESSES",
+            value_parser = ValueParser::from(sui::parse_object_id)
         )]
         addresses: Vec<sui::ObjectID>,
         /// How many leader caps to assign to each address
@@ -29,6 +39,46 @@ pub(crate) enum NetworkCommand {
         #[command(flatten)]
         gas: GasArgs,
     },
+    #[command(about = "List all Nexus networks the active address holds leader caps for")]
+    List,
+    #[command(subcommand, about = "Manage leader caps")]
+    LeaderCap(LeaderCapCommand),
+}
+
+#[derive(Subcommand)]
+pub(crate) enum LeaderCapCommand {
+    #[command(about = "List the leader caps held by an address")]
+    List {
+        #[arg(
+            long = "address",
+            short = 'a',
+            help = "The address to list leader caps for, defaults to the active address",
+            value_name = "ADDRESS",
+            value_parser = ValueParser::from(sui::parse_address)
+        )]
+        address: Option<sui::Address>,
+    },
+    #[command(about = "Transfer a leader cap of the active address to another address")]
+    Transfer {
+        #[arg(
+            long = "cap",
+            short = 'c',
+            help = "The leader cap object ID to transfer",
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
+        )]
+        cap: sui::ObjectID,
+        #[arg(
+            long = "to",
+            short = 't',
+            help = "The address to transfer the leader cap to",
+            value_name = "ADDRESS",
+            value_parser = ValueParser::from(sui::parse_address)
+        )]
+        to: sui::Address,
+        #[command(flatten)]
+        gas: GasArgs,
+    },
 }
 
 /// Handle the provided network command. The [NetworkCommand] instance is passed
@@ -49,5 +99,19 @@ pub(crate) async fn handle(command: NetworkCommand) -> AnyResult<(), NexusCliErr
             )
             .await
         }
+
+        // == `$ nexus network list` ==
+        NetworkCommand::List => list_networks().await,
+
+        // == `$ nexus network leader-cap` ==
+        NetworkCommand::LeaderCap(command) => match command {
+            // == `$ nexus network leader-cap list` ==
+            LeaderCapCommand::List { address } => list_leader_caps(address).await,
+
+            // == `$ nexus network leader-cap transfer` ==
+            LeaderCapCommand::Transfer { cap, to, gas } => {
+                transfer_leader_cap(cap, to, gas.sui_gas_coin, gas.sui_gas_budget).await
+            }
+        },
     }
 }
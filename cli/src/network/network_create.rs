@@ -1,5 +1,11 @@
 use {
-    crate::{command_title, display::json_output, loading, notify_success, prelude::*, sui::*},
+    crate::{
+        command_title,
+        display::{json_output, TxProgress},
+        notify_success,
+        prelude::*,
+        sui::*,
+    },
     nexus_sdk::{
         events::{NexusEvent, NexusEventKind},
         idents::workflow,
@@ -39,12 +45,12 @@ pub(crate) async fn create_network(
     let reference_gas_price = fetch_reference_gas_price(&sui).await?;
 
     // Craft a TX to create a new network.
-    let tx_handle = loading!("Crafting transaction...");
+    let progress = TxProgress::new();
 
     let addresses = match serde_json::to_value(addresses).map(sui::SuiJsonValue::new) {
         Ok(Ok(addrs)) => addrs,
         _ => {
-            tx_handle.error();
+            progress.error();
 
             return Err(NexusCliError::Any(anyhow!("Failed to serialize addresses")));
         }
@@ -53,7 +59,7 @@ pub(crate) async fn create_network(
     let count_leader_caps = match sui::SuiJsonValue::new(count_leader_caps.to_string().into()) {
         Ok(count) => count,
         Err(e) => {
-            tx_handle.error();
+            progress.error();
 
             return Err(NexusCliError::Any(e));
         }
@@ -80,16 +86,14 @@ pub(crate) async fn create_network(
     {
         Ok(tx_data) => tx_data,
         Err(e) => {
-            tx_handle.error();
+            progress.error();
 
             return Err(NexusCliError::Any(e));
         }
     };
 
-    tx_handle.success();
-
     // Sign the transaction and send it to the network.
-    let response = sign_and_execute_transaction(&sui, &wallet, tx_data).await?;
+    let response = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
 
     // Parse network ID from the response.
     let Some(events) = response.events else {
@@ -0,0 +1,149 @@
+use {
+    super::network_list::{fetch_leader_caps, OwnedLeaderCap},
+    crate::{
+        command_title,
+        display::json_output,
+        item,
+        loading,
+        notify_success,
+        prelude::*,
+        sui::*,
+    },
+};
+
+/// Leader caps held by a single address.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+struct LeaderCapHolder {
+    holder: sui::Address,
+    cap_count: usize,
+    leader_caps: Vec<OwnedLeaderCap>,
+}
+
+impl LeaderCapHolder {
+    /// Caps are sorted by their network and then by their ID.
+    fn new(holder: sui::Address, mut leader_caps: Vec<OwnedLeaderCap>) -> Self {
+        leader_caps.sort_by_key(|cap| (cap.network_id, cap.leader_cap_id));
+
+        Self {
+            holder,
+            cap_count: leader_caps.len(),
+            leader_caps,
+        }
+    }
+}
+
+/// List the leader caps held by `address`, or by the active address if not
+/// provided.
+pub(crate) async fn list_leader_caps(
+    address: Option<sui::Address>,
+) -> AnyResult<(), NexusCliError> {
+    // Load CLI configuration.
+    let mut conf = CliConf::load().await.unwrap_or_default();
+
+    // Nexus objects must be present in the configuration.
+    let NexusObjects {
+        workflow_pkg_id,
+        primitives_pkg_id,
+        ..
+    } = &get_nexus_objects(&mut conf).await?;
+
+    let sui = build_sui_client(&conf.sui).await?;
+
+    let holder = match address {
+        Some(address) => address,
+        None => {
+            let mut wallet = create_wallet_context(&conf.sui.wallet_path, conf.sui.net).await?;
+
+            wallet.active_address().map_err(NexusCliError::Any)?
+        }
+    };
+
+    command_title!("Listing leader caps held by '{holder}'");
+
+    let caps_handle = loading!("Fetching leader caps...");
+
+    let caps = match fetch_leader_caps(&sui, *workflow_pkg_id, *primitives_pkg_id, holder).await {
+        Ok(caps) => caps,
+        Err(e) => {
+            caps_handle.error();
+
+            return Err(NexusCliError::Any(e));
+        }
+    };
+
+    caps_handle.success();
+
+    let holder = LeaderCapHolder::new(holder, caps);
+
+    if holder.leader_caps.is_empty() {
+        notify_success!("Address holds no leader caps");
+    } else {
+        notify_success!(
+            "Address holds {cap_count} leader caps",
+            cap_count = holder.cap_count.to_string().truecolor(100, 100, 100)
+        );
+    }
+
+    for cap in &holder.leader_caps {
+        item!(
+            "Leader cap '{leader_cap_id}' for network '{network_id}'",
+            leader_cap_id = cap.leader_cap_id.to_string().truecolor(100, 100, 100),
+            network_id = cap.network_id.to_string().truecolor(100, 100, 100),
+        );
+    }
+
+    json_output(&holder)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::network::network_list::LastUsed};
+
+    #[test]
+    fn test_leader_cap_holder() {
+        let holder = sui::Address::random_for_testing_only();
+        let network_a = sui::ObjectID::from_hex_literal("0xa").unwrap();
+        let network_b = sui::ObjectID::from_hex_literal("0xb").unwrap();
+        let [cap_1, cap_2, cap_3] = [
+            sui::ObjectID::from_hex_literal("0x1").unwrap(),
+            sui::ObjectID::from_hex_literal("0x2").unwrap(),
+            sui::ObjectID::from_hex_literal("0x3").unwrap(),
+        ];
+
+        let cap = |leader_cap_id, network_id| OwnedLeaderCap {
+            leader_cap_id,
+            network_id,
+            last_used: LastUsed::At(10),
+        };
+
+        let listed = LeaderCapHolder::new(
+            holder,
+            vec![
+                cap(cap_3, network_a),
+                cap(cap_1, network_b),
+                cap(cap_2, network_a),
+            ],
+        );
+
+        assert_eq!(listed.cap_count, 3);
+        assert_eq!(
+            serde_json::to_value(&listed).unwrap(),
+            json!({
+                "holder": holder,
+                "cap_count": 3,
+                "leader_caps": [
+                    { "leader_cap_id": cap_2, "network_id": network_a },
+                    { "leader_cap_id": cap_3, "network_id": network_a },
+                    { "leader_cap_id": cap_1, "network_id": network_b },
+                ],
+            })
+        );
+
+        let empty = LeaderCapHolder::new(holder, vec![]);
+
+        assert_eq!(empty.cap_count, 0);
+        assert!(empty.leader_caps.is_empty());
+    }
+}
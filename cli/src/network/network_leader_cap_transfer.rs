@@ -0,0 +1,150 @@
+use {
+    super::network_list::leader_cap_type,
+    crate::{
+        command_title,
+        display::{json_output, TxProgress},
+        loading,
+        prelude::*,
+        sui::*,
+    },
+    nexus_sdk::transactions::network,
+};
+
+/// Transfer the leader cap `cap` of the active address to `to`.
+pub(crate) async fn transfer_leader_cap(
+    cap: sui::ObjectID,
+    to: sui::Address,
+    sui_gas_coin: Option<sui::ObjectID>,
+    sui_gas_budget: u64,
+) -> AnyResult<(), NexusCliError> {
+    command_title!("Transferring leader cap '{cap}' to '{to}'");
+
+    // Load CLI configuration.
+    let mut conf = CliConf::load().await.unwrap_or_default();
+
+    // Nexus objects must be present in the configuration.
+    let NexusObjects {
+        workflow_pkg_id,
+        primitives_pkg_id,
+        ..
+    } = &get_nexus_objects(&mut conf).await?;
+
+    // Create wallet context, Sui client and find the active address.
+    let mut wallet = create_wallet_context(&conf.sui.wallet_path, conf.sui.net).await?;
+    let sui = build_sui_client(&conf.sui).await?;
+    let address = wallet.active_address().map_err(NexusCliError::Any)?;
+
+    // Fetch gas coin object.
+    let gas_coin = fetch_gas_coin(&sui, address, sui_gas_coin).await?;
+
+    // Fetch the leader cap and make sure it is one before transferring it.
+    let leader_cap_handle = loading!("Fetching leader cap {cap}...");
+
+    let expected = leader_cap_type(*workflow_pkg_id, *primitives_pkg_id);
+
+    let leader_cap = match fetch_leader_cap(&sui, cap, &expected).await {
+        Ok(leader_cap) => leader_cap,
+        Err(e) => {
+            leader_cap_handle.error();
+
+            return Err(NexusCliError::Any(e));
+        }
+    };
+
+    leader_cap_handle.success();
+
+    // Fetch reference gas price.
+    let reference_gas_price = fetch_reference_gas_price(&sui).await?;
+
+    // Craft the transaction.
+    let progress = TxProgress::new();
+
+    let mut tx = sui::ProgrammableTransactionBuilder::new();
+
+    if let Err(e) = network::transfer_leader_cap(&mut tx, &leader_cap, to) {
+        progress.error();
+
+        return Err(NexusCliError::Any(e));
+    }
+
+    let tx_data = sui::TransactionData::new_programmable(
+        address,
+        vec![gas_coin.object_ref()],
+        tx.finish(),
+        sui_gas_budget,
+        reference_gas_price,
+    );
+
+    // Sign and send the TX.
+    let response = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
+
+    json_output(&json!({ "digest": response.digest }))?;
+
+    Ok(())
+}
+
+/// Fetch the object `cap` and check that its type is `expected`.
+async fn fetch_leader_cap(
+    sui: &sui::Client,
+    cap: sui::ObjectID,
+    expected: &sui::MoveStructTag,
+) -> AnyResult<sui::ObjectRef> {
+    let options = sui::ObjectDataOptions::new().with_type();
+
+    let response = sui.read_api().get_object_with_options(cap, options).await?;
+
+    let Some(data) = response.data else {
+        bail!("Could not fetch object '{cap}'");
+    };
+
+    check_leader_cap_type(cap, data.type_.as_ref(), expected)?;
+
+    Ok(data.object_ref())
+}
+
+/// Only `CloneableOwnerCap<OverNetwork>` objects can be transferred as leader
+/// caps.
+fn check_leader_cap_type(
+    cap: sui::ObjectID,
+    found: Option<&sui::ObjectType>,
+    expected: &sui::MoveStructTag,
+) -> AnyResult<()> {
+    match found {
+        Some(sui::ObjectType::Struct(found))
+            if sui::MoveStructTag::from(found.clone()) == *expected =>
+        {
+            Ok(())
+        }
+        Some(found) => bail!("Object '{cap}' is a '{found}' and not a leader cap '{expected}'"),
+        None => bail!("Object '{cap}' is not a leader cap '{expected}'"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, nexus_sdk::idents::workflow};
+
+    #[test]
+    fn test_check_leader_cap_type() {
+        let cap = sui::ObjectID::random();
+        let workflow_pkg_id = sui::ObjectID::random();
+        let primitives_pkg_id = sui::ObjectID::random();
+        let expected = leader_cap_type(workflow_pkg_id, primitives_pkg_id);
+
+        let found = sui::ObjectType::Struct(expected.clone().into());
+
+        assert!(check_leader_cap_type(cap, Some(&found), &expected).is_ok());
+
+        // A cap over anything else than the network is rejected.
+        let mut other = expected.clone();
+        other.type_params = vec![workflow::into_type_tag(
+            workflow_pkg_id,
+            workflow::Gas::OVER_GAS,
+        )];
+        let found = sui::ObjectType::Struct(other.into());
+
+        assert!(check_leader_cap_type(cap, Some(&found), &expected).is_err());
+        assert!(check_leader_cap_type(cap, Some(&sui::ObjectType::Package), &expected).is_err());
+        assert!(check_leader_cap_type(cap, None, &expected).is_err());
+    }
+}
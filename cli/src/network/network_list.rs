@@ -0,0 +1,329 @@
+use {
+    crate::{
+        command_title,
+        display::json_output,
+        item,
+        loading,
+        notify_success,
+        prelude::*,
+        sui::*,
+    },
+    nexus_sdk::{
+        idents::{primitives, workflow},
+        object_crawler::{fetch_many, Structure},
+    },
+    std::collections::BTreeMap,
+};
+
+/// Maximum number of digests a `multi_get_transactions_with_options` call
+/// accepts.
+const MULTI_GET_TRANSACTIONS_LIMIT: usize = 50;
+
+/// The parts of the on-chain leader cap object we care about.
+#[derive(Clone, Debug, Deserialize)]
+struct LeaderCap {
+    network_id: sui::ObjectID,
+}
+
+/// Leader caps of the active address grouped by their network.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+struct NetworkLeaderCaps {
+    network_id: sui::ObjectID,
+    leader_cap_ids: Vec<sui::ObjectID>,
+    cap_count: usize,
+    /// When any of the caps was last used.
+    #[serde(skip)]
+    last_used: LastUsed,
+}
+
+/// When a leader cap was last used. Ordered so that the latest use of a group
+/// of caps is their maximum and a failed lookup is never hidden.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum LastUsed {
+    /// The cap has no previous transaction or it has no timestamp.
+    #[default]
+    Unknown,
+    /// Timestamp of the last transaction that used the cap.
+    At(u64),
+    /// The last transaction that used the cap could not be fetched.
+    LookupFailed(String),
+}
+
+impl std::fmt::Display for LastUsed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LastUsed::At(ms) => {
+                match chrono::DateTime::<chrono::Utc>::from_timestamp_millis(*ms as i64) {
+                    Some(date) => write!(f, "{date}"),
+                    None => write!(f, "unknown"),
+                }
+            }
+            LastUsed::Unknown => write!(f, "unknown"),
+            LastUsed::LookupFailed(e) => write!(f, "lookup failed: {e}"),
+        }
+    }
+}
+
+/// List all Nexus networks the active address holds leader caps for.
+pub(crate) async fn list_networks() -> AnyResult<(), NexusCliError> {
+    command_title!("Listing Nexus networks of the active address");
+
+    // Load CLI configuration.
+    let mut conf = CliConf::load().await.unwrap_or_default();
+
+    // Nexus objects must be present in the configuration.
+    let NexusObjects {
+        workflow_pkg_id,
+        primitives_pkg_id,
+        ..
+    } = &get_nexus_objects(&mut conf).await?;
+
+    // Create wallet context, Sui client and find the active address.
+    let mut wallet = create_wallet_context(&conf.sui.wallet_path, conf.sui.net).await?;
+    let sui = build_sui_client(&conf.sui).await?;
+    let address = wallet.active_address().map_err(NexusCliError::Any)?;
+
+    let caps_handle = loading!("Fetching leader caps...");
+
+    let caps = match fetch_leader_caps(&sui, *workflow_pkg_id, *primitives_pkg_id, address).await {
+        Ok(caps) => caps,
+        Err(e) => {
+            caps_handle.error();
+
+            return Err(NexusCliError::Any(e));
+        }
+    };
+
+    caps_handle.success();
+
+    let networks = group_by_network(caps);
+
+    if networks.is_empty() {
+        notify_success!("Active address holds no leader caps");
+    }
+
+    for network in &networks {
+        let last_used = network.last_used.to_string();
+
+        item!(
+            "Network '{network_id}' with {cap_count} leader caps, last used '{last_used}'",
+            network_id = network.network_id.to_string().truecolor(100, 100, 100),
+            cap_count = network.cap_count.to_string().truecolor(100, 100, 100),
+            last_used = last_used.truecolor(100, 100, 100),
+        );
+    }
+
+    json_output(&networks)?;
+
+    Ok(())
+}
+
+/// A leader cap owned by an address.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct OwnedLeaderCap {
+    pub(crate) leader_cap_id: sui::ObjectID,
+    pub(crate) network_id: sui::ObjectID,
+    /// When the cap was last used.
+    #[serde(skip)]
+    pub(crate) last_used: LastUsed,
+}
+
+/// The `CloneableOwnerCap<OverNetwork>` type of leader caps.
+pub(crate) fn leader_cap_type(
+    workflow_pkg_id: sui::ObjectID,
+    primitives_pkg_id: sui::ObjectID,
+) -> sui::MoveStructTag {
+    sui::MoveStructTag {
+        address: *primitives_pkg_id,
+        module: primitives::OwnerCap::CLONEABLE_OWNER_CAP.module.into(),
+        name: primitives::OwnerCap::CLONEABLE_OWNER_CAP.name.into(),
+        type_params: vec![workflow::into_type_tag(
+            workflow_pkg_id,
+            workflow::LeaderCap::OVER_NETWORK,
+        )],
+    }
+}
+
+/// Fetch all leader caps owned by `address` together with when they were
+/// last used.
+pub(crate) async fn fetch_leader_caps(
+    sui: &sui::Client,
+    workflow_pkg_id: sui::ObjectID,
+    primitives_pkg_id: sui::ObjectID,
+    address: sui::Address,
+) -> AnyResult<Vec<OwnedLeaderCap>> {
+    let leader_cap_type = leader_cap_type(workflow_pkg_id, primitives_pkg_id);
+
+    let query = sui::ObjectResponseQuery::new(
+        Some(sui::ObjectDataFilter::StructType(leader_cap_type)),
+        Some(sui::ObjectDataOptions::new().with_previous_transaction()),
+    );
+
+    let limit = None;
+    let mut cursor = None;
+    let mut owned = Vec::new();
+
+    // Keep fetching leader caps until there are no more pages.
+    loop {
+        let response = sui
+            .read_api()
+            .get_owned_objects(address, Some(query.clone()), cursor, limit)
+            .await?;
+
+        cursor = response.next_cursor;
+        owned.extend(response.data.into_iter().filter_map(|r| r.data));
+
+        if !response.has_next_page {
+            break;
+        }
+    }
+
+    if owned.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let caps =
+        fetch_many::<Structure<LeaderCap>>(sui, owned.iter().map(|data| data.object_id).collect())
+            .await?;
+
+    // Find out when each cap was last used. This is best effort as the
+    // cap is still listed if the lookup fails.
+    let digests = owned
+        .iter()
+        .filter_map(|data| data.previous_transaction)
+        .collect::<Vec<_>>();
+
+    let mut by_digest = HashMap::new();
+
+    for chunk in digests.chunks(MULTI_GET_TRANSACTIONS_LIMIT) {
+        match sui
+            .read_api()
+            .multi_get_transactions_with_options(
+                chunk.to_vec(),
+                sui::TransactionBlockResponseOptions::new(),
+            )
+            .await
+        {
+            Ok(txs) => by_digest.extend(txs.into_iter().map(|tx| {
+                let last_used = tx.timestamp_ms.map_or(LastUsed::Unknown, LastUsed::At);
+
+                (tx.digest, last_used)
+            })),
+            Err(e) => by_digest.extend(
+                chunk
+                    .iter()
+                    .map(|digest| (*digest, LastUsed::LookupFailed(e.to_string()))),
+            ),
+        }
+    }
+
+    let last_used = owned
+        .iter()
+        .filter_map(|data| {
+            let last_used = by_digest.get(&data.previous_transaction?)?;
+
+            Some((data.object_id, last_used.clone()))
+        })
+        .collect::<HashMap<_, _>>();
+
+    Ok(caps
+        .into_iter()
+        .map(|cap| OwnedLeaderCap {
+            leader_cap_id: cap.id,
+            network_id: cap.data.into_inner().network_id,
+            last_used: last_used.get(&cap.id).cloned().unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// Group leader caps by their network. Networks are sorted by their ID.
+fn group_by_network(caps: Vec<OwnedLeaderCap>) -> Vec<NetworkLeaderCaps> {
+    let mut networks = BTreeMap::<sui::ObjectID, NetworkLeaderCaps>::new();
+
+    for cap in caps {
+        let network = networks
+            .entry(cap.network_id)
+            .or_insert_with(|| NetworkLeaderCaps {
+                network_id: cap.network_id,
+                leader_cap_ids: vec![],
+                cap_count: 0,
+                last_used: LastUsed::Unknown,
+            });
+
+        network.leader_cap_ids.push(cap.leader_cap_id);
+        network.cap_count += 1;
+        network.last_used = network.last_used.clone().max(cap.last_used);
+    }
+
+    networks.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_by_network() {
+        let network_a = sui::ObjectID::from_hex_literal("0xa").unwrap();
+        let network_b = sui::ObjectID::from_hex_literal("0xb").unwrap();
+        let [cap_1, cap_2, cap_3] = [
+            sui::ObjectID::random(),
+            sui::ObjectID::random(),
+            sui::ObjectID::random(),
+        ];
+
+        let cap = |leader_cap_id, network_id, last_used| OwnedLeaderCap {
+            leader_cap_id,
+            network_id,
+            last_used,
+        };
+
+        let networks = group_by_network(vec![
+            cap(cap_1, network_b, LastUsed::Unknown),
+            cap(cap_2, network_a, LastUsed::At(10)),
+            cap(cap_3, network_b, LastUsed::At(20)),
+        ]);
+
+        assert_eq!(
+            networks,
+            vec![
+                NetworkLeaderCaps {
+                    network_id: network_a,
+                    leader_cap_ids: vec![cap_2],
+                    cap_count: 1,
+                    last_used: LastUsed::At(10),
+                },
+                NetworkLeaderCaps {
+                    network_id: network_b,
+                    leader_cap_ids: vec![cap_1, cap_3],
+                    cap_count: 2,
+                    last_used: LastUsed::At(20),
+                },
+            ]
+        );
+
+        assert_eq!(
+            serde_json::to_value(&networks[0]).unwrap(),
+            json!({
+                "network_id": network_a,
+                "leader_cap_ids": [cap_2],
+                "cap_count": 1,
+            })
+        );
+
+        assert!(group_by_network(vec![]).is_empty());
+    }
+
+    #[test]
+    fn test_last_used() {
+        let failed = LastUsed::LookupFailed("timeout".to_string());
+
+        // A failed lookup is never hidden by a known timestamp.
+        assert_eq!(LastUsed::At(20).max(failed.clone()), failed);
+        assert_eq!(LastUsed::Unknown.max(LastUsed::At(10)), LastUsed::At(10));
+
+        assert_eq!(LastUsed::Unknown.to_string(), "unknown");
+        assert_eq!(LastUsed::At(0).to_string(), "1970-01-01 00:00:00 UTC");
+        assert_eq!(failed.to_string(), "lookup failed: timeout");
+    }
+}
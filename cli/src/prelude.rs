@@ -14,13 +14,22 @@ pub(crate) use {
     std::{
         collections::HashMap,
         path::{Path, PathBuf},
-        sync::atomic::{AtomicBool, Ordering},
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            OnceLock,
+        },
     },
 };
 
 /// Where to find config file.
 pub(crate) const CLI_CONF_PATH: &str = "~/.nexus/conf.toml";
 
+/// Environment variables that override the configuration when it's loaded,
+/// without changing the file.
+pub(crate) const SUI_NET_ENV: &str = "NEXUS_SUI_NET";
+pub(crate) const SUI_RPC_URL_ENV: &str = "NEXUS_SUI_RPC_URL";
+pub(crate) const NEXUS_OBJECTS_PATH_ENV: &str = "NEXUS_OBJECTS_PATH";
+
 /// objects.toml locations for each network.
 pub(crate) const DEVNET_OBJECTS_TOML: &str =
     "https://storage.googleapis.com/production-talus-sui-packages/objects.devnet.toml";
@@ -54,14 +63,40 @@ pub(crate) struct CliConf {
     pub(crate) nexus: Option<NexusObjects>,
     #[serde(default)]
     pub(crate) tools: HashMap<ToolFqn, ToolOwnerCaps>,
+    #[serde(default, deserialize_with = "deserialize_crypto_conf")]
     pub(crate) crypto: Option<Secret<CryptoConf>>,
+    /// File that every submitted transaction is appended to as a JSON line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) audit_log: Option<PathBuf>,
+    /// Domains that tools may be validated and registered with.
+    #[serde(default, skip_serializing_if = "NamespacePolicy::is_empty")]
+    pub(crate) tool_namespaces: NamespacePolicy,
+    /// Profile to use when no `--profile` flag is passed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) profile: Option<String>,
+    /// Named overrides of the `sui` and `nexus` sections.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub(crate) profiles: HashMap<String, ProfileConf>,
+    /// Base values replaced by the applied profile, if any.
+    #[serde(skip)]
+    pub(crate) applied_profile: Option<AppliedProfile>,
+    /// Values replaced by environment variables, if any.
+    #[serde(skip)]
+    pub(crate) applied_env: Option<AppliedEnv>,
 }
 
 impl CliConf {
+    /// Load the configuration and apply the overrides of the selected profile
+    /// and of the environment, in that order.
     pub(crate) async fn load() -> AnyResult<Self> {
         let conf_path = expand_tilde(CLI_CONF_PATH)?;
 
-        Self::load_from_path(&conf_path).await
+        let mut conf = Self::load_from_path(&conf_path).await?;
+
+        conf.apply_profile()?;
+        conf.apply_env().await?;
+
+        Ok(conf)
     }
 
     pub(crate) async fn load_from_path(path: &PathBuf) -> AnyResult<Self> {
@@ -78,13 +113,308 @@ impl CliConf {
 
     pub(crate) async fn save_to_path(&self, path: &PathBuf) -> AnyResult<()> {
         let parent_folder = path.parent().expect("Parent folder must exist.");
-        let conf = toml::to_string_pretty(&self)?;
+        let conf = self.to_toml_string()?;
 
         tokio::fs::create_dir_all(parent_folder).await?;
         tokio::fs::write(path, conf).await?;
 
         Ok(())
     }
+
+    /// Make sure that the profile selected with `--profile` or the `profile`
+    /// field exists.
+    pub(crate) async fn check_profile() -> AnyResult<()> {
+        let conf_path = expand_tilde(CLI_CONF_PATH)?;
+        let conf = Self::load_from_path(&conf_path).await.unwrap_or_default();
+
+        match conf.selected_profile() {
+            Some(name) if !conf.profiles.contains_key(name) => {
+                bail!("Profile '{name}' does not exist in the configuration")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Name of the profile selected with `--profile`, falling back to the
+    /// `profile` field.
+    pub(crate) fn selected_profile(&self) -> Option<&str> {
+        ACTIVE_PROFILE
+            .get()
+            .or(self.profile.as_ref())
+            .map(String::as_str)
+    }
+
+    /// Override the `sui` and `nexus` sections with the values of the
+    /// selected profile.
+    pub(crate) fn apply_profile(&mut self) -> AnyResult<()> {
+        let Some(name) = self.selected_profile().map(str::to_string) else {
+            return Ok(());
+        };
+
+        let Some(profile) = self.profiles.get(&name).cloned() else {
+            bail!("Profile '{name}' does not exist in the configuration");
+        };
+
+        self.applied_profile = Some(AppliedProfile {
+            name,
+            sui: self.sui.clone(),
+            nexus: self.nexus.clone(),
+        });
+
+        if let Some(net) = profile.sui.net {
+            self.sui.net = net;
+        }
+
+        if let Some(wallet_path) = profile.sui.wallet_path {
+            self.sui.wallet_path = wallet_path;
+        }
+
+        if let Some(rpc_url) = profile.sui.rpc_url {
+            self.sui.rpc_url = Some(rpc_url);
+        }
+
+        if let Some(nexus) = profile.nexus {
+            self.nexus = Some(nexus);
+        }
+
+        Ok(())
+    }
+
+    /// Override the `sui.net`, `sui.rpc_url` and `nexus` values with those of
+    /// the [`SUI_NET_ENV`], [`SUI_RPC_URL_ENV`] and [`NEXUS_OBJECTS_PATH_ENV`]
+    /// environment variables. Empty variables are ignored.
+    pub(crate) async fn apply_env(&mut self) -> AnyResult<()> {
+        let env_var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+        let sui = self.sui.clone();
+        let nexus = self.nexus.clone();
+        let mut vars = vec![];
+
+        if let Some(net) = env_var(SUI_NET_ENV) {
+            self.sui.net = SuiNet::from_str(&net, true)
+                .map_err(|e| anyhow!("Invalid {SUI_NET_ENV} '{net}': {e}"))?;
+
+            vars.push(SUI_NET_ENV);
+        }
+
+        if let Some(rpc_url) = env_var(SUI_RPC_URL_ENV) {
+            let rpc_url = reqwest::Url::parse(&rpc_url)
+                .map_err(|e| anyhow!("Invalid {SUI_RPC_URL_ENV} '{rpc_url}': {e}"))?;
+
+            self.sui.rpc_url = Some(rpc_url);
+
+            vars.push(SUI_RPC_URL_ENV);
+        }
+
+        if let Some(path) = env_var(NEXUS_OBJECTS_PATH_ENV) {
+            let path = expand_tilde(&path)?;
+            let objects = tokio::fs::read_to_string(&path)
+                .await
+                .map_err(|e| anyhow!("Failed to read {}: {e}", path.display()))?;
+
+            self.nexus = Some(
+                toml::from_str(&objects)
+                    .map_err(|e| anyhow!("Failed to parse {}: {e}", path.display()))?,
+            );
+
+            vars.push(NEXUS_OBJECTS_PATH_ENV);
+        }
+
+        if !vars.is_empty() {
+            self.applied_env = Some(AppliedEnv {
+                vars,
+                sui,
+                nexus,
+                overridden_sui: self.sui.clone(),
+                overridden_nexus: self.nexus.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Where the current value of each setting that can be overridden comes
+    /// from: the environment, the applied profile or the file.
+    pub(crate) fn value_sources(&self) -> Vec<(&'static str, String)> {
+        let profile = self
+            .applied_profile
+            .as_ref()
+            .and_then(|applied| Some((&applied.name, self.profiles.get(&applied.name)?)));
+
+        let source = |var: Option<&'static str>, in_profile: fn(&ProfileConf) -> bool| {
+            let from_env = |var| {
+                self.applied_env
+                    .as_ref()
+                    .is_some_and(|env| env.vars.contains(&var))
+            };
+
+            match (var, profile) {
+                (Some(var), _) if from_env(var) => format!("environment ({var})"),
+                (_, Some((name, conf))) if in_profile(conf) => format!("profile '{name}'"),
+                _ => "file".to_string(),
+            }
+        };
+
+        vec![
+            (
+                "sui.net",
+                source(Some(SUI_NET_ENV), |p| p.sui.net.is_some()),
+            ),
+            (
+                "sui.wallet_path",
+                source(None, |p| p.sui.wallet_path.is_some()),
+            ),
+            (
+                "sui.rpc_url",
+                source(Some(SUI_RPC_URL_ENV), |p| p.sui.rpc_url.is_some()),
+            ),
+            (
+                "nexus",
+                source(Some(NEXUS_OBJECTS_PATH_ENV), |p| p.nexus.is_some()),
+            ),
+        ]
+    }
+
+    /// The `sui` and `nexus` sections without the values set by environment
+    /// variables. Values changed since they were applied are kept.
+    fn without_env(&self) -> (SuiConf, Option<NexusObjects>) {
+        let Some(env) = &self.applied_env else {
+            return (self.sui.clone(), self.nexus.clone());
+        };
+
+        fn pick<T: Clone + PartialEq>(current: &T, overridden: &T, base: &T) -> T {
+            if current == overridden {
+                base.clone()
+            } else {
+                current.clone()
+            }
+        }
+
+        let sui = SuiConf {
+            net: pick(&self.sui.net, &env.overridden_sui.net, &env.sui.net),
+            wallet_path: pick(
+                &self.sui.wallet_path,
+                &env.overridden_sui.wallet_path,
+                &env.sui.wallet_path,
+            ),
+            rpc_url: pick(
+                &self.sui.rpc_url,
+                &env.overridden_sui.rpc_url,
+                &env.sui.rpc_url,
+            ),
+        };
+        let nexus = pick(&self.nexus, &env.overridden_nexus, &env.nexus);
+
+        (sui, nexus)
+    }
+
+    /// Serialize the configuration. Values set by environment variables are
+    /// not saved. If a profile was applied, the base values are restored and
+    /// any changes to them are saved to the profile instead.
+    fn to_toml_string(&self) -> AnyResult<String> {
+        if self.applied_profile.is_none() && self.applied_env.is_none() {
+            return Ok(toml::to_string_pretty(self)?);
+        }
+
+        let (sui, nexus) = self.without_env();
+
+        let mut value = toml::Value::try_from(self)?;
+
+        let table = value
+            .as_table_mut()
+            .ok_or_else(|| anyhow!("Configuration must serialize to a table"))?;
+
+        let Some(base) = &self.applied_profile else {
+            insert_sections(table, &sui, nexus.as_ref())?;
+
+            return Ok(toml::to_string_pretty(&value)?);
+        };
+
+        let mut profile = self.profiles.get(&base.name).cloned().unwrap_or_default();
+
+        if sui.net != base.sui.net {
+            profile.sui.net = Some(sui.net);
+        }
+
+        if sui.wallet_path != base.sui.wallet_path {
+            profile.sui.wallet_path = Some(sui.wallet_path.clone());
+        }
+
+        if sui.rpc_url != base.sui.rpc_url {
+            profile.sui.rpc_url = sui.rpc_url.clone();
+        }
+
+        if nexus != base.nexus {
+            profile.nexus = nexus.clone();
+        }
+
+        insert_sections(table, &base.sui, base.nexus.as_ref())?;
+
+        table
+            .entry("profiles")
+            .or_insert_with(|| toml::Value::Table(Default::default()))
+            .as_table_mut()
+            .ok_or_else(|| anyhow!("Profiles must serialize to a table"))?
+            .insert(base.name.clone(), toml::Value::try_from(&profile)?);
+
+        Ok(toml::to_string_pretty(&value)?)
+    }
+}
+
+/// Replace the `sui` and `nexus` sections of the serialized configuration.
+fn insert_sections(
+    table: &mut toml::Table,
+    sui: &SuiConf,
+    nexus: Option<&NexusObjects>,
+) -> AnyResult<()> {
+    table.insert("sui".to_string(), toml::Value::try_from(sui)?);
+
+    match nexus {
+        Some(nexus) => table.insert("nexus".to_string(), toml::Value::try_from(nexus)?),
+        None => table.remove("nexus"),
+    };
+
+    Ok(())
+}
+
+/// Named set of overrides for the `sui` and `nexus` sections of the
+/// configuration.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ProfileConf {
+    #[serde(default)]
+    pub(crate) sui: SuiProfileConf,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) nexus: Option<NexusObjects>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SuiProfileConf {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) net: Option<SuiNet>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) wallet_path: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) rpc_url: Option<reqwest::Url>,
+}
+
+/// Base values of the configuration that were overridden by a profile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct AppliedProfile {
+    pub(crate) name: String,
+    pub(crate) sui: SuiConf,
+    pub(crate) nexus: Option<NexusObjects>,
+}
+
+/// Values of the configuration before and after environment variables
+/// overrode them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct AppliedEnv {
+    /// Names of the applied environment variables.
+    pub(crate) vars: Vec<&'static str>,
+    pub(crate) sui: SuiConf,
+    pub(crate) nexus: Option<NexusObjects>,
+    pub(crate) overridden_sui: SuiConf,
+    pub(crate) overridden_nexus: Option<NexusObjects>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -113,7 +443,110 @@ pub(crate) struct CryptoConf {
     pub(crate) identity_key: Option<IdentityKey>,
     /// Stored Double-Ratchet sessions keyed by their 32-byte session-id.
     #[serde(default)]
-    pub(crate) sessions: HashMap<[u8; 32], Session>,
+    pub(crate) sessions: HashMap<[u8; 32], StoredSession>,
+}
+
+impl CryptoConf {
+    /// Remove the sessions that expired `max_age` after they were last used
+    /// and return their IDs.
+    pub(crate) fn prune_sessions(&mut self, max_age: chrono::Duration) -> Vec<[u8; 32]> {
+        let now = chrono::Utc::now();
+        let expired = self
+            .sessions
+            .iter()
+            .filter(|(_, stored)| stored.is_expired(max_age, now))
+            .map(|(session_id, _)| *session_id)
+            .collect::<Vec<_>>();
+
+        for session_id in &expired {
+            self.sessions.remove(session_id);
+        }
+
+        expired
+    }
+}
+
+/// Default number of days after their last use that sessions expire.
+pub(crate) const DEFAULT_SESSION_MAX_AGE_DAYS: u32 = 30;
+
+/// A [`Session`] along with when it was created and last used. The timestamps
+/// are stored next to the cryptographic state of the session, not in it.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct StoredSession {
+    pub(crate) session: Session,
+    pub(crate) created_at: chrono::DateTime<chrono::Utc>,
+    pub(crate) last_used: chrono::DateTime<chrono::Utc>,
+}
+
+impl StoredSession {
+    pub(crate) fn new(session: Session) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            session,
+            created_at: now,
+            last_used: now,
+        }
+    }
+
+    /// Mark the session as used and return it.
+    pub(crate) fn touch(&mut self) -> &mut Session {
+        self.last_used = chrono::Utc::now();
+
+        &mut self.session
+    }
+
+    /// Whether the session was last used more than `max_age` before `now`.
+    pub(crate) fn is_expired(
+        &self,
+        max_age: chrono::Duration,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> bool {
+        now - self.last_used > max_age
+    }
+}
+
+/// Layout of [`CryptoConf`] before sessions were wrapped in [`StoredSession`].
+#[derive(Serialize, Deserialize, Default)]
+struct LegacyCryptoConf {
+    identity_key: Option<IdentityKey>,
+    #[serde(default)]
+    sessions: HashMap<[u8; 32], Session>,
+}
+
+/// Deserialize the crypto section, migrating sessions stored in the
+/// [`LegacyCryptoConf`] layout. Their creation time is unknown so they are
+/// treated as created now.
+fn deserialize_crypto_conf<'de, D>(deserializer: D) -> Result<Option<Secret<CryptoConf>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::{value::StrDeserializer, Error};
+
+    let Some(encoded) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+
+    let encoded = || StrDeserializer::<serde::de::value::Error>::new(&encoded);
+
+    if let Ok(crypto) = Secret::<CryptoConf>::deserialize(encoded()) {
+        return Ok(Some(crypto));
+    }
+
+    let mut legacy =
+        Secret::<LegacyCryptoConf>::deserialize(encoded()).map_err(D::Error::custom)?;
+    let LegacyCryptoConf {
+        identity_key,
+        sessions,
+    } = std::mem::take(&mut *legacy);
+
+    Ok(Some(Secret::new(CryptoConf {
+        identity_key,
+        sessions: sessions
+            .into_iter()
+            .map(|(session_id, session)| (session_id, StoredSession::new(session)))
+            .collect(),
+    })))
 }
 
 // Custom implementations because `IdentityKey` does not implement common traits.
@@ -150,15 +583,17 @@ pub(crate) struct GasArgs {
         long = "sui-gas-coin",
         short = 'g',
         help = "The gas coin object ID. First coin object is chosen if not present.",
-        value_name = "OBJECT_ID"
+        value_name = "OBJECT_ID",
+        value_parser = ValueParser::from(sui::parse_object_id)
     )]
     pub(crate) sui_gas_coin: Option<sui::ObjectID>,
     #[arg(
         long = "sui-gas-budget",
         short = 'b',
-        help = "The gas budget for the transaction.",
+        help = "The gas budget for the transaction in MIST, or in SUI with a 'sui' suffix.",
         value_name = "AMOUNT",
-        default_value_t = sui::MIST_PER_SUI / 10
+        default_value_t = sui::MIST_PER_SUI / 10,
+        value_parser = ValueParser::from(parse_mist)
     )]
     pub(crate) sui_gas_budget: u64,
 }
@@ -166,6 +601,21 @@ pub(crate) struct GasArgs {
 /// Whether to change the output format to JSON.
 pub(crate) static JSON_MODE: AtomicBool = AtomicBool::new(false);
 
+/// Name of the configuration profile selected with the `--profile` flag.
+pub(crate) static ACTIVE_PROFILE: OnceLock<String> = OnceLock::new();
+
+/// File that the structured output is written to instead of stdout, set with
+/// the `--output` flag.
+pub(crate) static OUTPUT_PATH: OnceLock<PathBuf> = OnceLock::new();
+
+/// Default number of seconds to wait for a submitted transaction to be
+/// checkpointed.
+pub(crate) const DEFAULT_FINALITY_TIMEOUT_SECS: u64 = 60;
+
+/// How long to wait for submitted transactions to be checkpointed, set with
+/// the `--finality-timeout` flag. `None` if the `--no-wait` flag is passed.
+pub(crate) static FINALITY_TIMEOUT: OnceLock<Option<std::time::Duration>> = OnceLock::new();
+
 // == Used by clap ==
 
 /// Expands `~/` to the user's home directory in path arguments.
@@ -185,6 +635,54 @@ pub(crate) fn parse_json_string(json: &str) -> AnyResult<serde_json::Value> {
     serde_json::from_str(json).map_err(AnyError::from)
 }
 
+/// Parses an amount of SUI into MIST. Accepts amounts like `0.5sui` and
+/// `500000000mist`. Bare integers are treated as MIST.
+pub(crate) fn parse_mist(amount: &str) -> AnyResult<u64> {
+    let normalized = amount.trim().to_lowercase();
+
+    let parse_digits = |digits: &str| -> AnyResult<u64> {
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            bail!(
+                "Invalid amount '{amount}', expected e.g. '0.5sui', '500000000mist' or '500000000'"
+            );
+        }
+
+        digits
+            .parse::<u64>()
+            .map_err(|_| anyhow!("Amount '{amount}' is too large"))
+    };
+
+    let Some(sui) = normalized.strip_suffix("sui") else {
+        let mist = normalized.strip_suffix("mist").unwrap_or(&normalized);
+
+        return parse_digits(mist.trim_end());
+    };
+
+    // SUI has 9 decimal places so the fraction is padded to a MIST amount.
+    let sui = sui.trim_end();
+    let (whole, fraction) = sui.split_once('.').unwrap_or((sui, ""));
+
+    if whole.is_empty() && fraction.is_empty() {
+        bail!("Invalid amount '{amount}', expected e.g. '0.5sui'");
+    }
+
+    if fraction.len() > 9 {
+        bail!("Amount '{amount}' has more than 9 decimal places");
+    }
+
+    let whole = if whole.is_empty() {
+        0
+    } else {
+        parse_digits(whole)?
+    };
+    let fraction = parse_digits(&format!("{fraction:0<9}"))?;
+
+    whole
+        .checked_mul(sui::MIST_PER_SUI)
+        .and_then(|mist| mist.checked_add(fraction))
+        .ok_or_else(|| anyhow!("Amount '{amount}' is too large"))
+}
+
 // == Used by serde ==
 
 fn default_sui_wallet_path() -> PathBuf {
@@ -194,7 +692,21 @@ fn default_sui_wallet_path() -> PathBuf {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use {
+        super::*,
+        nexus_sdk::{crypto::x3dh::PreKeyBundle, test_utils::sui_mocks},
+    };
+
+    fn test_session() -> Session {
+        let receiver_identity = IdentityKey::generate();
+        let spk_secret = IdentityKey::generate().secret().clone();
+        let bundle = PreKeyBundle::new(&receiver_identity, 1, &spk_secret, None, None);
+
+        let (_, session) =
+            Session::initiate(&IdentityKey::generate(), &bundle, b"test").expect("X3DH initiate");
+
+        session
+    }
 
     #[test]
     fn test_expand_tilde() {
@@ -212,6 +724,179 @@ mod tests {
         assert_eq!(parsed, serde_json::json!({"key": "value"}));
     }
 
+    #[test]
+    fn test_parse_mist() {
+        assert_eq!(parse_mist("1sui").unwrap(), 1_000_000_000);
+        assert_eq!(parse_mist("1 SUI").unwrap(), 1_000_000_000);
+        assert_eq!(parse_mist("0.5sui").unwrap(), 500_000_000);
+        assert_eq!(parse_mist(".25sui").unwrap(), 250_000_000);
+        assert_eq!(parse_mist("1.000000001sui").unwrap(), 1_000_000_001);
+        assert_eq!(parse_mist("500000000mist").unwrap(), 500_000_000);
+
+        // Bare integers are passed through as MIST.
+        assert_eq!(parse_mist("500000000").unwrap(), 500_000_000);
+        assert_eq!(parse_mist("0").unwrap(), 0);
+
+        assert!(parse_mist("0.0000000001sui")
+            .unwrap_err()
+            .to_string()
+            .contains("more than 9 decimal places"));
+        assert!(parse_mist("18446744073709551616")
+            .unwrap_err()
+            .to_string()
+            .contains("too large"));
+        assert!(parse_mist("18446744074sui")
+            .unwrap_err()
+            .to_string()
+            .contains("too large"));
+
+        for invalid in [
+            "", "sui", ".sui", "1.5", "1.5mist", "-1sui", "+1", "1e9", "1..5sui", "one",
+        ] {
+            assert!(parse_mist(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_apply_profile() {
+        let dev = ProfileConf {
+            sui: SuiProfileConf {
+                net: Some(SuiNet::Devnet),
+                ..Default::default()
+            },
+            nexus: None,
+        };
+
+        let mut conf = CliConf {
+            sui: SuiConf {
+                net: SuiNet::Testnet,
+                wallet_path: "/wallet".into(),
+                rpc_url: None,
+            },
+            profile: Some("dev".to_string()),
+            profiles: HashMap::from([("dev".to_string(), dev)]),
+            ..Default::default()
+        };
+
+        conf.apply_profile().unwrap();
+
+        // Only the values set in the profile are overridden.
+        assert_eq!(conf.sui.net, SuiNet::Devnet);
+        assert_eq!(conf.sui.wallet_path, PathBuf::from("/wallet"));
+
+        // Changes are saved to the profile, leaving the base values intact.
+        conf.sui.rpc_url = Some(reqwest::Url::parse("https://devnet.sui.io").unwrap());
+
+        let saved: CliConf = toml::from_str(&conf.to_toml_string().unwrap()).unwrap();
+        let profile = saved.profiles.get("dev").unwrap();
+
+        assert_eq!(saved.sui.net, SuiNet::Testnet);
+        assert_eq!(saved.sui.rpc_url, None);
+        assert_eq!(profile.sui.net, Some(SuiNet::Devnet));
+        assert_eq!(profile.sui.wallet_path, None);
+        assert_eq!(profile.sui.rpc_url, conf.sui.rpc_url);
+
+        // Missing profiles are an error.
+        let mut conf = CliConf {
+            profile: Some("prod".to_string()),
+            ..Default::default()
+        };
+
+        assert!(conf.apply_profile().is_err());
+    }
+
+    #[tokio::test]
+    #[serial_test::serial(conf_env)]
+    async fn test_apply_env() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let conf_path = tempdir.path().join("conf.toml");
+        let objects_path = tempdir.path().join("objects.toml");
+
+        let objects = NexusObjects {
+            workflow_pkg_id: sui::ObjectID::random(),
+            primitives_pkg_id: sui::ObjectID::random(),
+            interface_pkg_id: sui::ObjectID::random(),
+            network_id: sui::ObjectID::random(),
+            tool_registry: sui_mocks::mock_sui_object_ref(),
+            default_tap: sui_mocks::mock_sui_object_ref(),
+            gas_service: sui_mocks::mock_sui_object_ref(),
+            pre_key_vault: sui_mocks::mock_sui_object_ref(),
+        };
+
+        tokio::fs::write(&objects_path, toml::to_string(&objects).unwrap())
+            .await
+            .unwrap();
+
+        CliConf {
+            sui: SuiConf {
+                net: SuiNet::Testnet,
+                wallet_path: "/wallet".into(),
+                rpc_url: None,
+            },
+            ..Default::default()
+        }
+        .save_to_path(&conf_path)
+        .await
+        .unwrap();
+
+        let read_file = || async {
+            toml::from_str::<toml::Value>(&tokio::fs::read_to_string(&conf_path).await.unwrap())
+                .unwrap()
+        };
+        let file = read_file().await;
+
+        std::env::set_var(SUI_NET_ENV, "Devnet");
+        std::env::set_var(SUI_RPC_URL_ENV, "https://devnet.sui.io");
+        std::env::set_var(NEXUS_OBJECTS_PATH_ENV, &objects_path);
+
+        let mut conf = CliConf::load_from_path(&conf_path).await.unwrap();
+        conf.apply_env().await.unwrap();
+
+        // The environment takes precedence over the file.
+        assert_eq!(conf.sui.net, SuiNet::Devnet);
+        assert_eq!(
+            conf.sui.rpc_url,
+            Some(reqwest::Url::parse("https://devnet.sui.io").unwrap())
+        );
+        assert_eq!(conf.sui.wallet_path, PathBuf::from("/wallet"));
+        assert_eq!(conf.nexus, Some(objects));
+        assert_eq!(
+            conf.value_sources(),
+            vec![
+                ("sui.net", format!("environment ({SUI_NET_ENV})")),
+                ("sui.wallet_path", "file".to_string()),
+                ("sui.rpc_url", format!("environment ({SUI_RPC_URL_ENV})")),
+                ("nexus", format!("environment ({NEXUS_OBJECTS_PATH_ENV})")),
+            ]
+        );
+
+        // Saving keeps the file values.
+        conf.save_to_path(&conf_path).await.unwrap();
+
+        assert_eq!(read_file().await, file);
+
+        // Unless they were changed after the environment was applied.
+        conf.sui.net = SuiNet::Mainnet;
+        conf.save_to_path(&conf_path).await.unwrap();
+
+        let saved = CliConf::load_from_path(&conf_path).await.unwrap();
+
+        assert_eq!(saved.sui.net, SuiNet::Mainnet);
+        assert_eq!(saved.sui.rpc_url, None);
+        assert_eq!(saved.nexus, None);
+
+        // Invalid values are an error.
+        std::env::set_var(SUI_NET_ENV, "moonnet");
+
+        let mut conf = CliConf::load_from_path(&conf_path).await.unwrap();
+
+        assert!(conf.apply_env().await.is_err());
+
+        std::env::remove_var(SUI_NET_ENV);
+        std::env::remove_var(SUI_RPC_URL_ENV);
+        std::env::remove_var(NEXUS_OBJECTS_PATH_ENV);
+    }
+
     #[test]
     fn test_sui_net_display() {
         assert_eq!(SuiNet::Localnet.to_string(), "localnet");
@@ -219,4 +904,70 @@ mod tests {
         assert_eq!(SuiNet::Testnet.to_string(), "testnet");
         assert_eq!(SuiNet::Mainnet.to_string(), "mainnet");
     }
+
+    #[test]
+    fn test_prune_sessions() {
+        let fresh = StoredSession::new(test_session());
+        let mut stale = StoredSession::new(test_session());
+
+        stale.created_at -= chrono::Duration::days(40);
+        stale.last_used -= chrono::Duration::days(31);
+
+        let fresh_id = *fresh.session.id();
+        let stale_id = *stale.session.id();
+
+        let mut crypto = CryptoConf {
+            identity_key: None,
+            sessions: HashMap::from([(fresh_id, fresh), (stale_id, stale)]),
+        };
+
+        // Only the session unused for longer than the max-age is pruned.
+        assert_eq!(
+            crypto.prune_sessions(chrono::Duration::days(30)),
+            vec![stale_id]
+        );
+        assert_eq!(crypto.sessions.len(), 1);
+        assert!(crypto.sessions.contains_key(&fresh_id));
+
+        // Using a session resets its expiry.
+        let stored = crypto.sessions.get_mut(&fresh_id).unwrap();
+
+        stored.last_used -= chrono::Duration::days(31);
+        stored.touch();
+
+        assert!(crypto.prune_sessions(chrono::Duration::days(30)).is_empty());
+    }
+
+    #[test]
+    #[serial_test::serial(master_key_env)]
+    fn test_legacy_crypto_conf_migration() {
+        let tmp = tempfile::TempDir::new().unwrap();
+
+        std::env::set_var("XDG_CONFIG_HOME", tmp.path());
+        std::env::set_var("NEXUS_CLI_STORE_PASSPHRASE", "migration-test-passphrase");
+
+        let session = test_session();
+        let session_id = *session.id();
+        let legacy = Secret::new(LegacyCryptoConf {
+            identity_key: Some(IdentityKey::generate()),
+            sessions: HashMap::from([(session_id, session)]),
+        });
+
+        let mut conf = toml::Value::try_from(CliConf::default()).unwrap();
+
+        conf.as_table_mut().unwrap().insert(
+            "crypto".to_string(),
+            toml::Value::try_from(&legacy).unwrap(),
+        );
+
+        let conf: CliConf = conf.try_into().unwrap();
+        let crypto = conf.crypto.expect("crypto section");
+        let stored = crypto.sessions.get(&session_id).expect("session migrated");
+
+        assert!(crypto.identity_key.is_some());
+        assert_eq!(stored.session.id(), &session_id);
+        assert_eq!(stored.created_at, stored.last_used);
+
+        std::env::remove_var("XDG_CONFIG_HOME");
+    }
 }
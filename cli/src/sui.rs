@@ -1,5 +1,11 @@
 use {
-    crate::{loading, notify_success, prelude::*},
+    crate::{
+        audit::record_transaction,
+        display::{TxProgress, TxStage},
+        loading,
+        notify_success,
+        prelude::*,
+    },
     nexus_sdk::{object_crawler::fetch_one, sui},
 };
 
@@ -137,16 +143,29 @@ pub(crate) async fn fetch_reference_gas_price(sui: &sui::Client) -> AnyResult<u6
     Ok(response)
 }
 
-/// Sign a transaction with the provided wallet and execute it.
+/// Dry run a transaction, sign it with the provided wallet and execute it.
+/// The `progress` started when the transaction was crafted is carried on
+/// through the remaining stages.
 ///
 /// Returns `Ok` with the transaction block response if successful, or `Err` if
-/// the signing or the execution fails, or if the response contains errors.
+/// the dry run, the signing or the execution fails, or if the response
+/// contains errors.
 pub(crate) async fn sign_and_execute_transaction(
     sui: &sui::Client,
     wallet: &sui::WalletContext,
     tx_data: sui::TransactionData,
+    mut progress: TxProgress,
 ) -> AnyResult<sui::TransactionBlockResponse, NexusCliError> {
-    let signing_handle = loading!("Signing transaction...");
+    // Catch failing transactions before they are signed and cost gas.
+    progress.stage(TxStage::DryRunning);
+
+    if let Err(e) = transactions::gas::estimate_gas(sui, tx_data.clone()).await {
+        progress.error();
+
+        return Err(NexusCliError::Any(e));
+    }
+
+    progress.stage(TxStage::Signing);
 
     let envelope = wallet.sign_transaction(&tx_data);
 
@@ -156,25 +175,32 @@ pub(crate) async fn sign_and_execute_transaction(
         .with_object_changes()
         .with_events();
 
+    let finality_timeout = finality_timeout();
+
     // We want to confirm that the tx was executed (the name of this variant is
-    // misleading).
-    let resp_finality = sui::ExecuteTransactionRequestType::WaitForLocalExecution;
+    // misleading) unless we are not waiting for it at all.
+    let resp_finality = match finality_timeout {
+        Some(_) => sui::ExecuteTransactionRequestType::WaitForLocalExecution,
+        None => sui::ExecuteTransactionRequestType::WaitForEffectsCert,
+    };
+
+    progress.stage(TxStage::Submitting);
 
     let response = match sui
         .quorum_driver_api()
-        .execute_transaction_block(envelope, resp_options, Some(resp_finality))
+        .execute_transaction_block(envelope, resp_options.clone(), Some(resp_finality))
         .await
     {
         Ok(response) => response,
         Err(e) => {
-            signing_handle.error();
+            progress.error();
 
             return Err(NexusCliError::Sui(e));
         }
     };
 
     if !response.errors.is_empty() {
-        signing_handle.error();
+        progress.error();
 
         return Err(NexusCliError::Any(anyhow!(
             "Transaction failed with errors: {errors:?}",
@@ -182,25 +208,70 @@ pub(crate) async fn sign_and_execute_transaction(
         )));
     }
 
+    // Wait for the TX to be checkpointed so that follow-up commands see its
+    // effects. The final response is used to check the status.
+    let response = match finality_timeout {
+        Some(timeout) => {
+            progress.stage(TxStage::Finalizing);
+
+            match transactions::finality::wait_for_finality(
+                sui,
+                response.digest,
+                resp_options,
+                timeout,
+            )
+            .await
+            {
+                Ok(response) => response,
+                Err(e) => {
+                    progress.error();
+
+                    return Err(NexusCliError::Any(e));
+                }
+            }
+        }
+        None => response,
+    };
+
     // Check if any effects failed in the TX.
     if let Some(sui::TransactionBlockEffects::V1(effect)) = &response.effects {
         if let sui::ExecutionStatus::Failure { error } = effect.clone().into_status() {
-            signing_handle.error();
+            progress.error();
 
             return Err(NexusCliError::Any(anyhow!(error)));
         }
     };
 
-    signing_handle.success();
+    progress.success();
+
+    record_transaction(response.digest).await;
 
     notify_success!(
         "Transaction digest: {digest}",
         digest = response.digest.to_string().truecolor(100, 100, 100)
     );
 
+    if let Some(checkpoint) = response.checkpoint {
+        notify_success!(
+            "Transaction finalized in checkpoint: {checkpoint}",
+            checkpoint = checkpoint.to_string().truecolor(100, 100, 100)
+        );
+    }
+
     Ok(response)
 }
 
+/// How long to wait for a submitted transaction to be checkpointed or `None`
+/// if the CLI should not wait.
+fn finality_timeout() -> Option<std::time::Duration> {
+    FINALITY_TIMEOUT
+        .get()
+        .copied()
+        .unwrap_or(Some(std::time::Duration::from_secs(
+            DEFAULT_FINALITY_TIMEOUT_SECS,
+        )))
+}
+
 /// Fetch a single object from Sui by its ID.
 pub(crate) async fn fetch_object_by_id(
     sui: &sui::Client,
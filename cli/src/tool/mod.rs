@@ -1,5 +1,9 @@
+mod tool_benchmark;
 mod tool_claim_collateral;
+mod tool_deprecate;
+mod tool_health_check;
 mod tool_list;
+mod tool_meta;
 mod tool_new;
 mod tool_register;
 mod tool_set_invocation_cost;
@@ -8,14 +12,20 @@ mod tool_validate;
 
 use {
     crate::prelude::*,
+    tool_benchmark::*,
     tool_claim_collateral::*,
+    tool_deprecate::*,
+    tool_health_check::*,
     tool_list::*,
+    tool_meta::*,
     tool_new::*,
     tool_register::*,
     tool_set_invocation_cost::*,
     tool_unregister::*,
     tool_validate::*,
 };
+// Used to resolve tool URLs when simulating and validating DAGs.
+pub(crate) use {tool_list::fetch_tool_urls, tool_validate::tool_base_url};
 
 #[derive(Subcommand)]
 pub(crate) enum ToolCommand {
@@ -52,6 +62,69 @@ pub(crate) enum ToolCommand {
         ident: ToolIdent,
     },
 
+    #[command(about = "Fetch and display the meta of a tool based on its identifier.")]
+    Meta {
+        /// The ident of the Tool to fetch the meta of.
+        #[command(flatten)]
+        ident: ToolIdent,
+    },
+
+    #[command(about = "Check the health of a tool based on its identifier.")]
+    HealthCheck {
+        /// The ident of the Tool to check.
+        #[command(flatten)]
+        ident: ToolIdent,
+        /// How long to wait for the tool to respond.
+        #[arg(
+            long = "timeout-ms",
+            help = "How long to wait for the tool to respond in milliseconds",
+            default_value = "5000",
+            value_name = "MS"
+        )]
+        timeout_ms: u64,
+    },
+
+    #[command(about = "Load test an off-chain tool by invoking it concurrently.")]
+    Benchmark {
+        /// The URL of the off-chain tool to benchmark.
+        #[arg(
+            long = "url",
+            short = 'u',
+            help = "The URL of the off-chain Tool to benchmark",
+            value_name = "URL"
+        )]
+        url: reqwest::Url,
+        /// How many invocations to send at the same time.
+        #[arg(
+            long = "concurrency",
+            short = 'c',
+            help = "How many invocations to send at the same time",
+            default_value = "10",
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+            value_name = "COUNT"
+        )]
+        concurrency: usize,
+        /// How many invocations to send in total.
+        #[arg(
+            long = "requests",
+            short = 'r',
+            help = "How many invocations to send in total",
+            default_value = "100",
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+            value_name = "COUNT"
+        )]
+        requests: usize,
+        /// The input data to invoke the tool with.
+        #[arg(
+            long = "input-json",
+            short = 'i',
+            help = "The input data to invoke the Tool with. Must match the Tool input schema.",
+            value_parser = ValueParser::from(parse_json_string),
+            value_name = "DATA"
+        )]
+        input_json: serde_json::Value,
+    },
+
     #[command(about = "Register a tool based on its identifier.")]
     Register {
         /// The collateral coin object ID. Second coin object is chosen if not
@@ -60,15 +133,17 @@ pub(crate) enum ToolCommand {
             long = "collateral-coin",
             short = 'c',
             help = "The collateral coin object ID. Second coin object is chosen if not present.",
-            value_name = "OBJECT_ID"
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
         )]
         collateral_coin: Option<sui::ObjectID>,
         #[arg(
             long = "invocation-cost",
             short = 'i',
-            help = "What is the cost of invoking this tool in MIST.",
+            help = "What is the cost of invoking this tool in MIST, or in SUI with a 'sui' suffix.",
             default_value = "0",
-            value_name = "MIST"
+            value_name = "AMOUNT",
+            value_parser = ValueParser::from(parse_mist)
         )]
         invocation_cost: u64,
         #[arg(
@@ -101,7 +176,8 @@ pub(crate) enum ToolCommand {
             long = "owner-cap",
             short = 'o',
             help = "The OwnerCap<OverTool> object ID that must be owned by the sender.",
-            value_name = "OBJECT_ID"
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
         )]
         owner_cap: Option<sui::ObjectID>,
         /// Whether to skip the confirmation prompt.
@@ -111,6 +187,41 @@ pub(crate) enum ToolCommand {
         gas: GasArgs,
     },
 
+    #[command(about = "Mark a tool identified by its FQN as deprecated.")]
+    Deprecate {
+        #[arg(
+            long = "tool-fqn",
+            short = 't',
+            help = "The FQN of the tool to deprecate.",
+            value_name = "FQN"
+        )]
+        tool_fqn: ToolFqn,
+        #[arg(
+            long = "owner-cap",
+            short = 'o',
+            help = "The OwnerCap<OverTool> object ID that must be owned by the sender.",
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
+        )]
+        owner_cap: Option<sui::ObjectID>,
+        #[arg(
+            long = "replacement-fqn",
+            short = 'r',
+            help = "The FQN of the tool that should be used instead.",
+            value_name = "FQN"
+        )]
+        replacement_fqn: Option<ToolFqn>,
+        #[arg(
+            long = "reason",
+            help = "Why the tool is deprecated.",
+            default_value = "",
+            value_name = "REASON"
+        )]
+        reason: String,
+        #[command(flatten)]
+        gas: GasArgs,
+    },
+
     #[command(about = "Claim collateral for a tool identified by its FQN.")]
     ClaimCollateral {
         #[arg(
@@ -124,7 +235,8 @@ pub(crate) enum ToolCommand {
             long = "owner-cap",
             short = 'o',
             help = "The OwnerCap<OverTool> object ID that must be owned by the sender.",
-            value_name = "OBJECT_ID"
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
         )]
         owner_cap: Option<sui::ObjectID>,
         #[command(flatten)]
@@ -144,15 +256,17 @@ pub(crate) enum ToolCommand {
             long = "owner-cap",
             short = 'o',
             help = "The OwnerCap<OverGas> object ID that must be owned by the sender.",
-            value_name = "OBJECT_ID"
+            value_name = "OBJECT_ID",
+            value_parser = ValueParser::from(sui::parse_object_id)
         )]
         owner_cap: Option<sui::ObjectID>,
         #[arg(
             long = "invocation-cost",
             short = 'i',
-            help = "What is the cost of invoking this tool in MIST.",
+            help = "What is the cost of invoking this tool in MIST, or in SUI with a 'sui' suffix.",
             default_value = "0",
-            value_name = "MIST"
+            value_name = "AMOUNT",
+            value_parser = ValueParser::from(parse_mist)
         )]
         invocation_cost: u64,
         #[command(flatten)]
@@ -201,6 +315,22 @@ pub(crate) async fn handle(command: ToolCommand) -> AnyResult<(), NexusCliError>
         // == `$ nexus tool validate` ==
         ToolCommand::Validate { ident } => validate_tool(ident).await.map(|_| ()),
 
+        // == `$ nexus tool meta` ==
+        ToolCommand::Meta { ident } => show_tool_meta(ident).await.map(|_| ()),
+
+        // == `$ nexus tool health-check` ==
+        ToolCommand::HealthCheck { ident, timeout_ms } => {
+            health_check_tool(ident, timeout_ms).await
+        }
+
+        // == `$ nexus tool benchmark` ==
+        ToolCommand::Benchmark {
+            url,
+            concurrency,
+            requests,
+            input_json,
+        } => benchmark_tool(url, concurrency, requests, input_json).await,
+
         // == `$ nexus tool register` ==
         ToolCommand::Register {
             ident,
@@ -239,6 +369,25 @@ pub(crate) async fn handle(command: ToolCommand) -> AnyResult<(), NexusCliError>
             .await
         }
 
+        // == `$ nexus tool deprecate` ==
+        ToolCommand::Deprecate {
+            tool_fqn,
+            owner_cap,
+            replacement_fqn,
+            reason,
+            gas,
+        } => {
+            deprecate_tool(
+                tool_fqn,
+                owner_cap,
+                replacement_fqn,
+                reason,
+                gas.sui_gas_coin,
+                gas.sui_gas_budget,
+            )
+            .await
+        }
+
         // == `$ nexus tool claim-collateral` ==
         ToolCommand::ClaimCollateral {
             tool_fqn,
@@ -0,0 +1,404 @@
+use {
+    crate::{
+        command_title,
+        display::json_output,
+        item,
+        loading,
+        notify_error,
+        notify_success,
+        prelude::*,
+        tool::{tool_base_url, validate_tool, ToolIdent},
+    },
+    std::{
+        collections::BTreeMap,
+        time::{Duration, Instant},
+    },
+    tokio::task::JoinSet,
+};
+
+/// Summary of a finished benchmark. Latencies only include successful
+/// invocations.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct BenchmarkSummary {
+    requests: usize,
+    concurrency: usize,
+    successes: usize,
+    errors: usize,
+    total_ms: u128,
+    requests_per_second: f64,
+    p50_ms: Option<u128>,
+    p95_ms: Option<u128>,
+    p99_ms: Option<u128>,
+    /// Number of occurrences of each error message.
+    error_counts: BTreeMap<String, usize>,
+}
+
+/// Send `requests` invocations with the given `input_json` to the off-chain
+/// tool at `url`, at most `concurrency` at a time, and print a summary of the
+/// latencies.
+pub(crate) async fn benchmark_tool(
+    url: reqwest::Url,
+    concurrency: usize,
+    requests: usize,
+    input_json: serde_json::Value,
+) -> AnyResult<(), NexusCliError> {
+    command_title!("Benchmarking Tool at '{url}'");
+
+    // Fetch the tool meta to validate the input against.
+    let meta = validate_tool(ToolIdent {
+        off_chain: Some(url.clone()),
+        on_chain: None,
+    })
+    .await?;
+
+    let input_handle = loading!("Validating input JSON against the tool input schema...");
+
+    if let Err(e) = validate_input(&meta.input_schema, &input_json) {
+        input_handle.error();
+
+        return Err(NexusCliError::Any(e));
+    }
+
+    input_handle.success();
+
+    let invoke_url = tool_base_url(url)
+        .join("invoke")
+        .expect("Appending invoke must be valid");
+
+    let client = reqwest::Client::new();
+
+    let benchmark_handle =
+        loading!("Sending {requests} requests with concurrency {concurrency} to '{invoke_url}'...");
+
+    let started = Instant::now();
+    let mut set = JoinSet::new();
+    let mut results = Vec::with_capacity(requests);
+
+    for _ in 0..requests {
+        // Wait for a slot to free up.
+        if set.len() >= concurrency {
+            if let Some(result) = set.join_next().await {
+                results.push(flatten_join_result(result));
+            }
+        }
+
+        let client = client.clone();
+        let invoke_url = invoke_url.clone();
+        let input_json = input_json.clone();
+
+        set.spawn(async move { invoke_once(&client, invoke_url, &input_json).await });
+    }
+
+    while let Some(result) = set.join_next().await {
+        results.push(flatten_join_result(result));
+    }
+
+    let summary = summarize(results, concurrency, started.elapsed());
+
+    if summary.errors == 0 {
+        benchmark_handle.success();
+    } else {
+        benchmark_handle.error();
+    }
+
+    notify_success!(
+        "Tool handled {rps} requests per second",
+        rps = format!("{:.2}", summary.requests_per_second).truecolor(100, 100, 100)
+    );
+
+    let format_latency = |latency: Option<u128>| match latency {
+        Some(latency) => format!("{latency} ms"),
+        None => "-".to_string(),
+    };
+
+    item!(
+        "Successful: {}/{}",
+        summary.successes.to_string().truecolor(100, 100, 100),
+        summary.requests.to_string().truecolor(100, 100, 100)
+    );
+    item!(
+        "Total time: {} ms",
+        summary.total_ms.to_string().truecolor(100, 100, 100)
+    );
+    item!(
+        "Latency p50: {}, p95: {}, p99: {}",
+        format_latency(summary.p50_ms).truecolor(100, 100, 100),
+        format_latency(summary.p95_ms).truecolor(100, 100, 100),
+        format_latency(summary.p99_ms).truecolor(100, 100, 100)
+    );
+
+    if summary.errors > 0 {
+        notify_error!(
+            "{errors} requests failed",
+            errors = summary.errors.to_string().truecolor(100, 100, 100)
+        );
+
+        for (error, count) in &summary.error_counts {
+            item!("{count}x {}", error.truecolor(100, 100, 100));
+        }
+    }
+
+    json_output(&summary)?;
+
+    Ok(())
+}
+
+/// Validate the input JSON against the JSON schema of the tool input.
+fn validate_input(
+    input_schema: &serde_json::Value,
+    input_json: &serde_json::Value,
+) -> AnyResult<()> {
+    let validator = jsonschema::validator_for(input_schema)
+        .map_err(|e| anyhow!("The tool input schema is not a valid JSON schema: {e}"))?;
+
+    let errors = validator
+        .iter_errors(input_json)
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>();
+
+    if !errors.is_empty() {
+        bail!(
+            "The input JSON does not match the tool input schema: {}",
+            errors.join(", ")
+        );
+    }
+
+    Ok(())
+}
+
+/// Invoke the tool once and measure how long it took. Non-2xx responses are
+/// errors.
+async fn invoke_once(
+    client: &reqwest::Client,
+    invoke_url: reqwest::Url,
+    input_json: &serde_json::Value,
+) -> Result<Duration, String> {
+    let started = Instant::now();
+
+    let response = client
+        .post(invoke_url)
+        .json(input_json)
+        .send()
+        .await
+        .map_err(|e| e.without_url().to_string())?;
+
+    let status = response.status();
+
+    // Read the whole body so that the latency includes the response transfer.
+    response
+        .bytes()
+        .await
+        .map_err(|e| e.without_url().to_string())?;
+
+    if !status.is_success() {
+        return Err(format!("Tool responded with status '{status}'"));
+    }
+
+    Ok(started.elapsed())
+}
+
+/// A panicked request task counts as a failed request.
+fn flatten_join_result(
+    result: Result<Result<Duration, String>, tokio::task::JoinError>,
+) -> Result<Duration, String> {
+    result.unwrap_or_else(|e| Err(format!("Request task failed: {e}")))
+}
+
+/// Aggregate the results of all invocations into a [`BenchmarkSummary`].
+fn summarize(
+    results: Vec<Result<Duration, String>>,
+    concurrency: usize,
+    elapsed: Duration,
+) -> BenchmarkSummary {
+    let requests = results.len();
+    let mut latencies = Vec::with_capacity(requests);
+    let mut error_counts = BTreeMap::<String, usize>::new();
+
+    for result in results {
+        match result {
+            Ok(latency) => latencies.push(latency.as_millis()),
+            Err(e) => *error_counts.entry(e).or_default() += 1,
+        }
+    }
+
+    latencies.sort_unstable();
+
+    let successes = latencies.len();
+    let requests_per_second = match elapsed.as_secs_f64() {
+        secs if secs > 0.0 => successes as f64 / secs,
+        _ => 0.0,
+    };
+
+    BenchmarkSummary {
+        requests,
+        concurrency,
+        successes,
+        errors: requests - successes,
+        total_ms: elapsed.as_millis(),
+        requests_per_second,
+        p50_ms: percentile(&latencies, 50),
+        p95_ms: percentile(&latencies, 95),
+        p99_ms: percentile(&latencies, 99),
+        error_counts,
+    }
+}
+
+/// Nearest-rank percentile of the `sorted` values.
+fn percentile(sorted: &[u128], percentile: usize) -> Option<u128> {
+    if sorted.is_empty() {
+        return None;
+    }
+
+    let rank = (percentile * sorted.len()).div_ceil(100).max(1);
+
+    sorted.get(rank - 1).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, mockito::Server};
+
+    #[test]
+    fn test_percentile() {
+        let values = (1..=100).collect::<Vec<u128>>();
+
+        assert_eq!(percentile(&values, 50), Some(50));
+        assert_eq!(percentile(&values, 95), Some(95));
+        assert_eq!(percentile(&values, 99), Some(99));
+        assert_eq!(percentile(&values, 100), Some(100));
+        assert_eq!(percentile(&[7], 50), Some(7));
+        assert_eq!(percentile(&[1, 2, 3], 99), Some(3));
+        assert_eq!(percentile(&[], 50), None);
+    }
+
+    #[test]
+    fn test_summarize_counts_errors_separately() {
+        let results = vec![
+            Ok(Duration::from_millis(30)),
+            Err("Tool responded with status '500 Internal Server Error'".to_string()),
+            Ok(Duration::from_millis(10)),
+            Err("Tool responded with status '500 Internal Server Error'".to_string()),
+            Ok(Duration::from_millis(20)),
+            Err("connection refused".to_string()),
+        ];
+
+        let summary = summarize(results, 2, Duration::from_secs(2));
+
+        assert_eq!(summary.requests, 6);
+        assert_eq!(summary.concurrency, 2);
+        assert_eq!(summary.successes, 3);
+        assert_eq!(summary.errors, 3);
+        assert_eq!(summary.total_ms, 2000);
+        assert_eq!(summary.requests_per_second, 1.5);
+        // Failed requests do not count towards the latencies.
+        assert_eq!(summary.p50_ms, Some(20));
+        assert_eq!(summary.p99_ms, Some(30));
+        assert_eq!(
+            summary.error_counts,
+            BTreeMap::from([
+                (
+                    "Tool responded with status '500 Internal Server Error'".to_string(),
+                    2
+                ),
+                ("connection refused".to_string(), 1),
+            ])
+        );
+
+        let summary = summarize(vec![Err("boom".to_string())], 1, Duration::from_secs(1));
+
+        assert_eq!(summary.p50_ms, None);
+        assert_eq!(summary.requests_per_second, 0.0);
+    }
+
+    #[test]
+    fn test_validate_input() {
+        let schema = json!({
+            "type": "object",
+            "properties": { "prompt": { "type": "string" } },
+            "required": ["prompt"]
+        });
+
+        assert!(validate_input(&schema, &json!({ "prompt": "hello" })).is_ok());
+
+        let err = validate_input(&schema, &json!({ "prompt": 1 })).unwrap_err();
+
+        assert!(err
+            .to_string()
+            .starts_with("The input JSON does not match the tool input schema"));
+        assert!(validate_input(&schema, &json!({})).is_err());
+    }
+
+    async fn mock_tool(server: &mut Server) {
+        server
+            .mock("GET", "/health")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", "/meta")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "fqn": "xyz.dummy.tool@1",
+                    "url": server.url(),
+                    "description": "Dummy tool",
+                    "input_schema": {
+                        "type": "object",
+                        "properties": { "prompt": { "type": "string" } },
+                        "required": ["prompt"]
+                    },
+                    "output_schema": { "oneOf": [] }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_benchmark_tool() {
+        let mut server = Server::new_async().await;
+
+        mock_tool(&mut server).await;
+
+        let invoke = server
+            .mock("POST", "/invoke")
+            .match_body(mockito::Matcher::Json(json!({ "prompt": "hello" })))
+            .with_status(200)
+            .with_body(json!({ "ok": { "message": "hello" } }).to_string())
+            .expect(10)
+            .create_async()
+            .await;
+
+        let url = reqwest::Url::parse(&server.url()).unwrap();
+        let result = benchmark_tool(url, 3, 10, json!({ "prompt": "hello" })).await;
+
+        assert!(result.is_ok());
+
+        invoke.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_benchmark_tool_invalid_input() {
+        let mut server = Server::new_async().await;
+
+        mock_tool(&mut server).await;
+
+        let invoke = server
+            .mock("POST", "/invoke")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let url = reqwest::Url::parse(&server.url()).unwrap();
+        let result = benchmark_tool(url, 1, 1, json!({ "message": "hello" })).await;
+
+        assert!(
+            matches!(result, Err(NexusCliError::Any(e)) if e.to_string().contains("does not match"))
+        );
+
+        invoke.assert_async().await;
+    }
+}
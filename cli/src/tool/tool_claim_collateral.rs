@@ -1,5 +1,10 @@
 use {
-    crate::{command_title, display::json_output, loading, prelude::*, sui::*},
+    crate::{
+        command_title,
+        display::{json_output, TxProgress},
+        prelude::*,
+        sui::*,
+    },
     nexus_sdk::transactions::tool,
 };
 
@@ -39,18 +44,16 @@ pub(crate) async fn claim_collateral(
     let owner_cap = fetch_object_by_id(&sui, owner_cap).await?;
 
     // Craft a TX to claim the collaters for a Tool.
-    let tx_handle = loading!("Crafting transaction...");
+    let progress = TxProgress::new();
 
     let mut tx = sui::ProgrammableTransactionBuilder::new();
 
     if let Err(e) = tool::claim_collateral_for_self(&mut tx, objects, &tool_fqn, &owner_cap) {
-        tx_handle.error();
+        progress.error();
 
         return Err(NexusCliError::Any(e));
     }
 
-    tx_handle.success();
-
     let tx_data = sui::TransactionData::new_programmable(
         address,
         vec![gas_coin.object_ref()],
@@ -60,7 +63,7 @@ pub(crate) async fn claim_collateral(
     );
 
     // Sign and submit the TX.
-    let response = sign_and_execute_transaction(&sui, &wallet, tx_data).await?;
+    let response = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
 
     json_output(&json!({ "digest": response.digest }))?;
 
@@ -0,0 +1,100 @@
+use {
+    crate::{
+        command_title,
+        display::{json_output, TxProgress},
+        notify_success,
+        prelude::*,
+        sui::*,
+    },
+    nexus_sdk::transactions::tool,
+};
+
+/// Mark a tool as deprecated based on its FQN and optionally redirect it to
+/// its replacement.
+pub(crate) async fn deprecate_tool(
+    tool_fqn: ToolFqn,
+    owner_cap: Option<sui::ObjectID>,
+    replacement_fqn: Option<ToolFqn>,
+    reason: String,
+    sui_gas_coin: Option<sui::ObjectID>,
+    sui_gas_budget: u64,
+) -> AnyResult<(), NexusCliError> {
+    command_title!("Deprecating Tool '{tool_fqn}'");
+
+    if replacement_fqn.as_ref() == Some(&tool_fqn) {
+        return Err(NexusCliError::Any(anyhow!(
+            "A tool cannot be replaced by itself."
+        )));
+    }
+
+    // Load CLI configuration.
+    let mut conf = CliConf::load().await.unwrap_or_default();
+
+    // Nexus objects must be present in the configuration.
+    let objects = &get_nexus_objects(&mut conf).await?;
+
+    // Create wallet context, Sui client and find the active address.
+    let mut wallet = create_wallet_context(&conf.sui.wallet_path, conf.sui.net).await?;
+    let sui = build_sui_client(&conf.sui).await?;
+    let address = wallet.active_address().map_err(NexusCliError::Any)?;
+
+    // Fetch gas coin object.
+    let gas_coin = fetch_gas_coin(&sui, address, sui_gas_coin).await?;
+
+    // Fetch reference gas price.
+    let reference_gas_price = fetch_reference_gas_price(&sui).await?;
+
+    // Use the provided or saved `owner_cap` object ID and fetch the object.
+    let Some(owner_cap) = owner_cap.or(conf.tools.get(&tool_fqn).map(|t| t.over_tool)) else {
+        return Err(NexusCliError::Any(anyhow!(
+            "No OwnerCap object ID found for tool '{tool_fqn}'."
+        )));
+    };
+
+    let owner_cap = fetch_object_by_id(&sui, owner_cap).await?;
+
+    // Craft a TX to deprecate the tool.
+    let progress = TxProgress::new();
+
+    let mut tx = sui::ProgrammableTransactionBuilder::new();
+
+    if let Err(e) = tool::deprecate(
+        &mut tx,
+        objects,
+        &tool_fqn,
+        &owner_cap,
+        &reason,
+        replacement_fqn.as_ref(),
+    ) {
+        progress.error();
+
+        return Err(NexusCliError::Any(e));
+    }
+
+    let tx_data = sui::TransactionData::new_programmable(
+        address,
+        vec![gas_coin.object_ref()],
+        tx.finish(),
+        sui_gas_budget,
+        reference_gas_price,
+    );
+
+    // Sign and submit the TX.
+    let response = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
+
+    match &replacement_fqn {
+        Some(replacement_fqn) => notify_success!(
+            "Tool {tool_fqn} deprecated in favour of {replacement_fqn}",
+            tool_fqn = tool_fqn.to_string().truecolor(100, 100, 100),
+            replacement_fqn = replacement_fqn.to_string().truecolor(100, 100, 100)
+        ),
+        None => notify_success!(
+            "Tool {tool_fqn} deprecated",
+            tool_fqn = tool_fqn.to_string().truecolor(100, 100, 100)
+        ),
+    }
+
+    json_output(&json!({ "digest": response.digest }))?;
+
+    Ok(())
+}
@@ -0,0 +1,229 @@
+use {
+    super::tool_list::ToolRegistry,
+    crate::{
+        command_title,
+        display::json_output,
+        item,
+        loading,
+        notify_success,
+        prelude::*,
+        sui::*,
+        tool::{tool_base_url, ToolIdent},
+    },
+    nexus_sdk::object_crawler::{fetch_one, Structure},
+    std::time::{Duration, Instant},
+};
+
+/// Ping the `/health` endpoint of either an off-chain or an on-chain tool.
+/// Fails if the tool does not respond with a 2xx status code within
+/// `timeout_ms`.
+pub(crate) async fn health_check_tool(
+    ident: ToolIdent,
+    timeout_ms: u64,
+) -> AnyResult<(), NexusCliError> {
+    let url = match (ident.off_chain, ident.on_chain) {
+        (Some(url), None) => url,
+        (None, Some(ident)) => fetch_on_chain_tool_url(ident).await?,
+        _ => unreachable!("Checked by clap"),
+    };
+
+    command_title!("Checking health of Tool at '{url}'");
+
+    let health_url = tool_base_url(url)
+        .join("health")
+        .expect("Appending health must be valid");
+
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_millis(timeout_ms))
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => return Err(NexusCliError::Http(e)),
+    };
+
+    let health_handle = loading!("Pinging '{health_url}'...");
+
+    let started = Instant::now();
+    let result = client.get(health_url).send().await;
+    let latency_ms = started.elapsed().as_millis();
+
+    let status = match result {
+        Ok(response) => response.status(),
+        Err(e) if e.is_timeout() => {
+            health_handle.error();
+
+            json_output(&json!({
+                "status": null,
+                "latency_ms": latency_ms,
+                "healthy": false,
+            }))?;
+
+            return Err(NexusCliError::Any(anyhow!(
+                "The tool did not respond within {timeout_ms} ms"
+            )));
+        }
+        Err(e) => {
+            health_handle.error();
+
+            return Err(NexusCliError::Http(e));
+        }
+    };
+
+    let healthy = status.is_success();
+
+    json_output(&json!({
+        "status": status.as_u16(),
+        "latency_ms": latency_ms,
+        "healthy": healthy,
+    }))?;
+
+    if !healthy {
+        health_handle.error();
+
+        return Err(NexusCliError::Any(anyhow!(
+            "The tool responded with status '{status}' in {latency_ms} ms"
+        )));
+    }
+
+    health_handle.success();
+
+    notify_success!(
+        "Tool is {healthy}",
+        healthy = "healthy".truecolor(100, 100, 100)
+    );
+
+    item!("Status: {}", status.to_string().truecolor(100, 100, 100));
+    item!(
+        "Latency: {} ms",
+        latency_ms.to_string().truecolor(100, 100, 100)
+    );
+
+    Ok(())
+}
+
+/// Find the URL of a tool registered in the tool registry based on its FQN.
+pub(super) async fn fetch_on_chain_tool_url(
+    ident: String,
+) -> AnyResult<reqwest::Url, NexusCliError> {
+    let tool_fqn = ident.parse::<ToolFqn>().map_err(|e| {
+        NexusCliError::Any(anyhow!(
+            "Invalid on-chain tool ident '{ident}', expected a tool FQN: {e}"
+        ))
+    })?;
+
+    // Load CLI configuration.
+    let mut conf = CliConf::load().await.unwrap_or_default();
+
+    // Nexus objects must be present in the configuration.
+    let NexusObjects { tool_registry, .. } = &get_nexus_objects(&mut conf).await?;
+
+    // Build the Sui client.
+    let sui = build_sui_client(&conf.sui).await?;
+
+    let tool_handle = loading!("Fetching tool '{tool_fqn}' from the tool registry...");
+
+    let tool_registry =
+        match fetch_one::<Structure<ToolRegistry>>(&sui, tool_registry.object_id).await {
+            Ok(tool_registry) => tool_registry.data.into_inner(),
+            Err(e) => {
+                tool_handle.error();
+
+                return Err(NexusCliError::Any(e));
+            }
+        };
+
+    let tools = match tool_registry.tools.fetch_all(&sui).await {
+        Ok(tools) => tools,
+        Err(e) => {
+            tool_handle.error();
+
+            return Err(NexusCliError::Any(e));
+        }
+    };
+
+    match tools.into_iter().find(|(fqn, _)| *fqn == tool_fqn) {
+        Some((_, tool)) => {
+            tool_handle.success();
+
+            Ok(tool.into_inner().url)
+        }
+        None => {
+            tool_handle.error();
+
+            Err(NexusCliError::Any(anyhow!(
+                "Tool '{tool_fqn}' is not registered in the tool registry"
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, mockito::Server};
+
+    fn off_chain(url: &str) -> ToolIdent {
+        ToolIdent {
+            off_chain: Some(reqwest::Url::parse(url).unwrap()),
+            on_chain: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_health_check_healthy_tool() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/dummy/tool/health")
+            .with_status(200)
+            .expect(2)
+            .create_async()
+            .await;
+
+        // With and without the trailing slash.
+        let url = format!("{}/dummy/tool", server.url());
+        assert!(health_check_tool(off_chain(&url), 1000).await.is_ok());
+
+        let url = format!("{}/dummy/tool/", server.url());
+        assert!(health_check_tool(off_chain(&url), 1000).await.is_ok());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_health_check_unhealthy_tool() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/health")
+            .with_status(503)
+            .create_async()
+            .await;
+
+        let result = health_check_tool(off_chain(&server.url()), 1000).await;
+
+        assert!(matches!(result, Err(NexusCliError::Any(e)) if e.to_string().contains("503")));
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_health_check_timeout() {
+        // Accept connections but never respond.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let mut sockets = vec![];
+
+            while let Ok((socket, _)) = listener.accept().await {
+                sockets.push(socket);
+            }
+        });
+
+        let result = health_check_tool(off_chain(&format!("http://{addr}")), 100).await;
+
+        assert!(
+            matches!(result, Err(NexusCliError::Any(e)) if e.to_string().contains("within 100 ms"))
+        );
+    }
+}
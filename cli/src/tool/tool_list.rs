@@ -56,12 +56,22 @@ pub(crate) async fn list_tools() -> AnyResult<(), NexusCliError> {
             "fqn": fqn,
             "url": tool.url,
             "registered_at_ms": tool.registered_at_ms,
-            "description": tool.description
+            "description": tool.description,
+            "deprecated": tool.deprecated
         }));
 
+        // Deprecated tools are labeled and struck through.
+        let (label, fqn) = if tool.deprecated {
+            (
+                format!("{} ", "[DEPRECATED]".yellow().bold()),
+                fqn.to_string().truecolor(100, 100, 100).strikethrough(),
+            )
+        } else {
+            (String::new(), fqn.to_string().truecolor(100, 100, 100))
+        };
+
         item!(
-            "Tool '{fqn}' at '{url}' registered '{registered_at}' - {description}",
-            fqn = fqn.to_string().truecolor(100, 100, 100),
+            "{label}Tool '{fqn}' at '{url}' registered '{registered_at}' - {description}",
             url = tool.url.as_str().truecolor(100, 100, 100),
             registered_at = tool.registered_at_ms.to_string().truecolor(100, 100, 100),
             description = tool.description.truecolor(100, 100, 100),
@@ -73,17 +83,37 @@ pub(crate) async fn list_tools() -> AnyResult<(), NexusCliError> {
     Ok(())
 }
 
+/// Fetch the URLs of all tools registered in the tool registry.
+pub(crate) async fn fetch_tool_urls(
+    sui: &sui::Client,
+    tool_registry_id: sui::ObjectID,
+) -> AnyResult<HashMap<ToolFqn, reqwest::Url>> {
+    let tool_registry = fetch_one::<Structure<ToolRegistry>>(sui, tool_registry_id)
+        .await?
+        .data
+        .into_inner();
+
+    let tools = tool_registry.tools.fetch_all(sui).await?;
+
+    Ok(tools
+        .into_iter()
+        .map(|(fqn, tool)| (fqn, tool.into_inner().url))
+        .collect())
+}
+
 #[derive(Debug, Clone, Deserialize)]
-struct ToolRegistry {
-    tools: ObjectBag<ToolFqn, Structure<Tool>>,
+pub(super) struct ToolRegistry {
+    pub(super) tools: ObjectBag<ToolFqn, Structure<Tool>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
-struct Tool {
+pub(super) struct Tool {
     #[serde(deserialize_with = "deserialize_bytes_to_url")]
-    url: reqwest::Url,
+    pub(super) url: reqwest::Url,
     #[serde(deserialize_with = "deserialize_bytes_to_lossy_utf8")]
     description: String,
     #[serde(deserialize_with = "deserialize_string_to_datetime")]
     registered_at_ms: chrono::DateTime<chrono::Utc>,
+    #[serde(default)]
+    deprecated: bool,
 }
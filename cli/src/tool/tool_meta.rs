@@ -0,0 +1,168 @@
+use {
+    super::{
+        tool_health_check::fetch_on_chain_tool_url,
+        tool_validate::{fetch_tool_meta, tool_base_url},
+    },
+    crate::{
+        command_title,
+        display::json_output,
+        item,
+        loading,
+        notify_success,
+        prelude::*,
+        tool::ToolIdent,
+    },
+    nexus_sdk::types::ToolMeta,
+};
+
+/// Fetch and display the [`ToolMeta`] of either an off-chain or an on-chain
+/// tool together with whether the tool is healthy.
+pub(crate) async fn show_tool_meta(ident: ToolIdent) -> AnyResult<ToolMeta, NexusCliError> {
+    let url = match (ident.off_chain, ident.on_chain) {
+        (Some(url), None) => url,
+        (None, Some(ident)) => fetch_on_chain_tool_url(ident).await?,
+        _ => unreachable!("Checked by clap"),
+    };
+
+    command_title!("Fetching meta of Tool at '{url}'");
+
+    let base_url = tool_base_url(&url);
+
+    let meta_handle = loading!("Fetching tool meta...");
+
+    let meta = match fetch_tool_meta(&base_url).await {
+        Ok(meta) => meta,
+        Err(e) => {
+            meta_handle.error();
+
+            return Err(NexusCliError::Http(e));
+        }
+    };
+
+    meta_handle.success();
+
+    // The meta is shown even if the tool is not healthy.
+    let health_url = base_url
+        .join("health")
+        .expect("Appending health must be valid");
+
+    let healthy = reqwest::Client::new()
+        .get(health_url)
+        .send()
+        .await
+        .is_ok_and(|response| response.status().is_success());
+
+    let schema = |schema: &serde_json::Value| {
+        serde_json::to_string_pretty(schema).unwrap_or_else(|_| schema.to_string())
+    };
+
+    notify_success!(
+        "Tool '{fqn}' at '{url}'",
+        fqn = meta.fqn.to_string().truecolor(100, 100, 100),
+        url = meta.url.to_string().truecolor(100, 100, 100),
+    );
+    item!("Description: {}", meta.description.truecolor(100, 100, 100));
+    item!(
+        "Healthy: {}",
+        match healthy {
+            true => "yes".green(),
+            false => "no".red(),
+        }
+    );
+    item!(
+        "Input schema:\n{}",
+        schema(&meta.input_schema).truecolor(100, 100, 100)
+    );
+    item!(
+        "Output schema:\n{}",
+        schema(&meta.output_schema).truecolor(100, 100, 100)
+    );
+
+    json_output(&json!({
+        "fqn": meta.fqn,
+        "url": meta.url,
+        "description": meta.description,
+        "input_schema": meta.input_schema,
+        "output_schema": meta.output_schema,
+        "healthy": healthy,
+    }))?;
+
+    Ok(meta)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, mockito::Server};
+
+    fn off_chain(url: &str) -> ToolIdent {
+        ToolIdent {
+            off_chain: Some(reqwest::Url::parse(url).unwrap()),
+            on_chain: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_show_tool_meta() {
+        let mut server = Server::new_async().await;
+
+        let meta_mock = server
+            .mock("GET", "/dummy/tool/meta")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "fqn": "xyz.dummy.tool@1",
+                    "url": format!("{}/dummy/tool", server.url()),
+                    "description": "A dummy tool",
+                    "input_schema": { "type": "object", "required": ["prompt"] },
+                    "output_schema": { "oneOf": [{ "type": "object" }] }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let health_mock = server
+            .mock("GET", "/dummy/tool/health")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let meta = show_tool_meta(off_chain(&format!("{}/dummy/tool/", server.url())))
+            .await
+            .unwrap();
+
+        assert_eq!(meta.fqn, fqn!("xyz.dummy.tool@1"));
+        assert_eq!(meta.url.path(), "/dummy/tool");
+        assert_eq!(meta.description, "A dummy tool");
+        assert_eq!(
+            meta.input_schema,
+            json!({ "type": "object", "required": ["prompt"] })
+        );
+        assert_eq!(
+            meta.output_schema,
+            json!({ "oneOf": [{ "type": "object" }] })
+        );
+
+        meta_mock.assert_async().await;
+        health_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_show_tool_meta_invalid() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/meta")
+            .with_status(200)
+            .with_body("not json")
+            .create_async()
+            .await;
+
+        let result = show_tool_meta(off_chain(&server.url())).await;
+
+        assert!(matches!(result, Err(NexusCliError::Http(_))));
+
+        mock.assert_async().await;
+    }
+}
@@ -1,7 +1,7 @@
 use {
     crate::{
         command_title,
-        display::json_output,
+        display::{json_output, TxProgress},
         loading,
         notify_error,
         notify_success,
@@ -94,7 +94,7 @@ pub(crate) async fn register_tool(
         let reference_gas_price = fetch_reference_gas_price(&sui).await?;
 
         // Craft a TX to register the tool.
-        let tx_handle = loading!("Crafting transaction...");
+        let progress = TxProgress::new();
 
         // Explicitly check that we're registering an off-chain tool. This is mainly
         // for when we implement logic for on-chain so that we don't forget to
@@ -113,13 +113,11 @@ pub(crate) async fn register_tool(
             &collateral_coin,
             invocation_cost,
         ) {
-            tx_handle.error();
+            progress.error();
 
             return Err(NexusCliError::Any(e));
         }
 
-        tx_handle.success();
-
         let tx_data = sui::TransactionData::new_programmable(
             address,
             vec![gas_coin.object_ref()],
@@ -129,7 +127,7 @@ pub(crate) async fn register_tool(
         );
 
         // Sign and submit the TX.
-        let response = match sign_and_execute_transaction(&sui, &wallet, tx_data).await {
+        let response = match sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await {
             Ok(response) => response,
             // If the tool is already registered, we don't want to fail the
             // command.
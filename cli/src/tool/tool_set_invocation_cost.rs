@@ -1,5 +1,11 @@
 use {
-    crate::{command_title, display::json_output, loading, prelude::*, sui::*},
+    crate::{
+        command_title,
+        display::{format_mist, json_output, TxProgress},
+        loading,
+        prelude::*,
+        sui::*,
+    },
     nexus_sdk::transactions::tool,
 };
 
@@ -11,7 +17,10 @@ pub(crate) async fn set_tool_invocation_cost(
     sui_gas_coin: Option<sui::ObjectID>,
     sui_gas_budget: u64,
 ) -> AnyResult<(), NexusCliError> {
-    command_title!("Setting '{invocation_cost}' invocation cost for tool '{tool_fqn}'");
+    command_title!(
+        "Setting '{cost}' invocation cost for tool '{tool_fqn}'",
+        cost = format_mist(invocation_cost)
+    );
 
     // Load CLI configuration.
     let mut conf = CliConf::load().await.unwrap_or_default();
@@ -40,20 +49,18 @@ pub(crate) async fn set_tool_invocation_cost(
     let owner_cap = fetch_object_by_id(&sui, owner_cap).await?;
 
     // Craft the transaction.
-    let tx_handle = loading!("Crafting transaction...");
+    let progress = TxProgress::new();
 
     let mut tx = sui::ProgrammableTransactionBuilder::new();
 
     if let Err(e) =
         tool::set_invocation_cost(&mut tx, objects, &tool_fqn, &owner_cap, invocation_cost)
     {
-        tx_handle.error();
+        progress.error();
 
         return Err(NexusCliError::Any(e));
     }
 
-    tx_handle.success();
-
     let tx_data = sui::TransactionData::new_programmable(
         address,
         vec![gas_coin.object_ref()],
@@ -63,7 +70,7 @@ pub(crate) async fn set_tool_invocation_cost(
     );
 
     // Sign and submit the TX.
-    let response = sign_and_execute_transaction(&sui, &wallet, tx_data).await?;
+    let response = sign_and_execute_transaction(&sui, &wallet, tx_data, progress).await?;
 
     json_output(&json!({ "digest": response.digest }))?;
 
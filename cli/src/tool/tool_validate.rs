@@ -6,28 +6,24 @@ use {
 
 /// Validate either an off-chain or an on-chain tool.
 pub(crate) async fn validate_tool(ident: ToolIdent) -> AnyResult<ToolMeta, NexusCliError> {
+    // The tool FQN must be allowed by the configured namespace policy.
+    let conf = CliConf::load().await.unwrap_or_default();
+
     match (ident.off_chain, ident.on_chain) {
-        (Some(url), None) => validate_off_chain_tool(url).await,
+        (Some(url), None) => validate_off_chain_tool(url, &conf.tool_namespaces).await,
         (None, Some(ident)) => validate_on_chain_tool(ident).await,
         _ => unreachable!("Checked by clap"),
     }
 }
 
 /// Validate an off-chain tool based on the provided URL.
-async fn validate_off_chain_tool(url: reqwest::Url) -> AnyResult<ToolMeta, NexusCliError> {
+async fn validate_off_chain_tool(
+    url: reqwest::Url,
+    policy: &NamespacePolicy,
+) -> AnyResult<ToolMeta, NexusCliError> {
     command_title!("Validating off-chain Tool at '{url}'");
 
-    // Strip the trailing slash from the URL path.
-    let path = match url.path().strip_suffix('/') {
-        Some(path) => path,
-        None => url.path(),
-    };
-
-    // Append the path to the base URL with a trailing slash.
-    let full_path = format!("{path}/");
-    let base_url = url
-        .join(full_path.as_str())
-        .expect("Joining URL must be valid");
+    let base_url = tool_base_url(&url);
 
     // Check health.
     let health_handle = loading!("Checking tool health...");
@@ -57,18 +53,7 @@ async fn validate_off_chain_tool(url: reqwest::Url) -> AnyResult<ToolMeta, Nexus
     // Check meta.
     let meta_handle = loading!("Checking tool meta...");
 
-    let meta_url = base_url.join("meta").expect("Appending meta must be valid");
-
-    let response = match reqwest::Client::new().get(meta_url).send().await {
-        Ok(response) => response,
-        Err(error) => {
-            meta_handle.error();
-
-            return Err(NexusCliError::Http(error));
-        }
-    };
-
-    let meta = match response.json::<ToolMeta>().await {
+    let meta = match fetch_tool_meta(&base_url).await {
         Ok(meta) => meta,
         Err(error) => {
             meta_handle.error();
@@ -86,6 +71,13 @@ async fn validate_off_chain_tool(url: reqwest::Url) -> AnyResult<ToolMeta, Nexus
         )));
     }
 
+    // Check that the tool domain is allowed.
+    if let Err(e) = validate_namespace(&meta.fqn, policy) {
+        meta_handle.error();
+
+        return Err(NexusCliError::Any(e));
+    }
+
     // TODO: <https://github.com/Talus-Network/nexus-sdk/issues/107>
 
     meta_handle.success();
@@ -93,6 +85,35 @@ async fn validate_off_chain_tool(url: reqwest::Url) -> AnyResult<ToolMeta, Nexus
     Ok(meta)
 }
 
+/// The URL of a tool with a trailing slash so that its endpoints can be
+/// joined to it.
+pub(crate) fn tool_base_url(url: &reqwest::Url) -> reqwest::Url {
+    // Strip the trailing slash from the URL path.
+    let path = match url.path().strip_suffix('/') {
+        Some(path) => path,
+        None => url.path(),
+    };
+
+    // Append the path to the base URL with a trailing slash.
+    let full_path = format!("{path}/");
+
+    url.join(full_path.as_str())
+        .expect("Joining URL must be valid")
+}
+
+/// Fetch the [`ToolMeta`] from the `/meta` endpoint of the tool at
+/// `base_url`.
+pub(crate) async fn fetch_tool_meta(base_url: &reqwest::Url) -> Result<ToolMeta, reqwest::Error> {
+    let meta_url = base_url.join("meta").expect("Appending meta must be valid");
+
+    reqwest::Client::new()
+        .get(meta_url)
+        .send()
+        .await?
+        .json::<ToolMeta>()
+        .await
+}
+
 /// Validate an on-chain tool based on the provided ident.
 async fn validate_on_chain_tool(_ident: String) -> AnyResult<ToolMeta, NexusCliError> {
     todo!("TODO: <https://github.com/Talus-Network/nexus-next/issues/96>")
@@ -218,4 +239,28 @@ mod tests {
         let meta = meta.unwrap();
         assert_eq!(meta.fqn, fqn!("xyz.dummy.tool@1"));
     }
+
+    #[tokio::test]
+    async fn test_validate_enforces_namespace_policy() {
+        tokio::spawn(async move { bootstrap!(([127, 0, 0, 1], 8043), [DummyTool]) });
+
+        // Give the webserver some time to start.
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let url = reqwest::Url::parse("http://localhost:8043/").unwrap();
+
+        let allowed = NamespacePolicy {
+            allow: vec!["xyz.dummy".to_string()],
+            deny: vec![],
+        };
+        let meta = validate_off_chain_tool(url.clone(), &allowed).await;
+        assert_eq!(meta.unwrap().fqn, fqn!("xyz.dummy.tool@1"));
+
+        let denied = NamespacePolicy {
+            allow: vec![],
+            deny: vec!["xyz.dummy".to_string()],
+        };
+        let error = validate_off_chain_tool(url, &denied).await.unwrap_err();
+        assert!(error.to_string().contains("denied namespace 'xyz.dummy'"));
+    }
 }
@@ -40,6 +40,7 @@
 //! ```
 
 use {
+    super::ct_eq_bytes,
     aes_siv::{
         aead::{Aead, Payload},
         Aes128SivAead,
@@ -56,7 +57,6 @@ use {
         collections::{HashMap, VecDeque},
         num::NonZeroUsize,
     },
-    subtle::ConstantTimeEq,
     thiserror::Error,
     x25519_dalek::{PublicKey, StaticSecret},
     zeroize::{Zeroize, Zeroizing},
@@ -472,7 +472,7 @@ impl RatchetStateHE {
     /// Reject identity *and* any of the eight small‑order points.
     fn validate_pk(pk: &PublicKey) -> Result<(), RatchetError> {
         for bad in SMALL_ORDER {
-            if pk.as_bytes().ct_eq(&bad).unwrap_u8() == 1 {
+            if ct_eq_bytes(pk.as_bytes(), &bad) {
                 return Err(RatchetError::InvalidPublicKey);
             }
         }
@@ -726,13 +726,28 @@ impl RatchetStateHE {
         ciphertext: &[u8],
         ad: &[u8],
     ) -> Result<Vec<u8>, RatchetError> {
+        self.ratchet_decrypt_he_with_id(enc_header, ciphertext, ad)
+            .map(|(pt, _)| pt)
+    }
+
+    /// Same as [`ratchet_decrypt_he`](RatchetStateHE::ratchet_decrypt_he)
+    /// but also returns the `(header_key, n)` pair that identifies the
+    /// message. The header key is the one that decrypted the header.
+    pub(crate) fn ratchet_decrypt_he_with_id(
+        &mut self,
+        enc_header: &[u8],
+        ciphertext: &[u8],
+        ad: &[u8],
+    ) -> Result<(Vec<u8>, ([u8; 32], u32)), RatchetError> {
         // 0. try skipped keys first
-        if let Some(pt) = self.try_skipped_keys(enc_header, ciphertext, ad)? {
-            return Ok(pt);
+        if let Some(found) = self.try_skipped_keys(enc_header, ciphertext, ad)? {
+            return Ok(found);
         }
 
         // 1. decrypt header using HK_r or NHK_r
+        let nhkr = self.nhkr;
         let (header, used_nhk, hk_used) = self.decrypt_header(enc_header)?;
+        let message_id = (hk_used.unwrap_or(nhkr), header.n);
 
         // If we used NHK_r, we need to do a DH ratchet and then handle skipped messages
         if used_nhk {
@@ -780,7 +795,7 @@ impl RatchetStateHE {
         let mut full_ad = ad.to_vec();
         full_ad.extend_from_slice(enc_header);
         let cipher = Aes128SivAead::new_from_slice(&mk).unwrap();
-        let result = cipher
+        let result: Result<Vec<u8>, RatchetError> = cipher
             .decrypt(
                 Nonce::from_slice(nonce_bytes),
                 Payload {
@@ -797,7 +812,13 @@ impl RatchetStateHE {
             }
         }
 
-        result
+        result.map(|pt| (pt, message_id))
+    }
+
+    /// Whether `enc_header` decrypts under `hk` to a header with counter `n`,
+    /// i.e. whether it belongs to the message identified by `(hk, n)`.
+    pub(crate) fn header_matches(hk: &[u8; 32], enc_header: &[u8], n: u32) -> bool {
+        Self::hdecrypt(hk, enc_header).is_ok_and(|hdr| hdr.n == n)
     }
 
     // === Internal helpers (skipped messages, DH‑ratchet) ===
@@ -808,18 +829,19 @@ impl RatchetStateHE {
         enc_header: &[u8],
         ciphertext: &[u8],
         ad: &[u8],
-    ) -> Result<Option<Vec<u8>>, RatchetError> {
+    ) -> Result<Option<(Vec<u8>, ([u8; 32], u32))>, RatchetError> {
         // Try all stored skipped message keys
         let keys: Vec<_> = self.mkskipped.keys().cloned().collect();
         for (hk, n) in keys {
             if let Ok(hdr) = Self::hdecrypt(&hk, enc_header) {
+                // The counter is public, only the header key is secret.
                 if hdr.n == n {
                     if let Some(mk) = self.mkskipped.get(&(hk, n)) {
                         // Try decrypting with the skipped key, ignore errors and only succeed on valid decryption.
                         if let Ok(Some(pt)) = Self::decrypt_with_mk(ciphertext, ad, enc_header, mk)
                         {
                             self.mkskipped.remove(&(hk, n));
-                            return Ok(Some(pt));
+                            return Ok(Some((pt, (hk, n))));
                         }
                     }
                 }
@@ -1032,7 +1054,8 @@ impl RatchetStateHE {
     /// * `n_max`      – if `Some(m)`, forget indices ≤ *m*; `None` ⇒ forget all.
     pub fn commit_receiver(&mut self, header_key: Option<[u8; 32]>, n_max: Option<u32>) {
         self.mkskipped.retain(|(hk, n), _| {
-            let hk_ok = header_key.is_none_or(|h| hk != &h);
+            // Header keys are secret so compare them in constant time.
+            let hk_ok = header_key.is_none_or(|h| !ct_eq_bytes(hk, &h));
             let n_ok = n_max.is_some_and(|m| *n > m);
             hk_ok && n_ok
         });
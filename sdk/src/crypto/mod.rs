@@ -2,3 +2,32 @@ pub mod double_ratchet;
 pub mod secret_bytes;
 pub mod session;
 pub mod x3dh;
+
+use subtle::ConstantTimeEq;
+
+/// Compare two byte strings in constant time.
+///
+/// Use this for anything derived from secrets, such as header keys, chain
+/// keys or MACs, so that the time taken does not reveal how many leading
+/// bytes match. Slices of different lengths are unequal; only the lengths are
+/// compared in variable time, and those are not secret.
+///
+/// Public values like identity keys, message counters or protocol versions
+/// can be compared with `==`.
+pub fn ct_eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    a.ct_eq(b).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ct_eq_bytes() {
+        assert!(ct_eq_bytes(&[1, 2, 3], &[1, 2, 3]));
+        assert!(ct_eq_bytes(&[], &[]));
+        assert!(!ct_eq_bytes(&[1, 2, 3], &[1, 2, 4]));
+        assert!(!ct_eq_bytes(&[1, 2, 3], &[1, 2]));
+        assert!(!ct_eq_bytes(&[0; 32], &[1; 32]));
+    }
+}
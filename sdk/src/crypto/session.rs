@@ -61,6 +61,7 @@ use {
     hkdf::Hkdf,
     serde::{Deserialize, Deserializer, Serialize, Serializer},
     sha2::{Digest, Sha256},
+    std::collections::VecDeque,
     thiserror::Error,
     x25519_dalek::{PublicKey, StaticSecret},
     zeroize::{Zeroize, Zeroizing},
@@ -73,6 +74,9 @@ const PROTOCOL_VERSION: u8 = 1;
 /// Change when you want to have a domain separation
 const HKDF_SALT: [u8; 32] = *b"X3DH-DR-v1-2025-05-20-----------";
 
+/// Default number of recently accepted messages remembered to reject replays.
+pub const DEFAULT_REPLAY_WINDOW: usize = 64;
+
 /// Errors that can arise during session establishment or normal messaging.
 #[derive(Debug, Error)]
 pub enum SessionError {
@@ -91,6 +95,9 @@ pub enum SessionError {
     /// Message claims an unsupported protocol version.
     #[error("Unsupported protocol version {0}")]
     Version(u8),
+    /// Message was already accepted by this session.
+    #[error("Message was already received")]
+    Replay,
 }
 
 impl From<hkdf::InvalidLength> for SessionError {
@@ -139,6 +146,62 @@ pub struct Session {
     local_identity: PublicKey,
     /// Remote peer's identity-DH public key.
     remote_identity: PublicKey,
+    /// Recently accepted messages, see [`Session::set_replay_window`].
+    replay_window: ReplayWindow,
+}
+
+/// Bounded FIFO of the `(header_key, n)` pairs of recently accepted messages.
+///
+/// Not persisted: a session restored from storage starts with an empty window
+/// and relies on the ratchet alone, which fails to decrypt most replays.
+struct ReplayWindow {
+    accepted: VecDeque<([u8; 32], u32)>,
+    size: usize,
+}
+
+impl ReplayWindow {
+    fn new(size: usize) -> Self {
+        Self {
+            accepted: VecDeque::with_capacity(size),
+            size,
+        }
+    }
+
+    /// Whether `enc_header` belongs to an already accepted message. Header
+    /// keys are secret so headers are matched by trial decryption rather than
+    /// by comparing keys.
+    fn contains(&self, enc_header: &[u8]) -> bool {
+        self.accepted
+            .iter()
+            .any(|(hk, n)| RatchetStateHE::header_matches(hk, enc_header, *n))
+    }
+
+    fn insert(&mut self, message_id: ([u8; 32], u32)) {
+        if self.size == 0 {
+            return;
+        }
+
+        while self.accepted.len() >= self.size {
+            self.accepted.pop_front();
+        }
+
+        self.accepted.push_back(message_id);
+    }
+
+    fn resize(&mut self, size: usize) {
+        while self.accepted.len() > size {
+            self.accepted.pop_front();
+        }
+
+        self.size = size;
+    }
+}
+
+impl Drop for ReplayWindow {
+    /// Zeroises the remembered header keys on drop.
+    fn drop(&mut self) {
+        self.accepted.iter_mut().for_each(|(hk, _)| hk.zeroize());
+    }
 }
 
 impl Serialize for Session {
@@ -173,6 +236,7 @@ impl<'de> Deserialize<'de> for Session {
             ratchet,
             local_identity: PublicKey::from(local_bytes),
             remote_identity: PublicKey::from(remote_bytes),
+            replay_window: ReplayWindow::new(DEFAULT_REPLAY_WINDOW),
         })
     }
 }
@@ -193,7 +257,8 @@ impl Session {
     /// Constructs per-session Associated-Data as `min(IK_A, IK_B) || max(IK_A, IK_B)`.
     ///
     /// Ordering the identity keys lexicographically ensures both peers derive
-    /// the same AD irrespective of role (Sender/Receiver).
+    /// the same AD irrespective of role (Sender/Receiver). Identity keys are
+    /// public so a variable-time comparison is fine here.
     fn make_associated_data(&self) -> Vec<u8> {
         let (first, second) = if self.local_identity.as_bytes() < self.remote_identity.as_bytes() {
             (
@@ -259,6 +324,7 @@ impl Session {
                 ratchet,
                 local_identity: identity.dh_public,
                 remote_identity: bundle.identity_pk,
+                replay_window: ReplayWindow::new(DEFAULT_REPLAY_WINDOW),
             },
         ))
     }
@@ -316,6 +382,7 @@ impl Session {
                 ratchet,
                 local_identity: identity.dh_public,
                 remote_identity: msg.ika_pub,
+                replay_window: ReplayWindow::new(DEFAULT_REPLAY_WINDOW),
             },
             plaintext,
         ))
@@ -330,6 +397,15 @@ impl Session {
         &self.session_id
     }
 
+    /// Sets how many recently accepted messages are remembered to reject
+    /// replays, [`DEFAULT_REPLAY_WINDOW`] by default. `0` disables the check.
+    ///
+    /// Every incoming message is checked against each remembered message, so
+    /// the cost of [`Session::decrypt`] grows with the window.
+    pub fn set_replay_window(&mut self, size: usize) {
+        self.replay_window.resize(size);
+    }
+
     /// Encrypts `plaintext`, advances the sending chain, and returns a
     /// [`Message::Standard`].  Fails only if ratchet state is inconsistent.
     pub fn encrypt(&mut self, plaintext: &[u8]) -> Result<Message, SessionError> {
@@ -350,6 +426,8 @@ impl Session {
     ///
     /// *Errors*:
     /// * `SessionError::Version` — peer sent an unsupported version tag.
+    /// * `SessionError::Replay` — message was already accepted, see
+    ///   [`Session::set_replay_window`].
     /// * `SessionError::DecryptionFailed` — MAC failed / bad ciphertext.
     pub fn decrypt(&mut self, message: &Message) -> Result<Vec<u8>, SessionError> {
         match message {
@@ -361,13 +439,23 @@ impl Session {
                 header,
                 ciphertext,
             }) => {
+                // The version is sent in the clear, no need for a
+                // constant-time comparison.
                 if *version != PROTOCOL_VERSION {
                     return Err(SessionError::Version(*version));
                 }
+                // Checked before decrypting so that a replay never touches the
+                // ratchet state.
+                if self.replay_window.contains(header) {
+                    return Err(SessionError::Replay);
+                }
                 let ad = self.make_associated_data();
-                self.ratchet
-                    .ratchet_decrypt_he(header, ciphertext, &ad)
-                    .map_err(|_| SessionError::DecryptionFailed)
+                let (plaintext, message_id) = self
+                    .ratchet
+                    .ratchet_decrypt_he_with_id(header, ciphertext, &ad)
+                    .map_err(|_| SessionError::DecryptionFailed)?;
+                self.replay_window.insert(message_id);
+                Ok(plaintext)
             }
         }
     }
@@ -422,6 +510,7 @@ impl Session {
             ratchet,
             local_identity,
             remote_identity,
+            replay_window: ReplayWindow::new(DEFAULT_REPLAY_WINDOW),
         }
     }
 
@@ -1377,4 +1466,50 @@ mod tests {
             "Sender should read their own array message correctly"
         );
     }
+
+    #[test]
+    fn test_replay_rejected() {
+        let sender_id = IdentityKey::generate();
+        let receiver_id = IdentityKey::generate();
+        let spk_secret = StaticSecret::random_from_rng(OsRng);
+        let bundle = PreKeyBundle::new(&receiver_id, 1, &spk_secret, None, None);
+        let (message, mut sender_sess) = Session::initiate(&sender_id, &bundle, b"msg").unwrap();
+
+        let initial_msg = match message {
+            Message::Initial(msg) => msg,
+            _ => panic!("Expected Initial message type"),
+        };
+
+        let (mut receiver_sess, _) =
+            Session::recv(&receiver_id, &spk_secret, &bundle, &initial_msg, None).unwrap();
+
+        let first = sender_sess.encrypt(b"first").unwrap();
+        let second = sender_sess.encrypt(b"second").unwrap();
+
+        // Out of order so that `first` is served from the skipped keys.
+        assert_eq!(receiver_sess.decrypt(&second).unwrap(), b"second");
+        assert_eq!(receiver_sess.decrypt(&first).unwrap(), b"first");
+
+        // Replaying the identical bytes is rejected as such.
+        assert!(matches!(
+            receiver_sess.decrypt(&second),
+            Err(SessionError::Replay)
+        ));
+        assert!(matches!(
+            receiver_sess.decrypt(&first),
+            Err(SessionError::Replay)
+        ));
+
+        // The session still works after the replays.
+        let third = sender_sess.encrypt(b"third").unwrap();
+        assert_eq!(receiver_sess.decrypt(&third).unwrap(), b"third");
+
+        // Once the message falls out of the window, the ratchet itself still
+        // refuses to decrypt it.
+        receiver_sess.set_replay_window(0);
+        assert!(matches!(
+            receiver_sess.decrypt(&second),
+            Err(SessionError::DecryptionFailed)
+        ));
+    }
 }
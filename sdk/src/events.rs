@@ -325,6 +325,51 @@ impl TryInto<NexusEvent> for sui::Event {
     }
 }
 
+impl RuntimeVertex {
+    /// Derive the status of this vertex in the given execution from the events
+    /// emitted so far, in the order they were emitted. Events of other
+    /// executions and vertices are ignored.
+    ///
+    /// A vertex that was not evaluated by the time the execution finished is
+    /// [`VertexStatus::Skipped`].
+    pub fn status<'a>(
+        &self,
+        execution: sui::ObjectID,
+        events: impl IntoIterator<Item = &'a NexusEventKind>,
+    ) -> VertexStatus {
+        events
+            .into_iter()
+            .fold(VertexStatus::Pending, |status, event| match event {
+                NexusEventKind::GasSettlementUpdate(e)
+                    if e.execution == execution && &e.vertex == self && !status.is_final() =>
+                {
+                    VertexStatus::Running
+                }
+                NexusEventKind::WalkAdvanced(e)
+                    if e.execution == execution && &e.vertex == self =>
+                {
+                    VertexStatus::Succeeded
+                }
+                NexusEventKind::EndStateReached(e)
+                    if e.execution == execution && &e.vertex == self =>
+                {
+                    VertexStatus::Succeeded
+                }
+                NexusEventKind::WalkFailed(e) if e.execution == execution && &e.vertex == self => {
+                    VertexStatus::Failed {
+                        reason: e.reason.clone(),
+                    }
+                }
+                NexusEventKind::ExecutionFinished(e)
+                    if e.execution == execution && !status.is_final() =>
+                {
+                    VertexStatus::Skipped
+                }
+                _ => status,
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {super::*, assert_matches::assert_matches};
@@ -406,4 +451,103 @@ mod tests {
                 e.worksheet_from_type.name == *"bar"
         );
     }
+
+    #[test]
+    fn test_runtime_vertex_status() {
+        let execution = sui::ObjectID::random();
+        let other_execution = sui::ObjectID::random();
+        let dag = sui::ObjectID::random();
+        let vertex = RuntimeVertex::plain("a");
+        let other_vertex = RuntimeVertex::plain("b");
+
+        let requested = NexusEventKind::RequestWalkExecution(RequestWalkExecutionEvent {
+            dag,
+            execution,
+            walk_index: 0,
+            next_vertex: vertex.clone(),
+            evaluations: sui::ObjectID::random(),
+            worksheet_from_type: TypeName {
+                name: "worksheet".to_string(),
+            },
+        });
+        let settled = |execution, vertex: &RuntimeVertex| {
+            NexusEventKind::GasSettlementUpdate(GasSettlementUpdateEvent {
+                execution,
+                tool_fqn: crate::fqn!("xyz.dummy.tool@1"),
+                vertex: vertex.clone(),
+                was_settled: true,
+            })
+        };
+        let advanced = |vertex: &RuntimeVertex| {
+            NexusEventKind::WalkAdvanced(WalkAdvancedEvent {
+                dag,
+                execution,
+                walk_index: 0,
+                vertex: vertex.clone(),
+                variant: TypeName {
+                    name: "ok".to_string(),
+                },
+                variant_ports_to_data: serde_json::json!({}),
+            })
+        };
+        let end_state = NexusEventKind::EndStateReached(EndStateReachedEvent {
+            dag,
+            execution,
+            walk_index: 0,
+            vertex: vertex.clone(),
+            variant: TypeName {
+                name: "ok".to_string(),
+            },
+            variant_ports_to_data: serde_json::json!({}),
+        });
+        let failed = NexusEventKind::WalkFailed(WalkFailedEvent {
+            dag,
+            execution,
+            walk_index: 0,
+            vertex: vertex.clone(),
+            reason: "tool unreachable".to_string(),
+        });
+        let finished = NexusEventKind::ExecutionFinished(ExecutionFinishedEvent {
+            dag,
+            execution,
+            has_any_walk_failed: false,
+            has_any_walk_succeeded: true,
+        });
+        let (settled_a, advanced_a) = (settled(execution, &vertex), advanced(&vertex));
+        let (settled_other_execution, settled_b, advanced_b) = (
+            settled(other_execution, &vertex),
+            settled(execution, &other_vertex),
+            advanced(&other_vertex),
+        );
+
+        let cases = [
+            (vec![], VertexStatus::Pending),
+            (vec![&requested], VertexStatus::Pending),
+            (vec![&requested, &settled_a], VertexStatus::Running),
+            (
+                vec![&requested, &settled_a, &advanced_a],
+                VertexStatus::Succeeded,
+            ),
+            (
+                vec![&requested, &end_state, &finished],
+                VertexStatus::Succeeded,
+            ),
+            (
+                vec![&requested, &failed, &finished],
+                VertexStatus::Failed {
+                    reason: "tool unreachable".to_string(),
+                },
+            ),
+            (vec![&requested, &finished], VertexStatus::Skipped),
+            // Events of other executions and vertices are ignored.
+            (
+                vec![&settled_other_execution, &settled_b, &advanced_b],
+                VertexStatus::Pending,
+            ),
+        ];
+
+        for (events, expected) in cases {
+            assert_eq!(vertex.status(execution, events), expected);
+        }
+    }
 }
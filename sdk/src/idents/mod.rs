@@ -19,6 +19,8 @@ pub mod move_std;
 pub mod primitives;
 pub mod sui_framework;
 pub mod tap;
+pub mod version;
+pub mod walrus;
 pub mod workflow;
 
 use crate::sui;
@@ -30,6 +30,54 @@ impl Ascii {
     }
 }
 
+// == `std::option` ==
+
+pub struct MoveOption;
+
+const OPTION_MODULE: &sui::MoveIdentStr = sui::move_ident_str!("option");
+
+impl MoveOption {
+    /// `std::option::none`
+    pub const NONE: ModuleAndNameIdent = ModuleAndNameIdent {
+        module: OPTION_MODULE,
+        name: sui::move_ident_str!("none"),
+    };
+    /// `std::option::some`
+    pub const SOME: ModuleAndNameIdent = ModuleAndNameIdent {
+        module: OPTION_MODULE,
+        name: sui::move_ident_str!("some"),
+    };
+
+    /// Create a Move `Option<T>` that holds `value` of type `T`.
+    pub fn option_some(
+        tx: &mut sui::ProgrammableTransactionBuilder,
+        type_tag: sui::MoveTypeTag,
+        value: sui::Argument,
+    ) -> sui::Argument {
+        tx.programmable_move_call(
+            sui::MOVE_STDLIB_PACKAGE_ID,
+            Self::SOME.module.into(),
+            Self::SOME.name.into(),
+            vec![type_tag],
+            vec![value],
+        )
+    }
+
+    /// Create an empty Move `Option<T>`.
+    pub fn option_none(
+        tx: &mut sui::ProgrammableTransactionBuilder,
+        type_tag: sui::MoveTypeTag,
+    ) -> sui::Argument {
+        tx.programmable_move_call(
+            sui::MOVE_STDLIB_PACKAGE_ID,
+            Self::NONE.module.into(),
+            Self::NONE.name.into(),
+            vec![type_tag],
+            vec![],
+        )
+    }
+}
+
 // == `std::vector` ==
 
 pub struct Vector;
@@ -47,4 +95,85 @@ impl Vector {
         module: VECTOR_MODULE,
         name: sui::move_ident_str!("push_back"),
     };
+
+    /// Create a Move `vector<T>` from arguments of type `T`. The type tag is
+    /// always passed so that empty vectors can be created too.
+    pub fn make_vector(
+        tx: &mut sui::ProgrammableTransactionBuilder,
+        type_tag: sui::MoveTypeTag,
+        elements: Vec<sui::Argument>,
+    ) -> sui::Argument {
+        tx.command(sui::Command::make_move_vec(Some(type_tag), elements))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_option_some() {
+        let mut tx = sui::ProgrammableTransactionBuilder::new();
+        let value = tx.pure(42u64).unwrap();
+
+        let option = MoveOption::option_some(&mut tx, sui::MoveTypeTag::U64, value);
+
+        assert_eq!(option, sui::Argument::Result(0));
+
+        let tx = tx.finish();
+        let sui::Command::MoveCall(call) = &tx.commands.last().unwrap() else {
+            panic!("Expected last command to be a MoveCall to create an option");
+        };
+
+        assert_eq!(call.package, sui::MOVE_STDLIB_PACKAGE_ID);
+        assert_eq!(call.module, MoveOption::SOME.module.to_string());
+        assert_eq!(call.function, MoveOption::SOME.name.to_string());
+        assert_eq!(call.type_arguments.len(), 1);
+        assert_eq!(call.arguments, vec![value]);
+    }
+
+    #[test]
+    fn test_option_none() {
+        let mut tx = sui::ProgrammableTransactionBuilder::new();
+
+        MoveOption::option_none(&mut tx, sui::MoveTypeTag::Address);
+
+        let tx = tx.finish();
+        let sui::Command::MoveCall(call) = &tx.commands.last().unwrap() else {
+            panic!("Expected last command to be a MoveCall to create an option");
+        };
+
+        assert_eq!(call.package, sui::MOVE_STDLIB_PACKAGE_ID);
+        assert_eq!(call.module, MoveOption::NONE.module.to_string());
+        assert_eq!(call.function, MoveOption::NONE.name.to_string());
+        assert_eq!(call.type_arguments.len(), 1);
+        assert!(call.arguments.is_empty());
+    }
+
+    #[test]
+    fn test_make_vector() {
+        let mut tx = sui::ProgrammableTransactionBuilder::new();
+        let first = tx.pure(1u8).unwrap();
+        let second = tx.pure(2u8).unwrap();
+
+        Vector::make_vector(&mut tx, sui::MoveTypeTag::U8, vec![first, second]);
+        Vector::make_vector(&mut tx, sui::MoveTypeTag::U8, vec![]);
+
+        let tx = tx.finish();
+
+        assert_eq!(tx.commands.len(), 2);
+
+        let sui::Command::MakeMoveVec(Some(type_tag), elements) = &tx.commands[0] else {
+            panic!("Expected command to be a MakeMoveVec with a type tag");
+        };
+
+        assert_eq!(*type_tag, sui::MoveTypeTag::U8);
+        assert_eq!(*elements, vec![first, second]);
+
+        let sui::Command::MakeMoveVec(Some(_), elements) = &tx.commands[1] else {
+            panic!("Expected command to be a MakeMoveVec with a type tag");
+        };
+
+        assert!(elements.is_empty());
+    }
 }
@@ -0,0 +1,329 @@
+//! Nexus Move packages are upgraded over time and new functions only exist
+//! from a certain package version onwards. This module defines the versions
+//! in which the idents used by the SDK are available so that incompatible
+//! on-chain packages can be detected before a PTB fails with a cryptic
+//! "function not found" error.
+//!
+//! # Example
+//!
+//! ```
+//! use nexus_sdk::idents::{version::*, workflow};
+//!
+//! let range = "2..".parse::<VersionRange>().unwrap();
+//! let required = [VersionedIdent {
+//!     ident: workflow::Dag::CANCEL_EXECUTION,
+//!     versions: range,
+//! }];
+//!
+//! assert!(check_compatibility(PackageVersion(2), &required).is_ok());
+//! assert!(check_compatibility(PackageVersion(1), &required).is_err());
+//! ```
+
+use {
+    crate::{
+        idents::{workflow, ModuleAndNameIdent},
+        sui,
+    },
+    std::{fmt, str::FromStr},
+    thiserror::Error,
+};
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum VersionError {
+    #[error("Invalid package version '{0}'")]
+    InvalidVersion(String),
+    #[error("Invalid package version range '{0}'")]
+    InvalidRange(String),
+    #[error(
+        "On-chain package at version {version} is missing the following idents: {}",
+        missing.join(", ")
+    )]
+    Incompatible {
+        version: PackageVersion,
+        missing: Vec<String>,
+    },
+}
+
+/// Version of a published Move package. Packages are published at version 1
+/// and every upgrade increments the version by one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackageVersion(pub u64);
+
+impl From<sui::SequenceNumber> for PackageVersion {
+    fn from(version: sui::SequenceNumber) -> Self {
+        Self(version.value())
+    }
+}
+
+impl fmt::Display for PackageVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{}", self.0)
+    }
+}
+
+/// Parse a version in the `3` or `v3` format.
+impl FromStr for PackageVersion {
+    type Err = VersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let number = trimmed.strip_prefix('v').unwrap_or(trimmed);
+
+        match number.parse::<u64>() {
+            Ok(version) if version > 0 => Ok(Self(version)),
+            _ => Err(VersionError::InvalidVersion(s.to_string())),
+        }
+    }
+}
+
+/// Inclusive range of package versions. A missing `max` means that the range
+/// is open ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VersionRange {
+    pub min: PackageVersion,
+    pub max: Option<PackageVersion>,
+}
+
+impl VersionRange {
+    /// All versions starting with `min`.
+    pub const fn since(min: u64) -> Self {
+        Self {
+            min: PackageVersion(min),
+            max: None,
+        }
+    }
+
+    /// All versions between `min` and `max`, both inclusive.
+    pub const fn between(min: u64, max: u64) -> Self {
+        Self {
+            min: PackageVersion(min),
+            max: Some(PackageVersion(max)),
+        }
+    }
+
+    /// Whether the given version is within this range.
+    pub fn contains(&self, version: PackageVersion) -> bool {
+        version >= self.min && self.max.is_none_or(|max| version <= max)
+    }
+}
+
+impl fmt::Display for VersionRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.max {
+            Some(max) if max == self.min => write!(f, "{}", self.min.0),
+            Some(max) => write!(f, "{}..={}", self.min.0, max.0),
+            None => write!(f, "{}..", self.min.0),
+        }
+    }
+}
+
+/// Parse a range in one of the following formats:
+///
+/// - `2` for exactly version 2
+/// - `2..` for version 2 and later
+/// - `..=4` for versions up to and including 4
+/// - `2..=4` for versions 2 to 4, both inclusive
+impl FromStr for VersionRange {
+    type Err = VersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || VersionError::InvalidRange(s.to_string());
+        let trimmed = s.trim();
+
+        let Some((min, max)) = trimmed.split_once("..") else {
+            let version = trimmed.parse::<PackageVersion>().map_err(|_| invalid())?;
+
+            return Ok(Self {
+                min: version,
+                max: Some(version),
+            });
+        };
+
+        let min = match min.trim() {
+            "" => PackageVersion(1),
+            min => min.parse().map_err(|_| invalid())?,
+        };
+
+        let max = match max.trim() {
+            "" => None,
+            max => match max.strip_prefix('=') {
+                Some(max) => Some(max.parse::<PackageVersion>().map_err(|_| invalid())?),
+                // Exclusive upper bounds are not supported to avoid confusion.
+                None => return Err(invalid()),
+            },
+        };
+
+        if max.is_some_and(|max| max < min) {
+            return Err(invalid());
+        }
+
+        Ok(Self { min, max })
+    }
+}
+
+/// A Move resource referenced by the SDK along with the package versions
+/// it exists in.
+pub struct VersionedIdent {
+    pub ident: ModuleAndNameIdent,
+    pub versions: VersionRange,
+}
+
+impl VersionedIdent {
+    /// Fully qualified name of the ident, e.g. `dag::cancel_execution`.
+    pub fn qualified_name(&self) -> String {
+        format!("{}::{}", self.ident.module, self.ident.name)
+    }
+}
+
+/// Idents of the workflow package that do not exist in all of its versions.
+/// Idents that are not listed here have been available since version 1.
+pub const WORKFLOW_IDENTS: &[VersionedIdent] = &[
+    VersionedIdent {
+        ident: workflow::Dag::CANCEL_EXECUTION,
+        versions: VersionRange::since(2),
+    },
+    VersionedIdent {
+        ident: workflow::ToolRegistry::DEPRECATE_TOOL,
+        versions: VersionRange::since(2),
+    },
+    VersionedIdent {
+        ident: workflow::ToolRegistry::REDIRECT_TOOL,
+        versions: VersionRange::since(2),
+    },
+];
+
+/// Check that all `idents` exist in a package published at `version`.
+/// Returns an error listing all idents that do not.
+pub fn check_compatibility(
+    version: PackageVersion,
+    idents: &[VersionedIdent],
+) -> Result<(), VersionError> {
+    let missing = idents
+        .iter()
+        .filter(|ident| !ident.versions.contains(version))
+        .map(|ident| format!("{} ({})", ident.qualified_name(), ident.versions))
+        .collect::<Vec<_>>();
+
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    Err(VersionError::Incompatible { version, missing })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versioned(ident: ModuleAndNameIdent, versions: &str) -> VersionedIdent {
+        VersionedIdent {
+            ident,
+            versions: versions.parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_package_version_from_str() {
+        assert_eq!("3".parse(), Ok(PackageVersion(3)));
+        assert_eq!(" v12 ".parse(), Ok(PackageVersion(12)));
+        assert_eq!(PackageVersion(3).to_string(), "v3");
+
+        assert!("0".parse::<PackageVersion>().is_err());
+        assert!("-1".parse::<PackageVersion>().is_err());
+        assert!("x3".parse::<PackageVersion>().is_err());
+        assert!("".parse::<PackageVersion>().is_err());
+    }
+
+    #[test]
+    fn test_version_range_from_str() {
+        assert_eq!("2".parse(), Ok(VersionRange::between(2, 2)));
+        assert_eq!("2..".parse(), Ok(VersionRange::since(2)));
+        assert_eq!("..=4".parse(), Ok(VersionRange::between(1, 4)));
+        assert_eq!("v2..=v4".parse(), Ok(VersionRange::between(2, 4)));
+        assert_eq!(" 2 ..= 4 ".parse(), Ok(VersionRange::between(2, 4)));
+
+        assert_eq!(
+            "2..4".parse::<VersionRange>(),
+            Err(VersionError::InvalidRange("2..4".to_string()))
+        );
+        assert!("4..=2".parse::<VersionRange>().is_err());
+        assert!("a..".parse::<VersionRange>().is_err());
+        assert!("..".parse::<VersionRange>().is_ok());
+
+        // Ranges display in the same format they are parsed from.
+        for range in ["2", "2..", "1..=4"] {
+            assert_eq!(range.parse::<VersionRange>().unwrap().to_string(), range);
+        }
+    }
+
+    #[test]
+    fn test_version_range_contains() {
+        let range = VersionRange::between(2, 4);
+
+        assert!(!range.contains(PackageVersion(1)));
+        assert!(range.contains(PackageVersion(2)));
+        assert!(range.contains(PackageVersion(4)));
+        assert!(!range.contains(PackageVersion(5)));
+
+        assert!(VersionRange::since(2).contains(PackageVersion(u64::MAX)));
+    }
+
+    #[test]
+    fn test_check_compatibility_compatible() {
+        let idents = [
+            versioned(workflow::Dag::NEW, "1.."),
+            versioned(workflow::Dag::CANCEL_EXECUTION, "3.."),
+            versioned(workflow::ToolRegistry::CLAIM_COLLATERAL_FOR_TOOL, "..=5"),
+        ];
+
+        assert_eq!(check_compatibility(PackageVersion(3), &idents), Ok(()));
+        assert_eq!(check_compatibility(PackageVersion(5), &idents), Ok(()));
+        assert_eq!(check_compatibility(PackageVersion(1), &[]), Ok(()));
+    }
+
+    #[test]
+    fn test_check_compatibility_incompatible() {
+        let idents = [
+            versioned(workflow::Dag::NEW, "1.."),
+            versioned(workflow::Dag::CANCEL_EXECUTION, "3.."),
+            versioned(workflow::ToolRegistry::DEPRECATE_TOOL, "4.."),
+            versioned(workflow::ToolRegistry::CLAIM_COLLATERAL_FOR_TOOL, "..=5"),
+        ];
+
+        let err = check_compatibility(PackageVersion(2), &idents).unwrap_err();
+
+        assert_eq!(
+            err,
+            VersionError::Incompatible {
+                version: PackageVersion(2),
+                missing: vec![
+                    "dag::cancel_execution (3..)".to_string(),
+                    "tool_registry::deprecate_tool (4..)".to_string(),
+                ],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "On-chain package at version v2 is missing the following idents: dag::cancel_execution (3..), tool_registry::deprecate_tool (4..)"
+        );
+
+        // Idents that were removed in an upgrade are reported too.
+        let err = check_compatibility(PackageVersion(6), &idents).unwrap_err();
+
+        assert!(
+            matches!(err, VersionError::Incompatible { missing, .. } if missing == vec!["tool_registry::claim_collateral_for_off_chain_tool (1..=5)"])
+        );
+    }
+
+    #[test]
+    fn test_workflow_idents() {
+        assert_eq!(
+            check_compatibility(PackageVersion(2), WORKFLOW_IDENTS),
+            Ok(())
+        );
+
+        let err = check_compatibility(PackageVersion(1), WORKFLOW_IDENTS).unwrap_err();
+
+        assert!(err.to_string().contains("dag::cancel_execution (2..)"));
+    }
+}
@@ -0,0 +1,18 @@
+//! Identifiers of the Walrus Move package. The package ID differs between
+//! networks and is passed in at runtime.
+
+use crate::{idents::ModuleAndNameIdent, sui};
+
+// == `walrus::blob` ==
+
+pub struct Blob;
+
+const BLOB_MODULE: &sui::MoveIdentStr = sui::move_ident_str!("blob");
+
+impl Blob {
+    /// `walrus::blob::insert_or_update_metadata_pair`
+    pub const INSERT_OR_UPDATE_METADATA_PAIR: ModuleAndNameIdent = ModuleAndNameIdent {
+        module: BLOB_MODULE,
+        name: sui::move_ident_str!("insert_or_update_metadata_pair"),
+    };
+}
@@ -36,6 +36,13 @@ pub struct Dag;
 const DAG_MODULE: &sui::MoveIdentStr = sui::move_ident_str!("dag");
 
 impl Dag {
+    /// Cancel a running DAG execution.
+    ///
+    /// `nexus_workflow::dag::cancel_execution`
+    pub const CANCEL_EXECUTION: ModuleAndNameIdent = ModuleAndNameIdent {
+        module: DAG_MODULE,
+        name: sui::move_ident_str!("cancel_execution"),
+    };
     /// The DAG struct. Mostly used for creating generic types.
     ///
     /// `nexus_workflow::dag::DAG`
@@ -498,6 +505,13 @@ impl ToolRegistry {
         // TODO: This will likely be renamed to `claim_collateral_for_tool`.
         name: sui::move_ident_str!("claim_collateral_for_off_chain_tool"),
     };
+    /// Mark a tool as deprecated with the provided reason.
+    ///
+    /// `nexus_workflow::tool_registry::deprecate_tool`
+    pub const DEPRECATE_TOOL: ModuleAndNameIdent = ModuleAndNameIdent {
+        module: TOOL_REGISTRY_MODULE,
+        name: sui::move_ident_str!("deprecate_tool"),
+    };
     /// OverSlashing struct type. Used to fetch caps for slashing tools.
     ///
     /// `nexus_workflow::tool_registry::OverSlashing`
@@ -512,6 +526,13 @@ impl ToolRegistry {
         module: TOOL_REGISTRY_MODULE,
         name: sui::move_ident_str!("OverTool"),
     };
+    /// Redirect a deprecated tool to its replacement.
+    ///
+    /// `nexus_workflow::tool_registry::redirect_tool`
+    pub const REDIRECT_TOOL: ModuleAndNameIdent = ModuleAndNameIdent {
+        module: TOOL_REGISTRY_MODULE,
+        name: sui::move_ident_str!("redirect_tool"),
+    };
     /// Register an off-chain tool. This returns the tool's owner cap.
     ///
     /// `nexus_workflow::tool_registry::register_off_chain_tool`
@@ -722,6 +743,20 @@ impl GasExtension {
         module: GAS_EXTENSION_MODULE,
         name: sui::move_ident_str!("enable_limited_invocations"),
     };
+    /// Owned ticket that pays for a tool until it expires.
+    ///
+    /// `nexus_workflow::gas_extension::ExpiryGasTicket`
+    pub const EXPIRY_GAS_TICKET: ModuleAndNameIdent = ModuleAndNameIdent {
+        module: GAS_EXTENSION_MODULE,
+        name: sui::move_ident_str!("ExpiryGasTicket"),
+    };
+    /// Owned ticket that pays for a limited number of tool invocations.
+    ///
+    /// `nexus_workflow::gas_extension::LimitedInvocationsGasTicket`
+    pub const LIMITED_INVOCATIONS_GAS_TICKET: ModuleAndNameIdent = ModuleAndNameIdent {
+        module: GAS_EXTENSION_MODULE,
+        name: sui::move_ident_str!("LimitedInvocationsGasTicket"),
+    };
 }
 
 // == `nexus_workflow::pre_key_vault` ==
@@ -27,6 +27,38 @@ where
     parse_object_response(response)
 }
 
+/// Fetch a single object from Sui and deserialize its BCS bytes directly into
+/// `T`, skipping the JSON representation used by [fetch_one].
+///
+/// This is lossless for `u64` and `u128` fields, which JSON represents as
+/// strings or numbers that don't fit into `f64`. The object is fetched with
+/// [sui::ObjectDataOptions::with_bcs] and [sui::ObjectDataOptions::with_owner].
+///
+/// BCS is not self-describing so `T` must mirror the Move struct field by
+/// field in declaration order, including its `id: sui::UID`. The wrappers of
+/// this module, such as [crate::object_crawler::ObjectTable], only understand
+/// the JSON representation and can't be used in `T`.
+pub async fn crawl_bcs<T>(
+    sui: &sui::Client,
+    object_id: sui::ObjectID,
+) -> anyhow::Result<Response<T>>
+where
+    T: DeserializeOwned,
+{
+    let options = sui::ObjectDataOptions::new().with_bcs().with_owner();
+
+    let response = match sui
+        .read_api()
+        .get_object_with_options(object_id, options)
+        .await
+    {
+        Ok(response) => response,
+        Err(e) => bail!("Could not fetch object {object_id}: {e}"),
+    };
+
+    parse_bcs_object_response(response)
+}
+
 /// Fetch a dynamic field object from Sui based on the provided key.
 pub(crate) async fn dynamic_fetch_one<V>(
     sui: &sui::Client,
@@ -163,6 +195,48 @@ where
     }
 }
 
+/// Helper function to parse the BCS bytes of the response from Sui into a
+/// [Response] struct.
+fn parse_bcs_object_response<T>(response: sui::ObjectResponse) -> anyhow::Result<Response<T>>
+where
+    T: DeserializeOwned,
+{
+    let object_id = match response.object_id() {
+        Ok(object_id) => object_id,
+        Err(e) => bail!("Could not get object ID from response: {e}"),
+    };
+
+    if let Some(e) = response.error {
+        bail!("Could not fetch object {object_id}: {e}");
+    }
+
+    let data = match response.data {
+        Some(data) => data,
+        None => bail!("Could not fetch object {object_id}"),
+    };
+
+    // We expect the BCS bytes of a move object as we requested them.
+    let Some(sui::RawData::MoveObject(object)) = data.bcs else {
+        bail!("Could not get move object BCS bytes from object {object_id}")
+    };
+
+    // We expect the object owner data as we requested it.
+    let Some(owner) = data.owner else {
+        bail!("Could not parse owner data from object {object_id}")
+    };
+
+    match bcs::from_bytes::<T>(&object.bcs_bytes) {
+        Ok(parsed) => Ok(Response {
+            id: object_id,
+            owner,
+            data: parsed,
+            version: data.version,
+            digest: data.digest,
+        }),
+        Err(e) => bail!("Could not parse BCS bytes of object {object_id}: {e}"),
+    }
+}
+
 // == Response wrapper ==
 
 /// We want to provide metadata from the response along with the object itself.
@@ -231,3 +305,60 @@ pub(crate) struct ObjectKV<K, V> {
 pub(crate) struct ObjectValue<T> {
     pub(crate) value: T,
 }
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::json, std::str::FromStr};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Vault {
+        id: sui::UID,
+        balance: u128,
+        epoch: u64,
+    }
+
+    fn bcs_object_response(bcs_bytes: Vec<u8>) -> sui::ObjectResponse {
+        let raw = sui::RawData::MoveObject(sui::RawMoveObject {
+            type_: sui::MoveStructTag::from_str("0x2::vault::Vault").unwrap(),
+            has_public_transfer: true,
+            version: sui::SequenceNumber::from_u64(1),
+            bcs_bytes,
+        });
+
+        serde_json::from_value(json!({
+            "data": {
+                "objectId": sui::ObjectID::from_single_byte(1).to_string(),
+                "version": "1",
+                "digest": sui::ObjectDigest::new([0; 32]).to_string(),
+                "owner": { "AddressOwner": sui::Address::ZERO.to_string() },
+                "bcs": raw,
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_bcs_object_response_is_lossless() {
+        // Neither fits into the 53 bit mantissa of a JSON number.
+        let vault = Vault {
+            id: sui::UID::new(sui::ObjectID::from_single_byte(1)),
+            balance: u128::MAX - 1,
+            epoch: u64::MAX - 1,
+        };
+
+        let response = bcs_object_response(bcs::to_bytes(&vault).unwrap());
+        let parsed = parse_bcs_object_response::<Vault>(response).unwrap();
+
+        assert_eq!(parsed.id, sui::ObjectID::from_single_byte(1));
+        assert_eq!(parsed.version, sui::SequenceNumber::from_u64(1));
+        assert_eq!(parsed.data, vault);
+        assert_eq!(parsed.data.balance, 340282366920938463463374607431768211454);
+    }
+
+    #[test]
+    fn test_parse_bcs_object_response_mismatch() {
+        let response = bcs_object_response(vec![1, 2, 3]);
+
+        assert!(parse_bcs_object_response::<Vault>(response).is_err());
+    }
+}
@@ -0,0 +1,193 @@
+//! Extract a single nested field from the JSON of a Move object by its dotted
+//! path without defining a mirror struct for the whole object.
+//!
+//! Move structs are represented as `{ "type": "...", "fields": { ... } }` and
+//! the `fields` wrapper is skipped transparently, so `registry.tools.size`
+//! reads `registry.fields.tools.fields.size`. Numeric segments index into
+//! vectors, for example `groups.contents.0.key`.
+
+use {serde_json::Value, thiserror::Error};
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FieldPathError {
+    #[error("Field '{segment}' does not exist at '{path}'")]
+    MissingField { path: String, segment: String },
+    #[error("Index {index} is out of bounds of the vector of length {len} at '{path}'")]
+    IndexOutOfBounds {
+        path: String,
+        index: usize,
+        len: usize,
+    },
+    #[error("Cannot read '{segment}' from the non-struct value at '{path}'")]
+    NotTraversable { path: String, segment: String },
+}
+
+/// Walk the Move object JSON along the dotted `path` and return the value at
+/// its end. An empty path returns the object itself.
+///
+/// Segments are looked up as-is first and only then in the `fields` of a
+/// struct, so that dynamic field objects and plain JSON work too.
+pub fn get_field_path(object: &Value, path: &str) -> Result<Value, FieldPathError> {
+    let mut current = object;
+
+    if path.is_empty() {
+        return Ok(current.clone());
+    }
+
+    let mut walked = String::new();
+
+    for segment in path.split('.') {
+        current = match current {
+            Value::Object(map) => match map
+                .get(segment)
+                .or_else(|| map.get("fields").and_then(|fields| fields.get(segment)))
+            {
+                Some(value) => value,
+                None => {
+                    return Err(FieldPathError::MissingField {
+                        path: walked,
+                        segment: segment.to_string(),
+                    })
+                }
+            },
+            Value::Array(items) => {
+                let Ok(index) = segment.parse::<usize>() else {
+                    return Err(FieldPathError::MissingField {
+                        path: walked,
+                        segment: segment.to_string(),
+                    });
+                };
+
+                match items.get(index) {
+                    Some(value) => value,
+                    None => {
+                        return Err(FieldPathError::IndexOutOfBounds {
+                            path: walked,
+                            index,
+                            len: items.len(),
+                        })
+                    }
+                }
+            }
+            _ => {
+                return Err(FieldPathError::NotTraversable {
+                    path: walked,
+                    segment: segment.to_string(),
+                })
+            }
+        };
+
+        if !walked.is_empty() {
+            walked.push('.');
+        }
+
+        walked.push_str(segment);
+    }
+
+    Ok(current.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::json};
+
+    /// A trimmed down `ToolRegistry` object as returned by the Sui RPC.
+    fn sample_object() -> Value {
+        json!({
+            "dataType": "moveObject",
+            "type": "0x1::tool_registry::ToolRegistry",
+            "hasPublicTransfer": false,
+            "fields": {
+                "id": { "id": "0x2" },
+                "registry": {
+                    "type": "0x1::tool_registry::Registry",
+                    "fields": {
+                        "tools": {
+                            "type": "0x2::object_table::ObjectTable<0x1::tool::ToolFqn, 0x1::tool::Tool>",
+                            "fields": { "id": { "id": "0x3" }, "size": "2" }
+                        },
+                        "invited": {
+                            "type": "0x2::vec_map::VecMap<address, u64>",
+                            "fields": {
+                                "contents": [
+                                    {
+                                        "type": "0x2::vec_map::Entry<address, u64>",
+                                        "fields": { "key": "0xa", "value": "10" }
+                                    },
+                                    {
+                                        "type": "0x2::vec_map::Entry<address, u64>",
+                                        "fields": { "key": "0xb", "value": "20" }
+                                    }
+                                ]
+                            }
+                        },
+                        "timeouts": ["100", "200"]
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_get_field_path() {
+        let object = sample_object();
+
+        assert_eq!(
+            get_field_path(&object, "registry.tools.size"),
+            Ok(json!("2"))
+        );
+        assert_eq!(
+            get_field_path(&object, "registry.invited.contents.1.value"),
+            Ok(json!("20"))
+        );
+        assert_eq!(
+            get_field_path(&object, "registry.timeouts.0"),
+            Ok(json!("100"))
+        );
+        // Keys present on the wrapper itself are found as well.
+        assert_eq!(
+            get_field_path(&object, "registry.type"),
+            Ok(json!("0x1::tool_registry::Registry"))
+        );
+        assert_eq!(
+            get_field_path(&object, "registry.fields.tools.fields.id.id"),
+            Ok(json!("0x3"))
+        );
+        assert_eq!(get_field_path(&object, ""), Ok(object.clone()));
+    }
+
+    #[test]
+    fn test_get_field_path_errors() {
+        let object = sample_object();
+
+        assert_eq!(
+            get_field_path(&object, "registry.missing.size"),
+            Err(FieldPathError::MissingField {
+                path: "registry".to_string(),
+                segment: "missing".to_string(),
+            })
+        );
+        assert_eq!(
+            get_field_path(&object, "registry.timeouts.2"),
+            Err(FieldPathError::IndexOutOfBounds {
+                path: "registry.timeouts".to_string(),
+                index: 2,
+                len: 2,
+            })
+        );
+        assert_eq!(
+            get_field_path(&object, "registry.timeouts.first"),
+            Err(FieldPathError::MissingField {
+                path: "registry.timeouts".to_string(),
+                segment: "first".to_string(),
+            })
+        );
+        assert_eq!(
+            get_field_path(&object, "registry.tools.size.value"),
+            Err(FieldPathError::NotTraversable {
+                path: "registry.tools.size".to_string(),
+                segment: "value".to_string(),
+            })
+        );
+    }
+}
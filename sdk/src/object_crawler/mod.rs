@@ -1,3 +1,4 @@
 pub(crate) mod fetching;
+mod field_path;
 mod wrappers;
-pub use {fetching::*, wrappers::*};
+pub use {fetching::*, field_path::*, wrappers::*};
@@ -32,6 +32,7 @@ pub use {
         rpc_types::{
             BcsEvent,
             Coin,
+            DryRunTransactionBlockResponse,
             EventFilter,
             EventPage,
             ObjectChange,
@@ -47,17 +48,20 @@ pub use {
             SuiObjectResponseQuery as ObjectResponseQuery,
             SuiParsedData as ParsedData,
             SuiParsedMoveObject as ParsedMoveObject,
+            SuiRawData as RawData,
+            SuiRawMoveObject as RawMoveObject,
             SuiTransactionBlockEffects as TransactionBlockEffects,
             SuiTransactionBlockResponse as TransactionBlockResponse,
             SuiTransactionBlockResponseOptions as TransactionBlockResponseOptions,
         },
         sui_client_config::{SuiClientConfig as ClientConfig, SuiEnv as Env},
         types::{
-            base_types::{ObjectID, SequenceNumber, SuiAddress as Address},
+            base_types::{ObjectID, ObjectType, SequenceNumber, SuiAddress as Address},
             crypto::SignatureScheme,
             digests::{ObjectDigest, TransactionDigest},
             dynamic_field::{DynamicFieldInfo, DynamicFieldName},
             event::EventID,
+            gas::GasCostSummary,
             gas_coin::MIST_PER_SUI,
             id::UID,
             object::Owner,
@@ -103,3 +107,91 @@ pub const CLOCK_OBJ_ARG: ObjectArg = ObjectArg::SharedObject {
     initial_shared_version: CLOCK_OBJECT_SHARED_VERSION,
     mutable: false,
 };
+
+/// Normalize an address or object ID to its canonical form, that is `0x`
+/// followed by 64 lowercase hex characters. Accepts addresses with or without
+/// the `0x` prefix, without leading zeros and in uppercase.
+pub fn normalize_address(address: &str) -> anyhow::Result<String> {
+    let trimmed = address.trim();
+    let hex = trimmed
+        .strip_prefix("0x")
+        .or_else(|| trimmed.strip_prefix("0X"))
+        .unwrap_or(trimmed);
+
+    if hex.is_empty() || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        anyhow::bail!("Invalid address '{address}': expected hex characters");
+    }
+
+    if hex.len() > ObjectID::LENGTH * 2 {
+        anyhow::bail!(
+            "Invalid address '{address}': expected at most {} hex characters",
+            ObjectID::LENGTH * 2
+        );
+    }
+
+    Ok(format!(
+        "0x{:0>width$}",
+        hex.to_ascii_lowercase(),
+        width = ObjectID::LENGTH * 2
+    ))
+}
+
+/// Parse an [ObjectID] from any form accepted by [normalize_address].
+pub fn parse_object_id(object_id: &str) -> anyhow::Result<ObjectID> {
+    Ok(ObjectID::from_hex_literal(&normalize_address(object_id)?)?)
+}
+
+/// Parse an [Address] from any form accepted by [normalize_address].
+pub fn parse_address(address: &str) -> anyhow::Result<Address> {
+    Ok(parse_object_id(address)?.into())
+}
+
+/// Whether both strings are the same address once normalized. Invalid
+/// addresses are never equal.
+pub fn normalized_eq(a: &str, b: &str) -> bool {
+    match (normalize_address(a), normalize_address(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CANONICAL: &str = "0x00000000000000000000000000000000000000000000000000000000000000ab";
+
+    #[test]
+    fn test_normalize_address() {
+        for address in [
+            "0xab",
+            "ab",
+            "0x00ab",
+            "0xAB",
+            "0XAB",
+            " 0xab ",
+            "00000000000000000000000000000000000000000000000000000000000000ab",
+            CANONICAL,
+        ] {
+            assert_eq!(normalize_address(address).unwrap(), CANONICAL);
+            assert!(normalized_eq(address, CANONICAL));
+        }
+
+        assert!(normalize_address("").is_err());
+        assert!(normalize_address("0x").is_err());
+        assert!(normalize_address("0xzz").is_err());
+        assert!(normalize_address(&format!("0x1{}", &CANONICAL[2..])).is_err());
+        assert!(!normalized_eq("0xab", "0xac"));
+        assert!(!normalized_eq("0xzz", "0xzz"));
+    }
+
+    #[test]
+    fn test_parse_object_id_and_address() {
+        let object_id = ObjectID::from_hex_literal(CANONICAL).unwrap();
+
+        assert_eq!(parse_object_id("AB").unwrap(), object_id);
+        assert_eq!(parse_object_id("0x00ab").unwrap(), object_id);
+        assert_eq!(parse_address("0xAB").unwrap(), Address::from(object_id));
+        assert!(parse_object_id("not an address").is_err());
+    }
+}
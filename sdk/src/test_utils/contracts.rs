@@ -1,11 +1,15 @@
 use {
-    crate::sui::{self, traits::*},
+    crate::{
+        sui::{self, traits::*},
+        test_utils::move_cache::{CompiledPackage, MoveCache},
+    },
     std::{fs::OpenOptions, path::PathBuf},
     sui_move_build::implicit_deps,
     sui_package_management::system_package_versions::latest_system_packages,
 };
 
-/// Publishes a Move package to Sui.
+/// Publishes a Move package to Sui. The package is only compiled if its
+/// sources changed since it was last compiled, see [MoveCache].
 ///
 /// `path_str` is the path relative to the project `Cargo.toml` directory.
 pub async fn publish_move_package(
@@ -32,11 +36,20 @@ pub async fn publish_move_package(
         .expect("Failed to get chain identifier.");
 
     // Compile the package.
-    let mut build_config = sui_move_build::BuildConfig::new_for_testing();
-    build_config.chain_id = Some(chain_id);
-    build_config.config.implicit_dependencies = implicit_deps(latest_system_packages());
-    let package = build_config
-        .build(&install_dir)
+    let package = MoveCache::global()
+        .get_or_compile(&install_dir, &chain_id, || {
+            let mut build_config = sui_move_build::BuildConfig::new_for_testing();
+            build_config.chain_id = Some(chain_id.clone());
+            build_config.config.implicit_dependencies = implicit_deps(latest_system_packages());
+            let package = build_config.build(&install_dir)?;
+
+            let with_unpublished_deps = false;
+
+            Ok(CompiledPackage {
+                modules: package.get_package_bytes(with_unpublished_deps),
+                dependencies: package.get_dependency_storage_package_ids(),
+            })
+        })
         .expect("Failed to build package.");
 
     let reference_gas_price = sui
@@ -45,15 +58,9 @@ pub async fn publish_move_package(
         .await
         .expect("Failed to fetch reference gas price.");
 
-    let with_unpublished_deps = false;
-
     let tx = sui
         .transaction_builder()
-        .publish_tx_kind(
-            addr,
-            package.get_package_bytes(with_unpublished_deps),
-            package.get_dependency_storage_package_ids(),
-        )
+        .publish_tx_kind(addr, package.modules, package.dependencies)
         .await
         .expect("Failed to build transaction.");
 
@@ -1,37 +1,21 @@
 use {
-    crate::sui,
+    crate::{sui, test_utils::gas::fetch_gas_coins},
     anyhow::bail,
-    reqwest::{header, Client},
+    reqwest::{header, Client, StatusCode},
     serde::Deserialize,
     std::time::Duration,
-    tokio_retry::{strategy::ExponentialBackoff, Retry},
+    tokio_retry::{strategy::ExponentialBackoff, Retry, RetryIf},
 };
 
 /// Request tokens from the Sui faucet for the given address.
 pub async fn request_tokens(url: &str, addr: sui::Address) -> anyhow::Result<()> {
-    #[derive(Debug, Deserialize)]
-    struct FaucetResponse {
-        error: Option<String>,
-    }
-
-    let json_body = serde_json::json![{
-        "FixedAmountRequest": {
-            "recipient": &addr.to_string()
-        }
-    }];
-
     let retry_strategy = ExponentialBackoff::from_millis(100)
         .max_delay(Duration::from_secs(5))
         .take(5);
 
     // Retry a couple times in case the faucet is slow to load.
     let response = Retry::spawn(retry_strategy, || async {
-        let resp = Client::new()
-            .post(url)
-            .header(header::USER_AGENT, "nexus-leader")
-            .json(&json_body)
-            .send()
-            .await?;
+        let resp = send_faucet_request(url, addr).await?;
 
         if !resp.status().is_success() {
             anyhow::bail!("Unexpected status: {}", resp.status())
@@ -41,6 +25,111 @@ pub async fn request_tokens(url: &str, addr: sui::Address) -> anyhow::Result<()>
     })
     .await?;
 
+    check_faucet_response(response).await
+}
+
+/// Request tokens from the Sui faucet for the given address, retrying with
+/// backoff while the faucet rate limits the requests.
+pub async fn request_faucet_with_retry(url: &str, addr: sui::Address) -> anyhow::Result<()> {
+    enum FaucetError {
+        RateLimited,
+        Other(anyhow::Error),
+    }
+
+    let retry_strategy = ExponentialBackoff::from_millis(2)
+        .factor(250)
+        .max_delay(Duration::from_secs(10))
+        .take(10);
+
+    let response = RetryIf::spawn(
+        retry_strategy,
+        || async {
+            let resp = send_faucet_request(url, addr)
+                .await
+                .map_err(FaucetError::Other)?;
+
+            match resp.status() {
+                StatusCode::TOO_MANY_REQUESTS => Err(FaucetError::RateLimited),
+                status if !status.is_success() => Err(FaucetError::Other(anyhow::anyhow!(
+                    "Unexpected status: {status}"
+                ))),
+                _ => Ok(resp),
+            }
+        },
+        |e: &FaucetError| matches!(e, FaucetError::RateLimited),
+    )
+    .await
+    .map_err(|e| match e {
+        FaucetError::RateLimited => anyhow::anyhow!("Faucet is still rate limiting requests"),
+        FaucetError::Other(e) => e,
+    })?;
+
+    check_faucet_response(response).await
+}
+
+/// Request tokens from the Sui faucet for the given address and poll its
+/// balance with backoff until it reaches `min_balance`. Returns the coins of
+/// the address or fails if the balance is not reached within `timeout`.
+pub async fn fund_and_wait(
+    sui: &sui::Client,
+    faucet_url: &str,
+    addr: sui::Address,
+    min_balance: u64,
+    timeout: Duration,
+) -> anyhow::Result<Vec<sui::Coin>> {
+    let fund = async {
+        request_faucet_with_retry(faucet_url, addr).await?;
+
+        let mut poll_delays = ExponentialBackoff::from_millis(2)
+            .factor(50)
+            .max_delay(Duration::from_secs(1));
+
+        loop {
+            let coins = fetch_gas_coins(sui, addr).await?;
+            let balance = coins
+                .iter()
+                .fold(0u64, |balance, coin| balance.saturating_add(coin.balance));
+
+            if balance >= min_balance {
+                return anyhow::Ok(coins);
+            }
+
+            // The backoff never runs out of delays.
+            if let Some(delay) = poll_delays.next() {
+                tokio::time::sleep(delay).await;
+            }
+        }
+    };
+
+    match tokio::time::timeout(timeout, fund).await {
+        Ok(coins) => coins,
+        Err(_) => bail!("Balance of '{addr}' did not reach {min_balance} MIST within {timeout:?}"),
+    }
+}
+
+async fn send_faucet_request(url: &str, addr: sui::Address) -> anyhow::Result<reqwest::Response> {
+    let json_body = serde_json::json![{
+        "FixedAmountRequest": {
+            "recipient": &addr.to_string()
+        }
+    }];
+
+    let resp = Client::new()
+        .post(url)
+        .header(header::USER_AGENT, "nexus-leader")
+        .json(&json_body)
+        .send()
+        .await?;
+
+    Ok(resp)
+}
+
+async fn check_faucet_response(response: reqwest::Response) -> anyhow::Result<()> {
+    #[derive(Debug, Deserialize)]
+    struct FaucetResponse {
+        error: Option<String>,
+    }
+
     let faucet_resp: FaucetResponse = response.json().await?;
 
     if let Some(err) = faucet_resp.error {
@@ -49,3 +138,80 @@ pub async fn request_tokens(url: &str, addr: sui::Address) -> anyhow::Result<()>
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::test_utils::{
+            sui_mocks,
+            sui_rpc::{MockSuiRpc, RpcMethod},
+        },
+        mockito::Server,
+        serde_json::json,
+    };
+
+    fn coins_page(coins: Vec<sui::Coin>) -> serde_json::Value {
+        json!({ "data": coins, "nextCursor": null, "hasNextPage": false })
+    }
+
+    #[tokio::test]
+    async fn test_fund_and_wait() {
+        let mut faucet = Server::new_async().await;
+        let faucet_mock = faucet
+            .mock("POST", "/gas")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "error": null }).to_string())
+            .create_async()
+            .await;
+
+        // The grant lands on the third poll.
+        let rpc = MockSuiRpc::builder()
+            .with_responses(
+                RpcMethod::GetCoins,
+                vec![
+                    coins_page(vec![]),
+                    coins_page(vec![sui_mocks::mock_sui_coin(100)]),
+                    coins_page(vec![
+                        sui_mocks::mock_sui_coin(100),
+                        sui_mocks::mock_sui_coin(1_000),
+                    ]),
+                ],
+            )
+            .build()
+            .await;
+
+        let sui = sui::ClientBuilder::default()
+            .build(rpc.url())
+            .await
+            .unwrap();
+        let addr = sui::Address::random_for_testing_only();
+
+        let coins = fund_and_wait(
+            &sui,
+            &format!("{}/gas", faucet.url()),
+            addr,
+            1_000,
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(coins.len(), 2);
+        faucet_mock.assert_async().await;
+        rpc.assert_calls(RpcMethod::GetCoins, 3);
+
+        // Times out if the balance is never reached.
+        let result = fund_and_wait(
+            &sui,
+            &format!("{}/gas", faucet.url()),
+            addr,
+            1_000_000,
+            Duration::from_millis(500),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
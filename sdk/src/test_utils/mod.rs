@@ -2,5 +2,7 @@ pub mod containers;
 pub mod contracts;
 pub mod faucet;
 pub mod gas;
+pub mod move_cache;
 pub mod sui_mocks;
+pub mod sui_rpc;
 pub mod wallet;
@@ -0,0 +1,443 @@
+//! Cache of compiled Move packages so that tests publishing the same package
+//! only compile it once.
+//!
+//! Packages are keyed by a hash of their source tree, including the sources
+//! of their local dependencies, and the chain they are compiled for. The
+//! `build` directory and `Move.lock` are excluded as they are written by
+//! compiling and publishing the package, except for the published addresses
+//! in the `Move.lock` of dependencies that the compiled package links against.
+//!
+//! The cache lives in memory for the duration of the process. Setting the
+//! [SHARED_DIR_ENV] environment variable or using [MoveCache::with_shared_dir]
+//! also stores compiled packages in a directory to share them across
+//! processes.
+
+use {
+    crate::sui,
+    serde::{Deserialize, Serialize},
+    sha2::{Digest, Sha256},
+    std::{
+        collections::{HashMap, HashSet},
+        path::{Path, PathBuf},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+            Mutex,
+            OnceLock,
+        },
+    },
+};
+
+/// Environment variable with the directory to share compiled packages in.
+pub const SHARED_DIR_ENV: &str = "NEXUS_MOVE_CACHE_DIR";
+
+/// The parts of a compiled package that are needed to publish it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompiledPackage {
+    pub modules: Vec<Vec<u8>>,
+    pub dependencies: Vec<sui::ObjectID>,
+}
+
+type Entry = Arc<Mutex<Option<CompiledPackage>>>;
+
+#[derive(Default)]
+pub struct MoveCache {
+    shared_dir: Option<PathBuf>,
+    /// Each package has its own lock so that different packages compile in
+    /// parallel while the same package is only compiled once.
+    entries: Mutex<HashMap<String, Entry>>,
+    hits: AtomicUsize,
+}
+
+impl MoveCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also store compiled packages in the given directory to share them
+    /// across processes, for example a directory in [std::env::temp_dir].
+    pub fn with_shared_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.shared_dir = Some(dir.into());
+
+        self
+    }
+
+    /// The cache shared by the process. Uses the directory in
+    /// [SHARED_DIR_ENV] if set.
+    pub fn global() -> &'static MoveCache {
+        static CACHE: OnceLock<MoveCache> = OnceLock::new();
+
+        CACHE.get_or_init(|| match std::env::var(SHARED_DIR_ENV) {
+            Ok(dir) if !dir.is_empty() => MoveCache::new().with_shared_dir(dir),
+            _ => MoveCache::new(),
+        })
+    }
+
+    /// Number of times a package was served from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::SeqCst)
+    }
+
+    /// Returns the cached package at `path` if its sources did not change
+    /// since it was compiled for the chain `chain_id` or compiles it with
+    /// `compile` otherwise.
+    pub fn get_or_compile(
+        &self,
+        path: impl AsRef<Path>,
+        chain_id: &str,
+        compile: impl FnOnce() -> anyhow::Result<CompiledPackage>,
+    ) -> anyhow::Result<CompiledPackage> {
+        let key = cache_key(path.as_ref(), chain_id)?;
+
+        let entry = self
+            .entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry(key.clone())
+            .or_default()
+            .clone();
+
+        let mut entry = entry
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if let Some(package) = entry.as_ref() {
+            self.hits.fetch_add(1, Ordering::SeqCst);
+
+            return Ok(package.clone());
+        }
+
+        if let Some(package) = self.read_shared(&key) {
+            self.hits.fetch_add(1, Ordering::SeqCst);
+
+            return Ok(entry.insert(package).clone());
+        }
+
+        let package = compile()?;
+
+        self.write_shared(&key, &package)?;
+
+        Ok(entry.insert(package).clone())
+    }
+
+    fn read_shared(&self, key: &str) -> Option<CompiledPackage> {
+        let path = self.shared_dir.as_ref()?.join(format!("{key}.json"));
+        let package = std::fs::read_to_string(path).ok()?;
+
+        // A corrupted file is treated as a miss and overwritten.
+        serde_json::from_str(&package).ok()
+    }
+
+    fn write_shared(&self, key: &str, package: &CompiledPackage) -> anyhow::Result<()> {
+        let Some(dir) = &self.shared_dir else {
+            return Ok(());
+        };
+
+        std::fs::create_dir_all(dir)?;
+
+        // Write to a temporary file first so that other processes never read
+        // a partially written package.
+        let tmp = dir.join(format!("{key}.json.{}", std::process::id()));
+
+        std::fs::write(&tmp, serde_json::to_vec(package)?)?;
+        std::fs::rename(tmp, dir.join(format!("{key}.json")))?;
+
+        Ok(())
+    }
+}
+
+/// Hash the sources of the package at `path` and of its local dependencies.
+/// The Sui version is included as it determines the system packages the
+/// package is compiled against. The chain ID and the published addresses in
+/// the `Move.lock` of the dependencies are included as they determine the
+/// addresses the package links against.
+pub fn cache_key(path: &Path, chain_id: &str) -> anyhow::Result<String> {
+    let mut hasher = Sha256::new();
+    let mut visited = HashSet::new();
+    let mut packages = vec![path.to_path_buf()];
+
+    hasher.update(env!("SUI_SDK_TAG"));
+    hasher.update(chain_id);
+
+    while let Some(package) = packages.pop() {
+        let package = package.canonicalize().map_err(|e| {
+            anyhow::anyhow!("Failed to read Move package '{}': {e}", package.display())
+        })?;
+
+        if !visited.insert(package.clone()) {
+            continue;
+        }
+
+        let manifest = std::fs::read_to_string(package.join("Move.toml"))?;
+
+        packages.extend(
+            local_dependencies(&manifest)
+                .into_iter()
+                .map(|dependency| package.join(dependency)),
+        );
+
+        // The package itself is published at a new address every time, only
+        // the addresses of its dependencies are linked against.
+        let lock = match visited.len() {
+            1 => None,
+            _ => std::fs::read_to_string(package.join("Move.lock")).ok(),
+        };
+
+        if let Some(lock) = lock {
+            for line in published_addresses(&lock) {
+                hasher.update(line);
+            }
+        }
+
+        for file in package_files(&package)? {
+            hasher.update(file.strip_prefix(&package)?.to_string_lossy().as_bytes());
+            hasher.update(std::fs::read(&file)?);
+        }
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// All files of the package in a stable order, excluding the files written
+/// when compiling and publishing it.
+fn package_files(package: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    let mut dirs = vec![package.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+
+            if dir == package && (path.ends_with("build") || path.ends_with("Move.lock")) {
+                continue;
+            }
+
+            match path.is_dir() {
+                true => dirs.push(path),
+                false => files.push(path),
+            }
+        }
+    }
+
+    files.sort();
+
+    Ok(files)
+}
+
+/// Lines of a `Move.lock` that record the chain and addresses the package was
+/// published at, such as `published-at`, `latest-published-id` and
+/// `chain-id`.
+fn published_addresses(lock: &str) -> Vec<&str> {
+    lock.lines()
+        .map(str::trim)
+        .filter(|line| {
+            line.split_once('=').is_some_and(|(key, _)| {
+                let key = key.trim();
+
+                key.contains("published") || key == "chain-id"
+            })
+        })
+        .collect()
+}
+
+/// Paths of the `local = "..."` dependencies in a `Move.toml`.
+fn local_dependencies(manifest: &str) -> Vec<String> {
+    manifest
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .flat_map(|line| {
+            line.match_indices("local")
+                .map(move |(index, _)| &line[index..])
+        })
+        .filter_map(|rest| {
+            let rest = rest["local".len()..].trim_start().strip_prefix('=')?;
+            let rest = rest.trim_start().strip_prefix('"')?;
+
+            rest.split('"').next().map(str::to_string)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHAIN_ID: &str = "4c78adac";
+
+    fn write_package(dir: &Path, name: &str, manifest_extra: &str, source: &str) -> PathBuf {
+        let package = dir.join(name);
+
+        std::fs::create_dir_all(package.join("sources")).unwrap();
+        std::fs::write(
+            package.join("Move.toml"),
+            format!("[package]\nname = \"{name}\"\n{manifest_extra}"),
+        )
+        .unwrap();
+        std::fs::write(package.join("sources").join("main.move"), source).unwrap();
+
+        package
+    }
+
+    fn compiled(byte: u8) -> CompiledPackage {
+        CompiledPackage {
+            modules: vec![vec![byte]],
+            dependencies: vec![sui::ObjectID::from_single_byte(2)],
+        }
+    }
+
+    #[test]
+    fn test_move_cache_hit_and_invalidation() {
+        let dir = tempfile::tempdir().unwrap();
+        let dependency = write_package(dir.path(), "dependency", "", "module dependency::a;");
+        let package = write_package(
+            dir.path(),
+            "package",
+            "[dependencies]\ndependency = { local = \"../dependency\" }\n",
+            "module package::b;",
+        );
+
+        let cache = MoveCache::new();
+        let compiles = AtomicUsize::new(0);
+        let compile = || {
+            let count = compiles.fetch_add(1, Ordering::SeqCst);
+
+            Ok(compiled(count as u8))
+        };
+
+        assert_eq!(
+            cache.get_or_compile(&package, CHAIN_ID, compile).unwrap(),
+            compiled(0)
+        );
+
+        // Sources did not change so the second compile is served from cache.
+        assert_eq!(
+            cache.get_or_compile(&package, CHAIN_ID, compile).unwrap(),
+            compiled(0)
+        );
+        assert_eq!(cache.hits(), 1);
+        assert_eq!(compiles.load(Ordering::SeqCst), 1);
+
+        // Files written by compiling and publishing are ignored.
+        std::fs::create_dir_all(package.join("build")).unwrap();
+        std::fs::write(package.join("build").join("out"), "out").unwrap();
+        std::fs::write(package.join("Move.lock"), "lock").unwrap();
+
+        assert_eq!(
+            cache.get_or_compile(&package, CHAIN_ID, compile).unwrap(),
+            compiled(0)
+        );
+        assert_eq!(cache.hits(), 2);
+
+        // Changing the sources of the package or of a local dependency
+        // invalidates the cache.
+        std::fs::write(
+            package.join("sources").join("main.move"),
+            "module package::c;",
+        )
+        .unwrap();
+
+        assert_eq!(
+            cache.get_or_compile(&package, CHAIN_ID, compile).unwrap(),
+            compiled(1)
+        );
+
+        std::fs::write(
+            dependency.join("sources").join("main.move"),
+            "module dependency::d;",
+        )
+        .unwrap();
+
+        assert_eq!(
+            cache.get_or_compile(&package, CHAIN_ID, compile).unwrap(),
+            compiled(2)
+        );
+        assert_eq!(cache.hits(), 2);
+
+        // Compiling for another chain or against a dependency published at
+        // another address invalidates the cache.
+        assert_eq!(
+            cache.get_or_compile(&package, "35834a8a", compile).unwrap(),
+            compiled(3)
+        );
+
+        std::fs::write(
+            dependency.join("Move.lock"),
+            "[env.localnet]\nchain-id = \"4c78adac\"\nlatest-published-id = \"0x1\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            cache.get_or_compile(&package, CHAIN_ID, compile).unwrap(),
+            compiled(4)
+        );
+        assert_eq!(cache.hits(), 2);
+    }
+
+    #[test]
+    fn test_move_cache_shared_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let shared_dir = dir.path().join("cache");
+        let package = write_package(dir.path(), "package", "", "module package::a;");
+
+        let cache = MoveCache::new().with_shared_dir(&shared_dir);
+
+        assert_eq!(
+            cache
+                .get_or_compile(&package, CHAIN_ID, || Ok(compiled(1)))
+                .unwrap(),
+            compiled(1)
+        );
+
+        // Another process reads the package from the shared directory.
+        let other = MoveCache::new().with_shared_dir(&shared_dir);
+
+        assert_eq!(
+            other
+                .get_or_compile(&package, CHAIN_ID, || anyhow::bail!("Must not compile"))
+                .unwrap(),
+            compiled(1)
+        );
+        assert_eq!(other.hits(), 1);
+    }
+
+    #[test]
+    fn test_published_addresses() {
+        let lock = r#"
+            [move]
+            version = 3
+            manifest_digest = "ABC"
+
+            [env.localnet]
+            chain-id = "4c78adac"
+            original-published-id = "0x1"
+            latest-published-id = "0x2"
+            published-version = "2"
+        "#;
+
+        assert_eq!(
+            published_addresses(lock),
+            vec![
+                "chain-id = \"4c78adac\"",
+                "original-published-id = \"0x1\"",
+                "latest-published-id = \"0x2\"",
+                "published-version = \"2\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_local_dependencies() {
+        let manifest = r#"
+            [dependencies]
+            Sui = { git = "https://github.com/MystenLabs/sui.git", rev = "mainnet" }
+            primitives = { local = "../primitives" }
+            # old = { local = "../old" }
+            interface = { local="../interface", override = true }
+        "#;
+
+        assert_eq!(
+            local_dependencies(manifest),
+            vec!["../primitives".to_string(), "../interface".to_string()]
+        );
+    }
+}
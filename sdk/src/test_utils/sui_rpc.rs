@@ -0,0 +1,282 @@
+//! In-process mock of the Sui JSON-RPC API that serves canned responses via
+//! [`mockito`]. Useful for tests that only need deterministic RPC responses
+//! and should not spin up a Sui container.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use nexus_sdk::{sui, test_utils::sui_rpc::{MockSuiRpc, RpcMethod}};
+//!
+//! let rpc = MockSuiRpc::builder()
+//!     .with_fixture(RpcMethod::GetObject, "tests/fixtures/object.json")?
+//!     .build()
+//!     .await;
+//!
+//! let client = sui::ClientBuilder::default().build(rpc.url()).await?;
+//!
+//! // ...
+//!
+//! rpc.assert_calls(RpcMethod::GetObject, 1);
+//! ```
+
+use {
+    mockito::{Matcher, Mock, Server, ServerGuard},
+    serde_json::{json, Value},
+    std::{
+        collections::HashMap,
+        path::Path,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    },
+};
+
+/// Sui JSON-RPC methods that can be mocked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RpcMethod {
+    GetObject,
+    MultiGetObjects,
+    QueryEvents,
+    DryRunTransactionBlock,
+    GetCoins,
+    GetTransactionBlock,
+    GetReferenceGasPrice,
+    ExecuteTransactionBlock,
+}
+
+impl RpcMethod {
+    /// Name of the method in the JSON-RPC request.
+    pub fn name(&self) -> &'static str {
+        match self {
+            RpcMethod::GetObject => "sui_getObject",
+            RpcMethod::MultiGetObjects => "sui_multiGetObjects",
+            RpcMethod::QueryEvents => "suix_queryEvents",
+            RpcMethod::DryRunTransactionBlock => "sui_dryRunTransactionBlock",
+            RpcMethod::GetCoins => "suix_getCoins",
+            RpcMethod::GetTransactionBlock => "sui_getTransactionBlock",
+            RpcMethod::GetReferenceGasPrice => "suix_getReferenceGasPrice",
+            RpcMethod::ExecuteTransactionBlock => "sui_executeTransactionBlock",
+        }
+    }
+}
+
+/// Builder for [MockSuiRpc] that registers the `result` each method replies
+/// with.
+#[derive(Clone, Debug, Default)]
+pub struct MockSuiRpcBuilder {
+    responses: HashMap<RpcMethod, Vec<Value>>,
+}
+
+impl MockSuiRpcBuilder {
+    /// Reply to `method` with the given `result`. Registering a method again
+    /// replaces its result.
+    pub fn with_response(self, method: RpcMethod, result: Value) -> Self {
+        self.with_responses(method, vec![result])
+    }
+
+    /// Reply to consecutive calls of `method` with the given `results` in
+    /// order. The last result is repeated once all were served. Useful to
+    /// mock state that changes over time, such as a balance.
+    pub fn with_responses(mut self, method: RpcMethod, results: Vec<Value>) -> Self {
+        match results.is_empty() {
+            true => self.responses.remove(&method),
+            false => self.responses.insert(method, results),
+        };
+
+        self
+    }
+
+    /// Reply to `method` with the `result` loaded from a JSON fixture file.
+    pub fn with_fixture(self, method: RpcMethod, path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let fixture = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read fixture '{}': {e}", path.display()))?;
+        let result = serde_json::from_str(&fixture)
+            .map_err(|e| anyhow::anyhow!("Invalid fixture '{}': {e}", path.display()))?;
+
+        Ok(self.with_response(method, result))
+    }
+
+    /// Start the mock server.
+    pub async fn build(self) -> MockSuiRpc {
+        let mut server = Server::new_async().await;
+        let mut mocks = Vec::with_capacity(self.responses.len() + 1);
+        let mut calls = HashMap::with_capacity(self.responses.len());
+
+        // [crate::sui::ClientBuilder] discovers the available methods when
+        // connecting.
+        let discover = json!({
+            "openrpc": "1.2.6",
+            "info": { "title": "Sui JSON-RPC", "version": env!("SUI_SDK_TAG") },
+            "methods": self
+                .responses
+                .keys()
+                .map(|method| json!({ "name": method.name() }))
+                .collect::<Vec<_>>(),
+        });
+
+        let discover_count = Arc::new(AtomicUsize::new(0));
+
+        mocks.push(mock_method(&mut server, "rpc.discover", vec![discover], discover_count).await);
+
+        for (method, results) in self.responses {
+            let count = Arc::new(AtomicUsize::new(0));
+
+            mocks.push(mock_method(&mut server, method.name(), results, count.clone()).await);
+            calls.insert(method, count);
+        }
+
+        MockSuiRpc {
+            server,
+            calls,
+            _mocks: mocks,
+        }
+    }
+}
+
+/// Mock Sui JSON-RPC server. Methods that were not registered reply with
+/// `501 Not Implemented`.
+pub struct MockSuiRpc {
+    server: ServerGuard,
+    calls: HashMap<RpcMethod, Arc<AtomicUsize>>,
+    /// Mocks are removed from the server when dropped.
+    _mocks: Vec<Mock>,
+}
+
+impl MockSuiRpc {
+    pub fn builder() -> MockSuiRpcBuilder {
+        MockSuiRpcBuilder::default()
+    }
+
+    /// URL of the mock server to build a [crate::sui::Client] with.
+    pub fn url(&self) -> String {
+        self.server.url()
+    }
+
+    /// Number of times `method` was called.
+    pub fn calls(&self, method: RpcMethod) -> usize {
+        self.calls
+            .get(&method)
+            .map_or(0, |count| count.load(Ordering::SeqCst))
+    }
+
+    /// Panics if `method` was not called exactly `expected` times.
+    pub fn assert_calls(&self, method: RpcMethod, expected: usize) {
+        let calls = self.calls(method);
+
+        assert_eq!(
+            calls,
+            expected,
+            "Expected '{}' to be called {expected} times but it was called {calls} times",
+            method.name()
+        );
+    }
+}
+
+/// Reply to requests of the given method with the `results` in order,
+/// echoing the request ID as JSON-RPC clients require.
+async fn mock_method(
+    server: &mut ServerGuard,
+    method: &str,
+    results: Vec<Value>,
+    count: Arc<AtomicUsize>,
+) -> Mock {
+    server
+        .mock("POST", "/")
+        .match_body(Matcher::PartialJson(json!({ "method": method })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(move |request| {
+            let call = count.fetch_add(1, Ordering::SeqCst);
+            let result = &results[call.min(results.len() - 1)];
+
+            let id = request
+                .body()
+                .ok()
+                .and_then(|body| serde_json::from_slice::<Value>(body).ok())
+                .map_or(Value::Null, |body| body["id"].clone());
+
+            json!({ "jsonrpc": "2.0", "id": id, "result": result })
+                .to_string()
+                .into()
+        })
+        .create_async()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::sui};
+
+    async fn rpc_call(url: &str, method: &str, params: Value) -> Value {
+        reqwest::Client::new()
+            .post(url)
+            .json(&json!({ "jsonrpc": "2.0", "id": 7, "method": method, "params": params }))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_mock_sui_rpc_get_object() {
+        let object_id = sui::ObjectID::random();
+        let object = json!({
+            "data": {
+                "objectId": object_id,
+                "version": "1",
+                "digest": sui::ObjectDigest::random(),
+            },
+        });
+
+        let rpc = MockSuiRpc::builder()
+            .with_response(RpcMethod::GetObject, object.clone())
+            .with_response(
+                RpcMethod::QueryEvents,
+                json!({ "data": [], "hasNextPage": false }),
+            )
+            .build()
+            .await;
+
+        let response = rpc_call(&rpc.url(), "sui_getObject", json!([object_id])).await;
+
+        assert_eq!(response["id"], 7);
+        assert_eq!(response["result"], object);
+        rpc.assert_calls(RpcMethod::GetObject, 1);
+        rpc.assert_calls(RpcMethod::QueryEvents, 0);
+        rpc.assert_calls(RpcMethod::MultiGetObjects, 0);
+
+        // The Sui client can connect to the mock.
+        assert!(sui::ClientBuilder::default().build(rpc.url()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_mock_sui_rpc_fixture() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dry_run.json");
+
+        std::fs::write(
+            &path,
+            r#"{ "effects": { "status": { "status": "success" } } }"#,
+        )
+        .unwrap();
+
+        let rpc = MockSuiRpc::builder()
+            .with_fixture(RpcMethod::DryRunTransactionBlock, &path)
+            .unwrap()
+            .build()
+            .await;
+
+        let response = rpc_call(&rpc.url(), "sui_dryRunTransactionBlock", json!(["AA=="])).await;
+
+        assert_eq!(response["result"]["effects"]["status"]["status"], "success");
+        rpc.assert_calls(RpcMethod::DryRunTransactionBlock, 1);
+
+        assert!(MockSuiRpc::builder()
+            .with_fixture(RpcMethod::GetObject, dir.path().join("missing.json"))
+            .is_err());
+    }
+}
@@ -143,6 +143,67 @@ impl<'de> Deserialize<'de> for ToolFqn {
     }
 }
 
+/// Restricts which domains tool FQNs may use. Entries are domain prefixes
+/// matched on whole `.`-separated segments, so `xyz.taluslabs` matches
+/// `xyz.taluslabs` and `xyz.taluslabs.llm` but not `xyz.taluslabsx`.
+///
+/// A domain is allowed if it matches no `deny` entry and either `allow` is
+/// empty or the domain matches one of its entries.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NamespacePolicy {
+    /// Domain prefixes that tools may use. Empty allows all domains.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+    /// Domain prefixes that tools must not use. Takes precedence over `allow`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<String>,
+}
+
+impl NamespacePolicy {
+    /// Whether the policy allows all domains.
+    pub fn is_empty(&self) -> bool {
+        self.allow.is_empty() && self.deny.is_empty()
+    }
+}
+
+/// Whether `domain` equals `prefix` or is a subdomain of it.
+fn domain_matches(domain: &str, prefix: &str) -> bool {
+    domain
+        .strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// Check that the domain of `fqn` is allowed by `policy`. This is separate
+/// from parsing so that [`fqn!`] and [FromStr] keep accepting any
+/// well-formed FQN.
+pub fn validate_namespace(fqn: &ToolFqn, policy: &NamespacePolicy) -> anyhow::Result<()> {
+    if let Some(prefix) = policy
+        .deny
+        .iter()
+        .find(|prefix| domain_matches(fqn.domain(), prefix))
+    {
+        anyhow::bail!(
+            "Tool FQN '{fqn}' uses the domain '{domain}' which is reserved by the denied namespace '{prefix}'",
+            domain = fqn.domain()
+        );
+    }
+
+    if !policy.allow.is_empty()
+        && !policy
+            .allow
+            .iter()
+            .any(|prefix| domain_matches(fqn.domain(), prefix))
+    {
+        anyhow::bail!(
+            "Tool FQN '{fqn}' uses the domain '{domain}' which is not in the allowed namespaces: {allowed}",
+            domain = fqn.domain(),
+            allowed = policy.allow.join(", ")
+        );
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use {super::*, assert_matches::assert_matches};
@@ -237,4 +298,31 @@ mod tests {
 
         assert_matches!(version_too_large, Err(e) if e.to_string().contains("Tool version too large"));
     }
+
+    #[test]
+    fn test_validate_namespace() {
+        let policy = NamespacePolicy {
+            allow: vec!["xyz.taluslabs".to_string(), "com.example".to_string()],
+            deny: vec!["xyz.taluslabs.internal".to_string()],
+        };
+
+        assert!(validate_namespace(&fqn!("xyz.taluslabs.example@1"), &policy).is_ok());
+        assert!(validate_namespace(&fqn!("xyz.taluslabs.llm.example@1"), &policy).is_ok());
+        assert!(validate_namespace(&fqn!("com.example.tool@2"), &policy).is_ok());
+
+        let denied = validate_namespace(&fqn!("xyz.taluslabs.internal.example@1"), &policy);
+
+        assert_matches!(denied, Err(e) if e.to_string().contains("denied namespace 'xyz.taluslabs.internal'"));
+
+        // Prefixes only match whole segments.
+        let not_allowed = validate_namespace(&fqn!("xyz.taluslabsx.example@1"), &policy);
+
+        assert_matches!(not_allowed, Err(e) if e.to_string().contains("not in the allowed namespaces: xyz.taluslabs, com.example"));
+
+        // An empty policy allows everything.
+        let policy = NamespacePolicy::default();
+
+        assert!(policy.is_empty());
+        assert!(validate_namespace(&fqn!("org.anyone.example@1"), &policy).is_ok());
+    }
 }
@@ -26,38 +26,25 @@ pub fn replenish_pre_keys(
     let pre_key_type =
         workflow::into_type_tag(objects.workflow_pkg_id, workflow::PreKeyVault::PRE_KEY);
 
-    // `vector::<PreKey>::empty`
-    let pre_key_vector = tx.programmable_move_call(
-        sui::MOVE_STDLIB_PACKAGE_ID,
-        move_std::Vector::EMPTY.module.into(),
-        move_std::Vector::EMPTY.name.into(),
-        vec![pre_key_type.clone()],
-        vec![],
-    );
+    let mut new_pre_keys = Vec::with_capacity(pre_keys.len());
 
     for pre_key in pre_keys {
         // `bytes: vector<u8>`
         let pre_key_bytes = tx.pure(bincode::serialize(pre_key)?)?;
 
         // `pre_key: PreKey`
-        let new_pre_key = tx.programmable_move_call(
+        new_pre_keys.push(tx.programmable_move_call(
             objects.workflow_pkg_id,
             workflow::PreKeyVault::PRE_KEY_FROM_BYTES.module.into(),
             workflow::PreKeyVault::PRE_KEY_FROM_BYTES.name.into(),
             vec![],
             vec![pre_key_bytes],
-        );
-
-        // `vector::<PreKey>::push_back`
-        tx.programmable_move_call(
-            sui::MOVE_STDLIB_PACKAGE_ID,
-            move_std::Vector::PUSH_BACK.module.into(),
-            move_std::Vector::PUSH_BACK.name.into(),
-            vec![pre_key_type.clone()],
-            vec![pre_key_vector, new_pre_key],
-        );
+        ));
     }
 
+    // `pre_keys: vector<PreKey>`
+    let pre_key_vector = move_std::Vector::make_vector(tx, pre_key_type, new_pre_keys);
+
     // `nexus_workflow::pre_key_vault::replenish_pre_keys`
     Ok(tx.programmable_move_call(
         objects.workflow_pkg_id,
@@ -515,6 +515,33 @@ pub fn execute(
     ))
 }
 
+/// PTB template to cancel a running DAG execution.
+pub fn cancel_execution(
+    tx: &mut sui::ProgrammableTransactionBuilder,
+    objects: &NexusObjects,
+    dag_execution: &sui::ObjectRef,
+    owner_cap: &sui::ObjectRef,
+) -> anyhow::Result<sui::Argument> {
+    // `self: &mut DAGExecution`
+    let dag_execution = tx.obj(sui::ObjectArg::SharedObject {
+        id: dag_execution.object_id,
+        initial_shared_version: dag_execution.version,
+        mutable: true,
+    })?;
+
+    // `owner_cap: &CloneableOwnerCap<T>`
+    let owner_cap = tx.obj(sui::ObjectArg::ImmOrOwnedObject(owner_cap.to_object_ref()))?;
+
+    // `workflow::dag::cancel_execution()`
+    Ok(tx.programmable_move_call(
+        objects.workflow_pkg_id,
+        workflow::Dag::CANCEL_EXECUTION.module.into(),
+        workflow::Dag::CANCEL_EXECUTION.name.into(),
+        vec![],
+        vec![dag_execution, owner_cap],
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -765,6 +792,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cancel_execution() {
+        let nexus_objects = sui_mocks::mock_nexus_objects();
+        let dag_execution = sui_mocks::mock_sui_object_ref();
+        let owner_cap = sui_mocks::mock_sui_object_ref();
+
+        let mut tx = sui::ProgrammableTransactionBuilder::new();
+        cancel_execution(&mut tx, &nexus_objects, &dag_execution, &owner_cap).unwrap();
+        let tx = tx.finish();
+
+        let sui::Command::MoveCall(call) = &tx.commands.last().unwrap() else {
+            panic!("Expected last command to be a MoveCall to cancel a DAG execution");
+        };
+
+        assert_eq!(call.package, nexus_objects.workflow_pkg_id);
+        assert_eq!(
+            call.module,
+            workflow::Dag::CANCEL_EXECUTION.module.to_string(),
+        );
+        assert_eq!(
+            call.function,
+            workflow::Dag::CANCEL_EXECUTION.name.to_string()
+        );
+        assert_eq!(call.arguments.len(), 2);
+    }
+
     #[test]
     fn test_create_output_unencrypted() {
         let objects = sui_mocks::mock_nexus_objects();
@@ -0,0 +1,133 @@
+use {crate::sui, std::time::Duration};
+
+/// How long to wait between polls for the transaction.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Poll the full node until the transaction with `digest` is included in a
+/// checkpoint and return its response with the given `options`.
+///
+/// Transactions that were only executed locally by the full node might not be
+/// visible to other full nodes yet. Once checkpointed, follow-up reads see the
+/// effects of the transaction. Fails if the transaction is not checkpointed
+/// within `timeout`.
+pub async fn wait_for_finality(
+    sui: &sui::Client,
+    digest: sui::TransactionDigest,
+    options: sui::TransactionBlockResponseOptions,
+    timeout: Duration,
+) -> anyhow::Result<sui::TransactionBlockResponse> {
+    let mut last_error = None;
+
+    let poll = async {
+        loop {
+            // The transaction is not found until the full node has executed it.
+            match sui
+                .read_api()
+                .get_transaction_with_options(digest, options.clone())
+                .await
+            {
+                Ok(response) if response.checkpoint.is_some() => return response,
+                Ok(_) => last_error = None,
+                Err(e) => last_error = Some(e),
+            }
+
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    };
+
+    // Bind the result so that the poll no longer borrows the last error.
+    let result = tokio::time::timeout(timeout, poll).await;
+
+    match result {
+        Ok(response) => Ok(response),
+        Err(_) => match last_error {
+            Some(e) => anyhow::bail!(
+                "Transaction '{digest}' was not checkpointed within {timeout:?}, last error: {e}"
+            ),
+            None => anyhow::bail!("Transaction '{digest}' was not checkpointed within {timeout:?}"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::test_utils::sui_rpc::{MockSuiRpc, RpcMethod},
+        serde_json::json,
+    };
+
+    fn response(digest: sui::TransactionDigest, checkpoint: Option<u64>) -> serde_json::Value {
+        let mut response = sui::TransactionBlockResponse::new(digest);
+        response.checkpoint = checkpoint;
+
+        json!(response)
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_finality() {
+        let digest = sui::TransactionDigest::random();
+
+        // The transaction is checkpointed on the third poll.
+        let rpc = MockSuiRpc::builder()
+            .with_responses(
+                RpcMethod::GetTransactionBlock,
+                vec![
+                    response(digest, None),
+                    response(digest, None),
+                    response(digest, Some(42)),
+                ],
+            )
+            .build()
+            .await;
+
+        let sui = sui::ClientBuilder::default()
+            .build(rpc.url())
+            .await
+            .unwrap();
+
+        let response = wait_for_finality(
+            &sui,
+            digest,
+            sui::TransactionBlockResponseOptions::new().with_effects(),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.digest, digest);
+        assert_eq!(response.checkpoint, Some(42));
+
+        rpc.assert_calls(RpcMethod::GetTransactionBlock, 3);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_finality_timeout() {
+        let digest = sui::TransactionDigest::random();
+
+        let rpc = MockSuiRpc::builder()
+            .with_response(RpcMethod::GetTransactionBlock, response(digest, None))
+            .build()
+            .await;
+
+        let sui = sui::ClientBuilder::default()
+            .build(rpc.url())
+            .await
+            .unwrap();
+
+        let err = wait_for_finality(
+            &sui,
+            digest,
+            sui::TransactionBlockResponseOptions::new(),
+            Duration::from_millis(600),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Transaction '{digest}' was not checkpointed within 600ms")
+        );
+        assert!(rpc.calls(RpcMethod::GetTransactionBlock) >= 2);
+    }
+}
@@ -1,10 +1,55 @@
-use crate::{
-    idents::{move_std, sui_framework, workflow},
-    sui,
-    types::NexusObjects,
-    ToolFqn,
+use {
+    crate::{
+        idents::{move_std, sui_framework, workflow},
+        sui::{self, traits::*},
+        types::NexusObjects,
+        ToolFqn,
+    },
+    serde::{Deserialize, Serialize},
 };
 
+/// Gas costs of a transaction in MIST as reported by a dry run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasEstimate {
+    pub computation_cost: u64,
+    pub storage_cost: u64,
+    pub storage_rebate: u64,
+}
+
+impl GasEstimate {
+    /// Net cost of the transaction, `computation_cost + storage_cost -
+    /// storage_rebate`. Can be negative if the transaction frees up storage.
+    pub fn net_cost(&self) -> i64 {
+        self.computation_cost as i64 + self.storage_cost as i64 - self.storage_rebate as i64
+    }
+}
+
+impl From<&sui::GasCostSummary> for GasEstimate {
+    fn from(summary: &sui::GasCostSummary) -> Self {
+        Self {
+            computation_cost: summary.computation_cost,
+            storage_cost: summary.storage_cost,
+            storage_rebate: summary.storage_rebate,
+        }
+    }
+}
+
+/// Dry run the given transaction and return its estimated gas costs.
+///
+/// Fails if the dry run fails or if the transaction would abort.
+pub async fn estimate_gas(
+    sui: &sui::Client,
+    tx_data: sui::TransactionData,
+) -> anyhow::Result<GasEstimate> {
+    let response = sui.read_api().dry_run_transaction_block(tx_data).await?;
+
+    if let sui::ExecutionStatus::Failure { error } = response.effects.status() {
+        anyhow::bail!("Dry run failed: {error}");
+    }
+
+    Ok(GasEstimate::from(response.effects.gas_cost_summary()))
+}
+
 /// PTB template to add gas budget to a transaction.
 pub fn add_budget(
     tx: &mut sui::ProgrammableTransactionBuilder,
@@ -133,6 +178,21 @@ pub fn buy_expiry_gas_ticket(
     tool_fqn: &ToolFqn,
     pay_with: &sui::ObjectRef,
     minutes: u64,
+) -> anyhow::Result<sui::Argument> {
+    // `pay_with: Coin<SUI>`
+    let pay_with = tx.obj(sui::ObjectArg::ImmOrOwnedObject(pay_with.to_object_ref()))?;
+
+    buy_expiry_gas_ticket_with_coin(tx, objects, tool_fqn, pay_with, minutes)
+}
+
+/// [`buy_expiry_gas_ticket`] paying with a coin that is already an argument of
+/// the transaction.
+fn buy_expiry_gas_ticket_with_coin(
+    tx: &mut sui::ProgrammableTransactionBuilder,
+    objects: &NexusObjects,
+    tool_fqn: &ToolFqn,
+    pay_with: sui::Argument,
+    minutes: u64,
 ) -> anyhow::Result<sui::Argument> {
     // `self: &mut GasService`
     let gas_service = tx.obj(sui::ObjectArg::SharedObject {
@@ -154,9 +214,6 @@ pub fn buy_expiry_gas_ticket(
     // `minutes: u64`
     let minutes = tx.pure(minutes)?;
 
-    // `pay_with: Coin<SUI>`
-    let pay_with = tx.obj(sui::ObjectArg::ImmOrOwnedObject(pay_with.to_object_ref()))?;
-
     // `clock: &Clock`
     let clock = tx.obj(sui::CLOCK_OBJ_ARG)?;
 
@@ -279,6 +336,21 @@ pub fn buy_limited_invocations_gas_ticket(
     tool_fqn: &ToolFqn,
     pay_with: &sui::ObjectRef,
     invocations: u64,
+) -> anyhow::Result<sui::Argument> {
+    // `pay_with: Coin<SUI>`
+    let pay_with = tx.obj(sui::ObjectArg::ImmOrOwnedObject(pay_with.to_object_ref()))?;
+
+    buy_limited_invocations_gas_ticket_with_coin(tx, objects, tool_fqn, pay_with, invocations)
+}
+
+/// [`buy_limited_invocations_gas_ticket`] paying with a coin that is already
+/// an argument of the transaction.
+fn buy_limited_invocations_gas_ticket_with_coin(
+    tx: &mut sui::ProgrammableTransactionBuilder,
+    objects: &NexusObjects,
+    tool_fqn: &ToolFqn,
+    pay_with: sui::Argument,
+    invocations: u64,
 ) -> anyhow::Result<sui::Argument> {
     // `self: &mut GasService`
     let gas_service = tx.obj(sui::ObjectArg::SharedObject {
@@ -300,9 +372,6 @@ pub fn buy_limited_invocations_gas_ticket(
     // `invocations: u64`
     let invocations = tx.pure(invocations)?;
 
-    // `pay_with: Coin<SUI>`
-    let pay_with = tx.obj(sui::ObjectArg::ImmOrOwnedObject(pay_with.to_object_ref()))?;
-
     // `clock: &Clock`
     let clock = tx.obj(sui::CLOCK_OBJ_ARG)?;
 
@@ -327,6 +396,115 @@ pub fn buy_limited_invocations_gas_ticket(
     ))
 }
 
+/// The kind of gas ticket to buy in a [`GasTicketPurchase`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GasTicketPurchaseKind {
+    Expiry { minutes: u64 },
+    LimitedInvocations { invocations: u64 },
+}
+
+/// A single gas ticket bought by [`buy_gas_tickets`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasTicketPurchase {
+    pub tool_fqn: ToolFqn,
+    pub kind: GasTicketPurchaseKind,
+    pub pay_with: sui::ObjectRef,
+    /// MIST to split off `pay_with` to pay for this ticket. If [`None`], the
+    /// whole coin is used which is only possible if no other ticket is paid
+    /// with the same coin.
+    pub amount: Option<u64>,
+}
+
+/// PTB template to buy multiple gas tickets in one transaction.
+///
+/// Coins that pay for more than one ticket, or for which an amount is given,
+/// are split once into one coin per ticket and the remainder stays with the
+/// original coin. Returns the result of each purchase in the order of
+/// `purchases`.
+pub fn buy_gas_tickets(
+    tx: &mut sui::ProgrammableTransactionBuilder,
+    objects: &NexusObjects,
+    purchases: &[GasTicketPurchase],
+) -> anyhow::Result<Vec<sui::Argument>> {
+    let mut pay_with = vec![None; purchases.len()];
+
+    for (index, purchase) in purchases.iter().enumerate() {
+        if pay_with[index].is_some() {
+            continue;
+        }
+
+        let coin_id = purchase.pay_with.object_id;
+
+        // All purchases paid with this coin, including this one.
+        let shared = purchases
+            .iter()
+            .enumerate()
+            .filter(|(_, other)| other.pay_with.object_id == coin_id)
+            .map(|(index, other)| (index, other.amount))
+            .collect::<Vec<_>>();
+
+        // `coin: Coin<SUI>`
+        let coin = tx.obj(sui::ObjectArg::ImmOrOwnedObject(
+            purchase.pay_with.to_object_ref(),
+        ))?;
+
+        if let [(_, None)] = shared.as_slice() {
+            pay_with[index] = Some(coin);
+
+            continue;
+        }
+
+        let mut amounts = Vec::with_capacity(shared.len());
+
+        for (_, amount) in &shared {
+            let Some(amount) = amount else {
+                anyhow::bail!(
+                    "Coin '{coin_id}' pays for {} tickets so each of them must specify an amount",
+                    shared.len()
+                );
+            };
+
+            amounts.push(tx.pure(*amount)?);
+        }
+
+        let sui::Argument::Result(split) = tx.command(sui::Command::SplitCoins(coin, amounts))
+        else {
+            unreachable!("Commands always return a result");
+        };
+
+        for (nested, (index, _)) in shared.iter().enumerate() {
+            pay_with[*index] = Some(sui::Argument::NestedResult(split, nested as u16));
+        }
+    }
+
+    purchases
+        .iter()
+        .zip(pay_with)
+        .map(|(purchase, pay_with)| {
+            let pay_with = pay_with.expect("Every purchase is assigned a coin");
+
+            match purchase.kind {
+                GasTicketPurchaseKind::Expiry { minutes } => buy_expiry_gas_ticket_with_coin(
+                    tx,
+                    objects,
+                    &purchase.tool_fqn,
+                    pay_with,
+                    minutes,
+                ),
+                GasTicketPurchaseKind::LimitedInvocations { invocations } => {
+                    buy_limited_invocations_gas_ticket_with_coin(
+                        tx,
+                        objects,
+                        &purchase.tool_fqn,
+                        pay_with,
+                        invocations,
+                    )
+                }
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -337,6 +515,30 @@ mod tests {
     /// Default cost per minute for gas expiry
     const DEFAULT_COST_PER_MINUTE: u64 = 100;
 
+    #[test]
+    fn test_gas_estimate_from_summary() {
+        let summary = sui::GasCostSummary {
+            computation_cost: 1_000,
+            storage_cost: 2_000,
+            storage_rebate: 500,
+            ..Default::default()
+        };
+
+        let estimate = GasEstimate::from(&summary);
+
+        assert_eq!(estimate.computation_cost, 1_000);
+        assert_eq!(estimate.storage_cost, 2_000);
+        assert_eq!(estimate.storage_rebate, 500);
+        assert_eq!(estimate.net_cost(), 2_500);
+
+        let estimate = GasEstimate {
+            storage_rebate: 5_000,
+            ..estimate
+        };
+
+        assert_eq!(estimate.net_cost(), -2_000);
+    }
+
     #[test]
     fn test_add_budget() {
         let objects = sui_mocks::mock_nexus_objects();
@@ -543,4 +745,117 @@ mod tests {
                 .to_string()
         );
     }
+
+    #[test]
+    fn test_buy_gas_tickets() {
+        let objects = sui_mocks::mock_nexus_objects();
+        let shared_coin = sui_mocks::mock_sui_object_ref();
+        let own_coin = sui_mocks::mock_sui_object_ref();
+
+        let purchases = vec![
+            GasTicketPurchase {
+                tool_fqn: fqn!("xyz.test.tool@1"),
+                kind: GasTicketPurchaseKind::Expiry { minutes: 60 },
+                pay_with: shared_coin.clone(),
+                amount: Some(1_000),
+            },
+            GasTicketPurchase {
+                tool_fqn: fqn!("xyz.other.tool@1"),
+                kind: GasTicketPurchaseKind::LimitedInvocations { invocations: 10 },
+                pay_with: shared_coin.clone(),
+                amount: Some(2_000),
+            },
+            GasTicketPurchase {
+                tool_fqn: fqn!("xyz.third.tool@1"),
+                kind: GasTicketPurchaseKind::LimitedInvocations { invocations: 5 },
+                pay_with: own_coin,
+                amount: None,
+            },
+        ];
+
+        let mut tx = sui::ProgrammableTransactionBuilder::new();
+        let results = buy_gas_tickets(&mut tx, &objects, &purchases).unwrap();
+        let tx = tx.finish();
+
+        assert_eq!(results.len(), 3);
+
+        // The shared coin is split once, the other coin is used as is.
+        let splits = tx
+            .commands
+            .iter()
+            .filter_map(|command| match command {
+                sui::Command::SplitCoins(_, amounts) => Some(amounts.len()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(splits, vec![2]);
+
+        let calls = tx
+            .commands
+            .iter()
+            .filter_map(|command| match command {
+                sui::Command::MoveCall(call) => Some(call),
+                _ => None,
+            })
+            .filter(|call| call.package == objects.workflow_pkg_id)
+            .collect::<Vec<_>>();
+
+        let functions = calls
+            .iter()
+            .map(|call| call.function.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            functions,
+            vec![
+                workflow::GasExtension::BUY_EXPIRY_GAS_TICKET
+                    .name
+                    .to_string(),
+                workflow::GasExtension::BUY_LIMITED_INVOCATIONS_GAS_TICKET
+                    .name
+                    .to_string(),
+                workflow::GasExtension::BUY_LIMITED_INVOCATIONS_GAS_TICKET
+                    .name
+                    .to_string(),
+            ]
+        );
+
+        // `pay_with` is the fifth argument of both functions.
+        assert!(matches!(
+            calls[0].arguments[4],
+            sui::Argument::NestedResult(_, 0)
+        ));
+        assert!(matches!(
+            calls[1].arguments[4],
+            sui::Argument::NestedResult(_, 1)
+        ));
+        assert!(matches!(calls[2].arguments[4], sui::Argument::Input(_)));
+    }
+
+    #[test]
+    fn test_buy_gas_tickets_shared_coin_without_amount() {
+        let objects = sui_mocks::mock_nexus_objects();
+        let coin = sui_mocks::mock_sui_object_ref();
+
+        let purchase = GasTicketPurchase {
+            tool_fqn: fqn!("xyz.test.tool@1"),
+            kind: GasTicketPurchaseKind::Expiry { minutes: 60 },
+            pay_with: coin,
+            amount: Some(1_000),
+        };
+
+        let purchases = vec![
+            purchase.clone(),
+            GasTicketPurchase {
+                amount: None,
+                ..purchase
+            },
+        ];
+
+        let mut tx = sui::ProgrammableTransactionBuilder::new();
+        let err = buy_gas_tickets(&mut tx, &objects, &purchases).unwrap_err();
+
+        assert!(err.to_string().contains("must specify an amount"));
+    }
 }
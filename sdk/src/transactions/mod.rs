@@ -9,3 +9,13 @@ pub mod gas;
 
 /// Transactions concerning operations around Nexus cryptography.
 pub mod crypto;
+
+/// Transactions concerning operations around Nexus networks.
+pub mod network;
+
+/// Waiting for submitted transactions to be final.
+pub mod finality;
+
+/// Transactions concerning the metadata of Walrus blobs.
+#[cfg(feature = "walrus")]
+pub mod walrus;
@@ -0,0 +1,58 @@
+use crate::sui::{self, traits::*};
+
+/// PTB template to transfer a leader cap to another address.
+pub fn transfer_leader_cap(
+    tx: &mut sui::ProgrammableTransactionBuilder,
+    leader_cap: &sui::ObjectRef,
+    recipient: sui::Address,
+) -> anyhow::Result<sui::Argument> {
+    // `leader_cap: CloneableOwnerCap<OverNetwork>`
+    let leader_cap = tx.obj(sui::ObjectArg::ImmOrOwnedObject(leader_cap.to_object_ref()))?;
+
+    // `recipient: address`
+    let recipient = tx.pure(recipient)?;
+
+    Ok(tx.command(sui::Command::TransferObjects(vec![leader_cap], recipient)))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_utils::sui_mocks};
+
+    #[test]
+    fn test_transfer_leader_cap() {
+        let leader_cap = sui_mocks::mock_sui_object_ref();
+        let recipient = sui::Address::random_for_testing_only();
+
+        let mut tx = sui::ProgrammableTransactionBuilder::new();
+        transfer_leader_cap(&mut tx, &leader_cap, recipient).unwrap();
+        let tx = tx.finish();
+
+        let sui::Command::TransferObjects(objects, sui::Argument::Input(recipient_index)) =
+            &tx.commands.last().unwrap()
+        else {
+            panic!("Expected last command to be a TransferObjects of the leader cap");
+        };
+
+        assert_eq!(objects.len(), 1);
+
+        let sui::Argument::Input(cap_index) = objects[0] else {
+            panic!("Expected the leader cap to be an input");
+        };
+
+        let sui::CallArg::Object(sui::ObjectArg::ImmOrOwnedObject((cap_id, _, _))) =
+            &tx.inputs[cap_index as usize]
+        else {
+            panic!("Expected the leader cap to be an owned object input");
+        };
+
+        assert_eq!(*cap_id, leader_cap.object_id);
+
+        // Addresses are BCS encoded as their raw bytes.
+        let sui::CallArg::Pure(bytes) = &tx.inputs[*recipient_index as usize] else {
+            panic!("Expected the recipient to be a pure input");
+        };
+
+        assert_eq!(*bytes, recipient.to_vec());
+    }
+}
@@ -228,6 +228,64 @@ pub fn unregister(
     ))
 }
 
+/// PTB template for deprecating a Nexus Tool. If a `replacement_fqn` is
+/// provided, the tool is also redirected to it.
+pub fn deprecate(
+    tx: &mut sui::ProgrammableTransactionBuilder,
+    objects: &NexusObjects,
+    tool_fqn: &ToolFqn,
+    owner_cap: &sui::ObjectRef,
+    reason: &str,
+    replacement_fqn: Option<&ToolFqn>,
+) -> anyhow::Result<sui::Argument> {
+    // `self: &mut ToolRegistry`
+    let tool_registry = tx.obj(sui::ObjectArg::SharedObject {
+        id: objects.tool_registry.object_id,
+        initial_shared_version: objects.tool_registry.version,
+        mutable: true,
+    })?;
+
+    // `owner_cap: &CloneableOwnerCap<OverTool>`
+    let owner_cap = tx.obj(sui::ObjectArg::ImmOrOwnedObject(owner_cap.to_object_ref()))?;
+
+    // `fqn: AsciiString`
+    let fqn = move_std::Ascii::ascii_string_from_str(tx, tool_fqn.to_string())?;
+
+    // `reason: vector<u8>`
+    let reason = tx.pure(reason.as_bytes())?;
+
+    // `clock: &Clock`
+    let clock = tx.obj(sui::CLOCK_OBJ_ARG)?;
+
+    // `nexus_workflow::tool_registry::deprecate_tool()`
+    let deprecated = tx.programmable_move_call(
+        objects.workflow_pkg_id,
+        workflow::ToolRegistry::DEPRECATE_TOOL.module.into(),
+        workflow::ToolRegistry::DEPRECATE_TOOL.name.into(),
+        vec![],
+        vec![tool_registry, owner_cap, fqn, reason, clock],
+    );
+
+    let Some(replacement_fqn) = replacement_fqn else {
+        return Ok(deprecated);
+    };
+
+    // `fqn: AsciiString`, the previous one was moved into `deprecate_tool`
+    let fqn = move_std::Ascii::ascii_string_from_str(tx, tool_fqn.to_string())?;
+
+    // `replacement_fqn: AsciiString`
+    let replacement_fqn = move_std::Ascii::ascii_string_from_str(tx, replacement_fqn.to_string())?;
+
+    // `nexus_workflow::tool_registry::redirect_tool()`
+    Ok(tx.programmable_move_call(
+        objects.workflow_pkg_id,
+        workflow::ToolRegistry::REDIRECT_TOOL.module.into(),
+        workflow::ToolRegistry::REDIRECT_TOOL.name.into(),
+        vec![],
+        vec![tool_registry, owner_cap, fqn, replacement_fqn],
+    ))
+}
+
 /// PTB template for claiming collateral for a Nexus Tool. The funds are
 /// transferred to the tx sender.
 pub fn claim_collateral_for_self(
@@ -354,6 +412,63 @@ mod tests {
         assert_eq!(call.arguments.len(), 4);
     }
 
+    #[test]
+    fn test_deprecate_tool() {
+        let objects = sui_mocks::mock_nexus_objects();
+        let tool_fqn = fqn!("xyz.dummy.tool@1");
+        let owner_cap = sui_mocks::mock_sui_object_ref();
+
+        // Without a replacement only the tool is deprecated.
+        let mut tx = sui::ProgrammableTransactionBuilder::new();
+        deprecate(&mut tx, &objects, &tool_fqn, &owner_cap, "Outdated", None)
+            .expect("Failed to build PTB for deprecating a tool.");
+        let tx = tx.finish();
+
+        let sui::Command::MoveCall(call) = &tx.commands.last().unwrap() else {
+            panic!("Expected last command to be a MoveCall to deprecate a tool");
+        };
+
+        assert_eq!(call.package, objects.workflow_pkg_id);
+
+        assert_eq!(
+            call.module,
+            workflow::ToolRegistry::DEPRECATE_TOOL.module.to_string(),
+        );
+
+        assert_eq!(
+            call.function,
+            workflow::ToolRegistry::DEPRECATE_TOOL.name.to_string()
+        );
+
+        assert_eq!(call.arguments.len(), 5);
+
+        // With a replacement the tool is also redirected.
+        let replacement_fqn = fqn!("xyz.dummy.tool@2");
+
+        let mut tx = sui::ProgrammableTransactionBuilder::new();
+        deprecate(
+            &mut tx,
+            &objects,
+            &tool_fqn,
+            &owner_cap,
+            "Outdated",
+            Some(&replacement_fqn),
+        )
+        .expect("Failed to build PTB for deprecating a tool.");
+        let tx = tx.finish();
+
+        let sui::Command::MoveCall(call) = &tx.commands.last().unwrap() else {
+            panic!("Expected last command to be a MoveCall to redirect a tool");
+        };
+
+        assert_eq!(
+            call.function,
+            workflow::ToolRegistry::REDIRECT_TOOL.name.to_string()
+        );
+
+        assert_eq!(call.arguments.len(), 4);
+    }
+
     #[test]
     fn test_claim_collateral_for_self() {
         let objects = sui_mocks::mock_nexus_objects();
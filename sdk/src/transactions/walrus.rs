@@ -0,0 +1,100 @@
+use crate::{
+    idents::walrus,
+    sui::{self, traits::*},
+    walrus::BlobMetadata,
+};
+
+/// PTB template to store the metadata of a Walrus blob as attributes of its
+/// `Blob` object. Existing attributes with the same keys are overwritten. Must
+/// be signed by the owner of the object.
+pub fn set_blob_metadata(
+    tx: &mut sui::ProgrammableTransactionBuilder,
+    walrus_pkg_id: sui::ObjectID,
+    blob: &sui::ObjectRef,
+    metadata: &BlobMetadata,
+) -> anyhow::Result<()> {
+    let attributes = metadata.to_attributes()?;
+
+    // `self: &mut Blob`
+    let blob = tx.obj(sui::ObjectArg::ImmOrOwnedObject(blob.to_object_ref()))?;
+
+    for (key, value) in attributes {
+        // `key: String`
+        let key = tx.pure(key)?;
+
+        // `value: String`
+        let value = tx.pure(value)?;
+
+        tx.programmable_move_call(
+            walrus_pkg_id,
+            walrus::Blob::INSERT_OR_UPDATE_METADATA_PAIR.module.into(),
+            walrus::Blob::INSERT_OR_UPDATE_METADATA_PAIR.name.into(),
+            vec![],
+            vec![blob, key, value],
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_utils::sui_mocks};
+
+    #[test]
+    fn test_set_blob_metadata() {
+        let walrus_pkg_id = sui::ObjectID::random();
+        let blob = sui_mocks::mock_sui_object_ref();
+        let metadata = BlobMetadata::new()
+            .with_content_type("application/json")
+            .with_tag("owner", "nexus");
+
+        let mut tx = sui::ProgrammableTransactionBuilder::new();
+        set_blob_metadata(&mut tx, walrus_pkg_id, &blob, &metadata).unwrap();
+        let tx = tx.finish();
+
+        // One call per attribute, in key order.
+        assert_eq!(tx.commands.len(), 2);
+
+        let mut keys = vec![];
+
+        for command in &tx.commands {
+            let sui::Command::MoveCall(call) = command else {
+                panic!("Expected a MoveCall to set an attribute");
+            };
+
+            assert_eq!(call.package, walrus_pkg_id);
+            assert_eq!(
+                call.module,
+                walrus::Blob::INSERT_OR_UPDATE_METADATA_PAIR
+                    .module
+                    .to_string()
+            );
+            assert_eq!(
+                call.function,
+                walrus::Blob::INSERT_OR_UPDATE_METADATA_PAIR
+                    .name
+                    .to_string()
+            );
+
+            let sui::Argument::Input(key_index) = call.arguments[1] else {
+                panic!("Expected the key to be an input");
+            };
+
+            let sui::CallArg::Pure(bytes) = &tx.inputs[key_index as usize] else {
+                panic!("Expected the key to be a pure input");
+            };
+
+            // Short strings are BCS encoded with a single length byte.
+            keys.push(String::from_utf8(bytes[1..].to_vec()).unwrap());
+        }
+
+        assert_eq!(keys, vec!["content-type", "owner"]);
+
+        // Invalid metadata is rejected before building the PTB.
+        let mut tx = sui::ProgrammableTransactionBuilder::new();
+        let metadata = BlobMetadata::new().with_tag("content-type", "text/plain");
+
+        assert!(set_blob_metadata(&mut tx, walrus_pkg_id, &blob, &metadata).is_err());
+    }
+}
@@ -9,8 +9,8 @@ mod type_name;
 pub use {
     json_dag::*,
     nexus_data::NexusData,
-    nexus_objects::NexusObjects,
-    runtime_vertex::RuntimeVertex,
+    nexus_objects::{MissingObject, MissingObjectReason, NexusObjects},
+    runtime_vertex::{RuntimeVertex, VertexStatus},
     serde_parsers::*,
     tool_meta::ToolMeta,
     type_name::TypeName,
@@ -23,6 +23,83 @@ pub enum NexusData {
     },
 }
 
+impl NexusData {
+    /// Create inline, unencrypted data from a JSON value.
+    pub fn new_inline(data: serde_json::Value) -> Self {
+        Self::Inline {
+            data,
+            encrypted: false,
+        }
+    }
+
+    /// Returns the JSON value of inline, unencrypted data or [`None`] for
+    /// encrypted or remote data.
+    pub fn as_json(&self) -> Option<&serde_json::Value> {
+        match self {
+            Self::Inline {
+                data,
+                encrypted: false,
+            } => Some(data),
+            Self::Inline {
+                encrypted: true, ..
+            }
+            | Self::Remote {} => None,
+        }
+    }
+
+    /// Returns the value if it is a JSON string.
+    pub fn as_str(&self) -> Option<&str> {
+        self.as_json()?.as_str()
+    }
+
+    /// Returns the value if it is a JSON number that fits into a [`u64`].
+    pub fn as_u64(&self) -> Option<u64> {
+        self.as_json()?.as_u64()
+    }
+
+    /// Returns the value if it is a JSON boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        self.as_json()?.as_bool()
+    }
+
+    /// Returns the value if it is a JSON array of numbers that all fit into
+    /// a [`u8`], which is how byte vectors are represented in DAGs.
+    pub fn as_bytes(&self) -> Option<Vec<u8>> {
+        self.as_json()?
+            .as_array()?
+            .iter()
+            .map(|byte| byte.as_u64().and_then(|byte| u8::try_from(byte).ok()))
+            .collect()
+    }
+}
+
+/// JSON values become inline, unencrypted data. This also provides the
+/// infallible [`TryFrom<serde_json::Value>`] implementation.
+impl From<serde_json::Value> for NexusData {
+    fn from(data: serde_json::Value) -> Self {
+        Self::new_inline(data)
+    }
+}
+
+/// Only inline data holds a JSON value, encrypted data must be decrypted and
+/// remote data must be fetched first.
+impl TryFrom<NexusData> for serde_json::Value {
+    type Error = anyhow::Error;
+
+    fn try_from(data: NexusData) -> Result<Self, Self::Error> {
+        match data {
+            NexusData::Inline {
+                data,
+                encrypted: false,
+            } => Ok(data),
+            NexusData::Inline {
+                encrypted: true, ..
+            } => anyhow::bail!("Encrypted data cannot be converted to JSON"),
+            NexusData::Remote {} => anyhow::bail!("Remote data cannot be converted to JSON"),
+        }
+    }
+}
+
 mod parser {
     //! We represent nexus data onchain as a struct of
     //! `{ storage: u8[], one: u8[], many: u8[][], encrypted: bool }`.
@@ -215,3 +292,67 @@ mod parser {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::json};
+
+    #[test]
+    fn test_nexus_data_accessors() {
+        assert_eq!(NexusData::from(json!("hello")).as_str(), Some("hello"));
+        assert_eq!(NexusData::from(json!(42)).as_u64(), Some(42));
+        assert_eq!(NexusData::from(json!(true)).as_bool(), Some(true));
+        assert_eq!(
+            NexusData::from(json!([1, 2, 255])).as_bytes(),
+            Some(vec![1, 2, 255])
+        );
+
+        // Values of another type are not converted.
+        assert_eq!(NexusData::from(json!("42")).as_u64(), None);
+        assert_eq!(NexusData::from(json!(-1)).as_u64(), None);
+        assert_eq!(NexusData::from(json!([1, 256])).as_bytes(), None);
+        assert_eq!(NexusData::from(json!(["a"])).as_bytes(), None);
+        assert_eq!(NexusData::from(json!(1)).as_str(), None);
+
+        // Remote data holds no value.
+        let remote = NexusData::Remote {};
+        assert_eq!(remote.as_json(), None);
+        assert_eq!(remote.as_str(), None);
+        assert!(serde_json::Value::try_from(remote).is_err());
+
+        // Encrypted data must be decrypted first.
+        let encrypted = NexusData::Inline {
+            data: json!("ciphertext"),
+            encrypted: true,
+        };
+        assert_eq!(encrypted.as_json(), None);
+        assert_eq!(encrypted.as_str(), None);
+        assert!(serde_json::Value::try_from(encrypted).is_err());
+    }
+
+    #[test]
+    fn test_nexus_data_json_round_trip() {
+        let value = json!({
+            "user": { "name": "Alice", "tags": ["a", "b"], "age": 30 },
+            "active": true,
+            "scores": [1.5, null, { "nested": [] }]
+        });
+
+        let data = NexusData::try_from(value.clone()).unwrap();
+        assert_eq!(
+            data,
+            NexusData::Inline {
+                data: value.clone(),
+                encrypted: false
+            }
+        );
+
+        // Survives the on-chain representation too.
+        let serialized = serde_json::to_string(&data).unwrap();
+        let deserialized = serde_json::from_str::<NexusData>(&serialized).unwrap();
+        assert_eq!(deserialized, data);
+
+        let json: serde_json::Value = deserialized.try_into().unwrap();
+        assert_eq!(json, value);
+    }
+}
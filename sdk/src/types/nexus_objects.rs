@@ -1,7 +1,15 @@
 //! [`NexusObjects`] struct is holding the Nexus object IDs and refs that are
 //! generated during Nexus package deployment.
 use {
-    crate::sui,
+    crate::{
+        idents::{
+            version::{check_compatibility, VersionError, VersionedIdent, WORKFLOW_IDENTS},
+            workflow,
+            ModuleAndNameIdent,
+        },
+        sui,
+        types::TypeName,
+    },
     serde::{Deserialize, Serialize},
 };
 
@@ -16,3 +24,352 @@ pub struct NexusObjects {
     pub gas_service: sui::ObjectRef,
     pub pre_key_vault: sui::ObjectRef,
 }
+
+/// An object referenced by [`NexusObjects`] that is not usable on the network.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MissingObject {
+    /// Name of the [`NexusObjects`] field, for example `tool_registry`.
+    pub name: &'static str,
+    pub object_id: sui::ObjectID,
+    pub reason: MissingObjectReason,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MissingObjectReason {
+    /// The object does not exist or was deleted.
+    NotFound,
+    /// The object exists but is of another type.
+    WrongType { expected: String, found: String },
+    /// The object could not be fetched from the RPC.
+    FetchFailed(String),
+    /// The package version lacks idents the SDK calls.
+    Incompatible(VersionError),
+}
+
+impl std::fmt::Display for MissingObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' ({}) ", self.name, self.object_id)?;
+
+        match &self.reason {
+            MissingObjectReason::NotFound => write!(f, "does not exist"),
+            MissingObjectReason::WrongType { expected, found } => {
+                write!(f, "is a '{found}' instead of a '{expected}'")
+            }
+            MissingObjectReason::FetchFailed(e) => write!(f, "could not be fetched: {e}"),
+            MissingObjectReason::Incompatible(e) => write!(f, "is incompatible: {e}"),
+        }
+    }
+}
+
+/// What an object referenced by [`NexusObjects`] is expected to be.
+enum ExpectedType {
+    /// A Move package that must contain the given idents at its version.
+    Package(&'static [VersionedIdent]),
+    /// A Move object, optionally of the given `module::Name` type.
+    Object(Option<ModuleAndNameIdent>),
+}
+
+impl NexusObjects {
+    /// Fetch all referenced objects in one batch and check that they exist
+    /// and are of the expected type. Returns every object that failed the
+    /// check.
+    ///
+    /// Only the module and name of Move types are compared because the types
+    /// keep the address of the original package after an upgrade. The version
+    /// of the workflow package is checked against
+    /// [`crate::idents::version::WORKFLOW_IDENTS`].
+    pub async fn verify(&self, sui: &sui::Client) -> Result<(), Vec<MissingObject>> {
+        let expected = [
+            (
+                "workflow_pkg_id",
+                self.workflow_pkg_id,
+                ExpectedType::Package(WORKFLOW_IDENTS),
+            ),
+            (
+                "primitives_pkg_id",
+                self.primitives_pkg_id,
+                ExpectedType::Package(&[]),
+            ),
+            (
+                "interface_pkg_id",
+                self.interface_pkg_id,
+                ExpectedType::Package(&[]),
+            ),
+            ("network_id", self.network_id, ExpectedType::Object(None)),
+            (
+                "tool_registry",
+                self.tool_registry.object_id,
+                ExpectedType::Object(Some(workflow::ToolRegistry::TOOL_REGISTRY)),
+            ),
+            (
+                "default_tap",
+                self.default_tap.object_id,
+                ExpectedType::Object(Some(workflow::DefaultTap::DEFAULT_TAP)),
+            ),
+            (
+                "gas_service",
+                self.gas_service.object_id,
+                ExpectedType::Object(Some(workflow::Gas::GAS_SERVICE)),
+            ),
+            (
+                "pre_key_vault",
+                self.pre_key_vault.object_id,
+                ExpectedType::Object(Some(workflow::PreKeyVault::PRE_KEY_VAULT)),
+            ),
+        ];
+
+        let object_ids = expected.iter().map(|(_, id, _)| *id).collect();
+        let options = sui::ObjectDataOptions::new().with_type();
+
+        let responses = match sui
+            .read_api()
+            .multi_get_object_with_options(object_ids, options)
+            .await
+        {
+            Ok(responses) => responses,
+            Err(e) => {
+                return Err(expected
+                    .into_iter()
+                    .map(|(name, object_id, _)| MissingObject {
+                        name,
+                        object_id,
+                        reason: MissingObjectReason::FetchFailed(e.to_string()),
+                    })
+                    .collect())
+            }
+        };
+
+        let mut responses = responses.into_iter();
+
+        let missing = expected
+            .into_iter()
+            .filter_map(|(name, object_id, expected)| {
+                let reason = match responses.next().and_then(|response| response.data) {
+                    Some(data) => check_type(&expected, data.type_.as_ref())
+                        .or_else(|| check_version(&expected, data.version))?,
+                    None => MissingObjectReason::NotFound,
+                };
+
+                Some(MissingObject {
+                    name,
+                    object_id,
+                    reason,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        match missing.is_empty() {
+            true => Ok(()),
+            false => Err(missing),
+        }
+    }
+}
+
+/// Returns the reason why the object type does not match the expectation.
+fn check_type(
+    expected: &ExpectedType,
+    found: Option<&sui::ObjectType>,
+) -> Option<MissingObjectReason> {
+    let matches = match (expected, found) {
+        (ExpectedType::Package(_), Some(sui::ObjectType::Package)) => true,
+        (ExpectedType::Object(None), Some(sui::ObjectType::Struct(_))) => true,
+        (ExpectedType::Object(Some(ident)), Some(found @ sui::ObjectType::Struct(_))) => {
+            let found = TypeName {
+                name: found.to_string(),
+            };
+
+            // The package address changes on upgrades so only the module and
+            // name are compared.
+            matches!(
+                found.to_type_tag(),
+                Ok(sui::MoveTypeTag::Struct(tag))
+                    if tag.module.as_str() == ident.module.as_str()
+                        && tag.name.as_str() == ident.name.as_str()
+            )
+        }
+        _ => false,
+    };
+
+    if matches {
+        return None;
+    }
+
+    let expected = match expected {
+        ExpectedType::Package(_) => "package".to_string(),
+        ExpectedType::Object(None) => "Move object".to_string(),
+        ExpectedType::Object(Some(ident)) => format!("{}::{}", ident.module, ident.name),
+    };
+
+    Some(MissingObjectReason::WrongType {
+        expected,
+        found: found.map_or("unknown".to_string(), ToString::to_string),
+    })
+}
+
+/// Returns the reason why the package at `version` is not compatible with the
+/// SDK.
+fn check_version(
+    expected: &ExpectedType,
+    version: sui::SequenceNumber,
+) -> Option<MissingObjectReason> {
+    let ExpectedType::Package(idents) = expected else {
+        return None;
+    };
+
+    check_compatibility(version.into(), idents)
+        .err()
+        .map(MissingObjectReason::Incompatible)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::test_utils::{
+            sui_mocks,
+            sui_rpc::{MockSuiRpc, RpcMethod},
+        },
+        serde_json::{json, Value},
+    };
+
+    fn object(object_id: sui::ObjectID, type_: &str) -> Value {
+        versioned_object(object_id, type_, 2)
+    }
+
+    fn versioned_object(object_id: sui::ObjectID, type_: &str, version: u64) -> Value {
+        json!({
+            "data": {
+                "objectId": object_id,
+                "version": version.to_string(),
+                "digest": sui::ObjectDigest::random(),
+                "type": type_,
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn test_verify_reports_missing_objects() {
+        let objects = sui_mocks::mock_nexus_objects();
+        // Types keep the original package address after upgrades.
+        let original_pkg = sui::ObjectID::random();
+
+        let responses = json!([
+            object(objects.workflow_pkg_id, "package"),
+            object(objects.primitives_pkg_id, "package"),
+            object(objects.interface_pkg_id, "package"),
+            object(
+                objects.network_id,
+                &format!("{original_pkg}::network::Network")
+            ),
+            object(
+                objects.tool_registry.object_id,
+                &format!("{original_pkg}::tool_registry::ToolRegistry"),
+            ),
+            {
+                "error": {
+                    "code": "notExists",
+                    "object_id": objects.default_tap.object_id,
+                },
+            },
+            object(
+                objects.gas_service.object_id,
+                &format!("{original_pkg}::gas::GasService"),
+            ),
+            object(objects.pre_key_vault.object_id, "package"),
+        ]);
+
+        let rpc = MockSuiRpc::builder()
+            .with_response(RpcMethod::MultiGetObjects, responses)
+            .build()
+            .await;
+
+        let sui = sui::ClientBuilder::default()
+            .build(rpc.url())
+            .await
+            .unwrap();
+
+        let missing = objects.verify(&sui).await.unwrap_err();
+
+        assert_eq!(
+            missing,
+            vec![
+                MissingObject {
+                    name: "default_tap",
+                    object_id: objects.default_tap.object_id,
+                    reason: MissingObjectReason::NotFound,
+                },
+                MissingObject {
+                    name: "pre_key_vault",
+                    object_id: objects.pre_key_vault.object_id,
+                    reason: MissingObjectReason::WrongType {
+                        expected: "pre_key_vault::PreKeyVault".to_string(),
+                        found: "package".to_string(),
+                    },
+                },
+            ]
+        );
+        assert_eq!(
+            missing[0].to_string(),
+            format!(
+                "'default_tap' ({}) does not exist",
+                objects.default_tap.object_id
+            )
+        );
+        rpc.assert_calls(RpcMethod::MultiGetObjects, 1);
+    }
+
+    #[tokio::test]
+    async fn test_verify_reports_incompatible_workflow_package() {
+        let objects = sui_mocks::mock_nexus_objects();
+        let original_pkg = sui::ObjectID::random();
+
+        let responses = json!([
+            versioned_object(objects.workflow_pkg_id, "package", 1),
+            object(objects.primitives_pkg_id, "package"),
+            object(objects.interface_pkg_id, "package"),
+            object(
+                objects.network_id,
+                &format!("{original_pkg}::network::Network")
+            ),
+            object(
+                objects.tool_registry.object_id,
+                &format!("{original_pkg}::tool_registry::ToolRegistry"),
+            ),
+            object(
+                objects.default_tap.object_id,
+                &format!("{original_pkg}::default_tap::DefaultTAP"),
+            ),
+            object(
+                objects.gas_service.object_id,
+                &format!("{original_pkg}::gas::GasService"),
+            ),
+            object(
+                objects.pre_key_vault.object_id,
+                &format!("{original_pkg}::pre_key_vault::PreKeyVault"),
+            ),
+        ]);
+
+        let rpc = MockSuiRpc::builder()
+            .with_response(RpcMethod::MultiGetObjects, responses)
+            .build()
+            .await;
+
+        let sui = sui::ClientBuilder::default()
+            .build(rpc.url())
+            .await
+            .unwrap();
+
+        let missing = objects.verify(&sui).await.unwrap_err();
+
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].name, "workflow_pkg_id");
+        assert!(matches!(
+            &missing[0].reason,
+            MissingObjectReason::Incompatible(VersionError::Incompatible { missing, .. })
+                if missing.len() == WORKFLOW_IDENTS.len()
+        ));
+        assert!(missing[0]
+            .to_string()
+            .contains("is incompatible: On-chain package at version v1"));
+    }
+}
@@ -6,10 +6,12 @@
 //! - [`RuntimeVertex::WithIterator`] variant contains the data about
 //!   which iteration of the vertex is being executed and what is the max number
 //!   of iterations.
+//!
+//! [`VertexStatus`] describes how far the execution of a vertex got.
 
 use {
     crate::types::*,
-    serde::{Deserialize, Serialize},
+    serde::{Deserialize, Deserializer, Serialize, Serializer},
 };
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
@@ -66,6 +68,102 @@ impl std::fmt::Display for RuntimeVertex {
     }
 }
 
+/// Execution status of a [`RuntimeVertex`]. (De)serializes like a Move enum,
+/// as `{ "variant": "...", "fields": { ... } }`, and variants unknown to this
+/// version of the SDK fall back to [`VertexStatus::Unknown`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VertexStatus {
+    /// The walk has requested the vertex to be evaluated.
+    Pending,
+    /// The tool invocation was paid for and the vertex is being evaluated.
+    Running,
+    /// The vertex was evaluated.
+    Succeeded,
+    /// The walk failed at this vertex.
+    Failed { reason: String },
+    /// The execution finished without evaluating the vertex.
+    Skipped,
+    /// A variant added after this version of the SDK.
+    Unknown { variant: String },
+}
+
+impl VertexStatus {
+    /// Whether the status can no longer change.
+    pub fn is_final(&self) -> bool {
+        !matches!(self, Self::Pending | Self::Running)
+    }
+
+    fn variant(&self) -> &str {
+        match self {
+            Self::Pending => "Pending",
+            Self::Running => "Running",
+            Self::Succeeded => "Succeeded",
+            Self::Failed { .. } => "Failed",
+            Self::Skipped => "Skipped",
+            Self::Unknown { variant } => variant,
+        }
+    }
+}
+
+impl std::fmt::Display for VertexStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Failed { reason } => write!(f, "Failed: {reason}"),
+            Self::Unknown { variant } => write!(f, "Unknown({variant})"),
+            status => f.write_str(status.variant()),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct MoveEnum<T> {
+    variant: T,
+    #[serde(default)]
+    fields: serde_json::Value,
+}
+
+impl Serialize for VertexStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let fields = match self {
+            Self::Failed { reason } => serde_json::json!({ "reason": reason }),
+            _ => serde_json::json!({}),
+        };
+
+        MoveEnum {
+            variant: self.variant(),
+            fields,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for VertexStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let MoveEnum { variant, fields } = MoveEnum::<String>::deserialize(deserializer)?;
+
+        Ok(match variant.as_str() {
+            "Pending" => Self::Pending,
+            "Running" => Self::Running,
+            "Succeeded" => Self::Succeeded,
+            "Failed" => Self::Failed {
+                reason: fields
+                    .get("reason")
+                    .and_then(|reason| reason.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+            },
+            "Skipped" => Self::Skipped,
+            _ => Self::Unknown { variant },
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -220,4 +318,54 @@ mod tests {
             _ => panic!("Expected WithIterator variant"),
         }
     }
+
+    #[test]
+    fn test_vertex_status_from_move_enum() {
+        let cases = [
+            (
+                r#"{"variant":"Pending","fields":{}}"#,
+                VertexStatus::Pending,
+            ),
+            (r#"{"variant":"Running"}"#, VertexStatus::Running),
+            (
+                r#"{"variant":"Succeeded","fields":{}}"#,
+                VertexStatus::Succeeded,
+            ),
+            (
+                r#"{"variant":"Failed","fields":{"reason":"tool unreachable"}}"#,
+                VertexStatus::Failed {
+                    reason: "tool unreachable".to_string(),
+                },
+            ),
+            (
+                r#"{"variant":"Skipped","fields":{}}"#,
+                VertexStatus::Skipped,
+            ),
+            (
+                r#"{"variant":"TimedOut","fields":{"after_ms":"100"}}"#,
+                VertexStatus::Unknown {
+                    variant: "TimedOut".to_string(),
+                },
+            ),
+        ];
+
+        for (json, expected) in cases {
+            let status: VertexStatus = serde_json::from_str(json).unwrap();
+
+            assert_eq!(status, expected);
+        }
+    }
+
+    #[test]
+    fn test_vertex_status_roundtrip() {
+        let status = VertexStatus::Failed {
+            reason: "boom".to_string(),
+        };
+
+        let json = serde_json::to_string(&status).unwrap();
+
+        assert_eq!(json, r#"{"variant":"Failed","fields":{"reason":"boom"}}"#);
+        assert_eq!(serde_json::from_str::<VertexStatus>(&json).unwrap(), status);
+        assert_eq!(status.to_string(), "Failed: boom");
+    }
 }
@@ -1,27 +1,183 @@
 //! Ubiqutously used name wrapper type. Useful to have this defined globally
 //! so that we don't have to redefine it in every module that uses it.
 
-use serde::{Deserialize, Serialize};
+use {
+    crate::sui,
+    serde::{Deserialize, Serialize},
+    std::str::FromStr,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TypeName {
     pub name: String,
 }
 
+impl TypeName {
+    /// Create a [TypeName] in the format of Move's `std::type_name`, that is
+    /// with full length addresses without the `0x` prefix.
+    pub fn from_type_tag(tag: &sui::MoveTypeTag) -> Self {
+        let with_prefix = false;
+
+        Self {
+            name: tag.to_canonical_string(with_prefix),
+        }
+    }
+
+    /// Parse the name into a [sui::MoveTypeTag]. Addresses may be in any
+    /// format, with or without the `0x` prefix and leading zeros.
+    pub fn to_type_tag(&self) -> anyhow::Result<sui::MoveTypeTag> {
+        sui::MoveTypeTag::from_str(&with_address_prefix(&self.name))
+            .map_err(|e| anyhow::anyhow!("Invalid type name '{}': {e}", self.name))
+    }
+
+    /// Whether both names refer to the same type, regardless of how their
+    /// addresses are formatted. Names that cannot be parsed are compared
+    /// as-is.
+    pub fn is_same_struct(&self, other: &TypeName) -> bool {
+        match (self.to_type_tag(), other.to_type_tag()) {
+            (Ok(tag), Ok(other_tag)) => tag == other_tag,
+            _ => self.name == other.name,
+        }
+    }
+}
+
+impl From<&sui::MoveTypeTag> for TypeName {
+    fn from(tag: &sui::MoveTypeTag) -> Self {
+        Self::from_type_tag(tag)
+    }
+}
+
 impl std::fmt::Display for TypeName {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}", self.name)
     }
 }
 
+/// Move's `std::type_name` omits the `0x` prefix of addresses which the type
+/// tag parser requires. Add it to the address of each struct in the name.
+fn with_address_prefix(name: &str) -> String {
+    let mut prefixed = String::with_capacity(name.len() + 8);
+    let mut rest = name;
+
+    // Each iteration starts at the beginning of a type.
+    loop {
+        let trimmed = rest.trim_start();
+
+        prefixed.push_str(&rest[..rest.len() - trimmed.len()]);
+
+        let address_len = trimmed
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(trimmed.len());
+        let address = &trimmed[..address_len];
+
+        if address_len > 0
+            && trimmed[address_len..].starts_with("::")
+            && address.chars().all(|c| c.is_ascii_hexdigit())
+        {
+            prefixed.push_str("0x");
+        }
+
+        // Type parameters start a new type.
+        match trimmed.find(['<', ',']) {
+            Some(index) => {
+                prefixed.push_str(&trimmed[..=index]);
+                rest = &trimmed[index + 1..];
+            }
+            None => {
+                prefixed.push_str(trimmed);
+
+                return prefixed;
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn coin_sui() -> sui::MoveTypeTag {
+        sui::MoveTypeTag::Struct(Box::new(sui::MoveStructTag {
+            address: *sui::FRAMEWORK_PACKAGE_ID,
+            module: sui::move_ident_str!("coin").into(),
+            name: sui::move_ident_str!("Coin").into(),
+            type_params: vec![sui::MoveTypeTag::Struct(Box::new(sui::MoveStructTag {
+                address: *sui::FRAMEWORK_PACKAGE_ID,
+                module: sui::move_ident_str!("sui").into(),
+                name: sui::move_ident_str!("SUI").into(),
+                type_params: vec![],
+            }))],
+        }))
+    }
+
     #[test]
     fn test_type_name_deser_display() {
         let name: TypeName = serde_json::from_str(r#"{"name":"test"}"#).unwrap();
 
         assert_eq!(name.to_string(), "test");
     }
+
+    #[test]
+    fn test_type_name_type_tag_round_trip() {
+        let tag = coin_sui();
+        let name = TypeName::from_type_tag(&tag);
+        let address = "0000000000000000000000000000000000000000000000000000000000000002";
+
+        assert_eq!(
+            name.name,
+            format!("{address}::coin::Coin<{address}::sui::SUI>")
+        );
+        assert_eq!(name.to_type_tag().unwrap(), tag);
+
+        // Addresses may be short, padded or without the prefix.
+        let short = TypeName {
+            name: "0x0002::coin::Coin<2::sui::SUI>".to_string(),
+        };
+
+        assert_eq!(short.to_type_tag().unwrap(), tag);
+
+        let vector = sui::MoveTypeTag::Vector(Box::new(tag));
+        let name = TypeName::from(&vector);
+
+        assert_eq!(name.to_type_tag().unwrap(), vector);
+        assert_eq!(TypeName::from_type_tag(&name.to_type_tag().unwrap()), name);
+    }
+
+    #[test]
+    fn test_type_name_is_same_struct() {
+        let name = TypeName::from_type_tag(&coin_sui());
+        let short = TypeName {
+            name: "0x2::coin::Coin<0x2::sui::SUI>".to_string(),
+        };
+        let padded = TypeName {
+            name: "0x0002::coin::Coin<2::sui::SUI>".to_string(),
+        };
+
+        assert!(name.is_same_struct(&short));
+        assert!(short.is_same_struct(&padded));
+        assert!(!name.is_same_struct(&TypeName {
+            name: "0x2::coin::Coin<0x3::sui::SUI>".to_string(),
+        }));
+        assert!(!name.is_same_struct(&TypeName {
+            name: "0x2::coin::TreasuryCap<0x2::sui::SUI>".to_string(),
+        }));
+
+        // Unparsable names are compared as-is.
+        let invalid = TypeName {
+            name: "not a type".to_string(),
+        };
+
+        assert!(invalid.is_same_struct(&invalid.clone()));
+        assert!(!invalid.is_same_struct(&name));
+    }
+
+    #[test]
+    fn test_with_address_prefix() {
+        assert_eq!(
+            with_address_prefix("02::coin::Coin<2::sui::SUI, vector<u8>>"),
+            "0x02::coin::Coin<0x2::sui::SUI, vector<u8>>"
+        );
+        assert_eq!(with_address_prefix("0x2::sui::SUI"), "0x2::sui::SUI");
+        assert_eq!(with_address_prefix("u64"), "u64");
+    }
 }
@@ -1,9 +1,15 @@
 use {
     crate::walrus::models::*,
     futures_util::StreamExt,
-    reqwest::Client,
+    reqwest::{
+        header::{HeaderMap, CONTENT_DISPOSITION, CONTENT_TYPE},
+        Client,
+        Response,
+        StatusCode,
+    },
     serde::{de::DeserializeOwned, Serialize},
-    std::{io, path::PathBuf},
+    serde_json::{json, Value},
+    std::{io, path::PathBuf, sync::Arc},
     thiserror::Error,
     tokio::{fs::File, io::AsyncWriteExt},
 };
@@ -13,7 +19,17 @@ use {
 pub const WALRUS_PUBLISHER_URL: &str = "https://publisher.walrus-testnet.walrus.space";
 pub const WALRUS_AGGREGATOR_URL: &str = "https://aggregator.walrus-testnet.walrus.space";
 
-/// Errors that can occur when interacting with the Walrus API
+// The system object is from <https://github.com/MystenLabs/walrus/blob/main/setup/client_config.yaml>
+// Sui RPC and Walrus system object used to read the storage prices
+pub const SUI_TESTNET_RPC_URL: &str = "https://fullnode.testnet.sui.io:443";
+pub const WALRUS_SYSTEM_OBJECT_ID: &str =
+    "0x6c2547cbbc38025cf3adac45f63cb0a8d12ecf777cdc75a4971612bf97fdf6af";
+
+/// Content type the aggregator serves blobs without a stored content type as.
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// Errors that can occur when interacting with the Walrus API. Converts into
+/// [anyhow::Error] via `?` like any other error.
 #[derive(Error, Debug)]
 pub enum WalrusError {
     /// Error reading file from disk
@@ -36,13 +52,24 @@ pub enum WalrusError {
         source: io::Error,
     },
 
-    /// Error serializing data to JSON
-    #[error("Failed to serialize data to JSON: {0}")]
+    /// Error serializing data to JSON or parsing verified bytes as JSON
+    #[error("Failed to (de)serialize JSON data: {0}")]
     SerializationError(#[from] serde_json::Error),
 
-    /// Error during HTTP request
-    #[error("HTTP request failed: {message}")]
-    RequestError {
+    /// The blob metadata cannot be encoded as blob attributes
+    #[error("Invalid blob metadata: {0}")]
+    InvalidMetadata(String),
+
+    /// The blob does not exist
+    #[error("Blob not found: {message}")]
+    NotFound {
+        /// Error message from API
+        message: String,
+    },
+
+    /// The request could not be sent or the response could not be received
+    #[error("{message}: {source}")]
+    Network {
         /// Error message
         message: String,
         /// The underlying reqwest error
@@ -50,28 +77,198 @@ pub enum WalrusError {
         source: reqwest::Error,
     },
 
-    /// Error from API response
-    #[error("API error: {status_code} - {message}")]
-    ApiError {
+    /// The request was rejected due to missing or invalid credentials
+    #[error("Unauthorized: {status} - {message}")]
+    Unauthorized {
         /// HTTP status code
-        status_code: u16,
+        status: u16,
         /// Error message from API
         message: String,
     },
 
-    /// Error processing stream data
-    #[error("Failed to process data stream: {0}")]
-    StreamError(#[from] reqwest::Error),
+    /// The request exceeds the storage quota or rate limit of the publisher
+    #[error("Quota exceeded: {status} - {message}")]
+    QuotaExceeded {
+        /// HTTP status code
+        status: u16,
+        /// Error message from API
+        message: String,
+    },
+
+    /// The response could not be decoded
+    #[error("{message}: {source}")]
+    Decode {
+        /// Error message
+        message: String,
+        /// The underlying reqwest error
+        #[source]
+        source: reqwest::Error,
+    },
+
+    /// The configured [BlobIdEncoder] failed to compute the blob ID
+    #[error("Failed to compute the blob ID of '{blob_id}': {source}")]
+    BlobIdEncoding {
+        /// The requested blob ID
+        blob_id: String,
+        /// The underlying encoder error
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+
+    /// Reads are verified by default but no [BlobIdEncoder] was set
+    #[error(
+        "A blob ID encoder is required to verify reads, set one with `with_blob_id_encoder` or opt out with `with_integrity_check(false)`"
+    )]
+    MissingBlobIdEncoder,
+
+    /// The downloaded bytes do not match the requested blob ID
+    #[error(
+        "Blob '{blob_id}' failed the integrity check, the downloaded bytes encode to '{computed}'"
+    )]
+    IntegrityMismatch {
+        /// The requested blob ID
+        blob_id: String,
+        /// The blob ID computed from the downloaded bytes
+        computed: String,
+    },
+
+    /// The Sui RPC returned an error or an unexpected response
+    #[error("Sui RPC error: {0}")]
+    SuiRpc(String),
+
+    /// The blob is too large to be encoded
+    #[error("Blob of {0} bytes is too large to be stored")]
+    BlobTooLarge(u64),
+
+    /// Any other error response from the API
+    #[error("API error: {status} - {message}")]
+    Server {
+        /// HTTP status code
+        status: u16,
+        /// Error message from API
+        message: String,
+    },
+}
+
+impl WalrusError {
+    /// Map an error response to the matching variant.
+    fn from_status(status: StatusCode, message: String) -> Self {
+        match status {
+            StatusCode::NOT_FOUND => WalrusError::NotFound { message },
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => WalrusError::Unauthorized {
+                status: status.as_u16(),
+                message,
+            },
+            StatusCode::PAYMENT_REQUIRED
+            | StatusCode::PAYLOAD_TOO_LARGE
+            | StatusCode::TOO_MANY_REQUESTS => WalrusError::QuotaExceeded {
+                status: status.as_u16(),
+                message,
+            },
+            _ => WalrusError::Server {
+                status: status.as_u16(),
+                message,
+            },
+        }
+    }
+
+    /// Map a failed request to [WalrusError::Decode] if the response could not
+    /// be decoded and to [WalrusError::Network] otherwise.
+    fn from_request(message: &str, source: reqwest::Error) -> Self {
+        let message = message.to_string();
+
+        match source.is_decode() {
+            true => WalrusError::Decode { message, source },
+            false => WalrusError::Network { message, source },
+        }
+    }
+
+    /// Returns the HTTP status code of the error response, if any.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            WalrusError::NotFound { .. } => Some(StatusCode::NOT_FOUND.as_u16()),
+            WalrusError::Unauthorized { status, .. }
+            | WalrusError::QuotaExceeded { status, .. }
+            | WalrusError::Server { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// Return the response if it is successful or the matching [WalrusError].
+async fn check_status(response: Response) -> Result<Response> {
+    let status = response.status();
+
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let message = response.text().await.unwrap_or_default();
+
+    Err(WalrusError::from_status(status, message))
+}
+
+/// Decode the metadata from the headers of an aggregator response to a read
+/// by object ID. Aggregators only return the attributes of the blob that are
+/// standard headers so tags are not included. Headers that are not valid UTF-8
+/// are ignored.
+fn metadata_from_headers(headers: &HeaderMap) -> BlobMetadata {
+    let attributes = [
+        (CONTENT_TYPE, CONTENT_TYPE_ATTRIBUTE),
+        (CONTENT_DISPOSITION, CONTENT_DISPOSITION_ATTRIBUTE),
+    ]
+    .into_iter()
+    .filter_map(|(header, attribute)| {
+        let value = headers.get(header)?.to_str().ok()?;
+
+        Some((attribute.to_string(), value.to_string()))
+    })
+    // Blobs without a content type attribute are served as octet streams.
+    .filter(|(attribute, value)| {
+        attribute != CONTENT_TYPE_ATTRIBUTE || !value.starts_with(DEFAULT_CONTENT_TYPE)
+    });
+
+    BlobMetadata::from_attributes(attributes)
 }
 
 /// Result type used throughout the Walrus client
 pub type Result<T> = std::result::Result<T, WalrusError>;
 
+/// Computes the blob ID of downloaded bytes so that reads can verify them
+/// against the requested blob ID.
+///
+/// Walrus derives blob IDs from the erasure coded slivers of a blob, which
+/// depends on the number of shards of the network. This crate does not bundle
+/// the encoder, an implementation is expected to wrap
+/// `walrus_core::encoding::EncodingConfig::compute_metadata`. Reads are
+/// verified by default so [WalrusClientBuilder::build] requires an encoder
+/// unless the check is disabled with
+/// [WalrusClientBuilder::with_integrity_check].
+///
+/// Implemented for closures taking the bytes and returning the blob ID.
+pub trait BlobIdEncoder: Send + Sync {
+    /// Returns the blob ID of the given bytes.
+    fn blob_id(&self, bytes: &[u8]) -> anyhow::Result<String>;
+}
+
+impl<F> BlobIdEncoder for F
+where
+    F: Fn(&[u8]) -> anyhow::Result<String> + Send + Sync,
+{
+    fn blob_id(&self, bytes: &[u8]) -> anyhow::Result<String> {
+        self(bytes)
+    }
+}
+
 /// Builder for WalrusClient configuration
 pub struct WalrusClientBuilder {
     client: Client,
     publisher_url: String,
     aggregator_url: String,
+    sui_rpc_url: String,
+    system_object_id: String,
+    blob_id_encoder: Option<Arc<dyn BlobIdEncoder>>,
+    integrity_check: bool,
 }
 
 impl Default for WalrusClientBuilder {
@@ -81,6 +278,10 @@ impl Default for WalrusClientBuilder {
             client: Client::new(),
             publisher_url: WALRUS_PUBLISHER_URL.to_string(),
             aggregator_url: WALRUS_AGGREGATOR_URL.to_string(),
+            sui_rpc_url: SUI_TESTNET_RPC_URL.to_string(),
+            system_object_id: WALRUS_SYSTEM_OBJECT_ID.to_string(),
+            blob_id_encoder: None,
+            integrity_check: true,
         }
     }
 }
@@ -109,13 +310,51 @@ impl WalrusClientBuilder {
         self
     }
 
+    /// Set a custom Sui RPC URL used to read the storage prices
+    pub fn with_sui_rpc_url(mut self, url: &str) -> Self {
+        self.sui_rpc_url = url.to_string();
+        self
+    }
+
+    /// Set the ID of the Walrus system object of the network
+    pub fn with_system_object_id(mut self, object_id: &str) -> Self {
+        self.system_object_id = object_id.to_string();
+        self
+    }
+
+    /// Set the encoder used to verify that the bytes returned by
+    /// [WalrusClient::read_file] and [WalrusClient::read_json] match the
+    /// requested blob ID
+    pub fn with_blob_id_encoder(mut self, encoder: impl BlobIdEncoder + 'static) -> Self {
+        self.blob_id_encoder = Some(Arc::new(encoder));
+        self
+    }
+
+    /// Enable or disable the integrity check of reads. Enabled by default,
+    /// disabling it skips encoding the downloaded bytes on
+    /// performance-critical paths and when no encoder is available
+    pub fn with_integrity_check(mut self, enabled: bool) -> Self {
+        self.integrity_check = enabled;
+        self
+    }
+
     /// Build the WalrusClient with the configured settings
-    pub fn build(self) -> WalrusClient {
-        WalrusClient {
+    ///
+    /// Fails with [WalrusError::MissingBlobIdEncoder] if the integrity check
+    /// is enabled but no [BlobIdEncoder] is set
+    pub fn build(self) -> Result<WalrusClient> {
+        if self.integrity_check && self.blob_id_encoder.is_none() {
+            return Err(WalrusError::MissingBlobIdEncoder);
+        }
+
+        Ok(WalrusClient {
             client: self.client,
             publisher_url: self.publisher_url,
             aggregator_url: self.aggregator_url,
-        }
+            sui_rpc_url: self.sui_rpc_url,
+            system_object_id: self.system_object_id,
+            blob_id_encoder: self.blob_id_encoder.filter(|_| self.integrity_check),
+        })
     }
 }
 
@@ -124,21 +363,13 @@ pub struct WalrusClient {
     client: Client,
     publisher_url: String,
     aggregator_url: String,
-}
-
-impl Default for WalrusClient {
-    /// Creates a default WalrusClient with standard configuration
-    fn default() -> Self {
-        WalrusClientBuilder::default().build()
-    }
+    sui_rpc_url: String,
+    system_object_id: String,
+    /// Unset only if the integrity check was disabled.
+    blob_id_encoder: Option<Arc<dyn BlobIdEncoder>>,
 }
 
 impl WalrusClient {
-    /// Create a new WalrusClient with default configuration
-    pub fn new() -> Self {
-        Self::default()
-    }
-
     /// Get a builder to create a customized WalrusClient
     pub fn builder() -> WalrusClientBuilder {
         WalrusClientBuilder::default()
@@ -181,28 +412,14 @@ impl WalrusClient {
             .body(file_content)
             .send()
             .await
-            .map_err(|e| WalrusError::RequestError {
-                message: "Failed to upload file".to_string(),
-                source: e,
-            })?;
+            .map_err(|e| WalrusError::from_request("Failed to upload file", e))?;
 
-        if !response.status().is_success() {
-            let status_code = response.status().as_u16();
-            let error_text = response.text().await.unwrap_or_default();
-            return Err(WalrusError::ApiError {
-                status_code,
-                message: error_text,
-            });
-        }
+        let response = check_status(response).await?;
 
-        let storage_info =
-            response
-                .json::<StorageInfo>()
-                .await
-                .map_err(|e| WalrusError::RequestError {
-                    message: "Failed to parse response".to_string(),
-                    source: e,
-                })?;
+        let storage_info = response
+            .json::<StorageInfo>()
+            .await
+            .map_err(|e| WalrusError::from_request("Failed to parse response", e))?;
 
         Ok(storage_info)
     }
@@ -235,38 +452,176 @@ impl WalrusClient {
         let response = self
             .client
             .put(&url)
-            .header("Content-Type", "application/json")
             .body(json_content)
             .send()
             .await
-            .map_err(|e| WalrusError::RequestError {
-                message: "Failed to upload JSON data".to_string(),
-                source: e,
-            })?;
+            .map_err(|e| WalrusError::from_request("Failed to upload JSON data", e))?;
 
-        if !response.status().is_success() {
-            let status_code = response.status().as_u16();
-            let error_text = response.text().await.unwrap_or_default();
-            return Err(WalrusError::ApiError {
-                status_code,
-                message: error_text,
-            });
+        let response = check_status(response).await?;
+
+        let storage_info = response
+            .json::<StorageInfo>()
+            .await
+            .map_err(|e| WalrusError::from_request("Failed to parse response", e))?;
+
+        Ok(storage_info)
+    }
+
+    /// Upload raw bytes to Walrus
+    ///
+    /// # Arguments
+    /// * `data` - Bytes to upload
+    /// * `epochs` - Number of epochs to store the data
+    /// * `send_to` - Optional address to which the created Blob object should be sent
+    ///
+    /// # Returns
+    /// * `Result<StorageInfo>` - Information about the uploaded data
+    pub async fn upload_bytes(
+        &self,
+        data: Vec<u8>,
+        epochs: u64,
+        send_to: Option<String>,
+    ) -> Result<StorageInfo> {
+        // Construct API URL with query parameters
+        let mut url = format!("{}/v1/blobs?epochs={}", self.publisher_url, epochs);
+        if let Some(address) = send_to {
+            url.push_str(&format!("&send_object_to={}", address));
         }
 
-        let storage_info =
-            response
-                .json::<StorageInfo>()
-                .await
-                .map_err(|e| WalrusError::RequestError {
-                    message: "Failed to parse response".to_string(),
-                    source: e,
-                })?;
+        // Send PUT request
+        let response = self
+            .client
+            .put(&url)
+            .body(data)
+            .send()
+            .await
+            .map_err(|e| WalrusError::from_request("Failed to upload data", e))?;
+
+        let response = check_status(response).await?;
+
+        let storage_info = response
+            .json::<StorageInfo>()
+            .await
+            .map_err(|e| WalrusError::from_request("Failed to parse response", e))?;
 
         Ok(storage_info)
     }
 
+    /// Fetch the storage prices of the current epoch from the Walrus system
+    /// object
+    ///
+    /// # Returns
+    /// * `Result<StoragePrices>` - The storage and write prices and the number of shards
+    pub async fn storage_prices(&self) -> Result<StoragePrices> {
+        let invalid = |field: &str| {
+            WalrusError::SuiRpc(format!("Walrus system object has no valid '{field}'"))
+        };
+
+        // The system state is a dynamic field of the system object keyed by
+        // the version of the system object.
+        let system = self
+            .sui_rpc(
+                "sui_getObject",
+                json!([self.system_object_id, { "showContent": true }]),
+            )
+            .await?;
+        let version = system
+            .pointer("/data/content/fields/version")
+            .cloned()
+            .ok_or_else(|| invalid("version"))?;
+
+        let state = self
+            .sui_rpc(
+                "suix_getDynamicFieldObject",
+                json!([self.system_object_id, { "type": "u64", "value": version }]),
+            )
+            .await?;
+        let fields = state
+            .pointer("/data/content/fields/value/fields")
+            .ok_or_else(|| invalid("system state"))?;
+
+        // `u64` values are returned as strings.
+        let number = |pointer: &str, field: &str| {
+            fields
+                .pointer(pointer)
+                .and_then(|value| match value {
+                    Value::String(value) => value.parse().ok(),
+                    value => value.as_u64(),
+                })
+                .ok_or_else(|| invalid(field))
+        };
+
+        Ok(StoragePrices {
+            storage_price_per_unit_size: number(
+                "/storage_price_per_unit_size",
+                "storage_price_per_unit_size",
+            )?,
+            write_price_per_unit_size: number(
+                "/write_price_per_unit_size",
+                "write_price_per_unit_size",
+            )?,
+            n_shards: number("/committee/fields/n_shards", "n_shards")?
+                .try_into()
+                .map_err(|_| invalid("n_shards"))?,
+        })
+    }
+
+    /// Estimate the cost of storing a blob from the storage prices of the
+    /// current epoch
+    ///
+    /// # Arguments
+    /// * `size_bytes` - Size of the blob in bytes
+    /// * `epochs` - Number of epochs to store the blob
+    ///
+    /// # Returns
+    /// * `Result<StorageCost>` - The encoded size and the estimated cost in FROST
+    pub async fn storage_cost(&self, size_bytes: u64, epochs: u32) -> Result<StorageCost> {
+        let prices = self.storage_prices().await?;
+
+        prices
+            .cost(size_bytes, epochs)
+            .ok_or(WalrusError::BlobTooLarge(size_bytes))
+    }
+
+    /// Call a Sui JSON-RPC method and return its result
+    async fn sui_rpc(&self, method: &str, params: Value) -> Result<Value> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let response = self
+            .client
+            .post(&self.sui_rpc_url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| WalrusError::from_request("Failed to call the Sui RPC", e))?;
+
+        let response = check_status(response).await?;
+
+        let mut response = response
+            .json::<Value>()
+            .await
+            .map_err(|e| WalrusError::from_request("Failed to parse the Sui RPC response", e))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(WalrusError::SuiRpc(error.to_string()));
+        }
+
+        response
+            .get_mut("result")
+            .map(Value::take)
+            .ok_or_else(|| WalrusError::SuiRpc(format!("No result returned by '{method}'")))
+    }
+
     /// Download a file from Walrus
     ///
+    /// The file is streamed to disk and is therefore not verified against the
+    /// blob ID, use [WalrusClient::read_file] to verify the contents.
+    ///
     /// # Arguments
     /// * `blob_id` - The blob ID of the file to download
     /// * `output` - Path where the downloaded file should be saved
@@ -275,24 +630,14 @@ impl WalrusClient {
         let url = format!("{}/v1/blobs/{}", self.aggregator_url, blob_id);
 
         // Send GET request
-        let response =
-            self.client
-                .get(&url)
-                .send()
-                .await
-                .map_err(|e| WalrusError::RequestError {
-                    message: "Failed to download blob".to_string(),
-                    source: e,
-                })?;
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| WalrusError::from_request("Failed to download blob", e))?;
 
-        if !response.status().is_success() {
-            let status_code = response.status().as_u16();
-            let error_text = response.text().await.unwrap_or_default();
-            return Err(WalrusError::ApiError {
-                status_code,
-                message: error_text,
-            });
-        }
+        let response = check_status(response).await?;
 
         // Stream the response body to file
         let mut file = File::create(output)
@@ -305,7 +650,8 @@ impl WalrusClient {
         let mut stream = response.bytes_stream();
 
         while let Some(chunk_result) = stream.next().await {
-            let chunk = chunk_result.map_err(WalrusError::StreamError)?;
+            let chunk = chunk_result
+                .map_err(|e| WalrusError::from_request("Failed to read response stream", e))?;
             file.write_all(&chunk)
                 .await
                 .map_err(|e| WalrusError::FileWriteError {
@@ -329,37 +675,67 @@ impl WalrusClient {
         let url = format!("{}/v1/blobs/{}", self.aggregator_url, blob_id);
 
         // Send GET request
-        let response =
-            self.client
-                .get(&url)
-                .send()
-                .await
-                .map_err(|e| WalrusError::RequestError {
-                    message: "Failed to download blob".to_string(),
-                    source: e,
-                })?;
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| WalrusError::from_request("Failed to download blob", e))?;
 
-        if !response.status().is_success() {
-            let status_code = response.status().as_u16();
-            let error_text = response.text().await.unwrap_or_default();
-            return Err(WalrusError::ApiError {
-                status_code,
-                message: error_text,
-            });
-        }
+        let response = check_status(response).await?;
 
         // Get the bytes directly from the response
         let bytes = response
             .bytes()
             .await
-            .map_err(|e| WalrusError::RequestError {
-                message: "Failed to read response bytes".to_string(),
-                source: e,
-            })?;
+            .map_err(|e| WalrusError::from_request("Failed to read response bytes", e))?;
+
+        self.verify_integrity(blob_id, &bytes)?;
 
         Ok(bytes.to_vec())
     }
 
+    /// Download a file from Walrus by the ID of its `Blob` object and return
+    /// its contents as bytes along with the metadata stored in the attributes
+    /// of the object
+    ///
+    /// The blob ID is not known up front so the contents are not verified
+    /// against it. Aggregators only return the content type and filename
+    /// attributes, tags are only available on-chain.
+    ///
+    /// # Arguments
+    /// * `blob_object_id` - The ID of the Sui object of the blob
+    ///
+    /// # Returns
+    /// * `Result<(Vec<u8>, BlobMetadata)>` - The file content as bytes and its metadata
+    pub async fn read_file_with_metadata(
+        &self,
+        blob_object_id: &str,
+    ) -> Result<(Vec<u8>, BlobMetadata)> {
+        let url = format!(
+            "{}/v1/blobs/by-object-id/{}",
+            self.aggregator_url, blob_object_id
+        );
+
+        // Send GET request
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| WalrusError::from_request("Failed to download blob", e))?;
+
+        let response = check_status(response).await?;
+        let metadata = metadata_from_headers(response.headers());
+
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| WalrusError::from_request("Failed to read response bytes", e))?;
+
+        Ok((bytes.to_vec(), metadata))
+    }
+
     /// Download and parse JSON data from Walrus
     ///
     /// # Arguments
@@ -375,35 +751,78 @@ impl WalrusClient {
         let url = format!("{}/v1/blobs/{}", self.aggregator_url, blob_id);
 
         // Send GET request
-        let response =
-            self.client
-                .get(&url)
-                .send()
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| WalrusError::from_request("Failed to download JSON blob", e))?;
+
+        let response = check_status(response).await?;
+
+        // Parse the JSON response, buffering it first if it has to be verified
+        let json_data = match self.blob_id_encoder {
+            Some(_) => {
+                let bytes = response
+                    .bytes()
+                    .await
+                    .map_err(|e| WalrusError::from_request("Failed to read response bytes", e))?;
+
+                self.verify_integrity(blob_id, &bytes)?;
+
+                serde_json::from_slice::<T>(&bytes)?
+            }
+            None => response
+                .json::<T>()
                 .await
-                .map_err(|e| WalrusError::RequestError {
-                    message: "Failed to download JSON blob".to_string(),
-                    source: e,
-                })?;
+                .map_err(|e| WalrusError::from_request("Failed to parse JSON data", e))?,
+        };
 
-        if !response.status().is_success() {
-            let status_code = response.status().as_u16();
-            let error_text = response.text().await.unwrap_or_default();
-            return Err(WalrusError::ApiError {
-                status_code,
-                message: error_text,
-            });
-        }
+        Ok(json_data)
+    }
+
+    /// Download and parse JSON data from Walrus by the ID of its `Blob`
+    /// object along with the metadata stored in the attributes of the object
+    ///
+    /// The blob ID is not known up front so the contents are not verified
+    /// against it. Aggregators only return the content type and filename
+    /// attributes, tags are only available on-chain.
+    ///
+    /// # Arguments
+    /// * `blob_object_id` - The ID of the Sui object of the blob
+    ///
+    /// # Returns
+    /// * `Result<(T, BlobMetadata)>` - The parsed JSON data and its metadata
+    ///
+    /// # Type Parameters
+    /// * `T` - The type to deserialize the JSON into, must implement DeserializeOwned
+    pub async fn read_json_with_metadata<T: DeserializeOwned>(
+        &self,
+        blob_object_id: &str,
+    ) -> Result<(T, BlobMetadata)> {
+        let url = format!(
+            "{}/v1/blobs/by-object-id/{}",
+            self.aggregator_url, blob_object_id
+        );
+
+        // Send GET request
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| WalrusError::from_request("Failed to download JSON blob", e))?;
+
+        let response = check_status(response).await?;
+        let metadata = metadata_from_headers(response.headers());
 
         // Parse the JSON response
         let json_data = response
             .json::<T>()
             .await
-            .map_err(|e| WalrusError::RequestError {
-                message: "Failed to parse JSON data".to_string(),
-                source: e,
-            })?;
+            .map_err(|e| WalrusError::from_request("Failed to parse JSON data", e))?;
 
-        Ok(json_data)
+        Ok((json_data, metadata))
     }
 
     /// Verify if a blob exists in the Walrus network
@@ -418,16 +837,69 @@ impl WalrusClient {
         let url = format!("{}/v1/blobs/{}", self.aggregator_url, blob_id);
 
         // Send HEAD request to check if blob exists
-        let response =
-            self.client
-                .head(&url)
-                .send()
-                .await
-                .map_err(|e| WalrusError::RequestError {
-                    message: "Failed to verify blob existence".to_string(),
-                    source: e,
-                })?;
+        let response = self
+            .client
+            .head(&url)
+            .send()
+            .await
+            .map_err(|e| WalrusError::from_request("Failed to verify blob existence", e))?;
 
         Ok(response.status().is_success())
     }
+
+    /// Check that the downloaded bytes encode to the requested blob ID unless
+    /// the integrity check is disabled.
+    fn verify_integrity(&self, blob_id: &str, bytes: &[u8]) -> Result<()> {
+        let Some(encoder) = &self.blob_id_encoder else {
+            return Ok(());
+        };
+
+        let computed = encoder
+            .blob_id(bytes)
+            .map_err(|e| WalrusError::BlobIdEncoding {
+                blob_id: blob_id.to_string(),
+                source: e.into(),
+            })?;
+
+        if computed != blob_id {
+            return Err(WalrusError::IntegrityMismatch {
+                blob_id: blob_id.to_string(),
+                computed,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Fetch the metadata stored in the attributes of a `Blob` object
+    /// without downloading the blob. Aggregators only return the content
+    /// type and filename attributes, tags are only available on-chain.
+    ///
+    /// # Arguments
+    /// * `blob_object_id` - The ID of the Sui object of the blob
+    ///
+    /// # Returns
+    /// * `Result<Option<BlobMetadata>>` - The metadata or `None` if the blob does not exist
+    pub async fn blob_metadata(&self, blob_object_id: &str) -> Result<Option<BlobMetadata>> {
+        let url = format!(
+            "{}/v1/blobs/by-object-id/{}",
+            self.aggregator_url, blob_object_id
+        );
+
+        // Send HEAD request to only fetch the headers
+        let response = self
+            .client
+            .head(&url)
+            .send()
+            .await
+            .map_err(|e| WalrusError::from_request("Failed to fetch blob metadata", e))?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let response = check_status(response).await?;
+
+        Ok(Some(metadata_from_headers(response.headers())))
+    }
 }
@@ -0,0 +1,135 @@
+//! Storage cost of Walrus blobs. Walrus charges for the erasure coded size of
+//! a blob, which is computed here the same way as in `walrus-core` for the
+//! RS2 encoding.
+
+/// Walrus charges for storage in units of 1 MiB of encoded size.
+pub const BYTES_PER_UNIT_SIZE: u64 = 1024 * 1024;
+
+/// Number of FROST in one WAL.
+pub const FROST_PER_WAL: u64 = 1_000_000_000;
+
+/// Length of the hashes and of the blob ID in the blob metadata.
+const DIGEST_LEN: u64 = 32;
+
+/// Symbols of the RS2 encoding are aligned to 2 bytes.
+const SYMBOL_ALIGNMENT: u64 = 2;
+
+/// Storage prices of the current Walrus epoch, read from the on-chain Walrus
+/// system object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StoragePrices {
+    /// Price in FROST of storing one unit of encoded size for one epoch
+    pub storage_price_per_unit_size: u64,
+    /// Price in FROST of writing one unit of encoded size, paid once
+    pub write_price_per_unit_size: u64,
+    /// Number of shards of the committee, which determines the encoded size
+    pub n_shards: u16,
+}
+
+/// Estimated cost of storing a blob
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StorageCost {
+    /// Size of the blob after erasure coding, in bytes
+    pub encoded_size: u64,
+    /// The cost in FROST
+    pub cost_frost: u64,
+}
+
+impl StoragePrices {
+    /// Cost of storing `size_bytes` for `epochs`, which is the storage price
+    /// for every epoch plus the write price of the encoded size. Returns
+    /// `None` if the blob is too large to be encoded.
+    pub fn cost(&self, size_bytes: u64, epochs: u32) -> Option<StorageCost> {
+        let encoded_size = encoded_blob_length(self.n_shards, size_bytes)?;
+        let units = encoded_size.div_ceil(BYTES_PER_UNIT_SIZE);
+
+        let storage = units
+            .checked_mul(self.storage_price_per_unit_size)?
+            .checked_mul(u64::from(epochs))?;
+        let write = units.checked_mul(self.write_price_per_unit_size)?;
+
+        Some(StorageCost {
+            encoded_size,
+            cost_frost: storage.checked_add(write)?,
+        })
+    }
+}
+
+/// Size in bytes of a blob of `size_bytes` after erasure coding for
+/// `n_shards`, including its metadata. Returns `None` if the blob is too large
+/// to be encoded or there are no shards.
+pub fn encoded_blob_length(n_shards: u16, size_bytes: u64) -> Option<u64> {
+    let (primary, secondary) = source_symbols(n_shards)?;
+    let n_shards = u64::from(n_shards);
+
+    let symbol_size = size_bytes
+        .max(1)
+        .div_ceil(primary * secondary)
+        .next_multiple_of(SYMBOL_ALIGNMENT);
+
+    if symbol_size > u64::from(u16::MAX) {
+        return None;
+    }
+
+    // Every shard stores a primary and a secondary sliver and a copy of the
+    // metadata, which holds two hashes per shard and the blob ID.
+    let slivers = n_shards * (primary + secondary) * symbol_size;
+    let metadata = n_shards * (n_shards * DIGEST_LEN * 2 + DIGEST_LEN);
+
+    Some(slivers + metadata)
+}
+
+/// Number of primary and secondary source symbols for `n_shards`.
+fn source_symbols(n_shards: u16) -> Option<(u64, u64)> {
+    let max_n_faulty = n_shards.checked_sub(1)? / 3;
+    let min_n_correct = n_shards - max_n_faulty;
+    // Keeps decoding possible with a few more faulty shards, at most 20% of
+    // the faulty shards up to 5.
+    let safety_limit = match n_shards {
+        0..=15 => 0,
+        16..=30 => 1,
+        31..=45 => 2,
+        46..=60 => 3,
+        61..=75 => 4,
+        _ => 5,
+    };
+
+    Some((
+        u64::from(min_n_correct - max_n_faulty - safety_limit),
+        u64::from(min_n_correct - safety_limit),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encoded_blob_length() {
+        // 1000 shards have 329 primary and 662 secondary source symbols.
+        assert_eq!(source_symbols(1000), Some((329, 662)));
+
+        // The smallest symbol is 2 bytes, plus 1000 copies of the metadata.
+        assert_eq!(
+            encoded_blob_length(1000, 1),
+            Some(1000 * 991 * 2 + 1000 * (1000 * 64 + 32))
+        );
+        assert_eq!(encoded_blob_length(0, 1), None);
+        assert_eq!(encoded_blob_length(1000, u64::MAX), None);
+    }
+
+    #[test]
+    fn test_cost() {
+        let prices = StoragePrices {
+            storage_price_per_unit_size: 100,
+            write_price_per_unit_size: 20,
+            n_shards: 1000,
+        };
+
+        let cost = prices.cost(1, 5).unwrap();
+
+        // 66,014,000 bytes of encoded size are charged as 63 units.
+        assert_eq!(cost.encoded_size, 66_014_000);
+        assert_eq!(cost.cost_frost, 63 * 100 * 5 + 63 * 20);
+    }
+}
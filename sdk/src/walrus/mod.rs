@@ -6,9 +6,12 @@
 //! - Downloading files from the Walrus network
 //! - Reading and parsing JSON data from the Walrus network
 //! - Verifying the existence of files in the Walrus network
+//! - Estimating the cost of storing a blob
+//! - Storing and reading metadata such as the content type alongside blobs
 
 mod client;
+mod cost;
 mod models;
 
 // Re-exports
-pub use {client::*, models::*};
+pub use {client::*, cost::*, models::*};
@@ -0,0 +1,158 @@
+use {
+    crate::walrus::WalrusError,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+    std::collections::BTreeMap,
+};
+
+/// Maximum number of tags that can be attached to a blob.
+pub const MAX_BLOB_TAGS: usize = 16;
+
+/// Attribute holding the content type of a blob. Aggregators return it as the
+/// `Content-Type` header when reading the blob by its object ID.
+pub const CONTENT_TYPE_ATTRIBUTE: &str = "content-type";
+
+/// Attribute holding the filename of a blob as a `Content-Disposition` value.
+/// Aggregators return it as the `Content-Disposition` header when reading the
+/// blob by its object ID.
+pub const CONTENT_DISPOSITION_ATTRIBUTE: &str = "content-disposition";
+
+/// Metadata of a blob, such as its content type or the name of the file it
+/// was uploaded from.
+///
+/// The metadata is stored as attributes of the on-chain `Blob` object, see
+/// [BlobMetadata::to_attributes]. Only the owner of the object can set them
+/// so the metadata is not part of the upload.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BlobMetadata {
+    /// MIME type of the blob, for example `application/json`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// Original name of the uploaded file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    /// Arbitrary key/value tags. Keys may only contain lowercase
+    /// alphanumerics, `-` and `_` and must not be one of the reserved
+    /// attribute keys.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tags: BTreeMap<String, String>,
+}
+
+impl BlobMetadata {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    pub fn with_filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    pub fn with_tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.tags.insert(key.into(), value.into());
+        self
+    }
+
+    /// Whether the content type is `application/json` or a `+json` type,
+    /// ignoring parameters such as `charset`. Returns `false` if no content
+    /// type is stored.
+    pub fn is_json(&self) -> bool {
+        let Some(content_type) = &self.content_type else {
+            return false;
+        };
+
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+
+        mime == "application/json" || (mime.starts_with("application/") && mime.ends_with("+json"))
+    }
+
+    /// Encode the metadata as the key/value attributes of a `Blob` object.
+    /// The content type and filename are stored under
+    /// [CONTENT_TYPE_ATTRIBUTE] and [CONTENT_DISPOSITION_ATTRIBUTE] and each
+    /// tag under its own key.
+    pub fn to_attributes(&self) -> Result<BTreeMap<String, String>, WalrusError> {
+        if self.tags.len() > MAX_BLOB_TAGS {
+            return Err(WalrusError::InvalidMetadata(format!(
+                "At most {MAX_BLOB_TAGS} tags are allowed, got {}",
+                self.tags.len()
+            )));
+        }
+
+        let mut attributes = BTreeMap::new();
+
+        for (key, value) in &self.tags {
+            let valid_key = !key.is_empty()
+                && key.chars().all(|c| {
+                    c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_')
+                });
+
+            if !valid_key {
+                return Err(WalrusError::InvalidMetadata(format!(
+                    "Tag key '{key}' may only contain lowercase alphanumerics, '-' and '_'"
+                )));
+            }
+
+            if key == CONTENT_TYPE_ATTRIBUTE || key == CONTENT_DISPOSITION_ATTRIBUTE {
+                return Err(WalrusError::InvalidMetadata(format!(
+                    "Tag key '{key}' is reserved"
+                )));
+            }
+
+            attributes.insert(key.clone(), value.clone());
+        }
+
+        if let Some(content_type) = &self.content_type {
+            attributes.insert(CONTENT_TYPE_ATTRIBUTE.to_string(), content_type.clone());
+        }
+
+        if let Some(filename) = &self.filename {
+            if filename.contains(['"', '\\']) {
+                return Err(WalrusError::InvalidMetadata(format!(
+                    "Filename '{filename}' may not contain quotes or backslashes"
+                )));
+            }
+
+            attributes.insert(
+                CONTENT_DISPOSITION_ATTRIBUTE.to_string(),
+                format!("attachment; filename=\"{filename}\""),
+            );
+        }
+
+        Ok(attributes)
+    }
+
+    /// Decode the key/value attributes of a `Blob` object. Keys other than
+    /// [CONTENT_TYPE_ATTRIBUTE] and [CONTENT_DISPOSITION_ATTRIBUTE] become
+    /// tags.
+    pub fn from_attributes(attributes: impl IntoIterator<Item = (String, String)>) -> Self {
+        let mut metadata = Self::default();
+
+        for (key, value) in attributes {
+            match key.as_str() {
+                CONTENT_TYPE_ATTRIBUTE => metadata.content_type = Some(value),
+                CONTENT_DISPOSITION_ATTRIBUTE => {
+                    metadata.filename = value
+                        .split(';')
+                        .find_map(|param| param.trim().strip_prefix("filename="))
+                        .map(|filename| filename.trim_matches('"').to_string());
+                }
+                _ => {
+                    metadata.tags.insert(key, value);
+                }
+            }
+        }
+
+        metadata
+    }
+}
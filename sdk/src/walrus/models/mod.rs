@@ -1,7 +1,8 @@
 // Re-exporting models for easier access
 pub mod blob;
+pub mod metadata;
 pub mod storage;
 pub mod sui;
 
 // Public exports
-pub use {blob::*, storage::*, sui::*};
+pub use {blob::*, metadata::*, storage::*, sui::*};
@@ -4,6 +4,7 @@ use {
     assert_matches::assert_matches,
     nexus_sdk::{object_crawler::*, sui, test_utils},
     serde::{Deserialize, Serialize},
+    std::time::Duration,
 };
 
 #[derive(Clone, Debug, Deserialize)]
@@ -86,16 +87,19 @@ async fn test_object_crawler() {
         .active_address()
         .expect("Failed to get active address.");
 
-    test_utils::faucet::request_tokens(&format!("http://127.0.0.1:{faucet_port}/gas"), addr)
-        .await
-        .expect("Failed to request tokens from faucet.");
-
-    let gas_coin = test_utils::gas::fetch_gas_coins(&sui, addr)
-        .await
-        .expect("Failed to fetch gas coin.")
-        .into_iter()
-        .next()
-        .unwrap();
+    // Publishing the package costs at most 1 SUI.
+    let gas_coin = test_utils::faucet::fund_and_wait(
+        &sui,
+        &format!("http://127.0.0.1:{faucet_port}/gas"),
+        addr,
+        sui::MIST_PER_SUI,
+        Duration::from_secs(30),
+    )
+    .await
+    .expect("Failed to fund the wallet.")
+    .into_iter()
+    .max_by_key(|coin| coin.balance)
+    .unwrap();
 
     // Publish test contract and fetch some IDs.
     let response = test_utils::contracts::publish_move_package(
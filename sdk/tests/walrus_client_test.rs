@@ -3,7 +3,15 @@
 use {
     anyhow::Result,
     mockito::{Server, ServerGuard},
-    nexus_sdk::walrus::{BlobObject, BlobStorage, NewlyCreated, StorageInfo, WalrusClient},
+    nexus_sdk::walrus::{
+        BlobMetadata,
+        BlobObject,
+        BlobStorage,
+        NewlyCreated,
+        StorageInfo,
+        WalrusClient,
+        WalrusError,
+    },
     serde::{Deserialize, Serialize},
     std::path::PathBuf,
     tempfile::tempdir,
@@ -33,7 +41,11 @@ async fn setup_mock_server() -> Result<(ServerGuard, WalrusClient)> {
         .with_client(client)
         .with_publisher_url(&server_url)
         .with_aggregator_url(&server_url)
-        .build();
+        .with_sui_rpc_url(&server_url)
+        .with_system_object_id("0x5")
+        // The mocked blob IDs are not derived from their contents.
+        .with_integrity_check(false)
+        .build()?;
 
     Ok((server, walrus_client))
 }
@@ -143,6 +155,108 @@ async fn test_upload_json() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_upload_bytes() -> Result<()> {
+    let (mut server, client) = setup_mock_server().await?;
+
+    // Setup mock response
+    let mock_response = StorageInfo {
+        newly_created: Some(NewlyCreated {
+            blob_object: BlobObject {
+                blob_id: "bytes_blob_id".to_string(),
+                id: "bytes_object_id".to_string(),
+                storage: BlobStorage { end_epoch: 300 },
+            },
+        }),
+        already_certified: None,
+    };
+
+    let mock = server
+        .mock(
+            "PUT",
+            mockito::Matcher::Regex(format!("/v1/blobs\\?epochs={}", EPOCHS)),
+        )
+        .match_body("Hello, World!")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&mock_response)?)
+        .create_async()
+        .await;
+
+    // Test upload_bytes
+    let storage_info = client
+        .upload_bytes(TEST_CONTENT.to_vec(), EPOCHS, None)
+        .await?;
+
+    // Verify response
+    let blob_object = storage_info.newly_created.unwrap().blob_object;
+    assert_eq!(blob_object.blob_id, "bytes_blob_id");
+    assert_eq!(blob_object.storage.end_epoch, 300);
+
+    // Verify the request was made
+    mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_storage_cost() -> Result<()> {
+    let (mut server, client) = setup_mock_server().await?;
+
+    let object_mock = server
+        .mock("POST", "/")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::PartialJson(serde_json::json!({ "method": "sui_getObject" })),
+            mockito::Matcher::Regex(r#""params":\["0x5","#.to_string()),
+        ]))
+        .with_status(200)
+        .with_body(
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": { "data": { "content": { "fields": { "version": "1" } } } },
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let state_mock = server
+        .mock("POST", "/")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::PartialJson(serde_json::json!({
+                "method": "suix_getDynamicFieldObject"
+            })),
+            // The system state is keyed by the version of the system object.
+            mockito::Matcher::Regex(r#"\{"type":"u64","value":"1"\}"#.to_string()),
+        ]))
+        .with_status(200)
+        .with_body(
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": { "data": { "content": { "fields": { "value": { "fields": {
+                    "committee": { "fields": { "n_shards": 1000 } },
+                    "storage_price_per_unit_size": "100",
+                    "write_price_per_unit_size": "20",
+                } } } } } },
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let storage_cost = client.storage_cost(1024, 5).await?;
+
+    // A small blob is encoded to 63 units of 1 MiB, the write price is only
+    // paid once.
+    assert_eq!(storage_cost.cost_frost, 63 * 100 * 5 + 63 * 20);
+
+    object_mock.assert_async().await;
+    state_mock.assert_async().await;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_download_file() -> Result<()> {
     let (mut server, client) = setup_mock_server().await?;
@@ -280,10 +394,263 @@ async fn test_error_handling() -> Result<()> {
 
     // Test error handling
     let result = client.read_json::<SampleData>("error_blob_id").await;
-    assert!(result.is_err());
+    assert!(matches!(
+        result,
+        Err(WalrusError::Server { status: 500, .. })
+    ));
 
     // Verify the request was made
     mock_error.assert_async().await;
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_error_not_found() -> Result<()> {
+    let (mut server, client) = setup_mock_server().await?;
+
+    let mock = server
+        .mock("GET", "/v1/blobs/missing_blob_id")
+        .with_status(404)
+        .with_body("Blob not found")
+        .create_async()
+        .await;
+
+    let err = client.read_file("missing_blob_id").await.unwrap_err();
+
+    assert!(matches!(err, WalrusError::NotFound { .. }));
+    assert_eq!(err.status_code(), Some(404));
+
+    mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_error_quota_and_unauthorized() -> Result<()> {
+    let (mut server, client) = setup_mock_server().await?;
+
+    let _quota = server
+        .mock("GET", "/v1/blobs/quota_blob_id")
+        .with_status(429)
+        .create_async()
+        .await;
+    let _unauthorized = server
+        .mock("GET", "/v1/blobs/private_blob_id")
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let result = client.read_file("quota_blob_id").await;
+    assert!(matches!(
+        result,
+        Err(WalrusError::QuotaExceeded { status: 429, .. })
+    ));
+
+    let result = client.read_file("private_blob_id").await;
+    assert!(matches!(
+        result,
+        Err(WalrusError::Unauthorized { status: 401, .. })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_error_network() -> Result<()> {
+    // Nothing listens on port 1 so the connection is refused.
+    let client = WalrusClient::builder()
+        .with_aggregator_url("http://127.0.0.1:1")
+        .with_integrity_check(false)
+        .build()?;
+
+    let err = client.read_file("test_blob_id").await.unwrap_err();
+
+    assert!(matches!(err, WalrusError::Network { .. }));
+    assert_eq!(err.status_code(), None);
+
+    // Converts into anyhow errors.
+    let err: anyhow::Error = err.into();
+    assert!(err.downcast_ref::<WalrusError>().is_some());
+
+    Ok(())
+}
+
+#[test]
+fn test_metadata_attributes() -> Result<()> {
+    let metadata = BlobMetadata::new()
+        .with_content_type("text/plain")
+        .with_filename("test_file.txt")
+        .with_tag("owner", "nexus")
+        .with_tag("version", "1");
+
+    let attributes = metadata.to_attributes()?;
+
+    assert_eq!(
+        attributes.get("content-type").map(String::as_str),
+        Some("text/plain")
+    );
+    assert_eq!(
+        attributes.get("content-disposition").map(String::as_str),
+        Some(r#"attachment; filename="test_file.txt""#)
+    );
+    assert_eq!(attributes.get("owner").map(String::as_str), Some("nexus"));
+    assert_eq!(BlobMetadata::from_attributes(attributes), metadata);
+
+    // Tag keys must be lowercase and must not shadow the reserved attributes.
+    for key in ["Owner", "content-type"] {
+        assert!(matches!(
+            BlobMetadata::new().with_tag(key, "nexus").to_attributes(),
+            Err(WalrusError::InvalidMetadata(_))
+        ));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_read_with_metadata() -> Result<()> {
+    let (mut server, client) = setup_mock_server().await?;
+
+    // Aggregators return the attributes of the blob object as headers.
+    let read_mock = server
+        .mock("GET", "/v1/blobs/by-object-id/0x1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("content-disposition", r#"attachment; filename="data.json""#)
+        .with_body(r#"{"name":"Test","value":1}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let head_mock = server
+        .mock("HEAD", "/v1/blobs/by-object-id/0x1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .create_async()
+        .await;
+
+    let (content, metadata) = client.read_file_with_metadata("0x1").await?;
+
+    assert_eq!(content, br#"{"name":"Test","value":1}"#);
+    assert_eq!(
+        metadata,
+        BlobMetadata::new()
+            .with_content_type("application/json")
+            .with_filename("data.json")
+    );
+
+    let (data, metadata) = client.read_json_with_metadata::<SampleData>("0x1").await?;
+
+    assert_eq!(data.value, 1);
+    assert!(metadata.is_json());
+
+    let status_metadata = client.blob_metadata("0x1").await?.unwrap();
+
+    assert!(status_metadata.is_json());
+
+    read_mock.assert_async().await;
+    head_mock.assert_async().await;
+
+    // Blobs without a content type attribute are served as octet streams.
+    let _untyped_mock = server
+        .mock("GET", "/v1/blobs/by-object-id/0x2")
+        .with_status(200)
+        .with_header("content-type", "application/octet-stream")
+        .with_body(TEST_CONTENT)
+        .create_async()
+        .await;
+    let _missing_mock = server
+        .mock("HEAD", "/v1/blobs/by-object-id/0x3")
+        .with_status(404)
+        .create_async()
+        .await;
+
+    let (_, metadata) = client.read_file_with_metadata("0x2").await?;
+
+    assert_eq!(metadata, BlobMetadata::default());
+    assert!(client.blob_metadata("0x3").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_integrity_check() -> Result<()> {
+    let mut server = Server::new_async().await;
+
+    // Stand-in for the RedStuff encoder, the blob ID is the hex of the bytes.
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    let encoder = |bytes: &[u8]| -> Result<String> { Ok(hex(bytes)) };
+    let stored = br#"{"name":"Test","value":1}"#;
+    let blob_id = hex(stored);
+
+    let _valid_mock = server
+        .mock("GET", format!("/v1/blobs/{blob_id}").as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(stored)
+        .create_async()
+        .await;
+    let _tampered_mock = server
+        .mock("GET", "/v1/blobs/tampered")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(stored)
+        .create_async()
+        .await;
+
+    let client = WalrusClient::builder()
+        .with_aggregator_url(&server.url())
+        .with_blob_id_encoder(encoder)
+        .build()?;
+
+    let data: SampleData = client.read_json(&blob_id).await?;
+
+    assert_eq!(data.value, 1);
+    assert_eq!(client.read_file(&blob_id).await?, stored.to_vec());
+
+    let result = client.read_json::<SampleData>("tampered").await;
+
+    assert!(matches!(
+        result,
+        Err(WalrusError::IntegrityMismatch { ref blob_id, ref computed })
+            if blob_id == "tampered" && computed == &hex(stored)
+    ));
+    assert!(matches!(
+        client.read_file("tampered").await,
+        Err(WalrusError::IntegrityMismatch { .. })
+    ));
+
+    // Encoder failures are reported instead of skipping the check.
+    let client = WalrusClient::builder()
+        .with_aggregator_url(&server.url())
+        .with_blob_id_encoder(|_: &[u8]| -> Result<String> { anyhow::bail!("no shards") })
+        .build()?;
+
+    assert!(matches!(
+        client.read_file(&blob_id).await,
+        Err(WalrusError::BlobIdEncoding { .. })
+    ));
+
+    // Reads are verified by default so an encoder is required.
+    assert!(matches!(
+        WalrusClient::builder()
+            .with_aggregator_url(&server.url())
+            .build(),
+        Err(WalrusError::MissingBlobIdEncoder)
+    ));
+
+    // The check can be disabled on performance-critical paths.
+    let client = WalrusClient::builder()
+        .with_aggregator_url(&server.url())
+        .with_blob_id_encoder(encoder)
+        .with_integrity_check(false)
+        .build()?;
+
+    assert_eq!(client.read_file("tampered").await?, stored.to_vec());
+    assert_eq!(client.read_json::<SampleData>("tampered").await?.value, 1);
+
+    Ok(())
+}
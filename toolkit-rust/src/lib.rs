@@ -6,7 +6,11 @@
 //!
 //! See more documentation at <https://github.com/Talus-Network/gitbook-docs/blob/production/nexus-sdk/toolkit-rust.md>
 
+mod logging;
+mod middleware;
 mod nexus_tool;
+#[cfg(feature = "spec")]
+mod openapi;
 mod runtime;
 mod secret;
 mod serde_tracked;
@@ -15,8 +19,29 @@ pub use {
     anyhow::Result as AnyResult,
     env_logger,
     log::debug,
-    nexus_tool::NexusTool,
-    runtime::routes_for_,
+    logging::init_logger_,
+    middleware::{
+        CircuitBreaker,
+        CircuitState,
+        CircuitStats,
+        Middleware,
+        MiddlewareStack,
+        RateLimiter,
+    },
+    nexus_tool::{NexusTool, ToolValidationError},
+    runtime::{
+        batch_route_,
+        current_request_id,
+        invoke_by_fqn_route_,
+        invoker_for_,
+        metrics_route_,
+        openapi_route_,
+        routes_for_,
+        serve_until_drained_,
+        shutdown_signal,
+        shutdown_signals_,
+        REQUEST_ID_HEADER,
+    },
     secret::{BestEncryptionEver, EncryptionStrategy, Secret},
     serde_tracked::*,
     warp::{self, http::StatusCode},
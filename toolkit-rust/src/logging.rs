@@ -0,0 +1,106 @@
+//! Logging setup of the toolkit. Logs are human-readable by default and can be
+//! switched to one JSON object per line by setting the [LOG_FORMAT_ENV]
+//! environment variable to `json`.
+
+use {crate::current_request_id, serde_json::json, std::io::Write};
+
+/// Environment variable that selects the log format.
+const LOG_FORMAT_ENV: &str = "NEXUS_LOG_FORMAT";
+
+tokio::task_local! {
+    pub(crate) static TOOL_FQN: String;
+}
+
+/// Initialize the logger in the format selected by [LOG_FORMAT_ENV]. Does
+/// nothing if a logger is already set.
+///
+/// **This is an internal function used by [crate::bootstrap!] macro and
+/// should not be used directly.**
+#[doc(hidden)]
+pub fn init_logger_() {
+    let json =
+        std::env::var(LOG_FORMAT_ENV).is_ok_and(|format| format.eq_ignore_ascii_case("json"));
+
+    let mut builder = env_logger::Builder::from_default_env();
+
+    if json {
+        builder.format(|buf, record| {
+            let timestamp = buf.timestamp_millis().to_string();
+
+            writeln!(buf, "{}", json_log_line(&timestamp, record))
+        });
+    }
+
+    let _ = builder.try_init();
+}
+
+/// Format the record as a JSON object. The request ID and the FQN of the tool
+/// are included when logged during an invocation.
+fn json_log_line(timestamp: &str, record: &log::Record) -> serde_json::Value {
+    let mut line = json!({
+        "timestamp": timestamp,
+        "level": record.level().as_str(),
+        "target": record.target(),
+        "message": record.args().to_string(),
+        "request_id": current_request_id(),
+    });
+
+    if let Ok(fqn) = TOOL_FQN.try_with(|fqn| fqn.clone()) {
+        line["fqn"] = json!(fqn);
+    }
+
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::runtime::REQUEST_ID};
+
+    fn log_line(record: &log::Record) -> serde_json::Value {
+        // Round trip through a string to assert that the line is valid JSON.
+        serde_json::from_str(&json_log_line("2025-01-01T00:00:00.000Z", record).to_string())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_json_log_line() {
+        let line = log_line(
+            &log::Record::builder()
+                .level(log::Level::Info)
+                .target("nexus_toolkit")
+                .args(format_args!("Listening on {}", 8080))
+                .build(),
+        );
+
+        assert_eq!(
+            line,
+            json!({
+                "timestamp": "2025-01-01T00:00:00.000Z",
+                "level": "INFO",
+                "target": "nexus_toolkit",
+                "message": "Listening on 8080",
+                "request_id": null,
+            })
+        );
+
+        let line = REQUEST_ID
+            .scope(
+                "request-id".to_string(),
+                TOOL_FQN.scope("xyz.dummy.tool@1".to_string(), async {
+                    log_line(
+                        &log::Record::builder()
+                            .level(log::Level::Error)
+                            .target("nexus_toolkit")
+                            .args(format_args!("Tool failed"))
+                            .build(),
+                    )
+                }),
+            )
+            .await;
+
+        assert_eq!(line["level"], "ERROR");
+        assert_eq!(line["message"], "Tool failed");
+        assert_eq!(line["request_id"], "request-id");
+        assert_eq!(line["fqn"], "xyz.dummy.tool@1");
+    }
+}
@@ -0,0 +1,268 @@
+//! [CircuitBreaker] stops invoking a tool whose dependencies keep failing.
+
+use {
+    crate::{Middleware, NexusTool},
+    serde::Serialize,
+    std::{
+        future::Future,
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    },
+    warp::http::StatusCode,
+};
+
+/// State of a [CircuitBreaker].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Invocations pass through.
+    Closed,
+    /// Invocations are rejected until the timeout elapses.
+    Open,
+    /// One trial invocation is let through to decide whether to close or
+    /// re-open the circuit.
+    HalfOpen,
+}
+
+/// Statistics of a [CircuitBreaker].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CircuitStats {
+    pub state: CircuitState,
+    /// How many times the circuit opened.
+    pub trips: u64,
+    /// How many times the circuit closed after a successful trial.
+    pub resets: u64,
+    /// Number of consecutive failed invocations.
+    pub consecutive_failures: u32,
+}
+
+/// [Middleware] that opens the circuit after `failure_threshold` consecutive
+/// failed invocations. While open, invocations are rejected with
+/// [StatusCode::SERVICE_UNAVAILABLE]. After `timeout`, one trial invocation is
+/// let through which closes the circuit on success and re-opens it on failure.
+///
+/// An invocation fails if the tool returns its `err` output variant.
+#[derive(Clone)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    timeout: Duration,
+    circuit: Arc<Mutex<Circuit>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    Closed,
+    Open { opened_at: Instant },
+    HalfOpen { trial_started_at: Instant },
+}
+
+#[derive(Debug)]
+struct Circuit {
+    state: State,
+    consecutive_failures: u32,
+    trips: u64,
+    resets: u64,
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: u32, timeout: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            timeout,
+            circuit: Arc::new(Mutex::new(Circuit {
+                state: State::Closed,
+                consecutive_failures: 0,
+                trips: 0,
+                resets: 0,
+            })),
+        }
+    }
+
+    /// Returns the current statistics of the circuit.
+    pub fn stats(&self) -> CircuitStats {
+        let circuit = self.lock();
+
+        CircuitStats {
+            state: match circuit.state {
+                State::Closed => CircuitState::Closed,
+                State::Open { .. } => CircuitState::Open,
+                State::HalfOpen { .. } => CircuitState::HalfOpen,
+            },
+            trips: circuit.trips,
+            resets: circuit.resets,
+            consecutive_failures: circuit.consecutive_failures,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Circuit> {
+        // The circuit is always left in a valid state so a poisoned lock can be
+        // recovered.
+        self.circuit
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Returns whether an invocation is allowed to pass.
+    fn try_pass(&self, now: Instant) -> bool {
+        let mut circuit = self.lock();
+
+        match circuit.state {
+            State::Closed => true,
+            State::Open { opened_at }
+            | State::HalfOpen {
+                trial_started_at: opened_at,
+            } if now.saturating_duration_since(opened_at) >= self.timeout => {
+                // Let a trial through. A trial that never reports back is
+                // replaced once the timeout elapses again.
+                circuit.state = State::HalfOpen {
+                    trial_started_at: now,
+                };
+
+                true
+            }
+            State::Open { .. } | State::HalfOpen { .. } => false,
+        }
+    }
+
+    /// Record the outcome of an invocation that was allowed to pass.
+    fn record(&self, failed: bool, now: Instant) {
+        let mut circuit = self.lock();
+
+        match (circuit.state, failed) {
+            (State::Closed, false) => circuit.consecutive_failures = 0,
+            (State::Closed, true) => {
+                circuit.consecutive_failures += 1;
+
+                if circuit.consecutive_failures >= self.failure_threshold {
+                    circuit.state = State::Open { opened_at: now };
+                    circuit.trips += 1;
+                }
+            }
+            (State::HalfOpen { .. }, false) => {
+                circuit.state = State::Closed;
+                circuit.consecutive_failures = 0;
+                circuit.resets += 1;
+            }
+            (State::HalfOpen { .. }, true) => {
+                circuit.state = State::Open { opened_at: now };
+                circuit.consecutive_failures += 1;
+                circuit.trips += 1;
+            }
+            // Invocations that started before the circuit opened do not
+            // affect it.
+            (State::Open { .. }, _) => (),
+        }
+    }
+}
+
+impl<T: NexusTool> Middleware<T> for CircuitBreaker {
+    fn before(&self, _: &T::Input) -> impl Future<Output = Result<(), StatusCode>> + Send {
+        async move {
+            match self.try_pass(Instant::now()) {
+                true => Ok(()),
+                false => Err(StatusCode::SERVICE_UNAVAILABLE),
+            }
+        }
+    }
+
+    fn after(&self, _: &T::Input, output: &T::Output) -> impl Future<Output = ()> + Send {
+        let failed = is_err_variant(output);
+
+        async move { self.record(failed, Instant::now()) }
+    }
+}
+
+/// Whether the output is the `err` variant of the tool output enum.
+pub(crate) fn is_err_variant<O: Serialize>(output: &O) -> bool {
+    match serde_json::to_value(output) {
+        Ok(serde_json::Value::Object(output)) => output
+            .keys()
+            .any(|variant| variant.eq_ignore_ascii_case("err")),
+        Ok(serde_json::Value::String(variant)) => variant.eq_ignore_ascii_case("err"),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::json};
+
+    #[test]
+    fn test_circuit_breaker_trips_and_resets() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(10));
+        let now = Instant::now();
+
+        assert!(breaker.try_pass(now));
+        breaker.record(true, now);
+        // Success resets the consecutive failures.
+        breaker.record(false, now);
+        breaker.record(true, now);
+
+        assert_eq!(breaker.stats().state, CircuitState::Closed);
+        assert_eq!(breaker.stats().consecutive_failures, 1);
+
+        breaker.record(true, now);
+
+        assert_eq!(
+            breaker.stats(),
+            CircuitStats {
+                state: CircuitState::Open,
+                trips: 1,
+                resets: 0,
+                consecutive_failures: 2,
+            }
+        );
+        assert!(!breaker.try_pass(now + Duration::from_secs(9)));
+
+        // One trial is let through after the timeout.
+        let later = now + Duration::from_secs(10);
+
+        assert!(breaker.try_pass(later));
+        assert_eq!(breaker.stats().state, CircuitState::HalfOpen);
+        assert!(!breaker.try_pass(later));
+
+        breaker.record(false, later);
+
+        assert_eq!(
+            breaker.stats(),
+            CircuitStats {
+                state: CircuitState::Closed,
+                trips: 1,
+                resets: 1,
+                consecutive_failures: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_circuit_breaker_failed_trial_reopens() {
+        let breaker = CircuitBreaker::new(1, Duration::from_secs(10));
+        let now = Instant::now();
+
+        breaker.record(true, now);
+
+        let later = now + Duration::from_secs(10);
+
+        assert!(breaker.try_pass(later));
+
+        breaker.record(true, later);
+
+        assert_eq!(breaker.stats().state, CircuitState::Open);
+        assert_eq!(breaker.stats().trips, 2);
+        assert!(!breaker.try_pass(later + Duration::from_secs(5)));
+
+        // A trial that never reports back is replaced after the timeout.
+        let much_later = later + Duration::from_secs(10);
+
+        assert!(breaker.try_pass(much_later));
+        assert!(breaker.try_pass(much_later + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn test_is_err_variant() {
+        assert!(is_err_variant(&json!({ "err": { "reason": "down" } })));
+        assert!(is_err_variant(&json!({ "Err": { "reason": "down" } })));
+        assert!(!is_err_variant(&json!({ "ok": { "message": "up" } })));
+        assert!(!is_err_variant(&json!("ok")));
+    }
+}
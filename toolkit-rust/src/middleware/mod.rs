@@ -0,0 +1,165 @@
+//! Middleware runs before and after each invocation of a [NexusTool] so that
+//! cross-cutting concerns like authentication, logging or metrics can be
+//! added without modifying [NexusTool::invoke].
+//!
+//! # Example
+//!
+//! ```ignore
+//! use nexus_toolkit::{Middleware, MiddlewareStack, NexusTool, StatusCode};
+//!
+//! struct RejectEmptyPrompt;
+//!
+//! impl Middleware<MyTool> for RejectEmptyPrompt {
+//!     async fn before(&self, input: &Input) -> Result<(), StatusCode> {
+//!         match input.prompt.is_empty() {
+//!             true => Err(StatusCode::BAD_REQUEST),
+//!             false => Ok(()),
+//!         }
+//!     }
+//! }
+//!
+//! impl NexusTool for MyTool {
+//!     // ...
+//!
+//!     fn middleware() -> MiddlewareStack<Self> {
+//!         MiddlewareStack::new().with_middleware(RejectEmptyPrompt)
+//!     }
+//! }
+//! ```
+
+mod circuit_breaker;
+mod rate_limit;
+
+#[cfg(feature = "metrics")]
+pub(crate) use circuit_breaker::is_err_variant;
+use {
+    crate::NexusTool,
+    std::{future::Future, pin::Pin, sync::Arc},
+    warp::http::StatusCode,
+};
+pub use {
+    circuit_breaker::{CircuitBreaker, CircuitState, CircuitStats},
+    rate_limit::RateLimiter,
+};
+
+/// Hooks that run around [NexusTool::invoke] of the tool `T`.
+///
+/// Hooks are called in registration order and their futures are awaited one
+/// after another. The returned futures must be `Send`, so hooks that are
+/// generic over the tool should not hold the input or output across an
+/// `.await`.
+pub trait Middleware<T: NexusTool>: Send + Sync + 'static {
+    /// Runs before the tool is invoked with the deserialized input. Returning
+    /// an error skips the invocation and the remaining middleware and replies
+    /// with the returned status code.
+    fn before(&self, input: &T::Input) -> impl Future<Output = Result<(), StatusCode>> + Send {
+        let _ = input;
+
+        async { Ok(()) }
+    }
+    /// Runs after the tool was invoked with its input and output.
+    fn after(&self, input: &T::Input, output: &T::Output) -> impl Future<Output = ()> + Send {
+        let _ = (input, output);
+
+        async {}
+    }
+}
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Object safe version of [Middleware] so that different middleware can be
+/// stored in one [MiddlewareStack].
+trait ErasedMiddleware<T: NexusTool>: Send + Sync {
+    fn before<'a>(&'a self, input: &'a T::Input) -> BoxFuture<'a, Result<(), StatusCode>>;
+    fn after<'a>(&'a self, input: &'a T::Input, output: &'a T::Output) -> BoxFuture<'a, ()>;
+}
+
+impl<T: NexusTool, M: Middleware<T>> ErasedMiddleware<T> for M {
+    fn before<'a>(&'a self, input: &'a T::Input) -> BoxFuture<'a, Result<(), StatusCode>> {
+        Box::pin(Middleware::<T>::before(self, input))
+    }
+
+    fn after<'a>(&'a self, input: &'a T::Input, output: &'a T::Output) -> BoxFuture<'a, ()> {
+        Box::pin(Middleware::<T>::after(self, input, output))
+    }
+}
+
+/// Ordered list of [Middleware] of the tool `T`. Middleware runs in the order
+/// it was registered in.
+pub struct MiddlewareStack<T: NexusTool> {
+    middleware: Vec<Arc<dyn ErasedMiddleware<T>>>,
+}
+
+impl<T: NexusTool> MiddlewareStack<T> {
+    pub fn new() -> Self {
+        Self { middleware: vec![] }
+    }
+
+    /// Register a middleware to run after all previously registered ones.
+    pub fn with_middleware<M: Middleware<T>>(mut self, middleware: M) -> Self {
+        self.middleware.push(Arc::new(middleware));
+
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.middleware.is_empty()
+    }
+
+    /// Run [Middleware::before] of each middleware, stopping at the first one
+    /// that returns an error.
+    ///
+    /// The hooks are created up front so that only their boxed futures borrow
+    /// the input, which spares [NexusTool::Input] from being `Sync`.
+    pub(crate) fn before<'a>(
+        &'a self,
+        input: &'a T::Input,
+    ) -> impl Future<Output = Result<(), StatusCode>> + Send + 'a {
+        let hooks = self
+            .middleware
+            .iter()
+            .map(|middleware| middleware.before(input))
+            .collect::<Vec<_>>();
+
+        async move {
+            for hook in hooks {
+                hook.await?;
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Run [Middleware::after] of each middleware.
+    pub(crate) fn after<'a>(
+        &'a self,
+        input: &'a T::Input,
+        output: &'a T::Output,
+    ) -> impl Future<Output = ()> + Send + 'a {
+        let hooks = self
+            .middleware
+            .iter()
+            .map(|middleware| middleware.after(input, output))
+            .collect::<Vec<_>>();
+
+        async move {
+            for hook in hooks {
+                hook.await;
+            }
+        }
+    }
+}
+
+impl<T: NexusTool> Default for MiddlewareStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: NexusTool> Clone for MiddlewareStack<T> {
+    fn clone(&self) -> Self {
+        Self {
+            middleware: self.middleware.clone(),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! [RateLimiter] throttles invocations of a tool using a token bucket.
+
+use {
+    crate::{runtime::forwarded_for, Middleware, NexusTool},
+    std::{collections::HashMap, future::Future, sync::Arc, time::Instant},
+    tokio::sync::Mutex,
+    warp::http::StatusCode,
+};
+
+/// Number of per-IP buckets after which buckets that refilled completely are
+/// dropped.
+const MAX_IDLE_BUCKETS: usize = 10_000;
+
+/// [Middleware] that rejects invocations with
+/// [StatusCode::TOO_MANY_REQUESTS] once the token bucket is empty.
+///
+/// The bucket holds up to `burst` tokens and is refilled at
+/// `requests_per_second`. Each invocation consumes one token.
+#[derive(Clone)]
+pub struct RateLimiter {
+    requests_per_second: f64,
+    burst: f64,
+    per_ip: bool,
+    /// Buckets keyed by the client address. A single bucket keyed by [None]
+    /// is used when not limiting per IP.
+    buckets: Arc<Mutex<HashMap<Option<String>, TokenBucket>>>,
+}
+
+impl RateLimiter {
+    /// Limit all invocations of the tool to `requests_per_second` with bursts
+    /// of up to `burst` requests.
+    pub fn new(requests_per_second: f64, burst: usize) -> Self {
+        Self {
+            requests_per_second,
+            burst: burst as f64,
+            per_ip: false,
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Limit the invocations of each client to `requests_per_second` with
+    /// bursts of up to `burst` requests. Clients are identified by the first
+    /// address in the `X-Forwarded-For` header. Requests without the header
+    /// share one bucket.
+    pub fn per_ip(requests_per_second: f64, burst: usize) -> Self {
+        Self {
+            per_ip: true,
+            ..Self::new(requests_per_second, burst)
+        }
+    }
+
+    /// Take a token from the bucket of the given client, returning whether
+    /// the request is allowed.
+    async fn try_acquire(&self, client: Option<String>, now: Instant) -> bool {
+        let key = match self.per_ip {
+            true => client,
+            false => None,
+        };
+
+        let mut buckets = self.buckets.lock().await;
+
+        if buckets.len() >= MAX_IDLE_BUCKETS && !buckets.contains_key(&key) {
+            buckets.retain(|_, bucket| !bucket.is_full(self.requests_per_second, self.burst, now));
+        }
+
+        buckets
+            .entry(key)
+            .or_insert_with(|| TokenBucket::full(self.burst, now))
+            .try_take(self.requests_per_second, self.burst, now)
+    }
+}
+
+impl<T: NexusTool> Middleware<T> for RateLimiter {
+    fn before(&self, _: &T::Input) -> impl Future<Output = Result<(), StatusCode>> + Send {
+        async move {
+            match self.try_acquire(forwarded_for(), Instant::now()).await {
+                true => Ok(()),
+                false => Err(StatusCode::TOO_MANY_REQUESTS),
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn full(burst: f64, now: Instant) -> Self {
+        Self {
+            tokens: burst,
+            refilled_at: now,
+        }
+    }
+
+    /// Add the tokens accumulated since the last refill, capped at `burst`.
+    fn refill(&mut self, requests_per_second: f64, burst: f64, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * requests_per_second).min(burst);
+        self.refilled_at = now;
+    }
+
+    fn try_take(&mut self, requests_per_second: f64, burst: f64, now: Instant) -> bool {
+        self.refill(requests_per_second, burst, now);
+
+        if self.tokens < 1.0 {
+            return false;
+        }
+
+        self.tokens -= 1.0;
+
+        true
+    }
+
+    fn is_full(&self, requests_per_second: f64, burst: f64, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+
+        self.tokens + elapsed.as_secs_f64() * requests_per_second >= burst
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::time::Duration};
+
+    #[tokio::test]
+    async fn test_rate_limiter_refills() {
+        let limiter = RateLimiter::new(2.0, 3);
+        let now = Instant::now();
+
+        // Burst is allowed right away.
+        for _ in 0..3 {
+            assert!(limiter.try_acquire(None, now).await);
+        }
+
+        assert!(!limiter.try_acquire(None, now).await);
+
+        // One token is refilled every 500ms.
+        let later = now + Duration::from_millis(500);
+
+        assert!(limiter.try_acquire(None, later).await);
+        assert!(!limiter.try_acquire(None, later).await);
+
+        // Refill is capped at the burst.
+        let much_later = later + Duration::from_secs(60);
+
+        for _ in 0..3 {
+            assert!(limiter.try_acquire(None, much_later).await);
+        }
+
+        assert!(!limiter.try_acquire(None, much_later).await);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_ignores_client_unless_per_ip() {
+        let limiter = RateLimiter::new(1.0, 1);
+        let now = Instant::now();
+
+        assert!(limiter.try_acquire(Some("10.0.0.1".to_string()), now).await);
+        assert!(!limiter.try_acquire(Some("10.0.0.2".to_string()), now).await);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_per_ip() {
+        let limiter = RateLimiter::per_ip(1.0, 1);
+        let now = Instant::now();
+
+        assert!(limiter.try_acquire(Some("10.0.0.1".to_string()), now).await);
+        assert!(!limiter.try_acquire(Some("10.0.0.1".to_string()), now).await);
+        assert!(limiter.try_acquire(Some("10.0.0.2".to_string()), now).await);
+        assert!(limiter.try_acquire(None, now).await);
+        assert!(!limiter.try_acquire(None, now).await);
+    }
+}
@@ -1,16 +1,55 @@
 //! See <https://github.com/Talus-Network/gitbook-docs/blob/production/nexus-sdk/toolkit-rust.md>
 
 use {
+    crate::{Middleware, MiddlewareStack},
     anyhow::Result as AnyResult,
     nexus_sdk::ToolFqn,
     reqwest::Url,
     schemars::JsonSchema,
-    serde::{de::DeserializeOwned, Serialize},
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
     serde_json::{json, Value},
     std::future::Future,
     warp::http::StatusCode,
 };
 
+/// Input that was deserialized but is rejected by [NexusTool::validate]. It is
+/// returned to the caller with a `400 Bad Request`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolValidationError {
+    /// JSON pointer (RFC 6901) to the invalid value, e.g. `/body/data`.
+    /// Empty if the error concerns the whole input.
+    pub pointer: String,
+    /// Why the input is invalid.
+    pub message: String,
+}
+
+impl ToolValidationError {
+    /// Create an error that concerns the whole input.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            pointer: String::new(),
+            message: message.into(),
+        }
+    }
+
+    /// Point the error at the value at the given JSON pointer.
+    pub fn at(mut self, pointer: impl Into<String>) -> Self {
+        self.pointer = pointer.into();
+        self
+    }
+}
+
+impl std::fmt::Display for ToolValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.pointer.is_empty() {
+            true => write!(f, "{}", self.message),
+            false => write!(f, "{} at '{}'", self.message, self.pointer),
+        }
+    }
+}
+
+impl std::error::Error for ToolValidationError {}
+
 /// This trait defines the interface for a Nexus Tool. It forces implementation
 /// of the following methods:
 ///
@@ -52,6 +91,18 @@ pub trait NexusTool: Send + Sync + 'static {
     ///
     /// It is used to generate the `/invoke` endpoint.
     fn invoke(&self, input: Self::Input) -> impl Future<Output = Self::Output> + Send;
+    /// Checks the deserialized input before it is passed to
+    /// [NexusTool::invoke]. Returning an error skips the invocation and
+    /// replies with `400 Bad Request` and the error. This defaults to
+    /// accepting any input.
+    ///
+    /// Use this for rules that the input schema can't express so that
+    /// [NexusTool::invoke] only deals with valid input.
+    fn validate(&self, input: &Self::Input) -> Result<(), ToolValidationError> {
+        let _ = input;
+
+        Ok(())
+    }
     /// Returns the health status of the tool. For now, this only returns an
     /// HTTP status code.
     ///
@@ -69,16 +120,50 @@ pub trait NexusTool: Send + Sync + 'static {
     fn description() -> &'static str {
         ""
     }
+    /// Returns the [crate::Middleware] that runs around each invocation of the tool,
+    /// in registration order. This defaults to no middleware.
+    ///
+    /// The stack is built once and shared by all routes that invoke the tool.
+    fn middleware() -> MiddlewareStack<Self>
+    where
+        Self: Sized,
+    {
+        MiddlewareStack::new()
+    }
+    /// Registers a [crate::Middleware] that runs around each invocation of the
+    /// tool after all previously registered middleware, starting with the
+    /// ones returned by [NexusTool::middleware].
+    ///
+    /// Middleware is registered for the tool type rather than this instance
+    /// so register it once, before the tool is served with
+    /// [crate::bootstrap!].
+    fn with_middleware<M: Middleware<Self>>(self, middleware: M) -> Self
+    where
+        Self: Sized,
+    {
+        crate::runtime::register_middleware::<Self, M>(middleware);
+
+        self
+    }
+    /// Returns how many invocations of the tool may run at the same time,
+    /// across `/invoke` and `/batch`. Further invocations are rejected with
+    /// `429 Too Many Requests` until one finishes. This defaults to [None],
+    /// which means unlimited.
+    ///
+    /// Use this for tools that call upstream APIs that ban clients sending
+    /// too many requests at once.
+    fn max_concurrency() -> Option<usize> {
+        None
+    }
     /// Construct a new instance of the tool. This is mainly here so that
     /// dependencies can be injected for testing purposes.
     fn new() -> impl Future<Output = Self> + Send;
-    /// Returns the metadata of the tool. It includes the domain, name, version,
-    /// input schema, and output schema.
+    /// Returns the schema of the tool. It includes the FQN, description, input
+    /// schema, and output schema.
     ///
-    /// It is used to generate the `/meta` endpoint.
-    fn meta(url: Url) -> Value {
+    /// It is used to generate the `/schema` and `/schemas` endpoints.
+    fn schema() -> Value {
         let fqn = Self::fqn();
-        let url = url.to_string();
         let description = Self::description();
         let input_schema = schemars::schema_for!(Self::Input);
         let output_schema = schemars::schema_for!(Self::Output);
@@ -86,11 +171,21 @@ pub trait NexusTool: Send + Sync + 'static {
         json!(
             {
                 "fqn": fqn,
-                "url": url,
                 "description": description,
                 "input_schema": input_schema,
                 "output_schema": output_schema,
             }
         )
     }
+    /// Returns the metadata of the tool. It includes the domain, name, version,
+    /// URL, input schema, and output schema.
+    ///
+    /// It is used to generate the `/meta` endpoint.
+    fn meta(url: Url) -> Value {
+        let mut meta = Self::schema();
+
+        meta["url"] = json!(url.to_string());
+
+        meta
+    }
 }
@@ -0,0 +1,250 @@
+//! Generates the OpenAPI 3.1 document served on `/openapi.json` from the
+//! schemas returned by [crate::NexusTool::schema].
+//!
+//! OpenAPI 3.1 uses JSON Schema 2020-12, same as `schemars`, so the schemas
+//! are used as-is. Only their `$defs` are moved to `components/schemas` as
+//! references are resolved against the root of the document.
+
+use serde_json::{json, Map, Value};
+
+/// Prefix of references to definitions in a `schemars` schema.
+const DEFS_REF_PREFIX: &str = "#/$defs/";
+
+/// Prefix of references to schemas in the OpenAPI document.
+const COMPONENTS_REF_PREFIX: &str = "#/components/schemas/";
+
+/// Build the OpenAPI document for the given tools. Each tool is its path and
+/// the value returned by [crate::NexusTool::schema].
+pub(crate) fn document(tools: &[(&str, Value)]) -> Value {
+    let mut paths = Map::new();
+    let mut components = Map::new();
+
+    for (path, schema) in tools {
+        let fqn = schema["fqn"].as_str().unwrap_or_default();
+
+        let input = register_schema(
+            &mut components,
+            fqn,
+            "Input",
+            schema["input_schema"].clone(),
+        );
+        let output = register_schema(
+            &mut components,
+            fqn,
+            "Output",
+            schema["output_schema"].clone(),
+        );
+
+        paths.insert(
+            invoke_path(path),
+            json!({
+                "post": {
+                    "operationId": fqn,
+                    "summary": schema["description"],
+                    "requestBody": {
+                        "required": true,
+                        "content": { "application/json": { "schema": input } },
+                    },
+                    "responses": {
+                        "200": {
+                            "description": "Output of the tool.",
+                            "content": { "application/json": { "schema": output } },
+                        },
+                        "400": { "description": "The input was rejected by the tool's validation." },
+                        "422": { "description": "The input could not be deserialized." },
+                        "500": { "description": "The output could not be serialized." },
+                    },
+                },
+            }),
+        );
+    }
+
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "Nexus Tools",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "paths": paths,
+        "components": { "schemas": components },
+    })
+}
+
+/// Path of the `/invoke` endpoint of a tool served on the given path.
+fn invoke_path(path: &str) -> String {
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .chain(["invoke"])
+        .fold(String::new(), |path, segment| format!("{path}/{segment}"))
+}
+
+/// Add the schema and its definitions to the components, returning a
+/// reference to the schema.
+fn register_schema(
+    components: &mut Map<String, Value>,
+    fqn: &str,
+    name: &str,
+    mut schema: Value,
+) -> Value {
+    let root = component_name(fqn, name);
+    let mut defs = Map::new();
+
+    if let Some(schema) = schema.as_object_mut() {
+        schema.remove("$schema");
+
+        if let Some(Value::Object(schema_defs)) = schema.remove("$defs") {
+            defs = schema_defs;
+        }
+    }
+
+    let mut schema_refs = vec![("#".to_string(), root.clone())];
+
+    for def in defs.keys() {
+        schema_refs.push((
+            format!("{DEFS_REF_PREFIX}{}", escape_pointer(def)),
+            component_name(fqn, def),
+        ));
+    }
+
+    for (def, mut def_schema) in defs {
+        rewrite_refs(&mut def_schema, &schema_refs);
+
+        components.insert(component_name(fqn, &def), def_schema);
+    }
+
+    rewrite_refs(&mut schema, &schema_refs);
+
+    components.insert(root.clone(), schema);
+
+    json!({ "$ref": format!("{COMPONENTS_REF_PREFIX}{root}") })
+}
+
+/// Point references to the schema or its definitions to the components.
+fn rewrite_refs(schema: &mut Value, schema_refs: &[(String, String)]) {
+    match schema {
+        Value::Object(schema) => {
+            let component = match schema.get("$ref") {
+                Some(Value::String(reference)) => schema_refs
+                    .iter()
+                    .find(|(from, _)| from == reference)
+                    .map(|(_, component)| component),
+                _ => None,
+            };
+
+            if let Some(component) = component {
+                schema.insert(
+                    "$ref".to_string(),
+                    json!(format!("{COMPONENTS_REF_PREFIX}{component}")),
+                );
+            }
+
+            schema
+                .values_mut()
+                .for_each(|schema| rewrite_refs(schema, schema_refs));
+        }
+        Value::Array(schemas) => schemas
+            .iter_mut()
+            .for_each(|schema| rewrite_refs(schema, schema_refs)),
+        _ => (),
+    }
+}
+
+/// Component names may only contain alphanumerics, `.`, `-` and `_`.
+fn component_name(fqn: &str, name: &str) -> String {
+    format!("{fqn}.{name}")
+        .chars()
+        .map(
+            |c| match c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                true => c,
+                false => '_',
+            },
+        )
+        .collect()
+}
+
+/// Escape a JSON pointer segment.
+fn escape_pointer(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tool_schema() -> Value {
+        json!({
+            "fqn": "xyz.dummy.tool@1",
+            "description": "Dummy tool",
+            "input_schema": {
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "Input",
+                "type": "object",
+                "properties": { "message": { "$ref": "#/$defs/Message" } },
+                "required": ["message"],
+                "$defs": {
+                    "Message": {
+                        "type": "object",
+                        "properties": {
+                            "text": { "type": "string" },
+                            "replies": { "type": "array", "items": { "$ref": "#/$defs/Message" } },
+                        },
+                    },
+                },
+            },
+            "output_schema": {
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "Output",
+                "oneOf": [
+                    { "type": "object", "properties": { "ok": { "$ref": "#" } } },
+                    { "type": "string", "enum": ["err"] },
+                ],
+            },
+        })
+    }
+
+    #[test]
+    fn test_document() {
+        let document = document(&[("/nested/path/", tool_schema())]);
+
+        assert_eq!(document["openapi"], "3.1.0");
+
+        let operation = &document["paths"]["/nested/path/invoke"]["post"];
+
+        assert_eq!(operation["operationId"], "xyz.dummy.tool@1");
+        assert_eq!(operation["summary"], "Dummy tool");
+        assert_eq!(
+            operation["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/xyz.dummy.tool_1.Input"
+        );
+        assert_eq!(
+            operation["responses"]["200"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/xyz.dummy.tool_1.Output"
+        );
+
+        let components = &document["components"]["schemas"];
+        let input = &components["xyz.dummy.tool_1.Input"];
+
+        assert!(input.get("$schema").is_none());
+        assert!(input.get("$defs").is_none());
+        assert_eq!(
+            input["properties"]["message"]["$ref"],
+            "#/components/schemas/xyz.dummy.tool_1.Message"
+        );
+        assert_eq!(
+            components["xyz.dummy.tool_1.Message"]["properties"]["replies"]["items"]["$ref"],
+            "#/components/schemas/xyz.dummy.tool_1.Message"
+        );
+        assert_eq!(
+            components["xyz.dummy.tool_1.Output"]["oneOf"][0]["properties"]["ok"]["$ref"],
+            "#/components/schemas/xyz.dummy.tool_1.Output"
+        );
+    }
+
+    #[test]
+    fn test_invoke_path() {
+        assert_eq!(invoke_path(""), "/invoke");
+        assert_eq!(invoke_path("/"), "/invoke");
+        assert_eq!(invoke_path("path"), "/path/invoke");
+        assert_eq!(invoke_path("/nested//path/"), "/nested/path/invoke");
+    }
+}
@@ -1,9 +1,26 @@
 //! See <https://github.com/Talus-Network/gitbook-docs/blob/production/nexus-sdk/toolkit-rust.md>
 
 use {
-    crate::NexusTool,
+    crate::{
+        logging::TOOL_FQN,
+        serde_tracked::from_value_tracked,
+        Middleware,
+        MiddlewareStack,
+        NexusTool,
+    },
+    nexus_sdk::ToolFqn,
     reqwest::Url,
     serde_json::json,
+    std::{
+        any::{Any, TypeId},
+        collections::HashMap,
+        future::Future,
+        pin::Pin,
+        sync::{Arc, Mutex, OnceLock, PoisonError},
+        time::Duration,
+    },
+    tokio::sync::Semaphore,
+    tracing::Instrument,
     warp::{
         filters::{host::Authority, path::FullPath},
         http::StatusCode,
@@ -13,6 +30,54 @@ use {
     },
 };
 
+/// Header carrying the ID that correlates a request across tools. It is read
+/// from the incoming request, generated if absent and echoed in the response.
+pub const REQUEST_ID_HEADER: &str = "X-Nexus-Request-Id";
+
+tokio::task_local! {
+    pub(crate) static REQUEST_ID: String;
+}
+
+/// Returns the ID of the request that is currently being handled by
+/// [NexusTool::invoke]. Tools can include it in their logs and forward it to
+/// downstream services via the [REQUEST_ID_HEADER] header.
+///
+/// Returns [None] when called outside of an invocation.
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(|request_id| request_id.clone()).ok()
+}
+
+/// Header carrying the addresses of the client and the proxies a request went
+/// through.
+const FORWARDED_FOR_HEADER: &str = "X-Forwarded-For";
+
+tokio::task_local! {
+    static FORWARDED_FOR: Option<String>;
+}
+
+/// Returns the client address of the request that is currently being handled,
+/// that is the first entry of the [FORWARDED_FOR_HEADER] header.
+///
+/// Returns [None] when called outside of an invocation or if the header is
+/// missing.
+pub(crate) fn forwarded_for() -> Option<String> {
+    FORWARDED_FOR
+        .try_with(|client| client.clone())
+        .ok()
+        .flatten()
+}
+
+/// Extract the client address from the [FORWARDED_FOR_HEADER] header.
+fn client_address(forwarded_for: Option<String>) -> Option<String> {
+    let forwarded_for = forwarded_for?;
+    let client = forwarded_for.split(',').next()?.trim();
+
+    match client.is_empty() {
+        true => None,
+        false => Some(client.to_string()),
+    }
+}
+
 /// Macro to bootstrap the runtime for a set of tools. The macro generates the
 /// necessary routes for each tool and serves them on the provided address.
 ///
@@ -71,7 +136,7 @@ macro_rules! bootstrap {
             .expect("Invalid socket address in BIND_ADDR")
     }};
     ($addr:expr, [$tool:ty $(, $next_tool:ty)* $(,)?]) => {{
-        let _ = $crate::env_logger::try_init();
+        $crate::init_logger_();
         use {
             $crate::warp::{http::StatusCode, Filter},
         };
@@ -96,9 +161,48 @@ macro_rules! bootstrap {
             .and($crate::warp::path("tools"))
             .map(move || $crate::warp::reply::json(&paths));
 
-        let routes = routes.or(default_health_route).or(default_tools_route);
-        // Serve the routes.
-        $crate::warp::serve(routes).run($addr).await
+        // Add a default schemas route to list the schemas of all tools.
+        let schemas = vec![
+            <$tool as $crate::NexusTool>::schema(),
+            $(<$next_tool as $crate::NexusTool>::schema(),)*
+        ];
+
+        let default_schemas_route = $crate::warp::get()
+            .and($crate::warp::path("schemas"))
+            .map(move || $crate::warp::reply::json(&schemas));
+
+        // Add a metrics route that is only served with the `metrics` feature.
+        let metrics_route = $crate::metrics_route_();
+
+        // Add an OpenAPI route that is only served with the `spec` feature.
+        let openapi_route = $crate::openapi_route_(vec![
+            (<$tool as $crate::NexusTool>::path(), <$tool as $crate::NexusTool>::schema()),
+            $((<$next_tool as $crate::NexusTool>::path(), <$next_tool as $crate::NexusTool>::schema()),)*
+        ]);
+
+        // Add routes to invoke any of the tools by their FQN. The batch route
+        // is only served with the `batch` feature.
+        let invokers = vec![
+            $crate::invoker_for_::<$tool>(),
+            $($crate::invoker_for_::<$next_tool>(),)*
+        ];
+        let invoke_by_fqn_route = $crate::invoke_by_fqn_route_(invokers.clone());
+        let batch_route = $crate::batch_route_(invokers);
+
+        let routes = routes
+            .or(default_health_route)
+            .or(default_tools_route)
+            .or(default_schemas_route)
+            .or(metrics_route)
+            .or(openapi_route)
+            .or(invoke_by_fqn_route)
+            .or(batch_route);
+        // Serve the routes until a shutdown signal is received and drain
+        // in-flight requests.
+        let (shutdown, drain_deadline) = $crate::shutdown_signals_();
+        let (_, server) = $crate::warp::serve(routes).bind_with_graceful_shutdown($addr, shutdown);
+
+        $crate::serve_until_drained_(server, drain_deadline).await
     }};
     // Default address.
     ([$($tool:ty),+ $(,)?]) => {{
@@ -151,14 +255,30 @@ pub fn routes_for_<T: NexusTool>() -> impl Filter<Extract = impl Reply, Error =
         .and(warp::path::full())
         .and_then(meta_handler::<T>);
 
+    // Schema path is tool base URL path and `/schema`.
+    let schema = T::schema();
+
+    let schema_route = warp::get()
+        .and(base_path.clone())
+        .and(warp::path("schema"))
+        .map(move || warp::reply::json(&schema));
+
     // Invoke path is tool base URL path and `/invoke`.
+    // The path must end here so that `/invoke/{fqn}` is not mistaken for the
+    // invoke route of a tool served on the root path.
     let invoke_route = warp::post()
         .and(base_path)
         .and(warp::path("invoke"))
+        .and(warp::path::end())
+        .and(warp::header::optional::<String>(REQUEST_ID_HEADER))
+        .and(warp::header::optional::<String>(FORWARDED_FOR_HEADER))
         .and(warp::body::json())
         .and_then(invoke_handler::<T>);
 
-    health_route.or(meta_route).or(invoke_route)
+    health_route
+        .or(meta_route)
+        .or(schema_route)
+        .or(invoke_route)
 }
 
 async fn health_handler<T: NexusTool>() -> Result<impl Reply, Rejection> {
@@ -169,6 +289,9 @@ async fn health_handler<T: NexusTool>() -> Result<impl Reply, Rejection> {
         .await
         .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
 
+    #[cfg(feature = "metrics")]
+    metrics::record_health_check(&T::fqn(), status);
+
     Ok(warp::reply::with_status("", status))
 }
 
@@ -244,31 +367,892 @@ async fn meta_handler<T: NexusTool>(
     ))
 }
 
-async fn invoke_handler<T: NexusTool>(input: serde_json::Value) -> Result<impl Reply, Rejection> {
+async fn invoke_handler<T: NexusTool>(
+    request_id: Option<String>,
+    forwarded_for: Option<String>,
+    input: serde_json::Value,
+) -> Result<impl Reply, Rejection> {
+    let request_id = request_id_or_new(request_id);
+
+    let span = tracing::info_span!("invoke", fqn = %T::fqn(), request_id = %request_id);
+
+    let invocation = REQUEST_ID.scope(request_id.clone(), invoke::<T>(input).instrument(span));
+
+    let (status, reply) = FORWARDED_FOR
+        .scope(client_address(forwarded_for), invocation)
+        .await;
+
+    Ok(warp::reply::with_header(
+        warp::reply::with_status(warp::reply::json(&reply), status),
+        REQUEST_ID_HEADER,
+        request_id,
+    ))
+}
+
+/// Semaphores that enforce [NexusTool::max_concurrency], keyed by the type of
+/// the tool so that `/invoke` and `/batch` share the same limit.
+static CONCURRENCY_LIMITS: OnceLock<Mutex<HashMap<TypeId, Arc<Semaphore>>>> = OnceLock::new();
+
+/// Returns the semaphore limiting concurrent invocations of the tool or [None]
+/// if the tool has no limit.
+fn concurrency_limit<T: NexusTool>() -> Option<Arc<Semaphore>> {
+    let max_concurrency = T::max_concurrency()?;
+
+    let mut limits = CONCURRENCY_LIMITS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    let semaphore = limits
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| Arc::new(Semaphore::new(max_concurrency.max(1))));
+
+    Some(semaphore.clone())
+}
+
+/// Middleware of each tool, keyed by the type of the tool so that `/invoke`,
+/// `/invoke/{fqn}` and `/batch` share the same middleware state.
+static MIDDLEWARE: OnceLock<Mutex<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>> = OnceLock::new();
+
+/// Returns the middleware stack of the tool, built from
+/// [NexusTool::middleware] the first time it is requested.
+fn middleware_for<T: NexusTool>() -> Arc<MiddlewareStack<T>> {
+    let mut stacks = MIDDLEWARE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    middleware_entry::<T>(&mut stacks)
+}
+
+/// Append the middleware to the stack of the tool. Invocations that already
+/// started keep running with the previous stack.
+pub(crate) fn register_middleware<T: NexusTool, M: Middleware<T>>(middleware: M) {
+    let mut stacks = MIDDLEWARE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    let stack = middleware_entry::<T>(&mut stacks)
+        .as_ref()
+        .clone()
+        .with_middleware(middleware);
+
+    stacks.insert(TypeId::of::<T>(), Arc::new(stack));
+}
+
+fn middleware_entry<T: NexusTool>(
+    stacks: &mut HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+) -> Arc<MiddlewareStack<T>> {
+    stacks
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| Arc::new(T::middleware()))
+        .clone()
+        .downcast()
+        .expect("Middleware is keyed by the type of its tool")
+}
+
+/// Deserialize the input, invoke the tool wrapped in its middleware and return
+/// the status code and body of the reply.
+async fn invoke<T: NexusTool>(input: serde_json::Value) -> (StatusCode, serde_json::Value) {
+    #[cfg(feature = "metrics")]
+    let started = std::time::Instant::now();
+
+    // Logs emitted during the invocation carry the FQN of the tool.
+    let (status, reply) = TOOL_FQN
+        .scope(T::fqn().to_string(), invoke_tool::<T>(input))
+        .await;
+
+    #[cfg(feature = "metrics")]
+    metrics::record_invocation(&T::fqn(), status, &reply, started.elapsed());
+
+    (status, reply)
+}
+
+async fn invoke_tool<T: NexusTool>(input: serde_json::Value) -> (StatusCode, serde_json::Value) {
+    // Reply with 429 rather than queueing if the tool is saturated. The permit
+    // is held until the invocation finishes.
+    let _permit = match concurrency_limit::<T>().map(Semaphore::try_acquire_owned) {
+        Some(Err(_)) => {
+            log::warn!(
+                "Tool '{}' reached its limit of concurrent invocations",
+                T::fqn()
+            );
+
+            let reply = json!({
+                "error": "concurrency_limit_reached",
+                "details": "Too many concurrent invocations, try again later.",
+            });
+
+            return (StatusCode::TOO_MANY_REQUESTS, reply);
+        }
+        Some(Ok(permit)) => Some(permit),
+        None => None,
+    };
+
+    let middleware = middleware_for::<T>();
+
+    // Keep the payload around for [Middleware::after] only if there is
+    // middleware to run.
+    let after_payload = (!middleware.is_empty()).then(|| input.clone());
+
     // Deserialize the input payload into [T::Input].
-    let input = match serde_json::from_value(input) {
+    let input = match from_value_tracked(input) {
         Ok(input) => input,
         Err(e) => {
+            log::error!("Failed to deserialize input of tool '{}': {e}", T::fqn());
+
             let reply = json!({
                 "error": "input_deserialization_error",
                 "details": e.to_string(),
+                "validation": e,
             });
 
             // Reply with 422 if we can't parse the input data.
-            return Ok(warp::reply::with_status(
-                warp::reply::json(&reply),
-                StatusCode::UNPROCESSABLE_ENTITY,
-            ));
+            return (StatusCode::UNPROCESSABLE_ENTITY, reply);
         }
     };
 
+    // Middleware can reject the request before the tool is invoked.
+    let before = middleware.before(&input);
+
+    if let Err(status) = before.await {
+        log::warn!(
+            "Middleware of tool '{}' rejected the request with status '{status}'",
+            T::fqn()
+        );
+        let reply = json!({
+            "error": "rejected_by_middleware",
+            "details": format!("Request was rejected with status '{status}'."),
+        });
+
+        return (status, reply);
+    }
+
     let tool = T::new().await;
 
+    // Reply with 400 if the input is well-formed but invalid.
+    if let Err(e) = tool.validate(&input) {
+        log::warn!("Input of tool '{}' is invalid: {e}", T::fqn());
+
+        let reply = json!({
+            "error": "input_validation_error",
+            "details": e.to_string(),
+            "validation": e,
+        });
+
+        return (StatusCode::BAD_REQUEST, reply);
+    }
+
     // Invoke the tool logic.
     let output = tool.invoke(input).await;
 
-    Ok(warp::reply::with_status(
-        warp::reply::json(&output),
-        StatusCode::OK,
+    // The tool consumed its input so the middleware gets a copy.
+    if let Some(Ok(input)) = after_payload.map(serde_json::from_value::<T::Input>) {
+        let after = middleware.after(&input, &output);
+
+        after.await;
+    }
+
+    match serde_json::to_value(output) {
+        Ok(output) => (StatusCode::OK, output),
+        Err(e) => {
+            log::error!("Failed to serialize output of tool '{}': {e}", T::fqn());
+
+            let reply = json!({
+                "error": "output_serialization_error",
+                "details": e.to_string(),
+            });
+
+            (StatusCode::INTERNAL_SERVER_ERROR, reply)
+        }
+    }
+}
+
+/// Use the incoming request ID or generate a new one.
+fn request_id_or_new(request_id: Option<String>) -> String {
+    match request_id {
+        Some(request_id) if !request_id.trim().is_empty() => request_id,
+        _ => uuid::Uuid::new_v4().to_string(),
+    }
+}
+
+// == Batch ==
+
+/// Maximum number of invocations from a single batch that run concurrently.
+#[cfg(feature = "batch")]
+const BATCH_CONCURRENCY: usize = 8;
+
+/// Type-erased [invoke] of a [NexusTool] so that tools can be looked up by
+/// their FQN or path.
+pub type Invoker = Arc<dyn Fn(serde_json::Value) -> Invocation + Send + Sync>;
+
+/// Future returned by an [Invoker].
+pub type Invocation = Pin<Box<dyn Future<Output = (StatusCode, serde_json::Value)> + Send>>;
+
+/// One entry of the `/batch` request body. The tool is addressed either by its
+/// FQN or by the path it is served on.
+#[cfg(feature = "batch")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BatchEntry {
+    #[serde(default)]
+    fqn: Option<ToolFqn>,
+    #[serde(default)]
+    tool_path: Option<String>,
+    input: serde_json::Value,
+}
+
+/// Tools that can be invoked via the `/batch` and `/invoke/{fqn}` routes along
+/// with their FQN and normalized path.
+struct RegisteredTools(Vec<(ToolFqn, String, Invoker)>);
+
+impl RegisteredTools {
+    fn by_fqn(&self, fqn: &ToolFqn) -> Option<&Invoker> {
+        self.0
+            .iter()
+            .find(|(tool_fqn, _, _)| tool_fqn == fqn)
+            .map(|(_, _, invoker)| invoker)
+    }
+
+    #[cfg(feature = "batch")]
+    fn by_path(&self, path: &str) -> Option<(&ToolFqn, &Invoker)> {
+        let path = normalize_tool_path(path);
+
+        self.0
+            .iter()
+            .find(|(_, tool_path, _)| *tool_path == path)
+            .map(|(fqn, _, invoker)| (fqn, invoker))
+    }
+}
+
+/// Paths are compared without leading, trailing and repeated slashes.
+fn normalize_tool_path(path: &str) -> String {
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// This function returns the FQN and path of a given [NexusTool] along with
+/// its [Invoker].
+///
+/// **This is an internal function used by [bootstrap!] macro and should not be
+/// used directly.**
+#[doc(hidden)]
+pub fn invoker_for_<T: NexusTool>() -> (ToolFqn, &'static str, Invoker) {
+    (
+        T::fqn(),
+        T::path(),
+        Arc::new(|input| -> Invocation { Box::pin(invoke::<T>(input)) }),
+    )
+}
+
+/// This function generates the `/batch` route that invokes any of the provided
+/// tools by their FQN or path.
+///
+/// **This is an internal function used by [bootstrap!] macro and should not be
+/// used directly.**
+#[doc(hidden)]
+#[cfg(feature = "batch")]
+pub fn batch_route_(
+    invokers: Vec<(ToolFqn, &'static str, Invoker)>,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    let invokers = Arc::new(RegisteredTools(
+        invokers
+            .into_iter()
+            .map(|(fqn, path, invoker)| (fqn, normalize_tool_path(path), invoker))
+            .collect(),
+    ));
+
+    warp::post()
+        .and(warp::path("batch"))
+        .and(warp::path::end())
+        .and(warp::header::optional::<String>(REQUEST_ID_HEADER))
+        .and(warp::header::optional::<String>(FORWARDED_FOR_HEADER))
+        .and(warp::body::json())
+        .and_then(move |request_id, forwarded_for, entries| {
+            batch_handler(invokers.clone(), request_id, forwarded_for, entries)
+        })
+}
+
+/// See [batch_route_]. Rejects all requests as the `batch` feature is
+/// disabled.
+#[doc(hidden)]
+#[cfg(not(feature = "batch"))]
+pub fn batch_route_(
+    _: Vec<(ToolFqn, &'static str, Invoker)>,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    warp::any().and_then(|| async { Err::<StatusCode, _>(warp::reject::not_found()) })
+}
+
+/// Invoke all entries concurrently and reply with their results in the same
+/// order. A failing entry does not affect the others.
+#[cfg(feature = "batch")]
+async fn batch_handler(
+    invokers: Arc<RegisteredTools>,
+    request_id: Option<String>,
+    forwarded_for: Option<String>,
+    entries: Vec<serde_json::Value>,
+) -> Result<impl Reply, Rejection> {
+    let request_id = request_id_or_new(request_id);
+    let forwarded_for = client_address(forwarded_for);
+    let semaphore = Arc::new(Semaphore::new(BATCH_CONCURRENCY));
+
+    // Entries whose task panicked keep this result.
+    let mut results = vec![
+        batch_result(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            json!({
+                "error": "invocation_failed",
+                "details": "Tool invocation did not finish.",
+            })
+        );
+        entries.len()
+    ];
+
+    let mut tasks = tokio::task::JoinSet::new();
+
+    for (index, entry) in entries.into_iter().enumerate() {
+        let invokers = invokers.clone();
+        let semaphore = semaphore.clone();
+        let request_id = request_id.clone();
+        let forwarded_for = forwarded_for.clone();
+
+        tasks.spawn(async move {
+            let _permit = semaphore
+                .acquire_owned()
+                .await
+                .expect("Semaphore is never closed");
+
+            let result = FORWARDED_FOR
+                .scope(
+                    forwarded_for,
+                    invoke_batch_entry(&invokers, entry, request_id),
+                )
+                .await;
+
+            (index, result)
+        });
+    }
+
+    while let Some(result) = tasks.join_next().await {
+        if let Ok((index, result)) = result {
+            results[index] = result;
+        }
+    }
+
+    Ok(warp::reply::with_header(
+        warp::reply::json(&results),
+        REQUEST_ID_HEADER,
+        request_id,
     ))
 }
+
+#[cfg(feature = "batch")]
+async fn invoke_batch_entry(
+    invokers: &RegisteredTools,
+    entry: serde_json::Value,
+    request_id: String,
+) -> serde_json::Value {
+    let BatchEntry {
+        fqn,
+        tool_path,
+        input,
+    } = match serde_json::from_value(entry) {
+        Ok(entry) => entry,
+        Err(e) => {
+            let reply = json!({
+                "error": "batch_entry_deserialization_error",
+                "details": e.to_string(),
+            });
+
+            return batch_result(StatusCode::BAD_REQUEST, reply);
+        }
+    };
+
+    let tool = match (fqn, tool_path) {
+        (Some(fqn), None) => match invokers.by_fqn(&fqn) {
+            Some(invoker) => Ok((fqn, invoker)),
+            None => Err(format!("Tool '{fqn}' is not served by this webserver.")),
+        },
+        (None, Some(tool_path)) => match invokers.by_path(&tool_path) {
+            Some((fqn, invoker)) => Ok((fqn.clone(), invoker)),
+            None => Err(format!(
+                "No tool is served on path '{tool_path}' by this webserver."
+            )),
+        },
+        _ => {
+            let reply = json!({
+                "error": "batch_entry_deserialization_error",
+                "details": "Exactly one of 'fqn' and 'tool_path' must be provided.",
+            });
+
+            return batch_result(StatusCode::BAD_REQUEST, reply);
+        }
+    };
+
+    let (fqn, invoker) = match tool {
+        Ok(tool) => tool,
+        Err(details) => {
+            let reply = json!({
+                "error": "tool_not_found",
+                "details": details,
+            });
+
+            return batch_result(StatusCode::NOT_FOUND, reply);
+        }
+    };
+
+    let span = tracing::info_span!("invoke", fqn = %fqn, request_id = %request_id);
+
+    let (status, reply) = REQUEST_ID
+        .scope(request_id, invoker(input).instrument(span))
+        .await;
+
+    batch_result(status, reply)
+}
+
+/// Each batch result holds the status code and body `/invoke` would reply
+/// with.
+#[cfg(feature = "batch")]
+fn batch_result(status: StatusCode, body: serde_json::Value) -> serde_json::Value {
+    json!({
+        "status": status.as_u16(),
+        "body": body,
+    })
+}
+
+// == Invoke by FQN ==
+
+/// This function generates the `POST /invoke/{fqn}` route that invokes any of
+/// the provided tools by their FQN, regardless of the path they are served on.
+///
+/// **This is an internal function used by [bootstrap!] macro and should not be
+/// used directly.**
+#[doc(hidden)]
+pub fn invoke_by_fqn_route_(
+    invokers: Vec<(ToolFqn, &'static str, Invoker)>,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    let invokers = Arc::new(RegisteredTools(
+        invokers
+            .into_iter()
+            .map(|(fqn, path, invoker)| (fqn, normalize_tool_path(path), invoker))
+            .collect(),
+    ));
+
+    warp::post()
+        .and(warp::path("invoke"))
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .and(warp::header::optional::<String>(REQUEST_ID_HEADER))
+        .and(warp::header::optional::<String>(FORWARDED_FOR_HEADER))
+        .and(warp::body::json())
+        .and_then(move |fqn, request_id, forwarded_for, input| {
+            invoke_by_fqn_handler(invokers.clone(), fqn, request_id, forwarded_for, input)
+        })
+}
+
+async fn invoke_by_fqn_handler(
+    invokers: Arc<RegisteredTools>,
+    fqn: String,
+    request_id: Option<String>,
+    forwarded_for: Option<String>,
+    input: serde_json::Value,
+) -> Result<impl Reply, Rejection> {
+    let request_id = request_id_or_new(request_id);
+
+    // Clients may percent-encode the `@` of the version.
+    let invoker = percent_decode(&fqn)
+        .and_then(|fqn| fqn.parse::<ToolFqn>().ok())
+        .and_then(|fqn| invokers.by_fqn(&fqn).map(|invoker| (fqn, invoker)));
+
+    let (status, reply) = match invoker {
+        Some((fqn, invoker)) => {
+            let span = tracing::info_span!("invoke", fqn = %fqn, request_id = %request_id);
+            let invocation = REQUEST_ID.scope(request_id.clone(), invoker(input).instrument(span));
+
+            FORWARDED_FOR
+                .scope(client_address(forwarded_for), invocation)
+                .await
+        }
+        None => {
+            let reply = json!({
+                "error": "tool_not_found",
+                "details": format!("No tool with FQN '{fqn}' is served by this webserver."),
+            });
+
+            (StatusCode::NOT_FOUND, reply)
+        }
+    };
+
+    Ok(warp::reply::with_header(
+        warp::reply::with_status(warp::reply::json(&reply), status),
+        REQUEST_ID_HEADER,
+        request_id,
+    ))
+}
+
+/// Decode `%XX` escapes of a path segment. Returns [None] if an escape is
+/// malformed or the result is not UTF-8.
+fn percent_decode(segment: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(segment.len());
+    let mut rest = segment.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte != b'%' {
+            bytes.push(byte);
+            rest = tail;
+            continue;
+        }
+
+        let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+        bytes.push(u8::from_str_radix(hex, 16).ok()?);
+        rest = &tail[2..];
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+// == OpenAPI ==
+
+/// This function generates the `/openapi.json` route that describes the
+/// `/invoke` endpoints of the given tools as an OpenAPI 3.1 document. Each tool
+/// is its [NexusTool::path] and [NexusTool::schema]. The route is only served
+/// when the `spec` feature is enabled.
+///
+/// **This is an internal function used by [bootstrap!] macro and should not be
+/// used directly.**
+#[doc(hidden)]
+#[cfg(feature = "spec")]
+pub fn openapi_route_(
+    tools: Vec<(&'static str, serde_json::Value)>,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    let document = crate::openapi::document(&tools);
+
+    warp::get()
+        .and(warp::path("openapi.json"))
+        .and(warp::path::end())
+        .map(move || warp::reply::json(&document))
+}
+
+/// See [openapi_route_]. Rejects all requests as the `spec` feature is
+/// disabled.
+#[doc(hidden)]
+#[cfg(not(feature = "spec"))]
+pub fn openapi_route_(
+    _: Vec<(&'static str, serde_json::Value)>,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    warp::any().and_then(|| async { Err::<StatusCode, _>(warp::reject::not_found()) })
+}
+
+// == Shutdown ==
+
+/// Environment variable with the number of milliseconds in-flight requests
+/// are given to finish after a shutdown signal is received.
+const DRAIN_TIMEOUT_ENV: &str = "DRAIN_TIMEOUT_MS";
+
+/// Default time in-flight requests are given to finish.
+const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_millis(30_000);
+
+/// Resolves once the process receives `SIGTERM` or `SIGINT`.
+pub async fn shutdown_signal() {
+    let interrupt = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            log::error!("Failed to listen for SIGINT: {e}");
+
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                log::error!("Failed to listen for SIGTERM: {e}");
+
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = interrupt => (),
+        _ = terminate => (),
+    }
+}
+
+/// Returns a future that resolves on [shutdown_signal] and a future that
+/// resolves once the drain timeout elapses after the signal was received.
+///
+/// **This is an internal function used by [bootstrap!] macro and should not be
+/// used directly.**
+#[doc(hidden)]
+pub fn shutdown_signals_() -> (
+    impl Future<Output = ()> + Send + 'static,
+    impl Future<Output = ()> + Send + 'static,
+) {
+    let (signal_tx, signal_rx) = tokio::sync::oneshot::channel::<()>();
+    let drain_timeout = drain_timeout(std::env::var(DRAIN_TIMEOUT_ENV).ok());
+
+    let shutdown = async move {
+        shutdown_signal().await;
+
+        log::info!("Shutting down, draining in-flight requests for {drain_timeout:?}");
+
+        let _ = signal_tx.send(());
+    };
+
+    let drain_deadline = async move {
+        match signal_rx.await {
+            Ok(()) => tokio::time::sleep(drain_timeout).await,
+            // The server stopped without a shutdown signal.
+            Err(_) => std::future::pending().await,
+        }
+    };
+
+    (shutdown, drain_deadline)
+}
+
+/// Run the server until it has drained all in-flight requests or until the
+/// drain deadline, whichever comes first.
+///
+/// **This is an internal function used by [bootstrap!] macro and should not be
+/// used directly.**
+#[doc(hidden)]
+pub async fn serve_until_drained_(
+    server: impl Future<Output = ()>,
+    drain_deadline: impl Future<Output = ()>,
+) {
+    tokio::select! {
+        _ = server => (),
+        _ = drain_deadline => log::warn!("Drain timeout elapsed, dropping in-flight requests"),
+    }
+}
+
+/// Parse the drain timeout in milliseconds, falling back to the default.
+fn drain_timeout(millis: Option<String>) -> Duration {
+    let Some(millis) = millis else {
+        return DEFAULT_DRAIN_TIMEOUT;
+    };
+
+    match millis.trim().parse::<u64>() {
+        Ok(millis) => Duration::from_millis(millis),
+        Err(_) => {
+            log::warn!("Invalid {DRAIN_TIMEOUT_ENV} '{millis}', using the default");
+
+            DEFAULT_DRAIN_TIMEOUT
+        }
+    }
+}
+
+// == Metrics ==
+
+/// This function generates the `/metrics` route that exposes the metrics of
+/// all tools in the Prometheus text format. The route is only served when the
+/// `metrics` feature is enabled.
+///
+/// **This is an internal function used by [bootstrap!] macro and should not be
+/// used directly.**
+#[doc(hidden)]
+#[cfg(feature = "metrics")]
+pub fn metrics_route_() -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    warp::get()
+        .and(warp::path("metrics"))
+        .and(warp::path::end())
+        .map(|| match metrics::encode() {
+            Ok(body) => warp::reply::with_status(
+                warp::reply::with_header(body, "content-type", prometheus::TEXT_FORMAT),
+                StatusCode::OK,
+            ),
+            Err(e) => warp::reply::with_status(
+                warp::reply::with_header(e.to_string(), "content-type", "text/plain"),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+        })
+}
+
+/// See [metrics_route_]. Rejects all requests as the `metrics` feature is
+/// disabled.
+#[doc(hidden)]
+#[cfg(not(feature = "metrics"))]
+pub fn metrics_route_() -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    warp::any().and_then(|| async { Err::<StatusCode, _>(warp::reject::not_found()) })
+}
+
+#[cfg(feature = "metrics")]
+mod metrics {
+    use {
+        crate::middleware::is_err_variant,
+        nexus_sdk::ToolFqn,
+        prometheus::{
+            Encoder,
+            HistogramOpts,
+            HistogramVec,
+            IntCounterVec,
+            Opts,
+            Registry,
+            TextEncoder,
+        },
+        std::{sync::OnceLock, time::Duration},
+        warp::http::StatusCode,
+    };
+
+    struct Metrics {
+        registry: Registry,
+        invocations: IntCounterVec,
+        invocation_duration: HistogramVec,
+        health_checks: IntCounterVec,
+    }
+
+    /// Metrics are shared by all tools served by the process.
+    fn metrics() -> &'static Metrics {
+        static METRICS: OnceLock<Metrics> = OnceLock::new();
+
+        METRICS.get_or_init(|| {
+            let registry = Registry::new();
+
+            let invocations = IntCounterVec::new(
+                Opts::new("nexus_invocations_total", "Number of tool invocations."),
+                &["fqn", "status"],
+            )
+            .expect("Metric must be valid");
+
+            let invocation_duration = HistogramVec::new(
+                HistogramOpts::new(
+                    "nexus_invocation_duration_seconds",
+                    "Duration of tool invocations in seconds.",
+                ),
+                &["fqn"],
+            )
+            .expect("Metric must be valid");
+
+            let health_checks = IntCounterVec::new(
+                Opts::new("nexus_health_check_total", "Number of tool health checks."),
+                &["fqn", "status"],
+            )
+            .expect("Metric must be valid");
+
+            registry
+                .register(Box::new(invocations.clone()))
+                .expect("Metric must be registered once");
+            registry
+                .register(Box::new(invocation_duration.clone()))
+                .expect("Metric must be registered once");
+            registry
+                .register(Box::new(health_checks.clone()))
+                .expect("Metric must be registered once");
+
+            Metrics {
+                registry,
+                invocations,
+                invocation_duration,
+                health_checks,
+            }
+        })
+    }
+
+    /// An invocation fails if it did not reply with `200 OK` or if the tool
+    /// returned its `err` output variant.
+    pub(super) fn record_invocation(
+        fqn: &ToolFqn,
+        status: StatusCode,
+        reply: &serde_json::Value,
+        elapsed: Duration,
+    ) {
+        let fqn = fqn.to_string();
+        let outcome = match status == StatusCode::OK && !is_err_variant(reply) {
+            true => "ok",
+            false => "err",
+        };
+
+        let metrics = metrics();
+
+        metrics
+            .invocations
+            .with_label_values(&[fqn.as_str(), outcome])
+            .inc();
+        metrics
+            .invocation_duration
+            .with_label_values(&[fqn.as_str()])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub(super) fn record_health_check(fqn: &ToolFqn, status: StatusCode) {
+        let fqn = fqn.to_string();
+        let outcome = match status == StatusCode::OK {
+            true => "ok",
+            false => "err",
+        };
+
+        metrics()
+            .health_checks
+            .with_label_values(&[fqn.as_str(), outcome])
+            .inc();
+    }
+
+    /// Encode all metrics in the Prometheus text format.
+    pub(super) fn encode() -> prometheus::Result<String> {
+        TextEncoder::new().encode_to_string(&metrics().registry.gather())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_current_request_id() {
+        assert_eq!(current_request_id(), None);
+
+        let request_id = REQUEST_ID
+            .scope("request-id".to_string(), async { current_request_id() })
+            .await;
+
+        assert_eq!(request_id.as_deref(), Some("request-id"));
+
+        assert_eq!(current_request_id(), None);
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(
+            percent_decode("xyz.dummy.tool%401").as_deref(),
+            Some("xyz.dummy.tool@1")
+        );
+        assert_eq!(
+            percent_decode("xyz.dummy.tool@1").as_deref(),
+            Some("xyz.dummy.tool@1")
+        );
+        assert_eq!(percent_decode("xyz%4"), None);
+        assert_eq!(percent_decode("xyz%zz"), None);
+    }
+
+    #[test]
+    fn test_drain_timeout() {
+        assert_eq!(drain_timeout(None), DEFAULT_DRAIN_TIMEOUT);
+        assert_eq!(
+            drain_timeout(Some("1500".to_string())),
+            Duration::from_millis(1500)
+        );
+        assert_eq!(
+            drain_timeout(Some("soon".to_string())),
+            DEFAULT_DRAIN_TIMEOUT
+        );
+    }
+
+    #[tokio::test]
+    async fn test_serve_until_drained() {
+        // Server finishes draining before the deadline.
+        serve_until_drained_(async {}, std::future::pending()).await;
+
+        // Deadline elapses while requests are still in-flight.
+        serve_until_drained_(std::future::pending(), async {}).await;
+    }
+}
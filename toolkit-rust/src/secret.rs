@@ -67,6 +67,12 @@ where
     }
 }
 
+impl<T: Clone, E: EncryptionStrategy> Clone for Secret<T, E> {
+    fn clone(&self) -> Self {
+        Secret(self.0.clone(), PhantomData)
+    }
+}
+
 impl<T> Deref for Secret<T> {
     type Target = T;
 
@@ -82,6 +88,10 @@ impl<T> DerefMut for Secret<T> {
 }
 
 impl<T> Secret<T> {
+    pub fn new(value: T) -> Self {
+        Secret(value, PhantomData)
+    }
+
     pub fn into_inner(self) -> T {
         self.0
     }
@@ -1,8 +1,14 @@
-use serde::{
-    de::{DeserializeOwned, Deserializer},
-    Deserialize,
-    Serialize,
-    Serializer,
+use {
+    schemars::{JsonSchema, Schema, SchemaGenerator},
+    serde::{
+        de::{self, DeserializeOwned, Deserializer, Visitor},
+        forward_to_deserialize_any,
+        Deserialize,
+        Serialize,
+        Serializer,
+    },
+    serde_path_to_error::Segment,
+    std::{borrow::Cow, collections::BTreeSet, ops::Deref},
 };
 
 /// A generic wrapper type that transparently serializes and deserializes the inner type `T`.
@@ -39,6 +45,220 @@ where
     }
 }
 
+/// Input that could not be deserialized, with the location of the offending
+/// value so that DAG authors don't have to search the whole input for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputError {
+    /// JSON pointer (RFC 6901) to the offending value, e.g. `/context/0/role`.
+    /// Empty if the error concerns the whole input.
+    pub pointer: String,
+    /// Name of the field if the value was not expected at all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unknown_field: Option<String>,
+    /// The error reported by the deserializer.
+    pub message: String,
+}
+
+impl std::fmt::Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.pointer.is_empty() {
+            true => write!(f, "{}", self.message),
+            false => write!(f, "{} at '{}'", self.message, self.pointer),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Deserialize `value` into `T`, reporting the JSON pointer of the value that
+/// failed.
+///
+/// Unknown fields are only reported for types with
+/// `#[serde(deny_unknown_fields)]` and only the first one is found as
+/// deserialization stops at the first error. Inside `#[serde(flatten)]` and
+/// untagged enums the pointer ends at the buffered value.
+pub fn from_value_tracked<T: DeserializeOwned>(value: serde_json::Value) -> Result<T, InputError> {
+    serde_path_to_error::deserialize(value).map_err(|e| {
+        let mut pointer = json_pointer(e.path().iter());
+        let message = e.inner().to_string();
+        let unknown_field = unknown_field(&message);
+
+        // The pointer should already end in the unknown field, but make sure
+        // it does as that depends on where the deserializer raised the error.
+        if let Some(field) = &unknown_field {
+            let last = format!("/{}", escape_pointer_token(field));
+
+            if !pointer.ends_with(&last) {
+                pointer.push_str(&last);
+            }
+        }
+
+        InputError {
+            pointer,
+            unknown_field,
+            message,
+        }
+    })
+}
+
+/// Format a path tracked by [serde_path_to_error] as a JSON pointer.
+fn json_pointer<'a>(segments: impl Iterator<Item = &'a Segment>) -> String {
+    segments
+        .filter_map(|segment| match segment {
+            Segment::Seq { index } => Some(index.to_string()),
+            Segment::Map { key } => Some(escape_pointer_token(key)),
+            // Enum variants and unknown segments have no counterpart in the
+            // JSON document.
+            Segment::Enum { .. } | Segment::Unknown => None,
+        })
+        .fold(String::new(), |pointer, token| format!("{pointer}/{token}"))
+}
+
+/// Escape `~` and `/` in a JSON pointer reference token.
+fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Extract the field name from serde's "unknown field `name`, expected ..."
+/// error message.
+fn unknown_field(message: &str) -> Option<String> {
+    message
+        .strip_prefix("unknown field `")?
+        .split_once('`')
+        .map(|(field, _)| field.to_string())
+}
+
+/// A wrapper that deserializes `T` and records which of its fields were
+/// missing from the input and therefore fell back to their default value.
+///
+/// Only the top-level fields of `T` are considered and only if `T` is a
+/// struct without `#[serde(flatten)]` fields. The input is buffered as a
+/// [serde_json::Value] to find the missing fields, so only use the wrapper
+/// where the set is needed.
+#[derive(Debug)]
+pub struct WithAppliedDefaults<T> {
+    value: T,
+    applied_defaults: BTreeSet<String>,
+}
+
+impl<T> WithAppliedDefaults<T> {
+    /// Fields of `T` that were not provided and got their default value.
+    pub fn applied_defaults(&self) -> &BTreeSet<String> {
+        &self.applied_defaults
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for WithAppliedDefaults<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<'de, T> Deserialize<'de> for WithAppliedDefaults<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let input = serde_json::Value::deserialize(deserializer)?;
+        let applied_defaults = applied_defaults::<T>(&input);
+        let value = T::deserialize(input).map_err(de::Error::custom)?;
+
+        Ok(WithAppliedDefaults {
+            value,
+            applied_defaults,
+        })
+    }
+}
+
+impl<T> Serialize for WithAppliedDefaults<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+/// The wrapper is transparent in the input schema of a tool.
+impl<T: JsonSchema> JsonSchema for WithAppliedDefaults<T> {
+    fn schema_name() -> Cow<'static, str> {
+        T::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        T::json_schema(gen)
+    }
+}
+
+/// Fields of the struct `T` that are missing from `input`. If `input` then
+/// deserializes into `T`, these are exactly the fields that fell back to
+/// their default value.
+pub fn applied_defaults<T: DeserializeOwned>(input: &serde_json::Value) -> BTreeSet<String> {
+    let Some(input) = input.as_object() else {
+        return BTreeSet::new();
+    };
+
+    struct_fields::<T>()
+        .iter()
+        .filter(|field| !input.contains_key(**field))
+        .map(|field| field.to_string())
+        .collect()
+}
+
+/// Names of the fields of the struct `T` as they appear in the input.
+///
+/// Deserializes `T` from a deserializer that only captures the field names
+/// passed to [Deserializer::deserialize_struct] and then bails.
+fn struct_fields<T: DeserializeOwned>() -> &'static [&'static str] {
+    struct FieldsDeserializer<'a> {
+        fields: &'a mut &'static [&'static str],
+    }
+
+    impl<'de> Deserializer<'de> for FieldsDeserializer<'_> {
+        type Error = de::value::Error;
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map enum identifier ignored_any
+        }
+
+        fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+            Err(de::Error::custom("not a struct"))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _: &'static str,
+            fields: &'static [&'static str],
+            _: V,
+        ) -> Result<V::Value, Self::Error> {
+            *self.fields = fields;
+
+            Err(de::Error::custom("fields captured"))
+        }
+    }
+
+    let mut fields: &'static [&'static str] = &[];
+    let _ = T::deserialize(FieldsDeserializer {
+        fields: &mut fields,
+    });
+
+    fields
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -133,4 +353,133 @@ mod tests {
             error_str
         );
     }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[allow(dead_code)]
+    struct Message {
+        role: String,
+        content: String,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[allow(dead_code)]
+    struct Prompt {
+        context: Vec<Message>,
+        #[serde(default)]
+        headers: std::collections::HashMap<String, Message>,
+    }
+
+    #[test]
+    fn test_from_value_tracked_unknown_field() {
+        let value = serde_json::json!({
+            "context": [
+                { "role": "user", "content": "hi" },
+                { "role": "user", "content": "hi", "name": "extra" },
+            ],
+        });
+
+        let err = from_value_tracked::<Prompt>(value).unwrap_err();
+
+        assert_eq!(err.pointer, "/context/1/name");
+        assert_eq!(err.unknown_field.as_deref(), Some("name"));
+        assert!(err.message.starts_with("unknown field `name`"));
+        assert!(err.to_string().ends_with("at '/context/1/name'"));
+
+        // Top-level unknown fields point at the field itself.
+        let err = from_value_tracked::<Prompt>(serde_json::json!({ "context": [], "extra": 1 }))
+            .unwrap_err();
+
+        assert_eq!(err.pointer, "/extra");
+        assert_eq!(err.unknown_field.as_deref(), Some("extra"));
+    }
+
+    #[test]
+    fn test_from_value_tracked_invalid_value() {
+        let value = serde_json::json!({
+            "context": [{ "role": 1, "content": "hi" }],
+        });
+
+        let err = from_value_tracked::<Prompt>(value).unwrap_err();
+
+        assert_eq!(err.pointer, "/context/0/role");
+        assert_eq!(err.unknown_field, None);
+
+        // Map keys are escaped.
+        let value = serde_json::json!({
+            "context": [],
+            "headers": { "a/b~c": { "role": "user", "content": "hi", "x": 1 } },
+        });
+
+        let err = from_value_tracked::<Prompt>(value).unwrap_err();
+
+        assert_eq!(err.pointer, "/headers/a~1b~0c/x");
+
+        // Missing fields concern the enclosing object.
+        let err = from_value_tracked::<Prompt>(serde_json::json!({})).unwrap_err();
+
+        assert_eq!(err.pointer, "");
+        assert_eq!(err.to_string(), "missing field `context`");
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    #[serde(deny_unknown_fields)]
+    struct Completion {
+        prompt: String,
+        #[serde(default = "default_model")]
+        model: String,
+        #[serde(default)]
+        temperature: f32,
+        #[serde(rename = "maxTokens", default)]
+        max_tokens: Option<u32>,
+    }
+
+    fn default_model() -> String {
+        "gpt-4o-mini".to_string()
+    }
+
+    #[test]
+    fn test_with_applied_defaults() {
+        let input: WithAppliedDefaults<Completion> = serde_json::from_value(serde_json::json!({
+            "prompt": "hi",
+            "temperature": 0.5,
+        }))
+        .unwrap();
+
+        assert_eq!(input.model, "gpt-4o-mini");
+        assert_eq!(input.temperature, 0.5);
+        assert_eq!(
+            input.applied_defaults(),
+            &BTreeSet::from(["model".to_string(), "maxTokens".to_string()])
+        );
+
+        // Explicitly provided fields are not reported even if they equal the
+        // default.
+        let input: WithAppliedDefaults<Completion> = serde_json::from_value(serde_json::json!({
+            "prompt": "hi",
+            "model": "gpt-4o-mini",
+            "temperature": 0.0,
+            "maxTokens": null,
+        }))
+        .unwrap();
+
+        assert!(input.applied_defaults().is_empty());
+
+        // Serializes and deserializes as the inner type.
+        assert_eq!(
+            serde_json::to_value(&input).unwrap()["model"],
+            "gpt-4o-mini"
+        );
+        assert!(
+            serde_json::from_value::<WithAppliedDefaults<Completion>>(serde_json::json!({}))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_applied_defaults_not_a_struct() {
+        assert!(applied_defaults::<Vec<String>>(&serde_json::json!(["a"])).is_empty());
+        assert!(applied_defaults::<Completion>(&serde_json::json!("prompt")).is_empty());
+    }
 }
@@ -45,6 +45,33 @@ impl NexusTool for DummyTool {
     }
 }
 
+struct EchoTool;
+
+impl NexusTool for EchoTool {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.dummy.echo@1")
+    }
+
+    fn path() -> &'static str {
+        "echo"
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, Self::Input { prompt }: Self::Input) -> Self::Output {
+        Output::Ok { message: prompt }
+    }
+}
+
 struct DummyErrTool;
 
 impl NexusTool for DummyErrTool {
@@ -74,6 +101,227 @@ impl NexusTool for DummyErrTool {
     }
 }
 
+struct RequestIdTool;
+
+impl NexusTool for RequestIdTool {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.dummy.request-id@1")
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, _: Self::Input) -> Self::Output {
+        match current_request_id() {
+            Some(request_id) => Output::Ok {
+                message: request_id,
+            },
+            None => Output::Err {
+                reason: "Request ID is not set".to_string(),
+            },
+        }
+    }
+}
+
+/// Records the order in which middleware hooks ran.
+static MIDDLEWARE_CALLS: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+
+struct RecordingMiddleware(&'static str);
+
+impl Middleware<MiddlewareTool> for RecordingMiddleware {
+    async fn before(&self, input: &Input) -> Result<(), StatusCode> {
+        MIDDLEWARE_CALLS
+            .lock()
+            .unwrap()
+            .push(format!("{} before {}", self.0, input.prompt));
+
+        Ok(())
+    }
+
+    async fn after(&self, input: &Input, _: &Output) {
+        MIDDLEWARE_CALLS
+            .lock()
+            .unwrap()
+            .push(format!("{} after {}", self.0, input.prompt));
+    }
+}
+
+struct RejectEmptyPrompt;
+
+impl Middleware<MiddlewareTool> for RejectEmptyPrompt {
+    async fn before(&self, input: &Input) -> Result<(), StatusCode> {
+        match input.prompt.is_empty() {
+            true => Err(StatusCode::UNAUTHORIZED),
+            false => Ok(()),
+        }
+    }
+}
+
+struct MiddlewareTool;
+
+impl NexusTool for MiddlewareTool {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.dummy.middleware@1")
+    }
+
+    fn middleware() -> MiddlewareStack<Self> {
+        MiddlewareStack::new()
+            .with_middleware(RecordingMiddleware("first"))
+            .with_middleware(RejectEmptyPrompt)
+            .with_middleware(RecordingMiddleware("second"))
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, Self::Input { prompt }: Self::Input) -> Self::Output {
+        MIDDLEWARE_CALLS
+            .lock()
+            .unwrap()
+            .push(format!("invoke {prompt}"));
+
+        Output::Ok {
+            message: format!("You said: {}", prompt),
+        }
+    }
+}
+
+struct RateLimitedTool;
+
+impl NexusTool for RateLimitedTool {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.dummy.rate-limited@1")
+    }
+
+    fn middleware() -> MiddlewareStack<Self> {
+        MiddlewareStack::new().with_middleware(RateLimiter::per_ip(0.001, 1))
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, Self::Input { prompt }: Self::Input) -> Self::Output {
+        Output::Ok { message: prompt }
+    }
+}
+
+/// Whether [ValidatingTool::invoke] ran.
+static VALIDATING_TOOL_INVOKED: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
+
+struct ValidatingTool;
+
+impl NexusTool for ValidatingTool {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.dummy.validating@1")
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    fn validate(&self, input: &Self::Input) -> Result<(), ToolValidationError> {
+        match input.prompt.trim().is_empty() {
+            true => Err(ToolValidationError::new("Prompt must not be blank").at("/prompt")),
+            false => Ok(()),
+        }
+    }
+
+    async fn invoke(&self, Self::Input { prompt }: Self::Input) -> Self::Output {
+        VALIDATING_TOOL_INVOKED.store(true, std::sync::atomic::Ordering::SeqCst);
+
+        Output::Ok { message: prompt }
+    }
+}
+
+struct SlowTool;
+
+impl NexusTool for SlowTool {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.dummy.slow@1")
+    }
+
+    fn max_concurrency() -> Option<usize> {
+        Some(1)
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, Self::Input { prompt }: Self::Input) -> Self::Output {
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+
+        Output::Ok { message: prompt }
+    }
+}
+
+#[cfg(feature = "metrics")]
+struct MetricsTool;
+
+#[cfg(feature = "metrics")]
+impl NexusTool for MetricsTool {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.dummy.metrics@1")
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, Self::Input { prompt }: Self::Input) -> Self::Output {
+        match prompt.as_str() {
+            "fail" => Output::Err { reason: prompt },
+            _ => Output::Ok { message: prompt },
+        }
+    }
+}
+
 // == Integration tests ==
 
 #[cfg(test)]
@@ -155,6 +403,83 @@ mod tests {
         let invoke_json = invoke.json::<serde_json::Value>().await.unwrap();
 
         assert_eq!(invoke_json["error"], "input_deserialization_error");
+        assert_eq!(invoke_json["validation"]["pointer"], "");
+        assert_eq!(
+            invoke_json["validation"]["message"],
+            "missing field `prompt`"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_400_when_input_invalid() {
+        tokio::spawn(async move { bootstrap!(([127, 0, 0, 1], 8055), ValidatingTool) });
+
+        // Give the webserver some time to start.
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let invoke = Client::new()
+            .post("http://localhost:8055/invoke")
+            .json(&json!({ "prompt": "  " }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(invoke.status(), 400);
+
+        let invoke_json = invoke.json::<serde_json::Value>().await.unwrap();
+
+        assert_eq!(invoke_json["error"], "input_validation_error");
+        assert_eq!(invoke_json["validation"]["pointer"], "/prompt");
+        assert_eq!(
+            invoke_json["validation"]["message"],
+            "Prompt must not be blank"
+        );
+        assert!(!VALIDATING_TOOL_INVOKED.load(std::sync::atomic::Ordering::SeqCst));
+
+        // Valid input is passed on to the tool.
+        let invoke = Client::new()
+            .post("http://localhost:8055/invoke")
+            .json(&json!({ "prompt": "Hello, world!" }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(invoke.status(), 200);
+        assert!(VALIDATING_TOOL_INVOKED.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_429_when_concurrency_limit_reached() {
+        tokio::spawn(async move { bootstrap!(([127, 0, 0, 1], 8056), SlowTool) });
+
+        // Give the webserver some time to start.
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let invoke = || async {
+            Client::new()
+                .post("http://localhost:8056/invoke")
+                .json(&json!({ "prompt": "Hello, world!" }))
+                .send()
+                .await
+                .unwrap()
+        };
+
+        let (first, second) = tokio::join!(invoke(), invoke());
+        let mut statuses = [first.status().as_u16(), second.status().as_u16()];
+        statuses.sort();
+
+        assert_eq!(statuses, [200, 429]);
+
+        let rejected = match first.status().as_u16() {
+            429 => first,
+            _ => second,
+        };
+        let rejected_json = rejected.json::<serde_json::Value>().await.unwrap();
+
+        assert_eq!(rejected_json["error"], "concurrency_limit_reached");
+
+        // The permit is released once the invocation finishes.
+        assert_eq!(invoke().await.status(), 200);
     }
 
     #[tokio::test]
@@ -232,4 +557,473 @@ mod tests {
 
         assert_eq!(invoke_json["error"], "input_deserialization_error");
     }
+
+    #[tokio::test]
+    async fn test_invoke_by_fqn() {
+        tokio::spawn(async move { bootstrap!(([127, 0, 0, 1], 8057), [DummyTool, EchoTool]) });
+
+        // Give the webserver some time to start.
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        // The `@` may be sent as is or percent-encoded.
+        for (fqn, message) in [
+            ("xyz.dummy.tool@1", "You said: Hello, world!"),
+            ("xyz.dummy.echo%401", "Hello, world!"),
+        ] {
+            let invoke = Client::new()
+                .post(format!("http://localhost:8057/invoke/{fqn}"))
+                .json(&json!({ "prompt": "Hello, world!" }))
+                .send()
+                .await
+                .unwrap();
+
+            assert_eq!(invoke.status(), 200);
+            assert!(invoke.headers().contains_key(REQUEST_ID_HEADER));
+
+            let invoke_json = invoke.json::<Output>().await.unwrap();
+
+            assert_eq!(
+                invoke_json,
+                Output::Ok {
+                    message: message.to_string(),
+                }
+            );
+        }
+
+        // The root tool is still served on its own invoke route.
+        let invoke = Client::new()
+            .post("http://localhost:8057/invoke")
+            .json(&json!({ "prompt": "Hello, world!" }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(invoke.status(), 200);
+
+        // Unregistered FQN.
+        let invoke = Client::new()
+            .post("http://localhost:8057/invoke/xyz.dummy.missing@1")
+            .json(&json!({ "prompt": "Hello, world!" }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(invoke.status(), 404);
+
+        let invoke_json = invoke.json::<serde_json::Value>().await.unwrap();
+
+        assert_eq!(invoke_json["error"], "tool_not_found");
+    }
+
+    #[tokio::test]
+    async fn test_request_id_header() {
+        tokio::spawn(async move { bootstrap!(([127, 0, 0, 1], 8047), RequestIdTool) });
+
+        // Give the webserver some time to start.
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        // Provided request ID is exposed to the tool and echoed back.
+        let invoke = Client::new()
+            .post("http://localhost:8047/invoke")
+            .header(REQUEST_ID_HEADER, "my-request-id")
+            .json(&json!({ "prompt": "Hello, world!" }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(invoke.status(), 200);
+        assert_eq!(invoke.headers()[REQUEST_ID_HEADER], "my-request-id");
+
+        let invoke_json = invoke.json::<Output>().await.unwrap();
+
+        assert_eq!(
+            invoke_json,
+            Output::Ok {
+                message: "my-request-id".to_string(),
+            }
+        );
+
+        // Request ID is generated if none is provided.
+        let invoke = Client::new()
+            .post("http://localhost:8047/invoke")
+            .json(&json!({ "prompt": "Hello, world!" }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(invoke.status(), 200);
+
+        let generated_id = invoke.headers()[REQUEST_ID_HEADER]
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        assert_eq!(generated_id.len(), 36);
+
+        let invoke_json = invoke.json::<Output>().await.unwrap();
+
+        assert_eq!(
+            invoke_json,
+            Output::Ok {
+                message: generated_id,
+            }
+        );
+
+        // Request ID is also echoed on malformed input.
+        let invoke = Client::new()
+            .post("http://localhost:8047/invoke")
+            .header(REQUEST_ID_HEADER, "malformed-request-id")
+            .json(&json!({ "invalid": "Hello, world!" }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(invoke.status(), 422);
+        assert_eq!(invoke.headers()[REQUEST_ID_HEADER], "malformed-request-id");
+
+        // Request ID is not set outside of an invocation.
+        assert!(current_request_id().is_none());
+    }
+
+    #[cfg(feature = "batch")]
+    #[tokio::test]
+    async fn test_batch_invocation() {
+        tokio::spawn(async move { bootstrap!(([127, 0, 0, 1], 8048), [DummyTool, RequestIdTool]) });
+
+        // Give the webserver some time to start.
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let batch = Client::new()
+            .post("http://localhost:8048/batch")
+            .header(REQUEST_ID_HEADER, "batch-request-id")
+            .json(&json!([
+                { "fqn": "xyz.dummy.tool@1", "input": { "prompt": "Hello, world!" } },
+                { "fqn": "xyz.dummy.request-id@1", "input": { "invalid": "Hello, world!" } },
+                { "fqn": "xyz.dummy.request-id@1", "input": { "prompt": "Hello, world!" } },
+                { "fqn": "xyz.dummy.unknown@1", "input": { "prompt": "Hello, world!" } },
+                { "input": { "prompt": "Hello, world!" } },
+            ]))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(batch.status(), 200);
+        assert_eq!(batch.headers()[REQUEST_ID_HEADER], "batch-request-id");
+
+        let results = batch.json::<Vec<serde_json::Value>>().await.unwrap();
+
+        assert_eq!(results.len(), 5);
+
+        // Results are in the same order as the entries.
+        assert_eq!(results[0]["status"], 200);
+        assert_eq!(
+            serde_json::from_value::<Output>(results[0]["body"].clone()).unwrap(),
+            Output::Ok {
+                message: "You said: Hello, world!".to_string(),
+            }
+        );
+
+        // Invalid input only fails its own entry.
+        assert_eq!(results[1]["status"], 422);
+        assert_eq!(results[1]["body"]["error"], "input_deserialization_error");
+
+        // Request ID is shared by all entries.
+        assert_eq!(results[2]["status"], 200);
+        assert_eq!(
+            serde_json::from_value::<Output>(results[2]["body"].clone()).unwrap(),
+            Output::Ok {
+                message: "batch-request-id".to_string(),
+            }
+        );
+
+        assert_eq!(results[3]["status"], 404);
+        assert_eq!(results[3]["body"]["error"], "tool_not_found");
+
+        assert_eq!(results[4]["status"], 400);
+        assert_eq!(
+            results[4]["body"]["error"],
+            "batch_entry_deserialization_error"
+        );
+    }
+
+    #[cfg(feature = "batch")]
+    #[tokio::test]
+    async fn test_batch_invocation_by_path() {
+        // Both tools share an FQN and can only be told apart by their path.
+        tokio::spawn(async move { bootstrap!(([127, 0, 0, 1], 8054), [DummyTool, DummyErrTool]) });
+
+        // Give the webserver some time to start.
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let batch = Client::new()
+            .post("http://localhost:8054/batch")
+            .json(&json!([
+                { "tool_path": "/path/", "input": { "prompt": "Hello, world!" } },
+                { "tool_path": "", "input": { "prompt": "Hello, world!" } },
+                { "tool_path": "unknown", "input": { "prompt": "Hello, world!" } },
+                { "fqn": "xyz.dummy.tool@1", "tool_path": "path", "input": { "prompt": "Hello, world!" } },
+            ]))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(batch.status(), 200);
+
+        let results = batch.json::<Vec<serde_json::Value>>().await.unwrap();
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0]["status"], 200);
+        assert_eq!(
+            serde_json::from_value::<Output>(results[0]["body"].clone()).unwrap(),
+            Output::Err {
+                reason: "Something went wrong".to_string(),
+            }
+        );
+        assert_eq!(results[1]["status"], 200);
+        assert_eq!(
+            serde_json::from_value::<Output>(results[1]["body"].clone()).unwrap(),
+            Output::Ok {
+                message: "You said: Hello, world!".to_string(),
+            }
+        );
+        assert_eq!(results[2]["status"], 404);
+        assert_eq!(results[2]["body"]["error"], "tool_not_found");
+        assert_eq!(results[3]["status"], 400);
+    }
+
+    #[tokio::test]
+    async fn test_middleware() {
+        // Registered after the middleware returned by `MiddlewareTool::middleware`.
+        MiddlewareTool::new()
+            .await
+            .with_middleware(RecordingMiddleware("third"));
+
+        tokio::spawn(async move { bootstrap!(([127, 0, 0, 1], 8049), MiddlewareTool) });
+
+        // Give the webserver some time to start.
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let invoke = Client::new()
+            .post("http://localhost:8049/invoke")
+            .json(&json!({ "prompt": "hi" }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(invoke.status(), 200);
+        assert_eq!(
+            invoke.json::<Output>().await.unwrap(),
+            Output::Ok {
+                message: "You said: hi".to_string(),
+            }
+        );
+
+        // Middleware runs in registration order around the invocation.
+        assert_eq!(
+            std::mem::take(&mut *MIDDLEWARE_CALLS.lock().unwrap()),
+            vec![
+                "first before hi",
+                "second before hi",
+                "third before hi",
+                "invoke hi",
+                "first after hi",
+                "second after hi",
+                "third after hi",
+            ]
+        );
+
+        let invoke = Client::new()
+            .post("http://localhost:8049/invoke")
+            .json(&json!({ "prompt": "" }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(invoke.status(), 401);
+
+        let json = invoke.json::<serde_json::Value>().await.unwrap();
+
+        assert_eq!(json["error"], "rejected_by_middleware");
+
+        // Rejection short-circuits the remaining middleware and the invocation.
+        assert_eq!(
+            std::mem::take(&mut *MIDDLEWARE_CALLS.lock().unwrap()),
+            vec!["first before "]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_per_ip() {
+        tokio::spawn(async move { bootstrap!(([127, 0, 0, 1], 8050), RateLimitedTool) });
+
+        // Give the webserver some time to start.
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let invoke = |forwarded_for: &'static str| async move {
+            Client::new()
+                .post("http://localhost:8050/invoke")
+                .header("X-Forwarded-For", forwarded_for)
+                .json(&json!({ "prompt": "hi" }))
+                .send()
+                .await
+                .unwrap()
+                .status()
+        };
+
+        assert_eq!(invoke("10.0.0.1").await, 200);
+        // The bucket is keyed on the client address, not the proxies.
+        assert_eq!(invoke("10.0.0.1, 10.0.0.100").await, 429);
+        assert_eq!(invoke("10.0.0.2").await, 200);
+
+        // The bucket is shared with the routes that invoke the tool by FQN.
+        let invoke_by_fqn = Client::new()
+            .post("http://localhost:8050/invoke/xyz.dummy.rate-limited@1")
+            .header("X-Forwarded-For", "10.0.0.2")
+            .json(&json!({ "prompt": "hi" }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(invoke_by_fqn.status(), 429);
+
+        #[cfg(feature = "batch")]
+        {
+            let batch = Client::new()
+                .post("http://localhost:8050/batch")
+                .header("X-Forwarded-For", "10.0.0.2")
+                .json(&json!([{ "fqn": "xyz.dummy.rate-limited@1", "input": { "prompt": "hi" } }]))
+                .send()
+                .await
+                .unwrap()
+                .json::<serde_json::Value>()
+                .await
+                .unwrap();
+
+            assert_eq!(batch[0]["status"], 429);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_schema_endpoints() {
+        tokio::spawn(async move { bootstrap!(([127, 0, 0, 1], 8051), [DummyTool, DummyErrTool]) });
+
+        // Give the webserver some time to start.
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let schema = Client::new()
+            .get("http://localhost:8051/path/schema")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(schema.status(), 200);
+
+        let schema_json = schema.json::<serde_json::Value>().await.unwrap();
+
+        assert_eq!(schema_json["fqn"], "xyz.dummy.tool@1");
+        assert_eq!(schema_json["description"], "");
+        assert_eq!(
+            schema_json["input_schema"],
+            json!(schemars::schema_for!(Input))
+        );
+        assert_eq!(
+            schema_json["output_schema"],
+            json!(schemars::schema_for!(Output))
+        );
+        assert!(schema_json.get("url").is_none());
+
+        let schemas = Client::new()
+            .get("http://localhost:8051/schemas")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(schemas.status(), 200);
+
+        let schemas_json = schemas.json::<Vec<serde_json::Value>>().await.unwrap();
+
+        assert_eq!(schemas_json.len(), 2);
+        assert_eq!(schemas_json[1], schema_json);
+    }
+
+    #[cfg(feature = "metrics")]
+    #[tokio::test]
+    async fn test_metrics() {
+        tokio::spawn(async move { bootstrap!(([127, 0, 0, 1], 8052), MetricsTool) });
+
+        // Give the webserver some time to start.
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let client = Client::new();
+
+        for prompt in ["hi", "fail", "hello"] {
+            client
+                .post("http://localhost:8052/invoke")
+                .json(&json!({ "prompt": prompt }))
+                .send()
+                .await
+                .unwrap();
+        }
+
+        client
+            .get("http://localhost:8052/health")
+            .send()
+            .await
+            .unwrap();
+
+        let metrics = client
+            .get("http://localhost:8052/metrics")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(metrics.status(), 200);
+
+        let metrics = metrics.text().await.unwrap();
+
+        assert!(
+            metrics.contains(r#"nexus_invocations_total{fqn="xyz.dummy.metrics@1",status="ok"} 2"#)
+        );
+        assert!(metrics
+            .contains(r#"nexus_invocations_total{fqn="xyz.dummy.metrics@1",status="err"} 1"#));
+        assert!(metrics
+            .contains(r#"nexus_invocation_duration_seconds_count{fqn="xyz.dummy.metrics@1"} 3"#));
+        assert!(metrics
+            .contains(r#"nexus_health_check_total{fqn="xyz.dummy.metrics@1",status="ok"} 1"#));
+    }
+
+    #[cfg(feature = "spec")]
+    #[tokio::test]
+    async fn test_openapi() {
+        tokio::spawn(async move { bootstrap!(([127, 0, 0, 1], 8053), [DummyTool, DummyErrTool]) });
+
+        // Give the webserver some time to start.
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let openapi = Client::new()
+            .get("http://localhost:8053/openapi.json")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(openapi.status(), 200);
+
+        let openapi = openapi.json::<serde_json::Value>().await.unwrap();
+
+        assert_eq!(openapi["openapi"], "3.1.0");
+
+        for path in ["/invoke", "/path/invoke"] {
+            let operation = &openapi["paths"][path]["post"];
+
+            assert_eq!(operation["operationId"], "xyz.dummy.tool@1");
+            assert_eq!(
+                operation["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+                "#/components/schemas/xyz.dummy.tool_1.Input"
+            );
+        }
+
+        let output = &openapi["components"]["schemas"]["xyz.dummy.tool_1.Output"];
+
+        assert!(output["oneOf"].is_array());
+    }
 }
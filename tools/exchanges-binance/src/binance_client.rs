@@ -0,0 +1,94 @@
+//! Binance API client implementation
+//!
+//! This module provides a clean client for interacting with the Binance Spot
+//! API market data endpoints.
+
+use {
+    crate::{
+        error::{BinanceApiError, BinanceErrorKind, BinanceErrorResponse},
+        tools::BINANCE_API_BASE,
+    },
+    reqwest::Client,
+    serde::de::DeserializeOwned,
+    std::sync::Arc,
+};
+
+/// Binance API client for making requests
+pub struct BinanceClient {
+    /// HTTP client for making requests
+    client: Arc<Client>,
+    /// Base URL for Binance API
+    base_url: String,
+}
+
+impl BinanceClient {
+    /// Creates a new Binance client instance
+    pub fn new(base_url: Option<&str>) -> Self {
+        let base_url = base_url.unwrap_or(BINANCE_API_BASE).to_string();
+
+        let client = Client::builder()
+            .user_agent("nexus-sdk-binance-tool/1.0")
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self {
+            client: Arc::new(client),
+            base_url,
+        }
+    }
+
+    /// Makes a GET request to the specified endpoint with the given query
+    /// parameters
+    pub async fn get<T>(
+        &self,
+        endpoint: &str,
+        query: &[(&str, &str)],
+    ) -> Result<T, BinanceErrorResponse>
+    where
+        T: DeserializeOwned,
+    {
+        let url = format!("{}/{}", self.base_url, endpoint);
+
+        let response = match self.client.get(&url).query(query).send().await {
+            Ok(response) => response,
+            Err(e) => {
+                return Err(BinanceErrorResponse {
+                    reason: format!("Network error: {}", e),
+                    kind: BinanceErrorKind::from_network_error(&e),
+                    status_code: None,
+                });
+            }
+        };
+
+        let status = response.status();
+        let text = match response.text().await {
+            Ok(text) => text,
+            Err(e) => {
+                return Err(BinanceErrorResponse {
+                    reason: format!("Failed to read response: {}", e),
+                    kind: BinanceErrorKind::Parse,
+                    status_code: None,
+                });
+            }
+        };
+
+        if !status.is_success() {
+            // Try to parse the error response from Binance API
+            return match serde_json::from_str::<BinanceApiError>(&text) {
+                Ok(api_error) => Err(api_error.to_error_response(status.as_u16())),
+                // If we can't parse the error response, fallback to status code mapping
+                Err(_) => Err(BinanceErrorResponse {
+                    reason: format!("API error ({}): {}", status, text),
+                    kind: BinanceErrorKind::from_status_code(status.as_u16()),
+                    status_code: Some(status.as_u16()),
+                }),
+            };
+        }
+
+        serde_json::from_str::<T>(&text).map_err(|e| BinanceErrorResponse {
+            reason: format!("Failed to parse JSON: {}", e),
+            kind: BinanceErrorKind::Parse,
+            status_code: None,
+        })
+    }
+}
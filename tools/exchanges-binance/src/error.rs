@@ -0,0 +1,103 @@
+use {
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+/// Error kind enumeration for Binance operations
+/// Based on the Binance Spot API error documentation
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BinanceErrorKind {
+    /// Malformed request or invalid parameter (HTTP 400)
+    InvalidRequest,
+    /// The symbol does not exist (code -1121)
+    InvalidSymbol,
+    /// Request blocked by the web application firewall (HTTP 403)
+    Forbidden,
+    /// Not found (HTTP 404)
+    NotFound,
+    /// Rate limit exceeded (HTTP 429, code -1003)
+    RateLimitExceeded,
+    /// IP banned after repeatedly exceeding the rate limit (HTTP 418)
+    IpBanned,
+    /// Internal server error (HTTP 5XX)
+    InternalServerError,
+    /// Network connection failed
+    NetworkConnectionFailed,
+    /// Network timeout
+    NetworkTimeout,
+    /// Error parsing response
+    Parse,
+    /// Unknown error
+    Unknown,
+}
+
+/// A Binance API error returned by the API
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+pub struct BinanceApiError {
+    /// Binance error code (e.g., -1121 for an invalid symbol)
+    pub code: i64,
+    /// Human-readable message providing more detail
+    pub msg: String,
+}
+
+/// Standard error response structure for Binance tools
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BinanceErrorResponse {
+    /// Detailed error message
+    pub reason: String,
+    /// Type of error
+    pub kind: BinanceErrorKind,
+    /// HTTP status code if available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+}
+
+impl BinanceErrorKind {
+    /// Maps Binance API error code to our error kind
+    pub fn from_api_error_code(code: i64) -> Option<Self> {
+        match code {
+            -1003 => Some(Self::RateLimitExceeded),
+            -1121 => Some(Self::InvalidSymbol),
+            // Illegal characters, missing, unknown or malformed parameters.
+            -1106..=-1100 => Some(Self::InvalidRequest),
+            _ => None,
+        }
+    }
+
+    /// Maps HTTP status code to our error kind
+    pub fn from_status_code(status_code: u16) -> Self {
+        match status_code {
+            400 => Self::InvalidRequest,
+            403 => Self::Forbidden,
+            404 => Self::NotFound,
+            418 => Self::IpBanned,
+            429 => Self::RateLimitExceeded,
+            500..=599 => Self::InternalServerError,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Maps network error to our error kind
+    pub fn from_network_error(error: &reqwest::Error) -> Self {
+        if error.is_timeout() {
+            Self::NetworkTimeout
+        } else {
+            Self::NetworkConnectionFailed
+        }
+    }
+}
+
+impl BinanceApiError {
+    /// Converts API error to our error response
+    pub fn to_error_response(&self, status_code: u16) -> BinanceErrorResponse {
+        let kind = BinanceErrorKind::from_api_error_code(self.code)
+            .unwrap_or_else(|| BinanceErrorKind::from_status_code(status_code));
+
+        BinanceErrorResponse {
+            reason: format!("API error ({}): {}", self.code, self.msg),
+            kind,
+            status_code: Some(status_code),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+#![doc = include_str!("../README.md")]
+
+use nexus_toolkit::bootstrap;
+
+mod binance_client;
+mod error;
+mod tools;
+
+#[tokio::main]
+async fn main() {
+    bootstrap!([
+        tools::get_spot_price::GetSpotPrice,
+        tools::get_24hr_ticker::Get24hrTicker,
+    ]);
+}
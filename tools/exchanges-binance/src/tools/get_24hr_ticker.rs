@@ -0,0 +1,287 @@
+//! # `xyz.taluslabs.exchanges.binance.ticker-24hr@1`
+//!
+//! Standard Nexus Tool that retrieves the 24 hour rolling window price change
+//! statistics for a symbol from Binance.
+
+use {
+    crate::{
+        binance_client::BinanceClient,
+        error::BinanceErrorKind,
+        tools::{deserialize_symbol, models::Ticker24hrData, parse_decimal, BINANCE_API_BASE},
+    },
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// Trading pair symbol to get the statistics for (e.g., "BTCUSDT" or ["BTC", "USDT"])
+    #[serde(deserialize_with = "deserialize_symbol")]
+    symbol: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok {
+        /// The trading pair symbol (e.g., "BTCUSDT")
+        symbol: String,
+        /// Absolute price change over the window
+        price_change: f64,
+        /// Relative price change over the window in percent
+        price_change_percent: f64,
+        /// Weighted average price over the window
+        weighted_avg_price: f64,
+        /// Last price
+        last_price: f64,
+        /// Best bid price
+        bid_price: f64,
+        /// Best ask price
+        ask_price: f64,
+        /// Open price of the window
+        open_price: f64,
+        /// Highest price in the window
+        high_price: f64,
+        /// Lowest price in the window
+        low_price: f64,
+        /// Base asset volume traded in the window
+        volume: f64,
+        /// Quote asset volume traded in the window
+        quote_volume: f64,
+        /// Start of the window in milliseconds since the epoch
+        open_time: u64,
+        /// End of the window in milliseconds since the epoch
+        close_time: u64,
+        /// Number of trades in the window
+        count: u64,
+    },
+    Err {
+        /// Detailed error message
+        reason: String,
+        /// Type of error (network, rate limit, invalid symbol, etc.)
+        kind: BinanceErrorKind,
+        /// HTTP status code if available
+        #[serde(skip_serializing_if = "Option::is_none")]
+        status_code: Option<u16>,
+    },
+}
+
+pub(crate) struct Get24hrTicker {
+    client: BinanceClient,
+}
+
+impl NexusTool for Get24hrTicker {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        let client = BinanceClient::new(Some(BINANCE_API_BASE));
+        Self { client }
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.exchanges.binance.ticker-24hr@1")
+    }
+
+    fn path() -> &'static str {
+        "/ticker-24hr"
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, request: Self::Input) -> Self::Output {
+        let data = match self
+            .client
+            .get::<Ticker24hrData>("api/v3/ticker/24hr", &[("symbol", &request.symbol)])
+            .await
+        {
+            Ok(data) => data,
+            Err(error_response) => {
+                return Output::Err {
+                    reason: error_response.reason,
+                    kind: error_response.kind,
+                    status_code: error_response.status_code,
+                }
+            }
+        };
+
+        match to_output(data) {
+            Ok(output) => output,
+            Err(reason) => Output::Err {
+                reason,
+                kind: BinanceErrorKind::Parse,
+                status_code: None,
+            },
+        }
+    }
+}
+
+/// Converts the decimal strings of the API response into numbers.
+fn to_output(data: Ticker24hrData) -> Result<Output, String> {
+    Ok(Output::Ok {
+        price_change: parse_decimal("price_change", &data.price_change)?,
+        price_change_percent: parse_decimal("price_change_percent", &data.price_change_percent)?,
+        weighted_avg_price: parse_decimal("weighted_avg_price", &data.weighted_avg_price)?,
+        last_price: parse_decimal("last_price", &data.last_price)?,
+        bid_price: parse_decimal("bid_price", &data.bid_price)?,
+        ask_price: parse_decimal("ask_price", &data.ask_price)?,
+        open_price: parse_decimal("open_price", &data.open_price)?,
+        high_price: parse_decimal("high_price", &data.high_price)?,
+        low_price: parse_decimal("low_price", &data.low_price)?,
+        volume: parse_decimal("volume", &data.volume)?,
+        quote_volume: parse_decimal("quote_volume", &data.quote_volume)?,
+        symbol: data.symbol,
+        open_time: data.open_time,
+        close_time: data.close_time,
+        count: data.count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        mockito::{Matcher, Server},
+        serde_json::json,
+    };
+
+    async fn create_server_and_tool() -> (mockito::ServerGuard, Get24hrTicker) {
+        let server = Server::new_async().await;
+        let client = BinanceClient::new(Some(&server.url()));
+        let tool = Get24hrTicker { client };
+        (server, tool)
+    }
+
+    fn ticker_body(last_price: &str) -> String {
+        json!({
+            "symbol": "ETHUSDT",
+            "priceChange": "-94.99999800",
+            "priceChangePercent": "-95.960",
+            "weightedAvgPrice": "0.29628482",
+            "prevClosePrice": "0.10002000",
+            "lastPrice": last_price,
+            "lastQty": "200.00000000",
+            "bidPrice": "4.00000000",
+            "bidQty": "100.00000000",
+            "askPrice": "4.00000200",
+            "askQty": "100.00000000",
+            "openPrice": "99.00000000",
+            "highPrice": "100.00000000",
+            "lowPrice": "0.10000000",
+            "volume": "8913.30000000",
+            "quoteVolume": "15.30000000",
+            "openTime": 1499783499040u64,
+            "closeTime": 1499869899040u64,
+            "firstId": 28385,
+            "lastId": 28460,
+            "count": 76
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_successful_ticker_request() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let mock = server
+            .mock("GET", "/api/v3/ticker/24hr")
+            .match_query(Matcher::UrlEncoded("symbol".into(), "ETHUSDT".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(ticker_body("4.00000200"))
+            .create_async()
+            .await;
+
+        let input = serde_json::from_value(json!({ "symbol": "ethusdt" })).unwrap();
+
+        match tool.invoke(input).await {
+            Output::Ok {
+                symbol,
+                price_change,
+                price_change_percent,
+                last_price,
+                high_price,
+                volume,
+                open_time,
+                close_time,
+                count,
+                ..
+            } => {
+                assert_eq!(symbol, "ETHUSDT");
+                assert_eq!(price_change, -94.999998);
+                assert_eq!(price_change_percent, -95.96);
+                assert_eq!(last_price, 4.000002);
+                assert_eq!(high_price, 100.0);
+                assert_eq!(volume, 8913.3);
+                assert_eq!(open_time, 1499783499040);
+                assert_eq!(close_time, 1499869899040);
+                assert_eq!(count, 76);
+            }
+            output => panic!("Expected Ok variant, got {output:?}"),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_invalid_decimal_in_response() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let mock = server
+            .mock("GET", "/api/v3/ticker/24hr")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(ticker_body("not a number"))
+            .create_async()
+            .await;
+
+        let input = serde_json::from_value(json!({ "symbol": "ETHUSDT" })).unwrap();
+
+        match tool.invoke(input).await {
+            Output::Err { reason, kind, .. } => {
+                assert_eq!(reason, "Invalid last_price in API response: 'not a number'");
+                assert_eq!(kind, BinanceErrorKind::Parse);
+            }
+            output => panic!("Expected Err variant, got {output:?}"),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_ip_banned() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let mock = server
+            .mock("GET", "/api/v3/ticker/24hr")
+            .match_query(Matcher::Any)
+            .with_status(418)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "code": -1003, "msg": "Way too many requests; IP banned." }).to_string(),
+            )
+            .create_async()
+            .await;
+
+        let input = serde_json::from_value(json!({ "symbol": ["ETH", "USDT"] })).unwrap();
+
+        match tool.invoke(input).await {
+            Output::Err {
+                kind, status_code, ..
+            } => {
+                // The API error code takes precedence over the status code.
+                assert_eq!(kind, BinanceErrorKind::RateLimitExceeded);
+                assert_eq!(status_code, Some(418));
+            }
+            output => panic!("Expected Err variant, got {output:?}"),
+        }
+
+        mock.assert_async().await;
+    }
+}
@@ -0,0 +1,220 @@
+//! # `xyz.taluslabs.exchanges.binance.spot-price@1`
+//!
+//! Standard Nexus Tool that retrieves the latest price for a symbol from Binance.
+
+use {
+    crate::{
+        binance_client::BinanceClient,
+        error::BinanceErrorKind,
+        tools::{deserialize_symbol, models::TickerPriceData, parse_decimal, BINANCE_API_BASE},
+    },
+    chrono::Utc,
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// Trading pair symbol to get the price for (e.g., "BTCUSDT" or ["BTC", "USDT"])
+    #[serde(deserialize_with = "deserialize_symbol")]
+    symbol: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok {
+        /// The trading pair symbol (e.g., "BTCUSDT")
+        symbol: String,
+        /// The latest price
+        price: f64,
+        /// When the price was retrieved, in milliseconds since the epoch
+        timestamp: u64,
+    },
+    Err {
+        /// Detailed error message
+        reason: String,
+        /// Type of error (network, rate limit, invalid symbol, etc.)
+        kind: BinanceErrorKind,
+        /// HTTP status code if available
+        #[serde(skip_serializing_if = "Option::is_none")]
+        status_code: Option<u16>,
+    },
+}
+
+pub(crate) struct GetSpotPrice {
+    client: BinanceClient,
+}
+
+impl NexusTool for GetSpotPrice {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        let client = BinanceClient::new(Some(BINANCE_API_BASE));
+        Self { client }
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.exchanges.binance.spot-price@1")
+    }
+
+    fn path() -> &'static str {
+        "/spot-price"
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, request: Self::Input) -> Self::Output {
+        let data = match self
+            .client
+            .get::<TickerPriceData>("api/v3/ticker/price", &[("symbol", &request.symbol)])
+            .await
+        {
+            Ok(data) => data,
+            Err(error_response) => {
+                return Output::Err {
+                    reason: error_response.reason,
+                    kind: error_response.kind,
+                    status_code: error_response.status_code,
+                }
+            }
+        };
+
+        // The endpoint does not return a timestamp so the time of retrieval is
+        // used instead.
+        let timestamp = Utc::now().timestamp_millis() as u64;
+
+        match parse_decimal("price", &data.price) {
+            Ok(price) => Output::Ok {
+                symbol: data.symbol,
+                price,
+                timestamp,
+            },
+            Err(reason) => Output::Err {
+                reason,
+                kind: BinanceErrorKind::Parse,
+                status_code: None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        mockito::{Matcher, Server},
+        serde_json::json,
+    };
+
+    async fn create_server_and_tool() -> (mockito::ServerGuard, GetSpotPrice) {
+        let server = Server::new_async().await;
+        let client = BinanceClient::new(Some(&server.url()));
+        let tool = GetSpotPrice { client };
+        (server, tool)
+    }
+
+    #[tokio::test]
+    async fn test_successful_spot_price() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let mock = server
+            .mock("GET", "/api/v3/ticker/price")
+            .match_query(Matcher::UrlEncoded("symbol".into(), "BTCUSDT".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "symbol": "BTCUSDT", "price": "45000.12000000" }).to_string())
+            .create_async()
+            .await;
+
+        // The symbol may be passed as a pair of lowercase assets.
+        let input = serde_json::from_value(json!({ "symbol": ["btc", "usdt"] })).unwrap();
+        let before = Utc::now().timestamp_millis() as u64;
+
+        match tool.invoke(input).await {
+            Output::Ok {
+                symbol,
+                price,
+                timestamp,
+            } => {
+                assert_eq!(symbol, "BTCUSDT");
+                assert_eq!(price, 45000.12);
+                assert!(timestamp >= before);
+            }
+            output => panic!("Expected Ok variant, got {output:?}"),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_invalid_symbol() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let mock = server
+            .mock("GET", "/api/v3/ticker/price")
+            .match_query(Matcher::UrlEncoded("symbol".into(), "NOPE".into()))
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "code": -1121, "msg": "Invalid symbol." }).to_string())
+            .create_async()
+            .await;
+
+        let input = serde_json::from_value(json!({ "symbol": "NOPE" })).unwrap();
+
+        match tool.invoke(input).await {
+            Output::Err {
+                reason,
+                kind,
+                status_code,
+            } => {
+                assert_eq!(reason, "API error (-1121): Invalid symbol.");
+                assert_eq!(kind, BinanceErrorKind::InvalidSymbol);
+                assert_eq!(status_code, Some(400));
+            }
+            output => panic!("Expected Err variant, got {output:?}"),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let mock = server
+            .mock("GET", "/api/v3/ticker/price")
+            .match_query(Matcher::Any)
+            .with_status(429)
+            .with_body("Too many requests")
+            .create_async()
+            .await;
+
+        let input = serde_json::from_value(json!({ "symbol": "BTCUSDT" })).unwrap();
+
+        match tool.invoke(input).await {
+            Output::Err {
+                kind, status_code, ..
+            } => {
+                assert_eq!(kind, BinanceErrorKind::RateLimitExceeded);
+                assert_eq!(status_code, Some(429));
+            }
+            output => panic!("Expected Err variant, got {output:?}"),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_invalid_symbol_input() {
+        for symbol in [json!(""), json!("BTC-USDT"), json!(["BTC"]), json!(1)] {
+            assert!(serde_json::from_value::<Input>(json!({ "symbol": symbol })).is_err());
+        }
+    }
+}
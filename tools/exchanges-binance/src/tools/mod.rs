@@ -0,0 +1,61 @@
+//! Market data endpoints for Binance API
+
+use {
+    serde::{Deserialize, Deserializer},
+    serde_json::Value,
+};
+
+pub(crate) const BINANCE_API_BASE: &str = "https://api.binance.com";
+
+pub(crate) mod get_24hr_ticker;
+pub(crate) mod get_spot_price;
+pub(crate) mod models;
+
+/// Custom deserializer for the trading pair symbol that accepts both string
+/// and tuple formats. The symbol is uppercased as Binance symbols are.
+pub(crate) fn deserialize_symbol<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Value::deserialize(deserializer)?;
+
+    let symbol = match value {
+        // Direct string format like "BTCUSDT"
+        Value::String(s) => s,
+        // Tuple format like ["BTC", "USDT"]
+        Value::Array(arr) if arr.len() == 2 => {
+            let base = arr[0].as_str().ok_or_else(|| {
+                serde::de::Error::custom("First element of symbol array must be a string")
+            })?;
+            let quote = arr[1].as_str().ok_or_else(|| {
+                serde::de::Error::custom("Second element of symbol array must be a string")
+            })?;
+            format!("{}{}", base, quote)
+        }
+        Value::Array(_) => {
+            return Err(serde::de::Error::custom(
+                "Symbol array must contain exactly 2 elements",
+            ))
+        }
+        _ => {
+            return Err(serde::de::Error::custom(
+                "Symbol must be either a string (e.g., 'BTCUSDT') or an array of two strings (e.g., ['BTC', 'USDT'])",
+            ))
+        }
+    };
+
+    if symbol.is_empty() || !symbol.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(serde::de::Error::custom(
+            "Symbol must be a non-empty alphanumeric string",
+        ));
+    }
+
+    Ok(symbol.to_ascii_uppercase())
+}
+
+/// Parses a decimal string returned by Binance into a float.
+pub(crate) fn parse_decimal(field: &str, value: &str) -> Result<f64, String> {
+    value
+        .parse::<f64>()
+        .map_err(|_| format!("Invalid {field} in API response: '{value}'"))
+}
@@ -0,0 +1,51 @@
+//! Data models for Binance market data endpoints
+
+use {
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+/// Latest price of a symbol from Binance API
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TickerPriceData {
+    /// The trading pair symbol (e.g., "BTCUSDT")
+    pub symbol: String,
+    /// The latest price as a decimal string
+    pub price: String,
+}
+
+/// 24 hour rolling window price change statistics from Binance API
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker24hrData {
+    /// The trading pair symbol (e.g., "BTCUSDT")
+    pub symbol: String,
+    /// Absolute price change
+    pub price_change: String,
+    /// Relative price change in percent
+    pub price_change_percent: String,
+    /// Weighted average price
+    pub weighted_avg_price: String,
+    /// Last price
+    pub last_price: String,
+    /// Best bid price
+    pub bid_price: String,
+    /// Best ask price
+    pub ask_price: String,
+    /// Open price
+    pub open_price: String,
+    /// High price
+    pub high_price: String,
+    /// Low price
+    pub low_price: String,
+    /// Base asset volume
+    pub volume: String,
+    /// Quote asset volume
+    pub quote_volume: String,
+    /// Start of the window in milliseconds since the epoch
+    pub open_time: u64,
+    /// End of the window in milliseconds since the epoch
+    pub close_time: u64,
+    /// Number of trades in the window
+    pub count: u64,
+}
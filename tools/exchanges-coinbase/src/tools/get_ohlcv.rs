@@ -0,0 +1,356 @@
+//! # `xyz.taluslabs.exchanges.coinbase.get-ohlcv@1`
+//!
+//! Standard Nexus Tool that retrieves historical OHLCV candles for a product from Coinbase Exchange API.
+
+use {
+    crate::{
+        coinbase_client::CoinbaseClient,
+        error::CoinbaseErrorKind,
+        tools::{deserialize_trading_pair, models::CandleData, COINBASE_EXCHANGE_API_BASE},
+    },
+    chrono::{DateTime, SecondsFormat},
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+/// Candle widths in seconds that Coinbase Exchange API supports
+const GRANULARITIES: [u64; 6] = [60, 300, 900, 3600, 21600, 86400];
+
+/// Maximum number of candles Coinbase Exchange API returns per request
+const MAX_CANDLES: u64 = 300;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// Product ID (currency pair) to get candles for (e.g., "BTC-USD", "ETH-EUR" or ["BTC", "USD"])
+    #[serde(deserialize_with = "deserialize_trading_pair")]
+    product_id: String,
+    /// Width of each candle in seconds, one of 60, 300, 900, 3600, 21600 or 86400
+    granularity: u64,
+    /// Start of the range in seconds since the epoch
+    start: u64,
+    /// End of the range in seconds since the epoch
+    end: u64,
+}
+
+/// A single OHLCV candle
+#[derive(Debug, PartialEq, Serialize, JsonSchema)]
+pub(crate) struct Candle {
+    /// Start of the candle in seconds since the epoch
+    timestamp: u64,
+    /// Opening price
+    open: f64,
+    /// Highest price
+    high: f64,
+    /// Lowest price
+    low: f64,
+    /// Closing price
+    close: f64,
+    /// Volume of trading activity
+    volume: f64,
+}
+
+impl From<CandleData> for Candle {
+    fn from(CandleData(timestamp, low, high, open, close, volume): CandleData) -> Self {
+        Self {
+            timestamp,
+            open,
+            high,
+            low,
+            close,
+            volume,
+        }
+    }
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok {
+        /// Candles in ascending order of their timestamp
+        candles: Vec<Candle>,
+    },
+    Err {
+        /// Detailed error message
+        reason: String,
+        /// Type of error (network, server, auth, etc.)
+        kind: CoinbaseErrorKind,
+        /// HTTP status code if available
+        #[serde(skip_serializing_if = "Option::is_none")]
+        status_code: Option<u16>,
+    },
+}
+
+pub(crate) struct GetOhlcv {
+    client: CoinbaseClient,
+}
+
+impl NexusTool for GetOhlcv {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        let client = CoinbaseClient::new(Some(COINBASE_EXCHANGE_API_BASE));
+        Self { client }
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.exchanges.coinbase.get-ohlcv@1")
+    }
+
+    fn path() -> &'static str {
+        "/get-ohlcv"
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, request: Self::Input) -> Self::Output {
+        if let Err(reason) = validate(&request) {
+            return Output::Err {
+                reason,
+                kind: CoinbaseErrorKind::InvalidRequest,
+                status_code: None,
+            };
+        }
+
+        let endpoint = format!(
+            "products/{}/candles?granularity={}&start={}&end={}",
+            request.product_id,
+            request.granularity,
+            to_iso8601(request.start),
+            to_iso8601(request.end),
+        );
+
+        match self.client.get::<Vec<CandleData>>(&endpoint).await {
+            Ok(candles) => {
+                // Coinbase returns the most recent candle first.
+                let mut candles = candles.into_iter().map(Candle::from).collect::<Vec<_>>();
+                candles.sort_by_key(|candle| candle.timestamp);
+
+                Output::Ok { candles }
+            }
+            Err(error_response) => Output::Err {
+                reason: error_response.reason,
+                kind: error_response.kind,
+                status_code: error_response.status_code,
+            },
+        }
+    }
+}
+
+/// Check that the request is accepted by Coinbase Exchange API, which limits
+/// the range to 300 candles.
+fn validate(request: &Input) -> Result<(), String> {
+    if request.product_id.is_empty() {
+        return Err("Product ID cannot be empty".to_string());
+    }
+
+    if !GRANULARITIES.contains(&request.granularity) {
+        return Err(format!(
+            "Granularity must be one of {GRANULARITIES:?} seconds, got {}",
+            request.granularity
+        ));
+    }
+
+    if request.start >= request.end {
+        return Err("Start must be before end".to_string());
+    }
+
+    if (request.end - request.start) / request.granularity > MAX_CANDLES {
+        return Err(format!(
+            "Range covers more than {MAX_CANDLES} candles, reduce the range or increase the granularity"
+        ));
+    }
+
+    if i64::try_from(request.end)
+        .ok()
+        .and_then(|end| DateTime::from_timestamp(end, 0))
+        .is_none()
+    {
+        return Err("End is not a valid timestamp".to_string());
+    }
+
+    Ok(())
+}
+
+/// Format seconds since the epoch as an ISO 8601 date, e.g. `2024-01-01T00:00:00Z`.
+fn to_iso8601(timestamp: u64) -> String {
+    DateTime::from_timestamp(timestamp as i64, 0)
+        .expect("Timestamp is validated")
+        .to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        ::{
+            mockito::{Matcher, Server},
+            serde_json::json,
+        },
+    };
+
+    async fn create_server_and_tool() -> (mockito::ServerGuard, GetOhlcv) {
+        let server = Server::new_async().await;
+        let client = CoinbaseClient::new(Some(&server.url()));
+        let tool = GetOhlcv { client };
+        (server, tool)
+    }
+
+    fn create_test_input(granularity: u64, start: u64, end: u64) -> Input {
+        Input {
+            product_id: "BTC-USD".to_string(),
+            granularity,
+            start,
+            end,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_successful_ohlcv_request() {
+        // Create server and tool
+        let (mut server, tool) = create_server_and_tool().await;
+
+        // Set up mock response, most recent candle first
+        let mock = server
+            .mock("GET", "/products/BTC-USD/candles")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("granularity".into(), "3600".into()),
+                Matcher::UrlEncoded("start".into(), "2024-01-01T00:00:00Z".into()),
+                Matcher::UrlEncoded("end".into(), "2024-01-01T02:00:00Z".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!([
+                    [1704070800, 42100.5, 42500.0, 42200.0, 42400.25, 120.5],
+                    [1704067200, 41900.0, 42300.0, 42000.0, 42200.0, 98.75]
+                ])
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        // 2024-01-01T00:00:00Z to 2024-01-01T02:00:00Z
+        let result = tool
+            .invoke(create_test_input(3600, 1704067200, 1704074400))
+            .await;
+
+        // Verify the response
+        match result {
+            Output::Ok { candles } => {
+                assert_eq!(
+                    candles,
+                    vec![
+                        Candle {
+                            timestamp: 1704067200,
+                            open: 42000.0,
+                            high: 42300.0,
+                            low: 41900.0,
+                            close: 42200.0,
+                            volume: 98.75,
+                        },
+                        Candle {
+                            timestamp: 1704070800,
+                            open: 42200.0,
+                            high: 42500.0,
+                            low: 42100.5,
+                            close: 42400.25,
+                            volume: 120.5,
+                        },
+                    ]
+                );
+            }
+            Output::Err {
+                reason,
+                kind,
+                status_code,
+            } => panic!(
+                "Expected success, got error: {} (Kind: {:?}, Status Code: {:?})",
+                reason, kind, status_code
+            ),
+        }
+
+        // Verify that the mock was called
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_invalid_range() {
+        let (_, tool) = create_server_and_tool().await;
+
+        let cases = [
+            (
+                create_test_input(3600, 100, 100),
+                "Start must be before end",
+            ),
+            (
+                create_test_input(3600, 200, 100),
+                "Start must be before end",
+            ),
+            (
+                create_test_input(120, 0, 3600),
+                "Granularity must be one of",
+            ),
+            (
+                // 301 one minute candles.
+                create_test_input(60, 0, 301 * 60),
+                "Range covers more than 300 candles",
+            ),
+        ];
+
+        for (input, expected) in cases {
+            match tool.invoke(input).await {
+                Output::Ok { .. } => panic!("Expected error, got success"),
+                Output::Err {
+                    reason,
+                    kind,
+                    status_code,
+                } => {
+                    assert!(reason.starts_with(expected), "{reason}");
+                    assert_eq!(kind, CoinbaseErrorKind::InvalidRequest);
+                    assert_eq!(status_code, None);
+                }
+            }
+        }
+
+        // Exactly 300 candles are allowed.
+        assert!(validate(&create_test_input(60, 0, 300 * 60)).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_api_error() {
+        // Create server and tool
+        let (mut server, tool) = create_server_and_tool().await;
+
+        // Set up mock for API error response
+        let mock = server
+            .mock("GET", "/products/BTC-USD/candles")
+            .match_query(Matcher::Any)
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "message": "NotFound" }).to_string())
+            .create_async()
+            .await;
+
+        let result = tool
+            .invoke(create_test_input(86400, 1704067200, 1706745600))
+            .await;
+
+        match result {
+            Output::Ok { .. } => panic!("Expected error, got success"),
+            Output::Err {
+                kind, status_code, ..
+            } => {
+                assert_eq!(kind, CoinbaseErrorKind::NotFound);
+                assert_eq!(status_code, Some(404));
+            }
+        }
+
+        mock.assert_async().await;
+    }
+}
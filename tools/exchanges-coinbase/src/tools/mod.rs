@@ -8,6 +8,7 @@ use {
 pub(crate) const COINBASE_API_BASE: &str = "https://api.coinbase.com";
 pub(crate) const COINBASE_EXCHANGE_API_BASE: &str = "https://api.exchange.coinbase.com";
 
+pub(crate) mod get_ohlcv;
 pub(crate) mod get_product_ticker;
 pub(crate) mod get_spot_price;
 pub(crate) mod models;
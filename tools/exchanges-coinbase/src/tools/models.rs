@@ -40,6 +40,24 @@ pub struct ProductTickerData {
     pub conversions_volume: Option<String>,
 }
 
+/// Candle from Coinbase Exchange API, returned as an array of
+/// `[time, low, high, open, close, volume]`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CandleData(
+    /// Start of the bucket in seconds since the epoch
+    pub u64,
+    /// Lowest price during the bucket
+    pub f64,
+    /// Highest price during the bucket
+    pub f64,
+    /// Opening price of the bucket
+    pub f64,
+    /// Closing price of the bucket
+    pub f64,
+    /// Volume of trading activity during the bucket
+    pub f64,
+);
+
 /// Coinbase API response with potential errors
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CoinbaseApiResponse<T> {
@@ -37,6 +37,9 @@ pub enum HttpErrorKind {
     /// Base64 decoding error
     #[serde(rename = "err_base64_decode")]
     Base64Decode,
+    /// Request not sent because the circuit breaker is open
+    #[serde(rename = "err_circuit_open")]
+    CircuitOpen,
 }
 
 /// Input validation errors
@@ -48,6 +51,10 @@ pub enum ValidationError {
     InvalidTimeout(String),
     #[error("Invalid retries: {0}")]
     InvalidRetries(String),
+    #[error("Invalid max_pages: {0}")]
+    InvalidMaxPages(String),
+    #[error("Invalid circuit breaker: {0}")]
+    InvalidCircuitBreaker(String),
     #[error("Multipart field name cannot be empty")]
     EmptyMultipartFieldName,
     #[error("Multipart field value cannot be empty")]
@@ -60,6 +67,48 @@ pub enum ValidationError {
     EmptyFormData,
     #[error("JSON body data cannot be null")]
     NullJsonData,
+    #[error("TLS client certificate and key must be provided together")]
+    IncompleteClientIdentity,
+    #[error("Invalid TLS client certificate or key: {0}")]
+    InvalidClientIdentity(String),
+    #[error("Invalid TLS CA bundle: {0}")]
+    InvalidCaBundle(String),
+    #[error("HMAC signatures cannot be computed for multipart bodies")]
+    HmacMultipartBody,
+    #[error("Client certificate cannot be provided both in auth and tls")]
+    ConflictingClientIdentity,
+    #[error("follow_pagination is only supported for GET requests")]
+    PaginationRequiresGet,
+}
+
+impl ValidationError {
+    /// JSON pointer to the input field the error concerns.
+    pub fn pointer(&self) -> &'static str {
+        match self {
+            Self::SchemaRequiresJson => "/expect_json",
+            Self::InvalidTimeout(_) => "/timeout_ms",
+            Self::InvalidRetries(_) => "/retries",
+            Self::InvalidMaxPages(_) => "/max_pages",
+            Self::PaginationRequiresGet => "/follow_pagination",
+            Self::InvalidCircuitBreaker(_) => "/circuit_breaker",
+            Self::EmptyMultipartFieldName
+            | Self::EmptyMultipartFieldValue
+            | Self::EmptyRawBody
+            | Self::InvalidBase64Data
+            | Self::EmptyFormData
+            | Self::NullJsonData => "/body",
+            Self::IncompleteClientIdentity
+            | Self::InvalidClientIdentity(_)
+            | Self::InvalidCaBundle(_) => "/tls",
+            Self::HmacMultipartBody | Self::ConflictingClientIdentity => "/auth",
+        }
+    }
+}
+
+impl From<ValidationError> for nexus_toolkit::ToolValidationError {
+    fn from(e: ValidationError) -> Self {
+        Self::new(e.to_string()).at(e.pointer())
+    }
 }
 
 /// HTTP tool errors (internal)
@@ -93,6 +142,9 @@ pub enum HttpToolError {
 
     #[error("Base64 decode error: {0}")]
     ErrBase64Decode(String),
+
+    #[error("Circuit breaker open for {url}, retry in {retry_after_ms}ms")]
+    ErrCircuitOpen { url: String, retry_after_ms: u64 },
 }
 
 impl HttpToolError {
@@ -143,6 +195,27 @@ impl HttpToolError {
                 kind: HttpErrorKind::Base64Decode,
                 status_code: None,
             },
+            HttpToolError::ErrCircuitOpen {
+                url,
+                retry_after_ms,
+            } => crate::http::Output::Err {
+                reason: format!(
+                    "Circuit breaker open for {} after consecutive failures, retry in {}ms",
+                    url, retry_after_ms
+                ),
+                kind: HttpErrorKind::CircuitOpen,
+                status_code: None,
+            },
+        }
+    }
+
+    /// Whether the error counts as a failure for the circuit breaker: a
+    /// server error response, a network error or a timeout.
+    pub fn is_server_failure(&self) -> bool {
+        match self {
+            HttpToolError::ErrHttp { status, .. } => *status >= 500,
+            HttpToolError::ErrNetwork(_) | HttpToolError::ErrTimeout(_) => true,
+            _ => false,
         }
     }
 
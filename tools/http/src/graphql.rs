@@ -0,0 +1,357 @@
+//! # `xyz.taluslabs.http.graphql@1`
+//!
+//! Standard Nexus Tool that sends a query to a GraphQL endpoint.
+
+use {
+    crate::{
+        errors::HttpToolError,
+        http_client::HttpClient,
+        jwt::refresh_jwt,
+        models::{AuthConfig, UrlInput},
+    },
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+    serde_json::{json, Value},
+    std::collections::HashMap,
+    warp::http::StatusCode,
+};
+
+/// Input model for the GraphQL tool
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// URL of the GraphQL endpoint
+    pub url: String,
+
+    /// GraphQL query or mutation document
+    pub query: String,
+
+    /// Variables referenced by the query, as a JSON object
+    #[serde(default)]
+    pub variables: Option<Value>,
+
+    /// Name of the operation to execute if the document contains several
+    #[serde(default)]
+    pub operation_name: Option<String>,
+
+    /// Authentication configuration
+    #[serde(default)]
+    pub auth: Option<AuthConfig>,
+
+    /// HTTP headers to include in the request
+    #[serde(default)]
+    pub headers: Option<HashMap<String, String>>,
+}
+
+/// Output model for the GraphQL tool
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum Output {
+    /// The query was executed without errors
+    Ok {
+        /// The `data` of the GraphQL response
+        data: Value,
+    },
+    /// The endpoint reported errors or could not be reached
+    Err {
+        /// Messages of the GraphQL errors, or a single message describing why
+        /// the request failed
+        errors: Vec<String>,
+    },
+}
+
+/// Body of a GraphQL response.
+#[derive(Debug, Deserialize)]
+struct GraphQLResponse {
+    #[serde(default)]
+    data: Option<Value>,
+    #[serde(default)]
+    errors: Option<Vec<GraphQLError>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQLError {
+    message: String,
+}
+
+/// GraphQL tool implementation
+pub(crate) struct GraphQL;
+
+impl NexusTool for GraphQL {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.http.graphql@1")
+    }
+
+    fn path() -> &'static str {
+        "/graphql"
+    }
+
+    fn description() -> &'static str {
+        "Sends a query or mutation to a GraphQL endpoint and returns its data."
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    fn validate(&self, input: &Self::Input) -> Result<(), ToolValidationError> {
+        if input.query.trim().is_empty() {
+            return Err(ToolValidationError::new("Query cannot be empty").at("/query"));
+        }
+
+        if input.variables.as_ref().is_some_and(|v| !v.is_object()) {
+            return Err(
+                ToolValidationError::new("Variables must be a JSON object").at("/variables")
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn invoke(&self, mut input: Self::Input) -> Self::Output {
+        match self.execute(&mut input).await {
+            Ok(output) => output,
+            Err(e) => Output::Err {
+                errors: vec![e.to_string()],
+            },
+        }
+    }
+}
+
+impl GraphQL {
+    /// Post the query to the endpoint and turn the response into an output.
+    async fn execute(&self, input: &mut Input) -> Result<Output, HttpToolError> {
+        refresh_jwt(input.auth.as_mut()).await?;
+
+        let http_client = HttpClient::with_config(None, None, None, input.auth.as_ref())?;
+        let url = http_client.resolve_url(&UrlInput::FullUrl(input.url.clone()))?;
+
+        let mut body = json!({ "query": input.query });
+
+        if let Some(variables) = &input.variables {
+            body["variables"] = variables.clone();
+        }
+
+        if let Some(operation_name) = &input.operation_name {
+            body["operationName"] = json!(operation_name);
+        }
+
+        let request = http_client
+            .build_request(
+                reqwest::Method::POST,
+                url,
+                input.auth.as_ref(),
+                input.headers.as_ref(),
+                None,
+            )?
+            .json(&body);
+        let request = http_client.sign_request(request, input.auth.as_ref())?;
+
+        let response = http_client.execute(request).await?;
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .map_err(HttpToolError::from_network_error)?;
+
+        // Servers may reply with a non-2xx status and still describe the
+        // errors in the body, which is more useful than the status.
+        let response = match serde_json::from_str::<GraphQLResponse>(&text) {
+            Ok(response) => response,
+            Err(_) if !status.is_success() => {
+                return Err(HttpToolError::ErrHttp {
+                    status: status.as_u16(),
+                    reason: status.canonical_reason().unwrap_or("Unknown").to_string(),
+                    snippet: text.chars().take(200).collect(),
+                })
+            }
+            Err(e) => return Err(HttpToolError::from_json_error(e)),
+        };
+
+        let errors = response.errors.unwrap_or_default();
+
+        if !errors.is_empty() {
+            return Ok(Output::Err {
+                errors: errors.into_iter().map(|e| e.message).collect(),
+            });
+        }
+
+        match response.data {
+            Some(data) if !data.is_null() => Ok(Output::Ok { data }),
+            _ if !status.is_success() => Err(HttpToolError::ErrHttp {
+                status: status.as_u16(),
+                reason: status.canonical_reason().unwrap_or("Unknown").to_string(),
+                snippet: text.chars().take(200).collect(),
+            }),
+            _ => Err(HttpToolError::ErrJsonParse(
+                "GraphQL response contains neither data nor errors".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        mockito::{Matcher, Server},
+    };
+
+    fn create_input(url: String) -> Input {
+        Input {
+            url,
+            query: "query User($id: ID!) { user(id: $id) { name } }".to_string(),
+            variables: Some(json!({ "id": "1" })),
+            operation_name: Some("User".to_string()),
+            auth: Some(AuthConfig::BearerToken {
+                token: "secret".to_string(),
+            }),
+            headers: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_graphql_query() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "Bearer secret")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::Json(json!({
+                "query": "query User($id: ID!) { user(id: $id) { name } }",
+                "variables": { "id": "1" },
+                "operationName": "User"
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "user": { "name": "Alice" } } }).to_string())
+            .create_async()
+            .await;
+
+        let output = GraphQL
+            .invoke(create_input(format!("{}/graphql", server.url())))
+            .await;
+
+        match output {
+            Output::Ok { data } => assert_eq!(data, json!({ "user": { "name": "Alice" } })),
+            Output::Err { errors } => panic!("Expected success, got errors: {:?}", errors),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_graphql_errors() {
+        let mut server = Server::new_async().await;
+
+        // Partial data is discarded when the response has errors.
+        let mock = server
+            .mock("POST", "/graphql")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "data": { "user": null },
+                    "errors": [
+                        { "message": "User not found", "path": ["user"] },
+                        { "message": "Rate limited" }
+                    ]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let output = GraphQL
+            .invoke(create_input(format!("{}/graphql", server.url())))
+            .await;
+
+        match output {
+            Output::Err { errors } => assert_eq!(errors, vec!["User not found", "Rate limited"]),
+            Output::Ok { data } => panic!("Expected errors, got data: {}", data),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_graphql_http_error() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/graphql")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "errors": [{ "message": "Syntax Error: Unexpected Name \"quer\"." }] })
+                    .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let output = GraphQL
+            .invoke(create_input(format!("{}/graphql", server.url())))
+            .await;
+
+        match output {
+            Output::Err { errors } => {
+                assert_eq!(errors, vec!["Syntax Error: Unexpected Name \"quer\"."])
+            }
+            Output::Ok { data } => panic!("Expected errors, got data: {}", data),
+        }
+
+        mock.assert_async().await;
+
+        let mock = server
+            .mock("POST", "/graphql")
+            .with_status(502)
+            .with_body("Bad Gateway")
+            .create_async()
+            .await;
+
+        let output = GraphQL
+            .invoke(create_input(format!("{}/graphql", server.url())))
+            .await;
+
+        match output {
+            Output::Err { errors } => assert_eq!(errors, vec!["HTTP error 502: Bad Gateway"]),
+            Output::Ok { data } => panic!("Expected errors, got data: {}", data),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_graphql_validation() {
+        let valid = create_input("https://example.com/graphql".to_string());
+        assert!(GraphQL.validate(&valid).is_ok());
+
+        let empty_query = Input {
+            query: "  ".to_string(),
+            ..create_input("https://example.com/graphql".to_string())
+        };
+        assert_eq!(
+            GraphQL.validate(&empty_query).unwrap_err().pointer,
+            "/query"
+        );
+
+        let invalid_variables = Input {
+            variables: Some(json!(["1"])),
+            ..create_input("https://example.com/graphql".to_string())
+        };
+        assert_eq!(
+            GraphQL.validate(&invalid_variables).unwrap_err().pointer,
+            "/variables"
+        );
+    }
+}
@@ -4,17 +4,21 @@
 
 use {
     crate::{
+        circuit_breaker::{circuit_key, CircuitBreaker},
         errors::{HttpErrorKind, HttpToolError, ValidationError},
         http_client::HttpClient,
+        jwt::refresh_jwt,
         models::{
             AuthConfig,
+            CircuitBreakerConfig,
             HttpJsonSchema,
             HttpMethod,
             RequestBody,
             SchemaValidationDetails,
+            TlsConfig,
             UrlInput,
         },
-        utils::validate_schema_detailed,
+        utils::{next_link, validate_schema_detailed},
     },
     base64::Engine,
     nexus_sdk::{fqn, ToolFqn},
@@ -72,8 +76,30 @@ pub(crate) struct Input {
     /// Whether to follow redirects (default: true)
     #[serde(default)]
     pub follow_redirects: Option<bool>,
+
+    /// TLS configuration (client certificate for mutual TLS, custom CA bundle)
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
+
+    /// Whether to follow `rel="next"` links of the `Link` header and merge
+    /// the pages (default: false)
+    #[serde(default)]
+    pub follow_pagination: Option<bool>,
+
+    /// Maximum number of pages to read when following pagination (default: 10)
+    #[serde(default)]
+    pub max_pages: Option<u32>,
+
+    /// Stop sending requests to the URL for a while after consecutive failures
+    #[serde(default)]
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
 }
 
+/// Pages read when following pagination unless `max_pages` is set.
+const DEFAULT_MAX_PAGES: u32 = 10;
+/// Upper bound of `max_pages`.
+const MAX_PAGES_LIMIT: u32 = 100;
+
 impl Input {
     /// Validate input parameters
     pub fn validate(&self) -> Result<(), ValidationError> {
@@ -91,6 +117,11 @@ impl Input {
         // Validate body configuration
         if let Some(body) = &self.body {
             match body {
+                RequestBody::Multipart { .. }
+                    if matches!(self.auth, Some(AuthConfig::HmacSignature { .. })) =>
+                {
+                    return Err(ValidationError::HmacMultipartBody);
+                }
                 RequestBody::Multipart { fields } => {
                     for field in fields {
                         if field.name.is_empty() {
@@ -127,6 +158,16 @@ impl Input {
             }
         }
 
+        // The client identity can only come from one place
+        if matches!(self.auth, Some(AuthConfig::ClientCert { .. }))
+            && self
+                .tls
+                .as_ref()
+                .is_some_and(|tls| tls.client_cert.is_some())
+        {
+            return Err(ValidationError::ConflictingClientIdentity);
+        }
+
         // Validate timeout_ms
         if let Some(timeout_ms) = self.timeout_ms {
             if timeout_ms == 0 {
@@ -151,6 +192,41 @@ impl Input {
             }
         }
 
+        // Pages are read with the same request, which must not have a body or
+        // side effects
+        if self.follow_pagination.unwrap_or(false) && !matches!(self.method, HttpMethod::Get) {
+            return Err(ValidationError::PaginationRequiresGet);
+        }
+
+        // Validate max_pages
+        if let Some(max_pages) = self.max_pages {
+            if max_pages == 0 || max_pages > MAX_PAGES_LIMIT {
+                return Err(ValidationError::InvalidMaxPages(format!(
+                    "max_pages must be between 1 and {MAX_PAGES_LIMIT}"
+                )));
+            }
+        }
+
+        // Validate circuit_breaker
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            if circuit_breaker.failure_threshold == 0 {
+                return Err(ValidationError::InvalidCircuitBreaker(
+                    "failure_threshold must be greater than 0".to_string(),
+                ));
+            }
+            if circuit_breaker.reset_after_ms == 0 {
+                return Err(ValidationError::InvalidCircuitBreaker(
+                    "reset_after_ms must be greater than 0".to_string(),
+                ));
+            }
+        }
+
+        // Validate TLS configuration
+        if let Some(tls) = &self.tls {
+            tls.identity()?;
+            tls.root_certificates()?;
+        }
+
         Ok(())
     }
 }
@@ -216,30 +292,62 @@ impl NexusTool for Http {
         Ok(StatusCode::OK)
     }
 
-    async fn invoke(&self, input: Self::Input) -> Self::Output {
-        // Validate input parameters
-        if let Err(validation_error) = input.validate() {
-            return HttpToolError::from_validation_error(validation_error).to_output();
+    fn validate(&self, input: &Self::Input) -> Result<(), ToolValidationError> {
+        input.validate().map_err(ToolValidationError::from)
+    }
+
+    async fn invoke(&self, mut input: Self::Input) -> Self::Output {
+        match self.execute(&mut input).await {
+            Ok(output) => output,
+            Err(e) => e.to_output(),
         }
+    }
+}
 
-        // Prepare request (client, URL, method, headers, body)
-        let (http_client, request) = match self.prepare_request(&input) {
-            Ok((client, req)) => (client, req),
-            Err(e) => return e.to_output(),
+/// A successful response that was read and parsed, before the schema
+/// validation.
+struct Page {
+    status: u16,
+    headers: HashMap<String, String>,
+    body: Vec<u8>,
+    text: Option<String>,
+    json: Option<Value>,
+}
+
+impl Page {
+    /// Append the next page: JSON arrays are concatenated and the bodies are
+    /// joined with a newline. The status and headers are the ones of the last
+    /// page.
+    fn append(self, next: Page) -> Result<Self, HttpToolError> {
+        let json = match (self.json, next.json) {
+            (None, None) => None,
+            (Some(Value::Array(mut items)), Some(Value::Array(next_items))) => {
+                items.extend(next_items);
+                Some(Value::Array(items))
+            }
+            _ => {
+                return Err(HttpToolError::ErrInput(
+                    "Paginated JSON responses must be arrays to be merged".to_string(),
+                ))
+            }
         };
 
-        // Execute request with or without retry logic
-        let retries = input.retries.unwrap_or(0);
-        let response = if retries > 0 {
-            http_client.execute_with_retry(request, retries).await
-        } else {
-            http_client.execute(request).await
+        let text = match (self.text, next.text) {
+            (Some(text), Some(next_text)) => Some(format!("{text}\n{next_text}")),
+            _ => None,
         };
 
-        match response {
-            Ok(response) => self.process_response(response, &input).await,
-            Err(e) => e.to_output(),
-        }
+        let mut body = self.body;
+        body.push(b'\n');
+        body.extend(next.body);
+
+        Ok(Self {
+            status: next.status,
+            headers: next.headers,
+            body,
+            text,
+            json,
+        })
     }
 }
 
@@ -249,6 +357,58 @@ impl Http {
         matches!(status.as_u16(), 204 | 202 | 205)
     }
 
+    /// Send the request, follow the pagination if enabled and build the output
+    async fn execute(&self, input: &mut Input) -> Result<Output, HttpToolError> {
+        refresh_jwt(input.auth.as_mut()).await?;
+
+        // Prepare request (client, URL, method, headers, body)
+        let (http_client, request) = self.prepare_request(input)?;
+
+        // Fail fast while the circuit of the URL is open
+        let circuit = match &input.circuit_breaker {
+            Some(config) => Some((circuit_key(&http_client.resolve_url(&input.url)?), config)),
+            None => None,
+        };
+
+        if let Some((key, config)) = &circuit {
+            if let Err(remaining) = CircuitBreaker::global().check(key, config) {
+                return Err(HttpToolError::ErrCircuitOpen {
+                    url: key.clone(),
+                    retry_after_ms: remaining.as_millis().try_into().unwrap_or(u64::MAX),
+                });
+            }
+        }
+
+        let page = self.fetch_page(&http_client, request, input).await;
+
+        if let Some((key, config)) = &circuit {
+            match &page {
+                Err(e) if e.is_server_failure() => {
+                    CircuitBreaker::global().record_failure(key, config)
+                }
+                _ => CircuitBreaker::global().record_success(key),
+            }
+        }
+
+        let mut page = page?;
+
+        if input.follow_pagination.unwrap_or(false) {
+            page = self.follow_pagination(&http_client, page, input).await?;
+        }
+
+        // Validate schema if provided
+        let schema_validation = self.validate_schema_response(&page.json, input)?;
+
+        Ok(Output::Ok {
+            status: page.status,
+            headers: page.headers,
+            raw_base64: base64::engine::general_purpose::STANDARD.encode(&page.body),
+            text: page.text,
+            json: page.json,
+            schema_validation,
+        })
+    }
+
     /// Prepare HTTP request (client, URL, method, headers, body)
     fn prepare_request(
         &self,
@@ -257,21 +417,41 @@ impl Http {
         // Create HTTP client with configuration
         let timeout_ms = input.timeout_ms.unwrap_or(5000);
         let follow_redirects = input.follow_redirects.unwrap_or(false);
-        let http_client = HttpClient::with_config(Some(timeout_ms), Some(follow_redirects))?;
+        let http_client = HttpClient::with_config(
+            Some(timeout_ms),
+            Some(follow_redirects),
+            input.tls.as_ref(),
+            input.auth.as_ref(),
+        )?;
 
         // Resolve URL from input with proper validation
         let resolved_url = http_client.resolve_url(&input.url)?;
 
+        let request =
+            self.build_request(&http_client, input, resolved_url, input.query.as_ref())?;
+
+        Ok((http_client, request))
+    }
+
+    /// Build the request to the URL with authentication, headers, query
+    /// parameters and body
+    fn build_request(
+        &self,
+        http_client: &HttpClient,
+        input: &Input,
+        url: url::Url,
+        query: Option<&HashMap<String, String>>,
+    ) -> Result<reqwest::RequestBuilder, HttpToolError> {
         // Build HTTP method
         let method = http_client.build_method(&input.method);
 
         // Build request with authentication, headers, and query parameters
         let request = http_client.build_request(
             method.clone(),
-            resolved_url.clone(),
+            url,
             input.auth.as_ref(),
             input.headers.as_ref(),
-            input.query.as_ref(),
+            query,
         )?;
 
         // Build request body if provided
@@ -281,11 +461,74 @@ impl Http {
             request
         };
 
-        Ok((http_client, request))
+        // Sign the request once the body is known
+        http_client.sign_request(request, input.auth.as_ref())
+    }
+
+    /// Execute the request with or without retry logic and read the response
+    async fn fetch_page(
+        &self,
+        http_client: &HttpClient,
+        request: reqwest::RequestBuilder,
+        input: &Input,
+    ) -> Result<Page, HttpToolError> {
+        let retries = input.retries.unwrap_or(0);
+        let response = if retries > 0 {
+            http_client.execute_with_retry(request, retries).await?
+        } else {
+            http_client.execute(request).await?
+        };
+
+        self.read_response(response, input).await
+    }
+
+    /// Request the pages linked with `rel="next"` in the `Link` header until
+    /// there are none left or `max_pages` pages were read, and merge them.
+    /// Links to another origin are not followed as the request carries the
+    /// authentication of the input URL
+    async fn follow_pagination(
+        &self,
+        http_client: &HttpClient,
+        first: Page,
+        input: &Input,
+    ) -> Result<Page, HttpToolError> {
+        let max_pages = input.max_pages.unwrap_or(DEFAULT_MAX_PAGES);
+        let mut url = http_client.resolve_url(&input.url)?;
+        let origin = url.origin();
+        let mut merged = first;
+
+        for _ in 1..max_pages {
+            let Some(next) = merged.headers.get("link").and_then(|link| next_link(link)) else {
+                break;
+            };
+
+            // The link may be relative to the current page. The query of the
+            // input is not added again as the link contains the full query.
+            let next = url
+                .join(next)
+                .map_err(HttpToolError::from_url_parse_error)?;
+
+            if next.origin() != origin {
+                break;
+            }
+
+            url = http_client.resolve_url(&UrlInput::FullUrl(next.to_string()))?;
+
+            let request = self.build_request(http_client, input, url.clone(), None)?;
+            let page = self.fetch_page(http_client, request, input).await?;
+
+            merged = merged.append(page)?;
+        }
+
+        Ok(merged)
     }
 
-    /// Process HTTP response and return structured output
-    async fn process_response(&self, response: reqwest::Response, input: &Input) -> Output {
+    /// Read and parse the HTTP response
+    async fn read_response(
+        &self,
+        response: reqwest::Response,
+        input: &Input,
+    ) -> Result<Page, HttpToolError> {
         let status = response.status().as_u16();
         let status_code = response.status();
 
@@ -299,7 +542,7 @@ impl Http {
                 body
             };
 
-            return HttpToolError::ErrHttp {
+            return Err(HttpToolError::ErrHttp {
                 status,
                 reason: if reason_phrase.is_empty() {
                     format!("HTTP error: {}", status)
@@ -307,8 +550,7 @@ impl Http {
                     format!("HTTP error: {} ({})", status, reason_phrase)
                 },
                 snippet,
-            }
-            .to_output();
+            });
         }
 
         // Get response headers
@@ -319,39 +561,25 @@ impl Http {
             .collect();
 
         // Get raw response body as bytes
-        let body_bytes = match response.bytes().await {
-            Ok(bytes) => bytes,
-            Err(e) => {
-                return HttpToolError::from_network_error(e).to_output();
-            }
-        };
-
-        // Encode raw body as base64
-        let raw_base64 = base64::engine::general_purpose::STANDARD.encode(&body_bytes);
+        let body = response
+            .bytes()
+            .await
+            .map_err(HttpToolError::from_network_error)?
+            .to_vec();
 
         // Try to decode as UTF-8 text
-        let text = String::from_utf8(body_bytes.to_vec()).ok();
+        let text = String::from_utf8(body.clone()).ok();
 
         // Parse JSON response
-        let json = match self.parse_json_response(&text, &headers, input, &status_code) {
-            Ok(json) => json,
-            Err(e) => return e.to_output(),
-        };
-
-        // Validate schema if provided
-        let schema_validation = match self.validate_schema_response(&json, input) {
-            Ok(validation) => validation,
-            Err(e) => return e.to_output(),
-        };
+        let json = self.parse_json_response(&text, &headers, input, &status_code)?;
 
-        Output::Ok {
+        Ok(Page {
             status,
             headers,
-            raw_base64,
+            body,
             text,
             json,
-            schema_validation,
-        }
+        })
     }
 
     /// Parse JSON from response text and headers
@@ -481,6 +709,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -535,6 +767,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -587,6 +823,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -637,6 +877,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -703,6 +947,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -744,6 +992,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -804,6 +1056,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -856,6 +1112,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -895,6 +1155,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -935,6 +1199,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -974,6 +1242,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -986,6 +1258,126 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_auth_hmac_signature() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        // HMAC-SHA256("test-secret", "POST\n/signed\n" + hex(SHA-256(body))).
+        let mock = server
+            .mock("POST", "/signed")
+            .match_header(
+                "x-signature",
+                "yUJLogQku+7uGXn3z6t0vEvQ4dKItU7MsthN5I4ytIw=",
+            )
+            .match_header("x-timestamp", mockito::Matcher::Missing)
+            .match_body(r#"{"hello":"world"}"#)
+            .with_status(200)
+            .create();
+
+        let auth = serde_json::from_value(serde_json::json!({
+            "secret": "best-encryption-ever-\"test-secret\"",
+            "header_name": "X-Signature"
+        }))
+        .unwrap();
+
+        let input = Input {
+            method: HttpMethod::Post,
+            url: UrlInput::FullUrl(format!("{}/signed", server.url())),
+            headers: None,
+            query: None,
+            auth: Some(auth),
+            body: Some(RequestBody::Json {
+                data: serde_json::json!({ "hello": "world" }),
+            }),
+            expect_json: None,
+            json_schema: None,
+            timeout_ms: None,
+            retries: None,
+            follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
+        };
+
+        let output = tool.invoke(input).await;
+
+        match output {
+            Output::Ok { status, .. } => {
+                assert_eq!(status, 200);
+            }
+            _ => panic!("Expected successful response, got: {:?}", output),
+        }
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_auth_jwt_refresh() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        // Unsigned JWTs that expired an hour ago and expire in 2100.
+        let jwt = |exp: u64| {
+            let encode = |value: serde_json::Value| {
+                base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(value.to_string())
+            };
+
+            format!(
+                "{}.{}.signature",
+                encode(serde_json::json!({ "alg": "HS256" })),
+                encode(serde_json::json!({ "exp": exp }))
+            )
+        };
+        let expired = jwt(std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            - 3600);
+        let fresh = jwt(4_102_444_800);
+
+        let token_mock = server
+            .mock("POST", "/oauth/token")
+            .match_body(mockito::Matcher::UrlEncoded(
+                "grant_type".into(),
+                "client_credentials".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({ "access_token": fresh }).to_string())
+            .create_async()
+            .await;
+        let mock = server
+            .mock("GET", "/protected")
+            .match_header("authorization", format!("Bearer {fresh}").as_str())
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let auth = serde_json::from_value(serde_json::json!({
+            "token": format!("best-encryption-ever-{}", serde_json::json!(expired)),
+            "refresh_url": format!("{}/oauth/token", server.url()),
+            "client_id": "client",
+            "client_secret": "best-encryption-ever-\"secret\"",
+            "leeway_seconds": 60
+        }))
+        .unwrap();
+        assert!(matches!(auth, AuthConfig::JwtRefresh { .. }));
+
+        let input = Input {
+            auth: Some(auth),
+            follow_pagination: None,
+            ..pagination_input(format!("{}/protected", server.url()), None)
+        };
+
+        match tool.invoke(input).await {
+            Output::Ok { status, .. } => assert_eq!(status, 200),
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        token_mock.assert_async().await;
+        mock.assert_async().await;
+    }
+
     #[tokio::test]
     async fn test_input_validation() {
         // Test valid case: json_schema provided with expect_json = true
@@ -1006,6 +1398,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
         assert!(valid_input.validate().is_ok());
 
@@ -1027,6 +1423,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
         assert!(invalid_input.validate().is_err());
 
@@ -1048,6 +1448,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
         assert!(invalid_input2.validate().is_err());
 
@@ -1064,6 +1468,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
         assert!(valid_input2.validate().is_ok());
     }
@@ -1090,17 +1498,17 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
-        let output = tool.invoke(input).await;
+        // The runtime rejects the input before it is invoked.
+        let error = NexusTool::validate(&tool, &input).unwrap_err();
 
-        match output {
-            Output::Err { reason, kind, .. } => {
-                assert!(matches!(kind, HttpErrorKind::Input));
-                assert!(reason.contains("Schema validation requires expect_json=true"));
-            }
-            _ => panic!("Expected Err with validation error"),
-        }
+        assert_eq!(error.pointer, "/expect_json");
+        assert_eq!(error.message, "Schema validation requires expect_json=true");
     }
 
     #[tokio::test]
@@ -1131,6 +1539,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -1170,6 +1582,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -1203,6 +1619,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         assert!(input.validate().is_err());
@@ -1223,6 +1643,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         assert!(input2.validate().is_err());
@@ -1243,6 +1667,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         assert!(input3.validate().is_err());
@@ -1272,6 +1700,10 @@ mod tests {
             timeout_ms: Some(5000), // 5 second timeout
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -1308,6 +1740,10 @@ mod tests {
             timeout_ms: None,
             retries: Some(2), // 2 retries
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -1356,6 +1792,10 @@ mod tests {
             timeout_ms: None,
             retries: Some(1), // 1 retry
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -1392,6 +1832,10 @@ mod tests {
             timeout_ms: None,
             retries: Some(2), // 2 retries available
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -1439,6 +1883,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -1480,6 +1928,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -1522,6 +1974,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -1561,6 +2017,10 @@ mod tests {
             timeout_ms: Some(100), // Very short timeout - 100ms
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let output = tool.invoke(input).await;
@@ -1590,6 +2050,10 @@ mod tests {
             timeout_ms: Some(35000), // 35 seconds - should fail
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         assert!(input.validate().is_err());
@@ -1610,6 +2074,10 @@ mod tests {
             timeout_ms: Some(25000), // 25 seconds - should pass
             retries: None,
             follow_redirects: None,
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         assert!(input.validate().is_ok());
@@ -1648,6 +2116,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: Some(true),
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let result = tool.invoke(input).await;
@@ -1671,6 +2143,10 @@ mod tests {
             timeout_ms: None,
             retries: None,
             follow_redirects: Some(false),
+            tls: None,
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
         };
 
         let result_no_redirect = tool.invoke(input_no_redirect).await;
@@ -1681,4 +2157,576 @@ mod tests {
             _ => panic!("Expected redirect response without following"),
         }
     }
+
+    #[tokio::test]
+    async fn test_circuit_breaker() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let input = || Input {
+            circuit_breaker: Some(CircuitBreakerConfig {
+                failure_threshold: 2,
+                reset_after_ms: 200,
+            }),
+            ..pagination_input(format!("{}/flaky?attempt=1", server.url()), None)
+        };
+
+        let failing = server
+            .mock("GET", "/flaky")
+            .match_query(mockito::Matcher::Any)
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+
+        for _ in 0..2 {
+            match tool.invoke(input()).await {
+                Output::Err {
+                    kind, status_code, ..
+                } => {
+                    assert_eq!(kind, HttpErrorKind::Http);
+                    assert_eq!(status_code, Some(503));
+                }
+                Output::Ok { .. } => panic!("Expected a server error"),
+            }
+        }
+
+        // The circuit is open, no request is sent.
+        match tool.invoke(input()).await {
+            Output::Err {
+                reason,
+                kind,
+                status_code,
+            } => {
+                assert_eq!(kind, HttpErrorKind::CircuitOpen);
+                assert_eq!(status_code, None);
+                assert!(reason.contains("/flaky"));
+            }
+            Output::Ok { .. } => panic!("Expected the circuit to be open"),
+        }
+
+        failing.assert_async().await;
+        failing.remove_async().await;
+
+        let recovered = server
+            .mock("GET", "/flaky")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_body("ok")
+            .expect(1)
+            .create_async()
+            .await;
+
+        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+
+        match tool.invoke(input()).await {
+            Output::Ok { status, .. } => assert_eq!(status, 200),
+            Output::Err { reason, .. } => panic!("Expected the circuit to reset, got: {}", reason),
+        }
+
+        recovered.assert_async().await;
+    }
+
+    #[test]
+    fn test_circuit_breaker_validation() {
+        let input = |failure_threshold, reset_after_ms| Input {
+            circuit_breaker: Some(CircuitBreakerConfig {
+                failure_threshold,
+                reset_after_ms,
+            }),
+            ..pagination_input("https://example.com".to_string(), None)
+        };
+
+        assert!(input(3, 1000).validate().is_ok());
+        assert!(matches!(
+            input(0, 1000).validate(),
+            Err(ValidationError::InvalidCircuitBreaker(_))
+        ));
+        assert!(matches!(
+            input(3, 0).validate(),
+            Err(ValidationError::InvalidCircuitBreaker(_))
+        ));
+    }
+
+    fn pagination_input(url: String, max_pages: Option<u32>) -> Input {
+        Input {
+            method: HttpMethod::Get,
+            url: UrlInput::FullUrl(url),
+            headers: Some(HashMap::from([(
+                "x-request-id".to_string(),
+                "paged".to_string(),
+            )])),
+            query: None,
+            auth: None,
+            body: None,
+            expect_json: None,
+            json_schema: None,
+            timeout_ms: None,
+            retries: None,
+            follow_redirects: None,
+            tls: None,
+            follow_pagination: Some(true),
+            max_pages,
+            circuit_breaker: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_follow_pagination() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        // Relative and absolute links are both followed.
+        let first = server
+            .mock("GET", "/items")
+            .match_header("x-request-id", "paged")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("link", r#"</items/2>; rel="next", </items/9>; rel="last""#)
+            .with_body(r#"[{"id":1},{"id":2}]"#)
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/items/2")
+            .match_header("x-request-id", "paged")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header(
+                "link",
+                &format!(r#"<{}/items/3>; rel="next""#, server.url()),
+            )
+            .with_body(r#"[{"id":3}]"#)
+            .create_async()
+            .await;
+        let third = server
+            .mock("GET", "/items/3")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"id":4}]"#)
+            .create_async()
+            .await;
+
+        let output = tool
+            .invoke(pagination_input(format!("{}/items", server.url()), None))
+            .await;
+
+        match output {
+            Output::Ok {
+                status,
+                text,
+                json,
+                raw_base64,
+                ..
+            } => {
+                assert_eq!(status, 200);
+                assert_eq!(
+                    json,
+                    Some(serde_json::json!([{"id":1},{"id":2},{"id":3},{"id":4}]))
+                );
+                assert_eq!(
+                    text.as_deref(),
+                    Some("[{\"id\":1},{\"id\":2}]\n[{\"id\":3}]\n[{\"id\":4}]")
+                );
+                assert_eq!(
+                    raw_base64,
+                    base64::engine::general_purpose::STANDARD.encode(text.unwrap())
+                );
+            }
+            Output::Err { reason, .. } => panic!("Expected merged pages, got error: {}", reason),
+        }
+
+        first.assert_async().await;
+        second.assert_async().await;
+        third.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_follow_pagination_same_origin_only() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let first = server
+            .mock("GET", "/items")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("link", r#"<https://other.example.com/items/2>; rel="next""#)
+            .with_body(r#"[{"id":1}]"#)
+            .create_async()
+            .await;
+
+        let mut input = pagination_input(format!("{}/items", server.url()), None);
+        input.auth = Some(AuthConfig::BearerToken {
+            token: "test-token".to_string(),
+        });
+
+        // The link to another origin is not followed so the token is not
+        // sent there.
+        match tool.invoke(input).await {
+            Output::Ok { json, .. } => {
+                assert_eq!(json, Some(serde_json::json!([{"id":1}])));
+            }
+            Output::Err { reason, .. } => panic!("Expected the first page, got error: {}", reason),
+        }
+
+        first.assert_async().await;
+
+        // Only GET requests are paginated.
+        let mut input = pagination_input(format!("{}/items", server.url()), None);
+        input.method = HttpMethod::Post;
+
+        assert!(matches!(
+            input.validate(),
+            Err(ValidationError::PaginationRequiresGet)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_follow_pagination_max_pages() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let first = server
+            .mock("GET", "/lines")
+            .with_status(200)
+            .with_header("link", r#"</lines/2>; rel="next""#)
+            .with_body("first")
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/lines/2")
+            .with_status(200)
+            .with_header("link", r#"</lines/3>; rel="next""#)
+            .with_body("second")
+            .create_async()
+            .await;
+        let third = server
+            .mock("GET", "/lines/3")
+            .with_status(200)
+            .with_body("third")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let output = tool
+            .invoke(pagination_input(format!("{}/lines", server.url()), Some(2)))
+            .await;
+
+        match output {
+            Output::Ok { text, json, .. } => {
+                assert_eq!(text.as_deref(), Some("first\nsecond"));
+                assert_eq!(json, None);
+            }
+            Output::Err { reason, .. } => panic!("Expected merged pages, got error: {}", reason),
+        }
+
+        first.assert_async().await;
+        second.assert_async().await;
+        third.assert_async().await;
+
+        // A failing page fails the whole request.
+        let failing = server
+            .mock("GET", "/failing/2")
+            .with_status(500)
+            .create_async()
+            .await;
+        let _first = server
+            .mock("GET", "/failing")
+            .with_status(200)
+            .with_header("link", r#"</failing/2>; rel="next""#)
+            .with_body("first")
+            .create_async()
+            .await;
+
+        match tool
+            .invoke(pagination_input(format!("{}/failing", server.url()), None))
+            .await
+        {
+            Output::Err {
+                kind, status_code, ..
+            } => {
+                assert_eq!(kind, HttpErrorKind::Http);
+                assert_eq!(status_code, Some(500));
+            }
+            Output::Ok { .. } => panic!("Expected the failing page to fail the request"),
+        }
+
+        failing.assert_async().await;
+
+        // JSON pages that are not arrays cannot be merged.
+        let _objects = server
+            .mock("GET", "/objects")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("link", r#"</objects>; rel="next""#)
+            .with_body(r#"{"id":1}"#)
+            .create_async()
+            .await;
+
+        match tool
+            .invoke(pagination_input(format!("{}/objects", server.url()), None))
+            .await
+        {
+            Output::Err { kind, .. } => assert_eq!(kind, HttpErrorKind::Input),
+            Output::Ok { .. } => panic!("Expected objects not to be merged"),
+        }
+
+        assert!(matches!(
+            pagination_input("https://example.com".to_string(), Some(0)).validate(),
+            Err(ValidationError::InvalidMaxPages(_))
+        ));
+        assert!(matches!(
+            pagination_input("https://example.com".to_string(), Some(101)).validate(),
+            Err(ValidationError::InvalidMaxPages(_))
+        ));
+    }
+
+    const CA_PEM: &str = include_str!("_certs/ca.pem");
+    const CLIENT_PEM: &str = include_str!("_certs/client.pem");
+    const CLIENT_KEY: &str = include_str!("_certs/client.key");
+
+    fn base64_pem(pem: &str) -> String {
+        base64::engine::general_purpose::STANDARD.encode(pem)
+    }
+
+    fn tls_input(url: String, tls: TlsConfig) -> Input {
+        Input {
+            method: HttpMethod::Get,
+            url: UrlInput::FullUrl(url),
+            headers: None,
+            query: None,
+            auth: None,
+            body: None,
+            expect_json: None,
+            json_schema: None,
+            timeout_ms: None,
+            retries: None,
+            follow_redirects: None,
+            tls: Some(tls),
+            follow_pagination: None,
+            max_pages: None,
+            circuit_breaker: None,
+        }
+    }
+
+    /// Spawn a TLS server on 127.0.0.1 that requires a client certificate
+    /// signed by the test CA and responds with the client's common name.
+    fn spawn_mtls_server() -> (u16, std::thread::JoinHandle<Option<String>>) {
+        use {
+            openssl::{
+                nid::Nid,
+                ssl::{SslAcceptor, SslFiletype, SslMethod, SslVerifyMode},
+            },
+            std::io::{Read, Write},
+        };
+
+        let certs = concat!(env!("CARGO_MANIFEST_DIR"), "/src/_certs");
+        let mut acceptor = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls()).unwrap();
+        acceptor
+            .set_private_key_file(format!("{certs}/server.key"), SslFiletype::PEM)
+            .unwrap();
+        acceptor
+            .set_certificate_chain_file(format!("{certs}/server.pem"))
+            .unwrap();
+        acceptor.set_ca_file(format!("{certs}/ca.pem")).unwrap();
+        acceptor.set_verify(SslVerifyMode::PEER | SslVerifyMode::FAIL_IF_NO_PEER_CERT);
+        let acceptor = acceptor.build();
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().ok()?;
+            let mut stream = acceptor.accept(stream).ok()?;
+
+            let common_name = stream
+                .ssl()
+                .peer_certificate()?
+                .subject_name()
+                .entries_by_nid(Nid::COMMONNAME)
+                .next()?
+                .data()
+                .as_utf8()
+                .ok()?
+                .to_string();
+
+            let mut request = [0u8; 4096];
+            let _ = stream.read(&mut request);
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                common_name.len(),
+                common_name
+            )
+            .ok()?;
+
+            Some(common_name)
+        });
+
+        (port, handle)
+    }
+
+    #[tokio::test]
+    async fn test_tls_validation() {
+        let url = "https://example.com".to_string();
+
+        // Valid client identity and CA bundle
+        let valid = tls_input(
+            url.clone(),
+            TlsConfig {
+                client_cert: Some(base64_pem(CLIENT_PEM)),
+                client_key: Some(base64_pem(CLIENT_KEY)),
+                ca_bundle: Some(base64_pem(&format!("{CA_PEM}{CLIENT_PEM}"))),
+            },
+        );
+        assert!(valid.validate().is_ok());
+        assert_eq!(
+            valid
+                .tls
+                .as_ref()
+                .unwrap()
+                .root_certificates()
+                .unwrap()
+                .len(),
+            2
+        );
+
+        // Certificate without a key
+        let incomplete = tls_input(
+            url.clone(),
+            TlsConfig {
+                client_cert: Some(base64_pem(CLIENT_PEM)),
+                ..Default::default()
+            },
+        );
+        assert!(matches!(
+            incomplete.validate(),
+            Err(ValidationError::IncompleteClientIdentity)
+        ));
+
+        // Not base64
+        let not_base64 = tls_input(
+            url.clone(),
+            TlsConfig {
+                ca_bundle: Some("not base64!".to_string()),
+                ..Default::default()
+            },
+        );
+        assert!(matches!(
+            not_base64.validate(),
+            Err(ValidationError::InvalidCaBundle(_))
+        ));
+
+        // Base64 but not a PEM document
+        let not_pem = tls_input(
+            url.clone(),
+            TlsConfig {
+                ca_bundle: Some(base64_pem("hello")),
+                ..Default::default()
+            },
+        );
+        assert!(matches!(
+            not_pem.validate(),
+            Err(ValidationError::InvalidCaBundle(_))
+        ));
+
+        // Malformed PEM contents
+        let malformed =
+            "-----BEGIN CERTIFICATE-----\nbm90IGEgY2VydA==\n-----END CERTIFICATE-----\n";
+        let malformed_ca = tls_input(
+            url.clone(),
+            TlsConfig {
+                ca_bundle: Some(base64_pem(malformed)),
+                ..Default::default()
+            },
+        );
+        assert!(matches!(
+            malformed_ca.validate(),
+            Err(ValidationError::InvalidCaBundle(_))
+        ));
+
+        // Client certificate both in auth and tls
+        let conflicting = Input {
+            auth: Some(
+                serde_json::from_value(serde_json::json!({
+                    "cert_pem": CLIENT_PEM,
+                    "key_pem": format!("best-encryption-ever-{}", serde_json::json!(CLIENT_KEY)),
+                }))
+                .unwrap(),
+            ),
+            ..tls_input(
+                url.clone(),
+                TlsConfig {
+                    client_cert: Some(base64_pem(CLIENT_PEM)),
+                    client_key: Some(base64_pem(CLIENT_KEY)),
+                    ca_bundle: None,
+                },
+            )
+        };
+        assert!(matches!(
+            conflicting.validate(),
+            Err(ValidationError::ConflictingClientIdentity)
+        ));
+
+        let malformed_identity = tls_input(
+            url,
+            TlsConfig {
+                client_cert: Some(base64_pem(malformed)),
+                client_key: Some(base64_pem(CLIENT_KEY)),
+                ca_bundle: None,
+            },
+        );
+        assert!(matches!(
+            malformed_identity.validate(),
+            Err(ValidationError::InvalidClientIdentity(_))
+        ));
+
+        // Invalid TLS input is reported as an input error
+        match Http::new().await.invoke(malformed_identity).await {
+            Output::Err { kind, .. } => assert_eq!(kind, HttpErrorKind::Input),
+            _ => panic!("Expected input error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mtls_handshake() {
+        let tool = Http::new().await;
+        let (port, server) = spawn_mtls_server();
+
+        let input = tls_input(
+            format!("https://127.0.0.1:{port}/"),
+            TlsConfig {
+                client_cert: Some(base64_pem(CLIENT_PEM)),
+                client_key: Some(base64_pem(CLIENT_KEY)),
+                ca_bundle: Some(base64_pem(CA_PEM)),
+            },
+        );
+
+        match tool.invoke(input).await {
+            Output::Ok { status, text, .. } => {
+                assert_eq!(status, 200);
+                assert_eq!(text.as_deref(), Some("nexus-http-client"));
+            }
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        assert_eq!(server.join().unwrap().as_deref(), Some("nexus-http-client"));
+    }
+
+    #[tokio::test]
+    async fn test_mtls_handshake_without_client_certificate() {
+        let tool = Http::new().await;
+        let (port, server) = spawn_mtls_server();
+
+        let input = tls_input(
+            format!("https://127.0.0.1:{port}/"),
+            TlsConfig {
+                ca_bundle: Some(base64_pem(CA_PEM)),
+                ..Default::default()
+            },
+        );
+
+        match tool.invoke(input).await {
+            Output::Err { kind, .. } => assert_eq!(kind, HttpErrorKind::Network),
+            Output::Ok { .. } => panic!("Expected the server to reject the handshake"),
+        }
+
+        assert!(server.join().unwrap().is_none());
+    }
 }
@@ -5,15 +5,29 @@
 use {
     crate::{
         errors::HttpToolError,
-        models::{AuthConfig, HttpMethod, RequestBody, UrlInput},
+        models::{AuthConfig, HttpMethod, RequestBody, TlsConfig, UrlInput},
     },
     backon::{ExponentialBuilder, Retryable},
     base64::Engine,
-    reqwest::{multipart::Form, Client, Method},
-    std::collections::HashMap,
+    hmac::{Hmac, Mac},
+    reqwest::{
+        header::{HeaderName, HeaderValue},
+        multipart::Form,
+        Client,
+        Method,
+        RequestBuilder,
+    },
+    sha2::{Digest, Sha256},
+    std::{
+        collections::HashMap,
+        time::{SystemTime, UNIX_EPOCH},
+    },
     url::Url,
 };
 
+/// Header that carries the timestamp of signed requests.
+pub const HMAC_TIMESTAMP_HEADER: &str = "X-Timestamp";
+
 /// HTTP Generic client for making requests
 pub struct HttpClient {
     /// HTTP client for making requests
@@ -23,13 +37,15 @@ pub struct HttpClient {
 impl HttpClient {
     /// Creates a new HTTP client instance with default configuration
     pub fn new() -> Result<Self, HttpToolError> {
-        Self::with_config(None, None) // Default: 30s timeout, follow redirects
+        Self::with_config(None, None, None, None) // Default: 30s timeout, follow redirects
     }
 
     /// Creates a new HTTP client with custom configuration
     pub fn with_config(
         timeout_ms: Option<u64>,
         follow_redirects: Option<bool>,
+        tls: Option<&TlsConfig>,
+        auth: Option<&AuthConfig>,
     ) -> Result<Self, HttpToolError> {
         let mut builder = Client::builder();
 
@@ -45,6 +61,39 @@ impl HttpClient {
             builder = builder.redirect(reqwest::redirect::Policy::none());
         }
 
+        // Present the client identity and trust only the given CA bundle
+        if let Some(tls) = tls {
+            if let Some(identity) = tls
+                .identity()
+                .map_err(HttpToolError::from_validation_error)?
+            {
+                builder = builder.identity(identity);
+            }
+
+            let certificates = tls
+                .root_certificates()
+                .map_err(HttpToolError::from_validation_error)?;
+
+            if !certificates.is_empty() {
+                builder = builder.tls_built_in_root_certs(false);
+            }
+
+            for certificate in certificates {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+
+        // Client certificates are part of the TLS handshake, not the request
+        if let Some(AuthConfig::ClientCert { cert_pem, key_pem }) = auth {
+            let identity =
+                reqwest::Identity::from_pkcs8_pem(cert_pem.as_bytes(), key_pem.as_bytes())
+                    .map_err(|e| {
+                        HttpToolError::ErrInput(format!("Invalid client certificate or key: {e}"))
+                    })?;
+
+            builder = builder.identity(identity);
+        }
+
         let client = builder.build().map_err(HttpToolError::from_network_error)?;
 
         Ok(Self { client })
@@ -122,6 +171,9 @@ impl HttpClient {
         match auth {
             AuthConfig::None => Ok(request),
             AuthConfig::BearerToken { token } => Ok(request.bearer_auth(token)),
+            // The token is refreshed by `jwt::refresh_jwt` before the request
+            // is built.
+            AuthConfig::JwtRefresh { token, .. } => Ok(request.bearer_auth(token.as_str())),
             AuthConfig::ApiKeyHeader { key, header_name } => {
                 let header_name = header_name.as_deref().unwrap_or("X-API-Key");
                 Ok(request.header(header_name, key))
@@ -133,7 +185,64 @@ impl HttpClient {
             AuthConfig::BasicAuth { username, password } => {
                 Ok(request.basic_auth(username, Some(password)))
             }
+            // The signature covers the body so it's added by `sign_request`.
+            AuthConfig::HmacSignature { .. } => Ok(request),
+            // The identity is configured on the client in `with_config`.
+            AuthConfig::ClientCert { .. } => Ok(request),
+        }
+    }
+
+    /// Signs the request if HMAC signature authentication is configured. Must
+    /// be called after the body is set.
+    pub fn sign_request(
+        &self,
+        request: RequestBuilder,
+        auth: Option<&AuthConfig>,
+    ) -> Result<RequestBuilder, HttpToolError> {
+        let Some(AuthConfig::HmacSignature {
+            secret,
+            header_name,
+            include_timestamp,
+        }) = auth
+        else {
+            return Ok(request);
+        };
+
+        let mut request = request.build().map_err(HttpToolError::from_network_error)?;
+
+        let timestamp = include_timestamp.then(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs()
+        });
+
+        let body = match request.body() {
+            Some(body) => body.as_bytes().ok_or_else(|| {
+                HttpToolError::ErrInput(
+                    "HMAC signatures cannot be computed for streamed bodies".to_string(),
+                )
+            })?,
+            None => &[],
+        };
+
+        let message = signing_message(timestamp, request.method(), request.url().path(), body);
+        let signature = hmac_signature(secret.as_bytes(), &message);
+
+        let header_name = HeaderName::from_bytes(header_name.as_bytes())
+            .map_err(|e| HttpToolError::ErrInput(format!("Invalid signature header name: {e}")))?;
+        let signature = HeaderValue::from_str(&signature)
+            .map_err(|e| HttpToolError::ErrInput(format!("Invalid signature header: {e}")))?;
+
+        request.headers_mut().insert(header_name, signature);
+
+        if let Some(timestamp) = timestamp {
+            request
+                .headers_mut()
+                .insert(HMAC_TIMESTAMP_HEADER, HeaderValue::from(timestamp));
         }
+
+        Ok(RequestBuilder::from_parts(self.client.clone(), request))
     }
 
     /// Check if HTTP method supports request body
@@ -260,11 +369,32 @@ impl Default for HttpClient {
     }
 }
 
+/// Builds the message covered by HMAC signatures: the method, path and hex
+/// encoded SHA-256 hash of the body separated by newlines, optionally
+/// preceded by the timestamp.
+fn signing_message(timestamp: Option<u64>, method: &Method, path: &str, body: &[u8]) -> String {
+    let message = format!("{method}\n{path}\n{:x}", Sha256::digest(body));
+
+    match timestamp {
+        Some(timestamp) => format!("{timestamp}\n{message}"),
+        None => message,
+    }
+}
+
+/// Computes the base64 encoded HMAC-SHA256 of the message.
+fn hmac_signature(secret: &[u8], message: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+
+    mac.update(message.as_bytes());
+
+    base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes())
+}
+
 #[cfg(test)]
 mod tests {
     use {
         super::*,
-        crate::models::{AuthConfig, HttpMethod, RequestBody, UrlInput},
+        crate::models::{AuthConfig, HttpMethod, RequestBody, TlsConfig, UrlInput},
         std::collections::HashMap,
     };
 
@@ -276,10 +406,40 @@ mod tests {
 
     #[test]
     fn test_http_client_with_config() {
-        let client = HttpClient::with_config(Some(5000), Some(true));
+        let client = HttpClient::with_config(Some(5000), Some(true), None, None);
         assert!(client.is_ok());
     }
 
+    #[test]
+    fn test_http_client_with_client_cert() {
+        let rcgen::CertifiedKey { cert, key_pair } =
+            rcgen::generate_simple_self_signed(vec!["client.example.com".to_string()]).unwrap();
+
+        let auth: AuthConfig = serde_json::from_value(serde_json::json!({
+            "cert_pem": cert.pem(),
+            "key_pem": format!("best-encryption-ever-{}", serde_json::json!(key_pair.serialize_pem())),
+        }))
+        .unwrap();
+
+        assert!(matches!(auth, AuthConfig::ClientCert { .. }));
+        assert!(HttpClient::with_config(None, None, None, Some(&auth)).is_ok());
+
+        // A key that does not belong to a PEM document is invalid input.
+        let auth: AuthConfig = serde_json::from_value(serde_json::json!({
+            "cert_pem": cert.pem(),
+            "key_pem": "best-encryption-ever-\"not a key\"",
+        }))
+        .unwrap();
+
+        match HttpClient::with_config(None, None, None, Some(&auth)) {
+            Err(HttpToolError::ErrInput(message)) => {
+                assert!(message.starts_with("Invalid client certificate or key"))
+            }
+            Err(e) => panic!("Expected input error, got: {e}"),
+            Ok(_) => panic!("Expected input error, got a client"),
+        }
+    }
+
     #[test]
     fn test_resolve_url_full() {
         let client = HttpClient::new().unwrap();
@@ -486,4 +646,59 @@ mod tests {
         assert!(result.is_ok());
         // Body should be ignored for GET requests
     }
+
+    #[test]
+    fn test_hmac_signature() {
+        // RFC 4231 test case 2.
+        assert_eq!(
+            hmac_signature(b"Jefe", "what do ya want for nothing?"),
+            "W9zBRr9gdU5qBCQmCJV1x1oAPwidJzmDnexYuWTsOEM="
+        );
+    }
+
+    #[test]
+    fn test_signing_message() {
+        let empty_hash = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+        assert_eq!(
+            signing_message(None, &Method::GET, "/users", b""),
+            format!("GET\n/users\n{empty_hash}")
+        );
+        assert_eq!(
+            signing_message(Some(1700000000), &Method::GET, "/users", b""),
+            format!("1700000000\nGET\n/users\n{empty_hash}")
+        );
+    }
+
+    #[test]
+    fn test_sign_request_with_timestamp() {
+        let client = HttpClient::new().unwrap();
+        let url = url::Url::parse("https://example.com/users").unwrap();
+        let request = client.client.request(reqwest::Method::GET, url);
+
+        let auth: AuthConfig = serde_json::from_value(serde_json::json!({
+            "secret": "best-encryption-ever-\"test-secret\"",
+            "header_name": "X-Signature",
+            "include_timestamp": true
+        }))
+        .unwrap();
+
+        let request = client
+            .sign_request(request, Some(&auth))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let timestamp = request.headers()[HMAC_TIMESTAMP_HEADER]
+            .to_str()
+            .unwrap()
+            .parse::<u64>()
+            .unwrap();
+        let message = signing_message(Some(timestamp), &Method::GET, "/users", b"");
+
+        assert_eq!(
+            request.headers()["X-Signature"],
+            hmac_signature(b"test-secret", &message).as_str()
+        );
+    }
 }
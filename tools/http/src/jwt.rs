@@ -0,0 +1,407 @@
+//! Refresh of short-lived JWTs for [`AuthConfig::JwtRefresh`]
+//!
+//! The `exp` claim of the token is read without verifying the signature, that
+//! is up to the server. When the token expires within the leeway, a new one is
+//! requested from the token endpoint with the `client_credentials` grant. New
+//! tokens are cached per token endpoint and client credentials so that
+//! concurrent requests wait for a single refresh and later requests reuse its
+//! token. The client secret is part of the cache key so that a cached token is
+//! only served to callers that could have requested it themselves.
+
+use {
+    crate::{
+        errors::HttpToolError,
+        http_client::HttpClient,
+        models::{AuthConfig, RequestBody, UrlInput},
+    },
+    base64::Engine,
+    serde::Deserialize,
+    sha2::{Digest, Sha256},
+    std::{
+        collections::HashMap,
+        sync::{Arc, Mutex, OnceLock, PoisonError},
+        time::{SystemTime, UNIX_EPOCH},
+    },
+};
+
+/// Tokens refreshed by this process.
+static GLOBAL_TOKEN_CACHE: OnceLock<TokenCache> = OnceLock::new();
+
+/// A refreshed token and its expiry as a Unix timestamp, if known.
+#[derive(Clone)]
+struct CachedToken {
+    token: String,
+    expires_at: Option<u64>,
+}
+
+/// Cache slot of one client. The lock is held while refreshing.
+type TokenSlot = Arc<tokio::sync::Mutex<Option<CachedToken>>>;
+
+/// Refreshed tokens keyed by token endpoint, client ID and the SHA-256 of the
+/// client secret.
+#[derive(Default)]
+struct TokenCache {
+    slots: Mutex<HashMap<(String, String, [u8; 32]), TokenSlot>>,
+}
+
+impl TokenCache {
+    fn global() -> &'static Self {
+        GLOBAL_TOKEN_CACHE.get_or_init(Self::default)
+    }
+
+    fn slot(&self, refresh_url: &str, client_id: &str, client_secret: &str) -> TokenSlot {
+        let secret_hash = Sha256::digest(client_secret.as_bytes()).into();
+
+        self.slots
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry((refresh_url.to_string(), client_id.to_string(), secret_hash))
+            .or_default()
+            .clone()
+    }
+}
+
+/// The claims of the token that matter here.
+#[derive(Deserialize)]
+struct Claims {
+    #[serde(default)]
+    exp: Option<u64>,
+}
+
+/// Successful response of the token endpoint.
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+/// Replace the token of [`AuthConfig::JwtRefresh`] with a fresh one if it
+/// expires within its leeway. Other authentication is left as is.
+pub(crate) async fn refresh_jwt(auth: Option<&mut AuthConfig>) -> Result<(), HttpToolError> {
+    let Some(AuthConfig::JwtRefresh {
+        token,
+        refresh_url,
+        client_id,
+        client_secret,
+        leeway_seconds,
+    }) = auth
+    else {
+        return Ok(());
+    };
+
+    if !expires_within(jwt_expiry(token.as_str())?, *leeway_seconds) {
+        return Ok(());
+    }
+
+    let slot = TokenCache::global().slot(refresh_url, client_id, client_secret.as_str());
+    let mut cached = slot.lock().await;
+
+    // An earlier or concurrent request may have refreshed the token already.
+    if let Some(cached) = cached.as_ref() {
+        if !expires_within(cached.expires_at, *leeway_seconds) {
+            **token = cached.token.clone();
+
+            return Ok(());
+        }
+    }
+
+    let fresh = request_token(refresh_url, client_id, client_secret.as_str()).await?;
+
+    **token = fresh.token.clone();
+    *cached = Some(fresh);
+
+    Ok(())
+}
+
+/// Request a new token with the `client_credentials` grant.
+async fn request_token(
+    refresh_url: &str,
+    client_id: &str,
+    client_secret: &str,
+) -> Result<CachedToken, HttpToolError> {
+    let http_client = HttpClient::new()?;
+    let url = http_client.resolve_url(&UrlInput::FullUrl(refresh_url.to_string()))?;
+    let form = RequestBody::Form {
+        data: HashMap::from([
+            ("grant_type".to_string(), "client_credentials".to_string()),
+            ("client_id".to_string(), client_id.to_string()),
+            ("client_secret".to_string(), client_secret.to_string()),
+        ]),
+    };
+
+    let request = http_client.build_request(reqwest::Method::POST, url, None, None, None)?;
+    let request = http_client.build_body(request, &form, &reqwest::Method::POST)?;
+
+    let response = http_client.execute(request).await?;
+    let status = response.status();
+    let text = response
+        .text()
+        .await
+        .map_err(HttpToolError::from_network_error)?;
+
+    if !status.is_success() {
+        return Err(HttpToolError::ErrHttp {
+            status: status.as_u16(),
+            reason: format!(
+                "Token refresh failed: {}",
+                status.canonical_reason().unwrap_or("Unknown")
+            ),
+            snippet: text.chars().take(200).collect(),
+        });
+    }
+
+    let response =
+        serde_json::from_str::<TokenResponse>(&text).map_err(HttpToolError::from_json_error)?;
+
+    // Access tokens are not necessarily JWTs, fall back to `expires_in`.
+    let expires_at = jwt_expiry(&response.access_token)
+        .ok()
+        .flatten()
+        .or_else(|| response.expires_in.map(|expires_in| now() + expires_in));
+
+    Ok(CachedToken {
+        token: response.access_token,
+        expires_at,
+    })
+}
+
+/// Read the `exp` claim of the JWT without verifying its signature.
+fn jwt_expiry(token: &str) -> Result<Option<u64>, HttpToolError> {
+    let invalid = |reason: String| HttpToolError::ErrInput(format!("Invalid JWT: {reason}"));
+
+    let [_, payload, _] = token.split('.').collect::<Vec<_>>()[..] else {
+        return Err(invalid("expected three dot separated segments".to_string()));
+    };
+
+    let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .map_err(|e| invalid(e.to_string()))?;
+
+    let claims = serde_json::from_slice::<Claims>(&payload).map_err(|e| invalid(e.to_string()))?;
+
+    Ok(claims.exp)
+}
+
+/// Tokens without a known expiry never expire.
+fn expires_within(expires_at: Option<u64>, leeway_seconds: u64) -> bool {
+    expires_at.is_some_and(|expires_at| expires_at <= now().saturating_add(leeway_seconds))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| now.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        mockito::{Matcher, Server},
+        serde_json::json,
+    };
+
+    /// Create an unsigned JWT that expires `expires_in` seconds from now.
+    fn jwt(expires_in: i64) -> String {
+        let encode = |value: serde_json::Value| {
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(value.to_string())
+        };
+
+        format!(
+            "{}.{}.signature",
+            encode(json!({ "alg": "HS256", "typ": "JWT" })),
+            encode(json!({ "sub": "client", "exp": now() as i64 + expires_in })),
+        )
+    }
+
+    fn jwt_auth(token: &str, refresh_url: String) -> AuthConfig {
+        jwt_auth_with_secret(token, refresh_url, "secret")
+    }
+
+    fn jwt_auth_with_secret(token: &str, refresh_url: String, secret: &str) -> AuthConfig {
+        serde_json::from_value(json!({
+            "token": format!("best-encryption-ever-{}", json!(token)),
+            "refresh_url": refresh_url,
+            "client_id": "client",
+            "client_secret": format!("best-encryption-ever-{}", json!(secret)),
+            "leeway_seconds": 30,
+        }))
+        .unwrap()
+    }
+
+    fn token(auth: &AuthConfig) -> &str {
+        match auth {
+            AuthConfig::JwtRefresh { token, .. } => token,
+            _ => panic!("Expected JwtRefresh auth"),
+        }
+    }
+
+    #[test]
+    fn test_jwt_refresh_without_leeway() {
+        let auth: AuthConfig = serde_json::from_value(json!({
+            "token": format!("best-encryption-ever-{}", json!("token")),
+            "refresh_url": "https://auth.example.com/token",
+            "client_id": "client",
+            "client_secret": format!("best-encryption-ever-{}", json!("secret")),
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            auth,
+            AuthConfig::JwtRefresh {
+                leeway_seconds: 0,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_jwt_expiry() {
+        let token = jwt(60);
+        let expiry = jwt_expiry(&token).unwrap().unwrap();
+
+        assert!(expiry > now() && expiry <= now() + 60);
+        assert!(!expires_within(Some(expiry), 30));
+        assert!(expires_within(Some(expiry), 90));
+        assert!(!expires_within(None, 90));
+
+        assert!(matches!(
+            jwt_expiry("not-a-jwt"),
+            Err(HttpToolError::ErrInput(_))
+        ));
+        assert!(matches!(
+            jwt_expiry("a.!!!.c"),
+            Err(HttpToolError::ErrInput(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_jwt_keeps_valid_token() {
+        let mut server = Server::new_async().await;
+        let mock = server.mock("POST", "/token").expect(0).create_async().await;
+
+        let valid = jwt(3600);
+        let mut auth = jwt_auth(&valid, format!("{}/token", server.url()));
+
+        refresh_jwt(Some(&mut auth)).await.unwrap();
+        assert_eq!(token(&auth), valid);
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_refresh_jwt_refreshes_once() {
+        let mut server = Server::new_async().await;
+        let fresh = jwt(3600);
+
+        let mock = server
+            .mock("POST", "/token")
+            .match_header("content-type", "application/x-www-form-urlencoded")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("grant_type".into(), "client_credentials".into()),
+                Matcher::UrlEncoded("client_id".into(), "client".into()),
+                Matcher::UrlEncoded("client_secret".into(), "secret".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "access_token": fresh, "token_type": "bearer" }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let expiring = jwt(10);
+        let refresh_url = format!("{}/token", server.url());
+        let mut first = jwt_auth(&expiring, refresh_url.clone());
+        let mut second = jwt_auth(&expiring, refresh_url.clone());
+
+        // Concurrent requests share a single refresh.
+        let (a, b) = tokio::join!(
+            refresh_jwt(Some(&mut first)),
+            refresh_jwt(Some(&mut second))
+        );
+        a.unwrap();
+        b.unwrap();
+
+        assert_eq!(token(&first), fresh);
+        assert_eq!(token(&second), fresh);
+
+        // Later requests with the old token reuse the refreshed one.
+        let mut third = jwt_auth(&expiring, refresh_url);
+        refresh_jwt(Some(&mut third)).await.unwrap();
+        assert_eq!(token(&third), fresh);
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_refresh_jwt_cache_requires_secret() {
+        let mut server = Server::new_async().await;
+        let fresh = jwt(3600);
+
+        let mock = server
+            .mock("POST", "/token")
+            .match_body(Matcher::UrlEncoded("client_secret".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "access_token": fresh }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let rejected = server
+            .mock("POST", "/token")
+            .match_body(Matcher::UrlEncoded("client_secret".into(), "guess".into()))
+            .with_status(401)
+            .with_body(json!({ "error": "invalid_client" }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let expiring = jwt(10);
+        let refresh_url = format!("{}/token", server.url());
+
+        let mut owner = jwt_auth(&expiring, refresh_url.clone());
+        refresh_jwt(Some(&mut owner)).await.unwrap();
+        assert_eq!(token(&owner), fresh);
+
+        // The same endpoint and client with another secret does not get the
+        // cached token but has to refresh it itself.
+        let mut other = jwt_auth_with_secret(&expiring, refresh_url, "guess");
+
+        assert!(matches!(
+            refresh_jwt(Some(&mut other)).await,
+            Err(HttpToolError::ErrHttp { status: 401, .. })
+        ));
+        assert_eq!(token(&other), expiring);
+
+        mock.assert_async().await;
+        rejected.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_refresh_jwt_failure() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/token")
+            .with_status(401)
+            .with_body(json!({ "error": "invalid_client" }).to_string())
+            .create_async()
+            .await;
+
+        let mut auth = jwt_auth(&jwt(-60), format!("{}/token", server.url()));
+
+        match refresh_jwt(Some(&mut auth)).await {
+            Err(HttpToolError::ErrHttp {
+                status, snippet, ..
+            }) => {
+                assert_eq!(status, 401);
+                assert!(snippet.contains("invalid_client"));
+            }
+            other => panic!("Expected the refresh to fail, got {:?}", other),
+        }
+
+        mock.assert_async().await;
+    }
+}
@@ -2,13 +2,17 @@
 
 use nexus_toolkit::bootstrap;
 
+mod circuit_breaker;
 mod errors;
+mod graphql;
 mod http;
 mod http_client;
+mod jwt;
 mod models;
+mod sse;
 mod utils;
 
 #[tokio::main]
 async fn main() {
-    bootstrap!([http::Http,]);
+    bootstrap!([http::Http, graphql::GraphQL, sse::SseConsumer]);
 }
@@ -1,4 +1,7 @@
 use {
+    crate::errors::ValidationError,
+    base64::Engine,
+    nexus_toolkit::Secret,
     schemars::JsonSchema,
     serde::{Deserialize, Serialize},
     serde_json::Value,
@@ -94,6 +97,23 @@ pub enum UrlInput {
 pub enum AuthConfig {
     /// No authentication
     None,
+    /// Short-lived JWT bearer token that is refreshed with the client
+    /// credentials grant shortly before it expires. Must stay before
+    /// `BearerToken` as the variants are untagged.
+    JwtRefresh {
+        /// The current JWT
+        token: Secret<String>,
+        /// URL of the OAuth2 token endpoint
+        refresh_url: String,
+        /// OAuth2 client ID
+        client_id: String,
+        /// OAuth2 client secret
+        client_secret: Secret<String>,
+        /// Refresh the token if it expires within this many seconds. Defaults
+        /// to 0 so that the auth is not mistaken for `BearerToken` without it
+        #[serde(default)]
+        leeway_seconds: u64,
+    },
     /// Bearer token authentication
     BearerToken {
         /// The bearer token
@@ -122,6 +142,24 @@ pub enum AuthConfig {
         /// Password
         password: String,
     },
+    /// HMAC-SHA256 signature of the method, path and body hash in a header
+    HmacSignature {
+        /// The shared secret used as the HMAC key
+        secret: Secret<String>,
+        /// Name of the header that carries the base64 encoded signature
+        header_name: String,
+        /// Prepend the Unix timestamp to the signed message and send it in
+        /// the `X-Timestamp` header
+        #[serde(default)]
+        include_timestamp: bool,
+    },
+    /// Client certificate presented to the server for mutual TLS
+    ClientCert {
+        /// Client certificate (chain) as a PEM string
+        cert_pem: String,
+        /// PKCS#8 private key of the certificate as a PEM string
+        key_pem: Secret<String>,
+    },
 }
 
 /// Request body configuration
@@ -164,3 +202,90 @@ pub struct MultipartField {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_type: Option<String>,
 }
+
+/// Circuit breaker configuration for requests to the same URL
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CircuitBreakerConfig {
+    /// Number of consecutive failures after which the circuit opens
+    pub failure_threshold: u32,
+    /// Time in milliseconds during which requests fail without being sent
+    /// once the circuit is open
+    pub reset_after_ms: u64,
+}
+
+/// TLS configuration for the request. All values are base64 encoded PEM.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+    /// Client certificate (chain) presented to the server for mutual TLS
+    #[serde(default)]
+    pub client_cert: Option<String>,
+    /// PKCS#8 private key of the client certificate
+    #[serde(default)]
+    pub client_key: Option<String>,
+    /// Root CA bundle used to verify the server instead of the system roots
+    #[serde(default)]
+    pub ca_bundle: Option<String>,
+}
+
+impl TlsConfig {
+    /// Build the client identity from the certificate and key, if provided.
+    pub fn identity(&self) -> Result<Option<reqwest::Identity>, ValidationError> {
+        let (cert, key) = match (&self.client_cert, &self.client_key) {
+            (None, None) => return Ok(None),
+            (Some(cert), Some(key)) => (cert, key),
+            _ => return Err(ValidationError::IncompleteClientIdentity),
+        };
+
+        let cert = decode_pem(cert).map_err(ValidationError::InvalidClientIdentity)?;
+        let key = decode_pem(key).map_err(ValidationError::InvalidClientIdentity)?;
+
+        reqwest::Identity::from_pkcs8_pem(&cert, &key)
+            .map(Some)
+            .map_err(|e| ValidationError::InvalidClientIdentity(e.to_string()))
+    }
+
+    /// Parse every certificate in the CA bundle, if provided.
+    pub fn root_certificates(&self) -> Result<Vec<reqwest::Certificate>, ValidationError> {
+        let Some(bundle) = &self.ca_bundle else {
+            return Ok(vec![]);
+        };
+
+        let bundle = decode_pem(bundle).map_err(ValidationError::InvalidCaBundle)?;
+        let bundle = String::from_utf8_lossy(&bundle);
+
+        let certificates = bundle
+            .split_inclusive(PEM_CERTIFICATE_END)
+            .filter(|block| block.contains(PEM_CERTIFICATE_BEGIN))
+            .map(|block| {
+                reqwest::Certificate::from_pem(block.as_bytes())
+                    .map_err(|e| ValidationError::InvalidCaBundle(e.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if certificates.is_empty() {
+            return Err(ValidationError::InvalidCaBundle(
+                "no certificates found".to_string(),
+            ));
+        }
+
+        Ok(certificates)
+    }
+}
+
+const PEM_CERTIFICATE_BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+const PEM_CERTIFICATE_END: &str = "-----END CERTIFICATE-----";
+
+/// Decode a base64 encoded PEM document.
+fn decode_pem(data: &str) -> Result<Vec<u8>, String> {
+    let pem = base64::engine::general_purpose::STANDARD
+        .decode(data.trim())
+        .map_err(|e| format!("value must be base64 encoded PEM: {e}"))?;
+
+    if !String::from_utf8_lossy(&pem).contains("-----BEGIN ") {
+        return Err("value is not a PEM document".to_string());
+    }
+
+    Ok(pem)
+}
@@ -0,0 +1,504 @@
+//! # `xyz.taluslabs.http.sse@1`
+//!
+//! Standard Nexus Tool that connects to a Server-Sent Events endpoint and
+//! collects the events pushed by the server.
+
+use {
+    crate::{
+        errors::{HttpErrorKind, HttpToolError},
+        http_client::HttpClient,
+        jwt::refresh_jwt,
+        models::{AuthConfig, UrlInput},
+    },
+    futures_util::StreamExt,
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+    std::time::Duration,
+    tokio::time::Instant,
+    warp::http::StatusCode,
+};
+
+/// How long to collect events for if no timeout is given.
+const DEFAULT_TIMEOUT_MS: u64 = 30_000;
+/// The longest a single invocation may stay connected.
+const MAX_TIMEOUT_MS: u64 = 300_000;
+
+/// Input model for the SSE consumer tool
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// URL of the Server-Sent Events endpoint
+    pub url: String,
+
+    /// Authentication configuration
+    #[serde(default)]
+    pub auth: Option<AuthConfig>,
+
+    /// Stop after this many events have been received
+    #[serde(default)]
+    pub max_events: Option<u32>,
+
+    /// How long to collect events for in milliseconds (default: 30000)
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+/// A single event received from the server
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub(crate) struct SseEvent {
+    /// The last event ID set by the server, if any
+    pub id: Option<String>,
+    /// The event type, [None] for the default `message` type
+    pub event: Option<String>,
+    /// The event data, multiple `data` lines are joined with a newline
+    pub data: String,
+}
+
+/// Output model for the SSE consumer tool
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum Output {
+    /// The events received until `max_events` was reached, the timeout
+    /// elapsed or the server closed the stream
+    Ok {
+        /// The events in the order they were received
+        events: Vec<SseEvent>,
+    },
+    /// Error response
+    Err {
+        /// Detailed error message
+        reason: String,
+        /// Type of error
+        kind: HttpErrorKind,
+        /// HTTP status code if available
+        #[serde(skip_serializing_if = "Option::is_none")]
+        status_code: Option<u16>,
+    },
+}
+
+impl From<HttpToolError> for Output {
+    fn from(e: HttpToolError) -> Self {
+        match e.to_output() {
+            crate::http::Output::Err {
+                reason,
+                kind,
+                status_code,
+            } => Output::Err {
+                reason,
+                kind,
+                status_code,
+            },
+            crate::http::Output::Ok { .. } => unreachable!("errors convert to the err variant"),
+        }
+    }
+}
+
+/// SSE consumer tool implementation
+pub(crate) struct SseConsumer;
+
+impl NexusTool for SseConsumer {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.http.sse@1")
+    }
+
+    fn path() -> &'static str {
+        "/sse"
+    }
+
+    fn description() -> &'static str {
+        "Connects to a Server-Sent Events endpoint and collects the events it pushes."
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    fn validate(&self, input: &Self::Input) -> Result<(), ToolValidationError> {
+        if input.max_events == Some(0) {
+            return Err(
+                ToolValidationError::new("max_events must be greater than 0").at("/max_events"),
+            );
+        }
+
+        if let Some(timeout_ms) = input.timeout_ms {
+            if timeout_ms == 0 || timeout_ms > MAX_TIMEOUT_MS {
+                return Err(ToolValidationError::new(format!(
+                    "timeout_ms must be between 1 and {MAX_TIMEOUT_MS}"
+                ))
+                .at("/timeout_ms"));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn invoke(&self, mut input: Self::Input) -> Self::Output {
+        match self.collect(&mut input).await {
+            Ok(events) => Output::Ok { events },
+            Err(e) => e.into(),
+        }
+    }
+}
+
+impl SseConsumer {
+    /// Connect to the endpoint and collect events until `max_events` is
+    /// reached, the timeout elapses or the server closes the stream.
+    async fn collect(&self, input: &mut Input) -> Result<Vec<SseEvent>, HttpToolError> {
+        refresh_jwt(input.auth.as_mut()).await?;
+
+        let timeout_ms = input.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS);
+        let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+        let max_events = input.max_events.map(|max| max as usize);
+
+        // The client timeout covers connecting and the whole body, the
+        // deadline below ends the collection gracefully instead.
+        let http_client =
+            HttpClient::with_config(Some(timeout_ms), None, None, input.auth.as_ref())?;
+        let url = http_client.resolve_url(&UrlInput::FullUrl(input.url.clone()))?;
+
+        let request = http_client
+            .build_request(reqwest::Method::GET, url, input.auth.as_ref(), None, None)?
+            .header(reqwest::header::ACCEPT, "text/event-stream")
+            .header(reqwest::header::CACHE_CONTROL, "no-cache");
+        let request = http_client.sign_request(request, input.auth.as_ref())?;
+
+        let response = http_client.execute(request).await?;
+        let status = response.status();
+
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+
+            return Err(HttpToolError::ErrHttp {
+                status: status.as_u16(),
+                reason: status.canonical_reason().unwrap_or("Unknown").to_string(),
+                snippet: text.chars().take(200).collect(),
+            });
+        }
+
+        let mut stream = response.bytes_stream();
+        let mut parser = SseParser::default();
+        let mut events = vec![];
+
+        loop {
+            let chunk = match tokio::time::timeout_at(deadline, stream.next()).await {
+                Ok(Some(Ok(chunk))) => chunk,
+                // The server closed the stream or the timeout elapsed.
+                Ok(None) | Err(_) => break,
+                Ok(Some(Err(e))) if e.is_timeout() => break,
+                Ok(Some(Err(e))) => return Err(HttpToolError::from_network_error(e)),
+            };
+
+            for event in parser.feed(&chunk) {
+                events.push(event);
+
+                if max_events.is_some_and(|max| events.len() >= max) {
+                    return Ok(events);
+                }
+            }
+        }
+
+        Ok(events)
+    }
+}
+
+/// Incremental parser of an `text/event-stream` body as described by
+/// <https://html.spec.whatwg.org/multipage/server-sent-events.html#event-stream-interpretation>.
+/// The `retry` field is ignored as the tool does not reconnect.
+#[derive(Default)]
+struct SseParser {
+    /// Bytes of the current line that has not been terminated yet.
+    line: Vec<u8>,
+    /// Whether the previous line ended with `\r` so that a following `\n`
+    /// belongs to the same line ending.
+    after_cr: bool,
+    /// Whether the first line has been seen, to strip a leading BOM.
+    started: bool,
+    data: Option<String>,
+    event: Option<String>,
+    last_event_id: Option<String>,
+}
+
+impl SseParser {
+    /// Feed a chunk of the body and return the events it completed.
+    fn feed(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        let mut events = vec![];
+
+        for &byte in chunk {
+            let after_cr = std::mem::replace(&mut self.after_cr, byte == b'\r');
+
+            match byte {
+                b'\n' if after_cr => continue,
+                b'\r' | b'\n' => {
+                    let line = std::mem::take(&mut self.line);
+
+                    events.extend(self.process_line(&String::from_utf8_lossy(&line)));
+                }
+                _ => self.line.push(byte),
+            }
+        }
+
+        events
+    }
+
+    /// Process a complete line and return the event it dispatched, if any.
+    fn process_line(&mut self, line: &str) -> Option<SseEvent> {
+        let line = if std::mem::replace(&mut self.started, true) {
+            line
+        } else {
+            line.strip_prefix('\u{feff}').unwrap_or(line)
+        };
+
+        if line.is_empty() {
+            return self.dispatch();
+        }
+
+        // Lines starting with a colon are comments, often used as keep-alive.
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+
+        match field {
+            "data" => match &mut self.data {
+                Some(data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => self.data = Some(value.to_string()),
+            },
+            "event" => self.event = Some(value.to_string()),
+            "id" if !value.contains('\0') => self.last_event_id = Some(value.to_string()),
+            _ => {}
+        }
+
+        None
+    }
+
+    /// Dispatch the buffered event. Events without data are discarded but the
+    /// last event ID persists across events.
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        let event = self.event.take().filter(|event| !event.is_empty());
+        let data = self.data.take()?;
+
+        Some(SseEvent {
+            id: self.last_event_id.clone().filter(|id| !id.is_empty()),
+            event,
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, mockito::Server, std::io::Write};
+
+    fn create_input(url: String) -> Input {
+        Input {
+            url,
+            auth: Some(AuthConfig::BearerToken {
+                token: "secret".to_string(),
+            }),
+            max_events: None,
+            timeout_ms: Some(1_000),
+        }
+    }
+
+    fn event(id: Option<&str>, event: Option<&str>, data: &str) -> SseEvent {
+        SseEvent {
+            id: id.map(str::to_string),
+            event: event.map(str::to_string),
+            data: data.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parser_fields() {
+        let mut parser = SseParser::default();
+
+        let events = parser.feed(
+            b"\xef\xbb\xbf: keep-alive\n\
+              id: 1\nevent: update\ndata: first\ndata:second\n\n\
+              data\n\n\
+              event: ignored\n\n\
+              id\ndata: third\n\n",
+        );
+
+        assert_eq!(
+            events,
+            vec![
+                event(Some("1"), Some("update"), "first\nsecond"),
+                event(Some("1"), None, ""),
+                event(None, None, "third"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parser_split_chunks() {
+        let mut parser = SseParser::default();
+
+        // Line endings and multi-byte characters split across chunks.
+        assert_eq!(parser.feed(b"data: h\xc3"), vec![]);
+        assert_eq!(parser.feed(b"\xa9llo\r"), vec![]);
+        assert_eq!(
+            parser.feed(b"\n\r\ndata: world\r\r"),
+            vec![event(None, None, "h\u{e9}llo"), event(None, None, "world")]
+        );
+
+        // An unterminated event is not dispatched.
+        assert_eq!(parser.feed(b"data: pending\n"), vec![]);
+    }
+
+    #[tokio::test]
+    async fn test_sse_until_stream_ends() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/events")
+            .match_header("accept", "text/event-stream")
+            .match_header("authorization", "Bearer secret")
+            .with_status(200)
+            .with_header("content-type", "text/event-stream")
+            .with_body("id: 1\ndata: first\n\nid: 2\nevent: done\ndata: second\n\n")
+            .create_async()
+            .await;
+
+        let output = SseConsumer
+            .invoke(create_input(format!("{}/events", server.url())))
+            .await;
+
+        match output {
+            Output::Ok { events } => assert_eq!(
+                events,
+                vec![
+                    event(Some("1"), None, "first"),
+                    event(Some("2"), Some("done"), "second"),
+                ]
+            ),
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_sse_max_events() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/events")
+            .with_status(200)
+            .with_header("content-type", "text/event-stream")
+            .with_body("data: 1\n\ndata: 2\n\ndata: 3\n\n")
+            .create_async()
+            .await;
+
+        let input = Input {
+            max_events: Some(2),
+            ..create_input(format!("{}/events", server.url()))
+        };
+
+        match SseConsumer.invoke(input).await {
+            Output::Ok { events } => {
+                assert_eq!(events, vec![event(None, None, "1"), event(None, None, "2")])
+            }
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_sse_timeout_returns_collected_events() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/events")
+            .with_status(200)
+            .with_header("content-type", "text/event-stream")
+            .with_chunked_body(|w| {
+                w.write_all(b"data: before\n\n")?;
+                w.flush()?;
+                std::thread::sleep(std::time::Duration::from_millis(1_000));
+                w.write_all(b"data: after\n\n")
+            })
+            .create_async()
+            .await;
+
+        let input = Input {
+            timeout_ms: Some(300),
+            ..create_input(format!("{}/events", server.url()))
+        };
+
+        match SseConsumer.invoke(input).await {
+            Output::Ok { events } => assert_eq!(events, vec![event(None, None, "before")]),
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_sse_http_error() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/events")
+            .with_status(401)
+            .with_body("Unauthorized")
+            .create_async()
+            .await;
+
+        match SseConsumer
+            .invoke(create_input(format!("{}/events", server.url())))
+            .await
+        {
+            Output::Err {
+                kind, status_code, ..
+            } => {
+                assert_eq!(kind, HttpErrorKind::Http);
+                assert_eq!(status_code, Some(401));
+            }
+            Output::Ok { events } => panic!("Expected error, got events: {:?}", events),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_sse_validation() {
+        let valid = create_input("https://example.com/events".to_string());
+        assert!(SseConsumer.validate(&valid).is_ok());
+
+        let zero_events = Input {
+            max_events: Some(0),
+            ..create_input("https://example.com/events".to_string())
+        };
+        assert_eq!(
+            SseConsumer.validate(&zero_events).unwrap_err().pointer,
+            "/max_events"
+        );
+
+        let long_timeout = Input {
+            timeout_ms: Some(MAX_TIMEOUT_MS + 1),
+            ..create_input("https://example.com/events".to_string())
+        };
+        assert_eq!(
+            SseConsumer.validate(&long_timeout).unwrap_err().pointer,
+            "/timeout_ms"
+        );
+    }
+}
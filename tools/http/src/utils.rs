@@ -54,3 +54,65 @@ pub(crate) fn validate_schema_detailed(
         }
     }
 }
+
+/// Find the URL of the `rel="next"` link in a `Link` header as described in
+/// RFC 8288, for example `<https://api.example.com/items?page=2>; rel="next"`.
+pub(crate) fn next_link(header: &str) -> Option<&str> {
+    let mut rest = header;
+
+    while let Some(start) = rest.find('<') {
+        let end = start + rest[start..].find('>')?;
+        let target = &rest[start + 1..end];
+
+        // Parameters run until the next link value.
+        let params_end = rest[end..].find('<').map_or(rest.len(), |i| end + i);
+        let params = &rest[end + 1..params_end];
+
+        let is_next = params.split([';', ',']).any(|param| {
+            param.split_once('=').is_some_and(|(name, value)| {
+                name.trim().eq_ignore_ascii_case("rel")
+                    && value
+                        .trim()
+                        .trim_matches('"')
+                        .split_whitespace()
+                        .any(|rel| rel.eq_ignore_ascii_case("next"))
+            })
+        });
+
+        if is_next {
+            return Some(target.trim());
+        }
+
+        rest = &rest[params_end..];
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_link() {
+        let header = r#"<https://api.example.com/items?page=1>; rel="prev", <https://api.example.com/items?page=3>; rel="next", <https://api.example.com/items?page=9>; rel="last""#;
+        assert_eq!(
+            next_link(header),
+            Some("https://api.example.com/items?page=3")
+        );
+
+        // Unquoted, several relations and relative targets
+        assert_eq!(
+            next_link("</items?page=2>; REL=next"),
+            Some("/items?page=2")
+        );
+        assert_eq!(
+            next_link(r#"</items?page=2>; title="a, b"; rel="next last""#),
+            Some("/items?page=2")
+        );
+
+        assert_eq!(next_link(r#"</items?page=1>; rel="prev""#), None);
+        assert_eq!(next_link(""), None);
+        assert_eq!(next_link("<unterminated; rel=next"), None);
+    }
+}
@@ -0,0 +1,127 @@
+//! This module provides the in-memory cache of tool outputs.
+//!
+//! Entries are keyed by the SHA-256 of the serialized input and expire after
+//! the TTL they were cached with. The cache holds at most `capacity` entries:
+//! expired entries are swept when it is full and if that is not enough, the
+//! oldest entries are evicted.
+
+use {
+    dashmap::DashMap,
+    std::time::{Duration, Instant},
+};
+
+/// A cached value, the time it was cached at and how long it is valid for.
+struct Entry<V> {
+    value: V,
+    cached_at: Instant,
+    ttl: Duration,
+}
+
+impl<V> Entry<V> {
+    fn is_expired(&self, ttl: Duration) -> bool {
+        self.cached_at.elapsed() >= ttl
+    }
+}
+
+/// Bounded cache of values that expire after a TTL.
+pub(crate) struct TtlCache<V> {
+    entries: DashMap<[u8; 32], Entry<V>>,
+    capacity: usize,
+}
+
+impl<V: Clone> TtlCache<V> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            entries: DashMap::new(),
+            capacity,
+        }
+    }
+
+    /// Get the value for `key` unless it is older than `ttl`. Stale entries are
+    /// evicted when they are accessed.
+    pub(crate) fn get(&self, key: &[u8; 32], ttl: Duration) -> Option<V> {
+        self.entries
+            .remove_if(key, |_, entry| entry.is_expired(ttl));
+
+        self.entries.get(key).map(|entry| entry.value.clone())
+    }
+
+    /// Cache `value` for `ttl`, making room for it if the cache is full.
+    pub(crate) fn insert(&self, key: [u8; 32], value: V, ttl: Duration) {
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            self.entries.retain(|_, entry| !entry.is_expired(entry.ttl));
+        }
+
+        while !self.entries.contains_key(&key) && self.entries.len() >= self.capacity.max(1) {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|entry| entry.cached_at)
+                .map(|entry| *entry.key());
+
+            match oldest {
+                Some(oldest) => self.entries.remove(&oldest),
+                None => break,
+            };
+        }
+
+        self.entries.insert(
+            key,
+            Entry {
+                value,
+                cached_at: Instant::now(),
+                ttl,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn test_get_evicts_stale_entries() {
+        let cache = TtlCache::new(2);
+
+        cache.insert([1; 32], "one", TTL);
+
+        assert_eq!(cache.get(&[1; 32], TTL), Some("one"));
+        assert_eq!(cache.get(&[2; 32], TTL), None);
+
+        // The TTL of the caller decides whether the entry is stale.
+        assert_eq!(cache.get(&[1; 32], Duration::ZERO), None);
+        assert_eq!(cache.entries.len(), 0);
+    }
+
+    #[test]
+    fn test_insert_is_bounded() {
+        let cache = TtlCache::new(2);
+
+        cache.insert([1; 32], "one", TTL);
+        cache.insert([2; 32], "two", Duration::ZERO);
+
+        // Expired entries are swept first.
+        cache.insert([3; 32], "three", TTL);
+
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(cache.get(&[1; 32], TTL), Some("one"));
+        assert_eq!(cache.get(&[3; 32], TTL), Some("three"));
+
+        // Then the oldest entry is evicted.
+        cache.insert([4; 32], "four", TTL);
+
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(cache.get(&[1; 32], TTL), None);
+        assert_eq!(cache.get(&[3; 32], TTL), Some("three"));
+        assert_eq!(cache.get(&[4; 32], TTL), Some("four"));
+
+        // Replacing an entry does not evict anything.
+        cache.insert([4; 32], "four again", TTL);
+
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(cache.get(&[3; 32], TTL), Some("three"));
+    }
+}
@@ -3,41 +3,71 @@
 use {
     anyhow::anyhow,
     async_openai::{
-        config::{OpenAIConfig, OPENAI_API_BASE},
-        error::OpenAIError,
+        config::OPENAI_API_BASE,
         types::{
             ChatCompletionRequestAssistantMessageArgs,
             ChatCompletionRequestMessage,
+            ChatCompletionRequestMessageContentPartImage,
+            ChatCompletionRequestMessageContentPartText,
             ChatCompletionRequestSystemMessageArgs,
             ChatCompletionRequestUserMessageArgs,
-            CreateChatCompletionRequestArgs,
-            ResponseFormat,
-            ResponseFormatJsonSchema,
+            ChatCompletionRequestUserMessageContent,
+            ChatCompletionRequestUserMessageContentPart,
+            ChatCompletionTool,
+            ChatCompletionToolType,
+            FunctionObject,
+            ImageUrl,
             Role,
         },
-        Client,
     },
+    cache::TtlCache,
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
+    providers::{
+        anthropic::{AnthropicClient, ANTHROPIC_API_BASE},
+        openai::OpenAIClient,
+        Completion,
+        CompletionRequest,
+        Provider,
+        ProviderClient,
+        ProviderKind,
+    },
     schemars::JsonSchema,
     serde::{Deserialize, Serialize},
+    sha2::{Digest, Sha256},
+    std::{
+        sync::{Arc, OnceLock},
+        time::Duration,
+    },
     strum_macros::EnumString,
 };
 
+mod cache;
+mod models;
+mod providers;
 mod status;
 
-/// The default model to use for chat completions.
+/// The default model to use for OpenAI chat completions.
 const DEFAULT_MODEL: &str = "gpt-4o-mini";
 /// The maximum number of tokens to generate in a chat completion.
 const DEFAULT_MAX_COMPLETION_TOKENS: u32 = 512;
 /// The default temperature to use for chat completions.
 const DEFAULT_TEMPERATURE: f32 = 1.0;
+/// Prefixes of the models that accept image inputs.
+const VISION_MODEL_PREFIXES: [&str; 6] = [
+    "gpt-4o",
+    "chatgpt-4o",
+    "gpt-4.1",
+    "gpt-4-turbo",
+    "gpt-4-vision",
+    "gpt-5",
+];
 
 /// Represents a message that can be sent to the OpenAI Chat Completion API.
 ///
 /// It can be either a full message with an explicit message type or a
 /// short message, defaulting to the `User` type.
-#[derive(Debug, PartialEq, Eq, Deserialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged)]
 enum Message {
     /// A full message with an explicit message type and content and name.
@@ -51,10 +81,44 @@ enum Message {
         /// participants of the same role.
         name: Option<String>,
     },
+    /// A user message with text and images. Only supported by vision models.
+    Vision {
+        /// The text content of the message.
+        text: String,
+        /// The URLs of the images, either `https://` or base64 encoded
+        /// `data:` URLs.
+        image_urls: Vec<String>,
+    },
     /// A short message, which defaults to the `User` message type.
     Short(String),
 }
 
+impl Message {
+    /// Whether the message contains images.
+    fn has_images(&self) -> bool {
+        matches!(self, Message::Vision { image_urls, .. } if !image_urls.is_empty())
+    }
+
+    /// The text of the message that counts towards the context window. Images
+    /// are not counted.
+    fn text(&self) -> models::MessageText<'_> {
+        match self {
+            Message::Full { value, name, .. } => models::MessageText {
+                content: value,
+                name: name.as_deref(),
+            },
+            Message::Vision { text, .. } => models::MessageText {
+                content: text,
+                name: None,
+            },
+            Message::Short(value) => models::MessageText {
+                content: value,
+                name: None,
+            },
+        }
+    }
+}
+
 /// Attempts to convert a [`Message`] to an
 /// [`async_openai::types::ChatCompletionRequestMessage`].
 impl TryFrom<Message> for ChatCompletionRequestMessage {
@@ -68,6 +132,26 @@ impl TryFrom<Message> for ChatCompletionRequestMessage {
                 .map(Into::into);
         }
 
+        if let Message::Vision { text, image_urls } = value {
+            let text = ChatCompletionRequestUserMessageContentPart::Text(
+                ChatCompletionRequestMessageContentPartText { text },
+            );
+            let images = image_urls.into_iter().map(|url| {
+                ChatCompletionRequestUserMessageContentPart::ImageUrl(
+                    ChatCompletionRequestMessageContentPartImage {
+                        image_url: ImageUrl { url, detail: None },
+                    },
+                )
+            });
+
+            return ChatCompletionRequestUserMessageArgs::default()
+                .content(ChatCompletionRequestUserMessageContent::Array(
+                    std::iter::once(text).chain(images).collect(),
+                ))
+                .build()
+                .map(Into::into);
+        }
+
         let Message::Full { role, value, name } = value else {
             unreachable!();
         };
@@ -111,7 +195,7 @@ impl TryFrom<Message> for ChatCompletionRequestMessage {
 /// can be a string in which case this enum defaults to `User`.
 ///
 /// This enum is case-insensitive.
-#[derive(Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema, EnumString)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema, EnumString)]
 #[serde(rename_all = "lowercase", try_from = "String")]
 #[strum(ascii_case_insensitive)]
 enum MessageKind {
@@ -148,7 +232,7 @@ impl From<Role> for MessageKind {
 }
 
 /// Defines the structure of the `json_schema` input port.
-#[derive(Clone, Debug, Deserialize, JsonSchema)]
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 struct OpenAIJsonSchema {
     /// The name of the schema. Must match `[a-zA-Z0-9-_]`, with a maximum
@@ -166,8 +250,67 @@ struct OpenAIJsonSchema {
     strict: Option<bool>,
 }
 
+/// Defines a tool the model may call. Mirrors
+/// [`async_openai::types::ChatCompletionTool`].
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct OpenAIFunctionDef {
+    /// The type of the tool. Currently, only `function` is supported.
+    #[serde(default, rename = "type")]
+    kind: OpenAIToolKind,
+    /// The function the model may call.
+    function: OpenAIFunction,
+}
+
+/// The type of a tool the model may call.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum OpenAIToolKind {
+    #[default]
+    Function,
+}
+
+/// Defines a function the model may call. Mirrors
+/// [`async_openai::types::FunctionObject`].
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct OpenAIFunction {
+    /// The name of the function. Must match `[a-zA-Z0-9-_]`, with a maximum
+    /// length of 64.
+    name: String,
+    /// A description of what the function does, used by the model to choose
+    /// when and how to call the function.
+    description: Option<String>,
+    /// The JSON schema of the parameters the function accepts. Omitting it
+    /// defines a function with an empty parameter list.
+    parameters: Option<schemars::Schema>,
+    /// Whether to enable strict schema adherence when generating the function
+    /// call. See <https://platform.openai.com/docs/guides/function-calling>.
+    strict: Option<bool>,
+}
+
+/// Convert an [`OpenAIFunctionDef`] to an
+/// [`async_openai::types::ChatCompletionTool`].
+impl From<OpenAIFunctionDef> for ChatCompletionTool {
+    fn from(value: OpenAIFunctionDef) -> Self {
+        let OpenAIFunctionDef { kind, function } = value;
+
+        ChatCompletionTool {
+            r#type: match kind {
+                OpenAIToolKind::Function => ChatCompletionToolType::Function,
+            },
+            function: FunctionObject {
+                name: function.name,
+                description: function.description,
+                parameters: function.parameters.map(|schema| schema.to_value()),
+                strict: function.strict,
+            },
+        }
+    }
+}
+
 /// Allow the interface to accept a [`Vec`] or a single [`Message`].
-#[derive(Debug, PartialEq, Eq, Deserialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged)]
 enum MessageBag {
     One(Message),
@@ -190,20 +333,30 @@ impl From<MessageBag> for Vec<Message> {
 }
 
 /// Represents the input for the OpenAI chat completion Tool.
-#[derive(Debug, Deserialize, JsonSchema)]
+///
+/// Serialized to compute the cache key, which must not depend on the API key
+/// or the TTL of the cache.
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 struct Input {
-    /// The OpenAI API key.
+    /// The OpenAI API key. Shorthand for an OpenAI `provider` with the default
+    /// API base.
     // TODO: <https://github.com/Talus-Network/nexus-sdk/issues/29>.
-    api_key: Secret<String>,
+    #[serde(default, skip_serializing)]
+    api_key: Option<Secret<String>>,
+    /// The provider to generate the completion with. Either this or `api_key`
+    /// must be set.
+    #[serde(default)]
+    provider: Option<Provider>,
     /// The prompt to send to the chat completion API.
     prompt: MessageBag,
     /// The context to provide to the chat completion API.
     #[serde(default)]
     context: MessageBag,
-    /// The model to use for chat completion.
-    #[serde(default = "default_model")]
-    model: String,
+    /// The model to use for chat completion. Defaults to a model of the
+    /// provider.
+    #[serde(default)]
+    model: Option<String>,
     /// The maximum number of tokens to generate.
     #[serde(default = "default_max_completion_tokens")]
     max_completion_tokens: u32,
@@ -213,10 +366,20 @@ struct Input {
     /// The JSON schema for the expected output.
     #[serde(default)]
     json_schema: Option<OpenAIJsonSchema>,
-}
-
-fn default_model() -> String {
-    DEFAULT_MODEL.to_string()
+    /// Whether to stream the completion and return its chunks.
+    #[serde(default)]
+    stream: Option<bool>,
+    /// The tools the model may call.
+    #[serde(default)]
+    tools: Option<Vec<OpenAIFunctionDef>>,
+    /// Whether to count the tokens of the messages and fail without calling
+    /// the API if they do not fit the context window of the model.
+    #[serde(default)]
+    preflight_token_check: Option<bool>,
+    /// How long to cache the output for identical inputs, in seconds. Outputs
+    /// are not cached if not set.
+    #[serde(default, skip_serializing)]
+    cache_ttl_seconds: Option<u64>,
 }
 
 fn default_max_completion_tokens() -> u32 {
@@ -228,7 +391,7 @@ fn default_temperature() -> f32 {
 }
 
 /// Represents the output of the OpenAI chat completion Tool.
-#[derive(Debug, PartialEq, Eq, Serialize, JsonSchema)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 enum Output {
     Text {
@@ -241,6 +404,16 @@ enum Output {
         role: MessageKind,
         completion: serde_json::Value,
     },
+    Stream {
+        id: String,
+        role: MessageKind,
+        chunks: Vec<String>,
+    },
+    FunctionCall {
+        id: String,
+        name: String,
+        arguments: serde_json::Value,
+    },
     Err {
         reason: String,
     },
@@ -249,12 +422,22 @@ enum Output {
 /// The OpenAI Chat Completion tool.
 ///
 /// This struct implements the `NexusTool` trait to integrate with the Nexus
-/// framework. It provides the logic for invoking the OpenAI chat completion
-/// API.
+/// framework. It provides the logic for invoking the chat completion API of
+/// the requested provider, OpenAI by default.
 struct OpenaiChatCompletion {
+    /// The base URL of the OpenAI API unless the provider sets one.
     api_base: String,
+    /// Outputs keyed by the SHA-256 of the serialized [`Input`].
+    cache: Arc<TtlCache<Output>>,
 }
 
+/// Maximum number of outputs kept in the cache.
+const CACHE_CAPACITY: usize = 1024;
+
+/// Outputs cached by this process. The runtime creates a new tool for every
+/// request so the cache must outlive them.
+static GLOBAL_CACHE: OnceLock<Arc<TtlCache<Output>>> = OnceLock::new();
+
 impl NexusTool for OpenaiChatCompletion {
     type Input = Input;
     type Output = Output;
@@ -262,6 +445,9 @@ impl NexusTool for OpenaiChatCompletion {
     async fn new() -> Self {
         Self {
             api_base: OPENAI_API_BASE.to_string(),
+            cache: GLOBAL_CACHE
+                .get_or_init(|| Arc::new(TtlCache::new(CACHE_CAPACITY)))
+                .clone(),
         }
     }
 
@@ -274,102 +460,180 @@ impl NexusTool for OpenaiChatCompletion {
         status::check_api_health().await
     }
 
-    /// Invokes the tool logic to generate a chat completion.
+    /// Invokes the tool logic to generate a chat completion. Outputs are
+    /// served from the cache if `cache_ttl_seconds` is set.
     async fn invoke(&self, request: Self::Input) -> Self::Output {
-        let cfg = OpenAIConfig::new()
-            .with_api_key(&*request.api_key)
-            .with_api_base(&self.api_base);
-
-        let client = Client::with_config(cfg);
-
-        // Parse context messages into OpenAI message types.
-        let context: Vec<Message> = request.context.into();
-        let context = context.into_iter().map(TryInto::try_into);
+        let Some(ttl) = request.cache_ttl_seconds.map(Duration::from_secs) else {
+            return self.complete(request).await;
+        };
 
-        // Chain the input prompt and collect.
-        let prompt: Vec<Message> = request.prompt.into();
-        let messages = context
-            .chain(prompt.into_iter().map(TryInto::try_into))
-            .collect::<Result<Vec<ChatCompletionRequestMessage>, OpenAIError>>();
-
-        // Should something go wrong, return an error. This is however very
-        // unlikely as the inputs are validated against a schema defined here.
-        let messages = match messages {
-            Ok(messages) => messages,
+        let key: [u8; 32] = match serde_json::to_vec(&request) {
+            Ok(bytes) => Sha256::digest(bytes).into(),
             Err(err) => {
                 return Output::Err {
-                    reason: err.to_string(),
+                    reason: format!("Error computing cache key: {}", err),
                 }
             }
         };
 
-        // Create the request to send to the OpenAI API and assign some basic
-        // parameters.
-        let mut openai_request = CreateChatCompletionRequestArgs::default();
-
-        let mut openai_request = openai_request
-            .max_completion_tokens(request.max_completion_tokens)
-            .model(request.model)
-            .temperature(request.temperature)
-            .messages(messages);
-
-        // If a JSON schema is provided, set it on the request.
-        if let Some(schema) = request.json_schema.clone() {
-            let json_schema = ResponseFormatJsonSchema {
-                name: schema.name,
-                schema: Some(schema.schema.to_value()),
-                description: schema.description,
-                strict: schema.strict,
-            };
+        if let Some(output) = self.cache.get(&key, ttl) {
+            return output;
+        }
+
+        let output = self.complete(request).await;
 
-            openai_request =
-                openai_request.response_format(ResponseFormat::JsonSchema { json_schema });
+        // Errors are not cached so that they can be retried.
+        if !matches!(output, Output::Err { .. }) {
+            self.cache.insert(key, output.clone(), ttl);
         }
 
-        // Build the request and handle any errors.
-        let openai_request = match openai_request.build() {
-            Ok(request) => request,
-            Err(err) => {
+        output
+    }
+}
+
+impl OpenaiChatCompletion {
+    /// Generate a chat completion by calling the API of the provider.
+    async fn complete(&self, request: Input) -> Output {
+        let provider = match (request.provider, request.api_key) {
+            (Some(provider), None) => provider,
+            (None, Some(api_key)) => Provider {
+                kind: ProviderKind::OpenAI,
+                api_key,
+                api_base: None,
+            },
+            (Some(_), Some(_)) => {
                 return Output::Err {
-                    reason: format!("Error building OpenAI request: {}", err),
+                    reason: "Only one of 'api_key' and 'provider' can be set".to_string(),
                 }
             }
-        };
-
-        let response = match client.chat().create(openai_request).await {
-            Ok(response) => response,
-            Err(err) => {
+            (None, None) => {
                 return Output::Err {
-                    reason: format!("Error calling OpenAI API: {}", err),
+                    reason: "Either 'api_key' or 'provider' must be set".to_string(),
                 }
             }
         };
 
-        // Parse the response into the expected output format. Current Tool
-        // design only supports a single choice so we take the first one.
-        //
-        // This design is also better for the Nexus interface as having a single
-        // plaintext field with the completion is better suited.
-        let choice = match response.choices.first() {
-            Some(choice) => choice,
-            None => {
-                return Output::Err {
-                    reason: "No choices returned from OpenAI API".to_string(),
-                }
+        let model = request
+            .model
+            .unwrap_or_else(|| provider.kind.default_model().to_string());
+
+        let context: Vec<Message> = request.context.into();
+        let prompt: Vec<Message> = request.prompt.into();
+
+        // Images are rejected upfront rather than relying on the API error.
+        // All Anthropic models accept images.
+        if provider.kind == ProviderKind::OpenAI
+            && context.iter().chain(&prompt).any(Message::has_images)
+            && !supports_vision(&model)
+        {
+            return Output::Err {
+                reason: format!("Model '{}' does not support image inputs", model),
+            };
+        }
+
+        if request.preflight_token_check.unwrap_or_default() {
+            let messages = context
+                .iter()
+                .chain(&prompt)
+                .map(Message::text)
+                .collect::<Vec<_>>();
+
+            if let Err(reason) = check_token_count(&model, &messages, request.max_completion_tokens)
+            {
+                return Output::Err { reason };
             }
+        }
+
+        let tools = request.tools.unwrap_or_default();
+        let has_tools = !tools.is_empty();
+
+        // Context messages are prepended to the prompt.
+        let completion_request = CompletionRequest {
+            model,
+            messages: context.into_iter().chain(prompt).collect(),
+            max_completion_tokens: request.max_completion_tokens,
+            temperature: request.temperature,
+            json_schema: request.json_schema.clone(),
+            tools,
         };
 
-        if let Some(refusal) = &choice.message.refusal {
-            return Output::Err {
-                reason: refusal.to_string(),
+        let api_key = &*provider.api_key;
+
+        if request.stream.unwrap_or_default() {
+            // JSON completions are validated as a whole.
+            if request.json_schema.is_some() {
+                return Output::Err {
+                    reason: "Streaming is not supported for JSON completions".to_string(),
+                };
+            }
+
+            // Tool calls are not collected from the stream.
+            if has_tools {
+                return Output::Err {
+                    reason: "Streaming is not supported for function calls".to_string(),
+                };
+            }
+
+            if provider.kind != ProviderKind::OpenAI {
+                return Output::Err {
+                    reason: "Streaming is only supported by the OpenAI provider".to_string(),
+                };
+            }
+
+            let api_base = provider.api_base.as_deref().unwrap_or(&self.api_base);
+
+            return match OpenAIClient::new(api_key, api_base)
+                .stream(completion_request)
+                .await
+            {
+                Ok(streamed) => Output::Stream {
+                    id: streamed.id,
+                    role: streamed.role,
+                    chunks: streamed.chunks,
+                },
+                Err(err) => Output::Err {
+                    reason: err.to_string(),
+                },
             };
         }
 
-        let completion = match &choice.message.content {
-            Some(completion) => completion.to_string(),
-            None => {
+        let response = match provider.kind {
+            ProviderKind::OpenAI => {
+                let api_base = provider.api_base.as_deref().unwrap_or(&self.api_base);
+
+                OpenAIClient::new(api_key, api_base)
+                    .complete(completion_request)
+                    .await
+            }
+            ProviderKind::Anthropic => {
+                let api_base = provider.api_base.as_deref().unwrap_or(ANTHROPIC_API_BASE);
+
+                AnthropicClient::new(api_key, api_base)
+                    .complete(completion_request)
+                    .await
+            }
+        };
+
+        let response = match response {
+            Ok(response) => response,
+            Err(err) => {
                 return Output::Err {
-                    reason: "No completion returned from OpenAI API".to_string(),
+                    reason: err.to_string(),
+                }
+            }
+        };
+
+        let completion = match response.completion {
+            Completion::Text(completion) => completion,
+            Completion::FunctionCall {
+                id,
+                name,
+                arguments,
+            } => {
+                return Output::FunctionCall {
+                    id,
+                    name,
+                    arguments,
                 }
             }
         };
@@ -378,7 +642,7 @@ impl NexusTool for OpenaiChatCompletion {
         let Some(OpenAIJsonSchema { schema, .. }) = request.json_schema else {
             return Output::Text {
                 id: response.id,
-                role: choice.message.role.into(),
+                role: response.role,
                 completion,
             };
         };
@@ -397,7 +661,7 @@ impl NexusTool for OpenaiChatCompletion {
         match jsonschema::draft202012::validate(&schema.to_value(), &completion) {
             Ok(()) => Output::Json {
                 id: response.id,
-                role: choice.message.role.into(),
+                role: response.role,
                 completion,
             },
             Err(e) => Output::Err {
@@ -407,6 +671,42 @@ impl NexusTool for OpenaiChatCompletion {
     }
 }
 
+/// Check that the messages and the completion fit the context window of the
+/// model. Models with an unknown context window are not checked.
+fn check_token_count(
+    model: &str,
+    messages: &[models::MessageText],
+    max_completion_tokens: u32,
+) -> Result<(), String> {
+    let Some(context_window) = models::context_window_for_model(model) else {
+        return Ok(());
+    };
+
+    let token_count = models::count_message_tokens(model, messages)
+        .map_err(|e| format!("Error counting tokens: {e}"))?
+        + max_completion_tokens as usize;
+
+    if token_count > context_window {
+        return Err(format!(
+            "Token count {token_count} exceeds context window {context_window}"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Whether the model accepts image inputs.
+fn supports_vision(model: &str) -> bool {
+    let model = model.to_ascii_lowercase();
+
+    VISION_MODEL_PREFIXES
+        .iter()
+        .any(|prefix| model.starts_with(prefix))
+        // Audio and realtime variants of vision models only accept text.
+        && !model.contains("-audio")
+        && !model.contains("-realtime")
+}
+
 /// The main entry point for the OpenAI Chat Completion tool.
 ///
 /// This function bootstraps the tool and starts the server.
@@ -436,6 +736,7 @@ mod tests {
         fn with_api_base(api_base: &str) -> Self {
             Self {
                 api_base: api_base.to_string(),
+                cache: Arc::new(TtlCache::new(CACHE_CAPACITY)),
             }
         }
     }
@@ -585,6 +886,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_message_deserialization_vision() {
+        let json = r#"{"text": "What is this?", "image_urls": ["https://example.com/a.png"]}"#;
+        let message: Message = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            message,
+            Message::Vision {
+                text: "What is this?".to_string(),
+                image_urls: vec!["https://example.com/a.png".to_string()]
+            }
+        );
+        assert!(message.has_images());
+    }
+
+    #[test]
+    fn test_supports_vision() {
+        assert!(supports_vision("gpt-4o"));
+        assert!(supports_vision("gpt-4o-mini"));
+        assert!(supports_vision("GPT-4.1-nano"));
+        assert!(!supports_vision("gpt-4o-audio-preview"));
+        assert!(!supports_vision("gpt-3.5-turbo"));
+    }
+
     #[test]
     fn test_message_deserialization_short() {
         let json = r#""Hello""#;
@@ -639,8 +963,8 @@ mod tests {
             ]
         }"#;
         let input: Input = serde_json::from_str(json).unwrap();
-        assert_eq!(&*input.api_key, "your_api_key");
-        assert_eq!(input.model, DEFAULT_MODEL);
+        assert_eq!(input.api_key.as_deref().unwrap(), "your_api_key");
+        assert_eq!(input.model, None);
         assert_eq!(
             input.prompt,
             MessageBag::Many(vec![
@@ -666,8 +990,8 @@ mod tests {
             "prompt": []
         }"#;
         let input: Input = serde_json::from_str(json).unwrap();
-        assert_eq!(&*input.api_key, "your_api_key");
-        assert_eq!(input.model, DEFAULT_MODEL);
+        assert_eq!(input.api_key.as_deref().unwrap(), "your_api_key");
+        assert_eq!(input.model, None);
         assert!(matches!(input.prompt, MessageBag::Many(messages) if messages.is_empty()));
     }
 
@@ -892,6 +1216,186 @@ mod tests {
         mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_function_call() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        #[derive(JsonSchema)]
+        #[allow(dead_code)]
+        struct Weather {
+            city: String,
+        }
+
+        let schema = schema_for!(Weather);
+
+        let json = json!({
+            "api_key": "best-encryption-ever-\"your_api_key\"",
+            "tools": [
+                {
+                    "type": "function",
+                    "function": {
+                        "name": "get_weather",
+                        "description": "Get the weather in a city",
+                        "parameters": schema
+                    }
+                }
+            ],
+            "prompt": "What is the weather in Prague?"
+        });
+
+        let input: Input = serde_json::from_value(json).unwrap();
+
+        let mock = server
+            .mock("POST", "/v1/chat/completions")
+            .match_header("authorization", "Bearer your_api_key")
+            .match_body(Matcher::PartialJson(json!({
+                "tools": [
+                    {
+                        "type": "function",
+                        "function": {
+                            "name": "get_weather",
+                            "description": "Get the weather in a city",
+                            "parameters": schema
+                        }
+                    }
+                ]
+            })))
+            .with_body(
+                json!({
+                    "id": "completion_id",
+                    "created": 1234567890,
+                    "model": DEFAULT_MODEL,
+                    "object": "chat.completion",
+                    "choices": [
+                        {
+                            "index": 0,
+                            "message": {
+                                "role": "assistant",
+                                "content": null,
+                                "tool_calls": [
+                                    {
+                                        "id": "call_1",
+                                        "type": "function",
+                                        "function": {
+                                            "name": "get_weather",
+                                            "arguments": "{\"city\":\"Prague\"}"
+                                        }
+                                    },
+                                    {
+                                        "id": "call_2",
+                                        "type": "function",
+                                        "function": {
+                                            "name": "get_weather",
+                                            "arguments": "{\"city\":\"Brno\"}"
+                                        }
+                                    }
+                                ]
+                            },
+                            "finish_reason": "tool_calls"
+                        }
+                    ],
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let output = tool.invoke(input).await;
+
+        // Only the first call is returned.
+        assert_eq!(
+            output,
+            Output::FunctionCall {
+                id: "call_1".to_string(),
+                name: "get_weather".to_string(),
+                arguments: json!({ "city": "Prague" }),
+            }
+        );
+
+        mock.assert_async().await;
+    }
+
+    fn mock_stream_body(chunks: &[&str]) -> String {
+        let mut body = chunks
+            .iter()
+            .enumerate()
+            .map(|(index, content)| {
+                let delta = match index {
+                    0 => json!({ "role": "assistant", "content": content }),
+                    _ => json!({ "content": content }),
+                };
+
+                let chunk = json!({
+                    "id": "completion_id",
+                    "created": 1234567890,
+                    "model": DEFAULT_MODEL,
+                    "object": "chat.completion.chunk",
+                    "choices": [{ "index": 0, "delta": delta, "finish_reason": null }],
+                });
+
+                format!("data: {chunk}\n\n")
+            })
+            .collect::<String>();
+
+        body.push_str("data: [DONE]\n\n");
+
+        body
+    }
+
+    #[tokio::test]
+    async fn test_stream() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let json = r#"{
+            "api_key": "best-encryption-ever-\"your_api_key\"",
+            "prompt": "Hello",
+            "stream": true
+        }"#;
+
+        let input: Input = serde_json::from_str(json).unwrap();
+
+        let mock = server
+            .mock("POST", "/v1/chat/completions")
+            .match_header("authorization", "Bearer your_api_key")
+            .match_body(Matcher::PartialJson(json!({ "stream": true })))
+            .with_header("content-type", "text/event-stream")
+            .with_body(mock_stream_body(&["", "Hello", ",", " world!"]))
+            .create_async()
+            .await;
+
+        let output = tool.invoke(input).await;
+
+        assert_eq!(
+            output,
+            Output::Stream {
+                id: "completion_id".to_string(),
+                role: MessageKind::Assistant,
+                chunks: vec!["Hello".to_string(), ",".to_string(), " world!".to_string()],
+            }
+        );
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_stream_with_json_schema() {
+        let (_server, tool) = create_server_and_tool().await;
+
+        let json = json!({
+            "api_key": "best-encryption-ever-\"your_api_key\"",
+            "json_schema": {
+                "name": "completion",
+                "schema": schema_for!(String)
+            },
+            "prompt": "generate json",
+            "stream": true
+        });
+
+        let input: Input = serde_json::from_value(json).unwrap();
+
+        assert!(matches!(tool.invoke(input).await, Output::Err { .. }));
+    }
+
     #[tokio::test]
     async fn test_schema_mismatch() {
         let (mut server, tool) = create_server_and_tool().await;
@@ -949,4 +1453,525 @@ mod tests {
 
         mock.assert_async().await;
     }
+
+    #[tokio::test]
+    async fn test_vision() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let json = json!({
+            "api_key": "best-encryption-ever-\"your_api_key\"",
+            "model": "gpt-4o",
+            "prompt": {
+                "text": "Describe these images",
+                "image_urls": [
+                    "https://example.com/cat.png",
+                    "data:image/png;base64,iVBORw0KGgo="
+                ]
+            }
+        });
+
+        let input: Input = serde_json::from_value(json).unwrap();
+
+        let mock = server
+            .mock("POST", "/v1/chat/completions")
+            .match_header("authorization", "Bearer your_api_key")
+            .match_request(|request| {
+                let Ok(body) = request.body() else {
+                    return false;
+                };
+                let body: serde_json::Value = serde_json::from_slice(body).unwrap();
+
+                let message = &body["messages"][0];
+                let content = &message["content"];
+
+                message["role"] == "user"
+                    && content.as_array().map(Vec::len) == Some(3)
+                    && content[0] == json!({ "type": "text", "text": "Describe these images" })
+                    && content[1]["type"] == "image_url"
+                    && content[1]["image_url"]["url"] == "https://example.com/cat.png"
+                    && content[2]["type"] == "image_url"
+                    && content[2]["image_url"]["url"] == "data:image/png;base64,iVBORw0KGgo="
+            })
+            .with_body(mock_response_body("A cat and a pixel."))
+            .create_async()
+            .await;
+
+        let output = tool.invoke(input).await;
+
+        assert_eq!(
+            output,
+            Output::Text {
+                id: "completion_id".to_string(),
+                role: MessageKind::System,
+                completion: "A cat and a pixel.".to_string()
+            }
+        );
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_vision_unsupported_model() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let json = json!({
+            "api_key": "best-encryption-ever-\"your_api_key\"",
+            "model": "gpt-3.5-turbo",
+            "prompt": [
+                "Hello",
+                { "text": "What is this?", "image_urls": ["https://example.com/cat.png"] }
+            ]
+        });
+
+        let input: Input = serde_json::from_value(json).unwrap();
+
+        let mock = server
+            .mock("POST", "/v1/chat/completions")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let output = tool.invoke(input).await;
+
+        assert!(
+            matches!(output, Output::Err { reason } if reason.contains("does not support image inputs"))
+        );
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_preflight_token_check() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        // Each repetition is at least one token so the prompt alone exceeds
+        // the 8192 token window of `gpt-4`.
+        let json = json!({
+            "api_key": "best-encryption-ever-\"your_api_key\"",
+            "model": "gpt-4",
+            "prompt": "hello ".repeat(10_000),
+            "preflight_token_check": true
+        });
+
+        let input: Input = serde_json::from_value(json).unwrap();
+
+        let rejected = server
+            .mock("POST", "/v1/chat/completions")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let output = tool.invoke(input).await;
+
+        assert!(
+            matches!(&output, Output::Err { reason } if reason.starts_with("Token count") && reason.ends_with("exceeds context window 8192")),
+            "Unexpected output: {output:?}"
+        );
+
+        rejected.assert_async().await;
+        rejected.remove_async().await;
+
+        // The completion tokens count towards the window too.
+        let json = json!({
+            "api_key": "best-encryption-ever-\"your_api_key\"",
+            "model": "gpt-4",
+            "prompt": "Hello",
+            "max_completion_tokens": 8192,
+            "preflight_token_check": true
+        });
+
+        let input: Input = serde_json::from_value(json).unwrap();
+
+        assert!(matches!(tool.invoke(input).await, Output::Err { .. }));
+
+        // Requests that fit are sent.
+        let json = json!({
+            "api_key": "best-encryption-ever-\"your_api_key\"",
+            "model": "gpt-4",
+            "prompt": "Hello",
+            "preflight_token_check": true
+        });
+
+        let input: Input = serde_json::from_value(json).unwrap();
+
+        let mock = server
+            .mock("POST", "/v1/chat/completions")
+            .with_body(mock_response_body("Hello, world!"))
+            .create_async()
+            .await;
+
+        assert!(matches!(tool.invoke(input).await, Output::Text { .. }));
+
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_input_provider_deserialization() {
+        let json = r#"{
+            "provider": {
+                "kind": "anthropic",
+                "api_key": "best-encryption-ever-\"your_api_key\"",
+                "api_base": "http://localhost:8080/v1"
+            },
+            "prompt": "Hello"
+        }"#;
+        let input: Input = serde_json::from_str(json).unwrap();
+        let provider = input.provider.unwrap();
+
+        assert!(input.api_key.is_none());
+        assert_eq!(provider.kind, ProviderKind::Anthropic);
+        assert_eq!(&*provider.api_key, "your_api_key");
+        assert_eq!(
+            provider.api_base.as_deref(),
+            Some("http://localhost:8080/v1")
+        );
+
+        let json = r#"{
+            "provider": {
+                "kind": "mistral",
+                "api_key": "best-encryption-ever-\"your_api_key\""
+            },
+            "prompt": "Hello"
+        }"#;
+
+        assert!(serde_json::from_str::<Input>(json).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_provider_or_api_key() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let mock = server
+            .mock("POST", "/v1/chat/completions")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let json = json!({
+            "prompt": "Hello"
+        });
+
+        let input: Input = serde_json::from_value(json).unwrap();
+
+        assert!(
+            matches!(tool.invoke(input).await, Output::Err { reason } if reason == "Either 'api_key' or 'provider' must be set")
+        );
+
+        let json = json!({
+            "api_key": "best-encryption-ever-\"your_api_key\"",
+            "provider": {
+                "kind": "openai",
+                "api_key": "best-encryption-ever-\"your_api_key\""
+            },
+            "prompt": "Hello"
+        });
+
+        let input: Input = serde_json::from_value(json).unwrap();
+
+        assert!(
+            matches!(tool.invoke(input).await, Output::Err { reason } if reason == "Only one of 'api_key' and 'provider' can be set")
+        );
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_openai_provider() {
+        let mut server = Server::new_async().await;
+        let tool = OpenaiChatCompletion::with_api_base("http://unused.invalid/v1");
+
+        let json = json!({
+            "provider": {
+                "kind": "openai",
+                "api_key": "best-encryption-ever-\"your_api_key\"",
+                "api_base": format!("http://{}/v1", server.host_with_port())
+            },
+            "prompt": "Hello"
+        });
+
+        let input: Input = serde_json::from_value(json).unwrap();
+
+        let mock = server
+            .mock("POST", "/v1/chat/completions")
+            .match_header("authorization", "Bearer your_api_key")
+            .match_body(Matcher::PartialJson(json!({ "model": DEFAULT_MODEL })))
+            .with_body(mock_response_body("Hello, world!"))
+            .create_async()
+            .await;
+
+        assert!(matches!(tool.invoke(input).await, Output::Text { .. }));
+
+        mock.assert_async().await;
+    }
+
+    fn anthropic_input(server: &mockito::ServerGuard, mut input: serde_json::Value) -> Input {
+        input["provider"] = json!({
+            "kind": "anthropic",
+            "api_key": "best-encryption-ever-\"your_api_key\"",
+            "api_base": format!("http://{}/v1", server.host_with_port())
+        });
+
+        serde_json::from_value(input).unwrap()
+    }
+
+    fn mock_anthropic_body(content: serde_json::Value) -> String {
+        json!({
+            "id": "msg_id",
+            "type": "message",
+            "role": "assistant",
+            "model": providers::DEFAULT_ANTHROPIC_MODEL,
+            "content": content,
+            "stop_reason": "end_turn",
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_anthropic() {
+        let mut server = Server::new_async().await;
+        let tool = OpenaiChatCompletion::with_api_base("http://unused.invalid/v1");
+
+        let input = anthropic_input(
+            &server,
+            json!({
+                "context": {
+                    "role": "system",
+                    "value": "You are a helpful assistant."
+                },
+                "prompt": [
+                    "Hello",
+                    {
+                        "text": "What is this?",
+                        "image_urls": [
+                            "https://example.com/cat.png",
+                            "data:image/png;base64,iVBORw0KGgo="
+                        ]
+                    }
+                ]
+            }),
+        );
+
+        let mock = server
+            .mock("POST", "/v1/messages")
+            .match_header("x-api-key", "your_api_key")
+            .match_header("anthropic-version", "2023-06-01")
+            .match_body(Matcher::Json(json!({
+                "model": providers::DEFAULT_ANTHROPIC_MODEL,
+                "max_tokens": DEFAULT_MAX_COMPLETION_TOKENS,
+                "temperature": DEFAULT_TEMPERATURE,
+                "system": "You are a helpful assistant.",
+                "messages": [
+                    { "role": "user", "content": "Hello" },
+                    {
+                        "role": "user",
+                        "content": [
+                            { "type": "text", "text": "What is this?" },
+                            {
+                                "type": "image",
+                                "source": { "type": "url", "url": "https://example.com/cat.png" }
+                            },
+                            {
+                                "type": "image",
+                                "source": {
+                                    "type": "base64",
+                                    "media_type": "image/png",
+                                    "data": "iVBORw0KGgo="
+                                }
+                            }
+                        ]
+                    }
+                ]
+            })))
+            .with_body(mock_anthropic_body(json!([
+                { "type": "text", "text": "Hello, " },
+                { "type": "text", "text": "a cat!" }
+            ])))
+            .create_async()
+            .await;
+
+        assert_eq!(
+            tool.invoke(input).await,
+            Output::Text {
+                id: "msg_id".to_string(),
+                role: MessageKind::Assistant,
+                completion: "Hello, a cat!".to_string()
+            }
+        );
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_anthropic_json_output() {
+        let mut server = Server::new_async().await;
+        let tool = OpenaiChatCompletion::with_api_base("http://unused.invalid/v1");
+
+        #[derive(PartialEq, Eq, Serialize, JsonSchema)]
+        struct Completion {
+            hello: String,
+        }
+
+        let schema = schema_for!(Completion);
+
+        let input = anthropic_input(
+            &server,
+            json!({
+                "model": "claude-sonnet-4-0",
+                "json_schema": {
+                    "name": "completion",
+                    "schema": schema
+                },
+                "prompt": "generate json"
+            }),
+        );
+
+        // JSON is requested by forcing the model to call a tool with the
+        // schema as its input schema.
+        let mock = server
+            .mock("POST", "/v1/messages")
+            .match_body(Matcher::PartialJson(json!({
+                "model": "claude-sonnet-4-0",
+                "tools": [{ "name": "completion", "input_schema": schema }],
+                "tool_choice": { "type": "tool", "name": "completion" }
+            })))
+            .with_body(mock_anthropic_body(json!([
+                { "type": "tool_use", "id": "toolu_1", "name": "completion", "input": { "hello": "world" } }
+            ])))
+            .create_async()
+            .await;
+
+        assert_eq!(
+            tool.invoke(input).await,
+            Output::Json {
+                id: "msg_id".to_string(),
+                role: MessageKind::Assistant,
+                completion: json!({ "hello": "world" })
+            }
+        );
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_anthropic_function_call() {
+        let mut server = Server::new_async().await;
+        let tool = OpenaiChatCompletion::with_api_base("http://unused.invalid/v1");
+
+        let input = anthropic_input(
+            &server,
+            json!({
+                "tools": [
+                    {
+                        "function": {
+                            "name": "get_time",
+                            "description": "Get the current time"
+                        }
+                    }
+                ],
+                "prompt": "What time is it?"
+            }),
+        );
+
+        let mock = server
+            .mock("POST", "/v1/messages")
+            .match_body(Matcher::PartialJson(json!({
+                "tools": [
+                    {
+                        "name": "get_time",
+                        "description": "Get the current time",
+                        "input_schema": { "type": "object", "properties": {} }
+                    }
+                ]
+            })))
+            .with_body(mock_anthropic_body(json!([
+                { "type": "text", "text": "Let me check." },
+                { "type": "tool_use", "id": "toolu_1", "name": "get_time", "input": {} }
+            ])))
+            .create_async()
+            .await;
+
+        assert_eq!(
+            tool.invoke(input).await,
+            Output::FunctionCall {
+                id: "toolu_1".to_string(),
+                name: "get_time".to_string(),
+                arguments: json!({}),
+            }
+        );
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_anthropic_error() {
+        let mut server = Server::new_async().await;
+        let tool = OpenaiChatCompletion::with_api_base("http://unused.invalid/v1");
+
+        let mock = server
+            .mock("POST", "/v1/messages")
+            .with_status(401)
+            .with_body(
+                json!({
+                    "type": "error",
+                    "error": { "type": "authentication_error", "message": "invalid x-api-key" }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let input = anthropic_input(&server, json!({ "prompt": "Hello" }));
+
+        assert_eq!(
+            tool.invoke(input).await,
+            Output::Err {
+                reason: "Error calling Anthropic API (401 Unauthorized): invalid x-api-key"
+                    .to_string()
+            }
+        );
+
+        mock.assert_async().await;
+
+        // Streaming is only implemented for OpenAI.
+        let input = anthropic_input(&server, json!({ "prompt": "Hello", "stream": true }));
+
+        assert!(
+            matches!(tool.invoke(input).await, Output::Err { reason } if reason.contains("only supported by the OpenAI provider"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cache() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let input = |api_key: &str, cache_ttl_seconds: u64| -> Input {
+            serde_json::from_value(json!({
+                "api_key": format!("best-encryption-ever-\"{api_key}\""),
+                "prompt": "Hello",
+                "temperature": 0.0,
+                "cache_ttl_seconds": cache_ttl_seconds
+            }))
+            .unwrap()
+        };
+
+        let mock = server
+            .mock("POST", "/v1/chat/completions")
+            .with_body(mock_response_body("Hello, world!"))
+            .expect(2)
+            .create_async()
+            .await;
+
+        let output = tool.invoke(input("your_api_key", 60)).await;
+
+        assert!(matches!(output, Output::Text { .. }));
+
+        // The API key and the TTL are not part of the cache key.
+        assert_eq!(tool.invoke(input("other_api_key", 60)).await, output);
+        assert_eq!(tool.invoke(input("your_api_key", 30)).await, output);
+
+        // Expired entries are evicted and the API is called again.
+        assert_eq!(tool.invoke(input("your_api_key", 0)).await, output);
+
+        mock.assert_async().await;
+    }
 }
@@ -0,0 +1,107 @@
+//! This module provides information about the OpenAI models that is used to
+//! check requests before they are sent to the API.
+//!
+//! Token counts follow
+//! <https://cookbook.openai.com/examples/how_to_count_tokens_with_tiktoken>
+//! and are an estimate as the exact overhead per message depends on the model.
+
+use tiktoken_rs::{get_bpe_from_model, o200k_base};
+
+/// Tokens added to every message for its role and delimiters.
+const TOKENS_PER_MESSAGE: usize = 3;
+/// Tokens added to messages with a participant name.
+const TOKENS_PER_NAME: usize = 1;
+/// Tokens that prime the reply of the assistant.
+const TOKENS_PER_REPLY: usize = 3;
+
+/// Context window sizes in tokens by model name prefix. The longest matching
+/// prefix wins so that, for example, `gpt-4-turbo` is not matched by `gpt-4`.
+pub(crate) const CONTEXT_WINDOWS: [(&str, usize); 14] = [
+    ("gpt-5", 400_000),
+    ("gpt-4.1", 1_047_576),
+    ("gpt-4o", 128_000),
+    ("chatgpt-4o", 128_000),
+    ("gpt-4-turbo", 128_000),
+    ("gpt-4-1106", 128_000),
+    ("gpt-4-0125", 128_000),
+    ("gpt-4-32k", 32_768),
+    ("gpt-4", 8_192),
+    ("gpt-3.5-turbo", 16_385),
+    ("o1-mini", 128_000),
+    ("o1", 200_000),
+    ("o3", 200_000),
+    ("o4-mini", 200_000),
+];
+
+/// Returns the context window of the model or [`None`] if the model is not
+/// known.
+pub(crate) fn context_window_for_model(model: &str) -> Option<usize> {
+    let model = model.to_ascii_lowercase();
+
+    CONTEXT_WINDOWS
+        .iter()
+        .filter(|(prefix, _)| model.starts_with(prefix))
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(_, window)| *window)
+}
+
+/// The parts of a message that count towards the context window.
+pub(crate) struct MessageText<'a> {
+    pub(crate) content: &'a str,
+    pub(crate) name: Option<&'a str>,
+}
+
+/// Count the tokens of the messages as they will be sent to the model.
+/// Models unknown to `tiktoken` are counted with the `o200k_base` encoding.
+pub(crate) fn count_message_tokens(model: &str, messages: &[MessageText]) -> anyhow::Result<usize> {
+    let bpe = get_bpe_from_model(model).or_else(|_| o200k_base())?;
+
+    let tokens = messages.iter().fold(TOKENS_PER_REPLY, |tokens, message| {
+        let name = message.name.map_or(0, |name| {
+            TOKENS_PER_NAME + bpe.encode_with_special_tokens(name).len()
+        });
+
+        tokens + TOKENS_PER_MESSAGE + bpe.encode_with_special_tokens(message.content).len() + name
+    });
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context_window_for_model() {
+        assert_eq!(context_window_for_model("gpt-4o-mini"), Some(128_000));
+        assert_eq!(context_window_for_model("GPT-4-32k-0613"), Some(32_768));
+        assert_eq!(
+            context_window_for_model("gpt-4-turbo-preview"),
+            Some(128_000)
+        );
+        assert_eq!(context_window_for_model("gpt-4"), Some(8_192));
+        assert_eq!(context_window_for_model("o1-mini"), Some(128_000));
+        assert_eq!(context_window_for_model("llama-3"), None);
+    }
+
+    #[test]
+    fn test_count_message_tokens() {
+        let messages = [
+            MessageText {
+                content: "hello world",
+                name: None,
+            },
+            MessageText {
+                content: "hello",
+                name: Some("robot"),
+            },
+        ];
+
+        // 3 for the reply, 3 per message, 2 + 1 for the content and 1 + 1
+        // for the name.
+        assert_eq!(count_message_tokens("gpt-4o", &messages).unwrap(), 14);
+
+        // Unknown models fall back to `o200k_base`.
+        assert_eq!(count_message_tokens("unknown", &messages).unwrap(), 14);
+    }
+}
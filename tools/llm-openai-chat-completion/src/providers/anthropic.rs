@@ -0,0 +1,272 @@
+//! Chat completions with the Anthropic Messages API.
+//!
+//! See <https://docs.anthropic.com/en/api/messages>. Anthropic has no response
+//! format parameter so JSON completions are requested by forcing the model to
+//! call a tool whose input schema is the requested JSON schema.
+
+use {
+    super::{Completion, CompletionRequest, CompletionResponse, ProviderClient},
+    crate::{Message, MessageKind},
+    anyhow::{anyhow, bail},
+    reqwest::Client,
+    serde::Deserialize,
+    serde_json::{json, Value},
+};
+
+/// The base URL of the Anthropic API.
+pub(crate) const ANTHROPIC_API_BASE: &str = "https://api.anthropic.com/v1";
+/// The version of the Anthropic API the requests are written against.
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// The response of the Messages API.
+#[derive(Debug, Deserialize)]
+struct MessagesResponse {
+    id: String,
+    content: Vec<ContentBlock>,
+    stop_reason: Option<String>,
+}
+
+/// A block of the content of a message. Blocks the Tool does not use, such as
+/// thinking blocks, are ignored.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ContentBlock {
+    Text {
+        text: String,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+        input: Value,
+    },
+    #[serde(other)]
+    Other,
+}
+
+/// The body of an error returned by the Anthropic API.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    error: ErrorDetail,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorDetail {
+    message: String,
+}
+
+pub(crate) struct AnthropicClient {
+    client: Client,
+    api_key: String,
+    api_base: String,
+}
+
+impl AnthropicClient {
+    pub(crate) fn new(api_key: &str, api_base: &str) -> Self {
+        Self {
+            client: Client::new(),
+            api_key: api_key.to_string(),
+            api_base: api_base.trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+impl ProviderClient for AnthropicClient {
+    async fn complete(&self, request: CompletionRequest) -> anyhow::Result<CompletionResponse> {
+        let is_json = request.json_schema.is_some();
+        let body = build_request(request)?;
+
+        let response = self
+            .client
+            .post(format!("{}/messages", self.api_base))
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION)
+            .header("content-type", "application/json")
+            .body(body.to_string())
+            .send()
+            .await
+            .map_err(|err| anyhow!("Error calling Anthropic API: {}", err))?;
+
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .map_err(|err| anyhow!("Error reading Anthropic response: {}", err))?;
+
+        if !status.is_success() {
+            let message = serde_json::from_str::<ErrorResponse>(&text)
+                .map(|response| response.error.message)
+                .unwrap_or(text);
+
+            bail!("Error calling Anthropic API ({}): {}", status, message);
+        }
+
+        let response = serde_json::from_str::<MessagesResponse>(&text)
+            .map_err(|err| anyhow!("Error parsing Anthropic response: {}", err))?;
+
+        if response.stop_reason.as_deref() == Some("refusal") {
+            bail!("The model refused to respond");
+        }
+
+        // Parallel tool calls are not supported yet so only the first call is
+        // returned.
+        let tool_use = response.content.iter().find_map(|block| match block {
+            ContentBlock::ToolUse { id, name, input } => Some((id, name, input)),
+            _ => None,
+        });
+
+        if let Some((id, name, input)) = tool_use {
+            // The call of the forced JSON tool is the JSON completion.
+            let completion = if is_json {
+                Completion::Text(input.to_string())
+            } else {
+                Completion::FunctionCall {
+                    id: id.clone(),
+                    name: name.clone(),
+                    arguments: input.clone(),
+                }
+            };
+
+            return Ok(CompletionResponse {
+                id: response.id,
+                role: MessageKind::Assistant,
+                completion,
+            });
+        }
+
+        let completion = response
+            .content
+            .into_iter()
+            .filter_map(|block| match block {
+                ContentBlock::Text { text } => Some(text),
+                _ => None,
+            })
+            .collect::<String>();
+
+        if completion.is_empty() {
+            bail!("No completion returned from Anthropic API");
+        }
+
+        Ok(CompletionResponse {
+            id: response.id,
+            role: MessageKind::Assistant,
+            completion: Completion::Text(completion),
+        })
+    }
+}
+
+/// Create the body of the Messages API request. System messages are sent as
+/// the top level `system` prompt as the API does not accept them in
+/// `messages`.
+fn build_request(request: CompletionRequest) -> anyhow::Result<Value> {
+    let mut system = vec![];
+    let mut messages = vec![];
+
+    for message in request.messages {
+        match message {
+            Message::Full {
+                role: MessageKind::System,
+                value,
+                ..
+            } => system.push(value),
+            // Anthropic does not support participant names.
+            Message::Full { role, value, .. } => {
+                let role = match role {
+                    MessageKind::User => "user",
+                    MessageKind::Assistant => "assistant",
+                    _ => bail!("Tool and Function roles are not supported"),
+                };
+
+                messages.push(json!({ "role": role, "content": value }));
+            }
+            Message::Vision { text, image_urls } => {
+                let images = image_urls
+                    .iter()
+                    .map(|url| image_block(url))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+
+                let content = std::iter::once(json!({ "type": "text", "text": text }))
+                    .chain(images)
+                    .collect::<Vec<_>>();
+
+                messages.push(json!({ "role": "user", "content": content }));
+            }
+            Message::Short(value) => messages.push(json!({ "role": "user", "content": value })),
+        }
+    }
+
+    let mut body = json!({
+        "model": request.model,
+        "max_tokens": request.max_completion_tokens,
+        "temperature": request.temperature,
+        "messages": messages,
+    });
+
+    if !system.is_empty() {
+        body["system"] = json!(system.join("\n\n"));
+    }
+
+    let mut tools = request
+        .tools
+        .into_iter()
+        .map(|tool| {
+            // Anthropic requires an input schema even if the function takes no
+            // parameters.
+            let input_schema = tool
+                .function
+                .parameters
+                .map(|schema| schema.to_value())
+                .unwrap_or_else(|| json!({ "type": "object", "properties": {} }));
+
+            tool_definition(tool.function.name, tool.function.description, input_schema)
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(schema) = request.json_schema {
+        if !tools.is_empty() {
+            bail!("JSON completions with tools are not supported by the Anthropic provider");
+        }
+
+        body["tool_choice"] = json!({ "type": "tool", "name": schema.name });
+
+        tools.push(tool_definition(
+            schema.name,
+            schema.description,
+            schema.schema.to_value(),
+        ));
+    }
+
+    if !tools.is_empty() {
+        body["tools"] = json!(tools);
+    }
+
+    Ok(body)
+}
+
+/// A tool the model may call. The description is left out rather than sent as
+/// `null` if there is none.
+fn tool_definition(name: String, description: Option<String>, input_schema: Value) -> Value {
+    let mut tool = json!({ "name": name, "input_schema": input_schema });
+
+    if let Some(description) = description {
+        tool["description"] = json!(description);
+    }
+
+    tool
+}
+
+/// Convert an image URL to an image content block. Base64 encoded `data:`
+/// URLs are sent inline, other URLs are fetched by Anthropic.
+fn image_block(url: &str) -> anyhow::Result<Value> {
+    let Some(data_url) = url.strip_prefix("data:") else {
+        return Ok(json!({ "type": "image", "source": { "type": "url", "url": url } }));
+    };
+
+    let Some((media_type, data)) = data_url.split_once(";base64,") else {
+        bail!("Image data URLs must be base64 encoded");
+    };
+
+    Ok(json!({
+        "type": "image",
+        "source": { "type": "base64", "media_type": media_type, "data": data },
+    }))
+}
@@ -0,0 +1,99 @@
+//! This module abstracts over the LLM providers the Tool can generate chat
+//! completions with.
+//!
+//! Every provider implements [`ProviderClient`] which turns a provider
+//! agnostic [`CompletionRequest`] into a [`CompletionResponse`]. Validating
+//! the input and mapping the response to the Tool output is left to the Tool.
+
+use {
+    crate::{Message, MessageKind, OpenAIFunctionDef, OpenAIJsonSchema},
+    nexus_toolkit::Secret,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+pub(crate) mod anthropic;
+pub(crate) mod openai;
+
+/// The default model to use for Anthropic chat completions.
+pub(crate) const DEFAULT_ANTHROPIC_MODEL: &str = "claude-3-5-haiku-latest";
+
+/// The LLM provider to generate the chat completion with.
+///
+/// Serialized as part of the cache key, which must not depend on the API key.
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Provider {
+    /// Which provider to call.
+    pub(crate) kind: ProviderKind,
+    /// The API key of the provider.
+    // TODO: <https://github.com/Talus-Network/nexus-sdk/issues/29>.
+    #[serde(skip_serializing)]
+    pub(crate) api_key: Secret<String>,
+    /// The base URL of the provider API. Defaults to the public API of the
+    /// provider.
+    #[serde(default)]
+    pub(crate) api_base: Option<String>,
+}
+
+/// The supported LLM providers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ProviderKind {
+    #[default]
+    OpenAI,
+    Anthropic,
+}
+
+impl ProviderKind {
+    /// The model to use if the input does not name one.
+    pub(crate) fn default_model(&self) -> &'static str {
+        match self {
+            ProviderKind::OpenAI => crate::DEFAULT_MODEL,
+            ProviderKind::Anthropic => DEFAULT_ANTHROPIC_MODEL,
+        }
+    }
+}
+
+/// A chat completion request that is not specific to any provider.
+#[derive(Debug)]
+pub(crate) struct CompletionRequest {
+    pub(crate) model: String,
+    /// The context and prompt messages in the order they are sent.
+    pub(crate) messages: Vec<Message>,
+    pub(crate) max_completion_tokens: u32,
+    pub(crate) temperature: f32,
+    pub(crate) json_schema: Option<OpenAIJsonSchema>,
+    pub(crate) tools: Vec<OpenAIFunctionDef>,
+}
+
+/// A chat completion returned by a provider.
+#[derive(Debug, PartialEq)]
+pub(crate) struct CompletionResponse {
+    /// Unique identifier for the completion.
+    pub(crate) id: String,
+    /// The role of the author of the completion.
+    pub(crate) role: MessageKind,
+    pub(crate) completion: Completion,
+}
+
+/// What the model responded with. Only the first choice is returned as the
+/// Tool supports a single completion.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Completion {
+    /// Plain text, or the raw JSON if a JSON schema was requested.
+    Text(String),
+    /// The model called one of the provided tools. Parallel tool calls are not
+    /// supported so this is the first call.
+    FunctionCall {
+        id: String,
+        name: String,
+        arguments: serde_json::Value,
+    },
+}
+
+/// A client of an LLM provider API.
+pub(crate) trait ProviderClient {
+    /// Generate a chat completion for the request.
+    async fn complete(&self, request: CompletionRequest) -> anyhow::Result<CompletionResponse>;
+}
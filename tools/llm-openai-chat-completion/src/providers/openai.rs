@@ -0,0 +1,210 @@
+//! Chat completions with the OpenAI API using [`async_openai`].
+
+use {
+    super::{Completion, CompletionRequest, CompletionResponse, ProviderClient},
+    crate::MessageKind,
+    anyhow::{anyhow, bail},
+    async_openai::{
+        config::OpenAIConfig,
+        error::OpenAIError,
+        types::{
+            ChatCompletionRequestMessage,
+            ChatCompletionTool,
+            CreateChatCompletionRequest,
+            CreateChatCompletionRequestArgs,
+            ResponseFormat,
+            ResponseFormatJsonSchema,
+        },
+        Client,
+    },
+    futures_util::StreamExt,
+};
+
+/// A completion that was streamed from the OpenAI API.
+#[derive(Debug, PartialEq)]
+pub(crate) struct StreamedCompletion {
+    pub(crate) id: String,
+    pub(crate) role: MessageKind,
+    /// The content of each streamed delta so that token boundaries are
+    /// preserved.
+    pub(crate) chunks: Vec<String>,
+}
+
+pub(crate) struct OpenAIClient {
+    client: Client<OpenAIConfig>,
+}
+
+impl OpenAIClient {
+    pub(crate) fn new(api_key: &str, api_base: &str) -> Self {
+        let cfg = OpenAIConfig::new()
+            .with_api_key(api_key)
+            .with_api_base(api_base);
+
+        Self {
+            client: Client::with_config(cfg),
+        }
+    }
+
+    /// Stream the completion and collect its chunks.
+    pub(crate) async fn stream(
+        &self,
+        request: CompletionRequest,
+    ) -> anyhow::Result<StreamedCompletion> {
+        let openai_request = build_request(request)?;
+
+        let mut stream = self
+            .client
+            .chat()
+            .create_stream(openai_request)
+            .await
+            .map_err(|err| anyhow!("Error calling OpenAI API: {}", err))?;
+
+        let mut id = None;
+        let mut role = MessageKind::Assistant;
+        let mut chunks = vec![];
+        let mut refusal = String::new();
+
+        while let Some(response) = stream.next().await {
+            let response =
+                response.map_err(|err| anyhow!("Error reading OpenAI stream: {}", err))?;
+
+            id.get_or_insert(response.id);
+
+            // Current Tool design only supports a single choice so we take the
+            // first one.
+            let Some(choice) = response
+                .choices
+                .into_iter()
+                .find(|choice| choice.index == 0)
+            else {
+                continue;
+            };
+
+            if let Some(delta_role) = choice.delta.role {
+                role = delta_role.into();
+            }
+
+            if let Some(delta_refusal) = choice.delta.refusal {
+                refusal.push_str(&delta_refusal);
+            }
+
+            match choice.delta.content {
+                Some(content) if !content.is_empty() => chunks.push(content),
+                _ => (),
+            }
+        }
+
+        if !refusal.is_empty() {
+            bail!(refusal);
+        }
+
+        match id {
+            Some(id) if !chunks.is_empty() => Ok(StreamedCompletion { id, role, chunks }),
+            _ => bail!("No completion returned from OpenAI API"),
+        }
+    }
+}
+
+impl ProviderClient for OpenAIClient {
+    async fn complete(&self, request: CompletionRequest) -> anyhow::Result<CompletionResponse> {
+        let openai_request = build_request(request)?;
+
+        let response = self
+            .client
+            .chat()
+            .create(openai_request)
+            .await
+            .map_err(|err| anyhow!("Error calling OpenAI API: {}", err))?;
+
+        // Current Tool design only supports a single choice so we take the
+        // first one.
+        //
+        // This design is also better for the Nexus interface as having a single
+        // plaintext field with the completion is better suited.
+        let Some(choice) = response.choices.into_iter().next() else {
+            bail!("No choices returned from OpenAI API");
+        };
+
+        if let Some(refusal) = choice.message.refusal {
+            bail!(refusal);
+        }
+
+        // Parallel tool calls are not supported yet so only the first call is
+        // returned.
+        if let Some(call) = choice
+            .message
+            .tool_calls
+            .and_then(|calls| calls.into_iter().next())
+        {
+            let arguments = serde_json::from_str(&call.function.arguments)
+                .map_err(|err| anyhow!("Error parsing function call arguments: {}", err))?;
+
+            return Ok(CompletionResponse {
+                id: response.id,
+                role: choice.message.role.into(),
+                completion: Completion::FunctionCall {
+                    id: call.id,
+                    name: call.function.name,
+                    arguments,
+                },
+            });
+        }
+
+        let Some(completion) = choice.message.content else {
+            bail!("No completion returned from OpenAI API");
+        };
+
+        Ok(CompletionResponse {
+            id: response.id,
+            role: choice.message.role.into(),
+            completion: Completion::Text(completion),
+        })
+    }
+}
+
+/// Create the request to send to the OpenAI API.
+fn build_request(request: CompletionRequest) -> anyhow::Result<CreateChatCompletionRequest> {
+    // Should something go wrong, return an error. This is however very
+    // unlikely as the inputs are validated against a schema defined in the
+    // Tool.
+    let messages = request
+        .messages
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<Result<Vec<ChatCompletionRequestMessage>, OpenAIError>>()?;
+
+    let mut openai_request = CreateChatCompletionRequestArgs::default();
+
+    let mut openai_request = openai_request
+        .max_completion_tokens(request.max_completion_tokens)
+        .model(request.model)
+        .temperature(request.temperature)
+        .messages(messages);
+
+    // If a JSON schema is provided, set it on the request.
+    if let Some(schema) = request.json_schema {
+        let json_schema = ResponseFormatJsonSchema {
+            name: schema.name,
+            schema: Some(schema.schema.to_value()),
+            description: schema.description,
+            strict: schema.strict,
+        };
+
+        openai_request = openai_request.response_format(ResponseFormat::JsonSchema { json_schema });
+    }
+
+    // If tools are provided, let the model call them.
+    if !request.tools.is_empty() {
+        openai_request = openai_request.tools(
+            request
+                .tools
+                .into_iter()
+                .map(ChatCompletionTool::from)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    openai_request
+        .build()
+        .map_err(|err| anyhow!("Error building OpenAI request: {}", err))
+}
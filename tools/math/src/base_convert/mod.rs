@@ -0,0 +1,187 @@
+//! # `xyz.taluslabs.math.base-convert@1`
+//!
+//! Standard Nexus Tool that converts an integer written in one base to another
+//! base, for example hexadecimal to binary.
+
+use {
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+    std::ops::RangeInclusive,
+};
+
+/// Bases that can be written with the digits `0-9` and letters `a-z`.
+const SUPPORTED_BASES: RangeInclusive<u8> = 2..=36;
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// The integer to convert, optionally prefixed with a sign.
+    value: String,
+    from_base: u8,
+    to_base: u8,
+    /// Write digits above 9 as uppercase letters, defaults to lowercase.
+    #[serde(default)]
+    uppercase: bool,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok { result: String },
+    Err { reason: String },
+}
+
+pub(crate) struct BaseConvert;
+
+impl NexusTool for BaseConvert {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.math.base-convert@1")
+    }
+
+    fn path() -> &'static str {
+        "/base-convert"
+    }
+
+    fn description() -> &'static str {
+        "Converts an integer written in one base between 2 and 36 to another."
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        // This tool has no external dependencies and as such, it is always
+        // healthy if the endpoint is reachable.
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(
+        &self,
+        Self::Input {
+            value,
+            from_base,
+            to_base,
+            uppercase,
+        }: Self::Input,
+    ) -> Self::Output {
+        for base in [from_base, to_base] {
+            if !SUPPORTED_BASES.contains(&base) {
+                return Output::Err {
+                    reason: format!("Base '{base}' is not supported, it must be between 2 and 36"),
+                };
+            }
+        }
+
+        let number = match i128::from_str_radix(&value, from_base as u32) {
+            Ok(number) => number,
+            Err(e) => {
+                return Output::Err {
+                    reason: format!("Value '{value}' is not a valid base {from_base} integer: {e}"),
+                }
+            }
+        };
+
+        Output::Ok {
+            result: to_radix(number, to_base as u32, uppercase),
+        }
+    }
+}
+
+/// Write the number in the given radix.
+fn to_radix(number: i128, radix: u32, uppercase: bool) -> String {
+    // Work with the magnitude so that `i128::MIN` can be negated.
+    let mut magnitude = number.unsigned_abs();
+    let mut digits = Vec::new();
+
+    loop {
+        let digit = char::from_digit((magnitude % radix as u128) as u32, radix)
+            .expect("Remainder is always a valid digit");
+
+        digits.push(match uppercase {
+            true => digit.to_ascii_uppercase(),
+            false => digit,
+        });
+
+        magnitude /= radix as u128;
+
+        if magnitude == 0 {
+            break;
+        }
+    }
+
+    if number < 0 {
+        digits.push('-');
+    }
+
+    digits.into_iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn convert(value: &str, from_base: u8, to_base: u8, uppercase: bool) -> Output {
+        BaseConvert::new()
+            .await
+            .invoke(Input {
+                value: value.to_string(),
+                from_base,
+                to_base,
+                uppercase,
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_base_convert() {
+        for (value, from_base, to_base, uppercase, expected) in [
+            ("255", 10, 16, false, "ff"),
+            ("255", 10, 16, true, "FF"),
+            ("ff", 16, 2, false, "11111111"),
+            ("FF", 16, 10, false, "255"),
+            ("-1010", 2, 10, false, "-10"),
+            ("0", 10, 2, false, "0"),
+            ("zz", 36, 10, false, "1295"),
+            ("1295", 10, 36, true, "ZZ"),
+            (
+                "-170141183460469231731687303715884105728",
+                10,
+                16,
+                false,
+                "-80000000000000000000000000000000",
+            ),
+        ] {
+            match convert(value, from_base, to_base, uppercase).await {
+                Output::Ok { result } => assert_eq!(result, expected, "{value}"),
+                output => panic!("Expected Ok variant, got {output:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_base_convert_errors() {
+        for (value, from_base, to_base) in [
+            ("12", 2, 10),
+            ("", 10, 2),
+            ("1.5", 10, 2),
+            ("10", 1, 10),
+            ("10", 10, 37),
+        ] {
+            match convert(value, from_base, to_base, false).await {
+                Output::Err { .. } => {}
+                output => panic!("Expected Err variant for '{value}', got {output:?}"),
+            }
+        }
+
+        match convert("10", 10, 0, false).await {
+            Output::Err { reason } => assert!(reason.contains("Base '0' is not supported")),
+            output => panic!("Expected Err variant, got {output:?}"),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! # `xyz.taluslabs.math.f64.add@1`
+//!
+//! Standard Nexus Tool that adds two [`f64`] numbers and returns the result.
+
+use {
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    a: f64,
+    b: f64,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok { result: f64 },
+    Err { reason: String },
+}
+
+pub(crate) struct F64Add;
+
+impl NexusTool for F64Add {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.math.f64.add@1")
+    }
+
+    fn path() -> &'static str {
+        "/f64/add"
+    }
+
+    fn description() -> &'static str {
+        "Adds two f64 numbers and returns the result."
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        // This tool has no external dependencies and as such, it is always
+        // healthy if the endpoint is reachable.
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, Self::Input { a, b }: Self::Input) -> Self::Output {
+        match a + b {
+            result if result.is_finite() => Output::Ok { result },
+            _ => Output::Err {
+                reason: format!("Adding '{a}' and '{b}' results in an overflow"),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_f64_add() {
+        let tool = F64Add::new().await;
+
+        let input = Input { a: 1.5, b: 2.25 };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Ok { result } if result == 3.75));
+
+        let input = Input { a: 1.5, b: -2.5 };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Ok { result } if result == -1.0));
+
+        let input = Input {
+            a: f64::MAX,
+            b: f64::MAX,
+        };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Err { .. }));
+
+        assert!(matches!(tool.health().await, Ok(StatusCode::OK)));
+    }
+}
@@ -0,0 +1,93 @@
+//! # `xyz.taluslabs.math.f64.cmp@1`
+//!
+//! Standard Nexus Tool that compares two [`f64`] numbers and returns the result.
+
+use {
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    a: f64,
+    b: f64,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Gt { a: f64, b: f64 },
+    Eq { a: f64, b: f64 },
+    Lt { a: f64, b: f64 },
+}
+
+pub(crate) struct F64Cmp;
+
+impl NexusTool for F64Cmp {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.math.f64.cmp@1")
+    }
+
+    fn path() -> &'static str {
+        "/f64/cmp"
+    }
+
+    fn description() -> &'static str {
+        "Compare two f64 numbers and return both numbers back."
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        // This tool has no external dependencies and as such, it is always
+        // healthy if the endpoint is reachable.
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, Self::Input { a, b }: Self::Input) -> Self::Output {
+        // JSON numbers are never NaN so the numbers are always ordered.
+        if a > b {
+            Output::Gt { a, b }
+        } else if a < b {
+            Output::Lt { a, b }
+        } else {
+            Output::Eq { a, b }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_f64_cmp() {
+        let tool = F64Cmp::new().await;
+
+        let input = Input { a: 1.5, b: 2.0 };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Lt { a, b } if a == 1.5 && b == 2.0));
+
+        let input = Input { a: 2.0, b: 1.5 };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Gt { a, b } if a == 2.0 && b == 1.5));
+
+        // Positive and negative zero are equal.
+        let input = Input { a: 0.0, b: -0.0 };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Eq { .. }));
+
+        assert!(matches!(tool.health().await, Ok(StatusCode::OK)));
+    }
+}
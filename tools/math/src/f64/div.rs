@@ -0,0 +1,103 @@
+//! # `xyz.taluslabs.math.f64.div@1`
+//!
+//! Standard Nexus Tool that divides two [`f64`] numbers and returns the result.
+
+use {
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    a: f64,
+    b: f64,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok { result: f64 },
+    Err { reason: String },
+}
+
+pub(crate) struct F64Div;
+
+impl NexusTool for F64Div {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.math.f64.div@1")
+    }
+
+    fn path() -> &'static str {
+        "/f64/div"
+    }
+
+    fn description() -> &'static str {
+        "Divide the first f64 number by the second and return the result."
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        // This tool has no external dependencies and as such, it is always
+        // healthy if the endpoint is reachable.
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, Self::Input { a, b }: Self::Input) -> Self::Output {
+        if b == 0.0 {
+            return Output::Err {
+                reason: format!("Dividing '{a}' by zero is undefined"),
+            };
+        }
+
+        match a / b {
+            result if result.is_finite() => Output::Ok { result },
+            _ => Output::Err {
+                reason: format!("Dividing '{a}' by '{b}' results in an overflow"),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_f64_div() {
+        let tool = F64Div::new().await;
+
+        let input = Input { a: 7.5, b: -2.5 };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Ok { result } if result == -3.0));
+
+        let input = Input { a: 1.0, b: 0.0 };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Err { reason } if reason.contains("zero")));
+
+        let input = Input { a: 1.0, b: -0.0 };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Err { reason } if reason.contains("zero")));
+
+        let input = Input {
+            a: f64::MAX,
+            b: 0.5,
+        };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Err { reason } if reason.contains("overflow")));
+
+        assert!(matches!(tool.health().await, Ok(StatusCode::OK)));
+    }
+}
@@ -0,0 +1,11 @@
+//! # `xyz.taluslabs.math.f64.*`
+//!
+//! This module contains tools for 64-bit floating point operations. JSON
+//! cannot represent infinite or NaN numbers so operations that would produce
+//! them return an error instead.
+
+pub(crate) mod add;
+pub(crate) mod cmp;
+pub(crate) mod div;
+pub(crate) mod mul;
+pub(crate) mod pow;
@@ -0,0 +1,122 @@
+//! # `xyz.taluslabs.math.f64.pow@1`
+//!
+//! Standard Nexus Tool that raises an [`f64`] number to the power of another
+//! and returns the result.
+
+use {
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    base: f64,
+    exponent: f64,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok { result: f64 },
+    Err { reason: String },
+}
+
+pub(crate) struct F64Pow;
+
+impl NexusTool for F64Pow {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.math.f64.pow@1")
+    }
+
+    fn path() -> &'static str {
+        "/f64/pow"
+    }
+
+    fn description() -> &'static str {
+        "Raise an f64 number to the power of another and return the result."
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        // This tool has no external dependencies and as such, it is always
+        // healthy if the endpoint is reachable.
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, Self::Input { base, exponent }: Self::Input) -> Self::Output {
+        match base.powf(exponent) {
+            result if result.is_finite() => Output::Ok { result },
+            result if result.is_nan() => Output::Err {
+                reason: format!("Raising '{base}' to the power of '{exponent}' is undefined"),
+            },
+            _ => Output::Err {
+                reason: format!(
+                    "Raising '{base}' to the power of '{exponent}' results in an overflow"
+                ),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_f64_pow() {
+        let tool = F64Pow::new().await;
+
+        let input = Input {
+            base: 2.0,
+            exponent: 10.0,
+        };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Ok { result } if result == 1024.0));
+
+        let input = Input {
+            base: 4.0,
+            exponent: -0.5,
+        };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Ok { result } if result == 0.5));
+
+        // Roots of negative numbers are not real.
+        let input = Input {
+            base: -8.0,
+            exponent: 0.5,
+        };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Err { reason } if reason.contains("undefined")));
+
+        let input = Input {
+            base: 10.0,
+            exponent: 400.0,
+        };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Err { reason } if reason.contains("overflow")));
+
+        // Division by zero.
+        let input = Input {
+            base: 0.0,
+            exponent: -1.0,
+        };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Err { .. }));
+
+        assert!(matches!(tool.health().await, Ok(StatusCode::OK)));
+    }
+}
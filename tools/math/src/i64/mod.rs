@@ -2,7 +2,25 @@
 //!
 //! This module contains tools for 64-bit signed integer operations.
 
+use {schemars::JsonSchema, serde::Deserialize};
+
 pub(crate) mod add;
 pub(crate) mod cmp;
+pub(crate) mod modulo;
 pub(crate) mod mul;
+pub(crate) mod pow;
+pub(crate) mod stats;
 pub(crate) mod sum;
+
+/// What to do when the result of an operation does not fit into an [`i64`].
+#[derive(Clone, Copy, Debug, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum OverflowMode {
+    /// Wrap around at the boundary of the type.
+    Wrap,
+    /// Clamp the result to [`i64::MIN`] or [`i64::MAX`].
+    Saturate,
+    /// Return an error.
+    #[default]
+    Error,
+}
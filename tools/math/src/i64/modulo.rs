@@ -0,0 +1,135 @@
+//! # `xyz.taluslabs.math.i64.modulo@1`
+//!
+//! Standard Nexus Tool that divides two [`i64`] numbers with floored division
+//! and returns the quotient and the remainder.
+
+use {
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    dividend: i64,
+    divisor: i64,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok { quotient: i64, remainder: i64 },
+    Err { reason: String },
+}
+
+pub(crate) struct I64Modulo;
+
+impl NexusTool for I64Modulo {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.math.i64.modulo@1")
+    }
+
+    fn path() -> &'static str {
+        "/i64/modulo"
+    }
+
+    fn description() -> &'static str {
+        "Divides two i64 numbers rounding towards negative infinity and returns the quotient and the remainder."
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        // This tool has no external dependencies and as such, it is always
+        // healthy if the endpoint is reachable.
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, Self::Input { dividend, divisor }: Self::Input) -> Self::Output {
+        if divisor == 0 {
+            return Output::Err {
+                reason: "Division by zero".to_string(),
+            };
+        }
+
+        // Only `i64::MIN / -1` overflows.
+        let (Some(mut quotient), Some(mut remainder)) =
+            (dividend.checked_div(divisor), dividend.checked_rem(divisor))
+        else {
+            return Output::Err {
+                reason: format!("Dividing '{dividend}' by '{divisor}' results in an overflow"),
+            };
+        };
+
+        // Rust truncates towards zero so the remainder has the sign of the
+        // dividend. Floored division gives it the sign of the divisor instead.
+        if remainder != 0 && (remainder < 0) != (divisor < 0) {
+            quotient -= 1;
+            remainder += divisor;
+        }
+
+        Output::Ok {
+            quotient,
+            remainder,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn modulo(dividend: i64, divisor: i64) -> Output {
+        I64Modulo::new()
+            .await
+            .invoke(Input { dividend, divisor })
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_i64_modulo() {
+        for (dividend, divisor, quotient, remainder) in [
+            (7, 3, 2, 1),
+            (-7, 3, -3, 2),
+            (7, -3, -3, -2),
+            (-7, -3, 2, -1),
+            (6, 3, 2, 0),
+            (-6, 3, -2, 0),
+            (0, 5, 0, 0),
+            (i64::MIN, 1, i64::MIN, 0),
+            (i64::MIN, i64::MAX, -2, i64::MAX - 1),
+        ] {
+            match modulo(dividend, divisor).await {
+                Output::Ok {
+                    quotient: q,
+                    remainder: r,
+                } => {
+                    assert_eq!((q, r), (quotient, remainder), "{dividend} / {divisor}");
+                    // The results always satisfy the division identity.
+                    assert_eq!(q as i128 * divisor as i128 + r as i128, dividend as i128);
+                }
+                output => panic!("Expected Ok variant, got {output:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_i64_modulo_errors() {
+        match modulo(1, 0).await {
+            Output::Err { reason } => assert_eq!(reason, "Division by zero"),
+            output => panic!("Expected Err variant, got {output:?}"),
+        }
+
+        match modulo(i64::MIN, -1).await {
+            Output::Err { reason } => assert!(reason.contains("overflow")),
+            output => panic!("Expected Err variant, got {output:?}"),
+        }
+    }
+}
@@ -3,6 +3,7 @@
 //! Standard Nexus Tool that multiplies two [`i64`] numbers and returns the result.
 
 use {
+    super::OverflowMode,
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
     schemars::JsonSchema,
@@ -14,6 +15,9 @@ use {
 pub(crate) struct Input {
     a: i64,
     b: i64,
+    /// What to do when the result overflows, defaults to an error.
+    #[serde(default)]
+    overflow_mode: OverflowMode,
 }
 
 #[derive(Serialize, JsonSchema)]
@@ -51,11 +55,26 @@ impl NexusTool for I64Mul {
         Ok(StatusCode::OK)
     }
 
-    async fn invoke(&self, Self::Input { a, b }: Self::Input) -> Self::Output {
-        match a.checked_mul(b) {
-            Some(result) => Output::Ok { result },
-            None => Output::Err {
-                reason: format!("Multiplying '{a}' and '{b}' results in an overflow"),
+    async fn invoke(
+        &self,
+        Self::Input {
+            a,
+            b,
+            overflow_mode,
+        }: Self::Input,
+    ) -> Self::Output {
+        match overflow_mode {
+            OverflowMode::Wrap => Output::Ok {
+                result: a.wrapping_mul(b),
+            },
+            OverflowMode::Saturate => Output::Ok {
+                result: a.saturating_mul(b),
+            },
+            OverflowMode::Error => match a.checked_mul(b) {
+                Some(result) => Output::Ok { result },
+                None => Output::Err {
+                    reason: format!("Multiplying '{a}' and '{b}' results in an overflow"),
+                },
             },
         }
     }
@@ -69,21 +88,61 @@ mod tests {
     async fn test_i64_mul() {
         let tool = I64Mul::new().await;
 
-        let input = Input { a: 2, b: 3 };
+        let input = Input {
+            a: 2,
+            b: 3,
+            overflow_mode: OverflowMode::Error,
+        };
         let output = tool.invoke(input).await;
 
         assert!(matches!(output, Output::Ok { result: 6 }));
 
-        let input = Input { a: i64::MAX, b: 2 };
+        let input = Input {
+            a: i64::MAX,
+            b: 2,
+            overflow_mode: OverflowMode::Error,
+        };
         let output = tool.invoke(input).await;
 
         assert!(matches!(output, Output::Err { .. }));
 
-        let input = Input { a: i64::MIN, b: 2 };
+        let input = Input {
+            a: i64::MIN,
+            b: 2,
+            overflow_mode: OverflowMode::Error,
+        };
         let output = tool.invoke(input).await;
 
         assert!(matches!(output, Output::Err { .. }));
 
         assert!(matches!(tool.health().await, Ok(StatusCode::OK)));
     }
+
+    #[tokio::test]
+    async fn test_i64_mul_overflow_mode() {
+        let tool = I64Mul::new().await;
+
+        let input = Input {
+            a: i64::MIN,
+            b: 2,
+            overflow_mode: OverflowMode::Wrap,
+        };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Ok { result } if result == 0));
+
+        let input = Input {
+            a: i64::MIN,
+            b: 2,
+            overflow_mode: OverflowMode::Saturate,
+        };
+        let output = tool.invoke(input).await;
+
+        assert!(matches!(output, Output::Ok { result } if result == i64::MIN));
+
+        // Overflows are errors unless a mode is set.
+        let input: Input = serde_json::from_str(r#"{"a": 1, "b": 2}"#).unwrap();
+
+        assert!(matches!(input.overflow_mode, OverflowMode::Error));
+    }
 }
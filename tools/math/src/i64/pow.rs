@@ -0,0 +1,139 @@
+//! # `xyz.taluslabs.math.i64.pow@1`
+//!
+//! Standard Nexus Tool that raises an [`i64`] number to a [`u32`] power and
+//! returns the result.
+
+use {
+    super::OverflowMode,
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    base: i64,
+    exponent: u32,
+    /// What to do when the result overflows, defaults to an error.
+    #[serde(default)]
+    overflow_mode: OverflowMode,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok { result: i64 },
+    Err { reason: String },
+}
+
+pub(crate) struct I64Pow;
+
+impl NexusTool for I64Pow {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.math.i64.pow@1")
+    }
+
+    fn path() -> &'static str {
+        "/i64/pow"
+    }
+
+    fn description() -> &'static str {
+        "Raises an i64 number to a u32 power and returns the result."
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        // This tool has no external dependencies and as such, it is always
+        // healthy if the endpoint is reachable.
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(
+        &self,
+        Self::Input {
+            base,
+            exponent,
+            overflow_mode,
+        }: Self::Input,
+    ) -> Self::Output {
+        match overflow_mode {
+            OverflowMode::Wrap => Output::Ok {
+                result: base.wrapping_pow(exponent),
+            },
+            OverflowMode::Saturate => Output::Ok {
+                result: base.saturating_pow(exponent),
+            },
+            OverflowMode::Error => match base.checked_pow(exponent) {
+                Some(result) => Output::Ok { result },
+                None => Output::Err {
+                    reason: format!(
+                        "Raising '{base}' to the power of '{exponent}' results in an overflow"
+                    ),
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn pow(base: i64, exponent: u32, overflow_mode: OverflowMode) -> Output {
+        I64Pow::new()
+            .await
+            .invoke(Input {
+                base,
+                exponent,
+                overflow_mode,
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_i64_pow() {
+        for (base, exponent, expected) in [
+            (2, 10, 1024),
+            (-3, 3, -27),
+            (-3, 2, 9),
+            (5, 0, 1),
+            (0, 0, 1),
+            (2, 62, 1 << 62),
+            (-2, 63, i64::MIN),
+        ] {
+            match pow(base, exponent, OverflowMode::Error).await {
+                Output::Ok { result } => assert_eq!(result, expected, "{base}^{exponent}"),
+                output => panic!("Expected Ok variant, got {output:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_i64_pow_overflow() {
+        match pow(2, 63, OverflowMode::Error).await {
+            Output::Err { reason } => assert!(reason.contains("overflow")),
+            output => panic!("Expected Err variant, got {output:?}"),
+        }
+
+        assert!(matches!(
+            pow(2, 63, OverflowMode::Wrap).await,
+            Output::Ok { result: i64::MIN }
+        ));
+        assert!(matches!(
+            pow(2, 63, OverflowMode::Saturate).await,
+            Output::Ok { result: i64::MAX }
+        ));
+        assert!(matches!(
+            pow(-3, 41, OverflowMode::Saturate).await,
+            Output::Ok { result: i64::MIN }
+        ));
+    }
+}
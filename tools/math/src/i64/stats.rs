@@ -0,0 +1,189 @@
+//! # `xyz.taluslabs.math.i64.stats@1`
+//!
+//! Standard Nexus Tool that summarises an array of [`i64`] numbers with their
+//! mean, median, standard deviation, minimum and maximum.
+
+use {
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    values: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok {
+        mean: f64,
+        median: f64,
+        std_dev: f64,
+        min: i64,
+        max: i64,
+        count: usize,
+    },
+    Err {
+        reason: String,
+    },
+}
+
+pub(crate) struct I64Stats;
+
+impl NexusTool for I64Stats {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.math.i64.stats@1")
+    }
+
+    fn path() -> &'static str {
+        "/i64/stats"
+    }
+
+    fn description() -> &'static str {
+        "Summarise an array of i64 numbers with their mean, median, standard deviation, minimum and maximum."
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        // This tool has no external dependencies and as such, it is always
+        // healthy if the endpoint is reachable.
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, Self::Input { mut values }: Self::Input) -> Self::Output {
+        if values.is_empty() {
+            return Output::Err {
+                reason: "Cannot compute statistics of an empty array".to_string(),
+            };
+        }
+
+        values.sort_unstable();
+
+        let count = values.len();
+        let mid = count / 2;
+
+        // Summing as i128 cannot overflow for any array that fits into memory.
+        let mean = (values.iter().map(|&x| x as i128).sum::<i128>() as f64) / count as f64;
+
+        let median = match count % 2 {
+            0 => (values[mid - 1] as f64 + values[mid] as f64) / 2.0,
+            _ => values[mid] as f64,
+        };
+
+        // Population standard deviation.
+        let variance = values
+            .iter()
+            .map(|&x| (x as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        Output::Ok {
+            mean,
+            median,
+            std_dev: variance.sqrt(),
+            min: values[0],
+            max: values[count - 1],
+            count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn stats(values: Vec<i64>) -> Output {
+        I64Stats::new().await.invoke(Input { values }).await
+    }
+
+    #[tokio::test]
+    async fn test_stats_odd_length() {
+        match stats(vec![9, 2, 4, 4, 5, 5, 7]).await {
+            Output::Ok {
+                mean,
+                median,
+                std_dev,
+                min,
+                max,
+                count,
+            } => {
+                assert_eq!(mean, 36.0 / 7.0);
+                assert_eq!(median, 5.0);
+                assert!((std_dev - 2.0996).abs() < 1e-4);
+                assert_eq!(min, 2);
+                assert_eq!(max, 9);
+                assert_eq!(count, 7);
+            }
+            output => panic!("Expected Ok variant, got {output:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stats_even_length() {
+        match stats(vec![2, 4, 4, 4, 5, 5, 7, 9]).await {
+            Output::Ok {
+                mean,
+                median,
+                std_dev,
+                count,
+                ..
+            } => {
+                assert_eq!(mean, 5.0);
+                assert_eq!(median, 4.5);
+                assert_eq!(std_dev, 2.0);
+                assert_eq!(count, 8);
+            }
+            output => panic!("Expected Ok variant, got {output:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stats_single_element() {
+        match stats(vec![-3]).await {
+            Output::Ok {
+                mean,
+                median,
+                std_dev,
+                min,
+                max,
+                count,
+            } => {
+                assert_eq!(mean, -3.0);
+                assert_eq!(median, -3.0);
+                assert_eq!(std_dev, 0.0);
+                assert_eq!((min, max, count), (-3, -3, 1));
+            }
+            output => panic!("Expected Ok variant, got {output:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stats_large_numbers() {
+        // Summing these as i64 would overflow.
+        match stats(vec![i64::MAX, i64::MAX]).await {
+            Output::Ok { mean, median, .. } => {
+                assert_eq!(mean, i64::MAX as f64);
+                assert_eq!(median, i64::MAX as f64);
+            }
+            output => panic!("Expected Ok variant, got {output:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stats_empty() {
+        match stats(vec![]).await {
+            Output::Err { reason } => assert!(reason.contains("empty")),
+            output => panic!("Expected Err variant, got {output:?}"),
+        }
+    }
+}
@@ -2,6 +2,8 @@
 
 use nexus_toolkit::bootstrap;
 
+mod base_convert;
+mod f64;
 mod i64;
 
 #[tokio::main]
@@ -11,5 +13,14 @@ async fn main() {
         i64::mul::I64Mul,
         i64::cmp::I64Cmp,
         i64::sum::I64Sum,
+        i64::stats::I64Stats,
+        i64::modulo::I64Modulo,
+        i64::pow::I64Pow,
+        f64::add::F64Add,
+        f64::mul::F64Mul,
+        f64::div::F64Div,
+        f64::cmp::F64Cmp,
+        f64::pow::F64Pow,
+        base_convert::BaseConvert,
     ])
 }
@@ -0,0 +1,493 @@
+//! # `xyz.taluslabs.exchanges.aggregator.get-aggregated-price@1`
+//!
+//! Standard Nexus Tool that fetches the price of a trading pair from multiple
+//! exchanges concurrently and aggregates them into a single price.
+
+use {
+    crate::sources::{fetch_price, ExchangeKind, Pair, SourceUrls},
+    futures_util::future::join_all,
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    reqwest::Client,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+    std::collections::HashSet,
+};
+
+/// How to combine the prices of the exchanges
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AggregationMethod {
+    /// The middle price, or the mean of the two middle prices
+    Median,
+    /// The average price
+    Mean,
+    /// The lowest price
+    Min,
+    /// The highest price
+    Max,
+}
+
+impl AggregationMethod {
+    /// Aggregates the given prices, which must not be empty.
+    fn apply(&self, prices: &[f64]) -> f64 {
+        let mut prices = prices.to_vec();
+        prices.sort_by(f64::total_cmp);
+
+        match self {
+            AggregationMethod::Median => {
+                let middle = prices.len() / 2;
+
+                if prices.len() % 2 == 0 {
+                    (prices[middle - 1] + prices[middle]) / 2.0
+                } else {
+                    prices[middle]
+                }
+            }
+            AggregationMethod::Mean => prices.iter().sum::<f64>() / prices.len() as f64,
+            AggregationMethod::Min => prices[0],
+            AggregationMethod::Max => prices[prices.len() - 1],
+        }
+    }
+}
+
+/// An exchange to fetch the price from
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ExchangeSource {
+    /// The exchange
+    name: ExchangeKind,
+    /// Optional API key for the exchange
+    // TODO: <https://github.com/Talus-Network/nexus-sdk/issues/29>.
+    #[serde(default)]
+    api_key: Option<Secret<String>>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// Trading pair to get the price for (e.g., "BTC-USD")
+    symbol: String,
+    /// The exchanges to fetch the price from, each at most once
+    exchanges: Vec<ExchangeSource>,
+    /// How to combine the prices of the exchanges
+    aggregation: AggregationMethod,
+}
+
+/// The price reported by an exchange
+#[derive(Debug, PartialEq, Serialize, JsonSchema)]
+pub(crate) struct SourcePrice {
+    /// The exchange
+    exchange: ExchangeKind,
+    /// The latest price on the exchange
+    price: f64,
+}
+
+/// An exchange that the price could not be fetched from
+#[derive(Debug, PartialEq, Serialize, JsonSchema)]
+pub(crate) struct SourceError {
+    /// The exchange
+    exchange: ExchangeKind,
+    /// Detailed error message
+    reason: String,
+    /// HTTP status code if available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status_code: Option<u16>,
+}
+
+/// Types of errors that can occur during aggregation
+#[derive(Debug, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AggregatorErrorKind {
+    /// The input is invalid
+    InvalidRequest,
+    /// No exchange returned a price
+    AllSourcesFailed,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok {
+        /// The aggregated price
+        aggregated_price: f64,
+        /// The prices the aggregated price is based on, in the order of the
+        /// input exchanges
+        sources: Vec<SourcePrice>,
+        /// The exchanges that the price could not be fetched from
+        failed: Vec<SourceError>,
+    },
+    Err {
+        /// Detailed error message
+        reason: String,
+        /// Type of error
+        kind: AggregatorErrorKind,
+        /// The exchanges that the price could not be fetched from
+        failed: Vec<SourceError>,
+    },
+}
+
+pub(crate) struct GetAggregatedPrice {
+    client: Client,
+    urls: SourceUrls,
+}
+
+impl NexusTool for GetAggregatedPrice {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        let client = Client::builder()
+            .user_agent("nexus-sdk-price-aggregator-tool/1.0")
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self {
+            client,
+            urls: SourceUrls::default(),
+        }
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.exchanges.aggregator.get-aggregated-price@1")
+    }
+
+    fn path() -> &'static str {
+        "/get-aggregated-price"
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, request: Self::Input) -> Self::Output {
+        let pair = match validate(&request) {
+            Ok(pair) => pair,
+            Err(reason) => {
+                return Output::Err {
+                    reason,
+                    kind: AggregatorErrorKind::InvalidRequest,
+                    failed: vec![],
+                }
+            }
+        };
+
+        let fetches = request.exchanges.iter().map(|source| {
+            let api_key = source.api_key.as_ref().map(|api_key| api_key.as_str());
+
+            fetch_price(&self.client, &self.urls, source.name, api_key, &pair)
+        });
+
+        let mut sources = vec![];
+        let mut failed = vec![];
+
+        // Results are returned in the order of the exchanges.
+        for (source, result) in request.exchanges.iter().zip(join_all(fetches).await) {
+            match result {
+                Ok(price) => sources.push(SourcePrice {
+                    exchange: source.name,
+                    price,
+                }),
+                Err(e) => failed.push(SourceError {
+                    exchange: source.name,
+                    reason: e.reason,
+                    status_code: e.status_code,
+                }),
+            }
+        }
+
+        if sources.is_empty() {
+            return Output::Err {
+                reason: "No exchange returned a price".to_string(),
+                kind: AggregatorErrorKind::AllSourcesFailed,
+                failed,
+            };
+        }
+
+        let prices = sources
+            .iter()
+            .map(|source| source.price)
+            .collect::<Vec<_>>();
+
+        Output::Ok {
+            aggregated_price: request.aggregation.apply(&prices),
+            sources,
+            failed,
+        }
+    }
+}
+
+/// Checks that at least one exchange is given, none of them twice, and parses
+/// the symbol.
+fn validate(request: &Input) -> Result<Pair, String> {
+    if request.exchanges.is_empty() {
+        return Err("At least one exchange must be given".to_string());
+    }
+
+    let mut seen = HashSet::new();
+
+    if let Some(duplicate) = request
+        .exchanges
+        .iter()
+        .find(|source| !seen.insert(source.name))
+    {
+        return Err(format!(
+            "Exchange '{}' is given more than once",
+            format!("{:?}", duplicate.name).to_lowercase()
+        ));
+    }
+
+    Pair::parse(&request.symbol)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        mockito::{Matcher, Server},
+        serde_json::json,
+    };
+
+    async fn create_server_and_tool() -> (mockito::ServerGuard, GetAggregatedPrice) {
+        let server = Server::new_async().await;
+        let tool = GetAggregatedPrice {
+            client: Client::new(),
+            urls: SourceUrls {
+                coinbase: server.url(),
+                binance: server.url(),
+                kraken: server.url(),
+            },
+        };
+        (server, tool)
+    }
+
+    fn create_input(exchanges: serde_json::Value, aggregation: &str) -> Input {
+        serde_json::from_value(json!({
+            "symbol": "btc-usd",
+            "exchanges": exchanges,
+            "aggregation": aggregation,
+        }))
+        .expect("Failed to deserialize test input")
+    }
+
+    #[test]
+    fn test_aggregation_methods() {
+        let prices = [102.0, 100.0, 110.0, 104.0];
+
+        assert_eq!(AggregationMethod::Median.apply(&prices), 103.0);
+        assert_eq!(AggregationMethod::Median.apply(&prices[..3]), 102.0);
+        assert_eq!(AggregationMethod::Mean.apply(&prices), 104.0);
+        assert_eq!(AggregationMethod::Min.apply(&prices), 100.0);
+        assert_eq!(AggregationMethod::Max.apply(&prices), 110.0);
+        assert_eq!(AggregationMethod::Median.apply(&[42.0]), 42.0);
+    }
+
+    #[tokio::test]
+    async fn test_aggregated_price() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let coinbase = server
+            .mock("GET", "/v2/prices/BTC-USD/spot")
+            .match_header("cb-access-key", Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "data": { "amount": "100.5", "base": "BTC", "currency": "USD" } })
+                    .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let binance = server
+            .mock("GET", "/api/v3/ticker/price")
+            .match_query(Matcher::UrlEncoded("symbol".into(), "BTCUSDT".into()))
+            .match_header("x-mbx-apikey", "binance_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "symbol": "BTCUSD", "price": "110.00000000" }).to_string())
+            .create_async()
+            .await;
+
+        let kraken = server
+            .mock("GET", "/0/public/Ticker")
+            .match_query(Matcher::UrlEncoded("pair".into(), "XBTUSD".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "error": [],
+                    "result": { "XXBTZUSD": { "a": ["101.1", "1", "1.000"], "c": ["101.5", "0.01"] } }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let input = create_input(
+            json!([
+                { "name": "coinbase", "api_key": "best-encryption-ever-\"coinbase_key\"" },
+                { "name": "binance", "api_key": "best-encryption-ever-\"binance_key\"" },
+                { "name": "kraken" }
+            ]),
+            "median",
+        );
+
+        match tool.invoke(input).await {
+            Output::Ok {
+                aggregated_price,
+                sources,
+                failed,
+            } => {
+                assert_eq!(aggregated_price, 101.5);
+                assert_eq!(
+                    sources,
+                    vec![
+                        SourcePrice {
+                            exchange: ExchangeKind::Coinbase,
+                            price: 100.5,
+                        },
+                        SourcePrice {
+                            exchange: ExchangeKind::Binance,
+                            price: 110.0,
+                        },
+                        SourcePrice {
+                            exchange: ExchangeKind::Kraken,
+                            price: 101.5,
+                        },
+                    ]
+                );
+                assert!(failed.is_empty());
+            }
+            output => panic!("Expected Ok variant, got {output:?}"),
+        }
+
+        coinbase.assert_async().await;
+        binance.assert_async().await;
+        kraken.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_failed_sources() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let coinbase = server
+            .mock("GET", "/v2/prices/BTC-USD/spot")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "amount": "100.0" } }).to_string())
+            .create_async()
+            .await;
+
+        let binance = server
+            .mock("GET", "/api/v3/ticker/price")
+            .match_query(Matcher::Any)
+            .with_status(429)
+            .with_body("Too many requests")
+            .expect(2)
+            .create_async()
+            .await;
+
+        let kraken = server
+            .mock("GET", "/0/public/Ticker")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "error": ["EQuery:Unknown asset pair"] }).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+
+        // The price is aggregated from the exchanges that returned one.
+        let input = create_input(
+            json!([{ "name": "binance" }, { "name": "coinbase" }, { "name": "kraken" }]),
+            "max",
+        );
+
+        match tool.invoke(input).await {
+            Output::Ok {
+                aggregated_price,
+                sources,
+                failed,
+            } => {
+                assert_eq!(aggregated_price, 100.0);
+                assert_eq!(sources.len(), 1);
+                assert_eq!(
+                    failed,
+                    vec![
+                        SourceError {
+                            exchange: ExchangeKind::Binance,
+                            reason: "API error (429 Too Many Requests): Too many requests"
+                                .to_string(),
+                            status_code: Some(429),
+                        },
+                        SourceError {
+                            exchange: ExchangeKind::Kraken,
+                            reason: "API error: EQuery:Unknown asset pair".to_string(),
+                            status_code: None,
+                        },
+                    ]
+                );
+            }
+            output => panic!("Expected Ok variant, got {output:?}"),
+        }
+
+        // Without any price, the aggregation fails.
+        let input = create_input(json!([{ "name": "binance" }, { "name": "kraken" }]), "mean");
+
+        match tool.invoke(input).await {
+            Output::Err { kind, failed, .. } => {
+                assert_eq!(kind, AggregatorErrorKind::AllSourcesFailed);
+                assert_eq!(failed.len(), 2);
+            }
+            output => panic!("Expected Err variant, got {output:?}"),
+        }
+
+        coinbase.assert_async().await;
+        binance.assert_async().await;
+        kraken.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_invalid_input() {
+        let (_, tool) = create_server_and_tool().await;
+
+        let cases = [
+            (create_input(json!([]), "median"), "At least one exchange"),
+            (
+                create_input(
+                    json!([{ "name": "kraken" }, { "name": "kraken" }]),
+                    "median",
+                ),
+                "Exchange 'kraken' is given more than once",
+            ),
+        ];
+
+        for (input, expected) in cases {
+            match tool.invoke(input).await {
+                Output::Err { reason, kind, .. } => {
+                    assert!(reason.starts_with(expected), "{reason}");
+                    assert_eq!(kind, AggregatorErrorKind::InvalidRequest);
+                }
+                output => panic!("Expected Err variant, got {output:?}"),
+            }
+        }
+
+        let mut input = create_input(json!([{ "name": "coinbase" }]), "min");
+        input.symbol = "BTCUSD".to_string();
+
+        assert!(matches!(
+            tool.invoke(input).await,
+            Output::Err {
+                kind: AggregatorErrorKind::InvalidRequest,
+                ..
+            }
+        ));
+
+        assert!(serde_json::from_value::<Input>(json!({
+            "symbol": "BTC-USD",
+            "exchanges": [{ "name": "ftx" }],
+            "aggregation": "median",
+        }))
+        .is_err());
+    }
+}
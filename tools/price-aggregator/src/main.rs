@@ -0,0 +1,11 @@
+#![doc = include_str!("../README.md")]
+
+use nexus_toolkit::bootstrap;
+
+mod aggregator;
+mod sources;
+
+#[tokio::main]
+async fn main() {
+    bootstrap!([aggregator::GetAggregatedPrice]);
+}
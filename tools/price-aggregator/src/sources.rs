@@ -0,0 +1,239 @@
+//! Clients for the public price endpoints of the supported exchanges.
+
+use {
+    reqwest::{Client, RequestBuilder},
+    schemars::JsonSchema,
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
+    serde_json::Value,
+};
+
+pub(crate) const COINBASE_API_BASE: &str = "https://api.coinbase.com";
+pub(crate) const BINANCE_API_BASE: &str = "https://api.binance.com";
+pub(crate) const KRAKEN_API_BASE: &str = "https://api.kraken.com";
+
+/// Exchanges that prices can be fetched from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ExchangeKind {
+    Coinbase,
+    Binance,
+    Kraken,
+}
+
+/// Base URLs of the exchange APIs
+#[derive(Clone, Debug)]
+pub(crate) struct SourceUrls {
+    pub(crate) coinbase: String,
+    pub(crate) binance: String,
+    pub(crate) kraken: String,
+}
+
+impl Default for SourceUrls {
+    fn default() -> Self {
+        Self {
+            coinbase: COINBASE_API_BASE.to_string(),
+            binance: BINANCE_API_BASE.to_string(),
+            kraken: KRAKEN_API_BASE.to_string(),
+        }
+    }
+}
+
+/// A trading pair such as BTC-USD
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Pair {
+    pub(crate) base: String,
+    pub(crate) quote: String,
+}
+
+impl Pair {
+    /// Parses a symbol of the form `BASE-QUOTE`, e.g. `BTC-USD`.
+    pub(crate) fn parse(symbol: &str) -> Result<Self, String> {
+        let is_asset =
+            |asset: &str| !asset.is_empty() && asset.chars().all(|c| c.is_ascii_alphanumeric());
+
+        match symbol.split_once('-') {
+            Some((base, quote)) if is_asset(base) && is_asset(quote) => Ok(Self {
+                base: base.to_ascii_uppercase(),
+                quote: quote.to_ascii_uppercase(),
+            }),
+            _ => Err(format!(
+                "Symbol must be of the form 'BASE-QUOTE' (e.g., 'BTC-USD'), got '{symbol}'"
+            )),
+        }
+    }
+}
+
+/// Error fetching the price from an exchange
+#[derive(Debug)]
+pub(crate) struct FetchError {
+    pub(crate) reason: String,
+    pub(crate) status_code: Option<u16>,
+}
+
+impl FetchError {
+    fn new(reason: String) -> Self {
+        Self {
+            reason,
+            status_code: None,
+        }
+    }
+}
+
+/// Fetches the latest price of `pair` from `exchange`. The API key is sent in
+/// the header the exchange expects it in, the endpoints used do not require
+/// one. Coinbase API keys only work on signed requests so none is sent to the
+/// public Coinbase endpoint.
+pub(crate) async fn fetch_price(
+    client: &Client,
+    urls: &SourceUrls,
+    exchange: ExchangeKind,
+    api_key: Option<&str>,
+    pair: &Pair,
+) -> Result<f64, FetchError> {
+    // Binance only lists USD prices against the USDT stablecoin.
+    let binance_asset = |asset: &str| match asset {
+        "USD" => "USDT".to_string(),
+        asset => asset.to_string(),
+    };
+
+    match exchange {
+        ExchangeKind::Coinbase => {
+            #[derive(Deserialize)]
+            struct Response {
+                data: SpotPrice,
+            }
+
+            #[derive(Deserialize)]
+            struct SpotPrice {
+                amount: String,
+            }
+
+            let url = format!(
+                "{}/v2/prices/{}-{}/spot",
+                urls.coinbase, pair.base, pair.quote
+            );
+            let response = get_json::<Response>(client.get(url)).await?;
+
+            parse_price(&response.data.amount)
+        }
+        ExchangeKind::Binance => {
+            #[derive(Deserialize)]
+            struct Response {
+                price: String,
+            }
+
+            let url = format!("{}/api/v3/ticker/price", urls.binance);
+            let symbol = format!(
+                "{}{}",
+                binance_asset(&pair.base),
+                binance_asset(&pair.quote)
+            );
+            let request = with_api_key(
+                client.get(url).query(&[("symbol", symbol)]),
+                "X-MBX-APIKEY",
+                api_key,
+            );
+            let response = get_json::<Response>(request).await?;
+
+            parse_price(&response.price)
+        }
+        ExchangeKind::Kraken => {
+            #[derive(Deserialize)]
+            struct Response {
+                error: Vec<String>,
+                #[serde(default)]
+                result: serde_json::Map<String, Value>,
+            }
+
+            // Kraken calls bitcoin XBT.
+            let kraken_asset = |asset: &str| match asset {
+                "BTC" => "XBT".to_string(),
+                asset => asset.to_string(),
+            };
+
+            let url = format!("{}/0/public/Ticker", urls.kraken);
+            let pair = format!("{}{}", kraken_asset(&pair.base), kraken_asset(&pair.quote));
+            let request =
+                with_api_key(client.get(url).query(&[("pair", pair)]), "API-Key", api_key);
+            let response = get_json::<Response>(request).await?;
+
+            if !response.error.is_empty() {
+                return Err(FetchError::new(format!(
+                    "API error: {}",
+                    response.error.join(", ")
+                )));
+            }
+
+            // The result is keyed by Kraken's name of the pair, which differs
+            // from the requested one. `c` holds the last trade price and volume.
+            let price = response
+                .result
+                .values()
+                .next()
+                .and_then(|ticker| ticker["c"][0].as_str())
+                .ok_or_else(|| {
+                    FetchError::new("Missing last trade price in response".to_string())
+                })?;
+
+            parse_price(price)
+        }
+    }
+}
+
+fn with_api_key(request: RequestBuilder, header: &str, api_key: Option<&str>) -> RequestBuilder {
+    match api_key {
+        Some(api_key) => request.header(header, api_key),
+        None => request,
+    }
+}
+
+async fn get_json<T: DeserializeOwned>(request: RequestBuilder) -> Result<T, FetchError> {
+    let response = request
+        .send()
+        .await
+        .map_err(|e| FetchError::new(format!("Network error: {e}")))?;
+
+    let status = response.status();
+    let text = response
+        .text()
+        .await
+        .map_err(|e| FetchError::new(format!("Failed to read response: {e}")))?;
+
+    if !status.is_success() {
+        return Err(FetchError {
+            reason: format!("API error ({status}): {text}"),
+            status_code: Some(status.as_u16()),
+        });
+    }
+
+    serde_json::from_str(&text).map_err(|e| FetchError::new(format!("Failed to parse JSON: {e}")))
+}
+
+fn parse_price(price: &str) -> Result<f64, FetchError> {
+    match price.parse::<f64>() {
+        Ok(price) if price.is_finite() && price > 0.0 => Ok(price),
+        _ => Err(FetchError::new(format!(
+            "Invalid price in response: '{price}'"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pair() {
+        assert_eq!(
+            Pair::parse("btc-usd").unwrap(),
+            Pair {
+                base: "BTC".to_string(),
+                quote: "USD".to_string(),
+            }
+        );
+
+        for invalid in ["", "BTC", "BTC-", "-USD", "BTC-USD-EUR", "BTC/USD"] {
+            assert!(Pair::parse(invalid).is_err(), "{invalid}");
+        }
+    }
+}
@@ -1,3 +1,5 @@
+pub(crate) mod refresh_token;
+
 use {
     oauth1_request::{delete, get, post, put, signature_method::HmacSha1, Token},
     schemars::JsonSchema,
@@ -0,0 +1,351 @@
+//! # `xyz.taluslabs.social.twitter.refresh-token@1`
+//!
+//! Standard Nexus Tool that exchanges an OAuth2 refresh token for a new access
+//! token. Access tokens expire after two hours and Twitter rotates the refresh
+//! token on every use, so the returned refresh token replaces the old one.
+
+use {
+    crate::{
+        error::{TwitterError, TwitterErrorKind, TwitterErrorResponse},
+        twitter_client::{api_base_from_env, RefreshFuture, TokenRefresher, TWITTER_API_BASE},
+    },
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    reqwest::Client,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+    std::sync::{Arc, Mutex},
+};
+
+/// Path of the OAuth2 token endpoint relative to the API base.
+const OAUTH2_TOKEN_ENDPOINT: &str = "oauth2/token";
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// The OAuth2 refresh token
+    refresh_token: Secret<String>,
+    /// The client ID of the Twitter app the token was issued to
+    client_id: String,
+}
+
+#[derive(Serialize, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok {
+        /// The new OAuth2 access token
+        access_token: Secret<String>,
+        /// The new refresh token, the old one can no longer be used
+        refresh_token: Secret<String>,
+        /// Seconds until the access token expires
+        expires_in: u64,
+    },
+    Err {
+        /// Detailed error message
+        reason: String,
+        /// Type of error (network, server, auth, etc.)
+        kind: TwitterErrorKind,
+        /// HTTP status code if available
+        #[serde(skip_serializing_if = "Option::is_none")]
+        status_code: Option<u16>,
+    },
+}
+
+/// Successful response of the OAuth2 token endpoint.
+#[derive(Deserialize, Debug)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: String,
+    expires_in: u64,
+}
+
+/// Error response of the OAuth2 token endpoint.
+#[derive(Deserialize, Debug)]
+struct OAuth2ErrorResponse {
+    error: String,
+    error_description: Option<String>,
+}
+
+pub(crate) struct RefreshToken {
+    api_base: String,
+}
+
+impl NexusTool for RefreshToken {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self {
+            api_base: api_base_from_env(TWITTER_API_BASE),
+        }
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.social.twitter.refresh-token@1")
+    }
+
+    fn path() -> &'static str {
+        "/refresh-token"
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, request: Self::Input) -> Self::Output {
+        let token_url = format!("{}/{}", self.api_base, OAUTH2_TOKEN_ENDPOINT);
+
+        match refresh_access_token(
+            &Client::new(),
+            &token_url,
+            &request.client_id,
+            &request.refresh_token,
+        )
+        .await
+        {
+            Ok(token) => Output::Ok {
+                access_token: Secret::new(token.access_token),
+                refresh_token: Secret::new(token.refresh_token),
+                expires_in: token.expires_in,
+            },
+            Err(e) => Output::Err {
+                reason: e.reason,
+                kind: e.kind,
+                status_code: e.status_code,
+            },
+        }
+    }
+}
+
+/// Exchange the refresh token for a new access and refresh token.
+async fn refresh_access_token(
+    client: &Client,
+    token_url: &str,
+    client_id: &str,
+    refresh_token: &str,
+) -> Result<TokenResponse, TwitterErrorResponse> {
+    let response = client
+        .post(token_url)
+        .form(&[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+            ("client_id", client_id),
+        ])
+        .send()
+        .await
+        .map_err(|e| TwitterError::Network(e).to_error_response())?;
+
+    let status = response.status();
+    let text = response
+        .text()
+        .await
+        .map_err(|e| TwitterError::Network(e).to_error_response())?;
+
+    if !status.is_success() {
+        let reason = match serde_json::from_str::<OAuth2ErrorResponse>(&text) {
+            Ok(e) => format!(
+                "Failed to refresh token: {}",
+                e.error_description.unwrap_or(e.error)
+            ),
+            Err(_) => format!("Failed to refresh token: {status}"),
+        };
+
+        // The token endpoint answers invalid or revoked tokens with a 400.
+        let kind = match status {
+            reqwest::StatusCode::BAD_REQUEST | reqwest::StatusCode::UNAUTHORIZED => {
+                TwitterErrorKind::Auth
+            }
+            _ => TwitterError::StatusError(status).to_error_response().kind,
+        };
+
+        return Err(TwitterErrorResponse {
+            reason,
+            kind,
+            status_code: Some(status.as_u16()),
+        });
+    }
+
+    serde_json::from_str(&text).map_err(|e| TwitterError::ParseError(e).to_error_response())
+}
+
+/// Create a [`TokenRefresher`] for [`crate::twitter_client::TwitterClient`]
+/// that keeps track of the rotated refresh token between refreshes.
+#[allow(dead_code)]
+pub(crate) fn token_refresher(
+    api_base: &str,
+    client_id: impl Into<String>,
+    refresh_token: impl Into<String>,
+) -> TokenRefresher {
+    let token_url = format!("{}/{}", api_base, OAUTH2_TOKEN_ENDPOINT);
+    let client_id = client_id.into();
+    let refresh_token = Arc::new(Mutex::new(refresh_token.into()));
+    let client = Client::new();
+
+    Arc::new(move || -> RefreshFuture {
+        let token_url = token_url.clone();
+        let client_id = client_id.clone();
+        let refresh_token = refresh_token.clone();
+        let client = client.clone();
+
+        Box::pin(async move {
+            let current = refresh_token
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .clone();
+
+            let token = refresh_access_token(&client, &token_url, &client_id, &current).await?;
+
+            *refresh_token
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()) = token.refresh_token;
+
+            Ok(token.access_token)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        ::{
+            mockito::{Matcher, Server},
+            serde_json::json,
+        },
+    };
+
+    fn create_test_input() -> Input {
+        serde_json::from_value(json!({
+            "refresh_token": "best-encryption-ever-\"old_refresh_token\"",
+            "client_id": "test_client_id",
+        }))
+        .unwrap()
+    }
+
+    fn token_body_matcher(refresh_token: &str) -> Matcher {
+        Matcher::AllOf(vec![
+            Matcher::UrlEncoded("grant_type".into(), "refresh_token".into()),
+            Matcher::UrlEncoded("refresh_token".into(), refresh_token.into()),
+            Matcher::UrlEncoded("client_id".into(), "test_client_id".into()),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_successful() {
+        let mut server = Server::new_async().await;
+        let tool = RefreshToken {
+            api_base: server.url(),
+        };
+
+        let mock = server
+            .mock("POST", "/oauth2/token")
+            .match_header("content-type", "application/x-www-form-urlencoded")
+            .match_body(token_body_matcher("old_refresh_token"))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "token_type": "bearer",
+                    "expires_in": 7200,
+                    "access_token": "new_access_token",
+                    "scope": "tweet.read users.read offline.access",
+                    "refresh_token": "new_refresh_token"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        match tool.invoke(create_test_input()).await {
+            Output::Ok {
+                access_token,
+                refresh_token,
+                expires_in,
+            } => {
+                assert_eq!(*access_token, "new_access_token");
+                assert_eq!(*refresh_token, "new_refresh_token");
+                assert_eq!(expires_in, 7200);
+            }
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_invalid() {
+        let mut server = Server::new_async().await;
+        let tool = RefreshToken {
+            api_base: server.url(),
+        };
+
+        let mock = server
+            .mock("POST", "/oauth2/token")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "error": "invalid_request",
+                    "error_description": "Value passed for the token was invalid."
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        match tool.invoke(create_test_input()).await {
+            Output::Ok { .. } => panic!("Expected error, got success"),
+            Output::Err {
+                reason,
+                kind,
+                status_code,
+            } => {
+                assert_eq!(
+                    reason,
+                    "Failed to refresh token: Value passed for the token was invalid."
+                );
+                assert_eq!(kind, TwitterErrorKind::Auth);
+                assert_eq!(status_code, Some(400));
+            }
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_token_refresher_rotates_refresh_token() {
+        let mut server = Server::new_async().await;
+
+        let mut mocks = vec![];
+
+        for (old, new) in [("first", "second"), ("second", "third")] {
+            mocks.push(
+                server
+                    .mock("POST", "/oauth2/token")
+                    .match_body(token_body_matcher(old))
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(
+                        json!({
+                            "expires_in": 7200,
+                            "access_token": format!("access_{new}"),
+                            "refresh_token": new
+                        })
+                        .to_string(),
+                    )
+                    .expect(1)
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let refresher = token_refresher(&server.url(), "test_client_id", "first");
+
+        assert_eq!(refresher().await.unwrap(), "access_second");
+        assert_eq!(refresher().await.unwrap(), "access_third");
+
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+    }
+}
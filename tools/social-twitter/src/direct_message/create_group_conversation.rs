@@ -7,7 +7,7 @@ use {
         auth::TwitterAuth,
         direct_message::models::{ConversationType, DmConversationResponse, Message},
         error::TwitterErrorKind,
-        twitter_client::{TwitterClient, TWITTER_API_BASE},
+        twitter_client::{api_base_from_env, TwitterClient, TWITTER_API_BASE},
     },
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
@@ -61,7 +61,7 @@ impl NexusTool for CreateGroupDmConversation {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string(),
+            api_base: api_base_from_env(TWITTER_API_BASE),
         }
     }
 
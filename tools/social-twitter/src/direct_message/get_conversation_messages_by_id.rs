@@ -16,7 +16,7 @@ use {
             UserField,
         },
         error::TwitterErrorKind,
-        twitter_client::{TwitterClient, TWITTER_API_BASE},
+        twitter_client::{api_base_from_env, TwitterClient, TWITTER_API_BASE},
     },
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
@@ -161,7 +161,7 @@ impl NexusTool for GetConversationMessagesById {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string(),
+            api_base: api_base_from_env(TWITTER_API_BASE),
         }
     }
 
@@ -7,7 +7,7 @@ use {
     crate::{
         auth::TwitterAuth,
         error::TwitterErrorKind,
-        twitter_client::{TwitterClient, TWITTER_API_BASE},
+        twitter_client::{api_base_from_env, TwitterClient, TWITTER_API_BASE},
     },
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
@@ -27,6 +27,24 @@ pub(crate) struct Input {
     text: Option<String>,
     /// Attachments to a DM Event.
     attachments: Option<Vec<Attachment>>,
+    /// ID of a media item uploaded with `upload-media` to attach to the DM.
+    media_id: Option<String>,
+}
+
+impl Input {
+    /// IDs of all media to attach, from both `media_id` and `attachments`.
+    fn media_ids(&self) -> Vec<&str> {
+        self.media_id
+            .iter()
+            .map(String::as_str)
+            .chain(
+                self.attachments
+                    .iter()
+                    .flatten()
+                    .map(|attachment| attachment.media_id.as_str()),
+            )
+            .collect()
+    }
 }
 
 #[derive(Serialize, JsonSchema)]
@@ -60,7 +78,7 @@ impl NexusTool for SendDirectMessage {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string(),
+            api_base: api_base_from_env(TWITTER_API_BASE),
         }
     }
 
@@ -77,6 +95,16 @@ impl NexusTool for SendDirectMessage {
         Ok(StatusCode::OK)
     }
 
+    fn validate(&self, input: &Self::Input) -> Result<(), ToolValidationError> {
+        if input.text.as_ref().is_none_or(|t| t.is_empty()) && input.media_ids().is_empty() {
+            return Err(ToolValidationError::new(
+                "Either text or media_id must be provided and non-empty",
+            ));
+        }
+
+        Ok(())
+    }
+
     async fn invoke(&self, request: Self::Input) -> Self::Output {
         // Build the endpoint for the Twitter API
         let suffix = format!("dm_conversations/with/{}/messages", request.participant_id);
@@ -100,10 +128,12 @@ impl NexusTool for SendDirectMessage {
             request_body["text"] = json!(text);
         }
 
-        if let Some(attachments) = &request.attachments {
-            let media_attachments: Vec<serde_json::Value> = attachments
+        let media_ids = request.media_ids();
+
+        if !media_ids.is_empty() {
+            let media_attachments: Vec<serde_json::Value> = media_ids
                 .iter()
-                .map(|a| json!({ "media_id": a.media_id }))
+                .map(|media_id| json!({ "media_id": media_id }))
                 .collect();
             request_body["attachments"] = json!(media_attachments);
         }
@@ -128,7 +158,11 @@ impl NexusTool for SendDirectMessage {
 
 #[cfg(test)]
 mod tests {
-    use {super::*, ::mockito::Server, serde_json::json};
+    use {
+        super::*,
+        ::mockito::{Matcher, Server},
+        serde_json::json,
+    };
 
     impl SendDirectMessage {
         fn with_api_base(api_base: &str) -> Self {
@@ -155,6 +189,7 @@ mod tests {
             participant_id: "12345".to_string(),
             text: Some("Test message".to_string()),
             attachments: None,
+            media_id: None,
         }
     }
 
@@ -221,6 +256,7 @@ mod tests {
                     media_id: "1146654567674912771".to_string(),
                 },
             ]),
+            media_id: None,
         };
 
         let mock = server
@@ -258,6 +294,62 @@ mod tests {
         mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_send_direct_message_with_media_id() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let input = Input {
+            media_id: Some("1146654567674912769".to_string()),
+            text: None,
+            ..create_test_input()
+        };
+
+        assert!(tool.validate(&input).is_ok());
+
+        let mock = server
+            .mock("POST", "/dm_conversations/with/12345/messages")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::Json(json!({
+                "attachments": [{ "media_id": "1146654567674912769" }]
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "data": {
+                        "dm_conversation_id": "123123123-456456456",
+                        "dm_event_id": "1146654567674912770"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        match tool.invoke(input).await {
+            Output::Ok { dm_event_id, .. } => assert_eq!(dm_event_id, "1146654567674912770"),
+            Output::Err { reason, kind, .. } => {
+                panic!("Expected success, got error: {:?} - {}", kind, reason)
+            }
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_send_direct_message_without_text_or_media() {
+        let tool = SendDirectMessage::with_api_base("http://localhost");
+
+        for text in [None, Some(String::new())] {
+            let input = Input {
+                text,
+                ..create_test_input()
+            };
+
+            assert!(tool.validate(&input).is_err());
+        }
+    }
+
     #[tokio::test]
     async fn test_send_direct_message_unauthorized() {
         let (mut server, tool) = create_server_and_tool().await;
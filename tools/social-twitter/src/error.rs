@@ -1,4 +1,5 @@
 use {
+    chrono::{DateTime, Utc},
     reqwest::{Response, StatusCode},
     schemars::JsonSchema,
     serde::{Deserialize, Serialize},
@@ -64,6 +65,17 @@ pub enum TwitterError {
     #[error("Twitter API status error: {0}")]
     StatusError(StatusCode),
 
+    #[error(
+        "Rate limit exceeded (resets at {}): {detail}",
+        describe_reset(reset_at)
+    )]
+    RateLimited {
+        /// When the rate limit window resets, if known
+        reset_at: Option<DateTime<Utc>>,
+        /// Details about the rate limit from the API or the local limiter
+        detail: String,
+    },
+
     #[error("Unknown error: {0}")]
     Other(String),
 }
@@ -158,6 +170,11 @@ impl TwitterError {
                     status_code: Some(code),
                 }
             }
+            TwitterError::RateLimited { .. } => TwitterErrorResponse {
+                reason: self.to_string(),
+                kind: TwitterErrorKind::RateLimit,
+                status_code: Some(StatusCode::TOO_MANY_REQUESTS.as_u16()),
+            },
             TwitterError::Other(_) => TwitterErrorResponse {
                 reason: self.to_string(),
                 kind: TwitterErrorKind::Unknown,
@@ -167,6 +184,11 @@ impl TwitterError {
     }
 }
 
+/// Format a rate limit reset time for error messages
+fn describe_reset(reset_at: &Option<DateTime<Utc>>) -> String {
+    reset_at.map_or_else(|| "unknown".to_string(), |reset_at| reset_at.to_rfc3339())
+}
+
 /// Result type for Twitter operations
 pub type TwitterResult<T> = Result<T, TwitterError>;
 
@@ -4,7 +4,7 @@
 
 use {
     super::models::ListMemberResponse,
-    crate::{auth::TwitterAuth, tweet::TWITTER_API_BASE},
+    crate::{auth::TwitterAuth, tweet::TWITTER_API_BASE, twitter_client::api_base_from_env},
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
     reqwest::Client,
@@ -46,7 +46,7 @@ impl NexusTool for AddMember {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string() + "/lists",
+            api_base: api_base_from_env(TWITTER_API_BASE) + "/lists",
         }
     }
 
@@ -3,7 +3,7 @@
 //! Standard Nexus Tool that creates a list on Twitter.
 
 use {
-    crate::{auth::TwitterAuth, tweet::TWITTER_API_BASE},
+    crate::{auth::TwitterAuth, tweet::TWITTER_API_BASE, twitter_client::api_base_from_env},
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
     reqwest::Client,
@@ -62,7 +62,7 @@ impl NexusTool for CreateList {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string() + "/lists",
+            api_base: api_base_from_env(TWITTER_API_BASE) + "/lists",
         }
     }
 
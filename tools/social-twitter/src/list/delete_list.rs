@@ -7,7 +7,7 @@ use {
         auth::TwitterAuth,
         error::TwitterErrorKind,
         list::models::DeleteListResponse,
-        twitter_client::{TwitterClient, TWITTER_API_BASE},
+        twitter_client::{api_base_from_env, TwitterClient, TWITTER_API_BASE},
     },
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
@@ -52,7 +52,7 @@ impl NexusTool for DeleteList {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string(),
+            api_base: api_base_from_env(TWITTER_API_BASE),
         }
     }
 
@@ -7,6 +7,7 @@ use {
         error::{parse_twitter_response, TwitterErrorKind, TwitterErrorResponse, TwitterResult},
         list::models::{Expansion, Includes, ListField, ListResponse, Meta, UserField},
         tweet::TWITTER_API_BASE,
+        twitter_client::api_base_from_env,
     },
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
@@ -89,7 +90,7 @@ impl NexusTool for GetList {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string() + "/lists",
+            api_base: api_base_from_env(TWITTER_API_BASE) + "/lists",
         }
     }
 
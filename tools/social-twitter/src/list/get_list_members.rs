@@ -10,6 +10,7 @@ use {
             models::{TweetField, UserField},
             TWITTER_API_BASE,
         },
+        twitter_client::api_base_from_env,
         user::models::{UserData, UsersResponse},
     },
     nexus_sdk::{fqn, ToolFqn},
@@ -79,7 +80,7 @@ impl NexusTool for GetListMembers {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string() + "/lists",
+            api_base: api_base_from_env(TWITTER_API_BASE) + "/lists",
         }
     }
 
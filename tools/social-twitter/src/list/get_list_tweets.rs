@@ -10,6 +10,7 @@ use {
             models::{MediaField, PlaceField, PollField, TweetField, UserField},
             TWITTER_API_BASE,
         },
+        twitter_client::api_base_from_env,
     },
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
@@ -92,7 +93,7 @@ impl NexusTool for GetListTweets {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string() + "/lists",
+            api_base: api_base_from_env(TWITTER_API_BASE) + "/lists",
         }
     }
 
@@ -6,7 +6,7 @@ use {
     crate::{
         error::TwitterErrorKind,
         list::models::{Expansion, Includes, ListData, ListField, ListsResponse, Meta, UserField},
-        twitter_client::{TwitterClient, TWITTER_API_BASE},
+        twitter_client::{api_base_from_env, TwitterClient, TWITTER_API_BASE},
     },
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
@@ -80,7 +80,7 @@ impl NexusTool for GetUserLists {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string(),
+            api_base: api_base_from_env(TWITTER_API_BASE),
         }
     }
 
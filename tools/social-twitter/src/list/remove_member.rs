@@ -4,7 +4,7 @@
 
 use {
     super::models::ListMemberResponse,
-    crate::{auth::TwitterAuth, tweet::TWITTER_API_BASE},
+    crate::{auth::TwitterAuth, tweet::TWITTER_API_BASE, twitter_client::api_base_from_env},
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
     reqwest::Client,
@@ -47,7 +47,7 @@ impl NexusTool for RemoveMember {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string() + "/lists",
+            api_base: api_base_from_env(TWITTER_API_BASE) + "/lists",
         }
     }
 
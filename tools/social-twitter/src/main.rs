@@ -10,6 +10,7 @@ mod direct_message;
 mod error;
 mod list;
 mod media;
+mod rate_limiter;
 mod tweet;
 mod twitter_client;
 mod user;
@@ -18,6 +19,8 @@ mod user;
 async fn main() {
     bootstrap!([
         tweet::post_tweet::PostTweet,
+        tweet::post_thread::PostThread,
+        tweet::create_thread::CreateThread,
         tweet::delete_tweet::DeleteTweet,
         tweet::get_tweet::GetTweet,
         tweet::like_tweet::LikeTweet,
@@ -29,6 +32,8 @@ async fn main() {
         tweet::undo_retweet_tweet::UndoRetweetTweet,
         tweet::get_tweets::GetTweets,
         tweet::retweet_tweet::RetweetTweet,
+        tweet::create_poll::CreatePoll,
+        tweet::get_poll_results::GetPollResults,
         list::create_list::CreateList,
         list::delete_list::DeleteList,
         list::get_list::GetList,
@@ -50,5 +55,6 @@ async fn main() {
         user::unfollow_user::UnfollowUser,
         user::get_users_by_username::GetUsersByUsername,
         user::get_users_by_id::GetUsersById,
+        auth::refresh_token::RefreshToken,
     ]);
 }
@@ -115,6 +115,20 @@ pub struct ProcessingInfo {
     /// State of upload
     /// Available options: succeeded, in_progress, pending, failed
     pub state: ProcessingState,
+    /// Reason for the failure if `state` is `failed`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ProcessingError>,
+}
+
+/// Error reported by Twitter when media processing fails
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct ProcessingError {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
 }
 
 /// State of media upload processing
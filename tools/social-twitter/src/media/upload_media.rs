@@ -1,6 +1,9 @@
 //! # `xyz.taluslabs.social.twitter.upload-media@1`
 //!
 //! Standard Nexus Tool that uploads media to Twitter.
+//!
+//! Images up to 5MB are uploaded in a single request, larger media as well as
+//! videos and GIFs use the chunked INIT/APPEND/FINALIZE flow.
 
 use {
     super::MEDIA_UPLOAD_ENDPOINT,
@@ -13,8 +16,10 @@ use {
             MediaType,
             MediaUploadData,
             MediaUploadResponse,
+            ProcessingInfo,
+            ProcessingState,
         },
-        twitter_client::{TwitterClient, TWITTER_X_API_BASE},
+        twitter_client::{api_base_from_env, TwitterClient, TWITTER_X_API_BASE},
     },
     base64,
     nexus_sdk::{fqn, ToolFqn},
@@ -22,8 +27,28 @@ use {
     reqwest::multipart::{Form, Part},
     schemars::JsonSchema,
     serde::{Deserialize, Serialize},
+    std::time::Duration,
 };
 
+/// Twitter API's maximum chunk size is 5MB as per documentation
+/// https://developer.x.com/en/docs/x-api/v1/media/upload-media/api-reference/post-media-upload-append
+const MAX_CHUNK_SIZE: usize = 5 * 1024 * 1024;
+
+/// Default chunk size for chunked uploads
+const DEFAULT_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// Media larger than this is always uploaded in chunks
+const MAX_SINGLE_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// Maximum number of STATUS checks before giving up on media processing
+const MAX_STATUS_ATTEMPTS: u32 = 20;
+
+/// First delay between STATUS checks if Twitter doesn't suggest one
+const INITIAL_STATUS_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Upper bound for the delay between STATUS checks
+const MAX_STATUS_BACKOFF: Duration = Duration::from_secs(30);
+
 /// Input for media upload
 #[derive(Debug, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -45,25 +70,17 @@ pub(crate) struct Input {
     #[serde(default)]
     additional_owners: Vec<String>,
 
-    /// Chunk size in bytes for uploading media (default: calculated based on media size and type)
-    /// Set to 0 to use automatic calculation
-    #[serde(default = "default_chunk_size")]
-    chunk_size: usize,
+    /// Chunk size in bytes for chunked uploads (default: 4MB)
+    /// Values above 5MB are capped to 5MB
+    #[serde(default)]
+    chunk_size_bytes: Option<usize>,
 
-    /// If false, waits for media processing to complete before returning
-    /// If true, returns immediately after upload (default: true)
-    #[serde(default = "default_optimistic_upload")]
+    /// If false, waits for media processing to complete before returning (default: false)
+    /// If true, returns immediately after upload
+    #[serde(default)]
     optimistic_upload: bool,
 }
 
-fn default_chunk_size() -> usize {
-    0 // 0 means auto-calculate based on media size and type
-}
-
-fn default_optimistic_upload() -> bool {
-    true // By default, return immediately after upload
-}
-
 /// Output for media upload
 #[derive(Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -128,7 +145,7 @@ impl NexusTool for UploadMedia {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_X_API_BASE.to_string(),
+            api_base: api_base_from_env(TWITTER_X_API_BASE),
         }
     }
 
@@ -164,7 +181,7 @@ impl NexusTool for UploadMedia {
             &media_data,
             &request.media_type,
             &request.media_category,
-            request.chunk_size,
+            request.chunk_size_bytes,
             if request.additional_owners.is_empty() {
                 None
             } else {
@@ -183,26 +200,135 @@ impl NexusTool for UploadMedia {
     }
 }
 
-/// Upload media to Twitter in chunks
+/// Upload media to Twitter, in chunks if required
 async fn upload_media(
     client: &TwitterClient,
     auth: &TwitterAuth,
     media_data: &[u8],
     media_type: &MediaType,
     media_category: &MediaCategory,
-    chunk_size: usize,
+    chunk_size: Option<usize>,
     additional_owners: Option<&Vec<String>>,
     optimistic_upload: bool,
 ) -> TwitterResult<MediaUploadData> {
-    // Calculate optimal chunk size if not specified
-    let optimal_chunk_size = if chunk_size > 0 {
-        chunk_size
+    let upload_result = if requires_chunked_upload(media_data.len(), media_category) {
+        upload_media_chunked(
+            client,
+            auth,
+            media_data,
+            media_type,
+            media_category,
+            chunk_size,
+            additional_owners,
+        )
+        .await?
     } else {
-        calculate_optimal_chunk_size(media_data.len(), media_type, media_category)
+        upload_single_part(
+            client,
+            auth,
+            media_data,
+            media_type,
+            media_category,
+            additional_owners,
+        )
+        .await?
     };
 
+    let media_id = upload_result.id.clone();
+
+    // STATUS phase - Wait for processing to complete if not optimistic
+    if let Some(processing_info) = &upload_result.processing_info {
+        // Processing can already fail during the upload
+        if processing_info.state == ProcessingState::Failed {
+            return Err(processing_failed_error(&media_id, processing_info));
+        }
+
+        // Check if media requires processing and is not already completed
+        if !optimistic_upload && processing_info.state != ProcessingState::Succeeded {
+            // Wait for processing to complete
+            return wait_for_processing_completion(
+                client,
+                auth,
+                &media_id,
+                processing_info.check_after_secs,
+            )
+            .await;
+        }
+    }
+
+    Ok(upload_result)
+}
+
+/// Whether the media has to be uploaded in chunks. Twitter only accepts
+/// videos and GIFs through the chunked upload flow.
+fn requires_chunked_upload(media_size: usize, media_category: &MediaCategory) -> bool {
+    media_size > MAX_SINGLE_PART_SIZE
+        || matches!(
+            media_category,
+            MediaCategory::TweetVideo
+                | MediaCategory::DmVideo
+                | MediaCategory::AmplifyVideo
+                | MediaCategory::TweetGif
+                | MediaCategory::DmGif
+        )
+}
+
+/// Upload media in a single request
+async fn upload_single_part(
+    client: &TwitterClient,
+    auth: &TwitterAuth,
+    media_data: &[u8],
+    media_type: &MediaType,
+    media_category: &MediaCategory,
+    additional_owners: Option<&Vec<String>>,
+) -> TwitterResult<MediaUploadData> {
+    let part = Part::bytes(media_data.to_vec()).file_name("media.bin"); // Generic filename, doesn't matter
+
+    let form = Form::new()
+        .text("media_type", media_type.to_string())
+        .text("media_category", media_category.to_string())
+        .text(
+            "additional_owners",
+            additional_owners
+                .map(|owners| owners.join(","))
+                .unwrap_or_default(),
+        )
+        .part("media", part);
+
+    client
+        .post::<MediaUploadResponse, ()>(auth, None, Some(form))
+        .await
+        .map_err(|e| {
+            TwitterError::ApiError(
+                e.reason,
+                format!("{:?}", e.kind),
+                e.status_code
+                    .map(|code| code.to_string())
+                    .unwrap_or_default(),
+            )
+        })
+}
+
+/// Upload media in chunks using the INIT, APPEND and FINALIZE commands
+async fn upload_media_chunked(
+    client: &TwitterClient,
+    auth: &TwitterAuth,
+    media_data: &[u8],
+    media_type: &MediaType,
+    media_category: &MediaCategory,
+    chunk_size: Option<usize>,
+    additional_owners: Option<&Vec<String>>,
+) -> TwitterResult<MediaUploadData> {
+    let chunk_size = chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE).min(MAX_CHUNK_SIZE);
+
+    if chunk_size == 0 {
+        return Err(TwitterError::Other(
+            "Chunk size must be greater than 0".to_string(),
+        ));
+    }
+
     // Validate number of chunks doesn't exceed Twitter's API limit of 999
-    let total_chunks = media_data.len().div_ceil(optimal_chunk_size);
+    let total_chunks = media_data.len().div_ceil(chunk_size);
     if total_chunks > 999 {
         return Err(TwitterError::Other(format!(
             "Media would require {} chunks, which exceeds Twitter's limit of 999.",
@@ -224,130 +350,14 @@ async fn upload_media(
     let media_id = init_response.id.clone();
 
     // 2. APPEND phase - Upload chunks
-    let chunks = media_data.chunks(optimal_chunk_size).enumerate();
+    let chunks = media_data.chunks(chunk_size).enumerate();
 
     for (i, chunk) in chunks {
         append_chunk(client, auth, &media_id, chunk, i as i32).await?;
     }
 
     // 3. FINALIZE phase - Complete the upload
-    let mut finalize_result = finalize_upload(client, auth, &media_id).await?;
-
-    // 4. STATUS phase - Wait for processing to complete if not optimistic
-    if !optimistic_upload {
-        // Check if media requires processing and is not already completed
-        if let Some(processing_info) = &finalize_result.processing_info {
-            if processing_info.state != super::models::ProcessingState::Succeeded {
-                // Wait for processing to complete
-                finalize_result = wait_for_processing_completion(client, auth, &media_id).await?;
-            }
-        }
-    }
-
-    Ok(finalize_result)
-}
-
-/// Calculate optimal chunk size based on media size and type
-fn calculate_optimal_chunk_size(
-    media_size: usize,
-    media_type: &MediaType,
-    media_category: &MediaCategory,
-) -> usize {
-    // Size units
-    const KB: usize = 1024;
-    const MB: usize = 1024 * 1024;
-
-    // Twitter API's maximum chunk size is 5MB as per documentation
-    // https://developer.x.com/en/docs/x-api/v1/media/upload-media/api-reference/post-media-upload-append
-    const MAX_CHUNK_SIZE: usize = 5 * MB; // 5MB
-
-    // Minimum chunk size to avoid too many requests
-    const MIN_CHUNK_SIZE: usize = 128 * KB; // 128KB
-
-    // Size thresholds
-    const LARGE_VIDEO_THRESHOLD: usize = 20 * MB; // 20MB
-    const SMALL_FILE_THRESHOLD: usize = 10 * MB; // 10MB
-    const MEDIUM_FILE_THRESHOLD: usize = 50 * MB; // 50MB
-
-    // Chunk sizes
-    const SMALLER_VIDEO_CHUNK_SIZE: usize = 4 * MB; // 4MB
-    const GIF_CHUNK_SIZE: usize = 3 * MB; // 3MB
-    const MAX_IMAGE_CHUNK_SIZE: usize = 2 * MB; // 2MB
-
-    // Ideal chunk counts
-    const SMALL_FILE_CHUNK_COUNT: usize = 8;
-    const MEDIUM_FILE_CHUNK_COUNT: usize = 15;
-    const LARGE_FILE_CHUNK_COUNT: usize = 25;
-
-    // Rounding unit
-    const CHUNK_SIZE_ALIGNMENT: usize = 128 * KB; // 128KB
-
-    // For very small files, use a single chunk if possible
-    if media_size <= MAX_CHUNK_SIZE {
-        // Use the full file size if it's under the maximum allowed chunk size
-        // This reduces overhead with multiple requests
-        return media_size;
-    }
-
-    // Twitter doc mentions optimizing for cellular clients, so we set reasonable limits
-    // For images (typically smaller and faster to upload)
-    if *media_type == MediaType::ImageJpeg
-        || *media_type == MediaType::ImageGif
-        || *media_type == MediaType::ImagePng
-        || *media_type == MediaType::ImageWebp
-    {
-        // For larger images, still keep chunks reasonably sized
-        return std::cmp::min(media_size / 4, MAX_IMAGE_CHUNK_SIZE); // Max 2MB chunks for images
-    }
-
-    // For GIFs, which can be larger but still image-based
-    if *media_type == MediaType::ImageGif || matches!(media_category, MediaCategory::TweetGif) {
-        // Balance between speed and reliability
-        return GIF_CHUNK_SIZE; // 3MB for GIFs
-    }
-
-    // For videos, which are usually much larger files
-    if *media_type == MediaType::VideoMp4
-        || *media_type == MediaType::VideoWebm
-        || *media_type == MediaType::VideoMp2t
-        || matches!(
-            media_category,
-            MediaCategory::TweetVideo | MediaCategory::DmVideo | MediaCategory::AmplifyVideo
-        )
-    {
-        // For large videos, use max chunk size for better upload efficiency
-        // The docs mention that larger chunks are better for stable connections
-        if media_size > LARGE_VIDEO_THRESHOLD {
-            return MAX_CHUNK_SIZE; // 5MB for large videos
-        } else {
-            return SMALLER_VIDEO_CHUNK_SIZE; // 4MB for smaller videos
-        }
-    }
-
-    // Calculate optimal number of chunks based on file size
-    // Aim for a reasonable number of chunks to balance reliability and performance
-    let ideal_chunk_count = if media_size < SMALL_FILE_THRESHOLD {
-        // For files < 10MB, aim for ~8 chunks
-        SMALL_FILE_CHUNK_COUNT
-    } else if media_size < MEDIUM_FILE_THRESHOLD {
-        // For files between 10MB and 50MB, aim for ~15 chunks
-        MEDIUM_FILE_CHUNK_COUNT
-    } else {
-        // For larger files, aim for ~25 chunks
-        LARGE_FILE_CHUNK_COUNT
-    };
-
-    // Calculate chunk size based on ideal chunk count
-    let calculated_size = media_size / ideal_chunk_count;
-
-    // Ensure the calculated size is within bounds and round to nearest 128KB
-    let chunk_size = std::cmp::min(
-        MAX_CHUNK_SIZE,
-        std::cmp::max(MIN_CHUNK_SIZE, calculated_size),
-    );
-
-    // Round to nearest 128KB for efficiency
-    (chunk_size / CHUNK_SIZE_ALIGNMENT) * CHUNK_SIZE_ALIGNMENT
+    finalize_upload(client, auth, &media_id).await
 }
 
 /// Initialize a media upload (INIT command)
@@ -452,51 +462,82 @@ async fn finalize_upload(
         })
 }
 
-/// Wait for media processing to complete
+/// Wait for media processing to complete, polling the STATUS command with
+/// backoff until the media either succeeds or fails
 async fn wait_for_processing_completion(
     client: &TwitterClient,
     auth: &TwitterAuth,
     media_id: &str,
+    check_after_secs: Option<i32>,
 ) -> TwitterResult<MediaUploadData> {
-    // Maximum number of attempts
-    const MAX_ATTEMPTS: u32 = 20;
+    let mut check_after_secs = check_after_secs;
     let mut attempts = 0;
 
     loop {
+        // Wait for the recommended time or back off exponentially
+        tokio::time::sleep(status_poll_delay(attempts, check_after_secs)).await;
+
         // Check status
         let status = check_media_status(client, auth, media_id).await?;
 
-        if let Some(processing_info) = &status.processing_info {
-            match processing_info.state {
-                super::models::ProcessingState::Succeeded => {
-                    // Processing completed successfully
-                    return Ok(status);
-                }
-                super::models::ProcessingState::Failed => {
-                    // Processing failed
-                    return Err(TwitterError::Other("Media processing failed".to_string()));
-                }
-                _ => {
-                    // Still processing
-                    attempts += 1;
-                    if attempts >= MAX_ATTEMPTS {
-                        return Err(TwitterError::Other(
-                            "Media processing timed out".to_string(),
-                        ));
-                    }
+        let Some(processing_info) = &status.processing_info else {
+            // No processing info means it's ready
+            return Ok(status);
+        };
 
-                    // Wait for the recommended time or default to 2 seconds
-                    let wait_secs = processing_info.check_after_secs.unwrap_or(2);
-                    tokio::time::sleep(std::time::Duration::from_secs(wait_secs as u64)).await;
+        match processing_info.state {
+            ProcessingState::Succeeded => return Ok(status),
+            ProcessingState::Failed => {
+                return Err(processing_failed_error(media_id, processing_info))
+            }
+            ProcessingState::Pending | ProcessingState::InProgress => {
+                // Still processing
+                attempts += 1;
+                if attempts >= MAX_STATUS_ATTEMPTS {
+                    return Err(TwitterError::Other(format!(
+                        "Media processing of '{}' timed out after {} status checks ({}% done)",
+                        media_id,
+                        attempts,
+                        processing_info.progress_percent.unwrap_or_default()
+                    )));
                 }
+
+                check_after_secs = processing_info.check_after_secs;
             }
-        } else {
-            // No processing info means it's ready
-            return Ok(status);
         }
     }
 }
 
+/// Delay before the next STATUS check. Twitter's `check_after_secs` hint is
+/// preferred, otherwise the delay doubles with each attempt.
+fn status_poll_delay(attempt: u32, check_after_secs: Option<i32>) -> Duration {
+    let delay = match check_after_secs {
+        Some(secs) => Duration::from_secs(secs.max(0) as u64),
+        None => INITIAL_STATUS_BACKOFF.saturating_mul(2u32.saturating_pow(attempt)),
+    };
+
+    delay.min(MAX_STATUS_BACKOFF)
+}
+
+/// Build an error from the processing info of media that failed processing
+fn processing_failed_error(media_id: &str, processing_info: &ProcessingInfo) -> TwitterError {
+    let detail = processing_info
+        .error
+        .as_ref()
+        .map(|error| match (&error.name, &error.message) {
+            (Some(name), Some(message)) => format!(" - {}: {}", name, message),
+            (Some(detail), None) | (None, Some(detail)) => format!(" - {}", detail),
+            (None, None) => String::new(),
+        })
+        .unwrap_or_default();
+
+    TwitterError::ApiError(
+        format!("Media processing of '{}' failed", media_id),
+        "processing_failed".to_string(),
+        detail,
+    )
+}
+
 /// Check media upload status
 async fn check_media_status(
     client: &TwitterClient,
@@ -524,7 +565,12 @@ async fn check_media_status(
 
 #[cfg(test)]
 mod tests {
-    use {super::*, crate::media::models::ProcessingState, mockito::Server, serde_json::json};
+    use {
+        super::*,
+        mockito::{Matcher, Server},
+        serde_json::json,
+        std::sync::{Arc, Mutex},
+    };
 
     impl UploadMedia {
         fn with_api_base(api_base: &str) -> Self {
@@ -554,7 +600,7 @@ mod tests {
             media_type: MediaType::ImageJpeg,
             media_category: MediaCategory::TweetImage,
             additional_owners: vec![],
-            chunk_size: 1024,
+            chunk_size_bytes: Some(1024),
             optimistic_upload: true,
         }
     }
@@ -777,4 +823,337 @@ mod tests {
         // Verify the mock was called
         mock.assert_async().await;
     }
+
+    /// Matches multipart requests for the given upload command.
+    fn command_matcher(command: &str) -> Matcher {
+        Matcher::Regex(format!(r#"name="command"\s+{}\b"#, command))
+    }
+
+    fn upload_response(processing_info: serde_json::Value) -> String {
+        json!({
+            "data": {
+                "id": "12345678901234567890",
+                "media_key": "7_12345678901234567890",
+                "processing_info": processing_info
+            }
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_upload_media_waits_for_processing() {
+        let (mut server, tool, _) = create_server_and_tool().await;
+
+        let init_mock = server
+            .mock("POST", "/media/upload")
+            .match_body(command_matcher("INIT"))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "data": {
+                        "id": "12345678901234567890",
+                        "media_key": "7_12345678901234567890",
+                        "expires_after_secs": 3600
+                    }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        // "Hello World" split into 4 byte chunks.
+        let append_mock = server
+            .mock("POST", "/media/upload")
+            .match_body(command_matcher("APPEND"))
+            .with_status(204)
+            .with_body("")
+            .expect(3)
+            .create_async()
+            .await;
+
+        let finalize_mock = server
+            .mock("POST", "/media/upload")
+            .match_body(command_matcher("FINALIZE"))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(upload_response(
+                json!({ "state": "pending", "check_after_secs": 0 }),
+            ))
+            .expect(1)
+            .create_async()
+            .await;
+
+        // First status check is still pending, the second one succeeds.
+        let pending_mock = server
+            .mock("POST", "/media/upload")
+            .match_body(command_matcher("STATUS"))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(upload_response(json!({
+                "state": "in_progress",
+                "check_after_secs": 0,
+                "progress_percent": 50
+            })))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let succeeded_mock = server
+            .mock("POST", "/media/upload")
+            .match_body(command_matcher("STATUS"))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(upload_response(
+                json!({ "state": "succeeded", "progress_percent": 100 }),
+            ))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut input = create_test_input();
+        input.media_type = MediaType::VideoMp4;
+        input.media_category = MediaCategory::TweetVideo;
+        input.chunk_size_bytes = Some(4);
+        input.optimistic_upload = false;
+
+        match tool.invoke(input).await {
+            Output::Ok {
+                media_id,
+                media_key,
+            } => {
+                assert_eq!(media_id, "12345678901234567890");
+                assert_eq!(media_key, "7_12345678901234567890");
+            }
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        init_mock.assert_async().await;
+        append_mock.assert_async().await;
+        finalize_mock.assert_async().await;
+        pending_mock.assert_async().await;
+        succeeded_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_upload_media_processing_failed() {
+        let (mut server, tool, _) = create_server_and_tool().await;
+
+        server
+            .mock("POST", "/media/upload")
+            .match_body(command_matcher("INIT"))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(upload_response(serde_json::Value::Null))
+            .create_async()
+            .await;
+
+        server
+            .mock("POST", "/media/upload")
+            .match_body(command_matcher("APPEND"))
+            .with_status(204)
+            .with_body("")
+            .create_async()
+            .await;
+
+        server
+            .mock("POST", "/media/upload")
+            .match_body(command_matcher("FINALIZE"))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(upload_response(
+                json!({ "state": "pending", "check_after_secs": 0 }),
+            ))
+            .create_async()
+            .await;
+
+        let status_mock = server
+            .mock("POST", "/media/upload")
+            .match_body(command_matcher("STATUS"))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(upload_response(json!({
+                "state": "failed",
+                "progress_percent": 20,
+                "error": {
+                    "code": 1,
+                    "name": "InvalidMedia",
+                    "message": "Unsupported video format"
+                }
+            })))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut input = create_test_input();
+        input.media_type = MediaType::VideoMp4;
+        input.media_category = MediaCategory::TweetVideo;
+        input.optimistic_upload = false;
+
+        match tool.invoke(input).await {
+            Output::Ok { .. } => panic!("Expected error, got success"),
+            Output::Err { reason, kind, .. } => {
+                assert!(
+                    reason.contains("Media processing of '12345678901234567890' failed"),
+                    "Error message should indicate processing failure, got: {}",
+                    reason
+                );
+                assert!(
+                    reason.contains("InvalidMedia: Unsupported video format"),
+                    "Error message should contain the processing error, got: {}",
+                    reason
+                );
+                assert_eq!(kind, TwitterErrorKind::Api);
+            }
+        }
+
+        status_mock.assert_async().await;
+    }
+
+    /// The upload command of a multipart request body, if any.
+    fn upload_command(body: &str) -> Option<String> {
+        let (_, rest) = body.split_once(r#"name="command""#)?;
+
+        Some(
+            rest.trim_start()
+                .chars()
+                .take_while(char::is_ascii_uppercase)
+                .collect(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_upload_media_chunked_sequence() {
+        let (mut server, tool, _) = create_server_and_tool().await;
+        let commands = Arc::new(Mutex::new(vec![]));
+
+        // Records the command of each request that the mock responds to.
+        let record = |command: &'static str| {
+            let commands = commands.clone();
+
+            move |request: &mockito::Request| {
+                let Ok(body) = request.body() else {
+                    return false;
+                };
+
+                match upload_command(&String::from_utf8_lossy(body)) {
+                    Some(received) if received == command => {
+                        commands.lock().unwrap().push(received);
+
+                        true
+                    }
+                    _ => false,
+                }
+            }
+        };
+
+        let init_mock = server
+            .mock("POST", "/media/upload")
+            .match_request(record("INIT"))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(upload_response(serde_json::Value::Null))
+            .create_async()
+            .await;
+
+        let append_mock = server
+            .mock("POST", "/media/upload")
+            .match_request(record("APPEND"))
+            .with_status(204)
+            .with_body("")
+            .expect(2)
+            .create_async()
+            .await;
+
+        let finalize_mock = server
+            .mock("POST", "/media/upload")
+            .match_request(record("FINALIZE"))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(upload_response(
+                json!({ "state": "succeeded", "progress_percent": 100 }),
+            ))
+            .create_async()
+            .await;
+
+        // Images above 5MB are uploaded in 4MB chunks by default.
+        let mut input = create_test_input();
+        input.media_data = base64::encode(vec![0u8; MAX_SINGLE_PART_SIZE + 1]);
+        input.chunk_size_bytes = None;
+
+        match tool.invoke(input).await {
+            Output::Ok { media_id, .. } => assert_eq!(media_id, "12345678901234567890"),
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        assert_eq!(
+            *commands.lock().unwrap(),
+            vec!["INIT", "APPEND", "APPEND", "FINALIZE"]
+        );
+
+        init_mock.assert_async().await;
+        append_mock.assert_async().await;
+        finalize_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_upload_media_single_part() {
+        let (mut server, tool, _) = create_server_and_tool().await;
+
+        let mock = server
+            .mock("POST", "/media/upload")
+            .match_request(|request| {
+                request.body().is_ok_and(|body| {
+                    let body = String::from_utf8_lossy(body);
+
+                    upload_command(&body).is_none()
+                        && body.contains(r#"name="media""#)
+                        && body.contains("Hello World")
+                })
+            })
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(upload_response(serde_json::Value::Null))
+            .expect(1)
+            .create_async()
+            .await;
+
+        match tool.invoke(create_test_input()).await {
+            Output::Ok { media_id, .. } => assert_eq!(media_id, "12345678901234567890"),
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_requires_chunked_upload() {
+        assert!(!requires_chunked_upload(
+            MAX_SINGLE_PART_SIZE,
+            &MediaCategory::TweetImage
+        ));
+        assert!(requires_chunked_upload(
+            MAX_SINGLE_PART_SIZE + 1,
+            &MediaCategory::TweetImage
+        ));
+        assert!(requires_chunked_upload(1, &MediaCategory::TweetVideo));
+        assert!(requires_chunked_upload(1, &MediaCategory::DmGif));
+    }
+
+    #[test]
+    fn test_status_poll_delay() {
+        // Twitter's hint is preferred.
+        assert_eq!(status_poll_delay(5, Some(3)), Duration::from_secs(3));
+        assert_eq!(status_poll_delay(0, Some(-1)), Duration::ZERO);
+
+        // Exponential backoff otherwise.
+        assert_eq!(status_poll_delay(0, None), Duration::from_secs(1));
+        assert_eq!(status_poll_delay(1, None), Duration::from_secs(2));
+        assert_eq!(status_poll_delay(3, None), Duration::from_secs(8));
+
+        // Both are capped.
+        assert_eq!(status_poll_delay(10, None), MAX_STATUS_BACKOFF);
+        assert_eq!(status_poll_delay(0, Some(3600)), MAX_STATUS_BACKOFF);
+    }
 }
@@ -0,0 +1,406 @@
+//! Rate limiting for the Twitter API client
+//!
+//! Requests are throttled with a token bucket per endpoint family (HTTP method,
+//! host and first path segment, e.g. `GET api.twitter.com/tweets`). When the
+//! API responds with `429 Too Many Requests`, the family is blocked until the
+//! time advertised in the `x-rate-limit-reset` header.
+//!
+//! A single [`RateLimiter`] is shared by all tools running in the process, see
+//! [`RateLimiter::global`].
+
+use {
+    crate::error::TwitterError,
+    chrono::{DateTime, TimeDelta, Utc},
+    reqwest::{header::HeaderMap, Url},
+    std::{
+        collections::HashMap,
+        sync::{Arc, Mutex, OnceLock, PoisonError},
+        time::{Duration, Instant},
+    },
+};
+
+/// Header that holds the UTC epoch seconds at which the current rate limit
+/// window resets.
+pub(crate) const RATE_LIMIT_RESET_HEADER: &str = "x-rate-limit-reset";
+
+/// Upper bound for [`RateLimitConfig::max_wait`] so that a misconfigured
+/// env var can't stall a tool invocation indefinitely.
+pub(crate) const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(60);
+
+/// Rate limiter shared by all Twitter tools in this process.
+static GLOBAL_RATE_LIMITER: OnceLock<Arc<RateLimiter>> = OnceLock::new();
+
+/// Configuration for [`RateLimiter`].
+///
+/// Defaults can be overridden with the `TWITTER_RATE_LIMIT_CAPACITY`,
+/// `TWITTER_RATE_LIMIT_WINDOW_SECS`, `TWITTER_RATE_LIMIT_MAX_WAIT_SECS`,
+/// `TWITTER_RATE_LIMIT_MAX_RETRIES` and `TWITTER_RATE_LIMIT_POLICY` env vars.
+#[derive(Clone, Debug)]
+pub struct RateLimitConfig {
+    /// Number of requests an endpoint family can make in one window.
+    pub capacity: u32,
+    /// Time it takes for an empty bucket to refill completely.
+    pub window: Duration,
+    /// Longest time a single request waits for a token or a rate limit reset
+    /// before giving up with [`TwitterError::RateLimited`]. Capped at 60
+    /// seconds when read from env vars.
+    pub max_wait: Duration,
+    /// How many times a request is retried after a `429` response.
+    pub max_retries: u32,
+    /// What clients using this limiter do when a request is rate limited.
+    pub policy: RateLimitPolicy,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 300,
+            window: Duration::from_secs(15 * 60),
+            max_wait: Duration::from_secs(30),
+            max_retries: 1,
+            policy: RateLimitPolicy::default(),
+        }
+    }
+}
+
+impl RateLimitConfig {
+    /// Build the config from env vars, falling back to defaults for missing
+    /// or invalid values.
+    pub fn from_env() -> Self {
+        fn var<T: std::str::FromStr>(name: &str) -> Option<T> {
+            std::env::var(name).ok()?.trim().parse().ok()
+        }
+
+        let default = Self::default();
+
+        Self {
+            capacity: var("TWITTER_RATE_LIMIT_CAPACITY").unwrap_or(default.capacity),
+            window: var("TWITTER_RATE_LIMIT_WINDOW_SECS")
+                .map(Duration::from_secs)
+                .unwrap_or(default.window),
+            max_wait: var("TWITTER_RATE_LIMIT_MAX_WAIT_SECS")
+                .map(|secs| Duration::from_secs(secs).min(MAX_RATE_LIMIT_WAIT))
+                .unwrap_or(default.max_wait),
+            max_retries: var("TWITTER_RATE_LIMIT_MAX_RETRIES").unwrap_or(default.max_retries),
+            policy: var("TWITTER_RATE_LIMIT_POLICY").unwrap_or(default.policy),
+        }
+    }
+}
+
+/// What a [`crate::twitter_client::TwitterClient`] does when a request is
+/// rate limited.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RateLimitPolicy {
+    /// Wait for a token or the `x-rate-limit-reset` time, up to
+    /// [`RateLimitConfig::max_wait`], and retry.
+    #[default]
+    Wait,
+    /// Fail with [`TwitterError::RateLimited`] right away.
+    Fail,
+}
+
+impl std::str::FromStr for RateLimitPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "wait" => Ok(Self::Wait),
+            "fail" => Ok(Self::Fail),
+            _ => Err(format!("Unknown rate limit policy '{s}'")),
+        }
+    }
+}
+
+/// Token bucket state for one endpoint family.
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+    /// Set when the API told us to back off until this time.
+    blocked_until: Option<DateTime<Utc>>,
+}
+
+/// Token bucket rate limiter keyed by endpoint family.
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the process-wide rate limiter, initializing it from env vars
+    /// on first use.
+    pub fn global() -> Arc<Self> {
+        GLOBAL_RATE_LIMITER
+            .get_or_init(|| Arc::new(Self::new(RateLimitConfig::from_env())))
+            .clone()
+    }
+
+    pub fn config(&self) -> &RateLimitConfig {
+        &self.config
+    }
+
+    /// Waits until a request for the given endpoint family is allowed.
+    ///
+    /// Fails with [`TwitterError::RateLimited`] if that would take longer
+    /// than [`RateLimitConfig::max_wait`], or immediately if no token is
+    /// available and the policy is [`RateLimitPolicy::Fail`].
+    pub async fn acquire(&self, family: &str, policy: RateLimitPolicy) -> Result<(), TwitterError> {
+        let max_wait = match policy {
+            RateLimitPolicy::Wait => self.config.max_wait,
+            RateLimitPolicy::Fail => Duration::ZERO,
+        };
+        let deadline = Instant::now() + max_wait;
+
+        loop {
+            let wait = match self.try_acquire(family) {
+                Ok(()) => return Ok(()),
+                Err(wait) => wait,
+            };
+
+            if Instant::now() + wait > deadline {
+                return Err(TwitterError::RateLimited {
+                    reset_at: Some(Utc::now() + TimeDelta::from_std(wait).unwrap_or_default()),
+                    detail: format!("Rate limit for '{}' exhausted", family),
+                });
+            }
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Takes a token from the bucket or returns how long to wait for one.
+    fn try_acquire(&self, family: &str) -> Result<(), Duration> {
+        let capacity = f64::from(self.config.capacity.max(1));
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        let bucket = buckets.entry(family.to_string()).or_insert_with(|| Bucket {
+            tokens: capacity,
+            last_refill: Instant::now(),
+            blocked_until: None,
+        });
+
+        if let Some(blocked_until) = bucket.blocked_until {
+            if let Ok(wait) = (blocked_until - Utc::now()).to_std() {
+                if !wait.is_zero() {
+                    return Err(wait);
+                }
+            }
+
+            // The window has reset, start over with a full bucket.
+            bucket.blocked_until = None;
+            bucket.tokens = capacity;
+            bucket.last_refill = Instant::now();
+        }
+
+        let refill_rate = capacity / self.config.window.as_secs_f64().max(f64::EPSILON);
+        let now = Instant::now();
+
+        bucket.tokens = (bucket.tokens
+            + now.duration_since(bucket.last_refill).as_secs_f64() * refill_rate)
+            .min(capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+
+            return Ok(());
+        }
+
+        Err(Duration::from_secs_f64((1.0 - bucket.tokens) / refill_rate))
+    }
+
+    /// Blocks the endpoint family until the given time, usually after a `429`.
+    pub fn block_until(&self, family: &str, reset_at: DateTime<Utc>) {
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        let bucket = buckets.entry(family.to_string()).or_insert_with(|| Bucket {
+            tokens: 0.0,
+            last_refill: Instant::now(),
+            blocked_until: None,
+        });
+
+        bucket.tokens = 0.0;
+        bucket.blocked_until = Some(match bucket.blocked_until {
+            Some(blocked_until) if blocked_until > reset_at => blocked_until,
+            _ => reset_at,
+        });
+    }
+
+    /// Whether waiting until `reset_at` fits into
+    /// [`RateLimitConfig::max_wait`].
+    pub fn can_wait_until(&self, reset_at: DateTime<Utc>) -> bool {
+        match (reset_at - Utc::now()).to_std() {
+            Ok(wait) => wait <= self.config.max_wait,
+            // Reset time is already in the past.
+            Err(_) => true,
+        }
+    }
+}
+
+/// Derives the endpoint family for a request, e.g. `GET api.twitter.com:443/tweets`.
+pub(crate) fn endpoint_family(method: &str, url: &str) -> String {
+    let Ok(url) = Url::parse(url) else {
+        return format!("{} {}", method, url);
+    };
+
+    // Skip the API version segment.
+    let resource = url
+        .path_segments()
+        .and_then(|mut segments| segments.find(|s| !s.is_empty() && *s != "2"))
+        .unwrap_or_default();
+
+    format!(
+        "{} {}:{}/{}",
+        method,
+        url.host_str().unwrap_or_default(),
+        url.port_or_known_default().unwrap_or_default(),
+        resource
+    )
+}
+
+/// Parses the `x-rate-limit-reset` header into a UTC timestamp.
+pub(crate) fn parse_rate_limit_reset(headers: &HeaderMap) -> Option<DateTime<Utc>> {
+    let seconds = headers
+        .get(RATE_LIMIT_RESET_HEADER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<i64>()
+        .ok()?;
+
+    DateTime::from_timestamp(seconds, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(capacity: u32, window: Duration, max_wait: Duration) -> RateLimitConfig {
+        RateLimitConfig {
+            capacity,
+            window,
+            max_wait,
+            max_retries: 1,
+            policy: RateLimitPolicy::Wait,
+        }
+    }
+
+    #[test]
+    fn test_parse_rate_limit_policy() {
+        assert_eq!("wait".parse::<RateLimitPolicy>(), Ok(RateLimitPolicy::Wait));
+        assert_eq!("FAIL".parse::<RateLimitPolicy>(), Ok(RateLimitPolicy::Fail));
+        assert!("retry".parse::<RateLimitPolicy>().is_err());
+    }
+
+    #[test]
+    fn test_endpoint_family() {
+        assert_eq!(
+            endpoint_family("GET", "https://api.twitter.com/2/tweets/123"),
+            "GET api.twitter.com:443/tweets"
+        );
+        assert_eq!(
+            endpoint_family("POST", "http://127.0.0.1:1234/users/123/retweets"),
+            "POST 127.0.0.1:1234/users"
+        );
+    }
+
+    #[test]
+    fn test_parse_rate_limit_reset() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(parse_rate_limit_reset(&headers), None);
+
+        headers.insert(RATE_LIMIT_RESET_HEADER, "1700000000".parse().unwrap());
+        assert_eq!(
+            parse_rate_limit_reset(&headers),
+            DateTime::from_timestamp(1_700_000_000, 0)
+        );
+
+        headers.insert(RATE_LIMIT_RESET_HEADER, "soon".parse().unwrap());
+        assert_eq!(parse_rate_limit_reset(&headers), None);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_exhausted_bucket() {
+        let limiter = RateLimiter::new(config(
+            2,
+            Duration::from_secs(3600),
+            Duration::from_millis(100),
+        ));
+
+        assert!(limiter
+            .acquire("GET tweets", RateLimitPolicy::Wait)
+            .await
+            .is_ok());
+        assert!(limiter
+            .acquire("GET tweets", RateLimitPolicy::Wait)
+            .await
+            .is_ok());
+
+        // Other families have their own bucket.
+        assert!(limiter
+            .acquire("GET users", RateLimitPolicy::Wait)
+            .await
+            .is_ok());
+
+        let err = limiter
+            .acquire("GET tweets", RateLimitPolicy::Wait)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            TwitterError::RateLimited {
+                reset_at: Some(_),
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_refill() {
+        let limiter = RateLimiter::new(config(
+            1,
+            Duration::from_millis(200),
+            Duration::from_secs(1),
+        ));
+
+        assert!(limiter
+            .acquire("GET tweets", RateLimitPolicy::Wait)
+            .await
+            .is_ok());
+
+        let started = Instant::now();
+        assert!(limiter
+            .acquire("GET tweets", RateLimitPolicy::Wait)
+            .await
+            .is_ok());
+        assert!(started.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_block_until() {
+        let limiter = RateLimiter::new(config(10, Duration::from_secs(60), Duration::from_secs(1)));
+
+        let reset_at = Utc::now() + TimeDelta::seconds(3600);
+        limiter.block_until("GET tweets", reset_at);
+
+        assert!(!limiter.can_wait_until(reset_at));
+
+        match limiter.acquire("GET tweets", RateLimitPolicy::Wait).await {
+            Err(TwitterError::RateLimited { .. }) => (),
+            other => panic!("Expected rate limited error, got: {:?}", other),
+        }
+
+        // Reset time in the past unblocks the family.
+        let limiter = RateLimiter::new(config(10, Duration::from_secs(60), Duration::from_secs(1)));
+        limiter.block_until("GET tweets", Utc::now() - TimeDelta::seconds(1));
+
+        assert!(limiter
+            .acquire("GET tweets", RateLimitPolicy::Wait)
+            .await
+            .is_ok());
+    }
+}
@@ -0,0 +1,317 @@
+//! # `xyz.taluslabs.social.twitter.create-poll@1`
+//!
+//! Standard Nexus Tool that posts a tweet with a poll attached.
+
+use {
+    super::models::PostTweetResponse,
+    crate::{
+        auth::TwitterAuth,
+        error::TwitterErrorKind,
+        twitter_client::{api_base_from_env, TwitterClient, TWITTER_API_BASE},
+    },
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+    serde_json::json,
+};
+
+/// Twitter accepts between 2 and 4 poll options.
+const POLL_OPTIONS: std::ops::RangeInclusive<usize> = 2..=4;
+/// Maximum number of characters of a poll option.
+const MAX_POLL_OPTION_LENGTH: usize = 25;
+/// Polls run between 5 minutes and 7 days.
+const POLL_DURATION_MINUTES: std::ops::RangeInclusive<u32> = 5..=10080;
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// Twitter API credentials
+    #[serde(flatten)]
+    auth: TwitterAuth,
+    /// Text of the tweet the poll is attached to
+    text: String,
+    /// Poll options, 2 to 4 options of at most 25 characters each
+    options: Vec<String>,
+    /// How long the poll runs in minutes, from 5 to 10080 (7 days)
+    duration_minutes: u32,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok {
+        /// ID of the tweet with the poll
+        id: String,
+        /// List of tweet IDs in the edit history
+        edit_history_tweet_ids: Vec<String>,
+        /// The actual content of the tweet
+        text: String,
+    },
+    Err {
+        /// Detailed error message
+        reason: String,
+        /// Type of error (network, server, auth, etc.)
+        kind: TwitterErrorKind,
+        /// HTTP status code if available
+        #[serde(skip_serializing_if = "Option::is_none")]
+        status_code: Option<u16>,
+    },
+}
+
+pub(crate) struct CreatePoll {
+    api_base: String,
+}
+
+impl NexusTool for CreatePoll {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self {
+            api_base: api_base_from_env(TWITTER_API_BASE),
+        }
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.social.twitter.create-poll@1")
+    }
+
+    fn path() -> &'static str {
+        "/create-poll"
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    fn validate(&self, input: &Self::Input) -> Result<(), ToolValidationError> {
+        if !POLL_OPTIONS.contains(&input.options.len()) {
+            return Err(ToolValidationError::new(format!(
+                "Poll must have between {} and {} options, got {}",
+                POLL_OPTIONS.start(),
+                POLL_OPTIONS.end(),
+                input.options.len()
+            ))
+            .at("/options"));
+        }
+
+        for (index, option) in input.options.iter().enumerate() {
+            let length = option.trim().chars().count();
+
+            if length == 0 || length > MAX_POLL_OPTION_LENGTH {
+                return Err(ToolValidationError::new(format!(
+                    "Poll option must have between 1 and {MAX_POLL_OPTION_LENGTH} characters, got {length}"
+                ))
+                .at(format!("/options/{index}")));
+            }
+        }
+
+        if !POLL_DURATION_MINUTES.contains(&input.duration_minutes) {
+            return Err(ToolValidationError::new(format!(
+                "Poll duration must be between {} and {} minutes, got {}",
+                POLL_DURATION_MINUTES.start(),
+                POLL_DURATION_MINUTES.end(),
+                input.duration_minutes
+            ))
+            .at("/duration_minutes"));
+        }
+
+        Ok(())
+    }
+
+    async fn invoke(&self, request: Self::Input) -> Self::Output {
+        let client = match TwitterClient::new(Some("tweets"), Some(&self.api_base)) {
+            Ok(client) => client,
+            Err(e) => {
+                return Output::Err {
+                    reason: e.to_string(),
+                    kind: TwitterErrorKind::Network,
+                    status_code: None,
+                }
+            }
+        };
+
+        let body = json!({
+            "text": request.text,
+            "poll": {
+                "options": request.options,
+                "duration_minutes": request.duration_minutes,
+            },
+        });
+
+        match client
+            .post::<PostTweetResponse, _>(&request.auth, Some(body), None)
+            .await
+        {
+            Ok(tweet) => Output::Ok {
+                id: tweet.id,
+                edit_history_tweet_ids: tweet.edit_history_tweet_ids,
+                text: tweet.text,
+            },
+            Err(e) => Output::Err {
+                reason: e.reason,
+                kind: e.kind,
+                status_code: e.status_code,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        ::{
+            mockito::{Matcher, Server},
+            serde_json::json,
+        },
+    };
+
+    impl CreatePoll {
+        fn with_api_base(api_base: &str) -> Self {
+            Self {
+                api_base: api_base.to_string(),
+            }
+        }
+    }
+
+    fn create_test_input(options: &[&str], duration_minutes: u32) -> Input {
+        Input {
+            auth: TwitterAuth::new(
+                "test_consumer_key",
+                "test_consumer_secret",
+                "test_access_token",
+                "test_access_token_secret",
+            ),
+            text: "Tabs or spaces?".to_string(),
+            options: options.iter().map(|o| o.to_string()).collect(),
+            duration_minutes,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_poll_successful() {
+        let mut server = Server::new_async().await;
+        let tool = CreatePoll::with_api_base(&server.url());
+
+        let mock = server
+            .mock("POST", "/tweets")
+            .match_body(Matcher::Json(json!({
+                "text": "Tabs or spaces?",
+                "poll": {
+                    "options": ["Tabs", "Spaces"],
+                    "duration_minutes": 60
+                }
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "data": {
+                        "id": "1445880548472328192",
+                        "edit_history_tweet_ids": ["1445880548472328192"],
+                        "text": "Tabs or spaces?"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        match tool
+            .invoke(create_test_input(&["Tabs", "Spaces"], 60))
+            .await
+        {
+            Output::Ok {
+                id,
+                edit_history_tweet_ids,
+                text,
+            } => {
+                assert_eq!(id, "1445880548472328192");
+                assert_eq!(edit_history_tweet_ids, vec!["1445880548472328192"]);
+                assert_eq!(text, "Tabs or spaces?");
+            }
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_poll_api_error() {
+        let mut server = Server::new_async().await;
+        let tool = CreatePoll::with_api_base(&server.url());
+
+        let mock = server
+            .mock("POST", "/tweets")
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "detail": "You are not permitted to create a poll.",
+                    "status": 403,
+                    "title": "Forbidden",
+                    "type": "about:blank"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        match tool
+            .invoke(create_test_input(&["Tabs", "Spaces"], 60))
+            .await
+        {
+            Output::Ok { .. } => panic!("Expected error, got success"),
+            Output::Err {
+                reason,
+                kind,
+                status_code,
+            } => {
+                assert_eq!(kind, TwitterErrorKind::Forbidden);
+                assert_eq!(status_code, Some(403));
+                assert!(reason.contains("403"), "Unexpected reason: {}", reason);
+            }
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_validate_poll() {
+        let tool = CreatePoll::with_api_base("http://localhost");
+
+        assert!(tool
+            .validate(&create_test_input(&["Tabs", "Spaces"], 5))
+            .is_ok());
+        assert!(tool
+            .validate(&create_test_input(&["A", "B", "C", "D"], 10080))
+            .is_ok());
+
+        let cases = [
+            (create_test_input(&["Tabs"], 60), "/options"),
+            (
+                create_test_input(&["A", "B", "C", "D", "E"], 60),
+                "/options",
+            ),
+            (create_test_input(&["Tabs", " "], 60), "/options/1"),
+            (
+                create_test_input(&["Tabs", "Spaces, obviously, always and forever"], 60),
+                "/options/1",
+            ),
+            (
+                create_test_input(&["Tabs", "Spaces"], 4),
+                "/duration_minutes",
+            ),
+            (
+                create_test_input(&["Tabs", "Spaces"], 10081),
+                "/duration_minutes",
+            ),
+        ];
+
+        for (input, pointer) in cases {
+            assert_eq!(tool.validate(&input).unwrap_err().pointer, pointer);
+        }
+    }
+}
@@ -0,0 +1,335 @@
+//! # `xyz.taluslabs.social.twitter.create-thread@1`
+//!
+//! Standard Nexus Tool that creates a thread from a list of tweet contents.
+
+use {
+    super::{
+        models::TweetContent,
+        post_thread::{post_tweets_as_thread, ThreadError},
+    },
+    crate::{
+        auth::TwitterAuth,
+        error::TwitterErrorKind,
+        twitter_client::{api_base_from_env, TWITTER_API_BASE},
+    },
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// Twitter API credentials
+    #[serde(flatten)]
+    auth: TwitterAuth,
+    /// Contents of the tweets in the thread, in order
+    tweets: Vec<TweetContent>,
+    /// Milliseconds to wait between posting two tweets, for example to stay
+    /// within rate limits. At most one minute.
+    #[serde(default)]
+    delay_ms: Option<u64>,
+}
+
+#[derive(Serialize, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok {
+        /// IDs of the posted tweets, in thread order
+        tweet_ids: Vec<String>,
+    },
+    Err {
+        /// Detailed error message
+        reason: String,
+        /// Type of error (network, server, auth, etc.)
+        kind: TwitterErrorKind,
+        /// HTTP status code if available
+        #[serde(skip_serializing_if = "Option::is_none")]
+        status_code: Option<u16>,
+        /// IDs of the tweets posted before the failure, in thread order. These
+        /// are not deleted and may need to be cleaned up manually.
+        posted_tweet_ids: Vec<String>,
+    },
+}
+
+pub(crate) struct CreateThread {
+    api_base: String,
+}
+
+impl NexusTool for CreateThread {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self {
+            api_base: api_base_from_env(TWITTER_API_BASE),
+        }
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.social.twitter.create-thread@1")
+    }
+
+    fn path() -> &'static str {
+        "/create-thread"
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, request: Self::Input) -> Self::Output {
+        let bodies = match request
+            .tweets
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(bodies) => bodies,
+            Err(e) => {
+                return Output::Err {
+                    reason: format!("Failed to serialize tweets: {e}"),
+                    kind: TwitterErrorKind::Parse,
+                    status_code: None,
+                    posted_tweet_ids: vec![],
+                }
+            }
+        };
+
+        match post_tweets_as_thread(
+            &self.api_base,
+            &request.auth,
+            bodies,
+            None,
+            request.delay_ms,
+        )
+        .await
+        {
+            Ok(tweet_ids) => Output::Ok { tweet_ids },
+            Err(ThreadError {
+                error,
+                posted_tweet_ids,
+            }) => Output::Err {
+                reason: if posted_tweet_ids.is_empty() {
+                    error.reason
+                } else {
+                    format!(
+                        "{} (already posted: {})",
+                        error.reason,
+                        posted_tweet_ids.join(", ")
+                    )
+                },
+                kind: error.kind,
+                status_code: error.status_code,
+                posted_tweet_ids,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::tweet::{models::MediaInfo, post_thread::MAX_DELAY_MS},
+        ::{
+            mockito::{Matcher, Server},
+            serde_json::json,
+        },
+    };
+
+    impl CreateThread {
+        fn with_api_base(api_base: &str) -> Self {
+            Self {
+                api_base: api_base.to_string(),
+            }
+        }
+    }
+
+    async fn create_server_and_tool() -> (mockito::ServerGuard, CreateThread) {
+        let server = Server::new_async().await;
+        let tool = CreateThread::with_api_base(&server.url());
+        (server, tool)
+    }
+
+    fn create_test_input(tweets: &[&str]) -> Input {
+        Input {
+            auth: TwitterAuth::new(
+                "test_consumer_key",
+                "test_consumer_secret",
+                "test_access_token",
+                "test_access_token_secret",
+            ),
+            tweets: tweets
+                .iter()
+                .map(|text| TweetContent {
+                    text: text.to_string(),
+                    media: None,
+                })
+                .collect(),
+            delay_ms: None,
+        }
+    }
+
+    fn tweet_body(id: &str, text: &str) -> String {
+        json!({
+            "data": {
+                "id": id,
+                "edit_history_tweet_ids": [id],
+                "text": text
+            }
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_create_thread_successful() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let first_mock = server
+            .mock("POST", "/tweets")
+            .match_body(Matcher::Json(json!({
+                "text": "First",
+                "media": { "media_ids": ["media_1"] }
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(tweet_body("1", "First"))
+            .create_async()
+            .await;
+
+        let second_mock = server
+            .mock("POST", "/tweets")
+            .match_body(Matcher::Json(json!({
+                "text": "Second",
+                "reply": { "in_reply_to_tweet_id": "1" }
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(tweet_body("2", "Second"))
+            .create_async()
+            .await;
+
+        let mut input = create_test_input(&["First", "Second"]);
+        input.tweets[0].media = Some(MediaInfo {
+            media_ids: vec!["media_1".to_string()],
+            tagged_user_ids: None,
+        });
+
+        match tool.invoke(input).await {
+            Output::Ok { tweet_ids } => assert_eq!(tweet_ids, vec!["1", "2"]),
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        first_mock.assert_async().await;
+        second_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_thread_lists_posted_tweets_on_failure() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let first_mock = server
+            .mock("POST", "/tweets")
+            .match_body(Matcher::Json(json!({ "text": "First" })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(tweet_body("1", "First"))
+            .create_async()
+            .await;
+
+        let second_mock = server
+            .mock("POST", "/tweets")
+            .match_body(Matcher::PartialJson(json!({ "text": "Second" })))
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "detail": "You are not allowed to create a Tweet with duplicate content.",
+                    "status": 403,
+                    "title": "Forbidden",
+                    "type": "about:blank"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let third_mock = server
+            .mock("POST", "/tweets")
+            .match_body(Matcher::PartialJson(json!({ "text": "Third" })))
+            .expect(0)
+            .create_async()
+            .await;
+
+        let input = create_test_input(&["First", "Second", "Third"]);
+
+        match tool.invoke(input).await {
+            Output::Ok { .. } => panic!("Expected error, got success"),
+            Output::Err {
+                reason,
+                status_code,
+                posted_tweet_ids,
+                ..
+            } => {
+                assert!(
+                    reason.contains("tweet 2 of 3") && reason.contains("already posted: 1"),
+                    "Expected failing and posted tweets to be reported, got: {}",
+                    reason
+                );
+                assert_eq!(status_code, Some(403));
+                assert_eq!(posted_tweet_ids, vec!["1"]);
+            }
+        }
+
+        first_mock.assert_async().await;
+        second_mock.assert_async().await;
+        third_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_thread_delay_too_long() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let mock = server
+            .mock("POST", "/tweets")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let mut input = create_test_input(&["First", "Second"]);
+        input.delay_ms = Some(MAX_DELAY_MS + 1);
+
+        match tool.invoke(input).await {
+            Output::Ok { .. } => panic!("Expected error, got success"),
+            Output::Err {
+                kind,
+                posted_tweet_ids,
+                ..
+            } => {
+                assert_eq!(kind, TwitterErrorKind::Validation);
+                assert!(posted_tweet_ids.is_empty());
+            }
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_thread_empty() {
+        let (_, tool) = create_server_and_tool().await;
+
+        match tool.invoke(create_test_input(&[])).await {
+            Output::Ok { .. } => panic!("Expected error, got success"),
+            Output::Err {
+                kind,
+                posted_tweet_ids,
+                ..
+            } => {
+                assert_eq!(kind, TwitterErrorKind::Validation);
+                assert!(posted_tweet_ids.is_empty());
+            }
+        }
+    }
+}
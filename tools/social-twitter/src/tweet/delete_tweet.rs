@@ -7,7 +7,7 @@ use {
     crate::{
         auth::TwitterAuth,
         error::TwitterErrorKind,
-        twitter_client::{TwitterClient, TWITTER_API_BASE},
+        twitter_client::{api_base_from_env, TwitterClient, TWITTER_API_BASE},
     },
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
@@ -53,7 +53,7 @@ impl NexusTool for DeleteTweet {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string(),
+            api_base: api_base_from_env(TWITTER_API_BASE),
         }
     }
 
@@ -100,6 +100,7 @@ impl NexusTool for DeleteTweet {
 mod tests {
     use {
         super::*,
+        crate::twitter_client::TWITTER_API_BASE_ENV,
         ::{mockito::Server, serde_json::json},
     };
 
@@ -129,6 +130,37 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_api_base_from_env() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("DELETE", "/tweets/12345")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "deleted": true } }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        // Trailing slashes are stripped from the overridden base URL.
+        std::env::set_var(TWITTER_API_BASE_ENV, format!("{}/", server.url()));
+        let tool = DeleteTweet::new().await;
+        std::env::remove_var(TWITTER_API_BASE_ENV);
+
+        assert_eq!(tool.api_base, server.url());
+
+        match tool.invoke(create_test_input()).await {
+            Output::Ok { deleted } => assert!(deleted),
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        mock.assert_async().await;
+
+        // Without the env var the default base URL is used.
+        assert_eq!(DeleteTweet::new().await.api_base, TWITTER_API_BASE);
+    }
+
     #[tokio::test]
     async fn test_successful_delete() {
         // Create server and tool
@@ -20,6 +20,7 @@ use {
             },
             TWITTER_API_BASE,
         },
+        twitter_client::api_base_from_env,
     },
     reqwest::Client,
     ::{
@@ -132,7 +133,7 @@ impl NexusTool for GetMentionedTweets {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string() + "/users",
+            api_base: api_base_from_env(TWITTER_API_BASE) + "/users",
         }
     }
 
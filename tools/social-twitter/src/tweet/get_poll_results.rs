@@ -0,0 +1,328 @@
+//! # `xyz.taluslabs.social.twitter.get-poll-results@1`
+//!
+//! Standard Nexus Tool that retrieves the poll attached to a tweet including
+//! the vote counts of its options.
+
+use {
+    super::models::{Poll, PollOption, PollTweetResponse},
+    crate::{
+        auth::TwitterAuth,
+        error::TwitterErrorKind,
+        twitter_client::{api_base_from_env, TwitterClient, TWITTER_API_BASE},
+    },
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+};
+
+/// Poll fields requested along with the tweet.
+const POLL_FIELDS: &str = "duration_minutes,end_datetime,id,options,voting_status";
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// Twitter API credentials
+    #[serde(flatten)]
+    auth: TwitterAuth,
+    /// ID of the tweet the poll is attached to
+    tweet_id: String,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok {
+        /// ID of the tweet the poll is attached to
+        tweet_id: String,
+        /// ID of the poll
+        poll_id: String,
+        /// Options of the poll in display order, with their vote counts
+        options: Vec<PollOption>,
+        /// Sum of the votes of all options
+        total_votes: i64,
+        /// Whether the poll is `open` or `closed`
+        voting_status: String,
+        /// How long the poll runs in minutes
+        duration_minutes: i32,
+        /// When the poll closes
+        end_datetime: String,
+    },
+    Err {
+        /// Detailed error message
+        reason: String,
+        /// Type of error (network, server, auth, etc.)
+        kind: TwitterErrorKind,
+        /// HTTP status code if available
+        #[serde(skip_serializing_if = "Option::is_none")]
+        status_code: Option<u16>,
+    },
+}
+
+pub(crate) struct GetPollResults {
+    api_base: String,
+}
+
+impl NexusTool for GetPollResults {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self {
+            api_base: api_base_from_env(TWITTER_API_BASE),
+        }
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.social.twitter.get-poll-results@1")
+    }
+
+    fn path() -> &'static str {
+        "/get-poll-results"
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, request: Self::Input) -> Self::Output {
+        let suffix = format!("tweets/{}", request.tweet_id);
+
+        let client = match TwitterClient::new(Some(&suffix), Some(&self.api_base)) {
+            Ok(client) => client,
+            Err(e) => {
+                return Output::Err {
+                    reason: e.to_string(),
+                    kind: TwitterErrorKind::Network,
+                    status_code: None,
+                }
+            }
+        };
+
+        let query_params = vec![
+            ("expansions".to_string(), "attachments.poll_ids".to_string()),
+            ("poll.fields".to_string(), POLL_FIELDS.to_string()),
+        ];
+
+        let (tweet, includes) = match client
+            .get_with_auth::<PollTweetResponse>(&request.auth, Some(query_params))
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                return Output::Err {
+                    reason: e.reason,
+                    kind: e.kind,
+                    status_code: e.status_code,
+                }
+            }
+        };
+
+        let poll_id = tweet
+            .attachments
+            .and_then(|attachments| attachments.poll_ids)
+            .and_then(|poll_ids| poll_ids.into_iter().next());
+        let polls = includes.and_then(|includes| includes.polls);
+
+        let Some(poll) = find_poll(polls.unwrap_or_default(), poll_id.as_deref()) else {
+            return Output::Err {
+                reason: format!("Tweet {} has no poll", tweet.id),
+                kind: TwitterErrorKind::NotFound,
+                status_code: None,
+            };
+        };
+
+        let mut options = poll.options;
+        options.sort_by_key(|option| option.position);
+
+        Output::Ok {
+            tweet_id: tweet.id,
+            poll_id: poll.id,
+            total_votes: options.iter().map(|option| i64::from(option.votes)).sum(),
+            options,
+            voting_status: poll.voting_status,
+            duration_minutes: poll.duration_minutes,
+            end_datetime: poll.end_datetime,
+        }
+    }
+}
+
+/// Find the poll attached to the tweet among the expanded polls, falling back
+/// to the first one if the tweet doesn't reference it.
+fn find_poll(polls: Vec<Poll>, poll_id: Option<&str>) -> Option<Poll> {
+    let index = polls
+        .iter()
+        .position(|poll| Some(poll.id.as_str()) == poll_id)
+        .unwrap_or(0);
+
+    polls.into_iter().nth(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        ::{
+            mockito::{Matcher, Server},
+            serde_json::json,
+        },
+    };
+
+    impl GetPollResults {
+        fn with_api_base(api_base: &str) -> Self {
+            Self {
+                api_base: api_base.to_string(),
+            }
+        }
+    }
+
+    fn create_test_input() -> Input {
+        Input {
+            auth: TwitterAuth::new(
+                "test_consumer_key",
+                "test_consumer_secret",
+                "test_access_token",
+                "test_access_token_secret",
+            ),
+            tweet_id: "1199786642791452673".to_string(),
+        }
+    }
+
+    fn query_matcher() -> Matcher {
+        Matcher::AllOf(vec![
+            Matcher::UrlEncoded("expansions".into(), "attachments.poll_ids".into()),
+            Matcher::UrlEncoded("poll.fields".into(), POLL_FIELDS.into()),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_get_poll_results_successful() {
+        let mut server = Server::new_async().await;
+        let tool = GetPollResults::with_api_base(&server.url());
+
+        let mock = server
+            .mock("GET", "/tweets/1199786642791452673")
+            .match_query(query_matcher())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "data": {
+                        "id": "1199786642791452673",
+                        "text": "C#",
+                        "edit_history_tweet_ids": ["1199786642791452673"],
+                        "attachments": { "poll_ids": ["1199786642468413448"] }
+                    },
+                    "includes": {
+                        "polls": [{
+                            "id": "1199786642468413448",
+                            "voting_status": "closed",
+                            "duration_minutes": 1440,
+                            "options": [
+                                { "position": 2, "label": "“C Sharp”", "votes": 795 },
+                                { "position": 1, "label": "“C Hashtag”", "votes": 156 }
+                            ],
+                            "end_datetime": "2019-11-28T20:26:41.000Z"
+                        }]
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        match tool.invoke(create_test_input()).await {
+            Output::Ok {
+                tweet_id,
+                poll_id,
+                options,
+                total_votes,
+                voting_status,
+                duration_minutes,
+                end_datetime,
+            } => {
+                assert_eq!(tweet_id, "1199786642791452673");
+                assert_eq!(poll_id, "1199786642468413448");
+                assert_eq!(options.len(), 2);
+                assert_eq!(options[0].label, "“C Hashtag”");
+                assert_eq!(options[1].votes, 795);
+                assert_eq!(total_votes, 951);
+                assert_eq!(voting_status, "closed");
+                assert_eq!(duration_minutes, 1440);
+                assert_eq!(end_datetime, "2019-11-28T20:26:41.000Z");
+            }
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_poll_results_without_poll() {
+        let mut server = Server::new_async().await;
+        let tool = GetPollResults::with_api_base(&server.url());
+
+        let mock = server
+            .mock("GET", "/tweets/1199786642791452673")
+            .match_query(query_matcher())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "data": {
+                        "id": "1199786642791452673",
+                        "text": "No poll here",
+                        "edit_history_tweet_ids": ["1199786642791452673"]
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        match tool.invoke(create_test_input()).await {
+            Output::Ok { .. } => panic!("Expected error, got success"),
+            Output::Err { reason, kind, .. } => {
+                assert_eq!(kind, TwitterErrorKind::NotFound);
+                assert_eq!(reason, "Tweet 1199786642791452673 has no poll");
+            }
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_poll_results_not_found() {
+        let mut server = Server::new_async().await;
+        let tool = GetPollResults::with_api_base(&server.url());
+
+        let mock = server
+            .mock("GET", "/tweets/1199786642791452673")
+            .match_query(query_matcher())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "errors": [{
+                        "value": "1199786642791452673",
+                        "detail": "Could not find tweet with id: [1199786642791452673].",
+                        "title": "Not Found Error",
+                        "resource_type": "tweet",
+                        "parameter": "id",
+                        "resource_id": "1199786642791452673",
+                        "type": "https://api.twitter.com/2/problems/resource-not-found"
+                    }]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        match tool.invoke(create_test_input()).await {
+            Output::Ok { .. } => panic!("Expected error, got success"),
+            Output::Err { kind, .. } => assert_eq!(kind, TwitterErrorKind::NotFound),
+        }
+
+        mock.assert_async().await;
+    }
+}
@@ -12,15 +12,18 @@ use {
             Meta,
             PlaceField,
             PollField,
+            SearchQuery,
+            SearchQueryInput,
             SortOrder,
             Tweet,
             TweetField,
             TweetsResponse,
             UserField,
+            MAX_SEARCH_QUERY_LENGTH,
         },
-        twitter_client::{TwitterClient, TWITTER_API_BASE},
+        twitter_client::{api_base_from_env, TwitterClient, TWITTER_API_BASE},
     },
-    chrono::DateTime,
+    chrono::{DateTime, NaiveDate},
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
     schemars::JsonSchema,
@@ -34,8 +37,9 @@ pub(crate) struct Input {
     /// Bearer Token for user's Twitter account
     bearer_token: String,
 
-    /// Search query for matching tweets
-    query: String,
+    /// Search query for matching tweets, either a raw query string or
+    /// keywords with search operators
+    query: SearchQueryInput,
 
     /// The oldest UTC timestamp from which the tweets will be provided (YYYY-MM-DDTHH:mm:ssZ)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -97,6 +101,24 @@ pub(crate) struct Input {
 impl Input {
     /// Validate input parameters
     fn validate(&self) -> Result<(), String> {
+        let query = self.query.to_query();
+
+        if query.trim().is_empty() {
+            return Err("query must not be empty".to_string());
+        }
+
+        if query.chars().count() > MAX_SEARCH_QUERY_LENGTH {
+            return Err(format!(
+                "query must be at most {} characters, got {}",
+                MAX_SEARCH_QUERY_LENGTH,
+                query.chars().count()
+            ));
+        }
+
+        if let SearchQueryInput::Structured(search_query) = &self.query {
+            validate_dates(search_query, &self.start_time, &self.end_time)?;
+        }
+
         // Validate max_results (10-100)
         if let Some(max_results) = self.max_results {
             if !(10..=100).contains(&max_results) {
@@ -128,6 +150,61 @@ impl Input {
 
         Ok(())
     }
+
+    /// The `start_time` and `end_time` parameters, falling back to the dates
+    /// of a structured query
+    fn time_range(&self) -> (Option<String>, Option<String>) {
+        let (since, until) = match &self.query {
+            SearchQueryInput::Structured(query) => (
+                query.since.as_deref().map(date_to_timestamp),
+                query.until.as_deref().map(date_to_timestamp),
+            ),
+            SearchQueryInput::Raw(_) => (None, None),
+        };
+
+        (
+            self.start_time.clone().or(since),
+            self.end_time.clone().or(until),
+        )
+    }
+}
+
+/// Check the `since` and `until` dates of a structured query. They are sent
+/// as `start_time` and `end_time` so they can't be combined with those.
+fn validate_dates(
+    query: &SearchQuery,
+    start_time: &Option<String>,
+    end_time: &Option<String>,
+) -> Result<(), String> {
+    for (name, date, conflict) in [
+        ("since", &query.since, ("start_time", start_time)),
+        ("until", &query.until, ("end_time", end_time)),
+    ] {
+        let Some(date) = date else {
+            continue;
+        };
+
+        if NaiveDate::parse_from_str(date, "%Y-%m-%d").is_err() {
+            return Err(format!(
+                "Invalid query.{} format: {}. Expected format: YYYY-MM-DD",
+                name, date
+            ));
+        }
+
+        if conflict.1.is_some() {
+            return Err(format!(
+                "query.{} and {} can't be used together",
+                name, conflict.0
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Midnight UTC of a YYYY-MM-DD date as a timestamp
+fn date_to_timestamp(date: &str) -> String {
+    format!("{}T00:00:00Z", date)
 }
 
 /// Check if a string is a valid ISO 8601 timestamp (YYYY-MM-DDTHH:mm:ssZ)
@@ -204,7 +281,7 @@ impl NexusTool for GetRecentSearchTweets {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string(),
+            api_base: api_base_from_env(TWITTER_API_BASE),
         }
     }
 
@@ -243,11 +320,12 @@ impl NexusTool for GetRecentSearchTweets {
 
         let mut query_params: Vec<(String, String)> = Vec::new();
 
-        query_params.push(("query".to_string(), request.query.clone()));
+        query_params.push(("query".to_string(), request.query.to_query()));
 
         // Add optional string parameters
-        Self::add_param(&mut query_params, "start_time", &request.start_time);
-        Self::add_param(&mut query_params, "end_time", &request.end_time);
+        let (start_time, end_time) = request.time_range();
+        Self::add_param(&mut query_params, "start_time", &start_time);
+        Self::add_param(&mut query_params, "end_time", &end_time);
         Self::add_param(&mut query_params, "since_id", &request.since_id);
         Self::add_param(&mut query_params, "until_id", &request.until_id);
         Self::add_param(&mut query_params, "max_results", &request.max_results);
@@ -324,7 +402,7 @@ mod tests {
     fn create_test_input() -> Input {
         Input {
             bearer_token: "test_bearer_token".to_string(),
-            query: "from:TwitterDev".to_string(),
+            query: "from:TwitterDev".to_string().into(),
             start_time: None,
             end_time: None,
             since_id: None,
@@ -608,7 +686,7 @@ mod tests {
             .await;
 
         let mut input = create_test_input();
-        input.query = "from:TwitterDev OR".to_string();
+        input.query = "from:TwitterDev OR".to_string().into();
 
         let output = tool.invoke(input).await;
 
@@ -851,4 +929,128 @@ mod tests {
 
         mock.assert_async().await;
     }
+
+    #[test]
+    fn test_search_query_builder() {
+        let query = SearchQuery::new("nexus")
+            .from_user("@TwitterDev")
+            .lang("en")
+            .exclude_retweets()
+            .has_images();
+
+        assert_eq!(
+            query.to_query(),
+            "nexus from:TwitterDev lang:en -is:retweet has:images"
+        );
+        assert_eq!(SearchQuery::default().lang("en").to_query(), "lang:en");
+    }
+
+    #[test]
+    fn test_query_input_untagged() {
+        let raw: SearchQueryInput = serde_json::from_value(json!("from:TwitterDev")).unwrap();
+        assert_eq!(raw, SearchQueryInput::Raw("from:TwitterDev".to_string()));
+
+        let structured: SearchQueryInput = serde_json::from_value(json!({
+            "keywords": "nexus",
+            "exclude_retweets": true,
+        }))
+        .unwrap();
+        assert_eq!(
+            structured,
+            SearchQueryInput::Structured(SearchQuery::new("nexus").exclude_retweets())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_structured_query() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let mock = server
+            .mock("GET", "/tweets/search/recent")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded(
+                    "query".into(),
+                    "nexus from:TwitterDev lang:en -is:retweet".into(),
+                ),
+                mockito::Matcher::UrlEncoded("start_time".into(), "2024-01-01T00:00:00Z".into()),
+                mockito::Matcher::UrlEncoded("end_time".into(), "2024-01-31T00:00:00Z".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "data": [
+                        {
+                            "id": "123456789",
+                            "text": "Test tweet"
+                        }
+                    ],
+                    "meta": {
+                        "result_count": 1
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let mut input = create_test_input();
+        input.query = SearchQuery::new("nexus")
+            .from_user("TwitterDev")
+            .lang("en")
+            .exclude_retweets()
+            .since("2024-01-01")
+            .until("2024-01-31")
+            .into();
+
+        match tool.invoke(input).await {
+            Output::Ok { data, .. } => assert_eq!(data.len(), 1),
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_query_validation() {
+        let (_, tool) = create_server_and_tool().await;
+
+        let invalid_queries: Vec<(SearchQueryInput, Option<String>, &str)> = vec![
+            (
+                "a".repeat(MAX_SEARCH_QUERY_LENGTH + 1).into(),
+                None,
+                "at most 512 characters",
+            ),
+            (SearchQuery::default().into(), None, "must not be empty"),
+            (
+                SearchQuery::new("nexus").since("01/01/2024").into(),
+                None,
+                "Invalid query.since format",
+            ),
+            (
+                SearchQuery::new("nexus").since("2024-01-01").into(),
+                Some("2024-01-01T00:00:00Z".to_string()),
+                "can't be used together",
+            ),
+        ];
+
+        for (query, start_time, expected) in invalid_queries {
+            let mut input = create_test_input();
+            input.query = query;
+            input.start_time = start_time;
+
+            match tool.invoke(input).await {
+                Output::Err { reason, kind, .. } => {
+                    assert_eq!(kind, TwitterErrorKind::Validation);
+                    assert!(
+                        reason.contains(expected),
+                        "Expected '{}' in error, got: {}",
+                        expected,
+                        reason
+                    );
+                }
+                Output::Ok { .. } => panic!("Expected validation error, got success"),
+            }
+        }
+    }
 }
@@ -6,7 +6,7 @@ use {
     crate::{
         error::TwitterErrorKind,
         tweet::models::{Granularity, TweetCount, TweetCountMeta, TweetCountResponse},
-        twitter_client::{TwitterClient, TWITTER_API_BASE},
+        twitter_client::{api_base_from_env, TwitterClient, TWITTER_API_BASE},
     },
     chrono::DateTime,
     nexus_sdk::{fqn, ToolFqn},
@@ -125,7 +125,7 @@ impl NexusTool for GetRecentTweetCount {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string(),
+            api_base: api_base_from_env(TWITTER_API_BASE),
         }
     }
 
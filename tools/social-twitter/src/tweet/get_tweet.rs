@@ -26,6 +26,7 @@ use {
             },
             TWITTER_API_BASE,
         },
+        twitter_client::api_base_from_env,
     },
     nexus_sdk::{fqn, ToolFqn},
     nexus_toolkit::*,
@@ -162,7 +163,7 @@ impl NexusTool for GetTweet {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string() + "/tweets",
+            api_base: api_base_from_env(TWITTER_API_BASE) + "/tweets",
         }
     }
 
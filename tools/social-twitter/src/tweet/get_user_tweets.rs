@@ -23,6 +23,7 @@ use {
             },
             TWITTER_API_BASE,
         },
+        twitter_client::api_base_from_env,
     },
     reqwest::Client,
     ::{
@@ -138,7 +139,7 @@ impl NexusTool for GetUserTweets {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string() + "/users",
+            api_base: api_base_from_env(TWITTER_API_BASE) + "/users",
         }
     }
 
@@ -3,7 +3,7 @@
 //! Standard Nexus Tool that posts a like to a tweet.
 
 use {
-    crate::{auth::TwitterAuth, tweet::TWITTER_API_BASE},
+    crate::{auth::TwitterAuth, tweet::TWITTER_API_BASE, twitter_client::api_base_from_env},
     reqwest::Client,
     ::{
         nexus_sdk::{fqn, ToolFqn},
@@ -51,7 +51,7 @@ impl NexusTool for LikeTweet {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string() + "/users",
+            api_base: api_base_from_env(TWITTER_API_BASE) + "/users",
         }
     }
 
@@ -2,8 +2,11 @@
 //!
 //! This module contains tools for Twitter operations.
 
+pub(crate) mod create_poll;
+pub(crate) mod create_thread;
 pub(crate) mod delete_tweet;
 pub(crate) mod get_mentioned_tweets;
+pub(crate) mod get_poll_results;
 pub(crate) mod get_recent_search_tweets;
 pub(crate) mod get_recent_tweet_count;
 pub(crate) mod get_tweet;
@@ -11,6 +14,7 @@ pub(crate) mod get_tweets;
 pub(crate) mod get_user_tweets;
 pub(crate) mod like_tweet;
 pub(crate) mod models;
+pub(crate) mod post_thread;
 pub(crate) mod post_tweet;
 pub(crate) mod retweet_tweet;
 pub(crate) mod undo_retweet_tweet;
@@ -598,6 +598,151 @@ pub enum ExcludeField {
     Retweets,
 }
 
+/// Maximum length of a recent search query.
+pub const MAX_SEARCH_QUERY_LENGTH: usize = 512;
+
+/// Search query built from keywords and common search operators.
+///
+/// The recent search API has no date operators, so [`SearchQuery::since`]
+/// and [`SearchQuery::until`] are sent as the `start_time` and `end_time`
+/// parameters instead.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SearchQuery {
+    /// Keywords or raw query to combine with the operators
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+    /// Only match tweets from this user, with or without the leading `@`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_user: Option<String>,
+    /// Only match tweets in this BCP 47 language, e.g. `en`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+    /// Exclude retweets from the results
+    #[serde(default)]
+    pub exclude_retweets: bool,
+    /// Only match tweets that contain images
+    #[serde(default)]
+    pub has_images: bool,
+    /// Only match tweets created on or after this UTC date (YYYY-MM-DD)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    /// Only match tweets created before this UTC date (YYYY-MM-DD)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+}
+
+impl SearchQuery {
+    /// Render the keywords and operators as a query string
+    pub fn to_query(&self) -> String {
+        let mut parts = vec![];
+
+        if let Some(keywords) = self.keywords.as_deref().map(str::trim) {
+            if !keywords.is_empty() {
+                parts.push(keywords.to_string());
+            }
+        }
+
+        if let Some(handle) = &self.from_user {
+            parts.push(format!("from:{}", handle.trim().trim_start_matches('@')));
+        }
+
+        if let Some(code) = &self.lang {
+            parts.push(format!("lang:{}", code.trim()));
+        }
+
+        if self.exclude_retweets {
+            parts.push("-is:retweet".to_string());
+        }
+
+        if self.has_images {
+            parts.push("has:images".to_string());
+        }
+
+        parts.join(" ")
+    }
+}
+
+// Builds queries in tests, tool inputs deserialize into a `SearchQuery`.
+#[cfg(test)]
+impl SearchQuery {
+    /// Create a query matching the given keywords
+    pub fn new(keywords: impl Into<String>) -> Self {
+        Self {
+            keywords: Some(keywords.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Append `from:<handle>`
+    pub fn from_user(mut self, handle: impl Into<String>) -> Self {
+        self.from_user = Some(handle.into());
+        self
+    }
+
+    /// Append `lang:<code>`
+    pub fn lang(mut self, code: impl Into<String>) -> Self {
+        self.lang = Some(code.into());
+        self
+    }
+
+    /// Append `-is:retweet`
+    pub fn exclude_retweets(mut self) -> Self {
+        self.exclude_retweets = true;
+        self
+    }
+
+    /// Append `has:images`
+    pub fn has_images(mut self) -> Self {
+        self.has_images = true;
+        self
+    }
+
+    /// Only match tweets created on or after the date (YYYY-MM-DD)
+    pub fn since(mut self, date: impl Into<String>) -> Self {
+        self.since = Some(date.into());
+        self
+    }
+
+    /// Only match tweets created before the date (YYYY-MM-DD)
+    pub fn until(mut self, date: impl Into<String>) -> Self {
+        self.until = Some(date.into());
+        self
+    }
+}
+
+/// Search query given either as a raw query string or as a [`SearchQuery`]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(untagged)]
+pub enum SearchQueryInput {
+    /// Raw query string passed to the API as is
+    Raw(String),
+    /// Query built from keywords and operators
+    Structured(SearchQuery),
+}
+
+impl SearchQueryInput {
+    /// Render the query string sent to the API
+    pub fn to_query(&self) -> String {
+        match self {
+            SearchQueryInput::Raw(query) => query.clone(),
+            SearchQueryInput::Structured(query) => query.to_query(),
+        }
+    }
+}
+
+impl From<String> for SearchQueryInput {
+    fn from(query: String) -> Self {
+        SearchQueryInput::Raw(query)
+    }
+}
+
+impl From<SearchQuery> for SearchQueryInput {
+    fn from(query: SearchQuery) -> Self {
+        SearchQueryInput::Structured(query)
+    }
+}
+
 // This models are only for the post_tweet tool
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct TweetResponse {
@@ -609,6 +754,31 @@ pub struct TweetResponse {
     pub text: String,
 }
 
+/// Twitter API response for a post tweet request
+#[derive(Debug, Deserialize)]
+pub struct PostTweetResponse {
+    /// Data returned when the request is successful
+    #[serde(default)]
+    pub data: Option<TweetResponse>,
+    /// Errors returned when the request fails
+    #[serde(default)]
+    pub errors: Option<Vec<TwitterApiError>>,
+}
+
+/// Twitter API response for a tweet looked up with its poll expanded
+#[derive(Debug, Deserialize)]
+pub struct PollTweetResponse {
+    /// Data returned when the request is successful
+    #[serde(default)]
+    pub data: Option<Tweet>,
+    /// Errors returned when the request fails
+    #[serde(default)]
+    pub errors: Option<Vec<TwitterApiError>>,
+    /// The expanded poll
+    #[serde(default)]
+    pub includes: Option<Includes>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct GeoInfo {
     /// Place ID for the location
@@ -624,6 +794,16 @@ pub struct MediaInfo {
     pub tagged_user_ids: Option<Vec<String>>,
 }
 
+/// Content of a single tweet in a thread
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TweetContent {
+    /// Text of the tweet
+    pub text: String,
+    /// Media to attach to the tweet
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media: Option<MediaInfo>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct PollInfo {
     /// Duration of the poll in minutes (5-10080)
@@ -778,9 +958,11 @@ pub struct UnlikeData {
     pub liked: bool,
 }
 
+impl_twitter_response_parser!(PostTweetResponse, TweetResponse);
 impl_twitter_response_parser!(RetweetResponse, RetweetData);
 impl_twitter_response_parser!(TweetCountResponse, Vec<TweetCount>, meta = TweetCountMeta);
 impl_twitter_response_parser!(DeleteResponse, DeleteData);
 impl_twitter_response_parser!(TweetsResponse, Vec<Tweet>, includes = Includes, meta = Meta);
 impl_twitter_response_parser!(UndoRetweetResponse, UndoRetweetData);
 impl_twitter_response_parser!(UnlikeResponse, UnlikeData);
+impl_twitter_response_parser!(PollTweetResponse, Tweet, includes = Includes);
@@ -0,0 +1,501 @@
+//! # `xyz.taluslabs.social.twitter.post-thread@1`
+//!
+//! Standard Nexus Tool that posts a thread of tweets to Twitter.
+
+use {
+    super::models::PostTweetResponse,
+    crate::{
+        auth::TwitterAuth,
+        error::{TwitterErrorKind, TwitterErrorResponse},
+        twitter_client::{api_base_from_env, TwitterClient, TWITTER_API_BASE},
+    },
+    nexus_sdk::{fqn, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+    serde_json::{json, Value},
+    std::time::Duration,
+};
+
+/// Longest wait between two tweets, so a thread cannot hold the tool for hours.
+pub(super) const MAX_DELAY_MS: u64 = 60_000;
+
+/// Error returned when a thread could not be posted completely.
+pub(super) struct ThreadError {
+    pub error: TwitterErrorResponse,
+    /// IDs of the tweets posted before the failure, in thread order
+    pub posted_tweet_ids: Vec<String>,
+}
+
+impl ThreadError {
+    fn validation(reason: String) -> Self {
+        Self {
+            error: TwitterErrorResponse {
+                reason,
+                kind: TwitterErrorKind::Validation,
+                status_code: None,
+            },
+            posted_tweet_ids: vec![],
+        }
+    }
+}
+
+/// Posts the tweet `bodies` in order, each one as a reply to the previous
+/// tweet. The first tweet replies to `in_reply_to_tweet_id` if given. Waits
+/// `delay_ms` between two tweets and stops at the first tweet that fails.
+pub(super) async fn post_tweets_as_thread(
+    api_base: &str,
+    auth: &TwitterAuth,
+    bodies: Vec<Value>,
+    in_reply_to_tweet_id: Option<String>,
+    delay_ms: Option<u64>,
+) -> Result<Vec<String>, ThreadError> {
+    if bodies.is_empty() {
+        return Err(ThreadError::validation(
+            "Thread must contain at least one tweet".to_string(),
+        ));
+    }
+
+    if let Some(delay_ms) = delay_ms.filter(|delay_ms| *delay_ms > MAX_DELAY_MS) {
+        return Err(ThreadError::validation(format!(
+            "Delay between tweets must be at most {MAX_DELAY_MS} ms, got {delay_ms} ms"
+        )));
+    }
+
+    let client = TwitterClient::new(Some("tweets"), Some(api_base)).map_err(|e| ThreadError {
+        error: TwitterErrorResponse {
+            reason: e.to_string(),
+            kind: TwitterErrorKind::Network,
+            status_code: None,
+        },
+        posted_tweet_ids: vec![],
+    })?;
+
+    let total = bodies.len();
+    let mut posted_tweet_ids: Vec<String> = Vec::with_capacity(total);
+
+    for (index, mut body) in bodies.into_iter().enumerate() {
+        if let Some(delay_ms) = delay_ms.filter(|_| index > 0) {
+            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+        }
+
+        // Each tweet replies to the previous one in the thread.
+        if let Some(reply_to) = posted_tweet_ids.last().or(in_reply_to_tweet_id.as_ref()) {
+            body["reply"] = json!({ "in_reply_to_tweet_id": reply_to });
+        }
+
+        match client
+            .post::<PostTweetResponse, _>(auth, Some(body), None)
+            .await
+        {
+            Ok(tweet) => posted_tweet_ids.push(tweet.id),
+            Err(e) => {
+                return Err(ThreadError {
+                    error: TwitterErrorResponse {
+                        reason: format!(
+                            "Failed to post tweet {} of {}: {}",
+                            index + 1,
+                            total,
+                            e.reason
+                        ),
+                        kind: e.kind,
+                        status_code: e.status_code,
+                    },
+                    posted_tweet_ids,
+                })
+            }
+        }
+    }
+
+    Ok(posted_tweet_ids)
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// Twitter API credentials
+    #[serde(flatten)]
+    auth: TwitterAuth,
+    /// Texts of the tweets in the thread, in order
+    tweets: Vec<String>,
+    /// Media IDs to attach to the tweets, matched to `tweets` by index. Use an
+    /// empty list for tweets without media.
+    #[serde(default)]
+    media_ids: Option<Vec<Vec<String>>>,
+    /// ID of an existing tweet the first tweet of the thread replies to. Use
+    /// this to resume a partially posted thread.
+    #[serde(default)]
+    in_reply_to_tweet_id: Option<String>,
+    /// Milliseconds to wait between posting two tweets, for example to stay
+    /// within rate limits. At most one minute.
+    #[serde(default)]
+    delay_ms: Option<u64>,
+}
+
+#[derive(Serialize, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok {
+        /// IDs of the posted tweets, in thread order
+        tweet_ids: Vec<String>,
+    },
+    Err {
+        /// Detailed error message
+        reason: String,
+        /// Type of error (network, server, auth, etc.)
+        kind: TwitterErrorKind,
+        /// HTTP status code if available
+        #[serde(skip_serializing_if = "Option::is_none")]
+        status_code: Option<u16>,
+        /// IDs of the tweets posted before the failure, in thread order. To
+        /// resume, pass the last one as `in_reply_to_tweet_id` along with the
+        /// remaining tweets.
+        posted_tweet_ids: Vec<String>,
+    },
+}
+
+pub(crate) struct PostThread {
+    api_base: String,
+}
+
+impl NexusTool for PostThread {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self {
+            api_base: api_base_from_env(TWITTER_API_BASE),
+        }
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.social.twitter.post-thread@1")
+    }
+
+    fn path() -> &'static str {
+        "/post-thread"
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, request: Self::Input) -> Self::Output {
+        let media_ids = request.media_ids.unwrap_or_default();
+
+        if media_ids.len() > request.tweets.len() {
+            return Output::Err {
+                reason: format!(
+                    "Got media IDs for {} tweets but the thread only has {} tweets",
+                    media_ids.len(),
+                    request.tweets.len()
+                ),
+                kind: TwitterErrorKind::Validation,
+                status_code: None,
+                posted_tweet_ids: vec![],
+            };
+        }
+
+        let bodies = request
+            .tweets
+            .iter()
+            .enumerate()
+            .map(|(index, text)| {
+                let mut body = json!({ "text": text });
+
+                if let Some(ids) = media_ids.get(index).filter(|ids| !ids.is_empty()) {
+                    body["media"] = json!({ "media_ids": ids });
+                }
+
+                body
+            })
+            .collect();
+
+        match post_tweets_as_thread(
+            &self.api_base,
+            &request.auth,
+            bodies,
+            request.in_reply_to_tweet_id,
+            request.delay_ms,
+        )
+        .await
+        {
+            Ok(tweet_ids) => Output::Ok { tweet_ids },
+            Err(ThreadError {
+                error,
+                posted_tweet_ids,
+            }) => Output::Err {
+                reason: error.reason,
+                kind: error.kind,
+                status_code: error.status_code,
+                posted_tweet_ids,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        ::{
+            mockito::{Matcher, Server},
+            serde_json::json,
+        },
+    };
+
+    impl PostThread {
+        fn with_api_base(api_base: &str) -> Self {
+            Self {
+                api_base: api_base.to_string(),
+            }
+        }
+    }
+
+    async fn create_server_and_tool() -> (mockito::ServerGuard, PostThread) {
+        let server = Server::new_async().await;
+        let tool = PostThread::with_api_base(&server.url());
+        (server, tool)
+    }
+
+    fn create_test_input(tweets: &[&str]) -> Input {
+        Input {
+            auth: TwitterAuth::new(
+                "test_consumer_key",
+                "test_consumer_secret",
+                "test_access_token",
+                "test_access_token_secret",
+            ),
+            tweets: tweets.iter().map(|t| t.to_string()).collect(),
+            media_ids: None,
+            in_reply_to_tweet_id: None,
+            delay_ms: None,
+        }
+    }
+
+    fn tweet_body(id: &str, text: &str) -> String {
+        json!({
+            "data": {
+                "id": id,
+                "edit_history_tweet_ids": [id],
+                "text": text
+            }
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_post_thread_successful() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let first_mock = server
+            .mock("POST", "/tweets")
+            .match_body(Matcher::Json(json!({
+                "text": "First",
+                "media": { "media_ids": ["media_1"] }
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(tweet_body("1", "First"))
+            .create_async()
+            .await;
+
+        let second_mock = server
+            .mock("POST", "/tweets")
+            .match_body(Matcher::Json(json!({
+                "text": "Second",
+                "reply": { "in_reply_to_tweet_id": "1" }
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(tweet_body("2", "Second"))
+            .create_async()
+            .await;
+
+        let third_mock = server
+            .mock("POST", "/tweets")
+            .match_body(Matcher::Json(json!({
+                "text": "Third",
+                "reply": { "in_reply_to_tweet_id": "2" }
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(tweet_body("3", "Third"))
+            .create_async()
+            .await;
+
+        let mut input = create_test_input(&["First", "Second", "Third"]);
+        input.media_ids = Some(vec![vec!["media_1".to_string()]]);
+
+        match tool.invoke(input).await {
+            Output::Ok { tweet_ids } => assert_eq!(tweet_ids, vec!["1", "2", "3"]),
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        first_mock.assert_async().await;
+        second_mock.assert_async().await;
+        third_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_post_thread_resume() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let mock = server
+            .mock("POST", "/tweets")
+            .match_body(Matcher::Json(json!({
+                "text": "Second",
+                "reply": { "in_reply_to_tweet_id": "1" }
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(tweet_body("2", "Second"))
+            .create_async()
+            .await;
+
+        let mut input = create_test_input(&["Second"]);
+        input.in_reply_to_tweet_id = Some("1".to_string());
+
+        match tool.invoke(input).await {
+            Output::Ok { tweet_ids } => assert_eq!(tweet_ids, vec!["2"]),
+            Output::Err { reason, .. } => panic!("Expected success, got error: {}", reason),
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_post_thread_partial_failure() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let first_mock = server
+            .mock("POST", "/tweets")
+            .match_body(Matcher::Json(json!({ "text": "First" })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(tweet_body("1", "First"))
+            .create_async()
+            .await;
+
+        let second_mock = server
+            .mock("POST", "/tweets")
+            .match_body(Matcher::PartialJson(json!({ "text": "Second" })))
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "detail": "You are not allowed to create a Tweet with duplicate content.",
+                    "status": 403,
+                    "title": "Forbidden",
+                    "type": "about:blank"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let third_mock = server
+            .mock("POST", "/tweets")
+            .match_body(Matcher::PartialJson(json!({ "text": "Third" })))
+            .expect(0)
+            .create_async()
+            .await;
+
+        let input = create_test_input(&["First", "Second", "Third"]);
+
+        match tool.invoke(input).await {
+            Output::Ok { .. } => panic!("Expected error, got success"),
+            Output::Err {
+                reason,
+                status_code,
+                posted_tweet_ids,
+                ..
+            } => {
+                assert!(
+                    reason.contains("tweet 2 of 3"),
+                    "Expected failing tweet to be reported, got: {}",
+                    reason
+                );
+                assert_eq!(status_code, Some(403));
+                assert_eq!(posted_tweet_ids, vec!["1"]);
+            }
+        }
+
+        first_mock.assert_async().await;
+        second_mock.assert_async().await;
+        third_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_post_thread_delay() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let mock = server
+            .mock("POST", "/tweets")
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(tweet_body("1", "Tweet"))
+            .expect(3)
+            .create_async()
+            .await;
+
+        let mut input = create_test_input(&["First", "Second", "Third"]);
+        input.delay_ms = Some(100);
+
+        let started = std::time::Instant::now();
+
+        assert!(matches!(tool.invoke(input).await, Output::Ok { .. }));
+
+        // No delay before the first tweet.
+        assert!(started.elapsed() >= Duration::from_millis(200));
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_post_thread_delay_too_long() {
+        let (mut server, tool) = create_server_and_tool().await;
+
+        let mock = server
+            .mock("POST", "/tweets")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let mut input = create_test_input(&["First", "Second"]);
+        input.delay_ms = Some(MAX_DELAY_MS + 1);
+
+        match tool.invoke(input).await {
+            Output::Ok { .. } => panic!("Expected error, got success"),
+            Output::Err {
+                reason,
+                kind,
+                posted_tweet_ids,
+                ..
+            } => {
+                assert_eq!(kind, TwitterErrorKind::Validation);
+                assert!(reason.contains("at most 60000 ms"));
+                assert!(posted_tweet_ids.is_empty());
+            }
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_post_thread_empty() {
+        let (_, tool) = create_server_and_tool().await;
+
+        match tool.invoke(create_test_input(&[])).await {
+            Output::Ok { .. } => panic!("Expected error, got success"),
+            Output::Err {
+                kind,
+                posted_tweet_ids,
+                ..
+            } => {
+                assert_eq!(kind, TwitterErrorKind::Validation);
+                assert!(posted_tweet_ids.is_empty());
+            }
+        }
+    }
+}
@@ -9,6 +9,7 @@ use {
             models::{GeoInfo, MediaInfo, PollInfo, ReplyInfo, ReplySettings, TweetResponse},
             TWITTER_API_BASE,
         },
+        twitter_client::api_base_from_env,
     },
     reqwest::Client,
     ::{
@@ -89,7 +90,7 @@ impl NexusTool for PostTweet {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string() + "/tweets",
+            api_base: api_base_from_env(TWITTER_API_BASE) + "/tweets",
         }
     }
 
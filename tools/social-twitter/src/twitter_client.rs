@@ -6,24 +6,49 @@ use {
     crate::{
         auth::TwitterAuth,
         error::{parse_twitter_response, TwitterError, TwitterErrorResponse},
+        rate_limiter::{endpoint_family, parse_rate_limit_reset, RateLimitPolicy, RateLimiter},
     },
-    reqwest::{multipart::Form, Client},
+    reqwest::{multipart::Form, Client, RequestBuilder, Response, StatusCode},
     serde::{de::DeserializeOwned, Serialize},
     serde_json::Value,
-    std::sync::Arc,
+    std::{future::Future, pin::Pin, sync::Arc},
 };
 
+/// Future returned by a [`TokenRefresher`]
+pub type RefreshFuture = Pin<Box<dyn Future<Output = Result<String, TwitterErrorResponse>> + Send>>;
+
+/// Callback that returns a new OAuth2 access token once the current one has
+/// expired, see [`crate::auth::refresh_token::token_refresher`]
+pub type TokenRefresher = Arc<dyn Fn() -> RefreshFuture + Send + Sync>;
+
 /// Twitter API client for making authenticated requests
 pub struct TwitterClient {
     /// HTTP client for making requests
     client: Arc<Client>,
     /// URL for Twitter API
     api_base: String,
+    /// Rate limiter shared by all clients in the process
+    rate_limiter: Arc<RateLimiter>,
+    /// Renews the bearer token when the API rejects it as expired
+    token_refresher: Option<TokenRefresher>,
 }
 
 pub(crate) const TWITTER_API_BASE: &str = "https://api.twitter.com/2";
 pub(crate) const TWITTER_X_API_BASE: &str = "https://api.x.com/2";
 
+/// Env var that overrides the Twitter API base URL of all tools, e.g. to route
+/// requests through a proxy or to a mock server
+pub(crate) const TWITTER_API_BASE_ENV: &str = "TWITTER_API_BASE";
+
+/// Returns the Twitter API base URL from the `TWITTER_API_BASE` env var,
+/// falling back to `default` if it is not set
+pub(crate) fn api_base_from_env(default: &str) -> String {
+    match std::env::var(TWITTER_API_BASE_ENV) {
+        Ok(base) if !base.trim().is_empty() => base.trim().trim_end_matches('/').to_string(),
+        _ => default.to_string(),
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum TwitterClientError {
     #[error("Unsupported HTTP method: {0}")]
@@ -33,23 +58,45 @@ pub enum TwitterClientError {
 impl TwitterClient {
     /// Creates a new Twitter client instance
     ///
-    /// Optionally takes an endpoint suffix to append to the API base
+    /// Optionally takes an endpoint suffix to append to the API base. Without
+    /// a base URL, the `TWITTER_API_BASE` env var or [`TWITTER_API_BASE`] is
+    /// used
     pub fn new(
         endpoint_suffix: Option<&str>,
         base_url: Option<&str>,
     ) -> Result<Self, TwitterClientError> {
-        let base = base_url.unwrap_or(TWITTER_API_BASE);
+        let base = match base_url {
+            Some(base) => base.to_string(),
+            None => api_base_from_env(TWITTER_API_BASE),
+        };
         let api_base = match endpoint_suffix {
             Some(suffix) => format!("{}/{}", base, suffix),
-            None => base.to_string(),
+            None => base,
         };
 
         Ok(Self {
             client: Arc::new(Client::new()),
             api_base,
+            rate_limiter: RateLimiter::global(),
+            token_refresher: None,
         })
     }
 
+    /// Replaces the process-wide rate limiter with the given one
+    #[cfg(test)]
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Renews the bearer token with the given callback and retries the request
+    /// once when the API responds with `401 Unauthorized`
+    #[allow(dead_code)]
+    pub fn with_token_refresher(mut self, token_refresher: TokenRefresher) -> Self {
+        self.token_refresher = Some(token_refresher);
+        self
+    }
+
     /// Returns the base API URL
     ///
     /// This is the base URL of the Twitter API, which is the URL of the API endpoint
@@ -148,52 +195,67 @@ impl TwitterClient {
         T: DeserializeOwned + std::fmt::Debug,
         Value: Serialize,
     {
-        let auth_header = match method {
-            "GET" => auth.generate_auth_header_for_get(&self.api_base),
-            "POST" => auth.generate_auth_header(&self.api_base),
-            "DELETE" => auth.generate_auth_header_for_delete(&self.api_base),
-            "PUT" => auth.generate_auth_header_for_put(&self.api_base),
-            _ => {
-                return Err(TwitterError::Other(
-                    TwitterClientError::UnsupportedMethod(method.to_string()).to_string(),
-                )
-                .to_error_response())
-            }
-        };
-
-        let mut request = self.client.request(
-            reqwest::Method::from_bytes(method.as_bytes()).unwrap(),
-            &self.api_base,
-        );
-
-        request = request.header("Authorization", auth_header);
-
-        // Set appropriate Content-Type header
-        if body.is_some() {
-            request = request.header("Content-Type", "application/json");
+        if !matches!(method, "GET" | "POST" | "DELETE" | "PUT") {
+            return Err(TwitterError::Other(
+                TwitterClientError::UnsupportedMethod(method.to_string()).to_string(),
+            )
+            .to_error_response());
         }
 
-        if let Some(body) = body {
-            request = request.json(&body);
-        }
-
-        // Add query parameters if provided
-        if let Some(params) = query_params {
-            request = request.query(&params);
-        }
+        // Multipart forms can't be cloned so requests with a form are only
+        // sent once.
+        let mut form = form;
+        let retryable = form.is_none();
+
+        let build_request = |form: Option<Form>| {
+            // Generate a fresh auth header (and nonce) for every attempt.
+            let auth_header = match method {
+                "GET" => auth.generate_auth_header_for_get(&self.api_base),
+                "POST" => auth.generate_auth_header(&self.api_base),
+                "DELETE" => auth.generate_auth_header_for_delete(&self.api_base),
+                _ => auth.generate_auth_header_for_put(&self.api_base),
+            };
+
+            let mut request = self.client.request(
+                reqwest::Method::from_bytes(method.as_bytes()).unwrap(),
+                &self.api_base,
+            );
+
+            request = request.header("Authorization", auth_header);
+
+            // Set appropriate Content-Type header
+            if let Some(body) = &body {
+                request = request
+                    .header("Content-Type", "application/json")
+                    .json(body);
+            }
 
-        if let Some(form) = form {
-            request = request.multipart(form);
-        }
+            // Add query parameters if provided
+            if let Some(params) = &query_params {
+                request = request.query(params);
+            }
 
-        // Network/connection errors
-        let response = match request.send().await {
-            Ok(response) => response,
-            Err(e) => {
-                return Err(TwitterError::Network(e).to_error_response());
+            if let Some(form) = form {
+                request = request.multipart(form);
             }
+
+            request
         };
 
+        let mut attempt = 0;
+        let response = self
+            .send_rate_limited(method, || {
+                attempt += 1;
+
+                match attempt {
+                    1 => Some(build_request(form.take())),
+                    _ if retryable => Some(build_request(None)),
+                    _ => None,
+                }
+            })
+            .await
+            .map_err(|e| e.to_error_response())?;
+
         // API errors (status codes, parsing, etc.)
         match parse_twitter_response::<T>(response).await {
             Ok(data) => Ok(data),
@@ -202,6 +264,9 @@ impl TwitterClient {
     }
 
     /// Makes an authenticated request to the Twitter API with a bearer token
+    ///
+    /// If a [`TokenRefresher`] is set, an expired bearer token is renewed and
+    /// the request is sent again with the new token
     async fn make_request_with_bearer_token<T>(
         &self,
         method: &str,
@@ -211,25 +276,41 @@ impl TwitterClient {
     where
         T: DeserializeOwned + std::fmt::Debug,
     {
-        let mut request = self.client.request(
-            reqwest::Method::from_bytes(method.as_bytes()).unwrap(),
-            &self.api_base,
-        );
-
-        request = request
-            .header("Authorization", format!("Bearer {}", bearer_token))
-            .header("Content-Type", "application/json");
-
-        // Add query parameters if provided
-        if let Some(params) = query_params {
-            request = request.query(&params);
-        }
+        let mut bearer_token = bearer_token;
+        let mut refreshed = false;
+
+        let response = loop {
+            let build_request = || {
+                let mut request = self.client.request(
+                    reqwest::Method::from_bytes(method.as_bytes()).unwrap(),
+                    &self.api_base,
+                );
+
+                request = request
+                    .header("Authorization", format!("Bearer {}", bearer_token))
+                    .header("Content-Type", "application/json");
+
+                // Add query parameters if provided
+                if let Some(params) = &query_params {
+                    request = request.query(params);
+                }
 
-        // Network/connection errors
-        let response = match request.send().await {
-            Ok(response) => response,
-            Err(e) => {
-                return Err(TwitterError::Network(e).to_error_response());
+                Some(request)
+            };
+
+            let response = self
+                .send_rate_limited(method, build_request)
+                .await
+                .map_err(|e| e.to_error_response())?;
+
+            match &self.token_refresher {
+                Some(token_refresher)
+                    if response.status() == StatusCode::UNAUTHORIZED && !refreshed =>
+                {
+                    bearer_token = token_refresher().await?;
+                    refreshed = true;
+                }
+                _ => break response,
             }
         };
 
@@ -239,6 +320,70 @@ impl TwitterClient {
             Err(e) => Err(e.to_error_response()),
         }
     }
+
+    /// Sends a request through the shared rate limiter.
+    ///
+    /// `build_request` is called for every attempt and returns `None` if the
+    /// request can't be sent again. On a `429` response the endpoint family is
+    /// blocked until `x-rate-limit-reset` and the request is retried if the
+    /// reset is within [`crate::rate_limiter::RateLimitConfig::max_wait`], otherwise
+    /// [`TwitterError::RateLimited`] is returned. With [`RateLimitPolicy::Fail`]
+    /// the request is never delayed or retried.
+    async fn send_rate_limited<F>(
+        &self,
+        method: &str,
+        mut build_request: F,
+    ) -> Result<Response, TwitterError>
+    where
+        F: FnMut() -> Option<RequestBuilder>,
+    {
+        let family = endpoint_family(method, &self.api_base);
+        let policy = self.rate_limiter.config().policy;
+        let mut request = build_request();
+        let mut retries = 0;
+
+        loop {
+            let Some(current) = request.take() else {
+                return Err(TwitterError::Other(
+                    "Request could not be built".to_string(),
+                ));
+            };
+
+            self.rate_limiter.acquire(&family, policy).await?;
+
+            // Network/connection errors
+            let response = current.send().await.map_err(TwitterError::Network)?;
+
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return Ok(response);
+            }
+
+            let reset_at = parse_rate_limit_reset(response.headers());
+
+            if let Some(reset_at) = reset_at {
+                self.rate_limiter.block_until(&family, reset_at);
+
+                if policy == RateLimitPolicy::Wait
+                    && retries < self.rate_limiter.config().max_retries
+                    && self.rate_limiter.can_wait_until(reset_at)
+                {
+                    request = build_request();
+                    retries += 1;
+
+                    if request.is_some() {
+                        continue;
+                    }
+                }
+            }
+
+            let detail = match parse_twitter_response::<Value>(response).await {
+                Ok(_) => StatusCode::TOO_MANY_REQUESTS.to_string(),
+                Err(e) => e.to_string(),
+            };
+
+            return Err(TwitterError::RateLimited { reset_at, detail });
+        }
+    }
 }
 
 /// Trait for parsing Twitter API responses into a specific output type.
@@ -385,3 +530,306 @@ macro_rules! impl_twitter_response_parser {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            auth::refresh_token::token_refresher,
+            error::TwitterErrorKind,
+            rate_limiter::{RateLimitConfig, RateLimitPolicy, RATE_LIMIT_RESET_HEADER},
+            tweet::models::DeleteResponse,
+        },
+        ::{
+            chrono::Utc,
+            mockito::Server,
+            serde_json::json,
+            std::time::{Duration, Instant},
+        },
+    };
+
+    fn create_test_auth() -> TwitterAuth {
+        TwitterAuth::new(
+            "test_consumer_key",
+            "test_consumer_secret",
+            "test_access_token",
+            "test_access_token_secret",
+        )
+    }
+
+    fn create_test_limiter(max_wait: Duration) -> Arc<RateLimiter> {
+        Arc::new(RateLimiter::new(RateLimitConfig {
+            max_wait,
+            ..Default::default()
+        }))
+    }
+
+    fn create_failing_test_limiter() -> Arc<RateLimiter> {
+        Arc::new(RateLimiter::new(RateLimitConfig {
+            policy: RateLimitPolicy::Fail,
+            ..Default::default()
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_waits_for_rate_limit_reset() {
+        let mut server = Server::new_async().await;
+        let reset_at = Utc::now().timestamp() + 1;
+
+        let rate_limited_mock = server
+            .mock("DELETE", "/tweets/12345")
+            .with_status(429)
+            .with_header("content-type", "application/json")
+            .with_header(RATE_LIMIT_RESET_HEADER, &reset_at.to_string())
+            .with_body(json!({ "title": "Too Many Requests" }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let success_mock = server
+            .mock("DELETE", "/tweets/12345")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "deleted": true } }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = TwitterClient::new(Some("tweets/12345"), Some(&server.url()))
+            .unwrap()
+            .with_rate_limiter(create_test_limiter(Duration::from_secs(5)));
+
+        let started = Instant::now();
+        let result = client.delete::<DeleteResponse>(&create_test_auth()).await;
+
+        match result {
+            Ok(data) => assert!(data.deleted),
+            Err(e) => panic!("Expected success after waiting, got error: {}", e.reason),
+        }
+
+        // The client must not retry before the reset time.
+        assert!(Utc::now().timestamp() >= reset_at);
+        assert!(started.elapsed() <= Duration::from_secs(5));
+
+        rate_limited_mock.assert_async().await;
+        success_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_reports_rate_limit_reset_beyond_max_wait() {
+        let mut server = Server::new_async().await;
+        let reset_at = Utc::now().timestamp() + 3600;
+
+        let mock = server
+            .mock("DELETE", "/tweets/12345")
+            .with_status(429)
+            .with_header("content-type", "application/json")
+            .with_header(RATE_LIMIT_RESET_HEADER, &reset_at.to_string())
+            .with_body(
+                json!({
+                    "status": 429,
+                    "title": "Too Many Requests",
+                    "type": "https://api.twitter.com/2/problems/rate-limit-exceeded",
+                    "detail": "Rate limit exceeded"
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let limiter = create_test_limiter(Duration::from_secs(1));
+        let client = TwitterClient::new(Some("tweets/12345"), Some(&server.url()))
+            .unwrap()
+            .with_rate_limiter(limiter.clone());
+
+        let err = client
+            .send_rate_limited("DELETE", || {
+                Some(client.client.delete(client.get_base_api_url()))
+            })
+            .await
+            .unwrap_err();
+
+        match &err {
+            TwitterError::RateLimited {
+                reset_at: actual,
+                detail,
+            } => {
+                assert_eq!(actual.map(|t| t.timestamp()), Some(reset_at));
+                assert!(
+                    detail.contains("Too Many Requests"),
+                    "Expected API error details, got: {}",
+                    detail
+                );
+            }
+            e => panic!("Expected rate limited error, got: {:?}", e),
+        }
+
+        let response = err.to_error_response();
+        assert_eq!(response.kind, TwitterErrorKind::RateLimit);
+        assert_eq!(response.status_code, Some(429));
+
+        // Subsequent requests to the same endpoint family are rejected
+        // without hitting the API.
+        let result = client.delete::<DeleteResponse>(&create_test_auth()).await;
+
+        match result {
+            Ok(_) => panic!("Expected rate limit error, got success"),
+            Err(e) => {
+                assert_eq!(e.kind, TwitterErrorKind::RateLimit);
+                assert_eq!(e.status_code, Some(429));
+            }
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_fail_policy_does_not_wait_for_reset() {
+        let mut server = Server::new_async().await;
+        let reset_at = Utc::now().timestamp() + 2;
+
+        let mock = server
+            .mock("DELETE", "/tweets/12345")
+            .with_status(429)
+            .with_header(RATE_LIMIT_RESET_HEADER, &reset_at.to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = TwitterClient::new(Some("tweets/12345"), Some(&server.url()))
+            .unwrap()
+            .with_rate_limiter(create_failing_test_limiter());
+
+        let started = Instant::now();
+
+        for _ in 0..2 {
+            let result = client.delete::<DeleteResponse>(&create_test_auth()).await;
+
+            match result {
+                Ok(_) => panic!("Expected rate limit error, got success"),
+                Err(e) => assert_eq!(e.kind, TwitterErrorKind::RateLimit),
+            }
+        }
+
+        // Neither the retry nor the blocked second request wait for the reset.
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_without_reset_header() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("DELETE", "/tweets/12345")
+            .with_status(429)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "errors": [{
+                        "message": "Rate limit exceeded",
+                        "code": 88
+                    }]
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = TwitterClient::new(Some("tweets/12345"), Some(&server.url()))
+            .unwrap()
+            .with_rate_limiter(create_test_limiter(Duration::from_secs(5)));
+
+        let result = client.delete::<DeleteResponse>(&create_test_auth()).await;
+
+        match result {
+            Ok(_) => panic!("Expected rate limit error, got success"),
+            Err(e) => {
+                assert_eq!(e.kind, TwitterErrorKind::RateLimit);
+                assert_eq!(e.status_code, Some(429));
+                assert!(
+                    e.reason.contains("resets at unknown"),
+                    "Expected unknown reset time, got: {}",
+                    e.reason
+                );
+            }
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_refreshes_expired_bearer_token() {
+        let mut server = Server::new_async().await;
+
+        let expired_mock = server
+            .mock("GET", "/tweets/12345")
+            .match_header("Authorization", "Bearer expired_token")
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "title": "Unauthorized",
+                    "type": "about:blank",
+                    "status": 401,
+                    "detail": "Unauthorized"
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let refresh_mock = server
+            .mock("POST", "/oauth2/token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "expires_in": 7200,
+                    "access_token": "fresh_token",
+                    "refresh_token": "new_refresh_token"
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let success_mock = server
+            .mock("GET", "/tweets/12345")
+            .match_header("Authorization", "Bearer fresh_token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "deleted": true } }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = TwitterClient::new(Some("tweets/12345"), Some(&server.url()))
+            .unwrap()
+            .with_rate_limiter(create_test_limiter(Duration::from_secs(5)))
+            .with_token_refresher(token_refresher(
+                &server.url(),
+                "test_client_id",
+                "refresh_token",
+            ));
+
+        let result = client
+            .get::<DeleteResponse>("expired_token".to_string(), None)
+            .await;
+
+        match result {
+            Ok(data) => assert!(data.deleted),
+            Err(e) => panic!("Expected success after refreshing, got error: {}", e.reason),
+        }
+
+        expired_mock.assert_async().await;
+        refresh_mock.assert_async().await;
+        success_mock.assert_async().await;
+    }
+}
@@ -9,6 +9,7 @@ use {
             models::{ExpansionField, TweetField, UserField},
             TWITTER_API_BASE,
         },
+        twitter_client::api_base_from_env,
         user::models::{
             Affiliation,
             ConnectionStatus,
@@ -138,7 +139,7 @@ impl NexusTool for GetUserById {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string() + "/users",
+            api_base: api_base_from_env(TWITTER_API_BASE) + "/users",
         }
     }
 
@@ -7,7 +7,7 @@ use {
         error::TwitterErrorKind,
         list::models::Includes,
         tweet::models::{ExpansionField, TweetField, UserField},
-        twitter_client::{TwitterClient, TWITTER_API_BASE},
+        twitter_client::{api_base_from_env, TwitterClient, TWITTER_API_BASE},
         user::models::{UserData, UsersResponse},
     },
     ::{
@@ -97,7 +97,7 @@ impl NexusTool for GetUsersById {
 
     async fn new() -> Self {
         Self {
-            api_base: TWITTER_API_BASE.to_string(),
+            api_base: api_base_from_env(TWITTER_API_BASE),
         }
     }
 
@@ -7,6 +7,10 @@ pub struct WalrusConfig {
     pub publisher_url: Option<String>,
     /// The URL of the aggregator
     pub aggregator_url: Option<String>,
+    /// The URL of the Sui RPC to read the storage prices from
+    pub sui_rpc_url: Option<String>,
+    /// The ID of the Walrus system object
+    pub system_object_id: Option<String>,
 }
 
 impl WalrusConfig {
@@ -27,9 +31,24 @@ impl WalrusConfig {
         self
     }
 
+    /// Set the Sui RPC URL
+    pub fn with_sui_rpc_url(mut self, url: Option<String>) -> Self {
+        self.sui_rpc_url = url;
+        self
+    }
+
+    /// Set the Walrus system object ID
+    pub fn with_system_object_id(mut self, object_id: Option<String>) -> Self {
+        self.system_object_id = object_id;
+        self
+    }
+
     /// Build a WalrusClient with the configured settings
+    ///
+    /// The tools do not bundle the Walrus encoder, so reads are not verified
+    /// against their blob ID
     pub fn build(self) -> WalrusClient {
-        let mut client_builder = WalrusClient::builder();
+        let mut client_builder = WalrusClient::builder().with_integrity_check(false);
 
         if let Some(publisher_url) = self.publisher_url {
             client_builder = client_builder.with_publisher_url(&publisher_url);
@@ -39,6 +58,14 @@ impl WalrusConfig {
             client_builder = client_builder.with_aggregator_url(&aggregator_url);
         }
 
-        client_builder.build()
+        if let Some(sui_rpc_url) = self.sui_rpc_url {
+            client_builder = client_builder.with_sui_rpc_url(&sui_rpc_url);
+        }
+
+        if let Some(system_object_id) = self.system_object_id {
+            client_builder = client_builder.with_system_object_id(&system_object_id);
+        }
+
+        client_builder.build().expect("Integrity check is disabled")
     }
 }
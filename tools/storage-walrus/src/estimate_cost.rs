@@ -0,0 +1,261 @@
+//! # `xyz.taluslabs.storage.walrus.estimate-cost@1`
+//!
+//! Standard Nexus Tool that estimates the cost of storing a blob on Walrus.
+
+use {
+    crate::client::WalrusConfig,
+    nexus_sdk::{
+        fqn,
+        walrus::{StorageCost, WalrusError, FROST_PER_WAL},
+        ToolFqn,
+    },
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+    thiserror::Error,
+};
+
+/// Errors that can occur during cost estimation
+#[derive(Error, Debug)]
+pub enum EstimateCostError {
+    #[error("Failed to estimate storage cost: {0}")]
+    EstimateError(#[from] WalrusError),
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+}
+
+/// Types of errors that can occur during cost estimation
+#[derive(Serialize, JsonSchema, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum EstimateErrorKind {
+    /// Error during network request
+    Network,
+    /// Error validating the input
+    Validation,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// Size of the blob in bytes
+    size_bytes: u64,
+    /// The number of epochs to store the blob
+    epochs: u32,
+    /// The URL of the Sui RPC to read the Walrus storage prices from
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::validation::deserialize_url_opt"
+    )]
+    sui_rpc_url: Option<String>,
+    /// The ID of the Walrus system object holding the storage prices
+    #[serde(default)]
+    system_object_id: Option<String>,
+}
+
+#[derive(Serialize, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok {
+        /// The size of the blob after erasure coding in bytes
+        encoded_size: u64,
+        /// The estimated cost in FROST
+        cost_frost: u64,
+        /// The estimated cost in WAL
+        cost_wal: f64,
+    },
+    Err {
+        /// Detailed error message
+        reason: String,
+        /// Type of error (network, validation)
+        kind: EstimateErrorKind,
+        /// HTTP status code if available
+        #[serde(skip_serializing_if = "Option::is_none")]
+        status_code: Option<u16>,
+    },
+}
+
+pub(crate) struct EstimateCost;
+
+impl NexusTool for EstimateCost {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.storage.walrus.estimate-cost@1")
+    }
+
+    fn path() -> &'static str {
+        "/estimate-cost"
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, input: Self::Input) -> Self::Output {
+        match self.estimate(input).await {
+            Ok(StorageCost {
+                encoded_size,
+                cost_frost,
+            }) => Output::Ok {
+                encoded_size,
+                cost_frost,
+                cost_wal: cost_frost as f64 / FROST_PER_WAL as f64,
+            },
+            Err(e) => {
+                let (kind, status_code) = match &e {
+                    EstimateCostError::InvalidInput(_)
+                    | EstimateCostError::EstimateError(WalrusError::BlobTooLarge(_)) => {
+                        (EstimateErrorKind::Validation, None)
+                    }
+                    EstimateCostError::EstimateError(err) => {
+                        (EstimateErrorKind::Network, err.status_code())
+                    }
+                };
+
+                Output::Err {
+                    reason: e.to_string(),
+                    kind,
+                    status_code,
+                }
+            }
+        }
+    }
+}
+
+impl EstimateCost {
+    async fn estimate(&self, input: Input) -> Result<StorageCost, EstimateCostError> {
+        if input.size_bytes == 0 || input.epochs == 0 {
+            return Err(EstimateCostError::InvalidInput(
+                "Size and epochs must be greater than zero".to_string(),
+            ));
+        }
+
+        let walrus_client = WalrusConfig::new()
+            .with_sui_rpc_url(input.sui_rpc_url)
+            .with_system_object_id(input.system_object_id)
+            .build();
+
+        Ok(walrus_client
+            .storage_cost(input.size_bytes, input.epochs)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        mockito::{Matcher, Server},
+        serde_json::json,
+    };
+
+    fn input(size_bytes: u64, url: String) -> Input {
+        Input {
+            size_bytes,
+            epochs: 3,
+            sui_rpc_url: Some(url),
+            system_object_id: Some("0x5".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_estimate_cost() {
+        let mut server = Server::new_async().await;
+
+        let object_mock = server
+            .mock("POST", "/")
+            .match_body(Matcher::PartialJson(json!({ "method": "sui_getObject" })))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "result": { "data": { "content": { "fields": { "version": "1" } } } },
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let state_mock = server
+            .mock("POST", "/")
+            .match_body(Matcher::PartialJson(
+                json!({ "method": "suix_getDynamicFieldObject" }),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "result": { "data": { "content": { "fields": { "value": { "fields": {
+                        "committee": { "fields": { "n_shards": 1000 } },
+                        "storage_price_per_unit_size": "10000000",
+                        "write_price_per_unit_size": "20000000",
+                    } } } } } },
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let output = EstimateCost.invoke(input(1_048_576, server.url())).await;
+
+        match output {
+            Output::Ok {
+                encoded_size,
+                cost_frost,
+                cost_wal,
+            } => {
+                // 1 MiB is encoded to 5.9 MB of slivers and 64 MB of metadata.
+                assert_eq!(encoded_size, 69_978_000);
+                // 67 units stored for 3 epochs and written once.
+                assert_eq!(cost_frost, 67 * 10_000_000 * 3 + 67 * 20_000_000);
+                assert_eq!(cost_wal, 3.35);
+            }
+            Output::Err { reason, .. } => panic!("Expected Ok result, got error: {reason}"),
+        }
+
+        object_mock.assert_async().await;
+        state_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_estimate_cost_error() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/")
+            .with_status(503)
+            .with_body("Service unavailable")
+            .create_async()
+            .await;
+
+        let output = EstimateCost.invoke(input(10, server.url())).await;
+
+        match output {
+            Output::Err {
+                kind, status_code, ..
+            } => {
+                assert_eq!(kind, EstimateErrorKind::Network);
+                assert_eq!(status_code, Some(503));
+            }
+            output => panic!("Expected Err result, got {output:?}"),
+        }
+
+        mock.assert_async().await;
+
+        let output = EstimateCost.invoke(input(0, server.url())).await;
+
+        assert!(matches!(
+            output,
+            Output::Err {
+                kind: EstimateErrorKind::Validation,
+                ..
+            }
+        ));
+    }
+}
@@ -3,10 +3,14 @@
 use nexus_toolkit::bootstrap;
 
 mod client;
+mod estimate_cost;
 mod read_file;
 mod read_json;
+mod read_many_json;
+mod upload_batch;
 mod upload_file;
 mod upload_json;
+mod upload_with_metadata;
 mod utils;
 mod verify_blob;
 
@@ -16,7 +20,11 @@ async fn main() {
         upload_file::UploadFile,
         upload_json::UploadJson,
         read_json::ReadJson,
+        read_many_json::ReadManyJson,
         verify_blob::VerifyBlob,
         read_file::ReadFile,
+        upload_with_metadata::UploadWithMetadata,
+        upload_batch::UploadBatch,
+        estimate_cost::EstimateCost,
     ])
 }
@@ -3,8 +3,12 @@
 //! Standard Nexus Tool that reads a file from Walrus and returns the contents.
 
 use {
-    crate::client::WalrusConfig,
-    nexus_sdk::{fqn, walrus::WalrusError, ToolFqn},
+    crate::{client::WalrusConfig, utils::blob::BlobRef},
+    nexus_sdk::{
+        fqn,
+        walrus::{BlobMetadata, WalrusError},
+        ToolFqn,
+    },
     nexus_toolkit::*,
     schemars::JsonSchema,
     serde::{Deserialize, Serialize},
@@ -16,6 +20,8 @@ use {
 pub enum ReadFileError {
     #[error("Failed to read file: {0}")]
     ReadError(#[from] WalrusError),
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
 }
 
 /// Types of errors that can occur during file read
@@ -24,13 +30,21 @@ pub enum ReadFileError {
 pub enum ReadErrorKind {
     /// Error during network request
     Network,
+    /// Invalid input
+    Validation,
 }
 
 #[derive(Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub(crate) struct Input {
-    /// The blob ID of the file to read
-    blob_id: String,
+    /// The blob ID of the file to read. Either this or `blob_object_id` must
+    /// be set
+    #[serde(default)]
+    blob_id: Option<String>,
+    /// The ID of the Sui object of the file to read. If set, the metadata
+    /// stored in the attributes of the object is returned
+    #[serde(default)]
+    blob_object_id: Option<String>,
     /// The URL of the aggregator to read the file from
     #[serde(
         default,
@@ -44,6 +58,10 @@ pub(crate) struct Input {
 pub(crate) enum Output {
     Ok {
         bytes: Vec<u8>,
+        /// Metadata stored in the attributes of the blob object, set if the
+        /// file was read by `blob_object_id`
+        #[serde(skip_serializing_if = "Option::is_none")]
+        metadata: Option<BlobMetadata>,
     },
     Err {
         /// Detailed error message
@@ -80,17 +98,15 @@ impl NexusTool for ReadFile {
 
     async fn invoke(&self, input: Self::Input) -> Self::Output {
         match self.read_file(input).await {
-            Ok(bytes) => Output::Ok { bytes },
+            Ok((bytes, metadata)) => Output::Ok { bytes, metadata },
             Err(e) => {
                 let (kind, status_code) = match &e {
                     ReadFileError::ReadError(err) => {
-                        let status_code = match err {
-                            WalrusError::ApiError { status_code, .. } => Some(*status_code),
-                            _ => None,
-                        };
+                        let status_code = err.status_code();
 
                         (ReadErrorKind::Network, status_code)
                     }
+                    ReadFileError::InvalidInput(_) => (ReadErrorKind::Validation, None),
                 };
 
                 Output::Err {
@@ -104,14 +120,27 @@ impl NexusTool for ReadFile {
 }
 
 impl ReadFile {
-    async fn read_file(&self, input: Input) -> Result<Vec<u8>, ReadFileError> {
+    async fn read_file(
+        &self,
+        input: Input,
+    ) -> Result<(Vec<u8>, Option<BlobMetadata>), ReadFileError> {
+        let blob = BlobRef::new(input.blob_id, input.blob_object_id)
+            .map_err(ReadFileError::InvalidInput)?;
+
         let walrus_client = WalrusConfig::new()
             .with_aggregator_url(input.aggregator_url)
             .build();
 
-        let _contents = walrus_client.read_file(&input.blob_id).await?;
+        match blob {
+            BlobRef::Id(blob_id) => Ok((walrus_client.read_file(&blob_id).await?, None)),
+            BlobRef::Object(blob_object_id) => {
+                let (bytes, metadata) = walrus_client
+                    .read_file_with_metadata(&blob_object_id)
+                    .await?;
 
-        Ok(_contents)
+                Ok((bytes, Some(metadata)))
+            }
+        }
     }
 }
 
@@ -131,7 +160,7 @@ mod tests {
             client: WalrusClient,
         ) -> Result<Vec<u8>, ReadFileError> {
             client
-                .read_file(&input.blob_id)
+                .read_file(input.blob_id.as_ref().unwrap())
                 .await
                 .map_err(ReadFileError::ReadError)
         }
@@ -143,7 +172,8 @@ mod tests {
 
         // Set up test input with server URL
         let input = Input {
-            blob_id: "test_blob_id".to_string(),
+            blob_id: Some("test_blob_id".to_string()),
+            blob_object_id: None,
             aggregator_url: Some(server_url.clone()),
         };
 
@@ -160,7 +190,10 @@ mod tests {
 
         // Set up mock response
         let mock = server
-            .mock("GET", format!("/v1/blobs/{}", input.blob_id).as_str())
+            .mock(
+                "GET",
+                format!("/v1/blobs/{}", input.blob_id.as_ref().unwrap()).as_str(),
+            )
             .with_status(200)
             .with_header("content-type", "application/octet-stream")
             .with_body(file_content)
@@ -194,7 +227,10 @@ mod tests {
 
         // Mock server error
         let mock = server
-            .mock("GET", format!("/v1/blobs/{}", input.blob_id).as_str())
+            .mock(
+                "GET",
+                format!("/v1/blobs/{}", input.blob_id.as_ref().unwrap()).as_str(),
+            )
             .with_status(500)
             .with_header("content-type", "application/json")
             .with_body(r#"{"error": "Internal server error"}"#)
@@ -214,8 +250,8 @@ mod tests {
         // Check if error contains 500 status code
         let error = result.unwrap_err();
         match error {
-            ReadFileError::ReadError(WalrusError::ApiError { status_code, .. }) => {
-                assert_eq!(status_code, 500);
+            ReadFileError::ReadError(WalrusError::Server { status, .. }) => {
+                assert_eq!(status, 500);
             }
             _ => panic!("Unexpected error type: {:?}", error),
         }
@@ -230,7 +266,10 @@ mod tests {
 
         // Mock not found response
         let mock = server
-            .mock("GET", format!("/v1/blobs/{}", input.blob_id).as_str())
+            .mock(
+                "GET",
+                format!("/v1/blobs/{}", input.blob_id.as_ref().unwrap()).as_str(),
+            )
             .with_status(404)
             .with_header("content-type", "application/json")
             .with_body(r#"{"error": "Blob not found"}"#)
@@ -250,9 +289,7 @@ mod tests {
         // Check if error contains 404 status code
         let error = result.unwrap_err();
         match error {
-            ReadFileError::ReadError(WalrusError::ApiError { status_code, .. }) => {
-                assert_eq!(status_code, 404);
-            }
+            ReadFileError::ReadError(WalrusError::NotFound { .. }) => {}
             _ => panic!("Unexpected error type: {:?}", error),
         }
 
@@ -266,7 +303,10 @@ mod tests {
 
         // Set up mock response
         let mock = server
-            .mock("GET", format!("/v1/blobs/{}", input.blob_id).as_str())
+            .mock(
+                "GET",
+                format!("/v1/blobs/{}", input.blob_id.as_ref().unwrap()).as_str(),
+            )
             .with_status(200)
             .with_header("content-type", "application/octet-stream")
             .with_body(file_content)
@@ -279,8 +319,9 @@ mod tests {
 
         // Verify correct output format
         match result {
-            Output::Ok { bytes } => {
+            Output::Ok { bytes, metadata } => {
                 assert_eq!(bytes, file_content);
+                assert_eq!(metadata, None);
             }
             Output::Err { reason, .. } => {
                 panic!("Expected OK result, got error: {}", reason);
@@ -297,7 +338,10 @@ mod tests {
 
         // Mock server error
         let mock = server
-            .mock("GET", format!("/v1/blobs/{}", input.blob_id).as_str())
+            .mock(
+                "GET",
+                format!("/v1/blobs/{}", input.blob_id.as_ref().unwrap()).as_str(),
+            )
             .with_status(500)
             .with_header("content-type", "application/json")
             .with_body(r#"{"error": "Internal server error"}"#)
@@ -323,4 +367,50 @@ mod tests {
 
         mock.assert_async().await;
     }
+
+    #[tokio::test]
+    async fn test_invoke_by_object_id() {
+        let (mut server, mut input, file_content) = create_test_server_and_input().await;
+
+        input.blob_id = None;
+        input.blob_object_id = Some("0x1".to_string());
+
+        // Aggregators return the attributes of the blob object as headers.
+        let mock = server
+            .mock("GET", "/v1/blobs/by-object-id/0x1")
+            .with_status(200)
+            .with_header("content-type", "text/plain")
+            .with_header("content-disposition", r#"attachment; filename="hello.txt""#)
+            .with_body(file_content)
+            .create_async()
+            .await;
+
+        match ReadFile::with_custom_client().invoke(input).await {
+            Output::Ok { bytes, metadata } => {
+                let metadata = metadata.expect("Metadata should be returned");
+
+                assert_eq!(bytes, file_content);
+                assert_eq!(metadata.content_type.as_deref(), Some("text/plain"));
+                assert_eq!(metadata.filename.as_deref(), Some("hello.txt"));
+            }
+            Output::Err { reason, .. } => {
+                panic!("Expected OK result, got error: {}", reason);
+            }
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_invoke_invalid_input() {
+        let (_server, mut input, _) = create_test_server_and_input().await;
+
+        // Exactly one of the IDs must be set.
+        input.blob_object_id = Some("0x1".to_string());
+
+        match ReadFile::with_custom_client().invoke(input).await {
+            Output::Ok { .. } => panic!("Expected error result, got success"),
+            Output::Err { kind, .. } => assert_eq!(kind, ReadErrorKind::Validation),
+        }
+    }
 }
@@ -3,8 +3,12 @@
 //! Standard Nexus Tool that reads a JSON file from Walrus and returns the JSON data.
 
 use {
-    crate::client::WalrusConfig,
-    nexus_sdk::{fqn, walrus::WalrusError, ToolFqn},
+    crate::{client::WalrusConfig, utils::blob::BlobRef},
+    nexus_sdk::{
+        fqn,
+        walrus::{BlobMetadata, WalrusClient, WalrusError},
+        ToolFqn,
+    },
     nexus_toolkit::*,
     schemars::JsonSchema,
     serde::{Deserialize, Serialize},
@@ -21,6 +25,31 @@ pub enum ReadJsonError {
     InvalidJson(String),
     #[error("JSON validation error: {0}")]
     ValidationError(String),
+    #[error("Blob content type '{0}' is not JSON")]
+    InvalidContentType(String),
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+}
+
+impl ReadJsonError {
+    /// The kind of error reported in the tool output.
+    pub(crate) fn kind(&self) -> ReadErrorKind {
+        match self {
+            ReadJsonError::ReadError(_) => ReadErrorKind::Network,
+            ReadJsonError::ValidationError(_) => ReadErrorKind::Schema,
+            ReadJsonError::InvalidJson(_)
+            | ReadJsonError::InvalidContentType(_)
+            | ReadJsonError::InvalidInput(_) => ReadErrorKind::Validation,
+        }
+    }
+
+    /// HTTP status code of the failed request, if any.
+    pub(crate) fn status_code(&self) -> Option<u16> {
+        match self {
+            ReadJsonError::ReadError(err) => err.status_code(),
+            _ => None,
+        }
+    }
 }
 
 /// Types of errors that can occur during JSON read
@@ -53,8 +82,15 @@ pub struct WalrusJsonSchema {
 #[derive(Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub(crate) struct Input {
-    /// The blob ID of the JSON file to read
-    blob_id: String,
+    /// The blob ID of the JSON file to read. Either this or `blob_object_id`
+    /// must be set
+    #[serde(default)]
+    blob_id: Option<String>,
+    /// The ID of the Sui object of the JSON file to read. If set, the metadata
+    /// stored in the attributes of the object is returned and the blob must
+    /// not have a content type other than JSON
+    #[serde(default)]
+    blob_object_id: Option<String>,
     /// The URL of the Walrus aggregator to read the JSON from
     #[serde(
         default,
@@ -73,6 +109,10 @@ pub(crate) enum Output {
     Ok {
         /// The JSON data that was read
         json: Value,
+        /// Metadata stored in the attributes of the blob object, set if the
+        /// JSON data was read by `blob_object_id`
+        #[serde(skip_serializing_if = "Option::is_none")]
+        metadata: Option<BlobMetadata>,
     },
     Err {
         /// Detailed error message
@@ -109,10 +149,14 @@ impl NexusTool for ReadJson {
 
     async fn invoke(&self, input: Self::Input) -> Self::Output {
         match self
-            .read(input.blob_id.clone(), input.aggregator_url.clone())
+            .read(
+                input.blob_id.clone(),
+                input.blob_object_id.clone(),
+                input.aggregator_url.clone(),
+            )
             .await
         {
-            Ok(string_result) => {
+            Ok((string_result, metadata)) => {
                 // Parse the JSON data
                 let json_data = match serde_json::from_str(&string_result) {
                     Ok(json) => json,
@@ -132,7 +176,10 @@ impl NexusTool for ReadJson {
                     match validate(schema_def, &json_data) {
                         Ok(()) => {
                             // Schema validation passed
-                            Output::Ok { json: json_data }
+                            Output::Ok {
+                                json: json_data,
+                                metadata,
+                            }
                         }
                         Err(e) => Output::Err {
                             reason: e.to_string(),
@@ -142,24 +189,17 @@ impl NexusTool for ReadJson {
                     }
                 } else {
                     // If we parsed valid JSON but no schema was provided
-                    Output::Ok { json: json_data }
-                }
-            }
-            Err(e) => {
-                // Extract status code from WalrusError if available
-                let status_code = match &e {
-                    ReadJsonError::ReadError(WalrusError::ApiError { status_code, .. }) => {
-                        Some(*status_code)
+                    Output::Ok {
+                        json: json_data,
+                        metadata,
                     }
-                    _ => None,
-                };
-
-                Output::Err {
-                    reason: e.to_string(),
-                    kind: ReadErrorKind::Network,
-                    status_code,
                 }
             }
+            Err(e) => Output::Err {
+                reason: e.to_string(),
+                kind: e.kind(),
+                status_code: e.status_code(),
+            },
         }
     }
 }
@@ -167,19 +207,56 @@ impl NexusTool for ReadJson {
 impl ReadJson {
     async fn read(
         &self,
-        blob_id: String,
+        blob_id: Option<String>,
+        blob_object_id: Option<String>,
         aggregator_url: Option<String>,
-    ) -> Result<String, ReadJsonError> {
+    ) -> Result<(String, Option<BlobMetadata>), ReadJsonError> {
+        let blob = BlobRef::new(blob_id, blob_object_id).map_err(ReadJsonError::InvalidInput)?;
+
         let walrus_client = WalrusConfig::new()
             .with_aggregator_url(aggregator_url)
             .build();
 
-        let storage_info = walrus_client.read_json(&blob_id).await?;
+        match blob {
+            BlobRef::Id(blob_id) => Ok((read_json_blob(&walrus_client, &blob_id).await?, None)),
+            BlobRef::Object(blob_object_id) => {
+                let (json, metadata) =
+                    read_json_blob_object(&walrus_client, &blob_object_id).await?;
 
-        Ok(storage_info)
+                Ok((json, Some(metadata)))
+            }
+        }
     }
 }
 
+/// Read the JSON text stored in a blob by the upload JSON tool.
+pub(crate) async fn read_json_blob(
+    walrus_client: &WalrusClient,
+    blob_id: &str,
+) -> Result<String, ReadJsonError> {
+    Ok(walrus_client.read_json(blob_id).await?)
+}
+
+/// Read the JSON text stored in a blob by the ID of its Sui object, checking
+/// that the blob was not stored with a content type other than JSON.
+async fn read_json_blob_object(
+    walrus_client: &WalrusClient,
+    blob_object_id: &str,
+) -> Result<(String, BlobMetadata), ReadJsonError> {
+    let (json, metadata) = walrus_client
+        .read_json_with_metadata(blob_object_id)
+        .await?;
+
+    // Blobs without a content type attribute are accepted.
+    if let Some(content_type) = &metadata.content_type {
+        if !metadata.is_json() {
+            return Err(ReadJsonError::InvalidContentType(content_type.clone()));
+        }
+    }
+
+    Ok((json, metadata))
+}
+
 fn validate(schema_def: &WalrusJsonSchema, json_data: &Value) -> Result<(), ReadJsonError> {
     // Extract the schema settings, using all fields
     let schema_name = &schema_def.name;
@@ -217,12 +294,13 @@ fn validate(schema_def: &WalrusJsonSchema, json_data: &Value) -> Result<(), Read
 
 #[cfg(test)]
 mod tests {
-    use {super::*, mockito::Server, nexus_sdk::walrus::WalrusClient, serde_json::json};
+    use {super::*, mockito::Server, serde_json::json};
 
     // Helper function to create test input
     fn create_test_input() -> Input {
         Input {
-            blob_id: "test_blob_id".to_string(),
+            blob_id: Some("test_blob_id".to_string()),
+            blob_object_id: None,
             aggregator_url: None,
             json_schema: None,
         }
@@ -258,8 +336,9 @@ mod tests {
             .create_async()
             .await;
 
-        let result: Result<serde_json::Value, WalrusError> =
-            client.read_json::<serde_json::Value>(&input.blob_id).await;
+        let result: Result<serde_json::Value, WalrusError> = client
+            .read_json::<serde_json::Value>(input.blob_id.as_ref().unwrap())
+            .await;
 
         match result {
             Ok(json_data) => {
@@ -278,7 +357,8 @@ mod tests {
 
         // Set aggregator_url to the mock server URL
         let input = Input {
-            blob_id: "test_blob_id".to_string(),
+            blob_id: Some("test_blob_id".to_string()),
+            blob_object_id: None,
             aggregator_url: Some(server.url()),
             json_schema: None,
         };
@@ -321,7 +401,8 @@ mod tests {
 
         // Set aggregator_url to the mock server URL
         let input = Input {
-            blob_id: "test_blob_id".to_string(),
+            blob_id: Some("test_blob_id".to_string()),
+            blob_object_id: None,
             aggregator_url: Some(server.url()),
             json_schema: None,
         };
@@ -364,7 +445,8 @@ mod tests {
 
         // Set aggregator_url to the mock server URL
         let _input = Input {
-            blob_id: "test_blob_id".to_string(),
+            blob_id: Some("test_blob_id".to_string()),
+            blob_object_id: None,
             aggregator_url: Some(server.url()),
             json_schema: None,
         };
@@ -382,7 +464,8 @@ mod tests {
         let tool = ReadJson {};
         let output = tool
             .invoke(Input {
-                blob_id: "test_blob_id".to_string(),
+                blob_id: Some("test_blob_id".to_string()),
+                blob_object_id: None,
                 aggregator_url: Some(server.url()),
                 json_schema: None,
             })
@@ -425,8 +508,9 @@ mod tests {
             .create_async()
             .await;
 
-        let result: Result<serde_json::Value, WalrusError> =
-            client.read_json::<serde_json::Value>(&input.blob_id).await;
+        let result: Result<serde_json::Value, WalrusError> = client
+            .read_json::<serde_json::Value>(input.blob_id.as_ref().unwrap())
+            .await;
         assert!(result.is_ok());
         let json_data = result.unwrap();
         assert_eq!(json_data["name"], "test");
@@ -469,7 +553,8 @@ mod tests {
         let tool = ReadJson {};
         let output = tool
             .invoke(Input {
-                blob_id: "test_blob_id".to_string(),
+                blob_id: Some("test_blob_id".to_string()),
+                blob_object_id: None,
                 aggregator_url: Some(server.url()),
                 json_schema: Some(WalrusJsonSchema {
                     name: "TestSchema".to_string(),
@@ -535,7 +620,8 @@ mod tests {
         let tool = ReadJson {};
         let output = tool
             .invoke(Input {
-                blob_id: "test_blob_id".to_string(),
+                blob_id: Some("test_blob_id".to_string()),
+                blob_object_id: None,
                 aggregator_url: Some(server.url()),
                 json_schema: Some(WalrusJsonSchema {
                     name: "StrictSchema".to_string(),
@@ -580,4 +666,85 @@ mod tests {
 
         mock.assert_async().await;
     }
+
+    #[tokio::test]
+    async fn test_read_json_metadata() {
+        let (mut server, _client) = create_mock_server_and_client().await;
+
+        // Aggregators return the attributes of the blob object as headers.
+        let json_mock = server
+            .mock("GET", "/v1/blobs/by-object-id/0x1")
+            .with_status(200)
+            .with_header("content-type", "application/json; charset=utf-8")
+            .with_body(json!(json!({ "name": "test" }).to_string()).to_string())
+            .create_async()
+            .await;
+        let text_mock = server
+            .mock("GET", "/v1/blobs/by-object-id/0x2")
+            .with_status(200)
+            .with_header("content-type", "text/plain")
+            .with_body(json!("{}").to_string())
+            .create_async()
+            .await;
+        let untyped_mock = server
+            .mock("GET", "/v1/blobs/by-object-id/0x3")
+            .with_status(200)
+            .with_header("content-type", "application/octet-stream")
+            .with_body(json!("{}").to_string())
+            .create_async()
+            .await;
+
+        let tool = ReadJson {};
+        let input = |blob_object_id: &str| Input {
+            blob_id: None,
+            blob_object_id: Some(blob_object_id.to_string()),
+            aggregator_url: Some(server.url()),
+            json_schema: None,
+        };
+
+        match tool.invoke(input("0x1")).await {
+            Output::Ok { json, metadata } => {
+                assert_eq!(json["name"], "test");
+                assert!(metadata.expect("Metadata should be returned").is_json());
+            }
+            Output::Err { reason, .. } => panic!("Expected OK response, got error: {reason}"),
+        }
+
+        // Blobs stored with a content type other than JSON are rejected.
+        match tool.invoke(input("0x2")).await {
+            Output::Ok { .. } => panic!("Expected error for non-JSON content type"),
+            Output::Err { kind, reason, .. } => {
+                assert_eq!(kind, ReadErrorKind::Validation);
+                assert!(reason.contains("text/plain"));
+            }
+        }
+
+        // Blobs without a content type attribute are accepted.
+        match tool.invoke(input("0x3")).await {
+            Output::Ok { metadata, .. } => {
+                assert_eq!(metadata, Some(BlobMetadata::default()));
+            }
+            Output::Err { reason, .. } => panic!("Expected OK response, got error: {reason}"),
+        }
+
+        // Exactly one of the IDs must be set.
+        let output = tool
+            .invoke(Input {
+                blob_id: Some("test_blob_id".to_string()),
+                ..input("0x1")
+            })
+            .await;
+
+        assert!(matches!(
+            output,
+            Output::Err {
+                kind: ReadErrorKind::Validation,
+                ..
+            }
+        ));
+
+        json_mock.assert_async().await;
+        text_mock.assert_async().await;
+        untyped_mock.assert_async().await;
+    }
 }
@@ -0,0 +1,260 @@
+//! # `xyz.taluslabs.storage.walrus.read-many-json@1`
+//!
+//! Standard Nexus Tool that reads multiple JSON files from Walrus concurrently
+//! and returns the JSON data of each.
+
+use {
+    crate::{
+        client::WalrusConfig,
+        read_json::{read_json_blob, ReadErrorKind, ReadJsonError},
+    },
+    futures_util::{stream, StreamExt},
+    nexus_sdk::{fqn, walrus::WalrusClient, ToolFqn},
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+    serde_json::Value,
+};
+
+/// The maximum number of blobs that can be read in one invocation.
+const MAX_BLOB_IDS: usize = 100;
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// The blob IDs of the JSON files to read, at most 100
+    blob_ids: Vec<String>,
+    /// The URL of the Walrus aggregator to read the JSON from
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::validation::deserialize_url_opt"
+    )]
+    aggregator_url: Option<String>,
+    /// The maximum number of blobs to download at once
+    #[serde(default = "default_max_concurrency")]
+    max_concurrency: usize,
+}
+
+fn default_max_concurrency() -> usize {
+    8
+}
+
+/// The JSON data or error of a single blob.
+#[derive(Serialize, JsonSchema, Debug)]
+pub(crate) struct BlobJson {
+    /// The blob ID of the JSON file
+    blob_id: String,
+    /// The JSON data that was read
+    #[serde(skip_serializing_if = "Option::is_none")]
+    json: Option<Value>,
+    /// Why the blob could not be read
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<BlobError>,
+}
+
+#[derive(Serialize, JsonSchema, Debug)]
+pub(crate) struct BlobError {
+    /// Detailed error message
+    reason: String,
+    /// Type of error
+    kind: ReadErrorKind,
+    /// HTTP status code if available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status_code: Option<u16>,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok {
+        /// The result of each blob in the order of the input blob IDs. Blobs
+        /// that could not be read carry an error instead of the JSON data.
+        results: Vec<BlobJson>,
+    },
+    Err {
+        /// Detailed error message
+        reason: String,
+        /// Type of error
+        kind: ReadErrorKind,
+    },
+}
+
+pub(crate) struct ReadManyJson;
+
+impl NexusTool for ReadManyJson {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self {}
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.storage.walrus.read-many-json@1")
+    }
+
+    fn path() -> &'static str {
+        "/read-many-json"
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, input: Self::Input) -> Self::Output {
+        if input.blob_ids.is_empty() || input.blob_ids.len() > MAX_BLOB_IDS {
+            return Output::Err {
+                reason: format!(
+                    "Expected between 1 and {MAX_BLOB_IDS} blob IDs, got {}",
+                    input.blob_ids.len()
+                ),
+                kind: ReadErrorKind::Validation,
+            };
+        }
+
+        let walrus_client = WalrusConfig::new()
+            .with_aggregator_url(input.aggregator_url)
+            .build();
+
+        let results = read_many(&walrus_client, input.blob_ids, input.max_concurrency).await;
+
+        Output::Ok { results }
+    }
+}
+
+/// Read the blobs with at most `max_concurrency` downloads at once. Results
+/// are returned in the order of `blob_ids` and failed reads do not stop the
+/// others.
+async fn read_many(
+    walrus_client: &WalrusClient,
+    blob_ids: Vec<String>,
+    max_concurrency: usize,
+) -> Vec<BlobJson> {
+    stream::iter(blob_ids)
+        .map(|blob_id| async move {
+            let result = read_json_blob(walrus_client, &blob_id)
+                .await
+                .and_then(|json| {
+                    serde_json::from_str(&json)
+                        .map_err(|e| ReadJsonError::InvalidJson(e.to_string()))
+                });
+
+            match result {
+                Ok(json) => BlobJson {
+                    blob_id,
+                    json: Some(json),
+                    error: None,
+                },
+                Err(e) => BlobJson {
+                    blob_id,
+                    json: None,
+                    error: Some(BlobError {
+                        reason: e.to_string(),
+                        kind: e.kind(),
+                        status_code: e.status_code(),
+                    }),
+                },
+            }
+        })
+        // Buffered rather than unordered to keep the input order.
+        .buffered(max_concurrency.max(1))
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, mockito::Server, serde_json::json};
+
+    #[tokio::test]
+    async fn test_read_many_json_partial_failure() {
+        let mut server = Server::new_async().await;
+
+        // Blobs written by the upload JSON tool contain the JSON text as a
+        // JSON string.
+        let mut mocks = vec![];
+
+        for (blob_id, value) in [("first", json!({ "a": 1 })), ("third", json!([1, 2]))] {
+            mocks.push(
+                server
+                    .mock("GET", format!("/v1/blobs/{blob_id}").as_str())
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(json!(value.to_string()).to_string())
+                    .create_async()
+                    .await,
+            );
+        }
+
+        mocks.push(
+            server
+                .mock("GET", "/v1/blobs/missing")
+                .with_status(404)
+                .with_body("Blob not found")
+                .create_async()
+                .await,
+        );
+
+        let tool = ReadManyJson {};
+        let output = tool
+            .invoke(Input {
+                blob_ids: vec![
+                    "third".to_string(),
+                    "missing".to_string(),
+                    "first".to_string(),
+                ],
+                aggregator_url: Some(server.url()),
+                max_concurrency: 2,
+            })
+            .await;
+
+        let output = serde_json::to_value(output).unwrap();
+
+        assert_eq!(
+            output,
+            json!({
+                "ok": {
+                    "results": [
+                        { "blob_id": "third", "json": [1, 2] },
+                        {
+                            "blob_id": "missing",
+                            "error": {
+                                "reason": "Failed to read JSON: Blob not found: Blob not found",
+                                "kind": "network",
+                                "status_code": 404,
+                            },
+                        },
+                        { "blob_id": "first", "json": { "a": 1 } },
+                    ],
+                },
+            })
+        );
+
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_many_json_invalid_input() {
+        let tool = ReadManyJson {};
+
+        for blob_ids in [vec![], vec!["blob".to_string(); MAX_BLOB_IDS + 1]] {
+            let output = tool
+                .invoke(Input {
+                    blob_ids,
+                    aggregator_url: None,
+                    max_concurrency: default_max_concurrency(),
+                })
+                .await;
+
+            assert!(matches!(
+                output,
+                Output::Err {
+                    kind: ReadErrorKind::Validation,
+                    ..
+                }
+            ));
+        }
+    }
+}
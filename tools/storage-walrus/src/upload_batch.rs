@@ -0,0 +1,368 @@
+//! # `xyz.taluslabs.storage.walrus.upload-batch@1`
+//!
+//! Standard Nexus Tool that uploads multiple files to Walrus concurrently and
+//! returns the blob ID of each.
+
+use {
+    crate::client::WalrusConfig,
+    base64::Engine,
+    futures_util::{stream, StreamExt},
+    nexus_sdk::{
+        fqn,
+        walrus::{StorageInfo, WalrusClient, WalrusError},
+        ToolFqn,
+    },
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+    thiserror::Error,
+};
+
+/// The maximum number of files that can be uploaded in one invocation.
+const MAX_FILES: usize = 100;
+
+/// Errors that can occur while uploading a single file
+#[derive(Error, Debug)]
+pub enum UploadBatchError {
+    #[error("Failed to upload file: {0}")]
+    UploadError(#[from] WalrusError),
+    #[error("Invalid base64 content: {0}")]
+    InvalidContent(#[from] base64::DecodeError),
+    #[error("Neither newly created nor already certified")]
+    MissingBlob,
+}
+
+impl UploadBatchError {
+    fn kind(&self) -> UploadErrorKind {
+        match self {
+            UploadBatchError::UploadError(_) => UploadErrorKind::Network,
+            UploadBatchError::InvalidContent(_) | UploadBatchError::MissingBlob => {
+                UploadErrorKind::Validation
+            }
+        }
+    }
+
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            UploadBatchError::UploadError(e) => e.status_code(),
+            _ => None,
+        }
+    }
+}
+
+/// Types of errors that can occur during batch upload
+#[derive(Serialize, JsonSchema, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum UploadErrorKind {
+    /// Error during network request
+    Network,
+    /// Error validating the input or a file
+    Validation,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// The files to upload, at most 100
+    files: Vec<FileEntry>,
+    /// The maximum number of files to upload at once
+    #[serde(default = "default_concurrency")]
+    concurrency: usize,
+    /// The walrus publisher URL
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::validation::deserialize_url_opt"
+    )]
+    publisher_url: Option<String>,
+    /// The number of epochs to store the files
+    #[serde(default = "default_epochs")]
+    epochs: u64,
+    /// Optional address to which the created Blob objects should be sent
+    #[serde(default)]
+    send_to: Option<String>,
+}
+
+fn default_concurrency() -> usize {
+    4
+}
+
+fn default_epochs() -> u64 {
+    1
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct FileEntry {
+    /// Name of the file, used to match results to files
+    name: String,
+    /// Base64 encoded content of the file
+    content_base64: String,
+}
+
+/// A file that was uploaded.
+#[derive(Serialize, JsonSchema, Debug, PartialEq)]
+pub(crate) struct BatchResult {
+    /// The name of the file
+    name: String,
+    /// The blob ID of the uploaded file
+    blob_id: String,
+}
+
+/// A file that could not be uploaded.
+#[derive(Serialize, JsonSchema, Debug, PartialEq)]
+pub(crate) struct BatchError {
+    /// The name of the file
+    name: String,
+    /// Detailed error message
+    reason: String,
+    /// Type of error
+    kind: UploadErrorKind,
+    /// HTTP status code if available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status_code: Option<u16>,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok {
+        /// The uploaded files in the order of the input files
+        results: Vec<BatchResult>,
+        /// The files that could not be uploaded in the order of the input
+        /// files
+        errors: Vec<BatchError>,
+    },
+    Err {
+        /// Detailed error message
+        reason: String,
+        /// Type of error
+        kind: UploadErrorKind,
+    },
+}
+
+pub(crate) struct UploadBatch;
+
+impl NexusTool for UploadBatch {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self {}
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.storage.walrus.upload-batch@1")
+    }
+
+    fn path() -> &'static str {
+        "/upload-batch"
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, input: Self::Input) -> Self::Output {
+        if input.files.is_empty() || input.files.len() > MAX_FILES {
+            return Output::Err {
+                reason: format!(
+                    "Expected between 1 and {MAX_FILES} files, got {}",
+                    input.files.len()
+                ),
+                kind: UploadErrorKind::Validation,
+            };
+        }
+
+        let walrus_client = WalrusConfig::new()
+            .with_publisher_url(input.publisher_url)
+            .build();
+
+        let uploads = upload_many(
+            &walrus_client,
+            input.files,
+            input.concurrency,
+            input.epochs,
+            input.send_to,
+        )
+        .await;
+
+        let mut results = vec![];
+        let mut errors = vec![];
+
+        for (name, upload) in uploads {
+            match upload {
+                Ok(blob_id) => results.push(BatchResult { name, blob_id }),
+                Err(e) => errors.push(BatchError {
+                    name,
+                    reason: e.to_string(),
+                    kind: e.kind(),
+                    status_code: e.status_code(),
+                }),
+            }
+        }
+
+        Output::Ok { results, errors }
+    }
+}
+
+/// Extracts the blob ID from the storage info.
+fn blob_id_of(storage_info: StorageInfo) -> Result<String, UploadBatchError> {
+    if let Some(newly_created) = storage_info.newly_created {
+        Ok(newly_created.blob_object.blob_id)
+    } else if let Some(already_certified) = storage_info.already_certified {
+        Ok(already_certified.blob_id)
+    } else {
+        Err(UploadBatchError::MissingBlob)
+    }
+}
+
+/// Upload the files with at most `concurrency` uploads at once. Results are
+/// returned in the order of `files` and failed uploads do not stop the others.
+async fn upload_many(
+    walrus_client: &WalrusClient,
+    files: Vec<FileEntry>,
+    concurrency: usize,
+    epochs: u64,
+    send_to: Option<String>,
+) -> Vec<(String, Result<String, UploadBatchError>)> {
+    stream::iter(files)
+        .map(|file| {
+            let send_to = send_to.clone();
+
+            async move {
+                let result = async {
+                    let content =
+                        base64::engine::general_purpose::STANDARD.decode(&file.content_base64)?;
+
+                    let storage_info = walrus_client
+                        .upload_bytes(content, epochs, send_to)
+                        .await?;
+
+                    blob_id_of(storage_info)
+                }
+                .await;
+
+                (file.name, result)
+            }
+        })
+        // Buffered rather than unordered to keep the input order.
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        mockito::{Matcher, Server},
+        serde_json::json,
+    };
+
+    fn file(name: &str, content_base64: &str) -> FileEntry {
+        FileEntry {
+            name: name.to_string(),
+            content_base64: content_base64.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upload_batch_partial_failure() {
+        let mut server = Server::new_async().await;
+
+        let ok_mock = server
+            .mock("PUT", "/v1/blobs")
+            .match_query(Matcher::Any)
+            .match_body("first")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "newlyCreated": {
+                        "blobObject": {
+                            "blobId": "first_blob_id",
+                            "id": "first_object_id",
+                            "storage": {
+                                "endEpoch": 100
+                            }
+                        }
+                    },
+                    "alreadyCertified": null
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let err_mock = server
+            .mock("PUT", "/v1/blobs")
+            .match_query(Matcher::Any)
+            .match_body("second")
+            .with_status(500)
+            .with_body("Internal server error")
+            .create_async()
+            .await;
+
+        let output = UploadBatch
+            .invoke(Input {
+                files: vec![
+                    // "first" and "second" in base64.
+                    file("first.txt", "Zmlyc3Q="),
+                    file("second.txt", "c2Vjb25k"),
+                    file("invalid.txt", "not base64!"),
+                ],
+                concurrency: 2,
+                publisher_url: Some(server.url()),
+                epochs: 1,
+                send_to: None,
+            })
+            .await;
+
+        match output {
+            Output::Ok { results, errors } => {
+                assert_eq!(
+                    results,
+                    vec![BatchResult {
+                        name: "first.txt".to_string(),
+                        blob_id: "first_blob_id".to_string(),
+                    }]
+                );
+
+                assert_eq!(errors.len(), 2);
+                assert_eq!(errors[0].name, "second.txt");
+                assert_eq!(errors[0].kind, UploadErrorKind::Network);
+                assert_eq!(errors[0].status_code, Some(500));
+                assert_eq!(errors[1].name, "invalid.txt");
+                assert_eq!(errors[1].kind, UploadErrorKind::Validation);
+                assert!(errors[1].reason.contains("Invalid base64 content"));
+            }
+            Output::Err { reason, .. } => panic!("Expected Ok result, got error: {reason}"),
+        }
+
+        ok_mock.assert_async().await;
+        err_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_upload_batch_invalid_input() {
+        for files in [vec![], (0..=MAX_FILES).map(|_| file("a", "YQ==")).collect()] {
+            let output = UploadBatch
+                .invoke(Input {
+                    files,
+                    concurrency: default_concurrency(),
+                    publisher_url: None,
+                    epochs: 1,
+                    send_to: None,
+                })
+                .await;
+
+            assert!(matches!(
+                output,
+                Output::Err {
+                    kind: UploadErrorKind::Validation,
+                    ..
+                }
+            ));
+        }
+    }
+}
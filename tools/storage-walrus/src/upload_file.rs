@@ -111,10 +111,7 @@ impl NexusTool for UploadFile {
                 let (kind, status_code) = match &e {
                     UploadFileError::InvalidFile(_) => (UploadErrorKind::Validation, None),
                     UploadFileError::UploadError(err) => {
-                        let status_code = match err {
-                            WalrusError::ApiError { status_code, .. } => Some(*status_code),
-                            _ => None,
-                        };
+                        let status_code = err.status_code();
 
                         (UploadErrorKind::Network, status_code)
                     }
@@ -442,10 +439,7 @@ mod tests {
                 let (kind, status_code) = match &e {
                     UploadFileError::InvalidFile(_) => (UploadErrorKind::Validation, None),
                     UploadFileError::UploadError(err) => {
-                        let status_code = match err {
-                            WalrusError::ApiError { status_code, .. } => Some(*status_code),
-                            _ => None,
-                        };
+                        let status_code = err.status_code();
 
                         (UploadErrorKind::Network, status_code)
                     }
@@ -132,10 +132,7 @@ impl NexusTool for UploadJson {
                 let (kind, status_code) = match &e {
                     UploadJsonError::InvalidJson(_) => (UploadErrorKind::Validation, None),
                     UploadJsonError::UploadError(err) => {
-                        let status_code = match err {
-                            WalrusError::ApiError { status_code, .. } => Some(*status_code),
-                            _ => None,
-                        };
+                        let status_code = err.status_code();
                         (UploadErrorKind::Network, status_code)
                     }
                 };
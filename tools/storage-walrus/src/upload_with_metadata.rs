@@ -0,0 +1,414 @@
+//! # `xyz.taluslabs.storage.walrus.upload-with-metadata@1`
+//!
+//! Standard Nexus Tool that uploads a file to Walrus together with a JSON blob
+//! describing it and returns the blob IDs of both.
+
+use {
+    crate::client::WalrusConfig,
+    nexus_sdk::{
+        fqn,
+        walrus::{StorageInfo, WalrusError},
+        ToolFqn,
+    },
+    nexus_toolkit::*,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+    std::{collections::HashMap, path::PathBuf},
+    thiserror::Error,
+};
+
+/// Maximum number of metadata key/value pairs.
+const MAX_METADATA_ENTRIES: usize = 16;
+/// Maximum length of a metadata value in bytes.
+const MAX_METADATA_VALUE_BYTES: usize = 256;
+
+/// Errors that can occur during file upload
+#[derive(Error, Debug)]
+pub enum UploadWithMetadataError {
+    #[error("Failed to upload file: {0}")]
+    UploadError(#[source] WalrusError),
+    #[error("Failed to upload metadata: {0}")]
+    MetadataUploadError(#[source] WalrusError),
+    #[error("Invalid file data: {0}")]
+    InvalidFile(String),
+    #[error("Invalid metadata: {0}")]
+    InvalidMetadata(String),
+    #[error("Neither newly created nor already certified")]
+    MissingBlob,
+}
+
+/// Types of errors that can occur during file upload
+#[derive(Serialize, JsonSchema, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum UploadErrorKind {
+    /// Error during network request
+    Network,
+    /// Error validating file or metadata
+    Validation,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Input {
+    /// The path to the file to upload
+    file_path: String,
+    /// Key/value pairs describing the file, at most 16 with values of at
+    /// most 256 bytes
+    metadata: HashMap<String, String>,
+    /// The walrus publisher URL
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::validation::deserialize_url_opt"
+    )]
+    publisher_url: Option<String>,
+    /// The number of epochs to store the file and its metadata
+    #[serde(default = "default_epochs")]
+    epochs: u64,
+    /// Optional address to which the created Blob objects should be sent
+    #[serde(default)]
+    send_to: Option<String>,
+}
+
+fn default_epochs() -> u64 {
+    1
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Output {
+    Ok {
+        /// The blob ID of the uploaded file
+        blob_id: String,
+        /// The blob ID of the JSON blob holding the metadata
+        metadata_blob_id: String,
+        /// The epoch at which the file blob will expire
+        end_epoch: u64,
+    },
+    Err {
+        /// Detailed error message
+        reason: String,
+        /// Type of error (upload, validation, etc.)
+        kind: UploadErrorKind,
+        /// HTTP status code if available
+        #[serde(skip_serializing_if = "Option::is_none")]
+        status_code: Option<u16>,
+    },
+}
+
+/// Contents of the metadata blob. The blob ID of the file is included so that
+/// the metadata can be traced back to the file it describes.
+#[derive(Serialize)]
+struct MetadataBlob<'a> {
+    blob_id: &'a str,
+    metadata: &'a HashMap<String, String>,
+}
+
+pub(crate) struct UploadWithMetadata;
+
+impl NexusTool for UploadWithMetadata {
+    type Input = Input;
+    type Output = Output;
+
+    async fn new() -> Self {
+        Self {}
+    }
+
+    fn fqn() -> ToolFqn {
+        fqn!("xyz.taluslabs.storage.walrus.upload-with-metadata@1")
+    }
+
+    fn path() -> &'static str {
+        "/upload-with-metadata"
+    }
+
+    async fn health(&self) -> AnyResult<StatusCode> {
+        Ok(StatusCode::OK)
+    }
+
+    async fn invoke(&self, input: Self::Input) -> Self::Output {
+        match self.upload(input).await {
+            Ok(output) => output,
+            Err(e) => {
+                let (kind, status_code) = match &e {
+                    UploadWithMetadataError::InvalidFile(_)
+                    | UploadWithMetadataError::InvalidMetadata(_)
+                    | UploadWithMetadataError::MissingBlob => (UploadErrorKind::Validation, None),
+                    UploadWithMetadataError::UploadError(err)
+                    | UploadWithMetadataError::MetadataUploadError(err) => {
+                        (UploadErrorKind::Network, err.status_code())
+                    }
+                };
+
+                Output::Err {
+                    reason: e.to_string(),
+                    kind,
+                    status_code,
+                }
+            }
+        }
+    }
+}
+
+/// Extracts the blob ID and end epoch from the storage info.
+fn blob_of(storage_info: StorageInfo) -> Result<(String, u64), UploadWithMetadataError> {
+    if let Some(newly_created) = storage_info.newly_created {
+        Ok((
+            newly_created.blob_object.blob_id,
+            newly_created.blob_object.storage.end_epoch,
+        ))
+    } else if let Some(already_certified) = storage_info.already_certified {
+        Ok((already_certified.blob_id, already_certified.end_epoch))
+    } else {
+        Err(UploadWithMetadataError::MissingBlob)
+    }
+}
+
+fn validate_file_path(file_path: &str) -> Result<(), UploadWithMetadataError> {
+    let file_path = PathBuf::from(file_path);
+    if !file_path.exists() {
+        return Err(UploadWithMetadataError::InvalidFile(format!(
+            "File does not exist: {}",
+            file_path.display()
+        )));
+    }
+    Ok(())
+}
+
+fn validate_metadata(metadata: &HashMap<String, String>) -> Result<(), UploadWithMetadataError> {
+    if metadata.len() > MAX_METADATA_ENTRIES {
+        return Err(UploadWithMetadataError::InvalidMetadata(format!(
+            "At most {MAX_METADATA_ENTRIES} entries are allowed, got {}",
+            metadata.len()
+        )));
+    }
+
+    if let Some((key, _)) = metadata
+        .iter()
+        .find(|(_, value)| value.len() > MAX_METADATA_VALUE_BYTES)
+    {
+        return Err(UploadWithMetadataError::InvalidMetadata(format!(
+            "Value of '{key}' exceeds {MAX_METADATA_VALUE_BYTES} bytes"
+        )));
+    }
+
+    Ok(())
+}
+
+impl UploadWithMetadata {
+    async fn upload(&self, input: Input) -> Result<Output, UploadWithMetadataError> {
+        validate_file_path(&input.file_path)?;
+        validate_metadata(&input.metadata)?;
+
+        let walrus_client = WalrusConfig::new()
+            .with_publisher_url(input.publisher_url)
+            .build();
+
+        let storage_info = walrus_client
+            .upload_file(
+                &PathBuf::from(&input.file_path),
+                input.epochs,
+                input.send_to.clone(),
+            )
+            .await
+            .map_err(UploadWithMetadataError::UploadError)?;
+
+        let (blob_id, end_epoch) = blob_of(storage_info)?;
+
+        // Walrus blob IDs are derived from the content so the metadata blob
+        // can only be uploaded once the file blob ID is known.
+        let metadata_blob = MetadataBlob {
+            blob_id: &blob_id,
+            metadata: &input.metadata,
+        };
+
+        let storage_info = walrus_client
+            .upload_json(&metadata_blob, input.epochs, input.send_to)
+            .await
+            .map_err(UploadWithMetadataError::MetadataUploadError)?;
+
+        let (metadata_blob_id, _) = blob_of(storage_info)?;
+
+        Ok(Output::Ok {
+            blob_id,
+            metadata_blob_id,
+            end_epoch,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        mockito::{Matcher, Server},
+        serde_json::json,
+    };
+
+    fn create_input(file_path: &str, publisher_url: String) -> Input {
+        Input {
+            file_path: file_path.to_string(),
+            metadata: HashMap::from([("author".to_string(), "alice".to_string())]),
+            publisher_url: Some(publisher_url),
+            epochs: 1,
+            send_to: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upload_with_metadata() {
+        let file_path = "test_upload_with_metadata.txt";
+        std::fs::write(file_path, "test").unwrap();
+
+        let mut server = Server::new_async().await;
+
+        let file_mock = server
+            .mock("PUT", "/v1/blobs")
+            .match_query(Matcher::Any)
+            .match_body("test")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "newlyCreated": {
+                        "blobObject": {
+                            "blobId": "file_blob_id",
+                            "id": "file_object_id",
+                            "storage": {
+                                "endEpoch": 100
+                            }
+                        }
+                    },
+                    "alreadyCertified": null
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let metadata_mock = server
+            .mock("PUT", "/v1/blobs")
+            .match_query(Matcher::Any)
+            .match_body(Matcher::Json(json!({
+                "blob_id": "file_blob_id",
+                "metadata": { "author": "alice" }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "newlyCreated": null,
+                    "alreadyCertified": {
+                        "blobId": "metadata_blob_id",
+                        "endEpoch": 100,
+                        "event": {
+                            "txDigest": "metadata_tx_digest",
+                            "timestampMs": 12345678,
+                            "suiAddress": "sui_address"
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let output = UploadWithMetadata
+            .invoke(create_input(file_path, server.url()))
+            .await;
+
+        std::fs::remove_file(file_path).unwrap();
+
+        match output {
+            Output::Ok {
+                blob_id,
+                metadata_blob_id,
+                end_epoch,
+            } => {
+                assert_eq!(blob_id, "file_blob_id");
+                assert_eq!(metadata_blob_id, "metadata_blob_id");
+                assert_eq!(end_epoch, 100);
+            }
+            Output::Err { reason, .. } => panic!("Expected Ok result, got error: {reason}"),
+        }
+
+        file_mock.assert_async().await;
+        metadata_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_upload_with_metadata_error() {
+        let file_path = "test_upload_with_metadata_error.txt";
+        std::fs::write(file_path, "test").unwrap();
+
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("PUT", "/v1/blobs")
+            .match_query(Matcher::Any)
+            .with_status(500)
+            .with_body("Internal server error")
+            .create_async()
+            .await;
+
+        let output = UploadWithMetadata
+            .invoke(create_input(file_path, server.url()))
+            .await;
+
+        std::fs::remove_file(file_path).unwrap();
+
+        match output {
+            Output::Err {
+                reason,
+                kind,
+                status_code,
+            } => {
+                assert!(reason.starts_with("Failed to upload file"));
+                assert_eq!(kind, UploadErrorKind::Network);
+                assert_eq!(status_code, Some(500));
+            }
+            Output::Ok { .. } => panic!("Expected error result, got success"),
+        }
+
+        // The metadata is not uploaded if the file upload fails.
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_upload_with_invalid_metadata() {
+        let mut input = create_input("Cargo.toml", "http://localhost".to_string());
+        input.metadata = (0..=MAX_METADATA_ENTRIES)
+            .map(|i| (format!("key{i}"), "value".to_string()))
+            .collect();
+
+        match UploadWithMetadata.invoke(input).await {
+            Output::Err { reason, kind, .. } => {
+                assert!(reason.contains("At most 16 entries"));
+                assert_eq!(kind, UploadErrorKind::Validation);
+            }
+            Output::Ok { .. } => panic!("Expected error result, got success"),
+        }
+
+        let mut input = create_input("Cargo.toml", "http://localhost".to_string());
+        input
+            .metadata
+            .insert("long".to_string(), "x".repeat(MAX_METADATA_VALUE_BYTES + 1));
+
+        match UploadWithMetadata.invoke(input).await {
+            Output::Err { reason, kind, .. } => {
+                assert!(reason.contains("Value of 'long' exceeds 256 bytes"));
+                assert_eq!(kind, UploadErrorKind::Validation);
+            }
+            Output::Ok { .. } => panic!("Expected error result, got success"),
+        }
+
+        let input = create_input("non_existent_file.txt", "http://localhost".to_string());
+
+        match UploadWithMetadata.invoke(input).await {
+            Output::Err { reason, kind, .. } => {
+                assert!(reason.contains("File does not exist"));
+                assert_eq!(kind, UploadErrorKind::Validation);
+            }
+            Output::Ok { .. } => panic!("Expected error result, got success"),
+        }
+    }
+}
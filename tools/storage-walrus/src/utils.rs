@@ -16,3 +16,28 @@ pub mod validation {
         Ok(opt)
     }
 }
+
+pub mod blob {
+    /// A blob referenced either by its blob ID or by the ID of its Sui object.
+    pub enum BlobRef {
+        /// Read by blob ID, no metadata is available
+        Id(String),
+        /// Read by the ID of the Sui object of the blob so that the metadata
+        /// stored in the attributes of the object is returned
+        Object(String),
+    }
+
+    impl BlobRef {
+        /// Exactly one of the IDs must be set.
+        pub fn new(
+            blob_id: Option<String>,
+            blob_object_id: Option<String>,
+        ) -> Result<Self, String> {
+            match (blob_id, blob_object_id) {
+                (Some(blob_id), None) => Ok(Self::Id(blob_id)),
+                (None, Some(blob_object_id)) => Ok(Self::Object(blob_object_id)),
+                _ => Err("Exactly one of 'blob_id' and 'blob_object_id' must be set".to_string()),
+            }
+        }
+    }
+}
@@ -91,11 +91,7 @@ impl NexusTool for VerifyBlob {
             }
             Err(e) => {
                 let status_code = match &e {
-                    VerifyBlobError::VerificationError(WalrusError::ApiError {
-                        status_code,
-                        ..
-                    }) => Some(*status_code),
-                    _ => None,
+                    VerifyBlobError::VerificationError(err) => err.status_code(),
                 };
 
                 Output::Err {
@@ -309,11 +305,7 @@ mod tests {
             }
             Err(e) => {
                 let status_code = match &e {
-                    VerifyBlobError::VerificationError(WalrusError::ApiError {
-                        status_code,
-                        ..
-                    }) => Some(*status_code),
-                    _ => None,
+                    VerifyBlobError::VerificationError(err) => err.status_code(),
                 };
 
                 Output::Err {